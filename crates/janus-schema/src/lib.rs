@@ -3,6 +3,9 @@
 //! This crate contains the generated schema types for GraphQL APIs used by Janus.
 //! Separating these into their own crate improves compile times by avoiding
 //! recompilation when unrelated code changes.
+//!
+//! The schemas themselves live in `schemas/` at the workspace root; refresh
+//! them with `cargo xtask schema`.
 
 // Disable all clippy lints for this crate - it's entirely generated code
 #![allow(clippy::all)]
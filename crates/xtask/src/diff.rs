@@ -0,0 +1,271 @@
+//! Breaking changes between two versions of a GraphQL schema, from the
+//! point of view of a client sending queries and mutations.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use graphql_parser::schema::{Definition, InputValue, Type, TypeDefinition, parse_schema};
+
+/// How a new schema differs from an old one
+#[derive(Debug, Default)]
+pub struct SchemaDiff {
+    /// Changes that can break existing queries
+    pub breaking: Vec<String>,
+    /// Types, fields, arguments, and values that are new
+    pub added: Vec<String>,
+}
+
+/// The parts of a named type that queries depend on
+#[derive(Debug, PartialEq)]
+struct TypeShape {
+    kind: &'static str,
+    /// Fields of objects and interfaces
+    fields: BTreeMap<String, FieldShape>,
+    /// Fields of input objects
+    inputs: BTreeMap<String, InputShape>,
+    /// Enum values and union members
+    members: BTreeSet<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct FieldShape {
+    ty: String,
+    args: BTreeMap<String, InputShape>,
+}
+
+#[derive(Debug, PartialEq)]
+struct InputShape {
+    ty: String,
+    /// Non-null without a default, so every caller must supply it
+    required: bool,
+}
+
+fn input_shapes(values: &[InputValue<'_, String>]) -> BTreeMap<String, InputShape> {
+    values
+        .iter()
+        .map(|v| {
+            let shape = InputShape {
+                ty: v.value_type.to_string(),
+                required: matches!(v.value_type, Type::NonNullType(_)) && v.default_value.is_none(),
+            };
+            (v.name.clone(), shape)
+        })
+        .collect()
+}
+
+fn shapes(sdl: &str) -> Result<BTreeMap<String, TypeShape>, String> {
+    let document =
+        parse_schema::<String>(sdl).map_err(|e| format!("failed to parse schema: {e}"))?;
+    let mut types = BTreeMap::new();
+    for definition in document.definitions {
+        let Definition::TypeDefinition(definition) = definition else {
+            continue;
+        };
+        let mut shape = TypeShape {
+            kind: "scalar",
+            fields: BTreeMap::new(),
+            inputs: BTreeMap::new(),
+            members: BTreeSet::new(),
+        };
+        let name = match definition {
+            TypeDefinition::Scalar(t) => t.name,
+            TypeDefinition::Object(t) => {
+                shape.kind = "object";
+                shape.fields = field_shapes(&t.fields);
+                t.name
+            }
+            TypeDefinition::Interface(t) => {
+                shape.kind = "interface";
+                shape.fields = field_shapes(&t.fields);
+                t.name
+            }
+            TypeDefinition::Union(t) => {
+                shape.kind = "union";
+                shape.members = t.types.into_iter().collect();
+                t.name
+            }
+            TypeDefinition::Enum(t) => {
+                shape.kind = "enum";
+                shape.members = t.values.into_iter().map(|v| v.name).collect();
+                t.name
+            }
+            TypeDefinition::InputObject(t) => {
+                shape.kind = "input";
+                shape.inputs = input_shapes(&t.fields);
+                t.name
+            }
+        };
+        types.insert(name, shape);
+    }
+    Ok(types)
+}
+
+fn field_shapes(
+    fields: &[graphql_parser::schema::Field<'_, String>],
+) -> BTreeMap<String, FieldShape> {
+    fields
+        .iter()
+        .map(|f| {
+            let shape = FieldShape {
+                ty: f.field_type.to_string(),
+                args: input_shapes(&f.arguments),
+            };
+            (f.name.clone(), shape)
+        })
+        .collect()
+}
+
+/// Compare two SDL documents. An empty `old` schema counts as having no types.
+pub fn compare(old: &str, new: &str) -> Result<SchemaDiff, String> {
+    let old = if old.trim().is_empty() {
+        BTreeMap::new()
+    } else {
+        shapes(old)?
+    };
+    let new = shapes(new)?;
+    let mut diff = SchemaDiff::default();
+
+    for (name, old_type) in &old {
+        let Some(new_type) = new.get(name) else {
+            diff.breaking.push(format!("type {name} removed"));
+            continue;
+        };
+        if old_type.kind != new_type.kind {
+            diff.breaking.push(format!(
+                "{name} changed from {} to {}",
+                old_type.kind, new_type.kind
+            ));
+            continue;
+        }
+
+        for (field, old_field) in &old_type.fields {
+            let path = format!("{name}.{field}");
+            let Some(new_field) = new_type.fields.get(field) else {
+                diff.breaking.push(format!("{path} removed"));
+                continue;
+            };
+            if old_field.ty != new_field.ty {
+                diff.breaking.push(format!(
+                    "{path} changed type from {} to {}",
+                    old_field.ty, new_field.ty
+                ));
+            }
+            compare_inputs(
+                &format!("{path} argument"),
+                &old_field.args,
+                &new_field.args,
+                &mut diff,
+            );
+        }
+        for field in new_type.fields.keys() {
+            if !old_type.fields.contains_key(field) {
+                diff.added.push(format!("{name}.{field}"));
+            }
+        }
+
+        compare_inputs(
+            &format!("{name} input field"),
+            &old_type.inputs,
+            &new_type.inputs,
+            &mut diff,
+        );
+
+        for member in old_type.members.difference(&new_type.members) {
+            diff.breaking
+                .push(format!("{name} no longer includes {member}"));
+        }
+        for member in new_type.members.difference(&old_type.members) {
+            diff.added.push(format!("{name}.{member}"));
+        }
+    }
+    for name in new.keys() {
+        if !old.contains_key(name) {
+            diff.added.push(format!("type {name}"));
+        }
+    }
+    Ok(diff)
+}
+
+/// Compare the arguments of a field or the fields of an input type
+fn compare_inputs(
+    what: &str,
+    old: &BTreeMap<String, InputShape>,
+    new: &BTreeMap<String, InputShape>,
+    diff: &mut SchemaDiff,
+) {
+    for (name, old_input) in old {
+        match new.get(name) {
+            None => diff.breaking.push(format!("{what} {name} removed")),
+            Some(new_input) if new_input.ty != old_input.ty => diff.breaking.push(format!(
+                "{what} {name} changed type from {} to {}",
+                old_input.ty, new_input.ty
+            )),
+            Some(_) => {}
+        }
+    }
+    for (name, new_input) in new {
+        if old.contains_key(name) {
+            continue;
+        }
+        if new_input.required {
+            diff.breaking
+                .push(format!("{what} {name} added as required"));
+        } else {
+            diff.added.push(format!("{what} {name}"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OLD: &str = r#"
+        type Query { issue(id: String!): Issue, viewer: User }
+        type Issue { id: ID!, title: String!, estimate: Float, state: State }
+        type User { id: ID! }
+        enum State { OPEN CLOSED ARCHIVED }
+        input IssueCreateInput { title: String!, teamId: String! }
+    "#;
+
+    const NEW: &str = r#"
+        type Query { issue(id: String!, includeArchived: Boolean): Issue }
+        type Issue { id: ID!, title: String, state: State, url: String! }
+        enum State { OPEN CLOSED }
+        input IssueCreateInput { title: String!, teamId: String!, projectId: String! }
+        scalar DateTime
+    "#;
+
+    #[test]
+    fn test_compare_reports_breaking_changes() {
+        let diff = compare(OLD, NEW).unwrap();
+        assert_eq!(
+            diff.breaking,
+            vec![
+                "Issue.estimate removed",
+                "Issue.title changed type from String! to String",
+                "IssueCreateInput input field projectId added as required",
+                "Query.viewer removed",
+                "State no longer includes ARCHIVED",
+                "type User removed",
+            ]
+        );
+        assert_eq!(
+            diff.added,
+            vec![
+                "Issue.url",
+                "Query.issue argument includeArchived",
+                "type DateTime"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_identical_and_initial() {
+        let diff = compare(OLD, OLD).unwrap();
+        assert!(diff.breaking.is_empty() && diff.added.is_empty());
+
+        let diff = compare("", NEW).unwrap();
+        assert!(diff.breaking.is_empty());
+        assert_eq!(diff.added.len(), 5);
+    }
+}
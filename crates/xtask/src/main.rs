@@ -0,0 +1,203 @@
+//! Development tasks for Janus (`cargo xtask <task>`).
+//!
+//! `cargo xtask schema` refreshes the GraphQL schemas in `schemas/` that
+//! `janus-schema` generates its cynic types from. Each schema is fetched by
+//! introspection, compared with the checked-in copy, and written back; Janus
+//! is then compiled against it, since cynic checks every query against the
+//! schema at build time.
+
+mod diff;
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+use clap::{Parser, Subcommand};
+use cynic::{GraphQlResponse, QueryBuilder};
+use cynic_introspection::IntrospectionQuery;
+
+#[derive(Parser)]
+#[command(name = "xtask", about = "Development tasks for Janus")]
+struct Cli {
+    #[command(subcommand)]
+    task: Task,
+}
+
+#[derive(Subcommand)]
+enum Task {
+    /// Fetch the latest provider GraphQL schemas and report breaking changes
+    Schema {
+        /// Only refresh this provider's schema (default: all)
+        #[arg(long)]
+        provider: Option<String>,
+
+        /// Report drift without writing; fails if any schema changed
+        #[arg(long)]
+        check: bool,
+
+        /// Skip compiling Janus against the refreshed schemas
+        #[arg(long)]
+        no_verify: bool,
+    },
+}
+
+/// A GraphQL API whose schema is checked in under `schemas/`
+struct SchemaSource {
+    /// Schema name registered with cynic (and file stem in `schemas/`)
+    name: &'static str,
+    endpoint: &'static str,
+    /// Environment variable holding the API token
+    token_env: &'static str,
+}
+
+/// Every GraphQL schema Janus is built against. A new provider adds an entry
+/// here and a `register_schema` call in `crates/janus-schema/build.rs`.
+const SCHEMAS: &[SchemaSource] = &[SchemaSource {
+    name: "linear",
+    endpoint: "https://api.linear.app/graphql",
+    token_env: "LINEAR_API_KEY",
+}];
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.task {
+        Task::Schema {
+            provider,
+            check,
+            no_verify,
+        } => refresh_schemas(provider.as_deref(), check, no_verify),
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .expect("xtask lives in crates/xtask")
+        .to_path_buf()
+}
+
+fn refresh_schemas(provider: Option<&str>, check: bool, no_verify: bool) -> ExitCode {
+    let sources: Vec<&SchemaSource> = SCHEMAS
+        .iter()
+        .filter(|s| provider.is_none_or(|p| p == s.name))
+        .collect();
+    if sources.is_empty() {
+        let names: Vec<&str> = SCHEMAS.iter().map(|s| s.name).collect();
+        eprintln!(
+            "unknown provider '{}'; expected one of: {}",
+            provider.unwrap_or_default(),
+            names.join(", ")
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let root = workspace_root();
+    let mut failed = false;
+    let mut changed = false;
+    for source in sources {
+        let path = root
+            .join("schemas")
+            .join(format!("{}.graphql", source.name));
+        match refresh_schema(source, &path, check) {
+            Ok(schema_changed) => changed |= schema_changed,
+            Err(e) => {
+                eprintln!("{}: {e}", source.name);
+                failed = true;
+            }
+        }
+    }
+
+    if check && changed {
+        eprintln!("\nSchemas have drifted; run `cargo xtask schema` to update them.");
+        return ExitCode::FAILURE;
+    }
+    if changed && !check && !no_verify {
+        println!("\nCompiling Janus against the refreshed schemas...");
+        let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+            .args(["check", "--workspace", "--all-targets"])
+            .current_dir(&root)
+            .status();
+        match status {
+            Ok(status) if status.success() => {
+                println!("All queries are valid against the new schemas.");
+            }
+            Ok(_) => {
+                eprintln!(
+                    "Janus no longer compiles: queries above use parts of the schema that changed."
+                );
+                failed = true;
+            }
+            Err(e) => {
+                eprintln!("failed to run cargo check: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Fetch one schema, report how it differs from `path`, and unless `check`,
+/// write it there. Returns whether the schema changed.
+fn refresh_schema(source: &SchemaSource, path: &Path, check: bool) -> Result<bool, String> {
+    let current = std::fs::read_to_string(path).unwrap_or_default();
+    let latest = fetch_sdl(source)?;
+    if current.trim() == latest.trim() {
+        println!("{}: up to date", source.name);
+        return Ok(false);
+    }
+
+    let diff = diff::compare(&current, &latest)?;
+    println!(
+        "{}: {} addition(s), {} breaking change(s)",
+        source.name,
+        diff.added.len(),
+        diff.breaking.len()
+    );
+    for change in &diff.breaking {
+        println!("  BREAKING  {change}");
+    }
+    for change in &diff.added {
+        println!("  added     {change}");
+    }
+
+    if !check {
+        std::fs::write(path, format!("{}\n", latest.trim_end()))
+            .map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+        println!("  wrote {}", path.display());
+    }
+    Ok(true)
+}
+
+/// Fetch a schema by introspection, as SDL
+fn fetch_sdl(source: &SchemaSource) -> Result<String, String> {
+    let token =
+        std::env::var(source.token_env).map_err(|_| format!("{} is not set", source.token_env))?;
+
+    let operation = IntrospectionQuery::build(());
+    let response = reqwest::blocking::Client::new()
+        .post(source.endpoint)
+        .header("Authorization", token)
+        .json(&operation)
+        .send()
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("introspection request failed: {e}"))?;
+    let body: GraphQlResponse<IntrospectionQuery> = response
+        .json()
+        .map_err(|e| format!("invalid introspection response: {e}"))?;
+
+    if let Some(errors) = body.errors.filter(|errors| !errors.is_empty()) {
+        let messages: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
+        return Err(format!("introspection failed: {}", messages.join("; ")));
+    }
+    let schema = body
+        .data
+        .ok_or("introspection response had no data")?
+        .into_schema()
+        .map_err(|e| format!("invalid introspection result: {e}"))?;
+    Ok(schema.to_sdl())
+}
@@ -2,8 +2,8 @@
 //!
 //! When a ticket has been in `Complete` status longer than the configured
 //! threshold (`archive.days`), the sweep transitions it to `Archived`. The
-//! threshold is measured against `completed-at` when present, falling back to
-//! the file's mtime when the ticket predates that field.
+//! threshold is measured against `closed-at` (or `completed-at`) when present,
+//! falling back to the file's mtime when the ticket predates those fields.
 
 use std::path::Path;
 use std::time::{Duration, SystemTime};
@@ -95,11 +95,16 @@ pub async fn sweep_with_threshold(
     Ok(result)
 }
 
-/// Compute the age of a ticket's completion, preferring `completed-at` and
-/// falling back to the file's mtime when unavailable. Returns `None` when
+/// Compute the age of a ticket's completion, preferring `closed-at`, then
+/// `completed-at`, and falling back to the file's mtime when unavailable. Returns `None` when
 /// neither signal is usable (e.g., ticket has no file path and no timestamp).
 pub fn ticket_age(ticket: &TicketMetadata, now: SystemTime) -> Option<Duration> {
-    if let Some(stamp) = ticket.completed_at.as_ref().and_then(|c| c.to_timestamp()) {
+    if let Some(stamp) = ticket
+        .closed_at
+        .as_ref()
+        .or(ticket.completed_at.as_ref())
+        .and_then(|c| c.to_timestamp())
+    {
         return duration_between(stamp, now);
     }
     ticket
@@ -0,0 +1,197 @@
+//! Content-addressable attachment store.
+//!
+//! Files attached to tickets are stored once under `.janus/objects/`, named by
+//! the blake3 hash of their content (`objects/3f/a9c1…`). A ticket records only
+//! a small manifest per attachment in its `attachments` frontmatter: the file
+//! name, content hash, and size. Attaching the same screenshot to several
+//! tickets stores it once, and renaming an attachment or moving a ticket only
+//! rewrites frontmatter.
+//!
+//! Objects are left in place when a ticket drops an attachment, since other
+//! tickets may share them; `janus attach gc` removes the objects no ticket
+//! references.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{JanusError, Result};
+use crate::paths::objects_dir;
+
+/// Length of a hex-encoded blake3 hash
+const HASH_LEN: usize = 64;
+
+/// A file attached to a ticket, as recorded in its frontmatter
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attachment {
+    /// File name shown in listings and used when exporting
+    pub name: String,
+    /// blake3 hash of the content, naming its object in `.janus/objects/`
+    pub hash: String,
+    /// Content size in bytes
+    pub size: u64,
+}
+
+/// Read a file into the object store and describe it as an attachment named
+/// `name` (default: the file's own name).
+pub fn attach_file(path: &Path, name: Option<&str>) -> Result<Attachment> {
+    let content = fs::read(path).map_err(|e| JanusError::StorageError {
+        operation: "read",
+        item_type: "attachment",
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    let name = match name {
+        Some(name) => name.to_string(),
+        None => path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .ok_or_else(|| {
+                JanusError::InvalidInput(format!("'{}' is not a file", path.display()))
+            })?,
+    };
+    validate_name(&name)?;
+
+    Ok(Attachment {
+        name,
+        hash: store_object(&content)?,
+        size: content.len() as u64,
+    })
+}
+
+/// Store `content` in the object store and return its hash. Content that is
+/// already stored is not written again.
+pub fn store_object(content: &[u8]) -> Result<String> {
+    let hash = blake3::hash(content).to_hex().to_string();
+    let path = object_path(&hash)?;
+    if !path.exists() {
+        crate::fs::write_bytes_atomic(&path, content)?;
+    }
+    Ok(hash)
+}
+
+/// Read an object's content, verifying it still matches its hash.
+pub fn read_object(hash: &str) -> Result<Vec<u8>> {
+    let content = fs::read(object_path(hash)?)
+        .map_err(|_| JanusError::AttachmentObjectInvalid(hash.to_string()))?;
+    if blake3::hash(&content).to_hex().as_str() != hash {
+        return Err(JanusError::AttachmentObjectInvalid(hash.to_string()));
+    }
+    Ok(content)
+}
+
+/// Path of the object with `hash`: `.janus/objects/{first two}/{rest}`.
+///
+/// Rejects anything that is not a lowercase hex blake3 hash, so a hand-edited
+/// manifest cannot point outside the object store.
+pub fn object_path(hash: &str) -> Result<PathBuf> {
+    let is_hash =
+        hash.len() == HASH_LEN && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'));
+    if !is_hash {
+        return Err(JanusError::AttachmentObjectInvalid(hash.to_string()));
+    }
+    Ok(objects_dir().join(&hash[..2]).join(&hash[2..]))
+}
+
+/// Stored objects that are not in `referenced`, as `(hash, path)` pairs.
+pub fn unreferenced_objects(referenced: &HashSet<String>) -> Vec<(String, PathBuf)> {
+    let Ok(prefixes) = fs::read_dir(objects_dir()) else {
+        return Vec::new();
+    };
+
+    let mut objects = Vec::new();
+    for prefix in prefixes.flatten() {
+        let Ok(entries) = fs::read_dir(prefix.path()) else {
+            continue;
+        };
+        let prefix = prefix.file_name().to_string_lossy().to_string();
+        for entry in entries.flatten() {
+            let hash = format!("{prefix}{}", entry.file_name().to_string_lossy());
+            if !referenced.contains(&hash) {
+                objects.push((hash, entry.path()));
+            }
+        }
+    }
+    objects.sort();
+    objects
+}
+
+/// Attachment names become file names on export, so they cannot contain path
+/// separators.
+pub(crate) fn validate_name(name: &str) -> Result<()> {
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+        return Err(JanusError::InvalidInput(format!(
+            "invalid attachment name '{name}': names cannot be empty or contain path separators"
+        )));
+    }
+    Ok(())
+}
+
+/// Human-readable size, e.g. `512 B` or `1.5 KB`
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths::JanusRootGuard;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_identical_content_is_stored_once() {
+        let tmp = TempDir::new().unwrap();
+        let _guard = JanusRootGuard::new(tmp.path().join(".janus"));
+
+        let first = tmp.path().join("screenshot.png");
+        let copy = tmp.path().join("copy.png");
+        fs::write(&first, b"pixels").unwrap();
+        fs::write(&copy, b"pixels").unwrap();
+
+        let a = attach_file(&first, None).unwrap();
+        let b = attach_file(&copy, Some("renamed.png")).unwrap();
+        assert_eq!(a.name, "screenshot.png");
+        assert_eq!(b.name, "renamed.png");
+        assert_eq!(a.hash, b.hash);
+        assert_eq!(a.size, 6);
+        assert_eq!(read_object(&a.hash).unwrap(), b"pixels");
+        assert_eq!(unreferenced_objects(&HashSet::new()).len(), 1);
+        assert!(unreferenced_objects(&HashSet::from([a.hash])).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_objects_are_rejected() {
+        let tmp = TempDir::new().unwrap();
+        let _guard = JanusRootGuard::new(tmp.path().join(".janus"));
+
+        assert!(object_path("../../etc/passwd").is_err());
+        assert!(object_path(&"A".repeat(HASH_LEN)).is_err());
+
+        let hash = store_object(b"original").unwrap();
+        fs::write(object_path(&hash).unwrap(), b"tampered").unwrap();
+        assert!(matches!(
+            read_object(&hash),
+            Err(JanusError::AttachmentObjectInvalid(_))
+        ));
+        assert!(validate_name("dir/file.png").is_err());
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MB");
+    }
+}
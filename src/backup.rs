@@ -0,0 +1,345 @@
+//! Snapshots of the `.janus` directory for `janus backup` and `janus restore`.
+//!
+//! A backup is a zstd-compressed tar archive. Its first entry is a manifest
+//! (`janus-backup.json`) listing every file with its size and BLAKE3 hash; the
+//! remaining entries are the files themselves, at paths relative to the Janus
+//! root. The embeddings cache is left out because `janus cache rebuild` can
+//! regenerate it, and so is the remote issue cache, which is refetched as
+//! needed.
+//!
+//! Both directions check the archive against its manifest: a new backup is
+//! read back once written, and a restore extracts into a staging directory and
+//! only swaps it in for the Janus root after every file has been verified.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{JanusError, Result};
+
+/// Name of the manifest entry at the start of every backup
+const MANIFEST_NAME: &str = "janus-backup.json";
+
+/// Manifest format written by this version of janus
+const MANIFEST_VERSION: u32 = 1;
+
+/// Top-level entries of the Janus root that are never backed up: caches,
+/// TUI search history from older versions, transaction journals, and lock files
+const EXCLUDED: &[&str] = &[
+    "embeddings",
+    "remote-cache",
+    "search_history",
+    ".txn",
+    ".locks",
+];
+
+/// Excluded entries carried over into a restored root. Transaction journals
+/// are dropped, since they describe files the restore replaces, and so are
+/// lock files.
+const KEPT_ON_RESTORE: &[&str] = &["embeddings", "remote-cache", "search_history"];
+
+/// Contents of a backup, stored as its first entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Manifest {
+    pub version: u32,
+    /// When the backup was taken (RFC 3339)
+    pub created: String,
+    pub files: Vec<ManifestEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Path relative to the Janus root, with `/` separators
+    pub path: String,
+    pub size: u64,
+    /// Hex-encoded BLAKE3 hash of the file contents
+    pub blake3: String,
+}
+
+impl Manifest {
+    /// Combined size of all files in bytes, before compression.
+    pub fn total_size(&self) -> u64 {
+        self.files.iter().map(|f| f.size).sum()
+    }
+}
+
+/// Write a backup of `root` to `output` and verify the written archive.
+///
+/// `output` must not exist yet. If verification fails (for example because a
+/// file changed while it was being archived), the archive is removed.
+pub fn create_backup(root: &Path, output: &Path) -> Result<Manifest> {
+    if !root.is_dir() {
+        return Err(JanusError::FileNotFound(root.display().to_string()));
+    }
+
+    let mut files = Vec::new();
+    collect_files(root, root, &mut files)?;
+    files.sort();
+
+    let mut entries = Vec::with_capacity(files.len());
+    for path in &files {
+        let data = fs::read(root.join(path))?;
+        entries.push(ManifestEntry {
+            path: path.clone(),
+            size: data.len() as u64,
+            blake3: blake3::hash(&data).to_hex().to_string(),
+        });
+    }
+    let manifest = Manifest {
+        version: MANIFEST_VERSION,
+        created: crate::utils::iso_date(),
+        files: entries,
+    };
+
+    let file = File::options().write(true).create_new(true).open(output)?;
+    let result = write_archive(root, &manifest, file).and_then(|()| read_archive(output, None));
+    if let Err(e) = result {
+        let _ = fs::remove_file(output);
+        return Err(e);
+    }
+    Ok(manifest)
+}
+
+/// Replace `root` with the contents of the backup at `archive`.
+///
+/// The archive is extracted into a staging directory next to `root` and
+/// verified before anything in `root` is touched. The embeddings cache of the
+/// current root, if any, is carried over.
+pub fn restore_backup(archive: &Path, root: &Path) -> Result<Manifest> {
+    let parent = match root.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    fs::create_dir_all(&parent)?;
+    let staging = tempfile::Builder::new()
+        .prefix(".janus-restore-")
+        .tempdir_in(&parent)?;
+    let staged_root = staging.path().join("root");
+    fs::create_dir(&staged_root)?;
+
+    let manifest = read_archive(archive, Some(&staged_root))?;
+
+    if root.exists() {
+        for name in KEPT_ON_RESTORE {
+            let path = root.join(name);
+            if path.exists() {
+                fs::rename(&path, staged_root.join(name))?;
+            }
+        }
+        // The previous root is removed along with the staging directory
+        let previous = staging.path().join("previous");
+        fs::rename(root, &previous)?;
+        if let Err(e) = fs::rename(&staged_root, root) {
+            let _ = fs::rename(&previous, root);
+            for name in KEPT_ON_RESTORE {
+                let _ = fs::rename(staged_root.join(name), root.join(name));
+            }
+            return Err(e.into());
+        }
+    } else {
+        fs::rename(&staged_root, root)?;
+    }
+
+    Ok(manifest)
+}
+
+/// Collect the paths of all regular files under `dir`, relative to `root`.
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<String>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        let relative = path.strip_prefix(root).map_err(|_| {
+            JanusError::InternalError(format!("{} escaped the Janus root", path.display()))
+        })?;
+        let relative = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        if dir == root && EXCLUDED.contains(&relative.as_str()) {
+            continue;
+        }
+        if file_type.is_dir() {
+            collect_files(root, &path, files)?;
+        } else if file_type.is_file() {
+            files.push(relative);
+        }
+    }
+    Ok(())
+}
+
+fn write_archive(root: &Path, manifest: &Manifest, file: File) -> Result<()> {
+    let encoder = zstd::Encoder::new(file, 0)?;
+    let mut builder = tar::Builder::new(encoder);
+
+    let manifest_json = serde_json::to_vec_pretty(manifest)?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest_json.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(jiff::Timestamp::now().as_second().max(0) as u64);
+    header.set_cksum();
+    builder.append_data(&mut header, MANIFEST_NAME, manifest_json.as_slice())?;
+
+    for entry in &manifest.files {
+        builder.append_path_with_name(root.join(&entry.path), &entry.path)?;
+    }
+
+    builder.into_inner()?.finish()?;
+    Ok(())
+}
+
+/// Read the backup at `path`, checking every file against the manifest.
+///
+/// With `dest`, verified files are also written below that directory.
+fn read_archive(path: &Path, dest: Option<&Path>) -> Result<Manifest> {
+    let invalid = |reason: String| JanusError::InvalidBackup(path.to_path_buf(), reason);
+
+    let decoder = zstd::Decoder::new(File::open(path)?)?;
+    let mut archive = tar::Archive::new(decoder);
+    let mut entries = archive.entries()?;
+
+    let manifest: Manifest = match entries.next() {
+        Some(entry) => {
+            let entry = entry?;
+            if entry.path()? != Path::new(MANIFEST_NAME) {
+                return Err(invalid(format!("first entry is not {MANIFEST_NAME}")));
+            }
+            serde_json::from_reader(entry)
+                .map_err(|e| invalid(format!("unreadable manifest: {e}")))?
+        }
+        None => return Err(invalid("archive is empty".to_string())),
+    };
+    if manifest.version > MANIFEST_VERSION {
+        return Err(invalid(format!(
+            "manifest version {} was written by a newer janus",
+            manifest.version
+        )));
+    }
+
+    let mut expected: BTreeMap<&str, &ManifestEntry> = manifest
+        .files
+        .iter()
+        .map(|f| (f.path.as_str(), f))
+        .collect();
+
+    for entry in entries {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let relative = entry.path()?.into_owned();
+        if !relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+        {
+            return Err(invalid(format!(
+                "unsafe path in archive: {}",
+                relative.display()
+            )));
+        }
+        let name = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let Some(file) = expected.remove(name.as_str()) else {
+            return Err(invalid(format!("{name} is not listed in the manifest")));
+        };
+
+        let mut data = Vec::with_capacity(file.size as usize);
+        entry.read_to_end(&mut data)?;
+        if data.len() as u64 != file.size || blake3::hash(&data).to_hex().as_str() != file.blake3 {
+            return Err(invalid(format!("{name} does not match its checksum")));
+        }
+
+        if let Some(dest) = dest {
+            let target = dest.join(&relative);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(target, data)?;
+        }
+    }
+
+    if let Some(missing) = expected.keys().next() {
+        return Err(invalid(format!("{missing} is missing from the archive")));
+    }
+
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_backup_and_restore_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().join(".janus");
+        write(&root, "items/j-a1b2.md", "ticket");
+        write(&root, "config.yaml", "archive:\n  days: 3\n");
+        write(&root, "embeddings/x.bin", "cache");
+        write(&root, "search_history", "status:new");
+        write(&root, ".txn/1234-abcd/journal.json", "{}");
+
+        let archive = tmp.path().join("backup.tar.zst");
+        let manifest = create_backup(&root, &archive).unwrap();
+        let paths: Vec<&str> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["config.yaml", "items/j-a1b2.md"]);
+
+        // A second backup to the same file is refused
+        assert!(create_backup(&root, &archive).is_err());
+
+        write(&root, "items/j-a1b2.md", "changed");
+        write(&root, "items/j-c3d4.md", "new");
+        restore_backup(&archive, &root).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(root.join("items/j-a1b2.md")).unwrap(),
+            "ticket"
+        );
+        assert!(!root.join("items/j-c3d4.md").exists());
+        // The embeddings cache is kept
+        assert_eq!(
+            fs::read_to_string(root.join("embeddings/x.bin")).unwrap(),
+            "cache"
+        );
+        assert!(!root.join(".txn").exists());
+    }
+
+    #[test]
+    fn test_restore_rejects_corrupted_backup() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().join(".janus");
+        write(&root, "items/j-a1b2.md", "ticket");
+
+        let archive = tmp.path().join("backup.tar.zst");
+        let mut manifest = create_backup(&root, &archive).unwrap();
+
+        // Rewrite the archive with a manifest that no longer matches
+        manifest.files[0].blake3 = blake3::hash(b"other").to_hex().to_string();
+        fs::remove_file(&archive).unwrap();
+        write_archive(&root, &manifest, File::create(&archive).unwrap()).unwrap();
+
+        write(&root, "items/j-a1b2.md", "current");
+        let err = restore_backup(&archive, &root).unwrap_err();
+        assert!(err.to_string().contains("does not match its checksum"));
+        // Nothing was touched
+        assert_eq!(
+            fs::read_to_string(root.join("items/j-a1b2.md")).unwrap(),
+            "current"
+        );
+    }
+}
@@ -1,10 +1,20 @@
 use clap::{Args, CommandFactory, Parser, Subcommand};
 use clap_complete::Shell;
+use std::ffi::OsString;
 use std::io;
+use std::path::PathBuf;
 use std::str::FromStr;
 
-use crate::query::SortField;
-use crate::types::{DEFAULT_PRIORITY_STR, TicketPriority, TicketSize, TicketStatus, TicketType};
+use crate::commands::complete::dynamic_completion_script;
+use crate::commands::{BoardExportFormat, CompletionKind};
+use crate::display::ColorChoice;
+use crate::query::{GroupField, SortField};
+use crate::secrets::EncryptionTool;
+use crate::ticket::TITLE_PREFIX;
+use crate::types::{
+    DEFAULT_PRIORITY_STR, TicketPriority, TicketResolution, TicketSection, TicketSize,
+    TicketStatus, TicketType,
+};
 
 /// Shared output options for commands that support JSON output.
 #[derive(Args, Clone, Copy, Debug)]
@@ -19,6 +29,25 @@ pub struct OutputOptions {
 #[command(about = "Plain-text issue tracking")]
 #[command(version)]
 pub struct Cli {
+    /// Refuse to run anything that modifies tickets, plans, docs, or config
+    /// (same as setting JANUS_READONLY=1)
+    #[arg(long, global = true)]
+    pub read_only: bool,
+
+    /// When to color output: auto, always, never. `auto` colors only when
+    /// stdout is a terminal and NO_COLOR is unset
+    #[arg(long, global = true, default_value = "auto", value_parser = parse_color_choice)]
+    pub color: ColorChoice,
+
+    /// Print long output directly instead of through $PAGER
+    #[arg(long, global = true)]
+    pub no_pager: bool,
+
+    /// Print failures to stderr as JSON with a stable error code:
+    /// {"error": {"code": "TICKET_NOT_FOUND", "message": "..."}}
+    #[arg(long, global = true)]
+    pub json_errors: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -29,7 +58,28 @@ pub enum Commands {
     #[command(visible_alias = "c")]
     Create {
         /// Ticket title
-        title: String,
+        #[arg(required_unless_present_any = ["interactive", "from_stdin", "batch"])]
+        title: Option<String>,
+
+        /// Prompt for each field instead (same as `janus new`)
+        #[arg(short, long, conflicts_with_all = ["title", "json", "from_stdin"])]
+        interactive: bool,
+
+        /// Create every ticket listed in a YAML or JSON file ("-" for stdin)
+        /// in one transaction, and print the ID assigned to each. Tickets can
+        /// refer to each other by name in `parent` and `deps`.
+        #[arg(long, value_name = "FILE", conflicts_with_all = [
+            "title", "interactive", "from_stdin", "description", "design", "acceptance",
+            "priority", "ticket_type", "external_ref", "parent", "prefix", "spawned_from",
+            "spawn_context", "size", "labels", "component",
+        ])]
+        batch: Option<String>,
+
+        /// Read the ticket as markdown from stdin: the H1 is the title, and
+        /// `## Design` and `## Acceptance Criteria` fill those sections while
+        /// the rest becomes the description. Explicit flags take precedence.
+        #[arg(long)]
+        from_stdin: bool,
 
         /// Description text
         #[arg(short, long)]
@@ -79,10 +129,18 @@ pub enum Commands {
         #[arg(long, value_delimiter = ',')]
         labels: Option<Vec<String>>,
 
+        /// Component or area of the codebase (suggested from `components.paths`
+        /// when `components.suggest` is enabled)
+        #[arg(long)]
+        component: Option<String>,
+
         #[command(flatten)]
         output: OutputOptions,
     },
 
+    /// Create a ticket with an interactive wizard (same as `create --interactive`)
+    New,
+
     /// Display ticket with relationships
     #[command(visible_alias = "s")]
     Show {
@@ -90,6 +148,38 @@ pub enum Commands {
         #[arg(value_parser = parse_partial_id)]
         id: String,
 
+        /// Print only one body section (description, design, acceptance, notes)
+        #[arg(long, value_parser = parse_ticket_section)]
+        section: Option<TicketSection>,
+
+        /// Render the markdown body with terminal styling instead of printing it raw
+        #[arg(long)]
+        render: bool,
+
+        /// Append a chronological feed of notes, status changes, syncs, and
+        /// commits that mention the ticket
+        #[arg(long, conflicts_with = "section")]
+        activity: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Print a ticket with its parents, deps, docs, plan phase, and recent notes
+    /// as one markdown (or JSON) bundle, ready to hand to a coding agent
+    Context {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        /// How many levels of parents and dependencies to include
+        #[arg(long, default_value_t = 1)]
+        depth: usize,
+
+        /// Number of most recent notes to include
+        #[arg(long, default_value_t = 5)]
+        notes: usize,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -101,6 +191,93 @@ pub enum Commands {
         #[arg(value_parser = parse_partial_id)]
         id: String,
 
+        /// Edit only one body section (description, design, acceptance, notes);
+        /// without a terminal, the new content is read from stdin
+        #[arg(long, value_parser = parse_ticket_section)]
+        section: Option<TicketSection>,
+
+        /// Point the editor at a list of open tickets via $JANUS_TICKET_LIST,
+        /// and reject references to tickets that do not exist on save
+        #[arg(long, conflicts_with = "section")]
+        with_helpers: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Summarize a ticket or plan with the configured LLM (requires the `llm` feature)
+    #[cfg(feature = "llm")]
+    Summarize {
+        /// Ticket or plan ID (can be partial)
+        id: String,
+
+        /// Also write the summary to the document's "Summary" section
+        #[arg(long)]
+        append: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Show tickets created, closed, or changed between two git revisions
+    ///
+    /// Ticket files are read from git as of each revision, so neither has to
+    /// be checked out, e.g. `janus diff v1.2.0 HEAD`.
+    Diff {
+        /// Earlier revision (commit, branch, or tag)
+        from: String,
+
+        /// Later revision (commit, branch, or tag)
+        to: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Show when each frontmatter field of a ticket last changed (from git history)
+    Blame {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        /// Only show a single field (e.g. status)
+        #[arg(long)]
+        field: Option<String>,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Show the tickets a ticket was spawned from and everything spawned from it
+    Lineage {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Print the resolved file path of a ticket
+    Path {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Open ticket in $EDITOR, or its linked remote issue in the browser
+    Open {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        /// Open the linked remote issue in the browser instead
+        #[arg(long)]
+        remote: bool,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -115,16 +292,93 @@ pub enum Commands {
         #[arg(trailing_var_arg = true)]
         text: Vec<String>,
 
+        /// Start the note from a template in `notes.templates` of the config;
+        /// without text, prompts for the rest of the note
+        #[arg(long, value_name = "NAME")]
+        template: Option<String>,
+
         #[command(flatten)]
         output: OutputOptions,
     },
 
+    /// List, edit, or remove individual ticket notes
+    Note {
+        #[command(subcommand)]
+        action: NoteAction,
+    },
+
     /// Mark ticket as in-progress
     Start {
         /// Ticket ID (can be partial)
         #[arg(value_parser = parse_partial_id)]
         id: String,
 
+        /// Confirm protected status transitions without prompting
+        #[arg(short = 'y', long)]
+        yes: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Hide a ticket from ls, next, and the TUI until a date passes
+    Snooze {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        /// When the snooze ends: a date (2025-07-01), a timestamp, or a
+        /// duration from now (3d, 2w)
+        #[arg(long, value_parser = parse_until_arg, required_unless_present = "clear")]
+        until: Option<jiff::Timestamp>,
+
+        /// Remove the snooze so the ticket shows up again
+        #[arg(long, conflicts_with = "until")]
+        clear: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Take an advisory lock on a ticket so other agents skip it
+    ///
+    /// Locked tickets are left out of `janus ready` and `janus next` until the
+    /// lock expires or is released with `janus unlock`.
+    Lock {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        /// Lock holder (default: $JANUS_AGENT, then the login name)
+        #[arg(long)]
+        owner: Option<String>,
+
+        /// How long the lock lasts: 30m, 2h, 1d
+        #[arg(long, default_value = "1h", value_parser = parse_duration_arg)]
+        ttl: jiff::SignedDuration,
+
+        /// Take the lock even if someone else holds it
+        #[arg(long)]
+        force: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Release an advisory lock taken with `janus lock`
+    Unlock {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        /// Lock holder (default: $JANUS_AGENT, then the login name)
+        #[arg(long)]
+        owner: Option<String>,
+
+        /// Release the lock even if someone else holds it
+        #[arg(long)]
+        force: bool,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -136,11 +390,19 @@ pub enum Commands {
     /// enforcement isn't needed, use `janus status <id> complete` instead.
     Close {
         /// Ticket ID (can be partial)
-        #[arg(value_parser = parse_partial_id)]
-        id: String,
+        #[arg(
+            value_parser = parse_partial_id,
+            required_unless_present = "ids",
+            conflicts_with = "ids"
+        )]
+        id: Option<String>,
+
+        /// Close several tickets at once (comma-separated IDs)
+        #[arg(long, value_delimiter = ',', value_parser = parse_partial_id)]
+        ids: Option<Vec<String>>,
 
         /// Completion summary text (required unless --no-summary is used)
-        #[arg(long, group = "summary_choice")]
+        #[arg(long, visible_alias = "note", group = "summary_choice")]
         summary: Option<String>,
 
         /// Explicitly close without a summary
@@ -151,6 +413,15 @@ pub enum Commands {
         #[arg(long)]
         cancel: bool,
 
+        /// Resolution to record (done, wontfix, duplicate); wontfix and
+        /// duplicate close the ticket as cancelled
+        #[arg(long, value_parser = parse_resolution)]
+        reason: Option<TicketResolution>,
+
+        /// Confirm protected status transitions without prompting
+        #[arg(short = 'y', long)]
+        yes: bool,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -161,6 +432,14 @@ pub enum Commands {
         #[arg(value_parser = parse_partial_id)]
         id: String,
 
+        /// Why the ticket is being reopened (added as a note)
+        #[arg(long)]
+        reason: Option<String>,
+
+        /// Confirm protected status transitions without prompting
+        #[arg(short = 'y', long)]
+        yes: bool,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -179,6 +458,10 @@ pub enum Commands {
         #[arg(value_parser = parse_status)]
         status: TicketStatus,
 
+        /// Confirm protected status transitions without prompting
+        #[arg(short = 'y', long)]
+        yes: bool,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -195,6 +478,27 @@ pub enum Commands {
         /// New value (omit to clear parent)
         value: Option<String>,
 
+        /// Confirm protected status transitions without prompting
+        #[arg(short = 'y', long)]
+        yes: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Set a ticket's size (xsmall, small, medium, large, xlarge)
+    ///
+    /// Sizes weigh 1, 2, 3, 5, and 8 points in plan progress and forecasts;
+    /// unsized tickets count as medium.
+    Size {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        /// New size (xs, s, m, l, and xl are accepted)
+        #[arg(value_parser = parse_size)]
+        size: TicketSize,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -211,6 +515,12 @@ pub enum Commands {
         action: LinkAction,
     },
 
+    /// Manage files attached to tickets
+    Attach {
+        #[command(subcommand)]
+        action: AttachAction,
+    },
+
     /// List tickets with optional filters
     #[command(visible_alias = "l")]
     Ls {
@@ -226,10 +536,32 @@ pub enum Commands {
         #[arg(long)]
         closed: bool,
 
+        /// Only tickets closed since a date (2024-06-01), a timestamp, or a
+        /// duration ago (7d, 12h, 2w); implies --closed
+        #[arg(
+            long,
+            visible_alias = "closed-since",
+            conflicts_with_all = ["active", "status"],
+            value_parser = parse_since_arg
+        )]
+        since: Option<jiff::Timestamp>,
+
+        /// Include archived tickets, which are hidden unless listed by --status
+        #[arg(long)]
+        include_archived: bool,
+
         /// Show only active tickets (exclude closed/cancelled)
         #[arg(long, conflicts_with_all = ["ready", "blocked", "closed", "status"])]
         active: bool,
 
+        /// Include snoozed tickets (hidden until their snooze date passes)
+        #[arg(long)]
+        snoozed: bool,
+
+        /// Show only tickets that have been reopened at least once
+        #[arg(long)]
+        reopened: bool,
+
         /// Filter by specific status (mutually exclusive with --ready, --blocked, --closed, --active)
         #[arg(long, conflicts_with_all = ["ready", "blocked", "closed", "active"], value_parser = parse_status)]
         status: Option<TicketStatus>,
@@ -266,6 +598,14 @@ pub enum Commands {
         #[arg(long, value_delimiter = ',')]
         labels: Option<Vec<String>>,
 
+        /// Filter by component (comma-separated, shows tickets matching ANY component)
+        #[arg(long, value_delimiter = ',')]
+        component: Option<Vec<String>>,
+
+        /// Filter by ID prefix (comma-separated, shows tickets matching ANY prefix)
+        #[arg(long, value_delimiter = ',')]
+        prefix: Option<Vec<String>>,
+
         /// Maximum tickets to show (unlimited if not specified)
         #[arg(long)]
         limit: Option<usize>,
@@ -274,6 +614,10 @@ pub enum Commands {
         #[arg(long, default_value = "priority", value_parser = parse_sort_field)]
         sort_by: SortField,
 
+        /// Keep running and re-render the list whenever tickets change
+        #[arg(long, conflicts_with = "json")]
+        watch: bool,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -285,13 +629,97 @@ pub enum Commands {
         /// Example: '.status == "new"' becomes select(.status == "new")
         #[arg(long)]
         filter: Option<String>,
+
+        /// Describe the tickets in plain English and let the configured LLM write
+        /// the filter, e.g. "open P1 bugs assigned to sam" (requires the `llm` feature)
+        #[arg(long, conflicts_with = "filter")]
+        nl: Option<String>,
+
+        /// Print one JSON line per group (status, assignee, type, priority)
+        /// with its count and tickets
+        #[arg(long, value_parser = parse_group_field)]
+        group_by: Option<GroupField>,
+
+        /// Print only counts: per group with --group-by, otherwise the total
+        #[arg(long)]
+        count: bool,
+
+        /// Include archived tickets, which are left out by default
+        #[arg(long)]
+        include_archived: bool,
+
+        /// Only tickets closed since a date (2024-06-01), a timestamp, or a
+        /// duration ago (7d, 12h, 2w)
+        #[arg(long, value_parser = parse_since_arg)]
+        closed_since: Option<jiff::Timestamp>,
+    },
+
+    /// Count tickets, optionally broken down by a field
+    Count {
+        /// Field to count by (status, assignee, type, priority)
+        #[arg(long, value_parser = parse_group_field)]
+        by: Option<GroupField>,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Show open tickets at risk of missing, or past, an SLA
+    Sla {
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Summarize how work is spread across the tracker
+    Stats {
+        #[command(subcommand)]
+        action: StatsAction,
+    },
+
+    /// List untriaged tickets, or suggest type, priority, size, and assignee for them
+    Triage {
+        /// Suggest triage fields with the configured LLM (requires the `llm` feature)
+        #[arg(long)]
+        suggest: bool,
+
+        /// Apply every suggestion without prompting
+        #[arg(long, requires = "suggest")]
+        auto: bool,
+
+        /// Maximum number of tickets to triage
+        #[arg(long)]
+        limit: Option<usize>,
+
+        #[command(flatten)]
+        output: OutputOptions,
     },
 
     /// Browse issues with fuzzy search
     View,
 
     /// View issues on a Kanban board
-    Board,
+    Board {
+        /// Only show this plan's tickets, with each card's phase
+        #[arg(long)]
+        plan: Option<String>,
+
+        /// Print the board as a document (md or html) instead of opening it
+        #[arg(long, value_name = "FORMAT", value_parser = parse_board_export_format)]
+        export: Option<BoardExportFormat>,
+    },
+
+    /// Browse issues, the board, a plan, and remote issues as tabs of one TUI
+    ///
+    /// Switch tabs with Alt+1..Alt+4 or Alt+Left/Alt+Right.
+    Tui {
+        /// Plan shown on the Plan tab (defaults to the plan in progress)
+        #[arg(long)]
+        plan: Option<String>,
+
+        /// Remote provider for the Remote tab (github or linear)
+        #[arg(long)]
+        provider: Option<String>,
+    },
 
     /// Move old completed tickets to archived status.
     ///
@@ -311,28 +739,117 @@ pub enum Commands {
         output: OutputOptions,
     },
 
+    /// Raise the priority of tickets that have been open too long.
+    ///
+    /// Rules come from the `escalation` section of config.yaml, e.g. bugs
+    /// older than 30 days go up one priority level, up to P1. Without
+    /// `--apply`, only reports the escalations that are due.
+    Escalate {
+        /// Write the escalations instead of only reporting them.
+        #[arg(long)]
+        apply: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Cancel open tickets whose `expires` date has passed.
+    ///
+    /// Each expired ticket is closed as cancelled with resolution `wontfix`,
+    /// and a note records the expiry date it passed.
+    Expire {
+        /// Print what would be cancelled without making changes.
+        #[arg(long)]
+        dry_run: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
     /// Manage remote issues (use --help for subcommands)
     Remote {
         #[command(subcommand)]
         action: RemoteAction,
     },
 
+    /// Manage git worktrees for tickets (one branch and worktree per ticket)
+    Worktree {
+        #[command(subcommand)]
+        action: WorktreeAction,
+    },
+
+    /// Print a conventional commit message for a ticket
+    ///
+    /// Rendered from the ticket's type, component, title, and description;
+    /// the template can be replaced with `messages.commit` in config.yaml.
+    /// Use as `git commit -F <(janus commit-msg <id>)`.
+    CommitMsg {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Print a pull request description for a ticket
+    ///
+    /// Rendered from the ticket's description, design notes, and acceptance
+    /// criteria (as a task list); the template can be replaced with
+    /// `messages.pr_body` in config.yaml.
+    PrBody {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Manage pull requests linked to tickets
+    Pr {
+        #[command(subcommand)]
+        action: PrAction,
+    },
+
     /// Manage configuration
     Config {
         #[command(subcommand)]
         action: ConfigAction,
     },
 
+    /// Acceptance criteria helpers (requires the `llm` feature)
+    #[cfg(feature = "llm")]
+    Ac {
+        #[command(subcommand)]
+        action: AcAction,
+    },
+
     /// Cache management
     Cache {
         #[command(subcommand)]
         action: CacheAction,
     },
 
-    /// Event log management
+    /// Show recent events from the event log, or manage it
+    ///
+    /// Prints the last events (ticket writes, status changes, sync results)
+    /// and exits; with --follow, keeps printing new events as they happen.
+    #[command(args_conflicts_with_subcommands = true)]
     Events {
         #[command(subcommand)]
-        action: EventsAction,
+        action: Option<EventsAction>,
+
+        /// Keep running and print new events as they are logged
+        #[arg(short, long)]
+        follow: bool,
+
+        /// Number of past events to print first
+        #[arg(short = 'n', long, default_value_t = 10)]
+        lines: usize,
+
+        #[command(flatten)]
+        output: OutputOptions,
     },
 
     /// Manage hooks
@@ -347,6 +864,82 @@ pub enum Commands {
         output: OutputOptions,
     },
 
+    /// Normalize the formatting of ticket and plan files
+    ///
+    /// Rewrites frontmatter key order, timestamps, arrays, and heading levels
+    /// the way Janus writes them, so diffs stay clean when several tools edit
+    /// the files.
+    Fmt {
+        /// Report unformatted files and fail instead of rewriting them
+        #[arg(long)]
+        check: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Remove references to deleted tickets
+    ///
+    /// Cleans up deps, links, and parent fields that point at tickets which no
+    /// longer exist, and plan entries for such tickets. The references are
+    /// listed before anything is changed.
+    Prune {
+        /// Print what would be removed without making changes
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip the confirmation prompt
+        #[arg(short = 'y', long)]
+        yes: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Summarize tracker health for CI
+    ///
+    /// Reports ready and blocked ticket counts, P0 tickets that have not
+    /// changed recently, and lint findings such as unparseable files and
+    /// references to deleted tickets.
+    Report {
+        /// Render the report as markdown for $GITHUB_STEP_SUMMARY
+        #[arg(long)]
+        github_summary: bool,
+
+        /// Days without changes before an open P0 ticket counts as stale
+        #[arg(long, default_value_t = 7)]
+        stale_days: u32,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Snapshot the Janus directory to a compressed archive
+    ///
+    /// Everything except the embeddings cache is saved, along with a manifest
+    /// of file checksums that is verified after writing and before restoring.
+    Backup {
+        /// Archive to write (default: janus-backup-<timestamp>.tar.zst)
+        #[arg(short = 'o', long = "output", value_name = "FILE")]
+        file: Option<PathBuf>,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Replace the Janus directory with the contents of a backup
+    Restore {
+        /// Archive created by `janus backup`
+        file: PathBuf,
+
+        /// Skip the confirmation prompt
+        #[arg(short = 'y', long)]
+        yes: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
     /// Plan management
     Plan {
         #[command(subcommand)]
@@ -389,17 +982,73 @@ pub enum Commands {
 
         /// Graph tickets in a specific plan
         #[arg(long)]
-        plan: Option<String>,
+        plan: Option<String>,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Show next ticket(s) to work on (dependency-aware)
+    #[command(visible_alias = "n")]
+    Next {
+        /// Maximum number of tickets to show (default: 5)
+        #[arg(short, long, default_value = "5")]
+        limit: usize,
+
+        /// Recommend work for this assignee, respecting their WIP limit and
+        /// size budget from the `capacity` config
+        #[arg(long = "for", value_name = "ASSIGNEE")]
+        assignee: Option<String>,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// List ready tickets ordered by the `ready` scoring policy
+    ///
+    /// Each ready ticket is scored from its priority, age, plan phase urgency,
+    /// and size, weighted by the `ready` config.
+    Ready {
+        /// Maximum number of tickets to show
+        #[arg(short, long)]
+        limit: Option<usize>,
+
+        /// Only show tickets of these sizes (e.g. --size xsmall,small)
+        #[arg(long, value_delimiter = ',', value_parser = parse_size)]
+        size: Option<Vec<TicketSize>>,
+
+        /// Show the score breakdown for each ticket
+        #[arg(long)]
+        explain: bool,
+
+        /// Keep running and re-render the queue whenever tickets change
+        #[arg(long, conflicts_with = "json")]
+        watch: bool,
 
         #[command(flatten)]
         output: OutputOptions,
     },
 
-    /// Show next ticket(s) to work on (dependency-aware)
-    #[command(visible_alias = "n")]
-    Next {
-        /// Maximum number of tickets to show (default: 5)
-        #[arg(short, long, default_value = "5")]
+    /// List blocked tickets and what is blocking them
+    ///
+    /// Shows each blocked ticket's unsatisfied dependencies and, when the
+    /// chain runs deeper, the root blockers at the bottom of it.
+    Blocked {
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Suggest tickets to pick up next, ranked for the current user
+    ///
+    /// Ready tickets that are unassigned or assigned to you are ranked by
+    /// priority, age, plan urgency, and similarity to work you have completed.
+    Suggest {
+        /// Suggest work for this assignee instead of git `user.name`
+        #[arg(long = "for", value_name = "ASSIGNEE")]
+        assignee: Option<String>,
+
+        /// Number of suggestions to show
+        #[arg(short, long, default_value_t = crate::suggest::DEFAULT_SUGGESTIONS)]
         limit: usize,
 
         #[command(flatten)]
@@ -407,11 +1056,26 @@ pub enum Commands {
     },
 
     /// Generate shell completions
+    ///
+    /// Bash, zsh, and fish scripts complete ticket IDs, plan IDs, and statuses
+    /// dynamically. Load them with e.g. `source <(janus completions bash)`.
     Completions {
         /// Shell to generate completions for [possible values: bash, zsh, fish, powershell, elvish]
         shell: Shell,
     },
 
+    /// Print completion candidates (used by generated completion scripts)
+    #[command(name = "_complete", hide = true)]
+    Complete {
+        /// What to complete: tickets, plans, or statuses
+        #[arg(value_parser = parse_completion_kind)]
+        kind: CompletionKind,
+
+        /// Text typed so far
+        #[arg(default_value = "", allow_hyphen_values = true)]
+        prefix: String,
+    },
+
     /// Start MCP (Model Context Protocol) server for AI agent integration
     Mcp {
         /// Show MCP protocol version instead of starting server
@@ -435,6 +1099,13 @@ pub enum Commands {
         #[command(flatten)]
         output: OutputOptions,
     },
+
+    /// Print JSON Schemas for the --json output of every command and the MCP
+    /// tool inputs
+    Schema {
+        #[command(subcommand)]
+        action: SchemaAction,
+    },
 }
 
 #[derive(Subcommand)]
@@ -444,13 +1115,25 @@ pub enum DepAction {
         /// Ticket ID
         #[arg(value_parser = parse_partial_id)]
         id: String,
-        /// Dependency ID (ticket that must be completed first)
-        #[arg(value_parser = parse_partial_id)]
+        /// Dependency ID (ticket that must be completed first), or a URL for
+        /// an external dependency that blocks until resolved
+        #[arg(value_parser = parse_dep_target)]
         dep_id: String,
 
         #[command(flatten)]
         output: OutputOptions,
     },
+    /// Resolve an external (URL) dependency, so it no longer blocks the ticket
+    Resolve {
+        /// Ticket ID
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+        /// URL of the external dependency
+        url: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
     /// Remove a dependency
     Remove {
         /// Ticket ID
@@ -502,6 +1185,81 @@ pub enum LinkAction {
     },
 }
 
+#[derive(Subcommand)]
+pub enum AttachAction {
+    /// Attach files to a ticket
+    Add {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+        /// Files to attach
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+        /// Name to attach the file under (default: its file name)
+        #[arg(long)]
+        name: Option<String>,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// List a ticket's attachments
+    Ls {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Remove an attachment from a ticket
+    Rm {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+        /// Attachment name
+        name: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Rename an attachment
+    Rename {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+        /// Current attachment name
+        name: String,
+        /// New attachment name
+        new_name: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Write an attachment's content to a file
+    Get {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+        /// Attachment name
+        name: String,
+        /// File to write (default: the attachment name in the current directory)
+        #[arg(short = 'o', long = "output", value_name = "FILE")]
+        file: Option<PathBuf>,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Delete stored attachment content that no ticket references
+    Gc {
+        /// Show what would be deleted without deleting it
+        #[arg(long)]
+        dry_run: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum ConfigAction {
     /// Show current configuration
@@ -524,6 +1282,44 @@ pub enum ConfigAction {
         /// Configuration key (github.token, linear.api_key, default.remote)
         key: String,
 
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Encrypt stored authentication tokens with age or gpg
+    ///
+    /// Migrates any plaintext tokens in config.yaml to ciphertext. Tokens set
+    /// afterwards with `config set` are encrypted automatically.
+    Encrypt {
+        /// Encryption tool: age or gpg
+        #[arg(long, default_value = "age", value_parser = parse_encryption_tool)]
+        tool: EncryptionTool,
+
+        /// age public key or gpg key ID/email (can be repeated)
+        #[arg(long = "recipient", required = true, action = clap::ArgAction::Append)]
+        recipients: Vec<String>,
+
+        /// age identity file used for decryption
+        #[arg(long)]
+        identity: Option<PathBuf>,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+}
+
+#[cfg(feature = "llm")]
+#[derive(Subcommand)]
+pub enum AcAction {
+    /// Draft acceptance criteria from the ticket description with the configured LLM
+    Suggest {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        /// Add the suggestions without asking for confirmation
+        #[arg(short = 'y', long)]
+        yes: bool,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -546,6 +1342,32 @@ pub enum CacheAction {
         #[command(flatten)]
         output: OutputOptions,
     },
+    /// Embed new and changed tickets, objectives, and documents, skipping unchanged ones
+    Sync {
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum StatsAction {
+    /// Show open and in-progress tickets and total size per assignee,
+    /// flagging anyone who is overloaded
+    Workload {
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SchemaAction {
+    /// Print every schema as one JSON document, or write one file per schema
+    Dump {
+        /// Write `commands/<command>.json` and `mcp/<tool>.json` files under
+        /// this directory instead of printing
+        #[arg(long, value_name = "DIR")]
+        dir: Option<PathBuf>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -632,6 +1454,14 @@ pub enum RemoteAction {
         #[arg(value_parser = parse_partial_id)]
         id: String,
 
+        /// Linear project to create the issue in (by name)
+        #[arg(long)]
+        project: Option<String>,
+
+        /// Linear cycle to schedule the issue in (by name or number)
+        #[arg(long)]
+        cycle: Option<String>,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -651,10 +1481,130 @@ pub enum RemoteAction {
 
     /// Sync a local ticket with its remote issue
     Sync {
+        /// Local ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id, required_unless_present = "all")]
+        id: Option<String>,
+
+        /// Pull every ticket linked to a remote in mirror mode
+        #[arg(long, conflicts_with = "id")]
+        all: bool,
+
+        /// With --all, keep pulling at this interval (e.g. 15m, 1h)
+        #[arg(long, value_name = "INTERVAL", requires = "all")]
+        every: Option<String>,
+
+        /// Show a field-by-field diff of local and remote without writing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum NoteAction {
+    /// List a ticket's notes, numbered from 1
+    Ls {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Replace the text of a note, keeping its timestamp
+    Edit {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        /// Note number, as shown by `janus note ls`
+        index: usize,
+
+        /// New note text (or pipe from stdin; opens $EDITOR otherwise)
+        #[arg(trailing_var_arg = true)]
+        text: Vec<String>,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Remove a note
+    Rm {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        /// Note number, as shown by `janus note ls`
+        index: usize,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum WorktreeAction {
+    /// Create a branch and git worktree for a ticket and record its path
+    ///
+    /// The branch is named after the ticket ID and title, and the worktree is
+    /// created next to the repository unless --path is given.
+    Start {
+        /// Ticket ID (can be partial)
+        #[arg(value_parser = parse_partial_id)]
+        id: String,
+
+        /// Where to create the worktree (default: ../<repo>-<id>)
+        #[arg(long)]
+        path: Option<String>,
+
+        /// Branch to create (default: <id>-<title>)
+        #[arg(long)]
+        branch: Option<String>,
+
+        /// Commit or branch to start from (default: HEAD)
+        #[arg(long)]
+        base: Option<String>,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// List tickets with worktrees
+    Ls {
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+
+    /// Remove the worktrees of closed tickets and forget missing ones
+    ///
+    /// Branches are kept.
+    Cleanup {
+        /// Print what would be removed without making changes
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Remove worktrees even if they have uncommitted changes
+        #[arg(long)]
+        force: bool,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum PrAction {
+    /// Link a GitHub pull request to a ticket
+    Link {
         /// Local ticket ID (can be partial)
         #[arg(value_parser = parse_partial_id)]
         id: String,
 
+        /// Pull request URL, owner/repo#number, or number in the ticket's repository.
+        /// If omitted, links pull requests whose title or body mentions the ticket ID
+        pr: Option<String>,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -820,6 +1770,37 @@ pub enum PlanAction {
         #[command(flatten)]
         output: OutputOptions,
     },
+    /// Rename a phase (keeps its number and tickets)
+    RenamePhase {
+        /// Plan ID (can be partial)
+        #[arg(value_parser = parse_plan_id)]
+        plan_id: String,
+
+        /// Phase name or number
+        phase: String,
+
+        /// New phase name
+        new_name: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Set the description of a phase
+    AnnotatePhase {
+        /// Plan ID (can be partial)
+        #[arg(value_parser = parse_plan_id)]
+        plan_id: String,
+
+        /// Phase name or number
+        phase: String,
+
+        /// New description (an empty string clears it)
+        #[arg(long)]
+        description: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
     /// Reorder tickets or phases
     Reorder {
         /// Plan ID (can be partial)
@@ -834,6 +1815,11 @@ pub enum PlanAction {
         #[arg(long = "reorder-phases")]
         reorder_phases: bool,
 
+        /// Read the new order from a file (`-` for stdin) instead of the editor:
+        /// one ticket ID, or phase number or name, per line
+        #[arg(long = "from-file", value_name = "PATH")]
+        from_file: Option<String>,
+
         #[command(flatten)]
         output: OutputOptions,
     },
@@ -892,6 +1878,49 @@ pub enum PlanAction {
         #[command(flatten)]
         output: OutputOptions,
     },
+    /// Show phase and ticket changes since a git revision
+    Diff {
+        /// Plan ID (can be partial)
+        #[arg(value_parser = parse_plan_id)]
+        id: String,
+
+        /// Git revision to compare against
+        #[arg(long = "ref", default_value = "HEAD")]
+        rev: String,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Estimate when a plan will be complete from recent throughput
+    Forecast {
+        /// Plan ID (can be partial)
+        #[arg(value_parser = parse_plan_id)]
+        id: String,
+
+        /// Weeks of completed tickets to measure throughput over
+        #[arg(long, default_value_t = crate::plan::forecast::DEFAULT_HISTORY_WEEKS, value_parser = clap::value_parser!(u32).range(1..))]
+        weeks: u32,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Push the plan's tickets to the default remote
+    Push {
+        /// Plan ID (can be partial)
+        #[arg(value_parser = parse_plan_id)]
+        id: String,
+
+        /// Linear project to place the issues in (by name)
+        #[arg(long)]
+        project: Option<String>,
+
+        /// Linear cycle number for phase 1; later phases go to the following cycles
+        #[arg(long)]
+        first_cycle: Option<u32>,
+
+        #[command(flatten)]
+        output: OutputOptions,
+    },
     /// Import a plan from a markdown file
     Import {
         /// File path (use "-" for stdin)
@@ -960,10 +1989,27 @@ pub enum DocAction {
         label: String,
         /// Document title
         #[arg(short, long)]
-        title: Option<String>,
-        /// Document description
+        title: Option<String>,
+        /// Document description
+        #[arg(short, long)]
+        description: Option<String>,
+        /// Tags for the document (can be repeated)
+        #[arg(long)]
+        tag: Vec<String>,
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Fetch a web page and add it as a document
+    Add {
+        /// URL of the page to fetch (HTML, markdown, or plain text)
+        #[arg(long)]
+        url: String,
+        /// Document label (defaults to the last segment of the URL path)
+        #[arg(short, long)]
+        label: Option<String>,
+        /// Document title (defaults to the page's heading or title)
         #[arg(short, long)]
-        description: Option<String>,
+        title: Option<String>,
         /// Tags for the document (can be repeated)
         #[arg(long)]
         tag: Vec<String>,
@@ -977,7 +2023,27 @@ pub enum DocAction {
         #[command(flatten)]
         output: OutputOptions,
     },
-    /// Search documents using semantic similarity
+    /// Reference a document from tickets
+    Link {
+        /// Document label (can be partial)
+        label: String,
+        /// Ticket IDs (can be partial)
+        #[arg(required = true)]
+        tickets: Vec<String>,
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Remove a document reference from tickets
+    Unlink {
+        /// Document label (can be partial)
+        label: String,
+        /// Ticket IDs (can be partial)
+        #[arg(required = true)]
+        tickets: Vec<String>,
+        #[command(flatten)]
+        output: OutputOptions,
+    },
+    /// Search document chunks by keyword and semantic similarity
     Search {
         /// Natural language search query
         query: String,
@@ -993,6 +2059,8 @@ pub enum DocAction {
         #[command(flatten)]
         output: OutputOptions,
     },
+    /// Browse documents interactively
+    View,
 }
 
 #[derive(Subcommand)]
@@ -1107,26 +2175,138 @@ pub enum ObjectiveRefAction {
 }
 
 impl Commands {
+    /// Whether the command can modify Janus data, which read-only mode refuses.
+    ///
+    /// Anything not explicitly recognized as a query counts as mutating. The
+    /// TUIs and the MCP server are allowed; writes made from them are stopped
+    /// by the checks in [`crate::fs`] and the MCP tool router instead.
+    pub fn is_mutating(&self) -> bool {
+        match self {
+            Commands::Show { .. }
+            | Commands::Context { .. }
+            | Commands::Blame { .. }
+            | Commands::Lineage { .. }
+            | Commands::Diff { .. }
+            | Commands::Path { .. }
+            | Commands::Ls { .. }
+            | Commands::Query { .. }
+            | Commands::Count { .. }
+            | Commands::Sla { .. }
+            | Commands::Stats { .. }
+            | Commands::View
+            | Commands::Board { .. }
+            | Commands::Tui { .. }
+            | Commands::Doctor { .. }
+            | Commands::Report { .. }
+            | Commands::Backup { .. }
+            | Commands::Graph { .. }
+            | Commands::Next { .. }
+            | Commands::Ready { .. }
+            | Commands::Blocked { .. }
+            | Commands::Suggest { .. }
+            | Commands::Completions { .. }
+            | Commands::Complete { .. }
+            | Commands::Mcp { .. }
+            | Commands::Search { .. }
+            | Commands::Schema { .. }
+            | Commands::CommitMsg { .. }
+            | Commands::PrBody { .. } => false,
+            #[cfg(feature = "llm")]
+            Commands::Summarize { append, .. } => *append,
+            Commands::Open { remote, .. } => !remote,
+            Commands::Triage { suggest, .. } => *suggest,
+            Commands::Escalate { apply, .. } => *apply,
+            Commands::Archive { dry_run, .. }
+            | Commands::Expire { dry_run, .. }
+            | Commands::Prune { dry_run, .. } => !dry_run,
+            Commands::Fmt { check, .. } => !check,
+            Commands::Events { action, .. } => action.is_some(),
+            Commands::Dep { action } => !matches!(action, DepAction::Tree { .. }),
+            Commands::Config { action } => {
+                !matches!(action, ConfigAction::Show { .. } | ConfigAction::Get { .. })
+            }
+            Commands::Cache { action } => !matches!(action, CacheAction::Status { .. }),
+            Commands::Hook { action } => {
+                !matches!(action, HookAction::List { .. } | HookAction::Log { .. })
+            }
+            Commands::Remote { action } => !matches!(action, RemoteAction::Browse { .. }),
+            Commands::Note { action } => !matches!(action, NoteAction::Ls { .. }),
+            Commands::Worktree { action } => match action {
+                WorktreeAction::Ls { .. } => false,
+                WorktreeAction::Cleanup { dry_run, .. } => !dry_run,
+                WorktreeAction::Start { .. } => true,
+            },
+            Commands::Attach { action } => match action {
+                AttachAction::Ls { .. } | AttachAction::Get { .. } => false,
+                AttachAction::Gc { dry_run, .. } => !dry_run,
+                _ => true,
+            },
+            Commands::Plan { action } => match action {
+                PlanAction::Show { .. }
+                | PlanAction::Ls { .. }
+                | PlanAction::Next { .. }
+                | PlanAction::Status { .. }
+                | PlanAction::Diff { .. }
+                | PlanAction::Forecast { .. }
+                | PlanAction::ImportSpec
+                | PlanAction::Verify { .. }
+                | PlanAction::Hud { .. } => false,
+                PlanAction::Import { dry_run, .. } => !dry_run,
+                _ => true,
+            },
+            Commands::Doc { action } => !matches!(
+                action,
+                DocAction::Ls { .. }
+                    | DocAction::Show { .. }
+                    | DocAction::Search { .. }
+                    | DocAction::View
+            ),
+            Commands::Objective { action } => !matches!(
+                action,
+                ObjectiveAction::Show { .. } | ObjectiveAction::Ls { .. }
+            ),
+            _ => true,
+        }
+    }
+
     /// Execute the command, dispatching to the appropriate handler.
     pub async fn run(self) -> crate::error::Result<()> {
+        if self.is_mutating() {
+            crate::read_only::ensure_writable(|| {
+                "run a command that modifies Janus data".to_string()
+            })?;
+        }
+
         use crate::commands::{
-            CreateOptions, LsOptions, cmd_add_note, cmd_adopt, cmd_archive, cmd_board,
-            cmd_cache_prune, cmd_cache_rebuild, cmd_cache_status, cmd_close, cmd_config_get,
-            cmd_config_set, cmd_config_show, cmd_create, cmd_dep_add, cmd_dep_remove, cmd_dep_tree,
-            cmd_doc_create, cmd_doc_edit, cmd_doc_ls, cmd_doc_search, cmd_doc_show, cmd_doctor,
-            cmd_edit, cmd_events_prune, cmd_graph, cmd_hook_disable, cmd_hook_enable,
-            cmd_hook_install, cmd_hook_list, cmd_hook_log, cmd_hook_run, cmd_link_add,
-            cmd_link_remove, cmd_ls_with_options, cmd_next, cmd_objective_add_criterion,
-            cmd_objective_add_note, cmd_objective_create, cmd_objective_delete, cmd_objective_edit,
-            cmd_objective_ls, cmd_objective_ref_add, cmd_objective_ref_del,
-            cmd_objective_ref_reset, cmd_objective_show, cmd_plan_add_phase,
-            cmd_plan_add_ticket, cmd_plan_create, cmd_plan_delete, cmd_plan_edit, cmd_plan_hud,
-            cmd_plan_import, cmd_plan_ls, cmd_plan_move_ticket, cmd_plan_next,
-            cmd_plan_remove_phase, cmd_plan_remove_ticket, cmd_plan_rename, cmd_plan_reorder,
-            cmd_plan_show, cmd_plan_status, cmd_plan_verify, cmd_push, cmd_query,
-            cmd_remote_browse, cmd_remote_link, cmd_reopen, cmd_search, cmd_set, cmd_show,
-            cmd_show_import_spec, cmd_start, cmd_status, cmd_sync, cmd_view,
+            CreateOptions, LsOptions, cmd_add_note, cmd_adopt, cmd_archive, cmd_attach_add,
+            cmd_attach_gc, cmd_attach_get, cmd_attach_ls, cmd_attach_rename, cmd_attach_rm,
+            cmd_backup, cmd_blame, cmd_blocked, cmd_board, cmd_board_export, cmd_cache_prune,
+            cmd_cache_rebuild, cmd_cache_status, cmd_cache_sync, cmd_close, cmd_commit_msg,
+            cmd_complete, cmd_config_encrypt, cmd_config_get, cmd_config_set, cmd_config_show,
+            cmd_context, cmd_count, cmd_create, cmd_create_batch, cmd_create_interactive,
+            cmd_dep_add, cmd_dep_remove, cmd_dep_resolve, cmd_dep_tree, cmd_diff, cmd_doc_add,
+            cmd_doc_create, cmd_doc_edit, cmd_doc_link, cmd_doc_ls, cmd_doc_search, cmd_doc_show,
+            cmd_doc_unlink, cmd_doc_view, cmd_doctor, cmd_edit, cmd_escalate, cmd_events_prune,
+            cmd_events_tail, cmd_expire, cmd_fmt, cmd_graph, cmd_hook_disable, cmd_hook_enable,
+            cmd_hook_install, cmd_hook_list, cmd_hook_log, cmd_hook_run, cmd_lineage, cmd_link_add,
+            cmd_link_remove, cmd_lock, cmd_ls_with_options, cmd_next, cmd_note_edit, cmd_note_ls,
+            cmd_note_rm, cmd_objective_add_criterion, cmd_objective_add_note, cmd_objective_create,
+            cmd_objective_delete, cmd_objective_edit, cmd_objective_ls, cmd_objective_ref_add,
+            cmd_objective_ref_del, cmd_objective_ref_reset, cmd_objective_show, cmd_open, cmd_path,
+            cmd_plan_add_phase, cmd_plan_add_ticket, cmd_plan_annotate_phase, cmd_plan_create,
+            cmd_plan_delete, cmd_plan_diff, cmd_plan_edit, cmd_plan_forecast, cmd_plan_hud,
+            cmd_plan_import, cmd_plan_ls, cmd_plan_move_ticket, cmd_plan_next, cmd_plan_push,
+            cmd_plan_remove_phase, cmd_plan_remove_ticket, cmd_plan_rename, cmd_plan_rename_phase,
+            cmd_plan_reorder, cmd_plan_show, cmd_plan_status, cmd_plan_verify, cmd_pr_body,
+            cmd_pr_link, cmd_prune, cmd_push, cmd_query, cmd_ready, cmd_remote_browse,
+            cmd_remote_link, cmd_reopen, cmd_report, cmd_restore, cmd_schema_dump, cmd_search,
+            cmd_set, cmd_show, cmd_show_import_spec, cmd_size, cmd_sla, cmd_snooze, cmd_start,
+            cmd_stats_workload, cmd_status, cmd_suggest, cmd_sync, cmd_sync_all, cmd_triage,
+            cmd_tui, cmd_unlock, cmd_view, cmd_worktree_cleanup, cmd_worktree_ls,
+            cmd_worktree_start, watch_output,
         };
+        #[cfg(feature = "llm")]
+        use crate::commands::{cmd_ac_suggest, cmd_summarize};
         use crate::error::JanusError;
 
         /// Handles validation results, returning Ok if valid, or an error if invalid.
@@ -1147,8 +2327,22 @@ impl Commands {
         }
 
         match self {
+            Commands::Create {
+                interactive: true,
+                output,
+                ..
+            } => cmd_create_interactive(output).await,
+            Commands::Create {
+                batch: Some(batch),
+                output,
+                ..
+            } => cmd_create_batch(&batch, output).await,
+            Commands::New => cmd_create_interactive(OutputOptions { json: false }).await,
             Commands::Create {
                 title,
+                interactive: _,
+                from_stdin,
+                batch: _,
                 description,
                 design,
                 acceptance,
@@ -1161,10 +2355,12 @@ impl Commands {
                 spawn_context,
                 size,
                 labels,
+                component,
                 output,
             } => {
                 cmd_create(CreateOptions {
-                    title,
+                    title: title.unwrap_or_default(),
+                    from_stdin,
                     description,
                     design,
                     acceptance,
@@ -1177,44 +2373,142 @@ impl Commands {
                     spawn_context,
                     size,
                     labels,
+                    component,
                     output,
                 })
                 .await
             }
 
-            Commands::Show { id, output } => cmd_show(&id, output).await,
-            Commands::Edit { id, output } => cmd_edit(&id, output).await,
-            Commands::AddNote { id, text, output } => {
+            Commands::Show {
+                id,
+                section,
+                render,
+                activity,
+                output,
+            } => cmd_show(&id, section, render, activity, output).await,
+            Commands::Context {
+                id,
+                depth,
+                notes,
+                output,
+            } => cmd_context(&id, depth, notes, output).await,
+            Commands::Edit {
+                id,
+                section,
+                with_helpers,
+                output,
+            } => cmd_edit(&id, section, with_helpers, output).await,
+            #[cfg(feature = "llm")]
+            Commands::Summarize { id, append, output } => cmd_summarize(&id, append, output).await,
+            Commands::Diff { from, to, output } => cmd_diff(&from, &to, output).await,
+            Commands::Blame { id, field, output } => cmd_blame(&id, field.as_deref(), output).await,
+            Commands::Lineage { id, output } => cmd_lineage(&id, output).await,
+            Commands::Path { id, output } => cmd_path(&id, output).await,
+            Commands::Open { id, remote, output } => cmd_open(&id, remote, output).await,
+            Commands::AddNote {
+                id,
+                text,
+                template,
+                output,
+            } => {
                 let note_text = if text.is_empty() {
                     None
                 } else {
                     Some(text.join(" "))
                 };
-                cmd_add_note(&id, note_text.as_deref(), output).await
+                cmd_add_note(&id, note_text.as_deref(), template.as_deref(), output).await
             }
 
-            Commands::Start { id, output } => cmd_start(&id, output).await,
+            Commands::Note { action } => match action {
+                NoteAction::Ls { id, output } => cmd_note_ls(&id, output).await,
+                NoteAction::Edit {
+                    id,
+                    index,
+                    text,
+                    output,
+                } => {
+                    let note_text = if text.is_empty() {
+                        None
+                    } else {
+                        Some(text.join(" "))
+                    };
+                    cmd_note_edit(&id, index, note_text.as_deref(), output).await
+                }
+                NoteAction::Rm { id, index, output } => cmd_note_rm(&id, index, output).await,
+            },
+
+            Commands::Start { id, yes, output } => cmd_start(&id, yes, output).await,
+            // `--clear` is the absence of `--until`; clap keeps the two exclusive
+            Commands::Snooze {
+                id, until, output, ..
+            } => cmd_snooze(&id, until, output).await,
+            Commands::Lock {
+                id,
+                owner,
+                ttl,
+                force,
+                output,
+            } => cmd_lock(&id, owner.as_deref(), ttl, force, output).await,
+            Commands::Unlock {
+                id,
+                owner,
+                force,
+                output,
+            } => cmd_unlock(&id, owner.as_deref(), force, output).await,
             Commands::Close {
                 id,
+                ids,
                 summary,
                 no_summary,
                 cancel,
+                reason,
+                yes,
+                output,
+            } => {
+                let ids = ids
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(id)
+                    .collect::<Vec<_>>();
+                cmd_close(
+                    &ids,
+                    summary.as_deref(),
+                    no_summary,
+                    cancel,
+                    reason,
+                    yes,
+                    output,
+                )
+                .await
+            }
+            Commands::Reopen {
+                id,
+                reason,
+                yes,
+                output,
+            } => cmd_reopen(&id, reason.as_deref(), yes, output).await,
+            Commands::Status {
+                id,
+                status,
+                yes,
                 output,
-            } => cmd_close(&id, summary.as_deref(), no_summary, cancel, output).await,
-            Commands::Reopen { id, output } => cmd_reopen(&id, output).await,
-            Commands::Status { id, status, output } => cmd_status(&id, status, output).await,
+            } => cmd_status(&id, status, yes, output).await,
             Commands::Set {
                 id,
                 field,
                 value,
+                yes,
                 output,
-            } => cmd_set(&id, &field, value.as_deref(), output).await,
+            } => cmd_set(&id, &field, value.as_deref(), yes, output).await,
+
+            Commands::Size { id, size, output } => cmd_size(&id, size, output).await,
 
             Commands::Dep { action } => match action {
                 DepAction::Add { id, dep_id, output } => cmd_dep_add(&id, &dep_id, output).await,
                 DepAction::Remove { id, dep_id, output } => {
                     cmd_dep_remove(&id, &dep_id, output).await
                 }
+                DepAction::Resolve { id, url, output } => cmd_dep_resolve(&id, &url, output).await,
                 DepAction::Tree { id, full, output } => cmd_dep_tree(&id, full, output).await,
             },
 
@@ -1225,11 +2519,39 @@ impl Commands {
                 }
             },
 
+            Commands::Attach { action } => match action {
+                AttachAction::Add {
+                    id,
+                    files,
+                    name,
+                    output,
+                } => cmd_attach_add(&id, &files, name.as_deref(), output).await,
+                AttachAction::Ls { id, output } => cmd_attach_ls(&id, output).await,
+                AttachAction::Rm { id, name, output } => cmd_attach_rm(&id, &name, output).await,
+                AttachAction::Rename {
+                    id,
+                    name,
+                    new_name,
+                    output,
+                } => cmd_attach_rename(&id, &name, &new_name, output).await,
+                AttachAction::Get {
+                    id,
+                    name,
+                    file,
+                    output,
+                } => cmd_attach_get(&id, &name, file, output).await,
+                AttachAction::Gc { dry_run, output } => cmd_attach_gc(dry_run, output),
+            },
+
             Commands::Ls {
                 ready,
                 blocked,
                 closed,
+                since,
+                include_archived,
                 active,
+                snoozed,
+                reopened,
                 status,
                 spawned_from,
                 depth,
@@ -1239,15 +2561,21 @@ impl Commands {
                 triaged,
                 size,
                 labels,
+                component,
+                prefix,
                 limit,
                 sort_by,
+                watch,
                 output,
             } => {
                 let opts = LsOptions {
                     filter_ready: ready,
                     filter_blocked: blocked,
-                    filter_closed: closed,
+                    filter_closed: closed || since.is_some(),
                     filter_active: active,
+                    closed_since: since,
+                    include_archived,
+                    include_snoozed: snoozed,
                     status_filter: status,
                     spawned_from,
                     depth,
@@ -1257,22 +2585,64 @@ impl Commands {
                     triaged,
                     size_filter: size,
                     label_filter: labels,
+                    component_filter: component,
+                    prefix_filter: prefix,
+                    reopened,
                     limit,
                     sort_by,
                     output,
                 };
-                cmd_ls_with_options(opts).await
+                if watch {
+                    watch_output("janus ls", || cmd_ls_with_options(opts.clone())).await
+                } else {
+                    cmd_ls_with_options(opts).await
+                }
             }
 
-            Commands::Query { filter } => cmd_query(filter.as_deref()).await,
+            Commands::Query {
+                filter,
+                nl,
+                group_by,
+                count,
+                include_archived,
+                closed_since,
+            } => {
+                cmd_query(
+                    filter.as_deref(),
+                    nl.as_deref(),
+                    group_by,
+                    count,
+                    include_archived,
+                    closed_since,
+                )
+                .await
+            }
+            Commands::Count { by, output } => cmd_count(by, output).await,
+            Commands::Sla { output } => cmd_sla(output).await,
+            Commands::Stats { action } => match action {
+                StatsAction::Workload { output } => cmd_stats_workload(output).await,
+            },
+            Commands::Triage {
+                suggest,
+                auto,
+                limit,
+                output,
+            } => cmd_triage(suggest, auto, limit, output).await,
 
             Commands::View => cmd_view().await,
-            Commands::Board => cmd_board().await,
+            Commands::Board {
+                plan,
+                export: Some(format),
+            } => cmd_board_export(plan.as_deref(), format).await,
+            Commands::Board { plan, .. } => cmd_board(plan.as_deref()).await,
+            Commands::Tui { plan, provider } => cmd_tui(plan.as_deref(), provider.as_deref()).await,
             Commands::Archive {
                 days,
                 dry_run,
                 output,
             } => cmd_archive(days, dry_run, output).await,
+            Commands::Escalate { apply, output } => cmd_escalate(apply, output).await,
+            Commands::Expire { dry_run, output } => cmd_expire(dry_run, output).await,
 
             Commands::Remote { action } => match action {
                 RemoteAction::Browse { provider } => cmd_remote_browse(provider.as_deref()).await,
@@ -1281,29 +2651,92 @@ impl Commands {
                     prefix,
                     output,
                 } => cmd_adopt(&remote_ref, prefix.as_deref(), output).await,
-                RemoteAction::Push { id, output } => cmd_push(&id, output).await,
+                RemoteAction::Push {
+                    id,
+                    project,
+                    cycle,
+                    output,
+                } => cmd_push(&id, project.as_deref(), cycle.as_deref(), output).await,
                 RemoteAction::Link {
                     id,
                     remote_ref,
                     output,
                 } => cmd_remote_link(&id, &remote_ref, output).await,
-                RemoteAction::Sync { id, output } => cmd_sync(&id, output).await,
+                RemoteAction::Sync {
+                    id,
+                    all,
+                    every,
+                    dry_run,
+                    output,
+                } => match id {
+                    Some(id) if !all => cmd_sync(&id, dry_run, output).await,
+                    _ => cmd_sync_all(dry_run, every.as_deref(), output).await,
+                },
+            },
+
+            Commands::Worktree { action } => match action {
+                WorktreeAction::Start {
+                    id,
+                    path,
+                    branch,
+                    base,
+                    output,
+                } => {
+                    cmd_worktree_start(
+                        &id,
+                        path.as_deref(),
+                        branch.as_deref(),
+                        base.as_deref(),
+                        output,
+                    )
+                    .await
+                }
+                WorktreeAction::Ls { output } => cmd_worktree_ls(output).await,
+                WorktreeAction::Cleanup {
+                    dry_run,
+                    force,
+                    output,
+                } => cmd_worktree_cleanup(dry_run, force, output).await,
+            },
+
+            Commands::CommitMsg { id, output } => cmd_commit_msg(&id, output).await,
+            Commands::PrBody { id, output } => cmd_pr_body(&id, output).await,
+            Commands::Pr { action } => match action {
+                PrAction::Link { id, pr, output } => cmd_pr_link(&id, pr.as_deref(), output).await,
             },
 
             Commands::Config { action } => match action {
                 ConfigAction::Show { output } => cmd_config_show(output),
                 ConfigAction::Set { key, value, output } => cmd_config_set(&key, &value, output),
                 ConfigAction::Get { key, output } => cmd_config_get(&key, output),
+                ConfigAction::Encrypt {
+                    tool,
+                    recipients,
+                    identity,
+                    output,
+                } => cmd_config_encrypt(tool, recipients, identity, output),
+            },
+
+            #[cfg(feature = "llm")]
+            Commands::Ac { action } => match action {
+                AcAction::Suggest { id, yes, output } => cmd_ac_suggest(&id, yes, output).await,
             },
 
             Commands::Cache { action } => match action {
                 CacheAction::Status { output } => cmd_cache_status(output).await,
                 CacheAction::Prune { output } => cmd_cache_prune(output).await,
                 CacheAction::Rebuild { output } => cmd_cache_rebuild(output).await,
+                CacheAction::Sync { output } => cmd_cache_sync(output).await,
             },
 
-            Commands::Events { action } => match action {
-                EventsAction::Prune { output } => cmd_events_prune(output).await,
+            Commands::Events {
+                action,
+                follow,
+                lines,
+                output,
+            } => match action {
+                Some(EventsAction::Prune { output }) => cmd_events_prune(output).await,
+                None => cmd_events_tail(lines, follow, output).await,
             },
 
             Commands::Hook { action } => match action {
@@ -1323,6 +2756,26 @@ impl Commands {
                 cmd_doctor(output),
                 "Ticket health check failed - some files have errors",
             ),
+            Commands::Fmt { check, output } => handle_validation_result(
+                cmd_fmt(check, output).await,
+                if check {
+                    "Some files are not formatted - run `janus fmt` to fix them"
+                } else {
+                    "Some files could not be formatted"
+                },
+            ),
+            Commands::Prune {
+                dry_run,
+                yes,
+                output,
+            } => cmd_prune(dry_run, yes, output).await,
+            Commands::Report {
+                github_summary,
+                stale_days,
+                output,
+            } => cmd_report(github_summary, stale_days, output).await,
+            Commands::Backup { file, output } => cmd_backup(file, output),
+            Commands::Restore { file, yes, output } => cmd_restore(&file, yes, output),
 
             Commands::Plan { action } => match action {
                 PlanAction::Create {
@@ -1403,12 +2856,34 @@ impl Commands {
                 } => {
                     cmd_plan_remove_phase(&plan_id, &phase, force, migrate.as_deref(), output).await
                 }
+                PlanAction::RenamePhase {
+                    plan_id,
+                    phase,
+                    new_name,
+                    output,
+                } => cmd_plan_rename_phase(&plan_id, &phase, &new_name, output).await,
+                PlanAction::AnnotatePhase {
+                    plan_id,
+                    phase,
+                    description,
+                    output,
+                } => cmd_plan_annotate_phase(&plan_id, &phase, &description, output).await,
                 PlanAction::Reorder {
                     plan_id,
                     phase,
                     reorder_phases,
+                    from_file,
                     output,
-                } => cmd_plan_reorder(&plan_id, phase.as_deref(), reorder_phases, output).await,
+                } => {
+                    cmd_plan_reorder(
+                        &plan_id,
+                        phase.as_deref(),
+                        reorder_phases,
+                        from_file.as_deref(),
+                        output,
+                    )
+                    .await
+                }
                 PlanAction::Delete { id, force, output } => {
                     cmd_plan_delete(&id, force, output).await
                 }
@@ -1425,6 +2900,16 @@ impl Commands {
                     output,
                 } => cmd_plan_next(&id, phase, all, count, output).await,
                 PlanAction::Status { id, output } => cmd_plan_status(&id, output).await,
+                PlanAction::Diff { id, rev, output } => cmd_plan_diff(&id, &rev, output).await,
+                PlanAction::Forecast { id, weeks, output } => {
+                    cmd_plan_forecast(&id, weeks, output).await
+                }
+                PlanAction::Push {
+                    id,
+                    project,
+                    first_cycle,
+                    output,
+                } => cmd_plan_push(&id, project.as_deref(), first_cycle, output).await,
                 PlanAction::Import {
                     file,
                     dry_run,
@@ -1471,13 +2956,41 @@ impl Commands {
                 .await
             }
 
-            Commands::Next { limit, output } => cmd_next(limit, output).await,
+            Commands::Next {
+                limit,
+                assignee,
+                output,
+            } => cmd_next(limit, assignee.as_deref(), output).await,
+            Commands::Ready {
+                limit,
+                size,
+                explain,
+                watch,
+                output,
+            } => {
+                if watch {
+                    watch_output("janus ready", || {
+                        cmd_ready(limit, size.clone(), explain, output)
+                    })
+                    .await
+                } else {
+                    cmd_ready(limit, size, explain, output).await
+                }
+            }
+            Commands::Blocked { output } => cmd_blocked(output).await,
+            Commands::Suggest {
+                assignee,
+                limit,
+                output,
+            } => cmd_suggest(assignee.as_deref(), limit, output).await,
 
             Commands::Completions { shell } => {
                 generate_completions(shell);
                 Ok(())
             }
 
+            Commands::Complete { kind, prefix } => cmd_complete(kind, &prefix).await,
+
             Commands::Mcp { version } => {
                 if version {
                     crate::mcp::cmd_mcp_version()
@@ -1493,6 +3006,10 @@ impl Commands {
                 output,
             } => cmd_search(&query, limit, threshold, output).await,
 
+            Commands::Schema { action } => match action {
+                SchemaAction::Dump { dir } => cmd_schema_dump(dir.as_deref()),
+            },
+
             Commands::Doc { action } => match action {
                 DocAction::Ls { output } => cmd_doc_ls(output).await,
                 DocAction::Show {
@@ -1507,7 +3024,24 @@ impl Commands {
                     tag,
                     output,
                 } => cmd_doc_create(&label, title, description, tag, output).await,
+                DocAction::Add {
+                    url,
+                    label,
+                    title,
+                    tag,
+                    output,
+                } => cmd_doc_add(&url, label.as_deref(), title, tag, output).await,
                 DocAction::Edit { label, output } => cmd_doc_edit(&label, output).await,
+                DocAction::Link {
+                    label,
+                    tickets,
+                    output,
+                } => cmd_doc_link(&label, &tickets, output).await,
+                DocAction::Unlink {
+                    label,
+                    tickets,
+                    output,
+                } => cmd_doc_unlink(&label, &tickets, output).await,
                 DocAction::Search {
                     query,
                     document,
@@ -1515,6 +3049,7 @@ impl Commands {
                     threshold,
                     output,
                 } => cmd_doc_search(&query, document.as_deref(), limit, threshold, output).await,
+                DocAction::View => cmd_doc_view().await,
             },
 
             Commands::Objective { action } => match action {
@@ -1568,6 +3103,59 @@ impl Commands {
     }
 }
 
+/// Rewrite `--title <query>` into an `@title:<query>` ticket reference.
+///
+/// Applies only to subcommands without a `--title` option of their own, so
+/// `janus show --title "login crash"` looks the ticket up by title while
+/// `janus doc create --title ...` is untouched. The reference takes the
+/// flag's place among the arguments, filling the next ID positional.
+pub fn expand_title_flag<I, T>(args: I) -> Vec<OsString>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString>,
+{
+    let mut args = args.into_iter().map(Into::into);
+    let mut expanded: Vec<OsString> = args.next().into_iter().collect();
+    let mut command = Cli::command();
+    let mut in_subcommands = true;
+
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            expanded.push(arg);
+            expanded.extend(args);
+            break;
+        }
+
+        let text = arg.to_string_lossy();
+        let has_title_option = command
+            .get_arguments()
+            .any(|a| a.get_long() == Some("title"));
+        if !has_title_option {
+            let query = if text == "--title" {
+                args.next()
+            } else {
+                text.strip_prefix("--title=").map(OsString::from)
+            };
+            if let Some(query) = query {
+                let mut reference = OsString::from(TITLE_PREFIX);
+                reference.push(query);
+                expanded.push(reference);
+                continue;
+            }
+        }
+
+        if in_subcommands && !text.starts_with('-') {
+            match command.find_subcommand(&arg).cloned() {
+                Some(subcommand) => command = subcommand,
+                None => in_subcommands = false,
+            }
+        }
+        expanded.push(arg);
+    }
+
+    expanded
+}
+
 /// Generic validation helper for parsing values with a standard error message format.
 fn parse_with_validation<T, F>(
     s: &str,
@@ -1605,6 +3193,24 @@ fn parse_type(s: &str) -> Result<TicketType, String> {
     )
 }
 
+fn parse_ticket_section(s: &str) -> Result<TicketSection, String> {
+    parse_with_validation(
+        s,
+        |v| v.parse().map_err(|_| String::new()),
+        "section",
+        TicketSection::ALL_STRINGS,
+    )
+}
+
+fn parse_color_choice(s: &str) -> Result<ColorChoice, String> {
+    parse_with_validation(
+        s,
+        |v| v.parse().map_err(|_| String::new()),
+        "color",
+        ColorChoice::ALL_STRINGS,
+    )
+}
+
 fn parse_status(s: &str) -> Result<TicketStatus, String> {
     parse_with_validation(
         s,
@@ -1619,6 +3225,13 @@ fn parse_partial_id(s: &str) -> Result<String, String> {
     if trimmed.is_empty() {
         return Err("ID cannot be empty".to_string());
     }
+    // Title queries are resolved by the ticket locator
+    if let Some(query) = trimmed.strip_prefix(TITLE_PREFIX) {
+        if query.trim().is_empty() {
+            return Err("title query cannot be empty".to_string());
+        }
+        return Ok(trimmed.to_string());
+    }
     if trimmed.starts_with('-') {
         return Err("ID cannot start with hyphen".to_string());
     }
@@ -1633,6 +3246,15 @@ fn parse_partial_id(s: &str) -> Result<String, String> {
     Ok(trimmed.to_string())
 }
 
+/// A dependency is a (partial) ticket ID or an external URL
+fn parse_dep_target(s: &str) -> Result<String, String> {
+    let trimmed = s.trim();
+    if crate::types::is_external_dep(trimmed) {
+        return Ok(trimmed.to_string());
+    }
+    parse_partial_id(trimmed)
+}
+
 fn parse_plan_id(s: &str) -> Result<String, String> {
     // Character-level validation only - allows partial plan IDs
     let trimmed = s.trim();
@@ -1669,6 +3291,45 @@ fn parse_sort_field(s: &str) -> Result<SortField, String> {
     )
 }
 
+fn parse_since_arg(s: &str) -> Result<jiff::Timestamp, String> {
+    crate::utils::parse_since(s).map_err(|e| e.to_string())
+}
+
+fn parse_until_arg(s: &str) -> Result<jiff::Timestamp, String> {
+    crate::utils::parse_until(s).map_err(|e| e.to_string())
+}
+
+fn parse_duration_arg(s: &str) -> Result<jiff::SignedDuration, String> {
+    crate::utils::parse_duration(s).map_err(|e| e.to_string())
+}
+
+fn parse_board_export_format(s: &str) -> Result<BoardExportFormat, String> {
+    parse_with_validation(
+        s,
+        |v| v.parse().map_err(|_| String::new()),
+        "export format",
+        BoardExportFormat::ALL_STRINGS,
+    )
+}
+
+fn parse_group_field(s: &str) -> Result<GroupField, String> {
+    parse_with_validation(
+        s,
+        |v| v.parse().map_err(|_| String::new()),
+        "group field",
+        GroupField::ALL_STRINGS,
+    )
+}
+
+fn parse_resolution(s: &str) -> Result<TicketResolution, String> {
+    parse_with_validation(
+        s,
+        |v| v.parse().map_err(|_| String::new()),
+        "resolution",
+        TicketResolution::ALL_STRINGS,
+    )
+}
+
 fn parse_size(s: &str) -> Result<TicketSize, String> {
     let mut valid_values = TicketSize::ALL_STRINGS.to_vec();
     valid_values.extend(["xs", "s", "m", "l", "xl"]);
@@ -1680,9 +3341,30 @@ fn parse_size(s: &str) -> Result<TicketSize, String> {
     )
 }
 
+fn parse_encryption_tool(s: &str) -> Result<EncryptionTool, String> {
+    parse_with_validation(
+        s,
+        |v| v.parse().map_err(|_| String::new()),
+        "encryption tool",
+        EncryptionTool::ALL_STRINGS,
+    )
+}
+
+fn parse_completion_kind(s: &str) -> Result<CompletionKind, String> {
+    parse_with_validation(
+        s,
+        |v| v.parse().map_err(|_| String::new()),
+        "completion kind",
+        CompletionKind::ALL_STRINGS,
+    )
+}
+
 pub fn generate_completions(shell: Shell) {
     let mut cmd = Cli::command();
     clap_complete::generate(shell, &mut cmd, "janus", &mut io::stdout());
+    if let Some(script) = dynamic_completion_script(shell, &cmd) {
+        print!("{script}");
+    }
 }
 
 #[cfg(test)]
@@ -1765,3 +3447,46 @@ mod tests {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(args: &[&str]) -> Vec<String> {
+        expand_title_flag(args.iter().copied())
+            .into_iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_expand_title_flag() {
+        assert_eq!(
+            expand(&["janus", "show", "--title", "login crash"]),
+            vec!["janus", "show", "@title:login crash"]
+        );
+        assert_eq!(
+            expand(&["janus", "dep", "add", "--title=login", "j-a1b2"]),
+            vec!["janus", "dep", "add", "@title:login", "j-a1b2"]
+        );
+    }
+
+    #[test]
+    fn test_expand_title_flag_keeps_own_title_options() {
+        let args = ["janus", "doc", "create", "ops", "--title", "Operations"];
+        assert_eq!(expand(&args), args);
+
+        let args = ["janus", "show", "j-a1b2", "--", "--title"];
+        assert_eq!(expand(&args), args);
+    }
+
+    #[test]
+    fn test_parse_partial_id_title_reference() {
+        assert_eq!(
+            parse_partial_id("@title:login crash").unwrap(),
+            "@title:login crash"
+        );
+        assert!(parse_partial_id("@title: ").is_err());
+        assert!(parse_partial_id("j@b1").is_err());
+    }
+}
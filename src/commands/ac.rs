@@ -0,0 +1,129 @@
+use serde_json::json;
+
+use super::{CommandOutput, interactive};
+use crate::cli::OutputOptions;
+use crate::config::Config;
+use crate::error::{JanusError, Result};
+use crate::ticket::Ticket;
+use crate::utils::is_stdin_tty;
+
+const SECTION: &str = "Acceptance Criteria";
+
+const SYSTEM_PROMPT: &str = "You write acceptance criteria for tickets in an issue tracker. \
+Given a ticket's title and description, reply with 3 to 7 acceptance criteria as a markdown \
+bullet list, one criterion per line. Each criterion must be specific and verifiable. \
+Do not repeat criteria the ticket already has and do not add any other text.";
+
+/// Draft acceptance criteria for a ticket with the configured LLM
+///
+/// The suggestions are shown and, once confirmed (or with `yes`), appended to
+/// the ticket's Acceptance Criteria section, which is created if missing.
+pub async fn cmd_ac_suggest(id: &str, yes: bool, output: OutputOptions) -> Result<()> {
+    let config = Config::load()?;
+    let ticket = Ticket::find(id).await?;
+    let metadata = ticket.read()?;
+    let description = ticket.extract_description()?;
+    let existing = ticket.extract_section(SECTION)?;
+
+    if description.is_none() && metadata.title.is_none() {
+        return Err(JanusError::InvalidInput(format!(
+            "ticket {} has no title or description to draft criteria from",
+            ticket.id
+        )));
+    }
+
+    let mut prompt = format!(
+        "Title: {}\n\nDescription:\n{}\n",
+        metadata.title.as_deref().unwrap_or(""),
+        description.as_deref().unwrap_or("(none)")
+    );
+    if let Some(existing) = existing.as_deref().filter(|s| !s.trim().is_empty()) {
+        prompt.push_str(&format!("\nExisting acceptance criteria:\n{existing}\n"));
+    }
+
+    let reply = crate::llm::complete(&config, SYSTEM_PROMPT, &prompt).await?;
+    let criteria = parse_criteria(&reply);
+    if criteria.is_empty() {
+        return Err(JanusError::Api(format!(
+            "LLM reply contained no acceptance criteria: {reply}"
+        )));
+    }
+
+    if !yes {
+        if output.json || !is_stdin_tty() {
+            return Err(JanusError::ConfirmationRequired(
+                "Adding suggested criteria requires -y/--yes flag in non-interactive contexts. Use -y to confirm.".to_string(),
+            ));
+        }
+        println!("Suggested acceptance criteria for {}:\n", ticket.id);
+        for criterion in &criteria {
+            println!("- {criterion}");
+        }
+        println!();
+        if !interactive::confirm(&format!("Add these to {}", ticket.id))? {
+            println!("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let list = criteria
+        .iter()
+        .map(|c| format!("- {c}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let section = match existing.as_deref().map(str::trim_end) {
+        Some(existing) if !existing.is_empty() => format!("{existing}\n{list}"),
+        _ => list,
+    };
+    ticket.update_section(SECTION, Some(&section))?;
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "acceptance_criteria_added",
+        "criteria": criteria,
+    }))
+    .with_text(format!(
+        "Added {} acceptance criteria to {}",
+        criteria.len(),
+        ticket.id
+    ))
+    .print(output)
+}
+
+/// Extract list items from the LLM reply, dropping bullets, numbering, and
+/// checkboxes. Lines that are not list items (preambles, headings) are ignored.
+fn parse_criteria(reply: &str) -> Vec<String> {
+    reply
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let item = line
+                .strip_prefix("- ")
+                .or_else(|| line.strip_prefix("* "))
+                .or_else(|| {
+                    let (number, rest) = line.split_once(". ")?;
+                    number.chars().all(|c| c.is_ascii_digit()).then_some(rest)
+                })?;
+            let item = item
+                .strip_prefix("[ ] ")
+                .or_else(|| item.strip_prefix("[x] "))
+                .unwrap_or(item)
+                .trim();
+            (!item.is_empty()).then(|| item.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_criteria() {
+        let reply = "Here are some criteria:\n\n- Login succeeds\n* [ ] Errors are shown\n2. Session persists\n\n-  \nDone.";
+        assert_eq!(
+            parse_criteria(reply),
+            vec!["Login succeeds", "Errors are shown", "Session persists"]
+        );
+    }
+}
@@ -1,24 +1,78 @@
+use std::io::{self, Write};
+
 use serde_json::json;
 
 use super::CommandOutput;
 use crate::cli::OutputOptions;
+use crate::config::Config;
 use crate::error::{JanusError, Result};
 use crate::ticket::Ticket;
 use crate::utils::validation::MAX_NOTE_LENGTH;
-use crate::utils::{is_stdin_tty, iso_date, read_stdin};
+use crate::utils::{expand_template, get_git_user_name, is_stdin_tty, iso_date, read_stdin};
+
+/// Expand the configured note template `name` for `ticket`
+fn note_template(name: &str, ticket: &Ticket) -> Result<String> {
+    let config = Config::load()?;
+    let template = config
+        .notes
+        .templates
+        .get(name)
+        .ok_or_else(|| JanusError::NoteTemplateNotFound(name.to_string()))?;
+
+    let user = get_git_user_name()
+        .or_else(|| std::env::var("USER").ok())
+        .unwrap_or_default();
+    let date = jiff::Zoned::now().strftime("%Y-%m-%d").to_string();
+    let title = ticket.read()?.title.unwrap_or_default();
+    expand_template(
+        name,
+        template,
+        &[
+            ("user", &user),
+            ("date", &date),
+            ("id", &ticket.id),
+            ("title", &title),
+        ],
+    )
+}
 
-/// Add a timestamped note to a ticket
-pub async fn cmd_add_note(id: &str, note_text: Option<&str>, output: OutputOptions) -> Result<()> {
+/// Show the expanded template as a prompt and read the rest of the note
+fn prompt_after_template(template: &str) -> Result<String> {
+    print!("{template}");
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+/// Add a timestamped note to a ticket, optionally starting from a note
+/// template in the config
+pub async fn cmd_add_note(
+    id: &str,
+    note_text: Option<&str>,
+    template: Option<&str>,
+    output: OutputOptions,
+) -> Result<()> {
     let ticket = Ticket::find(id).await?;
+    let template = match template {
+        Some(name) => Some(note_template(name, &ticket)?),
+        None => None,
+    };
 
-    // Get note text from argument or stdin
-    let note = if let Some(text) = note_text {
+    // Get note text from argument or stdin, or prompt for the rest of a template
+    let text = if let Some(text) = note_text {
         text.to_string()
     } else if !is_stdin_tty() {
         read_stdin()?
+    } else if let Some(template) = &template {
+        prompt_after_template(template)?
     } else {
         return Err(JanusError::EmptyNote);
     };
+    let note = match template {
+        Some(template) => format!("{template}{text}"),
+        None => text,
+    };
 
     // Validate that note content is not empty or whitespace-only
     if note.trim().is_empty() {
@@ -0,0 +1,276 @@
+//! Ticket attachments (`janus attach`)
+//!
+//! See [`crate::attachment`] for how attachment content is stored.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use owo_colors::OwoColorize;
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::attachment::{
+    Attachment, attach_file, format_size, read_object, unreferenced_objects, validate_name,
+};
+use crate::cli::OutputOptions;
+use crate::error::{JanusError, Result};
+use crate::ticket::{Ticket, get_all_tickets_from_disk};
+
+/// Attach files to a ticket
+///
+/// Re-attaching a name the ticket already has replaces that attachment.
+pub async fn cmd_attach_add(
+    id: &str,
+    files: &[PathBuf],
+    name: Option<&str>,
+    output: OutputOptions,
+) -> Result<()> {
+    if name.is_some() && files.len() > 1 {
+        return Err(JanusError::ConflictingFlags(
+            "--name can only be used when attaching a single file".to_string(),
+        ));
+    }
+    let (ticket, metadata) = Ticket::find_and_read(id).await?;
+
+    let mut attachments = metadata.attachments.clone();
+    let mut added = Vec::with_capacity(files.len());
+    let mut text_lines = Vec::with_capacity(files.len());
+    for file in files {
+        let attachment = attach_file(file, name)?;
+        let verb = match attachments.iter_mut().find(|a| a.name == attachment.name) {
+            Some(existing) if existing.hash == attachment.hash => "Already attached",
+            Some(existing) => {
+                *existing = attachment.clone();
+                "Replaced"
+            }
+            None => {
+                attachments.push(attachment.clone());
+                "Attached"
+            }
+        };
+        text_lines.push(format!(
+            "{verb} {} ({}) -> {}",
+            attachment.name,
+            format_size(attachment.size),
+            ticket.id.cyan()
+        ));
+        added.push(attachment);
+    }
+
+    if attachments != metadata.attachments {
+        write_attachments(&ticket, &attachments)?;
+    }
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "attachments_added",
+        "attachments": added,
+    }))
+    .with_text(text_lines.join("\n"))
+    .print(output)
+}
+
+/// List a ticket's attachments
+pub async fn cmd_attach_ls(id: &str, output: OutputOptions) -> Result<()> {
+    let (ticket, metadata) = Ticket::find_and_read(id).await?;
+
+    let text = if metadata.attachments.is_empty() {
+        format!("{} has no attachments", ticket.id.cyan())
+    } else {
+        let width = metadata
+            .attachments
+            .iter()
+            .map(|a| a.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        metadata
+            .attachments
+            .iter()
+            .map(|a| {
+                format!(
+                    "{:<width$}  {:>9}  {}",
+                    a.name,
+                    format_size(a.size),
+                    a.hash[..12.min(a.hash.len())].dimmed()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "attachments": metadata.attachments,
+    }))
+    .with_text(text)
+    .print(output)
+}
+
+/// Remove an attachment from a ticket
+///
+/// The content stays in the object store until `janus attach gc`, since other
+/// tickets may share it.
+pub async fn cmd_attach_rm(id: &str, name: &str, output: OutputOptions) -> Result<()> {
+    let (ticket, metadata) = Ticket::find_and_read(id).await?;
+    let removed = find_attachment(&ticket, &metadata.attachments, name)?.clone();
+
+    let attachments: Vec<Attachment> = metadata
+        .attachments
+        .into_iter()
+        .filter(|a| a.name != name)
+        .collect();
+    write_attachments(&ticket, &attachments)?;
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "attachment_removed",
+        "attachment": removed,
+    }))
+    .with_text(format!("Removed {} from {}", name, ticket.id.cyan()))
+    .print(output)
+}
+
+/// Rename a ticket's attachment without touching its content
+pub async fn cmd_attach_rename(
+    id: &str,
+    name: &str,
+    new_name: &str,
+    output: OutputOptions,
+) -> Result<()> {
+    let (ticket, metadata) = Ticket::find_and_read(id).await?;
+    find_attachment(&ticket, &metadata.attachments, name)?;
+    if name != new_name && metadata.attachments.iter().any(|a| a.name == new_name) {
+        return Err(JanusError::InvalidInput(format!(
+            "{} already has an attachment named '{new_name}'",
+            ticket.id
+        )));
+    }
+    validate_name(new_name)?;
+
+    let mut attachments = metadata.attachments.clone();
+    for attachment in attachments.iter_mut().filter(|a| a.name == name) {
+        attachment.name = new_name.to_string();
+    }
+    write_attachments(&ticket, &attachments)?;
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "attachment_renamed",
+        "from": name,
+        "to": new_name,
+    }))
+    .with_text(format!(
+        "Renamed {name} -> {new_name} on {}",
+        ticket.id.cyan()
+    ))
+    .print(output)
+}
+
+/// Export an attachment's content to a file
+///
+/// Writes to `file`, or to the attachment's name in the current directory.
+/// Existing files are not overwritten.
+pub async fn cmd_attach_get(
+    id: &str,
+    name: &str,
+    file: Option<PathBuf>,
+    output: OutputOptions,
+) -> Result<()> {
+    let (ticket, metadata) = Ticket::find_and_read(id).await?;
+    let attachment = find_attachment(&ticket, &metadata.attachments, name)?;
+    let file = file.unwrap_or_else(|| PathBuf::from(&attachment.name));
+    if file.exists() {
+        return Err(JanusError::InvalidInput(format!(
+            "{} already exists",
+            file.display()
+        )));
+    }
+
+    let content = read_object(&attachment.hash)?;
+    std::fs::write(&file, &content).map_err(|e| JanusError::StorageError {
+        operation: "write",
+        item_type: "file",
+        path: file.clone(),
+        source: e,
+    })?;
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "attachment": attachment,
+        "file": file.display().to_string(),
+    }))
+    .with_text(format!("Wrote {} to {}", attachment.name, file.display()))
+    .print(output)
+}
+
+/// Delete objects that no ticket references
+///
+/// Refuses to run when some ticket files fail to parse, since their
+/// attachments can't be counted as referenced.
+pub fn cmd_attach_gc(dry_run: bool, output: OutputOptions) -> Result<()> {
+    let tickets = get_all_tickets_from_disk();
+    if !tickets.failed.is_empty() {
+        let files: Vec<&str> = tickets.failed.iter().map(|(f, _)| f.as_str()).collect();
+        return Err(JanusError::InvalidInput(format!(
+            "cannot collect attachments while ticket files fail to parse: {}",
+            files.join(", ")
+        )));
+    }
+
+    let referenced: HashSet<String> = tickets
+        .items
+        .iter()
+        .flat_map(|ticket| ticket.attachments.iter().map(|a| a.hash.clone()))
+        .collect();
+    let unreferenced = unreferenced_objects(&referenced);
+
+    let mut freed = 0u64;
+    for (_, path) in &unreferenced {
+        freed += std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        if !dry_run {
+            crate::fs::delete_file(path)?;
+        }
+    }
+
+    let verb = if dry_run { "Would remove" } else { "Removed" };
+    let text = if unreferenced.is_empty() {
+        "No unreferenced attachment objects".to_string()
+    } else {
+        format!(
+            "{verb} {} unreferenced object(s), {}",
+            unreferenced.len(),
+            format_size(freed)
+        )
+    };
+
+    CommandOutput::new(json!({
+        "action": "attachments_gc",
+        "dry_run": dry_run,
+        "removed": unreferenced.iter().map(|(hash, _)| hash).collect::<Vec<_>>(),
+        "bytes_freed": freed,
+    }))
+    .with_text(text)
+    .print(output)
+}
+
+fn find_attachment<'a>(
+    ticket: &Ticket,
+    attachments: &'a [Attachment],
+    name: &str,
+) -> Result<&'a Attachment> {
+    attachments
+        .iter()
+        .find(|a| a.name == name)
+        .ok_or_else(|| JanusError::AttachmentNotFound {
+            id: ticket.id.clone(),
+            name: name.to_string(),
+        })
+}
+
+fn write_attachments(ticket: &Ticket, attachments: &[Attachment]) -> Result<()> {
+    if attachments.is_empty() {
+        ticket.remove_field("attachments")
+    } else {
+        ticket.update_field("attachments", &serde_json::to_string(attachments)?)
+    }
+}
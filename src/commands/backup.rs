@@ -0,0 +1,92 @@
+//! `janus backup` and `janus restore` commands.
+//!
+//! Thin wrappers around [`crate::backup`]; see that module for the archive
+//! format and how it is verified.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+
+use super::{CommandOutput, interactive};
+use crate::backup::{create_backup, restore_backup};
+use crate::cli::OutputOptions;
+use crate::error::{JanusError, Result};
+use crate::types::janus_root;
+use crate::utils::is_stdin_tty;
+
+/// Snapshot the Janus directory (without the embeddings cache) to `file`.
+///
+/// Defaults to `janus-backup-<timestamp>.tar.zst` in the current directory.
+pub fn cmd_backup(file: Option<PathBuf>, output: OutputOptions) -> Result<()> {
+    let root = janus_root();
+    let file = file.unwrap_or_else(|| {
+        let stamp = jiff::Zoned::now().strftime("%Y%m%d-%H%M%S");
+        PathBuf::from(format!("janus-backup-{stamp}.tar.zst"))
+    });
+    if file.exists() {
+        return Err(JanusError::InvalidInput(format!(
+            "{} already exists",
+            file.display()
+        )));
+    }
+
+    let manifest = create_backup(&root, &file)?;
+
+    CommandOutput::new(json!({
+        "file": file.display().to_string(),
+        "created": manifest.created,
+        "files": manifest.files.len(),
+        "size_bytes": manifest.total_size(),
+    }))
+    .with_text(format!(
+        "Backed up {} file(s) ({} bytes) from {} to {}",
+        manifest.files.len(),
+        manifest.total_size(),
+        root.display(),
+        file.display()
+    ))
+    .print(output)
+}
+
+/// Replace the Janus directory with the contents of a backup.
+///
+/// The backup is verified in full before the current directory is replaced,
+/// so a corrupted archive leaves everything as it was.
+pub fn cmd_restore(file: &Path, yes: bool, output: OutputOptions) -> Result<()> {
+    let root = janus_root();
+    if !file.is_file() {
+        return Err(JanusError::FileNotFound(file.display().to_string()));
+    }
+
+    if root.exists() && !yes {
+        if output.json || !is_stdin_tty() {
+            return Err(JanusError::ConfirmationRequired(
+                "Restoring replaces the current Janus directory and requires -y/--yes flag in non-interactive contexts. Use -y to confirm.".to_string(),
+            ));
+        }
+        if !interactive::confirm(&format!(
+            "Replace {} with the contents of {}",
+            root.display(),
+            file.display()
+        ))? {
+            println!("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let manifest = restore_backup(file, &root)?;
+
+    CommandOutput::new(json!({
+        "file": file.display().to_string(),
+        "created": manifest.created,
+        "files": manifest.files.len(),
+        "size_bytes": manifest.total_size(),
+    }))
+    .with_text(format!(
+        "Restored {} file(s) to {} from backup taken {}",
+        manifest.files.len(),
+        root.display(),
+        manifest.created
+    ))
+    .print(output)
+}
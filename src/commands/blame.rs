@@ -0,0 +1,301 @@
+//! Field-level blame for ticket frontmatter (`janus blame`)
+//!
+//! Walks the git history of a ticket file and reports, for each frontmatter
+//! field, the commit that last changed its value. Uncommitted edits in the
+//! working tree are attributed to a pseudo-revision.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::json;
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::error::{JanusError, Result};
+use crate::parser::split_frontmatter;
+use crate::ticket::Ticket;
+use crate::utils::{git, truncate_string};
+
+/// Separates commits in `git log` output
+const RECORD_SEP: char = '\u{1e}';
+/// Separates fields within a commit header
+const FIELD_SEP: char = '\u{1f}';
+
+/// A single revision of the ticket file.
+#[derive(Debug, Clone)]
+struct Revision {
+    /// Full commit hash, or `None` for the working tree
+    commit: Option<String>,
+    author: String,
+    date: String,
+    summary: String,
+    /// Frontmatter field values at this revision, rendered as strings
+    fields: BTreeMap<String, String>,
+}
+
+/// Blame information for one frontmatter field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct FieldBlame {
+    field: String,
+    value: String,
+    commit: Option<String>,
+    author: String,
+    date: String,
+    summary: String,
+}
+
+#[derive(Tabled)]
+struct BlameRow {
+    #[tabled(rename = "Field")]
+    field: String,
+    #[tabled(rename = "Value")]
+    value: String,
+    #[tabled(rename = "Commit")]
+    commit: String,
+    #[tabled(rename = "Author")]
+    author: String,
+    #[tabled(rename = "Date")]
+    date: String,
+    #[tabled(rename = "Summary")]
+    summary: String,
+}
+
+/// Show when each frontmatter field of a ticket last changed and by whom
+pub async fn cmd_blame(id: &str, field: Option<&str>, output: OutputOptions) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+    let mut revisions = load_revisions(&ticket.file_path)?;
+
+    // Include uncommitted edits so the blame reflects what `janus show` displays
+    let working = ticket.read_content()?;
+    revisions.push(Revision {
+        commit: None,
+        author: "(uncommitted)".to_string(),
+        date: String::new(),
+        summary: String::new(),
+        fields: frontmatter_fields(&working),
+    });
+
+    let mut blame = compute_field_blame(&revisions);
+    if let Some(field) = field {
+        blame.retain(|b| b.field == field);
+        if blame.is_empty() {
+            return Err(JanusError::InvalidFieldName(field.to_string()));
+        }
+    }
+
+    let rows: Vec<BlameRow> = blame
+        .iter()
+        .map(|b| BlameRow {
+            field: b.field.clone(),
+            value: truncate_string(&b.value, 40),
+            commit: b
+                .commit
+                .as_deref()
+                .map(|c| c.chars().take(8).collect())
+                .unwrap_or_else(|| "-".to_string()),
+            author: b.author.clone(),
+            date: b.date.chars().take(10).collect(),
+            summary: truncate_string(&b.summary, 50),
+        })
+        .collect();
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "fields": blame,
+    }))
+    .with_text(table.to_string())
+    .print(output)
+}
+
+/// Attribute each field's current value to the oldest revision of its latest
+/// unbroken run of that value.
+///
+/// `revisions` must be ordered oldest first. Fields absent from the newest
+/// revision are omitted.
+fn compute_field_blame(revisions: &[Revision]) -> Vec<FieldBlame> {
+    let mut last_change: BTreeMap<&str, &Revision> = BTreeMap::new();
+    let mut previous: Option<&Revision> = None;
+
+    for revision in revisions {
+        for (field, value) in &revision.fields {
+            let unchanged = previous.is_some_and(|p| p.fields.get(field) == Some(value));
+            if !unchanged {
+                last_change.insert(field.as_str(), revision);
+            }
+        }
+        previous = Some(revision);
+    }
+
+    let Some(latest) = revisions.last() else {
+        return Vec::new();
+    };
+
+    latest
+        .fields
+        .iter()
+        .filter_map(|(field, value)| {
+            let revision = last_change.get(field.as_str())?;
+            Some(FieldBlame {
+                field: field.clone(),
+                value: value.clone(),
+                commit: revision.commit.clone(),
+                author: revision.author.clone(),
+                date: revision.date.clone(),
+                summary: revision.summary.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Parse frontmatter into a map of field name to rendered value.
+///
+/// Unparseable revisions yield an empty map, so a corrupted historical commit
+/// shows up as every field changing rather than aborting the blame.
+pub(super) fn frontmatter_fields(content: &str) -> BTreeMap<String, String> {
+    let Ok((frontmatter, _)) = split_frontmatter(content) else {
+        return BTreeMap::new();
+    };
+    let Ok(mapping) = serde_yaml_ng::from_str::<serde_yaml_ng::Mapping>(&frontmatter) else {
+        return BTreeMap::new();
+    };
+
+    mapping
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let key = key.as_str()?.to_string();
+            let value = match value {
+                serde_yaml_ng::Value::String(s) => s,
+                other => serde_yaml_ng::to_string(&other).ok()?.trim().to_string(),
+            };
+            Some((key, value))
+        })
+        .collect()
+}
+
+/// Load every committed revision of the file, oldest first, following renames.
+fn load_revisions(path: &Path) -> Result<Vec<Revision>> {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    // Run git in the file's directory, so it finds the repository containing it
+    let dir = path.parent();
+    let format = format!("--format={RECORD_SEP}%H{FIELD_SEP}%an{FIELD_SEP}%aI{FIELD_SEP}%s");
+    let log = git(
+        dir,
+        ["log", "--follow", "--name-only", &format, "--", &file_name],
+    )?;
+
+    let mut revisions = Vec::new();
+    for record in log.split(RECORD_SEP).filter(|r| !r.trim().is_empty()) {
+        let mut lines = record.lines();
+        let header = lines.next().unwrap_or_default();
+        let mut parts = header.split(FIELD_SEP);
+        let (Some(commit), Some(author), Some(date), Some(summary)) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        // The repo-relative path of the file as of this commit (changes on rename)
+        let Some(file) = lines.map(str::trim).find(|l| !l.is_empty()) else {
+            continue;
+        };
+
+        // The file may be deleted in this commit; skip revisions without content
+        let Ok(content) = git(dir, ["show", &format!("{commit}:{file}")]) else {
+            continue;
+        };
+
+        revisions.push(Revision {
+            commit: Some(commit.to_string()),
+            author: author.to_string(),
+            date: date.to_string(),
+            summary: summary.to_string(),
+            fields: frontmatter_fields(&content),
+        });
+    }
+
+    revisions.reverse();
+    Ok(revisions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn revision(commit: &str, fields: &[(&str, &str)]) -> Revision {
+        Revision {
+            commit: Some(commit.to_string()),
+            author: format!("author-{commit}"),
+            date: "2024-01-01T00:00:00Z".to_string(),
+            summary: format!("commit {commit}"),
+            fields: fields
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_compute_field_blame_tracks_last_change() {
+        let revisions = vec![
+            revision("aaa", &[("id", "j-a1b2"), ("status", "new")]),
+            revision("bbb", &[("id", "j-a1b2"), ("status", "in_progress")]),
+            revision(
+                "ccc",
+                &[
+                    ("id", "j-a1b2"),
+                    ("status", "in_progress"),
+                    ("priority", "1"),
+                ],
+            ),
+        ];
+
+        let blame = compute_field_blame(&revisions);
+        let by_field: BTreeMap<_, _> = blame.iter().map(|b| (b.field.as_str(), b)).collect();
+
+        assert_eq!(by_field["id"].commit.as_deref(), Some("aaa"));
+        assert_eq!(by_field["status"].commit.as_deref(), Some("bbb"));
+        assert_eq!(by_field["status"].value, "in_progress");
+        assert_eq!(by_field["priority"].commit.as_deref(), Some("ccc"));
+    }
+
+    #[test]
+    fn test_compute_field_blame_value_reverted() {
+        let revisions = vec![
+            revision("aaa", &[("status", "new")]),
+            revision("bbb", &[("status", "complete")]),
+            revision("ccc", &[("status", "new")]),
+        ];
+
+        let blame = compute_field_blame(&revisions);
+        assert_eq!(blame[0].commit.as_deref(), Some("ccc"));
+    }
+
+    #[test]
+    fn test_compute_field_blame_omits_removed_fields() {
+        let revisions = vec![
+            revision("aaa", &[("status", "new"), ("assignee", "alice")]),
+            revision("bbb", &[("status", "new")]),
+        ];
+
+        let blame = compute_field_blame(&revisions);
+        assert_eq!(blame.len(), 1);
+        assert_eq!(blame[0].field, "status");
+    }
+
+    #[test]
+    fn test_frontmatter_fields_renders_sequences() {
+        let content = "---\nid: j-a1b2\ndeps: [\"j-c3d4\"]\npriority: 2\n---\n# Title\n";
+        let fields = frontmatter_fields(content);
+
+        assert_eq!(fields["id"], "j-a1b2");
+        assert_eq!(fields["priority"], "2");
+        assert_eq!(fields["deps"], "- j-c3d4");
+    }
+}
@@ -0,0 +1,110 @@
+//! Blocked tickets and why they are blocked (`janus blocked`)
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use jiff::Timestamp;
+use serde_json::json;
+
+use super::{
+    CommandOutput, FormatOptions, format_ticket_line, ticket_minimal_json_with_exists,
+    ticket_to_json,
+};
+use crate::cli::OutputOptions;
+use crate::display::{accent, dim, format_status_colored};
+use crate::error::Result;
+use crate::graph::{direct_blockers, root_blockers};
+use crate::query::{BlockedFilter, NotSnoozedFilter, TicketQueryBuilder};
+use crate::ticket::get_all_tickets_with_map;
+use crate::types::TicketMetadata;
+
+/// List blocked tickets with their direct and root blockers
+///
+/// Root blockers are only printed in text output when they differ from the
+/// direct blockers; JSON always includes both.
+pub async fn cmd_blocked(output: OutputOptions) -> Result<()> {
+    let (tickets, ticket_map) = get_all_tickets_with_map().await?;
+    let blocked = TicketQueryBuilder::new()
+        .with_filter(Box::new(BlockedFilter))
+        .with_filter(Box::new(NotSnoozedFilter::new(Timestamp::now())))
+        .execute(tickets)
+        .await?;
+
+    if blocked.is_empty() {
+        return CommandOutput::new(json!([]))
+            .with_text("No blocked tickets.")
+            .print(output);
+    }
+
+    let mut json_tickets = Vec::with_capacity(blocked.len());
+    let mut text = String::new();
+    for (i, ticket) in blocked.iter().enumerate() {
+        let direct = direct_blockers(ticket, &ticket_map);
+        let roots = root_blockers(ticket, &ticket_map);
+
+        let mut json = ticket_to_json(ticket);
+        json["blocked_by"] = blockers_json(&direct, &ticket_map);
+        json["root_blockers"] = blockers_json(&roots, &ticket_map);
+        json["external_blockers"] = json!(ticket.external_deps);
+        json_tickets.push(json);
+
+        if i > 0 {
+            text.push('\n');
+        }
+        text.push_str(&format_ticket_line(ticket, FormatOptions::default()));
+        if !direct.is_empty() {
+            write!(
+                text,
+                "\n  {} {}",
+                dim("blocked by:"),
+                format_blockers(&direct, &ticket_map)
+            )
+            .unwrap();
+        }
+        if !ticket.external_deps.is_empty() {
+            write!(
+                text,
+                "\n  {} {}",
+                dim("external:"),
+                ticket.external_deps.join(", ")
+            )
+            .unwrap();
+        }
+        let mut sorted_direct = direct.clone();
+        sorted_direct.sort();
+        if roots != sorted_direct {
+            write!(
+                text,
+                "\n  {} {}",
+                dim("root blockers:"),
+                format_blockers(&roots, &ticket_map)
+            )
+            .unwrap();
+        }
+    }
+
+    CommandOutput::new(json!(json_tickets))
+        .with_text(text)
+        .paged()
+        .print(output)
+}
+
+fn blockers_json(
+    ids: &[String],
+    ticket_map: &HashMap<String, TicketMetadata>,
+) -> serde_json::Value {
+    ids.iter()
+        .map(|id| ticket_minimal_json_with_exists(id, ticket_map.get(id)))
+        .collect()
+}
+
+fn format_blockers(ids: &[String], ticket_map: &HashMap<String, TicketMetadata>) -> String {
+    ids.iter()
+        .map(|id| match ticket_map.get(id).and_then(|t| t.status) {
+            Some(status) => format!("{} {}", accent(id), format_status_colored(status)),
+            None if ticket_map.contains_key(id) => accent(id),
+            None => format!("{} {}", accent(id), dim("[missing]")),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -8,11 +8,21 @@ use iocraft::prelude::*;
 use crate::archive::sweep_completed_tickets;
 use crate::error::{JanusError, Result};
 use crate::events::Actor;
+use crate::plan::Plan;
 use crate::store::{get_or_init_store, start_watching, stop_watching};
 use crate::tui::KanbanBoard;
 
 /// Launch the kanban board TUI
-pub async fn cmd_board() -> Result<()> {
+///
+/// # Arguments
+/// * `plan` - Restrict the board to this plan's tickets
+pub async fn cmd_board(plan: Option<&str>) -> Result<()> {
+    // Resolve the plan ID first (before entering fullscreen) so errors display cleanly
+    let plan_id = match plan {
+        Some(plan) => Some(Plan::find(plan).await?.id),
+        None => None,
+    };
+
     // Initialize store and start filesystem watcher for live updates
     let store = get_or_init_store().await?;
     let _ = start_watching(store).await;
@@ -20,10 +30,13 @@ pub async fn cmd_board() -> Result<()> {
     // Run the auto-archive sweep before launching the TUI so the Complete column
     // doesn't contain tickets that should have rolled off. Failures here are
     // non-fatal — we'd rather show the board than block on a sweep error.
-    let tickets = store.get_all_tickets();
-    let _ = sweep_completed_tickets(&tickets, Actor::AutoArchive).await;
+    // Read-only mode skips it entirely.
+    if !crate::read_only::is_enabled() {
+        let tickets = store.get_all_tickets();
+        let _ = sweep_completed_tickets(&tickets, Actor::AutoArchive).await;
+    }
 
-    let result = element!(KanbanBoard)
+    let result = element!(KanbanBoard(plan_id: plan_id))
         .fullscreen()
         .await
         .map_err(|e| JanusError::TuiError(format!("{e}")));
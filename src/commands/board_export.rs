@@ -0,0 +1,290 @@
+//! `janus board --export`: render the board as a markdown or HTML document.
+//!
+//! The export has the board's default columns in order, each with its cards.
+//! Cards link to their remote issue when the ticket is linked to one, and to
+//! the ticket file otherwise, relative to the directory the export was run
+//! from so the links keep working when the document is committed alongside
+//! the tickets.
+
+use std::path::Path;
+
+use jiff::Timestamp;
+
+use crate::config::Config;
+use crate::error::{JanusError, Result};
+use crate::plan::Plan;
+use crate::remote::RemoteRef;
+use crate::ticket::get_all_tickets;
+use crate::tui::board::model::{COLUMN_NAMES, COLUMNS, DEFAULT_VISIBLE_COLUMNS, PlanScope};
+use crate::types::TicketMetadata;
+
+/// Document format for `janus board --export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardExportFormat {
+    Markdown,
+    Html,
+}
+
+impl BoardExportFormat {
+    /// All valid string representations of this enum.
+    pub const ALL_STRINGS: &[&str] = &["md", "html"];
+}
+
+impl std::str::FromStr for BoardExportFormat {
+    type Err = JanusError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "md" | "markdown" => Ok(BoardExportFormat::Markdown),
+            "html" => Ok(BoardExportFormat::Html),
+            _ => Err(JanusError::InvalidFormat(format!(
+                "unknown board export format '{s}', expected md or html"
+            ))),
+        }
+    }
+}
+
+/// One card on the exported board
+struct Card {
+    id: String,
+    title: String,
+    /// Priority, type, assignee, and plan phase
+    details: Vec<String>,
+    link: Option<String>,
+}
+
+/// A column of the exported board
+struct Column {
+    name: &'static str,
+    cards: Vec<Card>,
+}
+
+/// Where a card links to: the remote issue, or else the ticket file
+fn card_link(ticket: &TicketMetadata, config: &Config, cwd: Option<&Path>) -> Option<String> {
+    if let Some(remote) = ticket.remote.as_deref()
+        && let Ok(remote) = RemoteRef::parse(remote, Some(config))
+    {
+        return Some(remote.web_url());
+    }
+    let path = ticket.file_path.as_deref()?;
+    let path = cwd
+        .and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path);
+    Some(path.to_string_lossy().replace('\\', "/"))
+}
+
+fn build_columns(
+    tickets: &[TicketMetadata],
+    scope: Option<&PlanScope>,
+    config: &Config,
+    cwd: Option<&Path>,
+) -> Vec<Column> {
+    COLUMNS
+        .iter()
+        .zip(COLUMN_NAMES)
+        .zip(DEFAULT_VISIBLE_COLUMNS)
+        .filter(|(_, visible)| *visible)
+        .map(|((status, name), _)| {
+            let mut column: Vec<&TicketMetadata> = tickets
+                .iter()
+                .filter(|t| t.status.unwrap_or_default() == *status)
+                .filter(|t| scope.is_none_or(|scope| scope.contains(t)))
+                .collect();
+            column.sort_by(|a, b| a.id.cmp(&b.id));
+
+            let cards = column
+                .into_iter()
+                .map(|ticket| {
+                    let mut details =
+                        vec![format!("P{}", ticket.priority.unwrap_or_default().as_num())];
+                    if let Some(ticket_type) = ticket.ticket_type {
+                        details.push(ticket_type.to_string());
+                    }
+                    if let Some(assignee) = &ticket.assignee {
+                        details.push(format!("@{assignee}"));
+                    }
+                    if let Some(phase) = scope.and_then(|scope| scope.phase_of(ticket)) {
+                        details.push(phase);
+                    }
+                    Card {
+                        id: ticket.id.as_deref().unwrap_or_default().to_string(),
+                        title: ticket.title.clone().unwrap_or_default(),
+                        details,
+                        link: card_link(ticket, config, cwd),
+                    }
+                })
+                .collect();
+            Column { name, cards }
+        })
+        .collect()
+}
+
+fn render_markdown(heading: &str, exported: &str, columns: &[Column]) -> String {
+    let mut markdown = format!("# {heading}\n\n_Exported {exported}_\n");
+    for column in columns {
+        markdown.push_str(&format!(
+            "\n## {} ({})\n\n",
+            column.name,
+            column.cards.len()
+        ));
+        if column.cards.is_empty() {
+            markdown.push_str("_No tickets_\n");
+        }
+        for card in &column.cards {
+            let id = match &card.link {
+                Some(link) => format!("[{}]({})", card.id, link.replace(' ', "%20")),
+                None => card.id.clone(),
+            };
+            markdown.push_str(&format!(
+                "- **{id}** {} ({})\n",
+                card.title.replace('[', "\\[").replace(']', "\\]"),
+                card.details.join(", ")
+            ));
+        }
+    }
+    markdown
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const HTML_STYLE: &str = "\
+body { font-family: system-ui, sans-serif; margin: 2rem; color: #1f2328; }
+.board { display: flex; gap: 1rem; align-items: flex-start; }
+.column { flex: 1; min-width: 12rem; background: #f6f8fa; border-radius: 6px; padding: 0.75rem; }
+.column h2 { font-size: 0.9rem; margin: 0 0 0.75rem; }
+.card { background: #fff; border: 1px solid #d0d7de; border-radius: 6px; padding: 0.5rem; margin-bottom: 0.5rem; }
+.card .id { font-family: ui-monospace, monospace; font-size: 0.8rem; }
+.card .details, .empty, .exported { color: #656d76; font-size: 0.8rem; }";
+
+fn render_html(heading: &str, exported: &str, columns: &[Column]) -> String {
+    let heading = escape_html(heading);
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{heading}</title>\n\
+         <style>\n{HTML_STYLE}\n</style>\n</head>\n<body>\n<h1>{heading}</h1>\n\
+         <p class=\"exported\">Exported {exported}</p>\n<div class=\"board\">\n"
+    );
+    for column in columns {
+        html.push_str(&format!(
+            "<section class=\"column\">\n<h2>{} ({})</h2>\n",
+            column.name,
+            column.cards.len()
+        ));
+        if column.cards.is_empty() {
+            html.push_str("<p class=\"empty\">No tickets</p>\n");
+        }
+        for card in &column.cards {
+            let id = escape_html(&card.id);
+            let id = match &card.link {
+                Some(link) => format!("<a class=\"id\" href=\"{}\">{id}</a>", escape_html(link)),
+                None => format!("<span class=\"id\">{id}</span>"),
+            };
+            html.push_str(&format!(
+                "<div class=\"card\">{id}<div>{}</div><div class=\"details\">{}</div></div>\n",
+                escape_html(&card.title),
+                escape_html(&card.details.join(" · "))
+            ));
+        }
+        html.push_str("</section>\n");
+    }
+    html.push_str("</div>\n</body>\n</html>\n");
+    html
+}
+
+/// Print the board, optionally restricted to a plan, as a markdown or HTML
+/// document
+pub async fn cmd_board_export(plan: Option<&str>, format: BoardExportFormat) -> Result<()> {
+    let config = Config::load()?;
+    let (heading, scope) = match plan {
+        Some(plan) => {
+            let plan = Plan::find(plan).await?;
+            let metadata = plan.read()?;
+            let heading = format!("Board: {}", metadata.title().unwrap_or(&plan.id));
+            (heading, Some(PlanScope::from_plan(&metadata)))
+        }
+        None => ("Board".to_string(), None),
+    };
+    let tickets = get_all_tickets().await?.items;
+    let cwd = std::env::current_dir().ok();
+    let columns = build_columns(&tickets, scope.as_ref(), &config, cwd.as_deref());
+
+    let exported = Timestamp::now().strftime("%Y-%m-%d %H:%M UTC").to_string();
+    let document = match format {
+        BoardExportFormat::Markdown => render_markdown(&heading, &exported, &columns),
+        BoardExportFormat::Html => render_html(&heading, &exported, &columns),
+    };
+    print!("{document}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TicketId, TicketStatus, TicketType};
+
+    fn columns() -> Vec<Column> {
+        let tickets = vec![
+            TicketMetadata {
+                id: Some(TicketId::new_unchecked("j-b2")),
+                title: Some("Fix <login> & logout".to_string()),
+                status: Some(TicketStatus::InProgress),
+                ticket_type: Some(TicketType::Bug),
+                assignee: Some("sam".to_string()),
+                remote: Some("github:acme/app/7".to_string()),
+                ..Default::default()
+            },
+            TicketMetadata {
+                id: Some(TicketId::new_unchecked("j-a1")),
+                title: Some("Write docs".to_string()),
+                status: Some(TicketStatus::New),
+                file_path: Some("/repo/.janus/items/j-a1.md".into()),
+                ..Default::default()
+            },
+            TicketMetadata {
+                id: Some(TicketId::new_unchecked("j-c3")),
+                status: Some(TicketStatus::Archived),
+                ..Default::default()
+            },
+        ];
+        build_columns(&tickets, None, &Config::default(), Some(Path::new("/repo")))
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let markdown = render_markdown("Board", "2026-01-01 00:00 UTC", &columns());
+        assert!(markdown.starts_with("# Board\n"));
+        assert!(
+            markdown.contains("## NEW (1)\n\n- **[j-a1](.janus/items/j-a1.md)** Write docs (P2)\n")
+        );
+        assert!(markdown.contains(
+            "- **[j-b2](https://github.com/acme/app/issues/7)** Fix <login> & logout (P2, bug, @sam)"
+        ));
+        assert!(markdown.contains("## NEXT (0)\n\n_No tickets_\n"));
+        assert!(!markdown.contains("ARCHIVED"));
+    }
+
+    #[test]
+    fn test_render_html_escapes() {
+        let html = render_html("Board", "2026-01-01 00:00 UTC", &columns());
+        assert!(html.contains("Fix &lt;login&gt; &amp; logout"));
+        assert!(html.contains("href=\"https://github.com/acme/app/issues/7\""));
+        assert_eq!(html.matches("<section").count(), 5);
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(
+            "md".parse::<BoardExportFormat>().unwrap(),
+            BoardExportFormat::Markdown
+        );
+        assert_eq!(
+            "HTML".parse::<BoardExportFormat>().unwrap(),
+            BoardExportFormat::Html
+        );
+        assert!("pdf".parse::<BoardExportFormat>().is_err());
+    }
+}
@@ -4,7 +4,10 @@ use tokio::time::timeout;
 
 use super::CommandOutput;
 use crate::cli::OutputOptions;
-use crate::embedding::model::{EMBEDDING_BATCH_SIZE, EMBEDDING_MODEL_NAME, EMBEDDING_TIMEOUT};
+use crate::config::Config;
+use crate::embedding::model::{
+    EMBEDDING_BATCH_SIZE, EMBEDDING_MODEL_NAME, EMBEDDING_TIMEOUT, ticket_embedding_text,
+};
 use crate::error::Result;
 use crate::events::log_cache_rebuilt;
 use crate::store::get_or_init_store;
@@ -57,46 +60,19 @@ pub async fn cmd_cache_status(output: OutputOptions) -> Result<()> {
 /// # Concurrency Warning
 ///
 /// This command is subject to a TOCTOU race: valid embedding keys are computed from
-/// current ticket file mtimes, and then orphaned files are deleted. If a ticket is
-/// modified between these two steps (e.g., by another process or a concurrent
-/// `janus cache rebuild`), a freshly-generated embedding could be incorrectly pruned.
-/// Do not run this command concurrently with `janus cache rebuild` or other operations
-/// that modify ticket files.
+/// the current ticket and objective content, and then orphaned files are deleted. If
+/// a ticket is edited between these two steps (e.g., by another process or a
+/// concurrent `janus cache rebuild`), a freshly-generated embedding could be
+/// incorrectly pruned. Do not run this command concurrently with `janus cache rebuild`
+/// or other operations that modify ticket files.
 pub async fn cmd_cache_prune(output: OutputOptions) -> Result<()> {
-    // 1. Get the store and compute valid embedding keys for all current ticket files
+    // 1. Get the store and compute valid embedding keys for all current tickets
+    // and objectives
     let store = get_or_init_store().await?;
-    let tickets = store.get_all_tickets();
+    let mut valid_keys = store.embedding_file_keys();
 
-    let mut valid_keys = std::collections::HashSet::new();
-    for ticket in &tickets {
-        let file_path = match &ticket.file_path {
-            Some(fp) => fp,
-            None => continue,
-        };
-        // Get mtime
-        let mtime_ns = match fs::metadata(file_path)
-            .ok()
-            .and_then(|m| m.modified().ok())
-            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-            .map(|d| d.as_nanos())
-        {
-            Some(ns) => ns,
-            None => continue,
-        };
-        match crate::store::TicketStore::embedding_key(file_path, mtime_ns) {
-            Ok(key) => {
-                valid_keys.insert(key);
-            }
-            Err(e) => {
-                eprintln!(
-                    "Warning: failed to compute embedding key for {}: {}",
-                    ticket.id.as_deref().unwrap_or("unknown"),
-                    e
-                );
-                continue;
-            }
-        }
-    }
+    // Document chunk embeddings are tracked by the doc manifest
+    valid_keys.extend(crate::store::TicketStore::doc_embedding_file_keys());
 
     // 2. Calculate bytes that will be freed (before pruning)
     let emb_dir = crate::types::janus_root().join("embeddings");
@@ -160,7 +136,6 @@ pub async fn cmd_cache_rebuild(output: OutputOptions) -> Result<()> {
     }
 
     let mut embedded_count = 0_usize;
-    let mut valid_keys = std::collections::HashSet::new();
 
     // Process tickets in batches for better performance
     let ticket_batches: Vec<Vec<_>> = tickets
@@ -169,26 +144,16 @@ pub async fn cmd_cache_rebuild(output: OutputOptions) -> Result<()> {
         .collect();
 
     for (batch_idx, batch) in ticket_batches.iter().enumerate() {
-        // Collect batch data: (file_path, mtime_ns, ticket_id, text)
+        // Collect batch data: (ticket_id, text)
         let batch_data: Vec<_> = batch
             .iter()
-            .filter_map(|ticket| {
-                let file_path = ticket.file_path.as_ref()?;
-                let mtime_ns = fs::metadata(file_path)
-                    .ok()
-                    .and_then(|m| m.modified().ok())
-                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-                    .map(|d| d.as_nanos())?;
-
-                let title = ticket.title.as_deref().unwrap_or("");
-                let body = ticket.body.as_deref().unwrap_or("");
-                let text = if body.is_empty() {
-                    title.to_string()
-                } else {
-                    format!("{title}\n\n{body}")
-                };
-
-                Some((file_path, mtime_ns, ticket.id.clone(), text))
+            .filter(|ticket| ticket.file_path.is_some())
+            .map(|ticket| {
+                let text = ticket_embedding_text(
+                    ticket.title.as_deref().unwrap_or(""),
+                    ticket.body.as_deref(),
+                );
+                (ticket.id.clone(), text)
             })
             .collect();
 
@@ -197,10 +162,7 @@ pub async fn cmd_cache_rebuild(output: OutputOptions) -> Result<()> {
         }
 
         // Extract texts for batch embedding
-        let texts: Vec<&str> = batch_data
-            .iter()
-            .map(|(_, _, _, text)| text.as_str())
-            .collect();
+        let texts: Vec<&str> = batch_data.iter().map(|(_, text)| text.as_str()).collect();
 
         // Calculate timeout for this batch (30 seconds per ticket in batch)
         let batch_timeout = EMBEDDING_TIMEOUT.saturating_mul(batch_data.len() as u32);
@@ -220,35 +182,19 @@ pub async fn cmd_cache_rebuild(output: OutputOptions) -> Result<()> {
         match embedding_result {
             Ok(Ok(embeddings)) => {
                 // Save all embeddings from the batch
-                for (i, (file_path, mtime_ns, ticket_id, _)) in batch_data.iter().enumerate() {
-                    if let Some(embedding) = embeddings.get(i) {
-                        let key =
-                            match crate::store::TicketStore::embedding_key(file_path, *mtime_ns) {
-                                Ok(k) => k,
-                                Err(e) => {
-                                    if !output.json {
-                                        eprintln!(
-                                            "Warning: failed to compute embedding key for {}: {}",
-                                            ticket_id.as_deref().unwrap_or("unknown"),
-                                            e
-                                        );
-                                    }
-                                    continue;
-                                }
-                            };
-                        if let Err(e) = crate::store::TicketStore::save_embedding(&key, embedding) {
-                            if !output.json {
-                                eprintln!(
-                                    "Warning: failed to save embedding for {}: {e}",
-                                    ticket_id.as_deref().unwrap_or("unknown")
-                                );
-                            }
-                        } else {
-                            valid_keys.insert(key);
-                            embedded_count += 1;
-                            if !output.json && embedded_count.is_multiple_of(10) {
-                                println!("  Progress: {embedded_count}/{ticket_count}");
-                            }
+                for ((ticket_id, text), embedding) in batch_data.iter().zip(&embeddings) {
+                    let key = crate::store::TicketStore::embedding_key(text);
+                    if let Err(e) = crate::store::TicketStore::save_embedding(&key, embedding) {
+                        if !output.json {
+                            eprintln!(
+                                "Warning: failed to save embedding for {}: {e}",
+                                ticket_id.as_deref().unwrap_or("unknown")
+                            );
+                        }
+                    } else {
+                        embedded_count += 1;
+                        if !output.json && embedded_count.is_multiple_of(10) {
+                            println!("  Progress: {embedded_count}/{ticket_count}");
                         }
                     }
                 }
@@ -273,6 +219,16 @@ pub async fn cmd_cache_rebuild(output: OutputOptions) -> Result<()> {
         }
     }
 
+    // Bring document chunk embeddings up to date; unchanged documents are skipped
+    let chunking = Config::load().map(|c| c.doc_chunking).unwrap_or_default();
+    if let Err(e) = store.sync_doc_embeddings(&chunking).await
+        && !output.json
+    {
+        eprintln!("Warning: failed to sync document embeddings: {e}");
+    }
+    let mut valid_keys = store.embedding_file_keys();
+    valid_keys.extend(crate::store::TicketStore::doc_embedding_file_keys());
+
     // Prune orphaned embedding files
     if let Err(e) = crate::store::TicketStore::prune_orphaned(&valid_keys)
         && !output.json
@@ -318,6 +274,45 @@ pub async fn cmd_cache_rebuild(output: OutputOptions) -> Result<()> {
     Ok(())
 }
 
+/// Bring embeddings up to date.
+///
+/// Tickets, objectives, plans (and their phases), and ticket notes are
+/// re-embedded only when their embedded text changed. Only documents whose content or chunking settings changed since
+/// the last sync are re-chunked, and only chunks whose text has no saved
+/// embedding are re-embedded.
+pub async fn cmd_cache_sync(output: OutputOptions) -> Result<()> {
+    let config = Config::load()?;
+    let store = get_or_init_store().await?;
+
+    let start = std::time::Instant::now();
+    let (tickets_embedded, _) = store.ensure_all_embeddings().await?;
+    let (objectives_embedded, _) = store.ensure_all_objective_embeddings().await?;
+    let (plans_embedded, _) = store.ensure_all_plan_embeddings().await?;
+    let (notes_embedded, _) = store.ensure_all_note_embeddings().await?;
+    let stats = store.sync_doc_embeddings(&config.doc_chunking).await?;
+    let duration = start.elapsed();
+
+    CommandOutput::new(json!({
+        "action": "cache_synced",
+        "tickets_embedded": tickets_embedded,
+        "objectives_embedded": objectives_embedded,
+        "plans_embedded": plans_embedded,
+        "notes_embedded": notes_embedded,
+        "doc_count": stats.docs,
+        "changed_docs": stats.changed,
+        "removed_docs": stats.removed,
+        "chunks_embedded": stats.embedded,
+        "chunks_reused": stats.reused,
+        "total_time_ms": duration.as_millis(),
+        "embedding_model": EMBEDDING_MODEL_NAME,
+    }))
+    .with_text(format!(
+        "Embeddings synced:\n  Tickets embedded: {tickets_embedded}\n  Objectives embedded: {objectives_embedded}\n  Plan sections embedded: {plans_embedded}\n  Notes embedded: {notes_embedded}\n  Documents: {}\n  Changed: {}\n  Removed: {}\n  Chunks embedded: {}\n  Chunks reused: {}\n  Total time: {duration:?}",
+        stats.docs, stats.changed, stats.removed, stats.embedded, stats.reused
+    ))
+    .print(output)
+}
+
 /// Calculate the total size of a directory in bytes.
 ///
 /// Recursively traverses subdirectories for robustness, even though the
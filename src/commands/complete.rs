@@ -0,0 +1,436 @@
+//! Dynamic shell completion support.
+//!
+//! `janus completions <shell>` emits the static clap-generated script followed by
+//! a small shell-specific wrapper. The wrapper works out which positional
+//! argument is being completed, skipping the values of flags such as
+//! `--status open`, and, for ticket IDs, plan IDs, and statuses,
+//! calls the hidden `janus _complete <kind> <prefix>` command to list candidates
+//! from the store instead of falling back to filename completion.
+
+use std::fmt::Write as _;
+
+use clap::Command;
+use clap_complete::Shell;
+
+use crate::error::{JanusError, Result};
+use crate::store::TicketStore;
+use crate::types::TicketStatus;
+
+/// The kind of value a completion request asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionKind {
+    Tickets,
+    Plans,
+    Statuses,
+}
+
+impl CompletionKind {
+    pub const ALL_STRINGS: &[&str] = &["tickets", "plans", "statuses"];
+}
+
+enum_display_fromstr!(
+    CompletionKind,
+    JanusError::invalid_completion_kind,
+    ["tickets", "plans", "statuses"],
+    {
+        Tickets => "tickets",
+        Plans => "plans",
+        Statuses => "statuses",
+    }
+);
+
+/// Print completion candidates for `kind` matching `prefix`, one per line.
+///
+/// IDs match when they start with the prefix or when their hash part (after the
+/// first `-`) does, so `a1` completes to `j-a1b2` just like the partial-ID
+/// resolution used by every other command. The store is loaded without
+/// generating embeddings to keep this fast enough to run on every keystroke.
+pub async fn cmd_complete(kind: CompletionKind, prefix: &str) -> Result<()> {
+    let candidates = match kind {
+        CompletionKind::Statuses => TicketStatus::ALL_STRINGS
+            .iter()
+            .filter(|s| s.starts_with(prefix))
+            .map(|s| s.to_string())
+            .collect(),
+        CompletionKind::Tickets => {
+            let store = TicketStore::init().await?;
+            filter_ids(store.get_all_ticket_ids(), prefix)
+        }
+        CompletionKind::Plans => {
+            let store = TicketStore::init().await?;
+            let ids = store.get_all_plans().into_iter().filter_map(|p| p.id);
+            filter_ids(ids, prefix)
+        }
+    };
+
+    for candidate in candidates {
+        println!("{candidate}");
+    }
+    Ok(())
+}
+
+fn filter_ids(ids: impl IntoIterator<Item = String>, prefix: &str) -> Vec<String> {
+    ids.into_iter()
+        .filter(|id| {
+            id.starts_with(prefix)
+                || id
+                    .split_once('-')
+                    .is_some_and(|(_, hash)| hash.starts_with(prefix))
+        })
+        .collect()
+}
+
+/// Which completion kind a positional argument takes, based on its name and
+/// the subcommand group it belongs to.
+fn positional_kind(group: Option<&str>, arg_id: &str) -> Option<CompletionKind> {
+    match (group, arg_id) {
+        (Some("objective" | "doc"), _) => None,
+        (_, "status") => Some(CompletionKind::Statuses),
+        (_, "plan_id") | (Some("plan"), "id") => Some(CompletionKind::Plans),
+        (_, "id" | "ids" | "id1" | "id2" | "dep_id" | "ticket_id") => Some(CompletionKind::Tickets),
+        _ => None,
+    }
+}
+
+/// A completable position: the subcommand path, the positional index, whether
+/// the argument is variadic, and the kind of value it takes.
+struct CompletionSlot {
+    path: String,
+    index: usize,
+    variadic: bool,
+    kind: CompletionKind,
+}
+
+/// Collected completion metadata for the whole command tree.
+#[derive(Default)]
+struct CompletionTable {
+    /// Subcommand paths that only dispatch to further subcommands
+    groups: Vec<String>,
+    slots: Vec<CompletionSlot>,
+    /// `path:flag` for every flag that takes a value, so the wrapper doesn't
+    /// count the value as a positional argument. The root command's flags
+    /// have an empty path.
+    value_flags: Vec<String>,
+}
+
+impl CompletionTable {
+    fn build(root: &Command) -> Self {
+        // Building propagates global flags such as `--color` to subcommands
+        let mut root = root.clone();
+        root.build();
+
+        let mut table = Self::default();
+        table.add_value_flags(&root, &[String::new()]);
+        for sub in root.get_subcommands() {
+            table.visit(sub, &[], None);
+        }
+        table
+    }
+
+    fn visit<'a>(&mut self, cmd: &'a Command, parents: &[String], group: Option<&'a str>) {
+        let names: Vec<&str> = std::iter::once(cmd.get_name())
+            .chain(cmd.get_visible_aliases())
+            .collect();
+        let paths: Vec<String> = if parents.is_empty() {
+            names.iter().map(|n| n.to_string()).collect()
+        } else {
+            parents
+                .iter()
+                .flat_map(|p| names.iter().map(move |n| format!("{p} {n}")))
+                .collect()
+        };
+        self.add_value_flags(cmd, &paths);
+
+        if cmd.has_subcommands() {
+            // Top-level groups (e.g. `plan`) decide how nested `id` arguments complete
+            let group = group.or_else(|| parents.is_empty().then_some(cmd.get_name()));
+            self.groups.extend(paths.iter().cloned());
+            for sub in cmd.get_subcommands() {
+                self.visit(sub, &paths, group);
+            }
+            return;
+        }
+
+        for (index, arg) in cmd.get_positionals().enumerate() {
+            let Some(kind) = positional_kind(group, arg.get_id().as_str()) else {
+                continue;
+            };
+            let variadic = arg.get_num_args().is_some_and(|n| n.max_values() > 1);
+            for path in &paths {
+                self.slots.push(CompletionSlot {
+                    path: path.clone(),
+                    index,
+                    variadic,
+                    kind,
+                });
+            }
+        }
+    }
+
+    fn add_value_flags(&mut self, cmd: &Command, paths: &[String]) {
+        for arg in cmd.get_arguments() {
+            if arg.is_positional() || !arg.get_action().takes_values() {
+                continue;
+            }
+            let longs = arg.get_long_and_visible_aliases().unwrap_or_default();
+            let shorts = arg.get_short_and_visible_aliases().unwrap_or_default();
+            let flags = longs
+                .into_iter()
+                .map(|long| format!("--{long}"))
+                .chain(shorts.into_iter().map(|short| format!("-{short}")));
+            for flag in flags {
+                for path in paths {
+                    self.value_flags.push(format!("'{path}:{flag}'"));
+                }
+            }
+        }
+    }
+
+    /// The `path:index` pattern a shell `case`/`switch` matches against.
+    fn pattern(slot: &CompletionSlot) -> String {
+        if slot.variadic {
+            format!("'{}:'*", slot.path)
+        } else {
+            format!("'{}:{}'", slot.path, slot.index)
+        }
+    }
+}
+
+/// Shell code that routes ID and status arguments to `janus _complete`.
+///
+/// Returns `None` for shells without dynamic completion support, which keep
+/// the static clap-generated script only.
+pub fn dynamic_completion_script(shell: Shell, cmd: &Command) -> Option<String> {
+    let table = CompletionTable::build(cmd);
+    match shell {
+        Shell::Bash => Some(posix_script(&table, false)),
+        Shell::Zsh => Some(posix_script(&table, true)),
+        Shell::Fish => Some(fish_script(&table)),
+        _ => None,
+    }
+}
+
+fn posix_script(table: &CompletionTable, zsh: bool) -> String {
+    let mut out = String::from("\n# Dynamic completion of ticket IDs, plan IDs, and statuses\n");
+
+    out.push_str("_janus_is_group() {\n    case \"$1\" in\n");
+    for group in &table.groups {
+        let _ = writeln!(out, "        '{group}') return 0 ;;");
+    }
+    out.push_str("    esac\n    return 1\n}\n\n");
+
+    out.push_str("_janus_takes_value() {\n    case \"$1:$2\" in\n");
+    for flag in &table.value_flags {
+        let _ = writeln!(out, "        {flag}) return 0 ;;");
+    }
+    out.push_str("    esac\n    return 1\n}\n\n");
+
+    out.push_str("_janus_complete_kind() {\n    case \"$1:$2\" in\n");
+    for slot in &table.slots {
+        let _ = writeln!(
+            out,
+            "        {}) echo {} ;;",
+            CompletionTable::pattern(slot),
+            slot.kind
+        );
+    }
+    out.push_str("    esac\n}\n\n");
+
+    // `path` is tied to $PATH in zsh, so the accumulator is named `cmdpath`
+    let (words, start, end, cur) = if zsh {
+        ("words", "2", "CURRENT", "$PREFIX")
+    } else {
+        ("COMP_WORDS", "1", "COMP_CWORD", "${COMP_WORDS[COMP_CWORD]}")
+    };
+    let _ = write!(
+        out,
+        r#"_janus_dynamic() {{
+    local cur="{cur}" cmdpath="" pos=0 i w kind="" skip=""
+    for ((i = {start}; i < {end}; i++)); do
+        w="${{{words}[i]}}"
+        if [[ -n "$skip" ]]; then
+            skip=""
+            continue
+        fi
+        if [[ "$w" == -* ]]; then
+            _janus_takes_value "$cmdpath" "$w" && skip=1
+            continue
+        fi
+        if [[ -z "$cmdpath" ]] || _janus_is_group "$cmdpath"; then
+            cmdpath="${{cmdpath:+$cmdpath }}$w"
+        else
+            pos=$((pos + 1))
+        fi
+    done
+    # `skip` still set means `cur` is a flag's value
+    if [[ -z "$skip" && "$cur" != -* ]]; then
+        kind="$(_janus_complete_kind "$cmdpath" "$pos")"
+    fi
+"#
+    );
+
+    if zsh {
+        out.push_str(
+            r#"    if [[ -n "$kind" ]]; then
+        local -a candidates
+        candidates=(${(f)"$(janus _complete "$kind" "$cur" 2>/dev/null)"})
+        compadd -U -- $candidates
+        return
+    fi
+    _janus "$@"
+}
+
+compdef _janus_dynamic janus
+"#,
+        );
+    } else {
+        out.push_str(
+            r#"    if [[ -n "$kind" ]]; then
+        COMPREPLY=($(janus _complete "$kind" "$cur" 2>/dev/null))
+        return 0
+    fi
+    _janus "$@"
+}
+
+complete -F _janus_dynamic -o bashdefault -o default janus
+"#,
+        );
+    }
+
+    out
+}
+
+fn fish_script(table: &CompletionTable) -> String {
+    let mut out = String::from("\n# Dynamic completion of ticket IDs, plan IDs, and statuses\n");
+
+    out.push_str("function __fish_janus_is_group\n    switch $argv[1]\n");
+    for group in &table.groups {
+        let _ = writeln!(out, "        case '{group}'\n            return 0");
+    }
+    out.push_str("    end\n    return 1\nend\n\n");
+
+    out.push_str("function __fish_janus_takes_value\n    switch \"$argv[1]:$argv[2]\"\n");
+    for flag in &table.value_flags {
+        let _ = writeln!(out, "        case {flag}\n            return 0");
+    }
+    out.push_str("    end\n    return 1\nend\n\n");
+
+    out.push_str("function __fish_janus_complete_kind\n    switch \"$argv[1]:$argv[2]\"\n");
+    for slot in &table.slots {
+        let _ = writeln!(
+            out,
+            "        case {}\n            echo {}",
+            CompletionTable::pattern(slot),
+            slot.kind
+        );
+    }
+    out.push_str("        case '*'\n            return 1\n    end\nend\n\n");
+
+    out.push_str(
+        r#"function __fish_janus_dynamic_kind
+    set -l tokens (commandline -opc)
+    set -e tokens[1]
+    set -l cmdpath ""
+    set -l pos 0
+    set -l skip 0
+    for w in $tokens
+        if test $skip -eq 1
+            set skip 0
+            continue
+        end
+        if string match -q -- '-*' $w
+            __fish_janus_takes_value "$cmdpath" $w; and set skip 1
+            continue
+        end
+        if test -z "$cmdpath"; or __fish_janus_is_group "$cmdpath"
+            set cmdpath (string trim -- "$cmdpath $w")
+        else
+            set pos (math $pos + 1)
+        end
+    end
+    # The token being completed is a flag's value
+    test $skip -eq 1; and return 1
+    __fish_janus_complete_kind "$cmdpath" $pos
+end
+
+complete -c janus -n '__fish_janus_dynamic_kind >/dev/null' -f -a '(janus _complete (__fish_janus_dynamic_kind) (commandline -ct) 2>/dev/null)'
+"#,
+    );
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Cli;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_completion_kind_roundtrip() {
+        for s in CompletionKind::ALL_STRINGS {
+            assert_eq!(s.parse::<CompletionKind>().unwrap().to_string(), *s);
+        }
+        assert!("bogus".parse::<CompletionKind>().is_err());
+    }
+
+    #[test]
+    fn test_filter_ids_matches_prefix_and_hash() {
+        let ids = vec![
+            "j-a1b2".to_string(),
+            "j-c3d4".to_string(),
+            "perf-a1ff".to_string(),
+        ];
+        assert_eq!(filter_ids(ids.clone(), "j-"), vec!["j-a1b2", "j-c3d4"]);
+        assert_eq!(filter_ids(ids.clone(), "a1"), vec!["j-a1b2", "perf-a1ff"]);
+        assert_eq!(filter_ids(ids, ""), vec!["j-a1b2", "j-c3d4", "perf-a1ff"]);
+    }
+
+    #[test]
+    fn test_completion_table_slots() {
+        let table = CompletionTable::build(&Cli::command());
+        let kind_of = |path: &str, index: usize| {
+            table
+                .slots
+                .iter()
+                .find(|s| s.path == path && s.index == index)
+                .map(|s| s.kind)
+        };
+
+        assert_eq!(kind_of("show", 0), Some(CompletionKind::Tickets));
+        assert_eq!(kind_of("s", 0), Some(CompletionKind::Tickets));
+        assert_eq!(kind_of("status", 1), Some(CompletionKind::Statuses));
+        assert_eq!(kind_of("dep add", 1), Some(CompletionKind::Tickets));
+        assert_eq!(kind_of("plan show", 0), Some(CompletionKind::Plans));
+        assert_eq!(kind_of("plan add-ticket", 1), Some(CompletionKind::Tickets));
+        assert!(table.groups.contains(&"plan".to_string()));
+        assert!(
+            table
+                .slots
+                .iter()
+                .any(|s| s.path == "link add" && s.variadic)
+        );
+    }
+
+    #[test]
+    fn test_completion_table_value_flags() {
+        let table = CompletionTable::build(&Cli::command());
+        let takes_value = |pattern: &str| table.value_flags.iter().any(|f| f == pattern);
+
+        assert!(takes_value("'ls:--status'"));
+        assert!(takes_value("':--color'"));
+        assert!(takes_value("'plan show:--color'"));
+        assert!(!takes_value("'ls:--json'"));
+    }
+
+    #[test]
+    fn test_dynamic_completion_script_shells() {
+        let cmd = Cli::command();
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let script = dynamic_completion_script(shell, &cmd).unwrap();
+            assert!(script.contains("janus _complete"));
+            assert!(script.contains("'plan show:0'"));
+        }
+        assert!(dynamic_completion_script(Shell::PowerShell, &cmd).is_none());
+    }
+}
@@ -2,15 +2,19 @@
 //!
 //! - `config set`: Set a configuration value
 //! - `config show`: Display current configuration
+//! - `config encrypt`: Encrypt stored authentication tokens
 
 use owo_colors::OwoColorize;
 use serde_json::json;
 
 use super::CommandOutput;
 use crate::cli::OutputOptions;
-use crate::config::Config;
+use std::path::PathBuf;
+
+use crate::config::{Config, EncryptionConfig};
 use crate::error::{JanusError, Result};
 use crate::remote::Platform;
+use crate::secrets::EncryptionTool;
 
 /// List of valid config keys
 const VALID_CONFIG_KEYS: &[&str] = &[
@@ -19,6 +23,7 @@ const VALID_CONFIG_KEYS: &[&str] = &[
     "default.remote",
     "semantic_search.enabled",
     "remote_timeout",
+    "git.auto_commit",
 ];
 
 /// Validate a config key is one of the known valid keys
@@ -57,8 +62,10 @@ pub fn cmd_config_show(output: OutputOptions) -> Result<()> {
         })
     });
 
-    let github_configured = config.github_token().is_some();
-    let linear_configured = config.linear_api_key().is_some();
+    // Tokens are not decrypted just to show whether they are set
+    let encrypted = config.encrypted_auth.is_some();
+    let github_configured = env_var_set("GITHUB_TOKEN") || config.auth.github.is_some();
+    let linear_configured = env_var_set("LINEAR_API_KEY") || config.auth.linear.is_some();
 
     // Build JSON output
     let json_output = json!({
@@ -66,11 +73,19 @@ pub fn cmd_config_show(output: OutputOptions) -> Result<()> {
         "auth": {
             "github_token_configured": github_configured,
             "linear_api_key_configured": linear_configured,
+            "encrypted": encrypted,
         },
         "semantic_search": {
             "enabled": config.semantic_search_enabled(),
         },
         "remote_timeout": config.remote_timeout().as_secs(),
+        "git": {
+            "auto_commit": config.git_auto_commit(),
+        },
+        "encryption": config.encryption.as_ref().map(|e| json!({
+            "tool": e.tool.to_string(),
+            "recipients": e.recipients,
+        })),
         "config_file": Config::config_path().to_string_lossy(),
     });
 
@@ -100,19 +115,27 @@ pub fn cmd_config_show(output: OutputOptions) -> Result<()> {
     // Auth status (don't show actual tokens)
     text_output.push_str(&format!("{}:\n", "auth".cyan()));
 
-    let github_status = if github_configured {
-        "configured".green().to_string()
-    } else {
-        "not configured".dimmed().to_string()
-    };
-    let linear_status = if linear_configured {
-        "configured".green().to_string()
-    } else {
-        "not configured".dimmed().to_string()
+    let token_status = |configured: bool| {
+        if configured {
+            "configured".green().to_string()
+        } else if encrypted {
+            "encrypted".green().to_string()
+        } else {
+            "not configured".dimmed().to_string()
+        }
     };
+    let github_status = token_status(github_configured);
+    let linear_status = token_status(linear_configured);
 
     text_output.push_str(&format!("  github.token: {github_status}\n"));
     text_output.push_str(&format!("  linear.api_key: {linear_status}\n"));
+    if let Some(ref encryption) = config.encryption {
+        text_output.push_str(&format!(
+            "  encrypted with {} ({} recipient(s))\n",
+            encryption.tool,
+            encryption.recipients.len()
+        ));
+    }
 
     text_output.push('\n');
 
@@ -132,6 +155,12 @@ pub fn cmd_config_show(output: OutputOptions) -> Result<()> {
         config.remote_timeout().as_secs()
     ));
 
+    text_output.push('\n');
+
+    // Git integration
+    text_output.push_str(&format!("{}:\n", "git".cyan()));
+    text_output.push_str(&format!("  auto_commit: {}\n", config.git_auto_commit()));
+
     text_output.push('\n');
     text_output.push_str(&format!(
         "{}",
@@ -151,7 +180,7 @@ pub fn cmd_config_set(key: &str, value: &str, output: OutputOptions) -> Result<(
 
     let (json_output, text_output) = match key {
         "github.token" => {
-            config.set_github_token(value.to_string());
+            config.set_github_token(value.to_string())?;
             config.save()?;
             let json = json!({
                 "action": "config_set",
@@ -162,7 +191,7 @@ pub fn cmd_config_set(key: &str, value: &str, output: OutputOptions) -> Result<(
             (json, text)
         }
         "linear.api_key" => {
-            config.set_linear_api_key(value.to_string());
+            config.set_linear_api_key(value.to_string())?;
             config.save()?;
             let json = json!({
                 "action": "config_set",
@@ -238,6 +267,23 @@ pub fn cmd_config_set(key: &str, value: &str, output: OutputOptions) -> Result<(
             let text = format!("Set {} to {} seconds", "remote_timeout".cyan(), timeout);
             (json, text)
         }
+        "git.auto_commit" => {
+            let enabled = value.parse::<bool>().map_err(|_| {
+                JanusError::Config(format!(
+                    "invalid value '{value}' for git.auto_commit. Expected: true or false"
+                ))
+            })?;
+            config.git.auto_commit = enabled;
+            config.save()?;
+            let json = json!({
+                "action": "config_set",
+                "key": key,
+                "value": enabled,
+                "success": true,
+            });
+            let text = format!("Set {} to {}", "git.auto_commit".cyan(), enabled);
+            (json, text)
+        }
         _ => {
             return Err(JanusError::Config(format!(
                 "unknown config key '{key}'. Valid keys: {}",
@@ -251,6 +297,64 @@ pub fn cmd_config_set(key: &str, value: &str, output: OutputOptions) -> Result<(
         .print(output)
 }
 
+/// Encrypt stored authentication tokens with age or gpg
+///
+/// Saving the config with encryption enabled replaces any plaintext `auth`
+/// section with ciphertext, so this also serves as the migration step.
+pub fn cmd_config_encrypt(
+    tool: EncryptionTool,
+    recipients: Vec<String>,
+    identity: Option<PathBuf>,
+    output: OutputOptions,
+) -> Result<()> {
+    let mut config = Config::load()?;
+    let had_secrets = config.has_stored_auth();
+
+    let encryption = EncryptionConfig {
+        tool,
+        recipients,
+        identity,
+    };
+    // Without an identity the remote commands would fail to decrypt
+    if tool == EncryptionTool::Age && encryption.identity_path().is_none() {
+        return Err(JanusError::Config(
+            "age encryption requires --identity <FILE> or JANUS_AGE_IDENTITY for decryption"
+                .to_string(),
+        ));
+    }
+
+    config.set_encryption(encryption)?;
+    config.save()?;
+
+    let recipient_count = config.encryption.as_ref().map_or(0, |e| e.recipients.len());
+    let text = if had_secrets {
+        format!(
+            "Encrypted {} with {tool} for {recipient_count} recipient(s)",
+            "auth".cyan()
+        )
+    } else {
+        format!(
+            "Enabled {} encryption with {tool}; tokens set later will be encrypted",
+            "auth".cyan()
+        )
+    };
+
+    CommandOutput::new(json!({
+        "action": "config_encrypt",
+        "tool": tool.to_string(),
+        "recipients": recipient_count,
+        "encrypted_existing_secrets": had_secrets,
+        "success": true,
+    }))
+    .with_text(text)
+    .print(output)
+}
+
+/// Whether an environment variable is set to a non-empty value
+fn env_var_set(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|value| !value.is_empty())
+}
+
 /// Parse a default_remote value like "github:myorg/myrepo" or "linear:myorg"
 fn parse_default_remote(value: &str) -> Result<(Platform, String)> {
     let parts: Vec<&str> = value.splitn(2, ':').collect();
@@ -280,7 +384,7 @@ pub fn cmd_config_get(key: &str, output: OutputOptions) -> Result<()> {
 
     let (json_output, text_output) = match key {
         "github.token" => {
-            if let Some(token) = config.github_token() {
+            if let Some(token) = config.github_token()? {
                 let masked = mask_sensitive_value(&token);
                 let json = json!({
                     "key": key,
@@ -295,7 +399,7 @@ pub fn cmd_config_get(key: &str, output: OutputOptions) -> Result<()> {
             }
         }
         "linear.api_key" => {
-            if let Some(api_key) = config.linear_api_key() {
+            if let Some(api_key) = config.linear_api_key()? {
                 let masked = mask_sensitive_value(&api_key);
                 let json = json!({
                     "key": key,
@@ -347,6 +451,16 @@ pub fn cmd_config_get(key: &str, output: OutputOptions) -> Result<()> {
             let text = format!("{timeout} seconds");
             (json, text)
         }
+        "git.auto_commit" => {
+            let enabled = config.git_auto_commit();
+            let json = json!({
+                "key": key,
+                "value": enabled,
+                "configured": true,
+            });
+            let text = enabled.to_string();
+            (json, text)
+        }
         _ => {
             return Err(JanusError::Config(format!(
                 "unknown config key '{key}'. Valid keys: {}",
@@ -0,0 +1,363 @@
+//! Context bundle command: everything a coding agent needs to work on a
+//! ticket, gathered into one markdown document (or JSON object).
+//!
+//! The bundle contains the ticket itself, its parents and dependencies up to
+//! `--depth` levels, related documents, the plan phase it belongs to, and its
+//! most recent notes.
+
+use std::collections::{HashMap, HashSet};
+
+use serde_json::{Value, json};
+
+use super::{CommandOutput, ticket_minimal_json, ticket_to_json};
+use crate::cli::OutputOptions;
+use crate::doc::Doc;
+use crate::error::Result;
+use crate::parser::{parse_document_raw, remove_section_from_body};
+use crate::plan::{PlanMetadata, PlanSection, get_all_plans};
+use crate::store::get_or_init_store;
+use crate::ticket::{Note, Ticket, build_ticket_map, parse_notes};
+use crate::types::TicketMetadata;
+
+/// A related ticket and how many hops away from the bundled ticket it is.
+struct Related<'a> {
+    ticket: &'a TicketMetadata,
+    depth: usize,
+    description: Option<String>,
+}
+
+/// Emit a context bundle for a ticket
+///
+/// `depth` limits how far the parent chain and dependency graph are followed
+/// (1 = direct parent and deps). `notes` is the number of most recent notes
+/// to include.
+pub async fn cmd_context(
+    id: &str,
+    depth: usize,
+    notes: usize,
+    output: OutputOptions,
+) -> Result<()> {
+    let (ticket, metadata) = Ticket::find_and_read(id).await?;
+    let ticket_map = build_ticket_map().await?;
+
+    let (_frontmatter, body) = parse_document_raw(&ticket.read_content()?)?;
+    let main_body = remove_section_from_body(&body, "Notes");
+    let notes = recent_notes(&body, notes);
+
+    let parents = parent_chain(&metadata, &ticket_map, depth)?;
+    let deps = dependency_tree(&metadata, &ticket_map, depth);
+    let docs = related_doc_bodies(&ticket.id, &metadata.docs).await?;
+
+    let plans: Vec<PlanMetadata> = get_all_plans()
+        .await?
+        .items
+        .into_iter()
+        .filter(|p| p.all_tickets().contains(&ticket.id.as_str()))
+        .collect();
+
+    let json = json!({
+        "ticket": {
+            "metadata": ticket_to_json(&metadata),
+            "body": main_body.trim(),
+        },
+        "parents": parents.iter().map(|r| {
+            let mut value = ticket_minimal_json(r.ticket);
+            value["depth"] = json!(r.depth);
+            value["description"] = json!(r.description);
+            value
+        }).collect::<Vec<_>>(),
+        "deps": deps.iter().map(|r| {
+            let mut value = ticket_minimal_json(r.ticket);
+            value["depth"] = json!(r.depth);
+            value["completion_summary"] = json!(r.ticket.completion_summary);
+            value
+        }).collect::<Vec<_>>(),
+        "docs": docs.iter().map(|(label, title, content)| {
+            json!({"label": label, "title": title, "content": content})
+        }).collect::<Vec<_>>(),
+        "plans": plans.iter().map(|p| plan_json(p, &ticket.id, &ticket_map)).collect::<Vec<_>>(),
+        "notes": notes.iter().map(|n| {
+            json!({"timestamp": n.timestamp, "text": n.text})
+        }).collect::<Vec<_>>(),
+    });
+
+    let markdown = render_markdown(
+        &main_body,
+        &parents,
+        &deps,
+        &docs,
+        &plans,
+        &ticket.id,
+        &ticket_map,
+        &notes,
+    );
+
+    CommandOutput::new(json).with_text(markdown).print(output)
+}
+
+/// Parents of the ticket, nearest first.
+fn parent_chain<'a>(
+    metadata: &TicketMetadata,
+    ticket_map: &'a HashMap<String, TicketMetadata>,
+    depth: usize,
+) -> Result<Vec<Related<'a>>> {
+    let mut parents = Vec::new();
+    let mut next = metadata.parent.as_deref();
+    while let Some(parent_id) = next
+        && parents.len() < depth
+    {
+        let Some(parent) = ticket_map.get(parent_id) else {
+            break;
+        };
+        let description = match &parent.file_path {
+            Some(path) => Ticket::new(path.clone())?.extract_description()?,
+            None => None,
+        };
+        parents.push(Related {
+            ticket: parent,
+            depth: parents.len() + 1,
+            description,
+        });
+        next = parent.parent.as_deref();
+    }
+    Ok(parents)
+}
+
+/// Dependencies of the ticket, breadth first, each listed once.
+fn dependency_tree<'a>(
+    metadata: &TicketMetadata,
+    ticket_map: &'a HashMap<String, TicketMetadata>,
+    depth: usize,
+) -> Vec<Related<'a>> {
+    let mut deps = Vec::new();
+    let mut seen: HashSet<&str> = metadata.id.as_deref().into_iter().collect();
+    let mut level: Vec<&str> = metadata.deps.iter().map(|d| d.as_ref()).collect();
+
+    for current_depth in 1..=depth {
+        let mut next_level = Vec::new();
+        for dep_id in level {
+            if !seen.insert(dep_id) {
+                continue;
+            }
+            let Some(dep) = ticket_map.get(dep_id) else {
+                continue;
+            };
+            next_level.extend(dep.deps.iter().map(|d| d.as_ref()));
+            deps.push(Related {
+                ticket: dep,
+                depth: current_depth,
+                description: None,
+            });
+        }
+        level = next_level;
+    }
+    deps
+}
+
+/// Label, title, and body (without frontmatter) of each related document.
+async fn related_doc_bodies(
+    ticket_id: &str,
+    ticket_docs: &[String],
+) -> Result<Vec<(String, Option<String>, String)>> {
+    let store = get_or_init_store().await?;
+    let all_docs: Vec<_> = store.docs().iter().map(|e| e.value().clone()).collect();
+
+    let mut docs = Vec::new();
+    for label in crate::doc::related_docs(ticket_id, ticket_docs, &all_docs) {
+        // Dangling labels are reported by `janus show` and `janus doctor`
+        let Ok(doc) = Doc::find(&label).await else {
+            continue;
+        };
+        let (_frontmatter, body) = parse_document_raw(&doc.read_content()?)?;
+        let title = doc.read()?.title;
+        docs.push((label, title, body.trim().to_string()));
+    }
+    Ok(docs)
+}
+
+/// The last `limit` notes from the Notes section, oldest first.
+fn recent_notes(body: &str, limit: usize) -> Vec<Note> {
+    let notes = parse_notes(body);
+    let skip = notes.len().saturating_sub(limit);
+    notes.into_iter().skip(skip).collect()
+}
+
+/// The phase (or simple plan ticket list) that contains `ticket_id`.
+fn plan_json(
+    plan: &PlanMetadata,
+    ticket_id: &str,
+    ticket_map: &HashMap<String, TicketMetadata>,
+) -> Value {
+    let tickets_json = |ids: &[String]| -> Vec<Value> {
+        ids.iter()
+            .map(|id| match ticket_map.get(id) {
+                Some(t) => ticket_minimal_json(t),
+                None => json!({"id": id, "title": null, "status": null}),
+            })
+            .collect()
+    };
+
+    let mut value = json!({
+        "id": plan.id,
+        "title": plan.title,
+        "description": plan.description,
+        "phase": null,
+        "tickets": [],
+    });
+    for section in &plan.sections {
+        match section {
+            PlanSection::Phase(phase) if phase.contains_ticket(ticket_id) => {
+                value["phase"] = json!({
+                    "number": phase.number,
+                    "name": phase.name,
+                    "description": phase.description,
+                    "success_criteria": phase.success_criteria,
+                });
+                value["tickets"] = json!(tickets_json(phase.tickets()));
+            }
+            PlanSection::Tickets(ts) if ts.ticket_list.contains_ticket(ticket_id) => {
+                value["tickets"] = json!(tickets_json(ts.ticket_list.tickets()));
+            }
+            _ => {}
+        }
+    }
+    value
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_markdown(
+    main_body: &str,
+    parents: &[Related],
+    deps: &[Related],
+    docs: &[(String, Option<String>, String)],
+    plans: &[PlanMetadata],
+    ticket_id: &str,
+    ticket_map: &HashMap<String, TicketMetadata>,
+    notes: &[Note],
+) -> String {
+    let mut out = vec![main_body.trim().to_string()];
+
+    for parent in parents {
+        let mut section = format!(
+            "## Parent: {} {}\n\nStatus: {}",
+            parent.ticket.id.as_deref().unwrap_or(""),
+            parent.ticket.title.as_deref().unwrap_or(""),
+            parent.ticket.status.unwrap_or_default()
+        );
+        if let Some(description) = &parent.description {
+            section.push_str(&format!("\n\n{description}"));
+        }
+        out.push(section);
+    }
+
+    if !deps.is_empty() {
+        let lines: Vec<String> = deps
+            .iter()
+            .map(|dep| {
+                let indent = "  ".repeat(dep.depth - 1);
+                let mut line = format!(
+                    "{indent}- {} [{}] {}",
+                    dep.ticket.id.as_deref().unwrap_or(""),
+                    dep.ticket.status.unwrap_or_default(),
+                    dep.ticket.title.as_deref().unwrap_or("")
+                );
+                if let Some(summary) = &dep.ticket.completion_summary {
+                    line.push_str(&format!(": {}", summary.lines().next().unwrap_or("")));
+                }
+                line
+            })
+            .collect();
+        out.push(format!("## Dependencies\n\n{}", lines.join("\n")));
+    }
+
+    for plan in plans {
+        let value = plan_json(plan, ticket_id, ticket_map);
+        let mut section = format!(
+            "## Plan: {} {}",
+            plan.id.as_deref().unwrap_or(""),
+            plan.title.as_deref().unwrap_or("")
+        );
+        if let Some(description) = &plan.description {
+            section.push_str(&format!("\n\n{}", description.trim()));
+        }
+        if let Some(phase) = value["phase"].as_object() {
+            section.push_str(&format!(
+                "\n\n### Phase {}: {}",
+                phase["number"].as_str().unwrap_or(""),
+                phase["name"].as_str().unwrap_or("")
+            ));
+            if let Some(description) = phase["description"].as_str() {
+                section.push_str(&format!("\n\n{}", description.trim()));
+            }
+            if let Some(criteria) = phase["success_criteria"].as_array()
+                && !criteria.is_empty()
+            {
+                section.push_str("\n\nSuccess criteria:\n");
+                for criterion in criteria {
+                    section.push_str(&format!("\n- {}", criterion.as_str().unwrap_or("")));
+                }
+            }
+        }
+        if let Some(tickets) = value["tickets"].as_array()
+            && !tickets.is_empty()
+        {
+            section.push_str("\n\nTickets:\n");
+            for (i, t) in tickets.iter().enumerate() {
+                let id = t["id"].as_str().unwrap_or("");
+                let marker = if id == ticket_id {
+                    " (this ticket)"
+                } else {
+                    ""
+                };
+                section.push_str(&format!(
+                    "\n{}. {} [{}] {}{marker}",
+                    i + 1,
+                    id,
+                    t["status"].as_str().unwrap_or("missing"),
+                    t["title"].as_str().unwrap_or("")
+                ));
+            }
+        }
+        out.push(section);
+    }
+
+    for (label, title, content) in docs {
+        out.push(format!(
+            "## Doc: {label}{}\n\n{content}",
+            title
+                .as_deref()
+                .map(|t| format!(" ({t})"))
+                .unwrap_or_default()
+        ));
+    }
+
+    if !notes.is_empty() {
+        let notes: Vec<String> = notes
+            .iter()
+            .map(|n| match &n.timestamp {
+                Some(ts) => format!("**{ts}**\n\n{}", n.text),
+                None => n.text.clone(),
+            })
+            .collect();
+        out.push(format!("## Recent Notes\n\n{}", notes.join("\n\n")));
+    }
+
+    out.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_notes() {
+        let body = "# Title\n\n## Notes\n\nContext before any note\n\n**2024-06-01T10:00:00Z**\n\nFirst note\nspans lines\n\n**2024-06-02T10:00:00Z**\n\nSecond **bold** note\n";
+        let notes = recent_notes(body, 10);
+        assert_eq!(notes.len(), 3);
+        assert_eq!(notes[0].timestamp, None);
+        assert_eq!(notes[1].timestamp.as_deref(), Some("2024-06-01T10:00:00Z"));
+        assert_eq!(notes[1].text, "First note\nspans lines");
+        assert_eq!(notes[2].text, "Second **bold** note");
+        assert_eq!(recent_notes(body, 1).len(), 1);
+    }
+}
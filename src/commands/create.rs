@@ -1,15 +1,26 @@
+use std::path::PathBuf;
+
 use serde_json::json;
 
-use super::CommandOutput;
+use super::interactive::{fuzzy_pick, prompt_parsed, prompt_text, select_option};
+use super::{CommandOutput, cmd_dep_add, cmd_plan_add_ticket};
 use crate::cli::OutputOptions;
-use crate::error::Result;
-use crate::ticket::{Ticket, TicketBuilder, parse_ticket};
-use crate::types::{TicketPriority, TicketSize, TicketType, tickets_items_dir};
+use crate::component::suggest_component;
+use crate::config::Config;
+use crate::error::{JanusError, Result};
+use crate::parser::{TITLE_RE, remove_section_from_body};
+use crate::plan::get_all_plans;
+use crate::store::get_or_init_store;
+use crate::ticket::{Ticket, TicketBuilder, extract_ticket_section, parse_ticket};
+use crate::types::{TicketPriority, TicketSection, TicketSize, TicketType, tickets_items_dir};
 use crate::utils::validation::validate_ticket_title;
+use crate::utils::{is_stdin_tty, read_stdin};
 
 /// Options for the `create` command, bundling all parameters.
 pub struct CreateOptions {
     pub title: String,
+    /// Fill the title and sections from markdown piped to stdin
+    pub from_stdin: bool,
     pub description: Option<String>,
     pub design: Option<String>,
     pub acceptance: Option<String>,
@@ -22,6 +33,7 @@ pub struct CreateOptions {
     pub spawn_context: Option<String>,
     pub size: Option<TicketSize>,
     pub labels: Option<Vec<String>>,
+    pub component: Option<String>,
     pub output: OutputOptions,
 }
 
@@ -48,10 +60,100 @@ fn compute_depth(canonical_id: Option<&str>) -> Option<u32> {
     Some(1)
 }
 
+/// Title and sections of a ticket written as markdown
+#[derive(Debug, Default, PartialEq)]
+struct MarkdownTicket {
+    title: Option<String>,
+    description: Option<String>,
+    design: Option<String>,
+    acceptance: Option<String>,
+}
+
+/// Split a markdown ticket into its parts.
+///
+/// The first H1 is the title. The design and acceptance criteria sections are
+/// taken out under any of their recognized headings, and everything else after
+/// the title, including other H2 sections, is the description.
+fn parse_markdown_ticket(markdown: &str) -> Result<MarkdownTicket> {
+    let markdown = markdown.replace("\r\n", "\n");
+    let (title, body) = match TITLE_RE.captures(&markdown) {
+        Some(caps) => (
+            caps.get(1).map(|m| m.as_str().trim().to_string()),
+            &markdown[caps.get(0).map_or(0, |m| m.end())..],
+        ),
+        None => (None, markdown.as_str()),
+    };
+
+    let design = extract_ticket_section(body, TicketSection::Design)?;
+    let acceptance = extract_ticket_section(body, TicketSection::Acceptance)?;
+    let mut rest = body.to_string();
+    for section in [TicketSection::Design, TicketSection::Acceptance] {
+        for heading in section.headings() {
+            rest = remove_section_from_body(&rest, heading);
+        }
+    }
+    let description = rest.trim();
+
+    Ok(MarkdownTicket {
+        title: title.filter(|t| !t.is_empty()),
+        description: (!description.is_empty()).then(|| description.to_string()),
+        design,
+        acceptance,
+    })
+}
+
+/// Fill the options from markdown piped to stdin, keeping explicit values
+fn merge_stdin(opts: &mut CreateOptions) -> Result<()> {
+    if is_stdin_tty() {
+        return Err(JanusError::InvalidInput(
+            "--from-stdin expects markdown piped to stdin: cat bug.md | janus create --from-stdin"
+                .to_string(),
+        ));
+    }
+    let piped = parse_markdown_ticket(&read_stdin()?)?;
+    if opts.title.is_empty() {
+        opts.title = piped.title.unwrap_or_default();
+    }
+    opts.description = opts.description.take().or(piped.description);
+    opts.design = opts.design.take().or(piped.design);
+    opts.acceptance = opts.acceptance.take().or(piped.acceptance);
+    Ok(())
+}
+
 /// Create a new ticket and print its ID
-pub async fn cmd_create(opts: CreateOptions) -> Result<()> {
+pub async fn cmd_create(mut opts: CreateOptions) -> Result<()> {
+    if opts.from_stdin {
+        merge_stdin(&mut opts)?;
+    }
+    let output = opts.output;
+    let (title, ticket_type, priority) = (opts.title.clone(), opts.ticket_type, opts.priority);
+    let created = create_ticket(opts).await?;
+
+    CommandOutput::new(json!({
+        "id": created.id,
+        "title": title,
+        "status": "new",
+        "type": ticket_type.to_string(),
+        "priority": priority.as_num(),
+        "component": created.component,
+        "file_path": created.file_path.to_string_lossy(),
+    }))
+    .with_text(&created.id)
+    .print(output)
+}
+
+/// A ticket written by [`create_ticket`]
+pub(super) struct CreatedTicket {
+    pub(super) id: String,
+    pub(super) file_path: PathBuf,
+    component: Option<String>,
+}
+
+/// Validate the options and write the new ticket
+pub(super) async fn create_ticket(opts: CreateOptions) -> Result<CreatedTicket> {
     let CreateOptions {
         title,
+        from_stdin: _,
         description,
         design,
         acceptance,
@@ -64,7 +166,8 @@ pub async fn cmd_create(opts: CreateOptions) -> Result<()> {
         spawn_context,
         size,
         labels,
-        output,
+        component,
+        output: _,
     } = opts;
 
     // Validate title using shared validation rules
@@ -87,6 +190,29 @@ pub async fn cmd_create(opts: CreateOptions) -> Result<()> {
     // Auto-compute depth if spawned_from is provided
     let depth = compute_depth(resolved_spawned_from.as_deref());
 
+    // Fall back to a suggested component when `components.suggest` is on
+    let config = Config::load().ok();
+    let component = component.or_else(|| match suggest_component(&config.as_ref()?.components) {
+        Ok(Some((component, source))) => {
+            eprintln!("Component: {component} (from {source}; pass --component to override)");
+            Some(component)
+        }
+        Ok(None) => None,
+        Err(e) => {
+            eprintln!("Warning: could not suggest a component: {e}");
+            None
+        }
+    });
+
+    // Without --prefix, use the component's prefix from `components.prefixes`
+    let prefix = prefix.or_else(|| {
+        let prefix = config
+            .as_ref()?
+            .components
+            .prefix_for(component.as_deref()?)?;
+        Some(prefix.to_string())
+    });
+
     let (id, file_path) = TicketBuilder::new(&title)
         .description(description.as_deref())
         .design(design.as_deref())
@@ -101,19 +227,149 @@ pub async fn cmd_create(opts: CreateOptions) -> Result<()> {
         .depth(depth)
         .size(size)
         .labels(labels.unwrap_or_default())
+        .component(component.as_deref())
         .run_hooks(true)
         .build()?;
 
     // Event logging is now handled in TicketBuilder::build() at the domain layer
 
-    CommandOutput::new(json!({
-        "id": id,
-        "title": title,
-        "status": "new",
-        "type": ticket_type.to_string(),
-        "priority": priority.as_num(),
-        "file_path": file_path.to_string_lossy(),
-    }))
-    .with_text(&id)
-    .print(output)
+    Ok(CreatedTicket {
+        id,
+        file_path,
+        component,
+    })
+}
+
+/// Create a ticket by walking through its fields (`janus create --interactive`
+/// or `janus new`).
+///
+/// Prompts for the title, type, priority, and description, then offers fuzzy
+/// pickers for dependencies among open tickets and for a plan (and phase) to
+/// add the ticket to.
+pub async fn cmd_create_interactive(output: OutputOptions) -> Result<()> {
+    if !is_stdin_tty() {
+        return Err(JanusError::NotInteractive(
+            "the creation wizard needs a terminal; pass a title to `janus create` instead"
+                .to_string(),
+        ));
+    }
+
+    let title = loop {
+        let title = prompt_text("Title", None)?;
+        match validate_ticket_title(&title) {
+            Ok(()) => break title,
+            // An empty answer (or end of input) gives up rather than asking forever
+            Err(e) if title.is_empty() => return Err(e),
+            Err(e) => println!("{e}"),
+        }
+    };
+    let ticket_type = prompt_parsed("Type", TicketType::default(), TicketType::ALL_STRINGS)?;
+    let priority = prompt_parsed(
+        "Priority",
+        TicketPriority::default(),
+        TicketPriority::ALL_STRINGS,
+    )?;
+    let description = prompt_text("Description (optional)", None)?;
+
+    let store = get_or_init_store().await?;
+    let mut open_tickets: Vec<(String, String)> = store
+        .tickets()
+        .iter()
+        .filter(|r| !r.value().status.unwrap_or_default().is_terminal())
+        .map(|r| (r.key().clone(), r.value().title.clone().unwrap_or_default()))
+        .collect();
+    open_tickets.sort();
+
+    let mut deps: Vec<String> = Vec::new();
+    if !open_tickets.is_empty() {
+        while let Some(dep) = fuzzy_pick(
+            "Depends on (search tickets, empty when done)",
+            &open_tickets,
+        )? {
+            if !deps.contains(&dep) {
+                deps.push(dep);
+            }
+        }
+    }
+
+    let plans = get_all_plans().await?.items;
+    let plan_choices: Vec<(String, String)> = plans
+        .iter()
+        .filter_map(|p| Some((p.id.as_deref()?.to_string(), p.title.clone()?)))
+        .collect();
+    let plan = if plan_choices.is_empty() {
+        None
+    } else {
+        fuzzy_pick("Add to plan (search plans, empty to skip)", &plan_choices)?
+    };
+    let phase = match plan
+        .as_deref()
+        .and_then(|plan_id| plans.iter().find(|p| p.id.as_deref() == Some(plan_id)))
+    {
+        Some(plan) if plan.is_phased() => {
+            let phases = plan.phases();
+            let names: Vec<String> = phases
+                .iter()
+                .map(|phase| format!("Phase {}: {}", phase.number, phase.name))
+                .collect();
+            for (i, name) in names.iter().enumerate() {
+                println!("  [{i}] {name}");
+            }
+            let options: Vec<&str> = names.iter().map(String::as_str).collect();
+            let choice = select_option("Phase", &options, Some(0))?;
+            Some(phases[choice].number.clone())
+        }
+        _ => None,
+    };
+
+    let created = create_ticket(CreateOptions {
+        title,
+        from_stdin: false,
+        description: Some(description).filter(|d| !d.is_empty()),
+        design: None,
+        acceptance: None,
+        priority,
+        ticket_type,
+        external_ref: None,
+        parent: None,
+        prefix: None,
+        spawned_from: None,
+        spawn_context: None,
+        size: None,
+        labels: None,
+        component: None,
+        output,
+    })
+    .await?;
+    println!("Created {}", created.id);
+
+    for dep in &deps {
+        cmd_dep_add(&created.id, dep, output).await?;
+    }
+    if let Some(plan) = plan {
+        cmd_plan_add_ticket(&plan, &created.id, phase.as_deref(), None, None, output).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_markdown_ticket() {
+        let markdown = "# Login fails on Safari\n\nUsers see a blank page.\n\n## Steps to Reproduce\n\n1. Open Safari\n\n## Acceptance\n\n- [ ] Login works\n\n## Design Notes\n\nCheck the cookie flags\n";
+        let ticket = parse_markdown_ticket(markdown).unwrap();
+        assert_eq!(ticket.title.as_deref(), Some("Login fails on Safari"));
+        assert_eq!(
+            ticket.description.as_deref(),
+            Some("Users see a blank page.\n\n## Steps to Reproduce\n\n1. Open Safari")
+        );
+        assert_eq!(ticket.design.as_deref(), Some("Check the cookie flags"));
+        assert_eq!(ticket.acceptance.as_deref(), Some("- [ ] Login works"));
+
+        let ticket = parse_markdown_ticket("Just some text\n").unwrap();
+        assert_eq!(ticket.title, None);
+        assert_eq!(ticket.description.as_deref(), Some("Just some text"));
+    }
 }
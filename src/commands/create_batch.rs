@@ -0,0 +1,359 @@
+//! `janus create --batch`: create many tickets from one YAML or JSON file.
+//!
+//! Tickets in the file can name each other as `parent` or in `deps` by a
+//! symbolic `name`, since their IDs are only assigned when they are created.
+//! A reference that is not a name in the file is resolved as an existing
+//! ticket ID. Every reference is checked before anything is written, and the
+//! tickets are then created in a single transaction.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde_json::json;
+
+use super::CommandOutput;
+use super::create::{CreateOptions, create_ticket};
+use crate::cli::OutputOptions;
+use crate::error::{JanusError, Result};
+use crate::fs::transaction;
+use crate::ticket::{ArrayField, Ticket};
+use crate::types::{TicketPriority, TicketSize, TicketType, validate_label};
+use crate::utils::validation::validate_ticket_title;
+
+/// Contents of a batch file
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BatchFile {
+    tickets: Vec<BatchTicket>,
+}
+
+/// One ticket to create, with the same fields as `janus create`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct BatchTicket {
+    /// Symbolic name other tickets in the file refer to this one by
+    name: Option<String>,
+    title: String,
+    description: Option<String>,
+    design: Option<String>,
+    acceptance: Option<String>,
+    #[serde(rename = "type", default)]
+    ticket_type: TicketType,
+    #[serde(default)]
+    priority: TicketPriority,
+    size: Option<TicketSize>,
+    labels: Option<Vec<String>>,
+    component: Option<String>,
+    prefix: Option<String>,
+    external_ref: Option<String>,
+    /// Name in the file or existing ticket ID
+    parent: Option<String>,
+    /// Names in the file or existing ticket IDs
+    #[serde(default)]
+    deps: Vec<String>,
+}
+
+/// A resolved `parent` or `deps` entry
+#[derive(Debug, Clone, PartialEq)]
+enum TicketRef {
+    /// Index of a ticket in the batch
+    Batch(usize),
+    /// ID of an existing ticket
+    Existing(String),
+}
+
+fn invalid(message: impl Into<String>) -> JanusError {
+    JanusError::InvalidBatch(message.into())
+}
+
+/// Parse a batch file. JSON is accepted as a subset of YAML.
+fn parse_batch(content: &str) -> Result<Vec<BatchTicket>> {
+    let batch: BatchFile = serde_yaml_ng::from_str(content).map_err(|e| invalid(e.to_string()))?;
+    if batch.tickets.is_empty() {
+        return Err(invalid("no tickets to create"));
+    }
+    Ok(batch.tickets)
+}
+
+/// Map each name to the index of its ticket, rejecting duplicates
+fn index_names(tickets: &[BatchTicket]) -> Result<HashMap<&str, usize>> {
+    let mut names = HashMap::new();
+    for (i, ticket) in tickets.iter().enumerate() {
+        if let Some(name) = ticket.name.as_deref()
+            && names.insert(name, i).is_some()
+        {
+            return Err(invalid(format!("name '{name}' is used more than once")));
+        }
+    }
+    Ok(names)
+}
+
+async fn resolve_ref(
+    reference: &str,
+    names: &HashMap<&str, usize>,
+    title: &str,
+) -> Result<TicketRef> {
+    if let Some(&i) = names.get(reference) {
+        return Ok(TicketRef::Batch(i));
+    }
+    Ticket::resolve_partial_id(reference)
+        .await
+        .map(TicketRef::Existing)
+        .map_err(|_| {
+            invalid(format!(
+                "'{title}' refers to '{reference}', which is neither a name in the batch nor an existing ticket"
+            ))
+        })
+}
+
+/// Order the batch so every ticket comes after its parent
+fn creation_order(tickets: &[BatchTicket], parents: &[Option<TicketRef>]) -> Result<Vec<usize>> {
+    let mut order = Vec::with_capacity(tickets.len());
+    let mut placed = vec![false; tickets.len()];
+    for start in 0..tickets.len() {
+        let mut chain = Vec::new();
+        let mut current = Some(start);
+        while let Some(i) = current.filter(|&i| !placed[i]) {
+            if chain.contains(&i) {
+                return Err(invalid(format!(
+                    "'{}' is its own ancestor through parent references",
+                    tickets[i].title
+                )));
+            }
+            chain.push(i);
+            current = match parents[i] {
+                Some(TicketRef::Batch(parent)) => Some(parent),
+                _ => None,
+            };
+        }
+        for &i in chain.iter().rev() {
+            placed[i] = true;
+            order.push(i);
+        }
+    }
+    Ok(order)
+}
+
+/// Reject dependency cycles among tickets in the batch. Existing tickets
+/// cannot depend on new ones, so no cycle can pass through them.
+fn check_dep_cycles(tickets: &[BatchTicket], deps: &[Vec<TicketRef>]) -> Result<()> {
+    fn visit(
+        i: usize,
+        deps: &[Vec<TicketRef>],
+        state: &mut [u8],
+        tickets: &[BatchTicket],
+    ) -> Result<()> {
+        match state[i] {
+            1 => {
+                return Err(invalid(format!(
+                    "dependencies of '{}' form a cycle",
+                    tickets[i].title
+                )));
+            }
+            2 => return Ok(()),
+            _ => {}
+        }
+        state[i] = 1;
+        for dep in &deps[i] {
+            if let TicketRef::Batch(d) = dep {
+                visit(*d, deps, state, tickets)?;
+            }
+        }
+        state[i] = 2;
+        Ok(())
+    }
+
+    // 0 = unvisited, 1 = on the current path, 2 = done
+    let mut state = vec![0u8; tickets.len()];
+    for i in 0..tickets.len() {
+        visit(i, deps, &mut state, tickets)?;
+    }
+    Ok(())
+}
+
+/// Create every ticket in a batch file (`-` for stdin) and print the ID
+/// assigned to each
+pub async fn cmd_create_batch(input: &str, output: OutputOptions) -> Result<()> {
+    let content = if input == "-" {
+        let mut buffer = String::new();
+        std::io::stdin().read_to_string(&mut buffer)?;
+        buffer
+    } else {
+        std::fs::read_to_string(input).map_err(|e| {
+            JanusError::Io(std::io::Error::new(
+                e.kind(),
+                format!("Failed to read batch file at {input}: {e}"),
+            ))
+        })?
+    };
+    let tickets = parse_batch(&content)?;
+    let names = index_names(&tickets)?;
+
+    let mut parents = Vec::with_capacity(tickets.len());
+    let mut deps = Vec::with_capacity(tickets.len());
+    for (i, ticket) in tickets.iter().enumerate() {
+        validate_ticket_title(&ticket.title)?;
+        for label in ticket.labels.iter().flatten() {
+            validate_label(label)?;
+        }
+
+        let parent = match ticket.parent.as_deref() {
+            Some(parent) => Some(resolve_ref(parent, &names, &ticket.title).await?),
+            None => None,
+        };
+        if parent == Some(TicketRef::Batch(i)) {
+            return Err(JanusError::SelfParentTicket);
+        }
+        parents.push(parent);
+
+        let mut ticket_deps = Vec::new();
+        for dep in &ticket.deps {
+            let dep = resolve_ref(dep, &names, &ticket.title).await?;
+            if dep == TicketRef::Batch(i) {
+                return Err(JanusError::SelfDependency);
+            }
+            if !ticket_deps.contains(&dep) {
+                ticket_deps.push(dep);
+            }
+        }
+        deps.push(ticket_deps);
+    }
+    let order = creation_order(&tickets, &parents)?;
+    check_dep_cycles(&tickets, &deps)?;
+
+    let created = transaction("create --batch", || async {
+        let mut created: Vec<Option<(String, PathBuf)>> = vec![None; tickets.len()];
+        for &i in &order {
+            let ticket = &tickets[i];
+            let parent = parents[i].as_ref().map(|parent| match parent {
+                TicketRef::Batch(p) => created[*p]
+                    .as_ref()
+                    .map(|(id, _)| id.clone())
+                    .expect("parents are created first"),
+                TicketRef::Existing(id) => id.clone(),
+            });
+            let ticket = create_ticket(CreateOptions {
+                title: ticket.title.clone(),
+                from_stdin: false,
+                description: ticket.description.clone(),
+                design: ticket.design.clone(),
+                acceptance: ticket.acceptance.clone(),
+                priority: ticket.priority,
+                ticket_type: ticket.ticket_type,
+                external_ref: ticket.external_ref.clone(),
+                parent,
+                prefix: ticket.prefix.clone(),
+                spawned_from: None,
+                spawn_context: None,
+                size: ticket.size,
+                labels: ticket.labels.clone(),
+                component: ticket.component.clone(),
+                output,
+            })
+            .await?;
+            created[i] = Some((ticket.id, ticket.file_path));
+        }
+        let created: Vec<(String, PathBuf)> = created.into_iter().flatten().collect();
+
+        for (i, ticket_deps) in deps.iter().enumerate() {
+            let (id, file_path) = &created[i];
+            let ticket = Ticket {
+                file_path: file_path.clone(),
+                id: id.clone(),
+            };
+            for dep in ticket_deps {
+                let dep_id = match dep {
+                    TicketRef::Batch(d) => &created[*d].0,
+                    TicketRef::Existing(id) => id,
+                };
+                ticket.add_to_array_field(ArrayField::Deps, dep_id)?;
+            }
+        }
+        Ok(created)
+    })
+    .await?;
+
+    let mut ids = serde_json::Map::new();
+    let mut lines = Vec::with_capacity(tickets.len());
+    let entries: Vec<serde_json::Value> = tickets
+        .iter()
+        .zip(&created)
+        .map(|(ticket, (id, file_path))| {
+            if let Some(name) = &ticket.name {
+                ids.insert(name.clone(), json!(id));
+            }
+            lines.push(format!(
+                "{} -> {id}",
+                ticket.name.as_deref().unwrap_or(&ticket.title)
+            ));
+            json!({
+                "name": ticket.name,
+                "id": id,
+                "title": ticket.title,
+                "file_path": file_path.to_string_lossy(),
+            })
+        })
+        .collect();
+
+    CommandOutput::new(json!({
+        "created": entries,
+        "ids": ids,
+    }))
+    .with_text(lines.join("\n"))
+    .print(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_batch_and_order() {
+        let tickets = parse_batch(
+            r#"
+tickets:
+  - name: api
+    title: Build the API
+    parent: epic
+    deps: [schema]
+  - name: schema
+    title: Design the schema
+    type: chore
+    priority: 1
+  - name: epic
+    title: Reporting
+    type: epic
+"#,
+        )
+        .unwrap();
+        assert_eq!(tickets[1].ticket_type, TicketType::Chore);
+        assert_eq!(tickets[1].priority, TicketPriority::P1);
+        assert_eq!(tickets[0].priority, TicketPriority::P2);
+
+        let parents = vec![Some(TicketRef::Batch(2)), None, None];
+        assert_eq!(creation_order(&tickets, &parents).unwrap(), vec![2, 0, 1]);
+
+        let looped = vec![Some(TicketRef::Batch(2)), None, Some(TicketRef::Batch(0))];
+        assert!(creation_order(&tickets, &looped).is_err());
+
+        let deps = vec![vec![TicketRef::Batch(1)], vec![], vec![]];
+        assert!(check_dep_cycles(&tickets, &deps).is_ok());
+        let deps = vec![vec![TicketRef::Batch(1)], vec![TicketRef::Batch(0)], vec![]];
+        assert!(check_dep_cycles(&tickets, &deps).is_err());
+    }
+
+    #[test]
+    fn test_parse_batch_rejects_bad_files() {
+        assert!(parse_batch("tickets: []").is_err());
+        assert!(parse_batch("tickets:\n  - title: A\n    owner: me\n").is_err());
+
+        let tickets = parse_batch("tickets:\n  - {name: a, title: A}\n  - {name: a, title: B}\n");
+        assert!(index_names(&tickets.unwrap()).is_err());
+
+        // JSON parses as YAML
+        let tickets = parse_batch(r#"{"tickets": [{"title": "A", "deps": ["j-1"]}]}"#).unwrap();
+        assert_eq!(tickets[0].deps, vec!["j-1"]);
+    }
+}
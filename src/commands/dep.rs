@@ -8,10 +8,16 @@ use crate::commands::dep_tree::{DepthCalculator, TreeBuilder, TreeFormatter};
 use crate::error::{JanusError, Result};
 use crate::graph::{check_circular_dependency, resolve_id_from_map};
 use crate::ticket::{ArrayField, Ticket, build_ticket_map};
+use crate::types::is_external_dep;
 
 /// Add a dependency to a ticket
+///
+/// A URL is recorded as an external dependency in `external-deps`.
 pub async fn cmd_dep_add(id: &str, dep_id: &str, output: OutputOptions) -> Result<()> {
     let ticket = Ticket::find(id).await?;
+    if is_external_dep(dep_id) {
+        return add_external_dep(&ticket, dep_id, output);
+    }
 
     // Validate that the dependency exists
     let dep_ticket = Ticket::find(dep_id).await?;
@@ -46,6 +52,66 @@ pub async fn cmd_dep_add(id: &str, dep_id: &str, output: OutputOptions) -> Resul
     .print(output)
 }
 
+fn add_external_dep(ticket: &Ticket, url: &str, output: OutputOptions) -> Result<()> {
+    let metadata = ticket.read()?;
+    let added = !metadata.external_deps.iter().any(|dep| dep == url);
+    let mut external_deps = metadata.external_deps;
+    if added {
+        external_deps.push(url.to_string());
+        ticket.update_field("external-deps", &serde_json::to_string(&external_deps)?)?;
+        crate::events::log_dependency_added(&ticket.id, url, None);
+    }
+
+    let text = if added {
+        format!("Added external dependency: {} -> {url}", ticket.id)
+    } else {
+        "Dependency already exists".to_string()
+    };
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": if added { "dep_added" } else { "dep_already_exists" },
+        "dep_id": url,
+        "external": true,
+        "current_external_deps": external_deps,
+    }))
+    .with_text(text)
+    .print(output)
+}
+
+/// Mark an external dependency as resolved, removing it from the ticket
+pub async fn cmd_dep_resolve(id: &str, url: &str, output: OutputOptions) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+    let metadata = ticket.read()?;
+
+    if !metadata.external_deps.iter().any(|dep| dep == url) {
+        return Err(JanusError::DependencyNotFound(url.to_string()));
+    }
+    let remaining: Vec<String> = metadata
+        .external_deps
+        .into_iter()
+        .filter(|dep| dep != url)
+        .collect();
+    if remaining.is_empty() {
+        ticket.remove_field("external-deps")?;
+    } else {
+        ticket.update_field("external-deps", &serde_json::to_string(&remaining)?)?;
+    }
+    crate::events::log_dependency_removed(&ticket.id, url, None);
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "dep_resolved",
+        "dep_id": url,
+        "current_external_deps": remaining,
+    }))
+    .with_text(format!(
+        "Resolved external dependency: {} -/-> {url}",
+        ticket.id
+    ))
+    .print(output)
+}
+
 /// Remove a dependency from a ticket
 pub async fn cmd_dep_remove(id: &str, dep_id: &str, output: OutputOptions) -> Result<()> {
     let ticket = Ticket::find(id).await?;
@@ -96,8 +162,11 @@ pub async fn cmd_dep_tree(id: &str, full_mode: bool, output: OutputOptions) -> R
     let (max_depth, subtree_depth) = DepthCalculator::calculate_depths(&root, &ticket_map);
 
     let formatter = TreeFormatter::new(&ticket_map, &max_depth, &subtree_depth);
-    formatter.print_root(&root);
-    formatter.print_tree(&root, 0, "", full_mode);
+    let mut lines = vec![formatter.format_root(&root)];
+    formatter.format_tree(&root, 0, "", full_mode, &mut lines);
 
-    Ok(())
+    CommandOutput::new(json_output)
+        .with_text(lines.join("\n"))
+        .paged()
+        .print(output)
 }
@@ -1,7 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use owo_colors::OwoColorize;
-
+use crate::display::{accent, dim, format_status_colored};
 use crate::types::TicketMetadata;
 
 pub struct TreeBuilder;
@@ -119,21 +118,29 @@ impl<'a> TreeFormatter<'a> {
         }
     }
 
-    pub fn print_root(&self, id: &str) {
+    pub fn format_root(&self, id: &str) -> String {
         let ticket = self.ticket_map.get(id);
         let status = ticket
             .and_then(|t| t.status)
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| "?".to_string());
+            .map(format_status_colored)
+            .unwrap_or_else(|| "[?]".to_string());
         let title = ticket
             .and_then(|t| t.title.as_ref())
             .map(|s| s.as_str())
             .unwrap_or("");
 
-        println!("{} [{}] {}", id.cyan(), status, title);
+        format!("{} {} {}", accent(id), status, title)
     }
 
-    pub fn print_tree(&self, id: &str, depth: usize, prefix: &str, full_mode: bool) {
+    /// Append a line for each descendant of `id`, drawn as a tree under it
+    pub fn format_tree(
+        &self,
+        id: &str,
+        depth: usize,
+        prefix: &str,
+        full_mode: bool,
+        lines: &mut Vec<String>,
+    ) {
         let children = self.get_printable_children(id, depth, full_mode);
 
         for (i, child) in children.iter().enumerate() {
@@ -144,27 +151,28 @@ impl<'a> TreeFormatter<'a> {
             let ticket = self.ticket_map.get(child);
             let status = ticket
                 .and_then(|t| t.status)
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| "?".to_string());
+                .map(format_status_colored)
+                .unwrap_or_else(|| "[?]".to_string());
             let title = ticket
                 .and_then(|t| t.title.as_ref())
                 .map(|s| s.as_str())
                 .unwrap_or("");
 
-            println!(
-                "{}{}{} [{}] {}",
-                prefix.dimmed(),
-                connector.dimmed(),
-                child.cyan(),
+            lines.push(format!(
+                "{}{}{} {} {}",
+                dim(prefix),
+                dim(connector),
+                accent(child),
                 status,
                 title
-            );
+            ));
 
-            self.print_tree(
+            self.format_tree(
                 child,
                 depth + 1,
                 &format!("{prefix}{child_prefix}"),
                 full_mode,
+                lines,
             );
         }
     }
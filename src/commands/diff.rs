@@ -0,0 +1,278 @@
+//! Tracker changes between two git revisions (`janus diff`)
+//!
+//! Reads every ticket file as of each revision straight from git, so neither
+//! revision has to be checked out. Tickets are reported as created, closed,
+//! reopened, deleted, or changed (with the fields that differ).
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+use serde_json::json;
+
+use super::CommandOutput;
+use super::blame::frontmatter_fields;
+use crate::cli::OutputOptions;
+use crate::error::Result;
+use crate::parser::extract_ticket_body;
+use crate::ticket::{enforce_filename_authority, parse_ticket};
+use crate::types::{TicketStatus, tickets_items_dir};
+use crate::utils::git;
+
+/// A ticket file as of one revision.
+#[derive(Debug, Clone, Default)]
+struct Snapshot {
+    title: String,
+    status: TicketStatus,
+    fields: BTreeMap<String, String>,
+    body: String,
+}
+
+/// One ticket in the diff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct TicketChange {
+    id: String,
+    title: String,
+    status: TicketStatus,
+    /// Fields that differ between the revisions (`changed` entries only)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<String>,
+    /// Why the ticket was closed (`closed` entries only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolution: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct TrackerDiff {
+    created: Vec<TicketChange>,
+    closed: Vec<TicketChange>,
+    reopened: Vec<TicketChange>,
+    deleted: Vec<TicketChange>,
+    changed: Vec<TicketChange>,
+}
+
+impl TrackerDiff {
+    fn is_empty(&self) -> bool {
+        self.created.is_empty()
+            && self.closed.is_empty()
+            && self.reopened.is_empty()
+            && self.deleted.is_empty()
+            && self.changed.is_empty()
+    }
+}
+
+/// Show tickets created, closed, reopened, deleted, or changed between two
+/// git revisions
+pub async fn cmd_diff(from: &str, to: &str, output: OutputOptions) -> Result<()> {
+    let before = load_snapshots(from)?;
+    let after = load_snapshots(to)?;
+    let diff = compute_diff(&before, &after);
+
+    let text = if diff.is_empty() {
+        format!("No ticket changes between {from} and {to}")
+    } else {
+        format_diff(&diff)
+    };
+
+    CommandOutput::new(json!({
+        "from": from,
+        "to": to,
+        "created": diff.created,
+        "closed": diff.closed,
+        "reopened": diff.reopened,
+        "deleted": diff.deleted,
+        "changed": diff.changed,
+    }))
+    .with_text(text)
+    .print(output)
+}
+
+/// Classify each ticket by comparing its two snapshots.
+fn compute_diff(
+    before: &BTreeMap<String, Snapshot>,
+    after: &BTreeMap<String, Snapshot>,
+) -> TrackerDiff {
+    let mut diff = TrackerDiff::default();
+    let change = |id: &str, snapshot: &Snapshot, fields: Vec<String>| TicketChange {
+        id: id.to_string(),
+        title: snapshot.title.clone(),
+        status: snapshot.status,
+        fields,
+        resolution: None,
+    };
+
+    for (id, new) in after {
+        let Some(old) = before.get(id) else {
+            diff.created.push(change(id, new, Vec::new()));
+            continue;
+        };
+
+        match (old.status.is_terminal(), new.status.is_terminal()) {
+            (false, true) => diff.closed.push(TicketChange {
+                resolution: new.fields.get("resolution").cloned(),
+                ..change(id, new, Vec::new())
+            }),
+            (true, false) => diff.reopened.push(change(id, new, Vec::new())),
+            _ => {
+                let fields = changed_fields(old, new);
+                if !fields.is_empty() {
+                    diff.changed.push(change(id, new, fields));
+                }
+            }
+        }
+    }
+
+    for (id, old) in before {
+        if !after.contains_key(id) {
+            diff.deleted.push(change(id, old, Vec::new()));
+        }
+    }
+
+    diff
+}
+
+/// Names of the frontmatter fields that differ, plus `title` and `body`.
+fn changed_fields(old: &Snapshot, new: &Snapshot) -> Vec<String> {
+    let keys: BTreeSet<&String> = old.fields.keys().chain(new.fields.keys()).collect();
+    let mut fields: Vec<String> = keys
+        .into_iter()
+        .filter(|k| old.fields.get(*k) != new.fields.get(*k))
+        .cloned()
+        .collect();
+    if old.title != new.title {
+        fields.push("title".to_string());
+    }
+    if old.body != new.body {
+        fields.push("body".to_string());
+    }
+    fields
+}
+
+fn format_diff(diff: &TrackerDiff) -> String {
+    let sections = [
+        ("Created", &diff.created),
+        ("Closed", &diff.closed),
+        ("Reopened", &diff.reopened),
+        ("Deleted", &diff.deleted),
+        ("Changed", &diff.changed),
+    ];
+
+    let mut blocks = Vec::new();
+    for (heading, changes) in sections {
+        if changes.is_empty() {
+            continue;
+        }
+        let mut lines = vec![format!("{heading} ({}):", changes.len())];
+        for c in changes {
+            let mut line = format!("  {:<10} [{}] {}", c.id, c.status, c.title);
+            if !c.fields.is_empty() {
+                line.push_str(&format!(" ({})", c.fields.join(", ")));
+            }
+            if let Some(resolution) = &c.resolution {
+                line.push_str(&format!(" ({resolution})"));
+            }
+            lines.push(line);
+        }
+        blocks.push(lines.join("\n"));
+    }
+    blocks.join("\n\n")
+}
+
+/// Load every ticket file in the items directory as of `rev`, keyed by ID.
+fn load_snapshots(rev: &str) -> Result<BTreeMap<String, Snapshot>> {
+    let items_dir = tickets_items_dir();
+    let items_dir = items_dir.to_string_lossy();
+    // `--full-name` gives repository-relative paths, as `git show` expects
+    let listing = git(
+        None,
+        &[
+            "ls-tree",
+            "-r",
+            "--full-name",
+            "--name-only",
+            rev,
+            "--",
+            &items_dir,
+        ],
+    )?;
+
+    let mut snapshots = BTreeMap::new();
+    for path in listing.lines().filter(|p| p.ends_with(".md")) {
+        let Some(stem) = path
+            .rsplit('/')
+            .next()
+            .and_then(|name| name.strip_suffix(".md"))
+        else {
+            continue;
+        };
+        let content = git(None, &["show", &format!("{rev}:{path}")])?;
+        snapshots.insert(stem.to_string(), snapshot(&content, stem));
+    }
+    Ok(snapshots)
+}
+
+/// Build a snapshot, tolerating files that do not parse at this revision.
+fn snapshot(content: &str, stem: &str) -> Snapshot {
+    let mut snapshot = Snapshot {
+        fields: frontmatter_fields(content),
+        body: extract_ticket_body(content).unwrap_or_default(),
+        ..Default::default()
+    };
+    if let Ok(mut metadata) = parse_ticket(content) {
+        enforce_filename_authority(&mut metadata, stem);
+        snapshot.title = metadata.title.unwrap_or_default();
+        snapshot.status = metadata.status.unwrap_or_default();
+    }
+    snapshot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticket(status: &str, title: &str, priority: &str) -> Snapshot {
+        let content = format!(
+            "---\nid: j-a1b2\nuuid: 550e8400-e29b-41d4-a716-446655440000\nstatus: {status}\npriority: {priority}\n---\n# {title}\n\nBody\n"
+        );
+        snapshot(&content, "j-a1b2")
+    }
+
+    fn map(entries: &[(&str, Snapshot)]) -> BTreeMap<String, Snapshot> {
+        entries
+            .iter()
+            .map(|(id, s)| (id.to_string(), s.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_compute_diff_classifies_tickets() {
+        let before = map(&[
+            ("j-open", ticket("new", "Open", "2")),
+            ("j-done", ticket("complete", "Done", "2")),
+            ("j-edit", ticket("new", "Edit", "2")),
+            ("j-gone", ticket("new", "Gone", "2")),
+            ("j-same", ticket("new", "Same", "2")),
+        ]);
+        let after = map(&[
+            (
+                "j-open",
+                ticket("cancelled\nresolution: wontfix", "Open", "2"),
+            ),
+            ("j-done", ticket("next", "Done", "2")),
+            ("j-edit", ticket("new", "Edited", "0")),
+            ("j-new", ticket("new", "New", "2")),
+            ("j-same", ticket("new", "Same", "2")),
+        ]);
+
+        let diff = compute_diff(&before, &after);
+        let ids = |changes: &[TicketChange]| -> Vec<String> {
+            changes.iter().map(|c| c.id.clone()).collect()
+        };
+        assert_eq!(ids(&diff.created), vec!["j-new"]);
+        assert_eq!(ids(&diff.closed), vec!["j-open"]);
+        assert_eq!(diff.closed[0].resolution.as_deref(), Some("wontfix"));
+        assert_eq!(ids(&diff.reopened), vec!["j-done"]);
+        assert_eq!(ids(&diff.deleted), vec!["j-gone"]);
+        assert_eq!(ids(&diff.changed), vec!["j-edit"]);
+        assert_eq!(diff.changed[0].fields, vec!["priority", "title"]);
+    }
+}
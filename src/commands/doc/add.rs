@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use reqwest::header::{CONTENT_TYPE, USER_AGENT};
+use url::Url;
+
+use crate::cli::OutputOptions;
+use crate::commands::CommandOutput;
+use crate::config::Config;
+use crate::doc::html::{html_title, html_to_markdown, url_slug};
+use crate::doc::types::{DocLabel, DocMetadata};
+use crate::doc::{Doc, ensure_docs_dir, sanitize_label, serialize_doc};
+use crate::error::{JanusError, Result};
+use crate::remote::http::configure_reqwest;
+use crate::store::get_or_init_store;
+
+/// Fetch a web page, store it as a document, and index it
+///
+/// HTML pages are converted to markdown; markdown and plain text are stored
+/// as-is. The URL is recorded in the `source` frontmatter field. When semantic
+/// search is enabled, the new document is embedded right away.
+pub async fn cmd_doc_add(
+    url: &str,
+    label: Option<&str>,
+    title: Option<String>,
+    tags: Vec<String>,
+    output: OutputOptions,
+) -> Result<()> {
+    let url = Url::parse(url)
+        .map_err(|e| JanusError::InvalidInput(format!("invalid URL '{url}': {e}")))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(JanusError::InvalidInput(format!(
+            "unsupported URL scheme '{}': expected http or https",
+            url.scheme()
+        )));
+    }
+
+    let config = Config::load()?;
+    let (markdown, page_title) = fetch_markdown(&url, &config).await?;
+    let (heading, body) = split_leading_h1(&markdown);
+
+    let doc_title = title
+        .or(heading)
+        .or(page_title)
+        .or_else(|| url_slug(&url))
+        .unwrap_or_else(|| url.to_string());
+
+    let label = match label {
+        Some(label) => label.to_string(),
+        None => url_slug(&url)
+            .map(|slug| sanitize_label(&slug))
+            .filter(|slug| !slug.is_empty())
+            .unwrap_or_else(|| sanitize_label(&doc_title)),
+    };
+    if label.is_empty() {
+        return Err(JanusError::InvalidInput(format!(
+            "could not derive a label from '{url}'; pass one with --label"
+        )));
+    }
+    let label = DocLabel::new(label)?;
+
+    ensure_docs_dir()?;
+    let file_path = crate::paths::docs_dir().join(format!("{label}.md"));
+    if file_path.exists() {
+        return Err(JanusError::DocAlreadyExists(label.to_string()));
+    }
+
+    let now_str = {
+        use jiff::Timestamp;
+        let now = Timestamp::now();
+        now.strftime("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+    };
+    let now = crate::types::CreatedAt::new_unchecked(&now_str);
+
+    let metadata = DocMetadata {
+        label: Some(label.clone()),
+        tags,
+        created: Some(now.clone()),
+        updated: Some(now),
+        source: Some(url.to_string()),
+        ..Default::default()
+    };
+
+    // Frontmatter only; the page supplies the body
+    let frontmatter = serialize_doc(&metadata)?;
+    let content = format!("{frontmatter}\n# {doc_title}\n\n{body}\n");
+
+    let doc = Doc::new(file_path.clone())?;
+    doc.write(&content)?;
+
+    let store = get_or_init_store().await?;
+    store.upsert_doc(DocMetadata {
+        title: Some(doc_title.clone()),
+        file_path: Some(file_path.clone()),
+        ..metadata
+    });
+
+    // Embed the new document so semantic doc search finds it immediately
+    let mut embedded = None;
+    if config.semantic_search_enabled() {
+        match store.sync_doc_embeddings(&config.doc_chunking).await {
+            Ok(stats) => embedded = Some(stats.embedded),
+            Err(e) => eprintln!(
+                "Warning: failed to embed document: {e}. Run 'janus cache sync' to retry."
+            ),
+        }
+    }
+
+    let mut text = format!("Added document {label}: {doc_title}");
+    if let Some(count) = embedded {
+        text.push_str(&format!(" ({count} chunk(s) embedded)"));
+    }
+
+    CommandOutput::new(serde_json::json!({
+        "action": "doc_added",
+        "label": label.to_string(),
+        "title": doc_title,
+        "source": url.to_string(),
+        "file_path": file_path.to_string_lossy().to_string(),
+        "embedded_chunks": embedded,
+    }))
+    .with_text(text)
+    .print(output)
+}
+
+/// Fetch `url` and return its content as markdown, plus the HTML page title.
+async fn fetch_markdown(url: &Url, config: &Config) -> Result<(String, Option<String>)> {
+    let builder = reqwest::Client::builder()
+        .timeout(Duration::from_secs(60))
+        .connect_timeout(Duration::from_secs(30));
+    let client = configure_reqwest(builder, &config.network)?.build()?;
+
+    let response = client
+        .get(url.clone())
+        .header(USER_AGENT, concat!("janus/", env!("CARGO_PKG_VERSION")))
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_ascii_lowercase())
+        .unwrap_or_default();
+    let text = response.text().await?;
+
+    if content_type.is_empty() || content_type.contains("html") {
+        Ok((html_to_markdown(&text)?, html_title(&text)))
+    } else if content_type.starts_with("text/") {
+        Ok((text.trim().to_string(), None))
+    } else {
+        Err(JanusError::InvalidInput(format!(
+            "unsupported content type '{content_type}' at {url}: expected HTML, markdown, or text"
+        )))
+    }
+}
+
+/// Split a leading `# Heading` off `markdown`, returning its text and the rest.
+fn split_leading_h1(markdown: &str) -> (Option<String>, &str) {
+    let markdown = markdown.trim_start();
+    let (first, rest) = markdown.split_once('\n').unwrap_or((markdown, ""));
+    match first.strip_prefix("# ") {
+        Some(heading) if !heading.trim().is_empty() => {
+            (Some(heading.trim().to_string()), rest.trim_start())
+        }
+        _ => (None, markdown),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_leading_h1() {
+        assert_eq!(
+            split_leading_h1("\n# RFC 42\n\nBody text"),
+            (Some("RFC 42".to_string()), "Body text")
+        );
+        assert_eq!(
+            split_leading_h1("## Section\n\nBody"),
+            (None, "## Section\n\nBody")
+        );
+        assert_eq!(split_leading_h1("# Only"), (Some("Only".to_string()), ""));
+    }
+}
@@ -40,8 +40,10 @@ pub async fn cmd_doc_create(
         label: Some(label.clone()),
         description,
         tags,
+        tickets: Vec::new(),
         created: Some(now.clone()),
         updated: Some(now.clone()),
+        source: None,
         title: Some(doc_title.clone()),
         file_path: Some(file_path.clone()),
         extra_frontmatter: None,
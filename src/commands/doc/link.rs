@@ -0,0 +1,78 @@
+use owo_colors::OwoColorize;
+use serde_json::json;
+
+use crate::cli::OutputOptions;
+use crate::commands::CommandOutput;
+use crate::doc::Doc;
+use crate::error::Result;
+use crate::ticket::Ticket;
+
+/// Reference a document from one or more tickets
+///
+/// Adds the document's label to each ticket's `docs` field.
+pub async fn cmd_doc_link(label: &str, ticket_ids: &[String], output: OutputOptions) -> Result<()> {
+    update_doc_links(label, ticket_ids, true, output).await
+}
+
+/// Remove a document reference from one or more tickets
+pub async fn cmd_doc_unlink(
+    label: &str,
+    ticket_ids: &[String],
+    output: OutputOptions,
+) -> Result<()> {
+    update_doc_links(label, ticket_ids, false, output).await
+}
+
+async fn update_doc_links(
+    label: &str,
+    ticket_ids: &[String],
+    link: bool,
+    output: OutputOptions,
+) -> Result<()> {
+    let doc = Doc::find(label).await?;
+
+    let mut changed = Vec::new();
+    let mut text_lines = Vec::with_capacity(ticket_ids.len());
+    for id in ticket_ids {
+        let (ticket, metadata) = Ticket::find_and_read(id).await?;
+        let has_doc = metadata.docs.contains(&doc.label);
+
+        let mut docs = metadata.docs.clone();
+        if link && !has_doc {
+            docs.push(doc.label.clone());
+        } else if !link && has_doc {
+            docs.retain(|d| d != &doc.label);
+        }
+
+        if docs.len() == metadata.docs.len() {
+            let state = if link {
+                "already links"
+            } else {
+                "does not link"
+            };
+            text_lines.push(format!("{} {state} {}", ticket.id.cyan(), doc.label.cyan()));
+            continue;
+        }
+
+        if docs.is_empty() {
+            ticket.remove_field("docs")?;
+        } else {
+            ticket.update_field("docs", &serde_json::to_string(&docs)?)?;
+        }
+        let verb = if link { "Linked" } else { "Unlinked" };
+        text_lines.push(format!(
+            "{verb} {} -> {}",
+            ticket.id.cyan(),
+            doc.label.cyan()
+        ));
+        changed.push(ticket.id.clone());
+    }
+
+    CommandOutput::new(json!({
+        "label": doc.label,
+        "action": if link { "doc_linked" } else { "doc_unlinked" },
+        "tickets": changed,
+    }))
+    .with_text(text_lines.join("\n"))
+    .print(output)
+}
@@ -4,17 +4,26 @@
 //! - `doc ls` - List all documents
 //! - `doc show` - Display a document
 //! - `doc create` - Create a new document
+//! - `doc add --url` - Fetch a web page and add it as a document
 //! - `doc edit` - Edit a document
-//! - `doc search` - Search documents semantically
+//! - `doc link` / `doc unlink` - Reference a document from tickets
+//! - `doc search` - Search document chunks by keyword and semantic similarity
+//! - `doc view` - Browse documents interactively
 
+mod add;
 mod create;
 mod edit;
+mod link;
 mod ls;
 mod search;
 mod show;
+mod view;
 
+pub use add::cmd_doc_add;
 pub use create::cmd_doc_create;
 pub use edit::cmd_doc_edit;
+pub use link::{cmd_doc_link, cmd_doc_unlink};
 pub use ls::cmd_doc_ls;
 pub use search::cmd_doc_search;
 pub use show::cmd_doc_show;
+pub use view::cmd_doc_view;
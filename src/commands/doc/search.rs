@@ -1,26 +1,18 @@
-use tabled::settings::Style;
-use tabled::{Table, Tabled};
+use owo_colors::OwoColorize;
 
 use crate::cli::OutputOptions;
 use crate::commands::print_json;
 use crate::config::Config;
+use crate::display::highlight_terms;
 use crate::error::{JanusError, Result};
+use crate::store::doc_search::{DocSearchResult, merge_doc_results, query_terms};
 use crate::store::get_or_init_store;
 
-/// A row in the document search results table
-#[derive(Tabled)]
-struct DocSearchRow {
-    #[tabled(rename = "Label")]
-    label: String,
-    #[tabled(rename = "Similarity")]
-    similarity: String,
-    #[tabled(rename = "Title")]
-    title: String,
-    #[tabled(rename = "Lines")]
-    lines: String,
-}
-
-/// Search documents using semantic similarity
+/// Search document chunks by keyword and semantic similarity
+///
+/// Lexical matches are always included. When semantic search is enabled and
+/// documents have embeddings, semantic matches are merged in; a chunk found
+/// both ways is reported once as a hybrid match.
 pub async fn cmd_doc_search(
     query: &str,
     document: Option<&str>,
@@ -35,17 +27,7 @@ pub async fn cmd_doc_search(
         ));
     }
 
-    // Check if semantic search is enabled
     let config = Config::load()?;
-    if !config.semantic_search_enabled() {
-        eprintln!(
-            "Semantic search is disabled. Enable with: janus config set semantic_search.enabled true"
-        );
-        return Err(JanusError::Config(
-            "Semantic search is disabled".to_string(),
-        ));
-    }
-
     let store = get_or_init_store().await?;
 
     // Resolve document label if specified
@@ -79,30 +61,29 @@ pub async fn cmd_doc_search(
         None
     };
 
-    // Check embedding coverage
-    let doc_embeddings: Vec<_> = store
+    let lexical = store.doc_lexical_search(query, resolved_label.as_deref(), limit);
+
+    // Semantic matches need embeddings; without them the search is lexical only
+    let has_doc_embeddings = store
         .embeddings()
         .iter()
-        .filter(|entry| entry.key().starts_with("doc:"))
-        .collect();
-
-    if doc_embeddings.is_empty() {
-        return Err(JanusError::DocNotFound(
-            "No documents with embeddings found. Run 'janus cache rebuild' to generate embeddings."
-                .to_string(),
-        ));
-    }
-
-    // Generate query embedding and perform search
-    let query_embedding = crate::embedding::model::generate_embedding(query)
-        .await
-        .map_err(JanusError::EmbeddingModel)?;
-
-    let results = match resolved_label {
-        Some(ref label) => store.doc_search_by_document(&query_embedding, label, limit),
-        None => store.doc_search(&query_embedding, limit),
+        .any(|entry| entry.key().starts_with("doc:"));
+    let semantic_used = config.semantic_search_enabled() && has_doc_embeddings;
+
+    let semantic = if semantic_used {
+        let query_embedding = crate::embedding::model::generate_embedding(query)
+            .await
+            .map_err(JanusError::EmbeddingModel)?;
+        match resolved_label {
+            Some(ref label) => store.doc_search_by_document(&query_embedding, label, limit),
+            None => store.doc_search(&query_embedding, limit),
+        }
+    } else {
+        Vec::new()
     };
 
+    let results = merge_doc_results(semantic, lexical, limit);
+
     // Filter by threshold if specified
     let results: Vec<_> = if let Some(t) = threshold {
         results.into_iter().filter(|r| r.similarity >= t).collect()
@@ -119,10 +100,12 @@ pub async fn cmd_doc_search(
                     "label": r.label,
                     "title": r.doc.title(),
                     "description": r.doc.description,
+                    "chunk": chunk_label(r),
                     "heading_path": r.heading_path,
                     "content_snippet": r.content_snippet,
                     "line_range": r.line_range,
                     "similarity": r.similarity,
+                    "match": r.match_kind.to_string(),
                 })
             })
             .collect();
@@ -133,28 +116,47 @@ pub async fn cmd_doc_search(
         } else {
             String::new()
         };
-        println!("Document search results for: \"{query}\"{target_info}\n");
+        let mode = if semantic_used { "" } else { " (lexical only)" };
+        println!("Document search results for: \"{query}\"{target_info}{mode}");
 
         if results.is_empty() {
-            println!("No matching documents found.");
+            println!("\nNo matching documents found.");
         } else {
-            let rows: Vec<DocSearchRow> = results
-                .iter()
-                .map(|r| DocSearchRow {
-                    label: r.label.clone(),
-                    similarity: format!("{:.2}", r.similarity),
-                    title: r.doc.title().unwrap_or("(no title)").to_string(),
-                    lines: if r.line_range.0 == 0 {
-                        "-".to_string()
-                    } else {
-                        format!("{}-{}", r.line_range.0, r.line_range.1)
-                    },
-                })
-                .collect();
+            let terms = query_terms(query);
+            // Group chunks under their document, keeping the best-ranked document first
+            let mut labels: Vec<&str> = Vec::new();
+            for r in &results {
+                if !labels.contains(&r.label.as_str()) {
+                    labels.push(&r.label);
+                }
+            }
 
-            let mut table = Table::new(rows);
-            table.with(Style::rounded());
-            println!("{table}");
+            for label in labels {
+                let mut chunks = results.iter().filter(|r| r.label == label).peekable();
+                let title = chunks
+                    .peek()
+                    .and_then(|r| r.doc.title())
+                    .unwrap_or("(no title)");
+                println!("\n{} {}", label.cyan().bold(), title);
+
+                for r in chunks {
+                    let lines = if r.line_range.0 == 0 {
+                        String::new()
+                    } else {
+                        format!(" lines {}-{}", r.line_range.0, r.line_range.1)
+                    };
+                    println!(
+                        "  {}{} {}",
+                        chunk_label(r).green(),
+                        lines.dimmed(),
+                        format!("[{:.2} {}]", r.similarity, r.match_kind).dimmed()
+                    );
+                    if !r.content_snippet.is_empty() {
+                        let snippet = r.content_snippet.split_whitespace().collect::<Vec<_>>();
+                        println!("    {}", highlight_terms(&snippet.join(" "), &terms));
+                    }
+                }
+            }
         }
 
         println!("\n{} result(s)", results.len());
@@ -162,3 +164,15 @@ pub async fn cmd_doc_search(
 
     Ok(())
 }
+
+/// Name of the chunk a result points at: its heading path, or the whole
+/// document for document-level matches
+fn chunk_label(result: &DocSearchResult) -> String {
+    if result.line_range.0 == 0 {
+        "(document)".to_string()
+    } else if result.heading_path.is_empty() {
+        "(intro)".to_string()
+    } else {
+        result.heading_path.join(" > ")
+    }
+}
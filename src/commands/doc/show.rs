@@ -1,15 +1,21 @@
 use owo_colors::OwoColorize;
 
 use crate::cli::OutputOptions;
-use crate::commands::print_json;
-use crate::doc::Doc;
+use crate::commands::{print_json, ticket_minimal_json_with_exists};
+use crate::doc::{Doc, related_tickets};
 use crate::error::Result;
+use crate::ticket::build_ticket_map;
 
 /// Display a document with optional line range
+///
+/// Related tickets include back-references: tickets whose `docs` field lists
+/// this document.
 pub async fn cmd_doc_show(label: &str, lines: Option<String>, output: OutputOptions) -> Result<()> {
     let doc = Doc::find(label).await?;
     let content = doc.read_content()?;
     let metadata = doc.read()?;
+    let ticket_map = build_ticket_map().await?;
+    let tickets = related_tickets(&doc.label, &metadata, &ticket_map);
 
     // Parse line range if provided
     let line_range = if let Some(range_str) = lines {
@@ -24,8 +30,13 @@ pub async fn cmd_doc_show(label: &str, lines: Option<String>, output: OutputOpti
             "title": metadata.title(),
             "description": metadata.description,
             "tags": metadata.tags,
+            "tickets": tickets
+                .iter()
+                .map(|id| ticket_minimal_json_with_exists(id, ticket_map.get(id)))
+                .collect::<Vec<_>>(),
             "created": metadata.created.as_ref().map(|c| c.to_string()),
             "updated": metadata.updated.as_ref().map(|c| c.to_string()),
+            "source": metadata.source,
             "content": content,
             "file_path": doc.file_path.to_string_lossy().to_string(),
         });
@@ -42,6 +53,18 @@ pub async fn cmd_doc_show(label: &str, lines: Option<String>, output: OutputOpti
         if !metadata.tags.is_empty() {
             println!("{}: {}", "Tags".green().bold(), metadata.tags.join(", "));
         }
+        if let Some(source) = &metadata.source {
+            println!("{}: {}", "Source".green().bold(), source);
+        }
+        if !tickets.is_empty() {
+            println!("{}:", "Tickets".green().bold());
+            for id in &tickets {
+                match ticket_map.get(id) {
+                    Some(ticket) => println!("{}", crate::display::format_ticket_bullet(ticket)),
+                    None => println!("- {} {}", id, "(not found)".dimmed()),
+                }
+            }
+        }
         println!();
 
         // Display content (with optional line range)
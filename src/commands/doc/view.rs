@@ -0,0 +1,22 @@
+use iocraft::prelude::*;
+
+use crate::error::{JanusError, Result};
+use crate::store::{get_or_init_store, start_watching, stop_watching};
+use crate::tui::DocBrowser;
+
+/// Launch the doc browser TUI
+pub async fn cmd_doc_view() -> Result<()> {
+    // Initialize store and start filesystem watcher for live updates
+    let store = get_or_init_store().await?;
+    let _ = start_watching(store).await;
+
+    let result = element!(DocBrowser)
+        .fullscreen()
+        .await
+        .map_err(|e| JanusError::TuiError(format!("{e}")));
+
+    // Release OS-level file watch handles, as in `janus view`
+    stop_watching();
+
+    result
+}
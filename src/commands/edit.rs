@@ -1,14 +1,34 @@
+use std::collections::HashSet;
+use std::io::Write;
+
 use serde_json::json;
 
+use super::interactive::confirm;
 use super::{CommandOutput, open_in_editor_for_entity};
 use crate::cli::OutputOptions;
-use crate::error::Result;
-use crate::ticket::Ticket;
+use crate::error::{JanusError, Result};
+use crate::fs::{read_file, write_file_atomic};
+use crate::ticket::{Ticket, get_all_tickets};
+use crate::types::{TicketMetadata, TicketSection};
+use crate::utils::{edit_in_editor, is_stdin_tty, open_in_editor_with_env, read_stdin};
+
+/// Environment variable pointing the editor at the ticket list written by
+/// `janus edit --with-helpers`
+pub const TICKET_LIST_ENV: &str = "JANUS_TICKET_LIST";
 
-/// Open a ticket in the default editor
-pub async fn cmd_edit(id: &str, output: OutputOptions) -> Result<()> {
+/// Open a ticket, or one section of it, in the default editor
+pub async fn cmd_edit(
+    id: &str,
+    section: Option<TicketSection>,
+    with_helpers: bool,
+    output: OutputOptions,
+) -> Result<()> {
     let ticket = Ticket::find(id).await?;
 
+    if let Some(section) = section {
+        return edit_section(&ticket, section, output);
+    }
+
     // Output in JSON format if requested (skip editor)
     if output.json {
         return CommandOutput::new(json!({
@@ -19,5 +39,185 @@ pub async fn cmd_edit(id: &str, output: OutputOptions) -> Result<()> {
         .print(output);
     }
 
+    if with_helpers {
+        return edit_with_helpers(&ticket).await;
+    }
+
     open_in_editor_for_entity("ticket", &ticket.file_path, output)
 }
+
+/// Open tickets as `<id>\t<title>` lines, for editor completion sources
+fn ticket_list(tickets: &[TicketMetadata]) -> String {
+    let mut open: Vec<&TicketMetadata> = tickets
+        .iter()
+        .filter(|t| !t.status.unwrap_or_default().is_terminal())
+        .collect();
+    open.sort_by(|a, b| a.id.cmp(&b.id));
+    open.iter()
+        .filter_map(|t| {
+            Some(format!(
+                "{}\t{}\n",
+                t.id.as_deref()?,
+                t.title.as_deref().unwrap_or_default()
+            ))
+        })
+        .collect()
+}
+
+/// Words in `content` shaped like an ID with one of the known ticket
+/// prefixes (`j-a1b2`) that name no existing ticket, in order of appearance
+fn unknown_references(content: &str, known: &HashSet<&str>) -> Vec<String> {
+    let prefixes: HashSet<&str> = known
+        .iter()
+        .filter_map(|id| id.rsplit_once('-').map(|(prefix, _)| prefix))
+        .collect();
+    let mut unknown: Vec<String> = Vec::new();
+    for word in content.split(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_')) {
+        let Some((prefix, suffix)) = word.rsplit_once('-') else {
+            continue;
+        };
+        let id_shaped = !suffix.is_empty()
+            && suffix
+                .chars()
+                .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c));
+        if id_shaped
+            && prefixes.contains(prefix)
+            && !known.contains(word)
+            && !unknown.iter().any(|u| u == word)
+        {
+            unknown.push(word.to_string());
+        }
+    }
+    unknown
+}
+
+/// Open the ticket in the editor with `$JANUS_TICKET_LIST` naming a temporary
+/// file of open ticket IDs and titles, then check every ticket reference in
+/// the saved file. References to tickets that do not exist send the user back
+/// to the editor; declining restores the ticket as it was.
+async fn edit_with_helpers(ticket: &Ticket) -> Result<()> {
+    if !is_stdin_tty() {
+        return Err(JanusError::InteractiveTerminalRequired(
+            ticket.file_path.clone(),
+        ));
+    }
+
+    let tickets = get_all_tickets().await?.items;
+    let known: HashSet<&str> = tickets.iter().filter_map(|t| t.id.as_deref()).collect();
+    let mut list = tempfile::Builder::new()
+        .prefix("janus-tickets-")
+        .suffix(".txt")
+        .tempfile()?;
+    list.write_all(ticket_list(&tickets).as_bytes())?;
+    list.flush()?;
+
+    let original = read_file(&ticket.file_path)?;
+    loop {
+        open_in_editor_with_env(&ticket.file_path, &[(TICKET_LIST_ENV, list.path())])?;
+        let unknown = unknown_references(&read_file(&ticket.file_path)?, &known);
+        if unknown.is_empty() {
+            return Ok(());
+        }
+        eprintln!("No such ticket: {}", unknown.join(", "));
+        if !confirm("Edit again")? {
+            write_file_atomic(&ticket.file_path, &original)?;
+            return Err(JanusError::UnknownTicketReferences(unknown));
+        }
+    }
+}
+
+/// Edit a single body section.
+///
+/// Interactively the section alone is opened in `$EDITOR`; otherwise its new
+/// content is read from stdin. Emptying the section removes it.
+fn edit_section(ticket: &Ticket, section: TicketSection, output: OutputOptions) -> Result<()> {
+    let current = ticket.read()?.sections.get(section).map(String::from);
+
+    let updated = if !is_stdin_tty() {
+        let input = read_stdin()?;
+        if input.is_empty() {
+            return Err(JanusError::NotInteractive(format!(
+                "pipe the new {section} section to stdin"
+            )));
+        }
+        input
+    } else if output.json {
+        return CommandOutput::new(json!({
+            "id": ticket.id,
+            "file_path": ticket.file_path.to_string_lossy(),
+            "section": section.to_string(),
+            "content": current,
+            "action": "edit",
+        }))
+        .print(output);
+    } else {
+        edit_in_editor(current.as_deref().unwrap_or_default())?
+    };
+
+    let changed = current.as_deref().unwrap_or_default() != updated;
+    if changed {
+        let content = (!updated.is_empty()).then_some(updated.as_str());
+        ticket.update_ticket_section(section, content)?;
+    }
+
+    let text = if changed {
+        format!("Updated {} of {}", section.heading(), ticket.id)
+    } else {
+        format!("No changes to {} of {}", section.heading(), ticket.id)
+    };
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "section": section.to_string(),
+        "action": if changed { "section_updated" } else { "unchanged" },
+    }))
+    .with_text(text)
+    .print(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TicketId, TicketStatus};
+
+    fn ticket(id: &str, status: TicketStatus) -> TicketMetadata {
+        TicketMetadata {
+            id: Some(TicketId::new_unchecked(id)),
+            title: Some(format!("Ticket {id}")),
+            status: Some(status),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_ticket_list_has_open_tickets() {
+        let tickets = vec![
+            ticket("j-b2", TicketStatus::New),
+            ticket("j-a1", TicketStatus::InProgress),
+            ticket("j-c3", TicketStatus::Complete),
+        ];
+        assert_eq!(
+            ticket_list(&tickets),
+            "j-a1\tTicket j-a1\nj-b2\tTicket j-b2\n"
+        );
+    }
+
+    #[test]
+    fn test_unknown_references() {
+        let known: HashSet<&str> = ["j-a1b2", "j-c3d4", "api-0012"].into_iter().collect();
+        let content = "\
+---
+id: j-a1b2
+uuid: 6f1e2a3b-0000-4000-8000-00000000abcd
+deps: [j-c3d4, j-c3d5]
+created: 2026-01-01T00:00:00Z
+---
+# Re-run the migration
+
+Follow-up to api-0013 (see j-c3d5), not x-abc or j-a1b2-notes.
+";
+        assert_eq!(
+            unknown_references(content, &known),
+            vec!["j-c3d5", "api-0013"]
+        );
+    }
+}
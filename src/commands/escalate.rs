@@ -0,0 +1,99 @@
+//! `janus escalate` command.
+//!
+//! Reports the priority escalations due under the `escalation` rules in
+//! config.yaml, and with `--apply` writes them (see
+//! [`crate::status::escalation`]).
+
+use jiff::Timestamp;
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::config::Config;
+use crate::error::Result;
+use crate::status::escalation::{apply_escalation, pending_escalations};
+use crate::store::get_or_init_store;
+
+/// List the escalations due now, and apply them when `apply` is set.
+pub async fn cmd_escalate(apply: bool, output: OutputOptions) -> Result<()> {
+    let config = Config::load()?;
+    if config.escalation.rules.is_empty() {
+        return CommandOutput::new(json!({
+            "escalations": [],
+            "applied": false,
+            "errors": [],
+        }))
+        .with_text("No escalation rules configured (escalation.rules in .janus/config.yaml).")
+        .print(output);
+    }
+
+    let now = Timestamp::now();
+    let store = get_or_init_store().await?;
+    let escalations = pending_escalations(&store.get_all_tickets(), &config.escalation, now);
+
+    let mut errors = Vec::new();
+    if apply {
+        for escalation in &escalations {
+            if let Err(e) = apply_escalation(escalation, now).await {
+                errors.push((escalation.id.clone(), e.to_string()));
+            }
+        }
+    }
+    let failed = |id: &str| errors.iter().any(|(e, _)| e == id);
+
+    let text = if escalations.is_empty() {
+        "No tickets are due for escalation.".to_string()
+    } else {
+        let mut lines = vec![if apply {
+            format!("Escalated {} ticket(s):", escalations.len() - errors.len())
+        } else {
+            format!(
+                "Would escalate {} ticket(s) (run with --apply to escalate):",
+                escalations.len()
+            )
+        }];
+        for escalation in escalations.iter().filter(|e| !failed(&e.id)) {
+            lines.push(format!(
+                "  {}  P{} -> P{}  {}  ({})",
+                escalation.id,
+                escalation.from,
+                escalation.to,
+                escalation.title.as_deref().unwrap_or_default(),
+                escalation.rule
+            ));
+        }
+        if !errors.is_empty() {
+            lines.push(format!(
+                "Errors on {} ticket(s): {}",
+                errors.len(),
+                errors
+                    .iter()
+                    .map(|(id, err)| format!("{id} ({err})"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        lines.join("\n")
+    };
+
+    CommandOutput::new(json!({
+        "escalations": escalations
+            .iter()
+            .filter(|e| !failed(&e.id))
+            .map(|e| json!({
+                "id": e.id,
+                "title": e.title,
+                "from": e.from,
+                "to": e.to,
+                "rule": e.rule,
+            }))
+            .collect::<Vec<_>>(),
+        "applied": apply,
+        "errors": errors
+            .iter()
+            .map(|(id, err)| json!({"id": id, "error": err}))
+            .collect::<Vec<_>>(),
+    }))
+    .with_text(text)
+    .print(output)
+}
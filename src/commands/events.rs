@@ -1,9 +1,72 @@
+use std::io::{ErrorKind, Write, stdout};
+use std::time::Duration;
+
+use owo_colors::OwoColorize;
 use serde_json::json;
 
 use super::CommandOutput;
 use crate::cli::OutputOptions;
 use crate::error::Result;
-use crate::events::{clear_events, events_file_path, read_events};
+use crate::events::{Event, EventTail, clear_events, events_file_path, read_events};
+
+/// How often `janus events --follow` checks the log for new events
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Print the most recent events, then optionally follow the log.
+///
+/// With `follow`, new events are printed as they are appended until the
+/// process is interrupted. With `--json`, each event is printed as one JSON
+/// line in the same format as `.janus/events.ndjson`.
+pub async fn cmd_events_tail(lines: usize, follow: bool, output: OutputOptions) -> Result<()> {
+    let mut tail = EventTail::new();
+    let events = tail.poll()?;
+    let start = events.len().saturating_sub(lines);
+    if !print_events(&events[start..], output)? || !follow {
+        return Ok(());
+    }
+
+    loop {
+        tokio::time::sleep(FOLLOW_POLL_INTERVAL).await;
+        let events = tail.poll()?;
+        if !print_events(&events, output)? {
+            return Ok(());
+        }
+    }
+}
+
+/// Print events to stdout, returning `false` once the reader has gone away
+/// (e.g. `janus events --follow | head`).
+fn print_events(events: &[Event], output: OutputOptions) -> Result<bool> {
+    let stdout = stdout();
+    let mut out = stdout.lock();
+    let result = events
+        .iter()
+        .try_for_each(|event| {
+            if output.json {
+                let line = serde_json::to_string(event).map_err(std::io::Error::other)?;
+                writeln!(out, "{line}")
+            } else {
+                writeln!(out, "{}", format_event_line(event))
+            }
+        })
+        .and_then(|()| out.flush());
+
+    match result {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// One-line text form of an event: `<timestamp> <actor> <summary>`
+fn format_event_line(event: &Event) -> String {
+    format!(
+        "{} {:<12} {}",
+        event.timestamp.dimmed(),
+        event.actor.to_string().cyan(),
+        event.summary()
+    )
+}
 
 /// Prune/clear the events log file.
 ///
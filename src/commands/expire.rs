@@ -0,0 +1,111 @@
+//! `janus expire` command.
+//!
+//! Tickets for spikes and experiments can declare an `expires` date. Once that
+//! date has passed, `janus ls` flags them, and this command cancels them as
+//! `wontfix` with a note recording why.
+
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::config::TransitionVia;
+use crate::error::Result;
+use crate::status::enforce_transition;
+use crate::store::get_or_init_store;
+use crate::ticket::Ticket;
+use crate::types::{TicketResolution, TicketStatus};
+
+/// Cancel one expired ticket and note why
+async fn expire_ticket(id: &str, expires: &str) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+    let old_status = ticket.read()?.status.unwrap_or_default();
+    enforce_transition(
+        &ticket.id,
+        old_status,
+        TicketStatus::Cancelled,
+        TransitionVia::Expire,
+        false,
+    )
+    .await?;
+    ticket.update_status(TicketStatus::Cancelled, None)?;
+    ticket.update_field("resolution", &TicketResolution::Wontfix.to_string())?;
+    ticket.add_note(&format!("Expired: passed its expiry date of {expires}"))
+}
+
+/// Cancel open tickets whose `expires` date has passed.
+pub async fn cmd_expire(dry_run: bool, output: OutputOptions) -> Result<()> {
+    let today = jiff::Zoned::now().date();
+    let store = get_or_init_store().await?;
+    let mut candidates: Vec<(String, String)> = store
+        .get_all_tickets()
+        .iter()
+        .filter(|t| t.is_expired_on(today))
+        .filter_map(|t| {
+            let id = t.id.as_ref()?.to_string();
+            Some((id, t.expires?.to_string()))
+        })
+        .collect();
+    candidates.sort();
+
+    if dry_run {
+        let ids: Vec<&str> = candidates.iter().map(|(id, _)| id.as_str()).collect();
+        let text = if ids.is_empty() {
+            "No tickets have expired.".to_string()
+        } else {
+            format!(
+                "Would cancel {} expired ticket(s): {}",
+                ids.len(),
+                ids.join(", ")
+            )
+        };
+        return CommandOutput::new(json!({
+            "expired": [],
+            "candidates": ids,
+            "dry_run": true,
+        }))
+        .with_text(text)
+        .print(output);
+    }
+
+    let mut expired = Vec::new();
+    let mut errors = Vec::new();
+    for (id, expires) in candidates {
+        match expire_ticket(&id, &expires).await {
+            Ok(()) => expired.push(id),
+            Err(e) => errors.push((id, e.to_string())),
+        }
+    }
+
+    let mut parts = Vec::new();
+    if expired.is_empty() {
+        parts.push("No tickets have expired.".to_string());
+    } else {
+        parts.push(format!(
+            "Cancelled {} expired ticket(s): {}",
+            expired.len(),
+            expired.join(", ")
+        ));
+    }
+    if !errors.is_empty() {
+        parts.push(format!(
+            "Errors on {} ticket(s): {}",
+            errors.len(),
+            errors
+                .iter()
+                .map(|(id, err)| format!("{id} ({err})"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    CommandOutput::new(json!({
+        "expired": expired,
+        "errors": errors
+            .iter()
+            .map(|(id, err)| json!({"id": id, "error": err}))
+            .collect::<Vec<_>>(),
+        "dry_run": false,
+    }))
+    .with_text(parts.join("\n"))
+    .print(output)
+}
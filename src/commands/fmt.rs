@@ -0,0 +1,258 @@
+//! Normalize ticket and plan files (`janus fmt`)
+//!
+//! Files written by hand, by older versions, or by other tools drift apart in
+//! small ways: frontmatter keys in a different order, timestamps with offsets
+//! or fractional seconds, flow-style arrays, stray `#` headings. Formatting
+//! rewrites every file the way Janus itself writes them, so diffs only show
+//! real changes.
+
+use std::path::PathBuf;
+
+use jiff::Timestamp;
+use serde_json::json;
+use serde_yaml_ng::{Mapping, Value};
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::error::{JanusError, Result};
+use crate::fs::{read_file, transaction, write_file_atomic};
+use crate::markdown_formatter::format_markdown;
+use crate::parser::{FrontmatterFormat, convert_frontmatter, split_frontmatter};
+use crate::types::{TicketField, plans_dir, tickets_items_dir};
+use crate::utils::{find_markdown_files, format_relative_path};
+
+/// Frontmatter keys that hold a timestamp
+const TIMESTAMP_FIELDS: &[&str] = &[
+    "created",
+    "completed-at",
+    "started-at",
+    "closed-at",
+    "snoozed-until",
+    "locked-until",
+];
+
+/// Ticket body sections, which always use level-2 headings
+const TICKET_SECTIONS: &[&str] = &[
+    "description",
+    "design",
+    "design notes",
+    "acceptance criteria",
+    "notes",
+];
+
+/// Plan frontmatter keys in the order `serialize_plan` writes them
+const PLAN_KEY_ORDER: &[&str] = &["id", "uuid", "created"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DocumentKind {
+    Ticket,
+    Plan,
+}
+
+/// Format every ticket and plan file, or with `check` only report the files
+/// that are not formatted.
+///
+/// Returns whether every file was already formatted, so `--check` can fail.
+pub async fn cmd_fmt(check: bool, output: OutputOptions) -> Result<(bool, Vec<String>)> {
+    let mut files = Vec::new();
+    for (dir, kind) in [
+        (tickets_items_dir(), DocumentKind::Ticket),
+        (plans_dir(), DocumentKind::Plan),
+    ] {
+        let mut names = find_markdown_files(&dir)?;
+        names.sort();
+        files.extend(names.into_iter().map(|name| (dir.join(name), kind)));
+    }
+
+    let mut changed: Vec<(PathBuf, String)> = Vec::new();
+    let mut failed: Vec<(String, String)> = Vec::new();
+    for (path, kind) in &files {
+        let content = read_file(path)?;
+        match format_document(&content, *kind) {
+            Ok(formatted) if formatted != content => changed.push((path.clone(), formatted)),
+            Ok(_) => {}
+            Err(e) => failed.push((format_relative_path(path), e.to_string())),
+        }
+    }
+
+    if !check && !changed.is_empty() {
+        transaction("fmt", || async {
+            for (path, formatted) in &changed {
+                write_file_atomic(path, formatted)?;
+            }
+            Ok(())
+        })
+        .await?;
+    }
+
+    let changed_names: Vec<String> = changed
+        .iter()
+        .map(|(path, _)| format_relative_path(path))
+        .collect();
+    let mut lines: Vec<String> = changed_names
+        .iter()
+        .map(|name| {
+            if check {
+                format!("Would format {name}")
+            } else {
+                format!("Formatted {name}")
+            }
+        })
+        .collect();
+    for (name, error) in &failed {
+        lines.push(format!("Skipped {name}: {error}"));
+    }
+    if changed.is_empty() {
+        lines.push(format!("All {} file(s) already formatted", files.len()));
+    }
+
+    CommandOutput::new(json!({
+        "check": check,
+        "files": files.len(),
+        "changed": changed_names,
+        "failed": failed
+            .iter()
+            .map(|(file, error)| json!({ "file": file, "error": error }))
+            .collect::<Vec<_>>(),
+    }))
+    .with_text(lines.join("\n"))
+    .print(output)?;
+
+    let clean = failed.is_empty() && (!check || changed.is_empty());
+    Ok((clean, changed_names))
+}
+
+/// The formatted version of a ticket or plan file.
+fn format_document(content: &str, kind: DocumentKind) -> Result<String> {
+    let format = FrontmatterFormat::detect(content);
+    let (frontmatter, body) = split_frontmatter(content)?;
+
+    let mapping: Mapping = if frontmatter.trim().is_empty() {
+        Mapping::new()
+    } else {
+        serde_yaml_ng::from_str(&frontmatter).map_err(|e| {
+            JanusError::InvalidFormat(format!("Failed to parse frontmatter YAML: {e}"))
+        })?
+    };
+    let key_order: Vec<&str> = match kind {
+        DocumentKind::Ticket => TicketField::all().iter().map(|f| f.as_str()).collect(),
+        DocumentKind::Plan => PLAN_KEY_ORDER.to_vec(),
+    };
+    let mapping = normalize_frontmatter(mapping, &key_order);
+    let frontmatter = serde_yaml_ng::to_string(&mapping)
+        .map_err(|e| JanusError::InternalError(format!("Failed to serialize frontmatter: {e}")))?;
+
+    let body = normalize_headings(&format_markdown(body.trim_start_matches('\n')), kind);
+    let body = format!("{}\n", body.trim_end());
+
+    convert_frontmatter(&format!("---\n{frontmatter}---\n{body}"), format)
+}
+
+/// Put known keys in `key_order` and the rest alphabetically after them,
+/// and write every timestamp as `YYYY-MM-DDTHH:MM:SSZ`.
+fn normalize_frontmatter(mut mapping: Mapping, key_order: &[&str]) -> Mapping {
+    let mut normalized = Mapping::new();
+    for key in key_order {
+        if let Some(value) = mapping.remove(*key) {
+            normalized.insert((*key).into(), value);
+        }
+    }
+    let mut rest: Vec<(Value, Value)> = mapping.into_iter().collect();
+    rest.sort_by_key(|(k, _)| k.as_str().unwrap_or_default().to_string());
+    normalized.extend(rest);
+
+    for field in TIMESTAMP_FIELDS {
+        if let Some(value) = normalized.get_mut(*field) {
+            normalize_timestamp(value);
+        }
+    }
+    if let Some(Value::Sequence(history)) = normalized.get_mut("status-history") {
+        for entry in history {
+            if let Some(at) = entry.get_mut("at") {
+                normalize_timestamp(at);
+            }
+        }
+    }
+    normalized
+}
+
+/// Rewrite an RFC 3339 timestamp in UTC without fractional seconds. Other
+/// values, such as bare dates, are left alone.
+fn normalize_timestamp(value: &mut Value) {
+    if let Some(ts) = value.as_str().and_then(|s| s.parse::<Timestamp>().ok()) {
+        *value = Value::String(ts.strftime("%Y-%m-%dT%H:%M:%SZ").to_string());
+    }
+}
+
+/// Keep the first level-1 heading as the title and demote later ones to
+/// level 2. In tickets, the standard sections are also moved to level 2.
+fn normalize_headings(body: &str, kind: DocumentKind) -> String {
+    let mut in_fence = false;
+    let mut seen_title = false;
+    let lines: Vec<String> = body
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_fence = !in_fence;
+            }
+            let Some((level, text)) = (!in_fence).then(|| parse_heading(line)).flatten() else {
+                return line.to_string();
+            };
+
+            let level = if level == 1 && !seen_title {
+                seen_title = true;
+                1
+            } else if level == 1 {
+                2
+            } else if kind == DocumentKind::Ticket
+                && TICKET_SECTIONS.contains(&text.to_lowercase().as_str())
+            {
+                2
+            } else {
+                level
+            };
+            format!("{} {text}", "#".repeat(level))
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// The level and text of an ATX heading line
+fn parse_heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let rest = &line[level..];
+    if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with(' ')) {
+        return None;
+    }
+    Some((level, rest.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_ticket() {
+        let content = "---\npriority: 1\nlabels: [ui, auth]\nid: j-a1b2\ncustom: x\nstatus: new\ncreated: 2024-06-01T12:00:00.250+02:00\n---\n# Login fails\n\n# Details\n\n### Notes\n\n* one\n";
+
+        let formatted = format_document(content, DocumentKind::Ticket).unwrap();
+        assert_eq!(
+            formatted,
+            "---\nid: j-a1b2\nstatus: new\ncreated: 2024-06-01T10:00:00Z\npriority: 1\nlabels:\n- ui\n- auth\ncustom: x\n---\n# Login fails\n\n## Details\n\n## Notes\n\n- one\n"
+        );
+        assert_eq!(
+            format_document(&formatted, DocumentKind::Ticket).unwrap(),
+            formatted
+        );
+    }
+
+    #[test]
+    fn test_normalize_headings() {
+        let body = "# Title\n\n# Extra\n\n```\n# not a heading\n```\n\n#hashtag";
+        assert_eq!(
+            normalize_headings(body, DocumentKind::Plan),
+            "# Title\n\n## Extra\n\n```\n# not a heading\n```\n\n#hashtag"
+        );
+    }
+}
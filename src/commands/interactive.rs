@@ -3,11 +3,19 @@
 //! This module provides reusable components for user interaction,
 //! separating CLI prompting logic from business logic.
 
+use std::fmt::Display;
 use std::io::{self, Write};
+use std::str::FromStr;
+
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
 
 use crate::error::{JanusError, Result};
 use crate::utils::is_stdin_tty;
 
+/// Maximum number of matches listed by [`fuzzy_pick`]
+const MAX_PICK_CHOICES: usize = 10;
+
 /// Prompt user for yes/no confirmation
 ///
 /// # Arguments
@@ -208,3 +216,116 @@ pub fn prompt_choice(
         );
     }
 }
+
+/// Prompt user for a value that must parse as `T`, re-prompting until it does
+///
+/// # Arguments
+/// * `prompt` - The prompt message
+/// * `default` - Value used if user just presses Enter
+/// * `valid_values` - Listed when the input does not parse
+///
+/// # Example
+/// ```no_run
+/// # use janus::commands::interactive::prompt_parsed;
+/// # use janus::types::TicketType;
+/// let ticket_type = prompt_parsed("Type", TicketType::Task, TicketType::ALL_STRINGS).unwrap();
+/// ```
+pub fn prompt_parsed<T>(prompt: &str, default: T, valid_values: &[&str]) -> Result<T>
+where
+    T: FromStr + Display,
+{
+    let default_str = default.to_string();
+    loop {
+        let input = prompt_text(prompt, Some(&default_str))?;
+        match input.parse() {
+            Ok(value) => return Ok(value),
+            Err(_) => println!(
+                "Invalid value '{input}'. Please enter one of: {}.",
+                valid_values.join(", ")
+            ),
+        }
+    }
+}
+
+/// Prompt user for a search query and pick one of the matching candidates
+///
+/// Candidates are `(id, label)` pairs, fuzzy-matched on both. A single match
+/// is picked directly; several are listed to choose from.
+///
+/// # Returns
+/// * The ID of the picked candidate
+/// * `None` if user entered an empty query
+///
+/// # Example
+/// ```no_run
+/// # use janus::commands::interactive::fuzzy_pick;
+/// let plans = [("plan-a1b2".to_string(), "Auth revamp".to_string())];
+/// let plan = fuzzy_pick("Add to plan", &plans).unwrap();
+/// ```
+pub fn fuzzy_pick(prompt: &str, candidates: &[(String, String)]) -> Result<Option<String>> {
+    loop {
+        let query = prompt_text(prompt, None)?;
+        if query.is_empty() {
+            return Ok(None);
+        }
+
+        let mut matches = fuzzy_matches(&query, candidates);
+        match matches.len() {
+            0 => println!("No matches for '{query}'."),
+            1 => {
+                let (id, label) = matches[0];
+                println!("  {id}  {label}");
+                return Ok(Some(id.clone()));
+            }
+            _ => {
+                matches.truncate(MAX_PICK_CHOICES);
+                for (i, (id, label)) in matches.iter().enumerate() {
+                    println!("  [{i}] {id}  {label}");
+                }
+                let options: Vec<&str> = matches.iter().map(|(id, _)| id.as_str()).collect();
+                let choice = select_option("Select one", &options, Some(0))?;
+                return Ok(Some(matches[choice].0.clone()));
+            }
+        }
+    }
+}
+
+/// Candidates whose ID or label fuzzy-match `query`, best first
+fn fuzzy_matches<'a>(query: &str, candidates: &'a [(String, String)]) -> Vec<&'a (String, String)> {
+    let matcher = SkimMatcherV2::default().smart_case();
+    let mut scored: Vec<(i64, &(String, String))> = candidates
+        .iter()
+        .filter_map(|candidate| {
+            let (id, label) = candidate;
+            let score = matcher.fuzzy_match(&format!("{id} {label}"), query)?;
+            Some((score, candidate))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.0.cmp(&b.1.0)));
+    scored.into_iter().map(|(_, candidate)| candidate).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_matches() {
+        let candidates = vec![
+            ("j-a1b2".to_string(), "Fix login crash".to_string()),
+            ("j-c3d4".to_string(), "Add logging".to_string()),
+            ("j-e5f6".to_string(), "Refactor parser".to_string()),
+        ];
+
+        let ids = |query: &str| -> Vec<String> {
+            fuzzy_matches(query, &candidates)
+                .into_iter()
+                .map(|(id, _)| id.clone())
+                .collect()
+        };
+        assert_eq!(ids("login crash"), vec!["j-a1b2"]);
+        assert_eq!(ids("e5f6"), vec!["j-e5f6"]);
+        assert!(ids("log").contains(&"j-c3d4".to_string()));
+        assert!(ids("zzz").is_empty());
+    }
+}
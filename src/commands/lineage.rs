@@ -0,0 +1,235 @@
+//! Spawned-from lineage tree (`janus lineage`)
+//!
+//! Tickets created while decomposing work record the ticket they came from in
+//! `spawned-from`. The lineage of a ticket is its chain of ancestors up to the
+//! origin ticket, followed by everything spawned from it in turn.
+
+use std::collections::{HashMap, HashSet};
+
+use serde_json::json;
+
+use super::{CommandOutput, ticket_minimal_json_with_exists};
+use crate::cli::OutputOptions;
+use crate::display::{accent, bold, dim, format_status_colored};
+use crate::error::Result;
+use crate::graph::resolve_id_from_map;
+use crate::ticket::build_ticket_map;
+use crate::types::TicketMetadata;
+
+/// Show the spawned-from ancestry and descendants of a ticket
+pub async fn cmd_lineage(id: &str, output: OutputOptions) -> Result<()> {
+    let ticket_map = build_ticket_map().await?;
+    let id = resolve_id_from_map(id, &ticket_map)?;
+
+    let ancestors = ancestors(&id, &ticket_map);
+    let children = children_index(&ticket_map);
+
+    // Ancestors are already drawn above the ticket, so a cycle back to one of
+    // them ends the descendant walk
+    let mut visited: HashSet<String> = ancestors.iter().cloned().collect();
+    visited.insert(id.clone());
+    let tree = descendants_json(&id, &ticket_map, &children, &mut visited.clone());
+
+    let json_output = json!({
+        "id": id,
+        "ancestors": ancestors
+            .iter()
+            .map(|a| ticket_minimal_json_with_exists(a, ticket_map.get(a)))
+            .collect::<Vec<_>>(),
+        "tree": tree,
+    });
+
+    // Ancestors form a single chain from the origin down to the ticket
+    let mut lines = Vec::new();
+    let mut prefix = String::new();
+    for (depth, ancestor) in ancestors.iter().enumerate() {
+        let connector = if depth == 0 { "" } else { "└── " };
+        lines.push(format!(
+            "{}{}{}",
+            dim(&prefix),
+            dim(connector),
+            format_node(ancestor, &ticket_map)
+        ));
+        if depth > 0 {
+            prefix.push_str("    ");
+        }
+    }
+    let connector = if ancestors.is_empty() {
+        ""
+    } else {
+        "└── "
+    };
+    lines.push(format!(
+        "{}{}{}",
+        dim(&prefix),
+        dim(connector),
+        bold(&format_node(&id, &ticket_map))
+    ));
+    if !ancestors.is_empty() {
+        prefix.push_str("    ");
+    }
+    format_descendants(
+        &id,
+        &prefix,
+        &ticket_map,
+        &children,
+        &mut visited,
+        &mut lines,
+    );
+
+    CommandOutput::new(json_output)
+        .with_text(lines.join("\n"))
+        .paged()
+        .print(output)
+}
+
+/// Ancestors of `id` from the origin ticket down to its direct parent.
+///
+/// Stops at a missing ticket (which is still included) or a cycle.
+fn ancestors(id: &str, ticket_map: &HashMap<String, TicketMetadata>) -> Vec<String> {
+    let mut chain = Vec::new();
+    let mut seen = HashSet::from([id.to_string()]);
+    let mut current = ticket_map.get(id).and_then(|t| t.spawned_from.as_deref());
+    while let Some(parent) = current {
+        if !seen.insert(parent.to_string()) {
+            break;
+        }
+        chain.push(parent.to_string());
+        current = ticket_map
+            .get(parent)
+            .and_then(|t| t.spawned_from.as_deref());
+    }
+    chain.reverse();
+    chain
+}
+
+/// Map of ticket ID to the IDs of tickets spawned from it, sorted
+fn children_index(ticket_map: &HashMap<String, TicketMetadata>) -> HashMap<String, Vec<String>> {
+    let mut children: HashMap<String, Vec<String>> = HashMap::new();
+    for (id, ticket) in ticket_map {
+        if let Some(parent) = ticket.spawned_from.as_deref() {
+            children
+                .entry(parent.to_string())
+                .or_default()
+                .push(id.clone());
+        }
+    }
+    for ids in children.values_mut() {
+        ids.sort();
+    }
+    children
+}
+
+fn descendants_json(
+    id: &str,
+    ticket_map: &HashMap<String, TicketMetadata>,
+    children: &HashMap<String, Vec<String>>,
+    visited: &mut HashSet<String>,
+) -> serde_json::Value {
+    let unvisited: Vec<&String> = children
+        .get(id)
+        .into_iter()
+        .flatten()
+        .filter(|child| visited.insert(child.to_string()))
+        .collect();
+    let spawned: Vec<serde_json::Value> = unvisited
+        .into_iter()
+        .map(|child| descendants_json(child, ticket_map, children, visited))
+        .collect();
+
+    let mut node = ticket_minimal_json_with_exists(id, ticket_map.get(id));
+    node["spawned"] = json!(spawned);
+    node
+}
+
+fn format_descendants(
+    id: &str,
+    prefix: &str,
+    ticket_map: &HashMap<String, TicketMetadata>,
+    children: &HashMap<String, Vec<String>>,
+    visited: &mut HashSet<String>,
+    lines: &mut Vec<String>,
+) {
+    let spawned: Vec<&String> = children
+        .get(id)
+        .into_iter()
+        .flatten()
+        .filter(|child| visited.insert(child.to_string()))
+        .collect();
+
+    for (i, child) in spawned.iter().enumerate() {
+        let is_last = i == spawned.len() - 1;
+        let connector = if is_last { "└── " } else { "├── " };
+        let child_prefix = if is_last { "    " } else { "│   " };
+
+        lines.push(format!(
+            "{}{}{}",
+            dim(prefix),
+            dim(connector),
+            format_node(child, ticket_map)
+        ));
+        format_descendants(
+            child,
+            &format!("{prefix}{child_prefix}"),
+            ticket_map,
+            children,
+            visited,
+            lines,
+        );
+    }
+}
+
+fn format_node(id: &str, ticket_map: &HashMap<String, TicketMetadata>) -> String {
+    let Some(ticket) = ticket_map.get(id) else {
+        return format!("{} {}", accent(id), dim("[missing]"));
+    };
+    let status = ticket
+        .status
+        .map(format_status_colored)
+        .unwrap_or_else(|| "[?]".to_string());
+    format!(
+        "{} {} {}",
+        accent(id),
+        status,
+        ticket.title.as_deref().unwrap_or("")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TicketId;
+
+    fn ticket(spawned_from: Option<&str>) -> TicketMetadata {
+        TicketMetadata {
+            spawned_from: spawned_from.map(TicketId::new_unchecked),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_ancestors_and_children() {
+        let ticket_map = HashMap::from([
+            ("j-root".to_string(), ticket(None)),
+            ("j-mid".to_string(), ticket(Some("j-root"))),
+            ("j-leaf".to_string(), ticket(Some("j-mid"))),
+            ("j-sib".to_string(), ticket(Some("j-mid"))),
+            ("j-orphan".to_string(), ticket(Some("j-gone"))),
+            ("j-loop1".to_string(), ticket(Some("j-loop2"))),
+            ("j-loop2".to_string(), ticket(Some("j-loop1"))),
+        ]);
+
+        assert_eq!(ancestors("j-leaf", &ticket_map), vec!["j-root", "j-mid"]);
+        assert!(ancestors("j-root", &ticket_map).is_empty());
+        assert_eq!(ancestors("j-orphan", &ticket_map), vec!["j-gone"]);
+        assert_eq!(ancestors("j-loop1", &ticket_map), vec!["j-loop2"]);
+
+        let children = children_index(&ticket_map);
+        assert_eq!(children["j-mid"], vec!["j-leaf", "j-sib"]);
+
+        let mut visited = HashSet::from(["j-root".to_string()]);
+        let tree = descendants_json("j-root", &ticket_map, &children, &mut visited);
+        assert_eq!(tree["spawned"][0]["id"], "j-mid");
+        assert_eq!(tree["spawned"][0]["spawned"].as_array().unwrap().len(), 2);
+    }
+}
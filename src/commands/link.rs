@@ -3,6 +3,7 @@ use serde_json::json;
 use super::CommandOutput;
 use crate::cli::OutputOptions;
 use crate::error::{JanusError, Result};
+use crate::fs::transaction;
 use crate::ticket::{ArrayField, Ticket};
 
 /// Add symmetric links between tickets
@@ -29,28 +30,32 @@ pub async fn cmd_link_add(ids: &[String], output: OutputOptions) -> Result<()> {
         tickets.push(Ticket::find(id).await?);
     }
 
-    let mut added_count = 0;
-    let mut asymmetric_warnings: Vec<(String, String)> = Vec::new();
-
-    // Add links between all pairs
-    for ticket in &tickets {
-        for other in &tickets {
-            if ticket.id != other.id {
-                let has_existing_link = ticket.has_in_array_field(ArrayField::Links, &other.id)?;
-                let other_has_link = other.has_in_array_field(ArrayField::Links, &ticket.id)?;
-
-                // Detect one-way link: other -> ticket exists, but ticket -> other does not yet
-                if !has_existing_link && other_has_link {
-                    asymmetric_warnings.push((other.id.clone(), ticket.id.clone()));
-                }
-
-                if ticket.add_to_array_field(ArrayField::Links, &other.id)? {
-                    added_count += 1;
-                    // Event logging is now handled in Ticket::add_to_array_field at the domain layer
+    // Add links between all pairs, all or none
+    let (added_count, asymmetric_warnings) = transaction("link add", || async {
+        let mut added_count = 0;
+        let mut asymmetric_warnings: Vec<(String, String)> = Vec::new();
+        for ticket in &tickets {
+            for other in &tickets {
+                if ticket.id != other.id {
+                    let has_existing_link =
+                        ticket.has_in_array_field(ArrayField::Links, &other.id)?;
+                    let other_has_link = other.has_in_array_field(ArrayField::Links, &ticket.id)?;
+
+                    // Detect one-way link: other -> ticket exists, but ticket -> other does not yet
+                    if !has_existing_link && other_has_link {
+                        asymmetric_warnings.push((other.id.clone(), ticket.id.clone()));
+                    }
+
+                    if ticket.add_to_array_field(ArrayField::Links, &other.id)? {
+                        added_count += 1;
+                        // Event logging is now handled in Ticket::add_to_array_field at the domain layer
+                    }
                 }
             }
         }
-    }
+        Ok((added_count, asymmetric_warnings))
+    })
+    .await?;
 
     // Report any one-way links that were detected and fixed
     let asymmetric_messages: Vec<String> = asymmetric_warnings
@@ -103,20 +108,15 @@ pub async fn cmd_link_remove(id1: &str, id2: &str, output: OutputOptions) -> Res
     let ticket1 = Ticket::find(id1).await?;
     let ticket2 = Ticket::find(id2).await?;
 
-    let mut removed_count = 0;
-    let mut removed_1_to_2 = false;
-    let mut removed_2_to_1 = false;
-
-    if ticket1.remove_from_array_field(ArrayField::Links, &ticket2.id)? {
-        removed_count += 1;
-        removed_1_to_2 = true;
-        // Event logging is now handled in Ticket::remove_from_array_field at the domain layer
-    }
-    if ticket2.remove_from_array_field(ArrayField::Links, &ticket1.id)? {
-        removed_count += 1;
-        removed_2_to_1 = true;
-        // Event logging is now handled in Ticket::remove_from_array_field at the domain layer
-    }
+    // Event logging is handled in Ticket::remove_from_array_field at the domain layer
+    let (removed_1_to_2, removed_2_to_1) = transaction("link remove", || async {
+        Ok((
+            ticket1.remove_from_array_field(ArrayField::Links, &ticket2.id)?,
+            ticket2.remove_from_array_field(ArrayField::Links, &ticket1.id)?,
+        ))
+    })
+    .await?;
+    let removed_count = usize::from(removed_1_to_2) + usize::from(removed_2_to_1);
 
     if removed_count == 0 {
         return Err(JanusError::LinkNotFound);
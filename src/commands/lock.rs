@@ -0,0 +1,151 @@
+//! Advisory ticket locks (`janus lock`, `janus unlock`)
+//!
+//! A lock records who is working a ticket in its `locked-by` and
+//! `locked-until` fields, so that several agents sharing a repository don't
+//! pick up the same ticket. Locks are advisory: they hide the ticket from
+//! `janus ready` and `janus next` until they expire, but don't block writes.
+//!
+//! Taking or releasing a lock holds an exclusive OS lock on the ticket (see
+//! [`crate::fs::exclusive_lock`]) from reading the current holder until both
+//! fields are written, so two agents locking at once can't both succeed.
+
+use jiff::{SignedDuration, Timestamp};
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::error::{JanusError, Result};
+use crate::ticket::Ticket;
+use crate::types::TicketMetadata;
+
+/// Lock holder used when `--owner` is not given: `$JANUS_AGENT`, then the
+/// login name
+fn default_lock_owner() -> String {
+    ["JANUS_AGENT", "USER", "USERNAME"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.trim().is_empty()))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Keep other `janus lock`/`unlock` runs off `ticket` until the guard is dropped
+fn lock_ticket(ticket: &Ticket) -> Result<std::fs::File> {
+    crate::fs::exclusive_lock(&format!("ticket-{}", ticket.id))
+}
+
+/// Fail if `metadata` holds an unexpired lock taken by someone other than `owner`
+fn check_lock(id: &str, metadata: &TicketMetadata, owner: &str, now: Timestamp) -> Result<()> {
+    match metadata.lock_holder_at(now) {
+        Some(holder) if holder != owner => Err(JanusError::TicketLocked {
+            id: id.to_string(),
+            owner: holder.to_string(),
+            until: metadata
+                .locked_until
+                .as_ref()
+                .map(|u| u.to_string())
+                .unwrap_or_default(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Lock a ticket for `owner` for `ttl`
+///
+/// Locking a ticket you already hold extends the lock. A lock held by someone
+/// else is only replaced with `force`.
+pub async fn cmd_lock(
+    id: &str,
+    owner: Option<&str>,
+    ttl: SignedDuration,
+    force: bool,
+    output: OutputOptions,
+) -> Result<()> {
+    if ttl.is_zero() || ttl.is_negative() {
+        return Err(JanusError::InvalidInput(
+            "lock TTL must be greater than zero".to_string(),
+        ));
+    }
+
+    let ticket = Ticket::find(id).await?;
+    let _guard = lock_ticket(&ticket)?;
+    let metadata = ticket.read()?;
+    let owner = owner.map_or_else(default_lock_owner, str::to_string);
+    let now = Timestamp::now();
+
+    if !force {
+        check_lock(&ticket.id, &metadata, &owner, now)?;
+    }
+
+    let until = now
+        .checked_add(ttl)
+        .map_err(|_| JanusError::InvalidInput("lock TTL is out of range".to_string()))?;
+    let until = until.strftime("%Y-%m-%dT%H:%M:%SZ").to_string();
+    ticket.update_fields(&[
+        ("locked-by", Some(owner.as_str())),
+        ("locked-until", Some(until.as_str())),
+    ])?;
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "locked",
+        "locked_by": owner,
+        "locked_until": until,
+    }))
+    .with_text(format!("Locked {} for {owner} until {until}", ticket.id))
+    .print(output)
+}
+
+/// Release a ticket's lock
+///
+/// Releasing a lock held by someone else requires `force`; expired locks can
+/// always be cleared.
+pub async fn cmd_unlock(
+    id: &str,
+    owner: Option<&str>,
+    force: bool,
+    output: OutputOptions,
+) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+    let _guard = lock_ticket(&ticket)?;
+    let metadata = ticket.read()?;
+    let owner = owner.map_or_else(default_lock_owner, str::to_string);
+
+    if !force {
+        check_lock(&ticket.id, &metadata, &owner, Timestamp::now())?;
+    }
+    if metadata.locked_by.is_some() || metadata.locked_until.is_some() {
+        ticket.update_fields(&[("locked-by", None), ("locked-until", None)])?;
+    }
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "unlocked",
+    }))
+    .with_text(format!("Unlocked {}", ticket.id))
+    .print(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CreatedAt;
+
+    #[test]
+    fn test_check_lock() {
+        let now: Timestamp = "2024-06-01T12:00:00Z".parse().unwrap();
+        let locked = TicketMetadata {
+            locked_by: Some("agent-a".to_string()),
+            locked_until: Some(CreatedAt::new_unchecked("2024-06-01T13:00:00Z")),
+            ..Default::default()
+        };
+
+        assert!(check_lock("j-a1b2", &locked, "agent-a", now).is_ok());
+        assert!(matches!(
+            check_lock("j-a1b2", &locked, "agent-b", now),
+            Err(JanusError::TicketLocked { owner, .. }) if owner == "agent-a"
+        ));
+
+        let later: Timestamp = "2024-06-01T13:00:00Z".parse().unwrap();
+        assert!(check_lock("j-a1b2", &locked, "agent-b", later).is_ok());
+        assert!(check_lock("j-a1b2", &TicketMetadata::default(), "agent-b", now).is_ok());
+    }
+}
@@ -1,26 +1,36 @@
 use std::collections::HashSet;
 use std::fmt::Write;
 
+use jiff::Timestamp;
+
 use super::{
     CommandOutput, FormatOptions, format_deps, format_ticket_line, get_next_items_phased,
     get_next_items_simple, ticket_to_json,
 };
 use crate::cli::OutputOptions;
+use crate::config::{Config, ThemeColor};
+use crate::display::{dim, paint};
 use crate::error::{JanusError, Result};
 use crate::plan::Plan;
 use crate::query::{
-    ActiveFilter, BlockedFilter, ClosedFilter, ReadyFilter, SizeFilter, SortField, SpawningFilter,
-    StatusFilter, TicketQueryBuilder, TriagedFilter,
+    ActiveFilter, BlockedFilter, ClosedFilter, ClosedSinceFilter, NotArchivedFilter,
+    NotSnoozedFilter, ReadyFilter, SizeFilter, SortField, SpawningFilter, StatusFilter,
+    TicketQueryBuilder, TriagedFilter,
 };
+use crate::sla::{SlaState, evaluate_ticket, worst_state};
 use crate::ticket::{Ticket, build_ticket_map, get_all_tickets_with_map};
 use crate::types::{TicketMetadata, TicketSize, TicketStatus};
 
 /// Options for the `ls` command, bundling all filter and display parameters.
+#[derive(Clone)]
 pub struct LsOptions {
     pub filter_ready: bool,
     pub filter_blocked: bool,
     pub filter_closed: bool,
     pub filter_active: bool,
+    pub closed_since: Option<Timestamp>,
+    pub include_archived: bool,
+    pub include_snoozed: bool,
     pub status_filter: Option<TicketStatus>,
     pub spawned_from: Option<String>,
     pub depth: Option<u32>,
@@ -30,6 +40,9 @@ pub struct LsOptions {
     pub triaged: Option<bool>,
     pub size_filter: Option<Vec<TicketSize>>,
     pub label_filter: Option<Vec<String>>,
+    pub component_filter: Option<Vec<String>>,
+    pub prefix_filter: Option<Vec<String>>,
+    pub reopened: bool,
     pub limit: Option<usize>,
     pub sort_by: SortField,
     pub output: OutputOptions,
@@ -43,6 +56,9 @@ impl LsOptions {
             filter_blocked: false,
             filter_closed: false,
             filter_active: false,
+            closed_since: None,
+            include_archived: false,
+            include_snoozed: false,
             status_filter: None,
             spawned_from: None,
             depth: None,
@@ -52,6 +68,9 @@ impl LsOptions {
             triaged: None,
             size_filter: None,
             label_filter: None,
+            component_filter: None,
+            prefix_filter: None,
+            reopened: false,
             limit: None,
             sort_by: SortField::default(),
             output: OutputOptions { json: false },
@@ -94,14 +113,64 @@ impl Default for LsOptions {
 
 /// Formats a list of tickets for output, handling both JSON and text formats.
 /// This helper consolidates the common output formatting logic used by listing commands.
+///
+/// Tickets at risk of missing, or past, a configured SLA are marked in the text
+/// output and carry an `sla` array in the JSON output. Open tickets past
+/// their `expires` date are marked as expired in both.
 fn format_ticket_list(display_tickets: &[TicketMetadata], output: OutputOptions) -> Result<()> {
-    let json_tickets: Vec<_> = display_tickets.iter().map(ticket_to_json).collect();
+    let sla = Config::load().map(|c| c.sla).unwrap_or_default();
+    let now = Timestamp::now();
+    let today = jiff::Zoned::now().date();
 
+    let mut json_tickets = Vec::with_capacity(display_tickets.len());
     // Build text output incrementally to avoid intermediate allocations
     let mut text_output = String::new();
     for (i, t) in display_tickets.iter().enumerate() {
+        let findings = evaluate_ticket(t, &sla, now);
+
+        let mut json = ticket_to_json(t);
+        if !findings.is_empty() {
+            json["sla"] = findings.iter().map(|f| f.to_json(now)).collect();
+        }
+        let expired = t.is_expired_on(today);
+        if expired {
+            json["expired"] = serde_json::Value::Bool(true);
+        }
+        json_tickets.push(json);
+
+        let mut suffix = format_deps(&t.deps);
+        if let Some(resolution) = t.resolution
+            && t.status.is_some_and(|s| s.is_terminal())
+        {
+            write!(suffix, " {}", dim(&format!("[{resolution}]"))).unwrap();
+        }
+        if let Some(holder) = t.lock_holder_at(now) {
+            write!(suffix, " {}", dim(&format!("[locked by {holder}]"))).unwrap();
+        }
+        if let Some(count) = t.reopen_count.filter(|&c| c > 0) {
+            write!(suffix, " {}", dim(&format!("[reopened {count}x]"))).unwrap();
+        }
+        if t.is_snoozed_at(now)
+            && let Some(until) = &t.snoozed_until
+        {
+            let date = crate::display::format_date_for_display(until.as_ref());
+            write!(suffix, " {}", dim(&format!("[snoozed until {date}]"))).unwrap();
+        }
+        if expired && let Some(expires) = t.expires {
+            let label = format!("[expired {expires}]");
+            write!(suffix, " {}", paint(&label, ThemeColor::Red)).unwrap();
+        }
+        match worst_state(&findings) {
+            Some(SlaState::Breached) => {
+                write!(suffix, " {}", paint("[SLA breached]", ThemeColor::Red)).unwrap()
+            }
+            Some(SlaState::AtRisk) => {
+                write!(suffix, " {}", paint("[SLA at risk]", ThemeColor::Yellow)).unwrap()
+            }
+            None => {}
+        }
         let opts = FormatOptions {
-            suffix: Some(format_deps(&t.deps)),
+            suffix: Some(suffix),
             ..Default::default()
         };
         if i > 0 {
@@ -112,6 +181,7 @@ fn format_ticket_list(display_tickets: &[TicketMetadata], output: OutputOptions)
 
     CommandOutput::new(serde_json::Value::Array(json_tickets))
         .with_text(text_output)
+        .paged()
         .print(output)
 }
 
@@ -126,7 +196,14 @@ pub async fn cmd_ls_with_options(opts: LsOptions) -> Result<()> {
                 "--phase cannot be used with --next-in-plan".to_string(),
             ));
         }
-        return cmd_ls_next_in_plan(plan_id, opts.limit, opts.sort_by, opts.output).await;
+        return cmd_ls_next_in_plan(
+            plan_id,
+            opts.limit,
+            opts.sort_by,
+            opts.include_snoozed,
+            opts.output,
+        )
+        .await;
     }
 
     let (tickets, _ticket_map) = get_all_tickets_with_map().await?;
@@ -160,11 +237,43 @@ pub async fn cmd_ls_with_options(opts: LsOptions) -> Result<()> {
         builder = builder.with_filter(Box::new(SizeFilter::new(sizes.clone())));
     }
 
+    // Add closed-since filter if specified (implies --closed)
+    if let Some(since) = opts.closed_since {
+        builder = builder.with_filter(Box::new(ClosedSinceFilter::new(since)));
+    }
+
+    // Hide archived tickets unless asked for, or listed by status
+    if !opts.include_archived && opts.status_filter != Some(TicketStatus::Archived) {
+        builder = builder.with_filter(Box::new(NotArchivedFilter));
+    }
+
+    // Hide snoozed tickets unless asked for
+    if !opts.include_snoozed {
+        builder = builder.with_filter(Box::new(NotSnoozedFilter::new(Timestamp::now())));
+    }
+
     // Add label filter if specified
     if let Some(ref labels) = opts.label_filter {
         builder = builder.with_filter(Box::new(crate::query::LabelFilter::new(labels.clone())));
     }
 
+    // Add component filter if specified
+    if let Some(ref components) = opts.component_filter {
+        builder = builder.with_filter(Box::new(crate::query::ComponentFilter::new(
+            components.clone(),
+        )));
+    }
+
+    // Add ID prefix filter if specified
+    if let Some(ref prefixes) = opts.prefix_filter {
+        builder = builder.with_filter(Box::new(crate::query::PrefixFilter::new(prefixes.clone())));
+    }
+
+    // Add reopened filter if specified
+    if opts.reopened {
+        builder = builder.with_filter(Box::new(crate::query::ReopenedFilter));
+    }
+
     // Add status-based filters
     if let Some(status) = opts.status_filter {
         // --status flag is mutually exclusive with --ready, --blocked, --closed
@@ -174,13 +283,17 @@ pub async fn cmd_ls_with_options(opts: LsOptions) -> Result<()> {
         let mut or_filters: Vec<Box<dyn crate::query::TicketFilter>> = Vec::new();
 
         if opts.filter_ready {
-            or_filters.push(Box::new(ReadyFilter));
+            let require = Config::load().map(|c| c.ready.require).unwrap_or_default();
+            or_filters.push(Box::new(ReadyFilter::new(require, Timestamp::now())));
         }
         if opts.filter_blocked {
             or_filters.push(Box::new(BlockedFilter));
         }
         if opts.filter_closed {
             or_filters.push(Box::new(ClosedFilter));
+            if opts.include_archived {
+                or_filters.push(Box::new(StatusFilter::new(TicketStatus::Archived)));
+            }
         }
         if opts.filter_active {
             or_filters.push(Box::new(ActiveFilter));
@@ -209,6 +322,7 @@ async fn cmd_ls_next_in_plan(
     plan_id: &str,
     limit: Option<usize>,
     sort_by: SortField,
+    include_snoozed: bool,
     output: OutputOptions,
 ) -> Result<()> {
     use crate::query::sort_tickets_by;
@@ -216,6 +330,7 @@ async fn cmd_ls_next_in_plan(
     let plan = Plan::find(plan_id).await?;
     let metadata = plan.read()?;
     let ticket_map = build_ticket_map().await?;
+    let now = Timestamp::now();
 
     // Use a large count to get all next items, then apply limit
     let count = limit.unwrap_or(usize::MAX);
@@ -240,6 +355,7 @@ async fn cmd_ls_next_in_plan(
     let mut display_tickets: Vec<TicketMetadata> = next_ticket_ids
         .iter()
         .filter_map(|id| ticket_map.get(id).cloned())
+        .filter(|t| include_snoozed || !t.is_snoozed_at(now))
         .collect();
 
     // Sort by priority
@@ -0,0 +1,185 @@
+//! Commit messages and pull request descriptions rendered from tickets
+//! (`janus commit-msg`, `janus pr-body`).
+//!
+//! Both fill a template with the ticket's title, type, and body sections. The
+//! built-in templates can be replaced under `messages` in the config (see
+//! [`MessagesConfig`](crate::config::MessagesConfig)). Headings left without
+//! content, for example because the ticket has no design notes, are dropped.
+
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::config::Config;
+use crate::error::Result;
+use crate::ticket::Ticket;
+use crate::types::TicketType;
+use crate::utils::expand_template;
+
+const DEFAULT_COMMIT_TEMPLATE: &str = "{kind}{scope}: {title}\n\n{description}\n\nRefs: {id}";
+
+const DEFAULT_PR_BODY_TEMPLATE: &str = "\
+## Summary
+
+{description}
+
+Ticket: {id}
+
+## Design
+
+{design}
+
+## Acceptance Criteria
+
+{acceptance}";
+
+/// Conventional commit type for a ticket type
+fn conventional_kind(ticket_type: TicketType) -> &'static str {
+    match ticket_type {
+        TicketType::Bug => "fix",
+        TicketType::Feature | TicketType::Epic => "feat",
+        TicketType::Task | TicketType::Chore => "chore",
+    }
+}
+
+/// Turn the list items of an acceptance criteria section into a task list
+fn checklist(acceptance: &str) -> String {
+    acceptance
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            let indent = &line[..line.len() - trimmed.len()];
+            match trimmed
+                .strip_prefix("- ")
+                .or_else(|| trimmed.strip_prefix("* "))
+            {
+                Some(item) if !item.starts_with('[') => format!("{indent}- [ ] {item}"),
+                _ => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Markdown heading level of a line, or 0 if it is not a heading
+fn heading_level(line: &str) -> usize {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if level > 0 && line[level..].starts_with(' ') {
+        level
+    } else {
+        0
+    }
+}
+
+/// Drop headings with nothing under them and collapse runs of blank lines
+fn tidy(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut kept: Vec<&str> = Vec::with_capacity(lines.len());
+    for (i, line) in lines.iter().enumerate() {
+        let level = heading_level(line);
+        if level > 0 {
+            let next = lines[i + 1..].iter().find(|l| !l.trim().is_empty());
+            if next.is_none_or(|next| (1..=level).contains(&heading_level(next))) {
+                continue;
+            }
+        }
+        if line.trim().is_empty() && kept.last().is_none_or(|last| last.trim().is_empty()) {
+            continue;
+        }
+        kept.push(line);
+    }
+    kept.join("\n").trim_end().to_string()
+}
+
+/// Fill `template` (named `name` in errors) with the fields of ticket `id`
+async fn render(id: &str, name: &str, template: &str) -> Result<(String, String)> {
+    let (ticket, metadata) = Ticket::find_and_read(id).await?;
+    let ticket_type = metadata.ticket_type.unwrap_or_default();
+    let title = metadata.title.clone().unwrap_or_default();
+    let scope = metadata
+        .component
+        .as_deref()
+        .map(|c| format!("({c})"))
+        .unwrap_or_default();
+    let description = ticket.extract_description()?.unwrap_or_default();
+    let design = ticket.extract_section("Design")?.unwrap_or_default();
+    let acceptance = ticket
+        .extract_section("Acceptance Criteria")?
+        .map(|a| checklist(&a))
+        .unwrap_or_default();
+
+    let rendered = expand_template(
+        name,
+        template,
+        &[
+            ("id", &ticket.id),
+            ("title", &title),
+            ("type", &ticket_type.to_string()),
+            ("kind", conventional_kind(ticket_type)),
+            ("scope", &scope),
+            ("description", description.trim()),
+            ("design", design.trim()),
+            ("acceptance", acceptance.trim()),
+        ],
+    )?;
+    Ok((ticket.id, tidy(&rendered)))
+}
+
+/// Print a conventional commit message for a ticket
+pub async fn cmd_commit_msg(id: &str, output: OutputOptions) -> Result<()> {
+    let config = Config::load()?;
+    let template = config
+        .messages
+        .commit
+        .as_deref()
+        .unwrap_or(DEFAULT_COMMIT_TEMPLATE);
+    let (id, message) = render(id, "messages.commit", template).await?;
+
+    CommandOutput::new(json!({
+        "id": id,
+        "message": message,
+    }))
+    .with_text(message)
+    .print(output)
+}
+
+/// Print a pull request description for a ticket
+pub async fn cmd_pr_body(id: &str, output: OutputOptions) -> Result<()> {
+    let config = Config::load()?;
+    let template = config
+        .messages
+        .pr_body
+        .as_deref()
+        .unwrap_or(DEFAULT_PR_BODY_TEMPLATE);
+    let (id, body) = render(id, "messages.pr_body", template).await?;
+
+    CommandOutput::new(json!({
+        "id": id,
+        "body": body,
+    }))
+    .with_text(body)
+    .print(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checklist() {
+        assert_eq!(
+            checklist("- Login works\n* Logout works\n- [x] Done already\nPlain text"),
+            "- [ ] Login works\n- [ ] Logout works\n- [x] Done already\nPlain text"
+        );
+    }
+
+    #[test]
+    fn test_tidy_drops_empty_sections() {
+        let text = "## Summary\n\n### Context\nFix it\n\n## Design\n\n\n\n# Notes\n";
+        assert_eq!(tidy(text), "## Summary\n\n### Context\nFix it");
+        assert_eq!(
+            tidy("fix: crash\n\n\n\nRefs: j-1\n"),
+            "fix: crash\n\nRefs: j-1"
+        );
+    }
+}
@@ -1,73 +1,147 @@
 use crate::cli::OutputOptions;
 
+#[cfg(feature = "llm")]
+mod ac;
 mod add_note;
 mod archive;
+mod attach;
+mod backup;
+mod blame;
+mod blocked;
 mod board;
+mod board_export;
 mod cache;
+pub mod complete;
 mod config;
+mod context;
 pub mod create;
+pub mod create_batch;
 mod dep;
 mod dep_tree;
+mod diff;
 mod doc;
 mod doctor;
 mod edit;
+mod escalate;
 mod events;
+mod expire;
+mod fmt;
 pub mod graph;
 pub mod hook;
 pub mod interactive;
 
+mod lineage;
 mod link;
+mod lock;
 mod ls;
+mod messages;
 mod next;
+mod note;
 mod objective;
+mod open;
 mod plan;
+pub mod pr;
+mod prune;
 mod query;
+mod ready;
 mod remote_browse;
+mod report;
+mod schema;
 pub mod search;
 mod set;
 mod show;
+mod sla;
+mod snooze;
+mod stats;
 mod status;
+mod suggest;
+#[cfg(feature = "llm")]
+mod summarize;
 pub mod sync;
+mod triage;
+mod tui;
 mod view;
+mod watch;
+mod worktree;
 
+#[cfg(feature = "llm")]
+pub use ac::cmd_ac_suggest;
 pub use add_note::cmd_add_note;
 pub use archive::cmd_archive;
+pub use attach::{
+    cmd_attach_add, cmd_attach_gc, cmd_attach_get, cmd_attach_ls, cmd_attach_rename, cmd_attach_rm,
+};
+pub use backup::{cmd_backup, cmd_restore};
+pub use blame::cmd_blame;
+pub use blocked::cmd_blocked;
 pub use board::cmd_board;
-pub use cache::{cmd_cache_prune, cmd_cache_rebuild, cmd_cache_status};
-pub use config::{cmd_config_get, cmd_config_set, cmd_config_show};
-pub use create::{CreateOptions, cmd_create};
-pub use dep::{cmd_dep_add, cmd_dep_remove, cmd_dep_tree};
-pub use doc::{cmd_doc_create, cmd_doc_edit, cmd_doc_ls, cmd_doc_search, cmd_doc_show};
+pub use board_export::{BoardExportFormat, cmd_board_export};
+pub use cache::{cmd_cache_prune, cmd_cache_rebuild, cmd_cache_status, cmd_cache_sync};
+pub use complete::{CompletionKind, cmd_complete};
+pub use config::{cmd_config_encrypt, cmd_config_get, cmd_config_set, cmd_config_show};
+pub use context::cmd_context;
+pub use create::{CreateOptions, cmd_create, cmd_create_interactive};
+pub use create_batch::cmd_create_batch;
+pub use dep::{cmd_dep_add, cmd_dep_remove, cmd_dep_resolve, cmd_dep_tree};
+pub use diff::cmd_diff;
+pub use doc::{
+    cmd_doc_add, cmd_doc_create, cmd_doc_edit, cmd_doc_link, cmd_doc_ls, cmd_doc_search,
+    cmd_doc_show, cmd_doc_unlink, cmd_doc_view,
+};
 pub use doctor::cmd_doctor;
 pub use edit::cmd_edit;
-pub use events::cmd_events_prune;
+pub use escalate::cmd_escalate;
+pub use events::{cmd_events_prune, cmd_events_tail};
+pub use expire::cmd_expire;
+pub use fmt::cmd_fmt;
 pub use graph::cmd_graph;
 pub use hook::{
     cmd_hook_disable, cmd_hook_enable, cmd_hook_install, cmd_hook_list, cmd_hook_log, cmd_hook_run,
 };
+pub use lineage::cmd_lineage;
 pub use link::{cmd_link_add, cmd_link_remove};
+pub use lock::{cmd_lock, cmd_unlock};
 pub use ls::{LsOptions, cmd_ls_with_options};
+pub use messages::{cmd_commit_msg, cmd_pr_body};
 pub use next::cmd_next;
+pub use note::{cmd_note_edit, cmd_note_ls, cmd_note_rm};
 pub use objective::{
     cmd_objective_add_criterion, cmd_objective_add_note, cmd_objective_create,
     cmd_objective_delete, cmd_objective_edit, cmd_objective_ls, cmd_objective_ref_add,
     cmd_objective_ref_del, cmd_objective_ref_reset, cmd_objective_show,
 };
+pub use open::{cmd_open, cmd_path};
 pub use plan::{
-    NextItemResult, cmd_plan_add_phase, cmd_plan_add_ticket, cmd_plan_create, cmd_plan_delete,
-    cmd_plan_edit, cmd_plan_hud, cmd_plan_import, cmd_plan_ls, cmd_plan_move_ticket, cmd_plan_next,
-    cmd_plan_remove_phase, cmd_plan_remove_ticket, cmd_plan_rename, cmd_plan_reorder,
-    cmd_plan_show, cmd_plan_status, cmd_plan_verify, cmd_show_import_spec, get_next_items_phased,
-    get_next_items_simple,
+    NextItemResult, cmd_plan_add_phase, cmd_plan_add_ticket, cmd_plan_annotate_phase,
+    cmd_plan_create, cmd_plan_delete, cmd_plan_diff, cmd_plan_edit, cmd_plan_forecast,
+    cmd_plan_hud, cmd_plan_import, cmd_plan_ls, cmd_plan_move_ticket, cmd_plan_next, cmd_plan_push,
+    cmd_plan_remove_phase, cmd_plan_remove_ticket, cmd_plan_rename, cmd_plan_rename_phase,
+    cmd_plan_reorder, cmd_plan_show, cmd_plan_status, cmd_plan_verify, cmd_show_import_spec,
+    get_next_items_phased, get_next_items_simple,
 };
-pub use query::cmd_query;
+pub use pr::cmd_pr_link;
+pub use prune::cmd_prune;
+pub use query::{cmd_count, cmd_query};
+pub use ready::cmd_ready;
 pub use remote_browse::cmd_remote_browse;
+pub use report::cmd_report;
+pub use schema::cmd_schema_dump;
 pub use search::cmd_search;
-pub use set::cmd_set;
+pub use set::{cmd_set, cmd_size};
 pub use show::cmd_show;
+pub use sla::cmd_sla;
+pub use snooze::cmd_snooze;
+pub use stats::cmd_stats_workload;
 pub use status::{cmd_close, cmd_reopen, cmd_start, cmd_status};
-pub use sync::{cmd_adopt, cmd_push, cmd_remote_link, cmd_sync};
+pub use suggest::cmd_suggest;
+#[cfg(feature = "llm")]
+pub use summarize::cmd_summarize;
+pub use sync::{cmd_adopt, cmd_push, cmd_remote_link, cmd_sync, cmd_sync_all};
+pub use triage::cmd_triage;
+pub use tui::cmd_tui;
 pub use view::cmd_view;
+pub use watch::watch_output;
+pub use worktree::{cmd_worktree_cleanup, cmd_worktree_ls, cmd_worktree_start};
 
 use std::path::Path;
 
@@ -137,6 +211,7 @@ pub fn format_size(size: Option<TicketSize>) -> String {
 pub struct CommandOutput {
     json: serde_json::Value,
     text: Option<String>,
+    paged: bool,
 }
 
 impl CommandOutput {
@@ -144,7 +219,11 @@ impl CommandOutput {
     ///
     /// If no text is provided, the JSON will be pretty-printed for text output too.
     pub fn new(json: serde_json::Value) -> Self {
-        Self { json, text: None }
+        Self {
+            json,
+            text: None,
+            paged: false,
+        }
     }
 
     /// Set the human-readable text output.
@@ -153,12 +232,25 @@ impl CommandOutput {
         self
     }
 
+    /// Send long text output through the pager (see [`crate::display::print_paged`]).
+    pub fn paged(mut self) -> Self {
+        self.paged = true;
+        self
+    }
+
     /// Print the output in the appropriate format.
     pub fn print(self, output: OutputOptions) -> Result<()> {
         if output.json {
             print_json(&self.json)?;
-        } else if let Some(text) = self.text {
-            println!("{text}");
+        } else if let Some(mut text) = self.text {
+            if !crate::display::colors_enabled() {
+                text = crate::display::strip_colors(&text);
+            }
+            if self.paged {
+                crate::display::print_paged(&text);
+            } else {
+                println!("{text}");
+            }
         } else {
             // Fallback: pretty-print JSON for text output
             println!("{}", serde_json::to_string_pretty(&self.json)?);
@@ -188,8 +280,19 @@ pub fn ticket_to_json(ticket: &TicketMetadata) -> serde_json::Value {
         "title": ticket.title,
         "status": ticket.status.map(|s| s.to_string()),
         "deps": ticket.deps,
+        "external_deps": ticket.external_deps,
         "links": ticket.links,
         "created": ticket.created,
+        "started_at": ticket.started_at,
+        "closed_at": ticket.closed_at,
+        "resolution": ticket.resolution.map(|r| r.to_string()),
+        "reopen_count": ticket.reopen_count.unwrap_or(0),
+        "status_history": ticket.status_history,
+        "snoozed_until": ticket.snoozed_until,
+        "expires": ticket.expires,
+        "locked_by": ticket.locked_by,
+        "locked_until": ticket.locked_until,
+        "worktree": ticket.worktree,
         "type": ticket.ticket_type.map(|t| t.to_string()),
         "priority": ticket.priority.map(|p| p.as_num()),
         "size": ticket.size.map(|s| s.to_string()),
@@ -197,6 +300,11 @@ pub fn ticket_to_json(ticket: &TicketMetadata) -> serde_json::Value {
         "parent": ticket.parent,
         "file_path": ticket.file_path.as_ref().map(|p| p.to_string_lossy().to_string()),
         "remote": ticket.remote,
+        "assignee": ticket.assignee,
+        "component": ticket.component,
+        "pull_requests": ticket.pull_requests,
+        "docs": ticket.docs,
+        "attachments": ticket.attachments,
         "completion_summary": ticket.completion_summary,
         "spawned_from": ticket.spawned_from,
         "depth": ticket.depth,
@@ -4,8 +4,9 @@ use serde_json::json;
 
 use crate::cli::OutputOptions;
 use crate::commands::CommandOutput;
+use crate::config::Config;
 use crate::error::Result;
-use crate::next::{InclusionReason, NextWorkFinder, WorkItem};
+use crate::next::{Capacity, InclusionReason, NextWorkFinder, WorkItem};
 use crate::status::is_dependency_satisfied;
 use crate::ticket::build_ticket_map;
 use crate::types::TicketData;
@@ -25,7 +26,11 @@ struct WorkItemJson {
 }
 
 /// Show next ticket(s) to work on (dependency-aware)
-pub async fn cmd_next(limit: usize, output: OutputOptions) -> Result<()> {
+///
+/// With `assignee`, only tickets that are unassigned or assigned to them are
+/// considered, and the WIP limit and size budget from the `capacity` config
+/// are respected.
+pub async fn cmd_next(limit: usize, assignee: Option<&str>, output: OutputOptions) -> Result<()> {
     let ticket_map = build_ticket_map().await?;
 
     if ticket_map.is_empty() {
@@ -45,7 +50,21 @@ pub async fn cmd_next(limit: usize, output: OutputOptions) -> Result<()> {
             .print(output);
     }
 
-    let finder = NextWorkFinder::new(&ticket_map);
+    let config = Config::load().unwrap_or_default();
+    let mut finder = NextWorkFinder::new(&ticket_map).with_requirements(config.ready.require);
+    if let Some(assignee) = assignee {
+        let capacity = Capacity::new(assignee, config.capacity.limits_for(assignee), &ticket_map);
+        if capacity.at_wip_limit() {
+            return CommandOutput::new(json!([]))
+                .with_text(format!(
+                    "{assignee} is at their WIP limit ({}/{} tickets in progress).",
+                    capacity.in_progress,
+                    capacity.limits.wip_limit.unwrap_or_default()
+                ))
+                .print(output);
+        }
+        finder = finder.with_capacity(capacity);
+    }
     let work_items = finder.get_next_work(limit);
 
     if work_items.is_empty() {
@@ -0,0 +1,109 @@
+//! Managing individual ticket notes (`janus note`)
+
+use owo_colors::OwoColorize;
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::error::{JanusError, Result};
+use crate::ticket::Ticket;
+use crate::utils::validation::MAX_NOTE_LENGTH;
+use crate::utils::{edit_in_editor, is_stdin_tty, read_stdin};
+
+/// List a ticket's notes, numbered from 1
+pub async fn cmd_note_ls(id: &str, output: OutputOptions) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+    let notes = ticket.notes()?;
+
+    let text = if notes.is_empty() {
+        format!("No notes on {}", ticket.id)
+    } else {
+        notes
+            .iter()
+            .enumerate()
+            .map(|(i, note)| {
+                let heading = match &note.timestamp {
+                    Some(timestamp) => format!("{}. {}", i + 1, timestamp.dimmed()),
+                    None => format!("{}.", i + 1),
+                };
+                let text: Vec<String> = note.text.lines().map(|l| format!("   {l}")).collect();
+                format!("{heading}\n{}", text.join("\n"))
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    };
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "notes": notes
+            .iter()
+            .enumerate()
+            .map(|(i, note)| json!({
+                "index": i + 1,
+                "timestamp": note.timestamp,
+                "text": note.text,
+            }))
+            .collect::<Vec<_>>(),
+    }))
+    .with_text(text)
+    .print(output)
+}
+
+/// Replace the text of a note
+///
+/// The new text comes from `note_text`, then stdin, and otherwise the note
+/// is opened in `$EDITOR`.
+pub async fn cmd_note_edit(
+    id: &str,
+    index: usize,
+    note_text: Option<&str>,
+    output: OutputOptions,
+) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+
+    let note = if let Some(text) = note_text {
+        text.to_string()
+    } else if !is_stdin_tty() {
+        read_stdin()?
+    } else {
+        let current = ticket
+            .notes()?
+            .into_iter()
+            .nth(index.saturating_sub(1))
+            .map(|n| n.text)
+            .unwrap_or_default();
+        edit_in_editor(&current)?
+    };
+
+    if note.len() > MAX_NOTE_LENGTH {
+        return Err(JanusError::NoteTooLong {
+            max: MAX_NOTE_LENGTH,
+            actual: note.len(),
+        });
+    }
+
+    ticket.edit_note(index, &note, None)?;
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "note_edited",
+        "index": index,
+        "note": note.trim(),
+    }))
+    .with_text(format!("Note {index} on {} updated", ticket.id))
+    .print(output)
+}
+
+/// Remove a note
+pub async fn cmd_note_rm(id: &str, index: usize, output: OutputOptions) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+    ticket.remove_note(index, None)?;
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "note_removed",
+        "index": index,
+    }))
+    .with_text(format!("Note {index} removed from {}", ticket.id))
+    .print(output)
+}
@@ -0,0 +1,62 @@
+use serde_json::json;
+
+use super::{CommandOutput, open_in_editor_for_entity};
+use crate::cli::OutputOptions;
+use crate::config::Config;
+use crate::error::{JanusError, Result};
+use crate::remote::RemoteRef;
+use crate::ticket::Ticket;
+use crate::utils::open_in_browser;
+
+/// Print the resolved file path of a ticket
+///
+/// Prints only the path so the output can be used directly in scripts,
+/// e.g. `$EDITOR "$(janus path a1b2)"`.
+pub async fn cmd_path(id: &str, output: OutputOptions) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+    let path = ticket.file_path.to_string_lossy().into_owned();
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "file_path": path,
+    }))
+    .with_text(path)
+    .print(output)
+}
+
+/// Open a ticket in $EDITOR, or its linked remote issue in the browser
+pub async fn cmd_open(id: &str, remote: bool, output: OutputOptions) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+
+    if !remote {
+        if output.json {
+            return CommandOutput::new(json!({
+                "id": ticket.id,
+                "file_path": ticket.file_path.to_string_lossy(),
+                "action": "open",
+            }))
+            .print(output);
+        }
+        return open_in_editor_for_entity("ticket", &ticket.file_path, output);
+    }
+
+    let metadata = ticket.read()?;
+    let remote_ref_str = metadata.remote.as_ref().ok_or(JanusError::NotLinked)?;
+    let config = Config::load()?;
+    let url = RemoteRef::parse(remote_ref_str, Some(&config))?.web_url();
+
+    // Output in JSON format if requested (skip browser)
+    if output.json {
+        return CommandOutput::new(json!({
+            "id": ticket.id,
+            "remote_ref": remote_ref_str,
+            "url": url,
+            "action": "open_remote",
+        }))
+        .print(output);
+    }
+
+    open_in_browser(&url)?;
+    println!("Opened {url}");
+    Ok(())
+}
@@ -0,0 +1,332 @@
+//! Plan structure changes since a git revision (`plan diff`)
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::cli::OutputOptions;
+use crate::commands::CommandOutput;
+use crate::error::{JanusError, Result};
+use crate::plan::Plan;
+use crate::plan::parser::parse_plan_content;
+use crate::plan::types::{PlanMetadata, PlanSection};
+use crate::utils::git;
+
+/// A phase as `number` plus `name`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct PhaseRef {
+    number: String,
+    name: String,
+}
+
+impl std::fmt::Display for PhaseRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.name.is_empty() {
+            write!(f, "Phase {}", self.number)
+        } else {
+            write!(f, "Phase {}: {}", self.number, self.name)
+        }
+    }
+}
+
+/// A ticket added to or removed from the plan
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct TicketEntry {
+    id: String,
+    /// Phase number, `None` for simple plans
+    phase: Option<String>,
+}
+
+/// A ticket that is in a different phase than before
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct TicketMove {
+    id: String,
+    from: String,
+    to: String,
+}
+
+/// A phase whose name changed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct PhaseRename {
+    number: String,
+    from: String,
+    to: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct PlanDiff {
+    tickets_added: Vec<TicketEntry>,
+    tickets_removed: Vec<TicketEntry>,
+    tickets_moved: Vec<TicketMove>,
+    phases_added: Vec<PhaseRef>,
+    phases_removed: Vec<PhaseRef>,
+    phases_renamed: Vec<PhaseRename>,
+    /// Whether phases present at both revisions are in a different order
+    phases_reordered: bool,
+    /// Phases (or `tickets` for simple plans) whose tickets kept their phase
+    /// but changed order
+    tickets_reordered: Vec<String>,
+}
+
+impl PlanDiff {
+    fn is_empty(&self) -> bool {
+        self.tickets_added.is_empty()
+            && self.tickets_removed.is_empty()
+            && self.tickets_moved.is_empty()
+            && self.phases_added.is_empty()
+            && self.phases_removed.is_empty()
+            && self.phases_renamed.is_empty()
+            && !self.phases_reordered
+            && self.tickets_reordered.is_empty()
+    }
+}
+
+/// Compare a plan's phases and tickets with the plan as of a git revision
+///
+/// # Arguments
+/// * `id` - The plan ID (can be partial)
+/// * `rev` - The git revision to compare against (e.g. `HEAD~5`)
+/// * `output_json` - If true, output result as JSON
+pub async fn cmd_plan_diff(id: &str, rev: &str, output: OutputOptions) -> Result<()> {
+    let plan = Plan::find(id).await?;
+    let current = plan.read()?;
+
+    let file_path = plan.file_path.to_string_lossy();
+    // `--full-name` gives the repository-relative path `git show` expects
+    let listing = git(
+        None,
+        &[
+            "ls-tree",
+            "--full-name",
+            "--name-only",
+            rev,
+            "--",
+            &file_path,
+        ],
+    )?;
+    let Some(path) = listing.lines().next() else {
+        return Err(JanusError::Git(format!(
+            "plan {} does not exist at {rev}",
+            plan.id
+        )));
+    };
+    let previous = parse_plan_content(&git(None, &["show", &format!("{rev}:{path}")])?)?;
+
+    let diff = compute_plan_diff(&previous, &current);
+    let text = if diff.is_empty() {
+        format!("No structural changes to plan {} since {rev}", plan.id)
+    } else {
+        format!(
+            "Changes to plan {} since {rev}:\n\n{}",
+            plan.id,
+            format_plan_diff(&diff)
+        )
+    };
+
+    let mut json = serde_json::to_value(&diff)?;
+    json["plan_id"] = json!(plan.id);
+    json["ref"] = json!(rev);
+    CommandOutput::new(json).with_text(text).print(output)
+}
+
+/// Each ticket's phase number (`None` in simple plans) and position in it
+fn ticket_positions(metadata: &PlanMetadata) -> BTreeMap<String, (Option<String>, usize)> {
+    let mut positions = BTreeMap::new();
+    for section in &metadata.sections {
+        let (phase, tickets) = match section {
+            PlanSection::Phase(p) => (Some(p.number.clone()), &p.ticket_list.tickets),
+            PlanSection::Tickets(ts) => (None, &ts.ticket_list.tickets),
+            PlanSection::FreeForm(_) => continue,
+        };
+        for (i, ticket) in tickets.iter().enumerate() {
+            positions
+                .entry(ticket.clone())
+                .or_insert((phase.clone(), i));
+        }
+    }
+    positions
+}
+
+fn phase_refs(metadata: &PlanMetadata) -> Vec<PhaseRef> {
+    metadata
+        .phases()
+        .iter()
+        .map(|p| PhaseRef {
+            number: p.number.clone(),
+            name: p.name.clone(),
+        })
+        .collect()
+}
+
+/// Compare two versions of a plan. Phases are matched by number, so a
+/// renamed phase keeps its tickets rather than showing them as moved.
+fn compute_plan_diff(before: &PlanMetadata, after: &PlanMetadata) -> PlanDiff {
+    let mut diff = PlanDiff::default();
+
+    let old_phases = phase_refs(before);
+    let new_phases = phase_refs(after);
+    let old_by_number: HashMap<&str, &PhaseRef> =
+        old_phases.iter().map(|p| (p.number.as_str(), p)).collect();
+    let new_by_number: HashMap<&str, &PhaseRef> =
+        new_phases.iter().map(|p| (p.number.as_str(), p)).collect();
+
+    for phase in &new_phases {
+        match old_by_number.get(phase.number.as_str()) {
+            None => diff.phases_added.push(phase.clone()),
+            Some(old) if old.name != phase.name => diff.phases_renamed.push(PhaseRename {
+                number: phase.number.clone(),
+                from: old.name.clone(),
+                to: phase.name.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    for phase in &old_phases {
+        if !new_by_number.contains_key(phase.number.as_str()) {
+            diff.phases_removed.push(phase.clone());
+        }
+    }
+    let kept_order = |phases: &[PhaseRef], other: &HashMap<&str, &PhaseRef>| -> Vec<String> {
+        phases
+            .iter()
+            .filter(|p| other.contains_key(p.number.as_str()))
+            .map(|p| p.number.clone())
+            .collect()
+    };
+    diff.phases_reordered =
+        kept_order(&old_phases, &new_by_number) != kept_order(&new_phases, &old_by_number);
+
+    let old_tickets = ticket_positions(before);
+    let new_tickets = ticket_positions(after);
+    let phase_label = |number: &Option<String>| -> String {
+        match number {
+            Some(n) => new_by_number
+                .get(n.as_str())
+                .or_else(|| old_by_number.get(n.as_str()))
+                .map(|p| p.to_string())
+                .unwrap_or_else(|| format!("Phase {n}")),
+            None => "tickets".to_string(),
+        }
+    };
+
+    // Tickets that stayed in their phase, in old and new order, per phase
+    let mut kept: BTreeMap<Option<String>, (Vec<(usize, &str)>, Vec<(usize, &str)>)> =
+        BTreeMap::new();
+    for (id, (phase, position)) in &new_tickets {
+        match old_tickets.get(id) {
+            None => diff.tickets_added.push(TicketEntry {
+                id: id.clone(),
+                phase: phase.clone(),
+            }),
+            Some((old_phase, _)) if old_phase != phase => diff.tickets_moved.push(TicketMove {
+                id: id.clone(),
+                from: phase_label(old_phase),
+                to: phase_label(phase),
+            }),
+            Some((_, old_position)) => {
+                let entry = kept.entry(phase.clone()).or_default();
+                entry.0.push((*old_position, id));
+                entry.1.push((*position, id));
+            }
+        }
+    }
+    for (id, (phase, _)) in &old_tickets {
+        if !new_tickets.contains_key(id) {
+            diff.tickets_removed.push(TicketEntry {
+                id: id.clone(),
+                phase: phase.clone(),
+            });
+        }
+    }
+    for (phase, (mut old_order, mut new_order)) in kept {
+        old_order.sort();
+        new_order.sort();
+        let ids = |order: &[(usize, &str)]| order.iter().map(|(_, id)| *id).collect::<Vec<_>>();
+        if ids(&old_order) != ids(&new_order) {
+            diff.tickets_reordered.push(phase_label(&phase));
+        }
+    }
+
+    diff
+}
+
+fn format_plan_diff(diff: &PlanDiff) -> String {
+    let mut lines = Vec::new();
+    for phase in &diff.phases_added {
+        lines.push(format!("  + {phase}"));
+    }
+    for phase in &diff.phases_removed {
+        lines.push(format!("  - {phase}"));
+    }
+    for rename in &diff.phases_renamed {
+        lines.push(format!(
+            "  ~ Phase {}: '{}' -> '{}'",
+            rename.number, rename.from, rename.to
+        ));
+    }
+    if diff.phases_reordered {
+        lines.push("  ~ phases reordered".to_string());
+    }
+    let in_phase = |phase: &Option<String>| match phase {
+        Some(n) => format!(" (Phase {n})"),
+        None => String::new(),
+    };
+    for ticket in &diff.tickets_added {
+        lines.push(format!("  + {}{}", ticket.id, in_phase(&ticket.phase)));
+    }
+    for ticket in &diff.tickets_removed {
+        lines.push(format!("  - {}{}", ticket.id, in_phase(&ticket.phase)));
+    }
+    for ticket in &diff.tickets_moved {
+        lines.push(format!(
+            "  > {}: {} -> {}",
+            ticket.id, ticket.from, ticket.to
+        ));
+    }
+    for phase in &diff.tickets_reordered {
+        lines.push(format!("  ~ tickets reordered in {phase}"));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(content: &str) -> PlanMetadata {
+        parse_plan_content(&format!(
+            "---\nid: plan-a1b2\nuuid: 550e8400-e29b-41d4-a716-446655440000\ncreated: 2024-01-01T00:00:00Z\n---\n# Plan\n\n{content}"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_compute_plan_diff() {
+        let before = plan(
+            "## Phase 1: Design\n\n### Tickets\n\n1. j-a\n2. j-b\n3. j-c\n\n## Phase 2: Build\n\n### Tickets\n\n1. j-d\n",
+        );
+        let after = plan(
+            "## Phase 1: Discovery\n\n### Tickets\n\n1. j-b\n2. j-a\n\n## Phase 3: Ship\n\n### Tickets\n\n1. j-c\n2. j-e\n",
+        );
+
+        let diff = compute_plan_diff(&before, &after);
+        assert_eq!(diff.phases_added[0].to_string(), "Phase 3: Ship");
+        assert_eq!(diff.phases_removed[0].to_string(), "Phase 2: Build");
+        assert_eq!(diff.phases_renamed[0].to, "Discovery");
+        assert!(!diff.phases_reordered);
+        assert_eq!(diff.tickets_added[0].id, "j-e");
+        assert_eq!(diff.tickets_removed[0].id, "j-d");
+        assert_eq!(
+            diff.tickets_moved,
+            vec![TicketMove {
+                id: "j-c".to_string(),
+                from: "Phase 1: Discovery".to_string(),
+                to: "Phase 3: Ship".to_string(),
+            }]
+        );
+        assert_eq!(diff.tickets_reordered, vec!["Phase 1: Discovery"]);
+
+        assert!(compute_plan_diff(&after, &after).is_empty());
+    }
+}
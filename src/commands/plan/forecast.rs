@@ -0,0 +1,114 @@
+//! Plan forecast command
+
+use jiff::Timestamp;
+use owo_colors::OwoColorize;
+use serde_json::json;
+
+use crate::cli::OutputOptions;
+use crate::commands::CommandOutput;
+use crate::error::Result;
+use crate::plan::Plan;
+use crate::plan::forecast::{
+    CONFIDENCE_LEVELS, Forecast, simulate, size_points, weekly_throughput,
+};
+use crate::ticket::build_ticket_map;
+
+/// Estimate when a plan will be complete
+///
+/// # Arguments
+/// * `id` - The plan ID (can be partial)
+/// * `weeks` - How many weeks of completed tickets to measure throughput over
+/// * `output` - Output options
+pub async fn cmd_plan_forecast(id: &str, weeks: u32, output: OutputOptions) -> Result<()> {
+    let plan = Plan::find(id).await?;
+    let metadata = plan.read()?;
+    let ticket_map = build_ticket_map().await?;
+    let now = Timestamp::now();
+
+    // Tickets missing from the store are skipped rather than guessed at
+    let remaining: Vec<_> = metadata
+        .all_tickets()
+        .into_iter()
+        .filter_map(|id| ticket_map.get(id))
+        .filter(|t| !t.status.unwrap_or_default().is_terminal())
+        .collect();
+    let remaining_points: u32 = remaining.iter().map(|t| size_points(t.size)).sum();
+
+    let history = weekly_throughput(ticket_map.values(), now, weeks);
+    let average = if history.is_empty() {
+        0.0
+    } else {
+        history.iter().sum::<u32>() as f64 / history.len() as f64
+    };
+    let forecast = if remaining.is_empty() {
+        None
+    } else {
+        simulate(&history, remaining_points)
+    };
+
+    let bands = |forecast: &Forecast| {
+        CONFIDENCE_LEVELS
+            .iter()
+            .zip(forecast.weeks)
+            .map(|(level, weeks)| (*level, weeks, Forecast::date(now, weeks)))
+            .collect::<Vec<_>>()
+    };
+
+    let forecast_json = forecast.as_ref().map(|f| {
+        bands(f)
+            .into_iter()
+            .map(|(confidence, weeks, date)| {
+                json!({
+                    "confidence": confidence,
+                    "weeks": weeks,
+                    "date": date,
+                })
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let json_output = json!({
+        "plan_id": plan.id,
+        "title": metadata.title,
+        "remaining_tickets": remaining.len(),
+        "remaining_points": remaining_points,
+        "history_weeks": weeks,
+        "weekly_throughput": history,
+        "average_throughput": average,
+        "forecast": forecast_json,
+    });
+
+    let title = metadata.title.as_deref().unwrap_or("Untitled");
+    let plan_id = metadata.id.as_deref().unwrap_or(&plan.id);
+    let mut text_output = format!("Plan: {} - {}\n", plan_id.cyan(), title);
+    text_output.push_str(&format!(
+        "Remaining: {} tickets ({} points)\n",
+        remaining.len(),
+        remaining_points
+    ));
+    text_output.push_str(&format!(
+        "Throughput: {average:.1} points/week over the last {weeks} weeks\n"
+    ));
+
+    match &forecast {
+        _ if remaining.is_empty() => text_output.push_str("All tickets in this plan are closed.\n"),
+        None => text_output.push_str(&format!(
+            "Not enough history to forecast: no tickets completed in the last {weeks} weeks.\n"
+        )),
+        Some(f) => {
+            text_output.push_str("\nForecast:\n");
+            for (confidence, weeks, date) in bands(f) {
+                text_output.push_str(&format!(
+                    "  {:>3}%  {}  {}\n",
+                    confidence,
+                    date,
+                    format!("({weeks} weeks)").dimmed()
+                ));
+            }
+        }
+    }
+
+    CommandOutput::new(json_output)
+        .with_text(text_output)
+        .print(output)
+}
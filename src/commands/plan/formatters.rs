@@ -2,13 +2,12 @@
 
 use std::collections::HashMap;
 
-use owo_colors::OwoColorize;
 use serde_json::json;
 
-use super::print_ticket_line;
+use super::format_plan_ticket;
 use crate::commands::print_json;
 use crate::commands::ticket_minimal_json_with_exists;
-use crate::display::format_status_colored;
+use crate::display::{bold, dim, format_status_colored, print_paged};
 use crate::error::Result;
 use crate::plan::types::{Phase, PhaseStatus, PlanMetadata, PlanSection, PlanStatus};
 use crate::plan::{Plan, compute_all_phase_statuses, compute_plan_status};
@@ -23,7 +22,7 @@ pub struct PhasesOnlyFormatter;
 impl RawFormatter {
     pub fn format(plan: &Plan) -> Result<()> {
         let content = plan.read_content()?;
-        println!("{content}");
+        print_paged(&content);
         Ok(())
     }
 }
@@ -37,74 +36,84 @@ impl FullFormatter {
     ) -> Result<()> {
         let plan_status = compute_plan_status(metadata, ticket_map);
 
-        Self::print_header(metadata, &plan_status);
-        Self::print_description(metadata);
-        Self::print_acceptance_criteria(metadata);
-        Self::print_sections(metadata, ticket_map, verbose_phases);
+        let mut lines = Vec::new();
+        Self::format_header(metadata, &plan_status, &mut lines);
+        Self::format_description(metadata, &mut lines);
+        Self::format_acceptance_criteria(metadata, &mut lines);
+        Self::format_sections(metadata, ticket_map, verbose_phases, &mut lines);
+        print_paged(&lines.join("\n"));
 
         Ok(())
     }
 
-    fn print_header(metadata: &PlanMetadata, status: &PlanStatus) {
+    fn format_header(metadata: &PlanMetadata, status: &PlanStatus, lines: &mut Vec<String>) {
         if let Some(ref title) = metadata.title {
-            println!("{}", format!("# {title}").bold());
+            lines.push(bold(&format!("# {title}")));
         }
 
         let status_badge = format_status_colored(status.status);
         let progress = status.progress_string();
-        println!();
-        println!("{status_badge} Progress: {progress} tickets");
+        lines.push(String::new());
+        lines.push(format!("{status_badge} Progress: {progress} tickets"));
     }
 
-    fn print_description(metadata: &PlanMetadata) {
+    fn format_description(metadata: &PlanMetadata, lines: &mut Vec<String>) {
         if let Some(ref description) = metadata.description {
-            println!();
-            println!("{description}");
+            lines.push(String::new());
+            lines.push(description.clone());
         }
     }
 
-    fn print_acceptance_criteria(metadata: &PlanMetadata) {
+    fn format_acceptance_criteria(metadata: &PlanMetadata, lines: &mut Vec<String>) {
         if !metadata.acceptance_criteria.is_empty() {
-            println!();
-            println!("{}", "## Acceptance Criteria".bold());
-            println!();
+            lines.push(String::new());
+            lines.push(bold("## Acceptance Criteria"));
+            lines.push(String::new());
             for criterion in &metadata.acceptance_criteria {
-                println!("- [ ] {criterion}");
+                lines.push(format!("- [ ] {criterion}"));
             }
         }
     }
 
-    fn print_sections(
+    fn format_sections(
         metadata: &PlanMetadata,
         ticket_map: &HashMap<String, TicketMetadata>,
         verbose_phases: &[String],
+        lines: &mut Vec<String>,
     ) {
         let phase_statuses = compute_all_phase_statuses(metadata, ticket_map);
         let mut phase_idx = 0;
 
         for section in &metadata.sections {
-            println!();
+            lines.push(String::new());
             match section {
                 PlanSection::Phase(phase) => {
                     let phase_status = phase_statuses.get(phase_idx);
                     phase_idx += 1;
-                    Self::print_phase_section(phase, phase_status, ticket_map, verbose_phases);
+                    Self::format_phase_section(
+                        phase,
+                        phase_status,
+                        ticket_map,
+                        verbose_phases,
+                        lines,
+                    );
                 }
                 PlanSection::Tickets(ts) => {
-                    Self::print_tickets_section(&ts.ticket_list.tickets, ticket_map);
+                    Self::format_tickets_section(&ts.ticket_list.tickets, ticket_map, lines);
                 }
                 PlanSection::FreeForm(freeform) => {
-                    Self::print_freeform_section(freeform);
+                    Self::format_freeform_section(freeform, lines);
                 }
             }
         }
     }
 
-    fn print_phase_section(
+    fn format_phase_section(
         phase: &Phase,
         phase_status: Option<&PhaseStatus>,
         ticket_map: &HashMap<String, TicketMetadata>,
         verbose_phases: &[String],
+        lines: &mut Vec<String>,
     ) {
         let status_str = phase_status
             .map(|s| format_status_colored(s.status))
@@ -113,60 +122,68 @@ impl FullFormatter {
             .map(|s| format!("({}/{})", s.completed_count, s.total_count))
             .unwrap_or_default();
 
-        if phase.name.is_empty() {
-            println!(
-                "{} {} {}",
-                format!("## Phase {}", phase.number).bold(),
-                status_str,
-                progress_str.dimmed()
-            );
+        let heading = if phase.name.is_empty() {
+            format!("## Phase {}", phase.number)
         } else {
-            println!(
-                "{} {} {}",
-                format!("## Phase {}: {}", phase.number, phase.name).bold(),
-                status_str,
-                progress_str.dimmed()
-            );
-        }
+            format!("## Phase {}: {}", phase.number, phase.name)
+        };
+        lines.push(format!(
+            "{} {} {}",
+            bold(&heading),
+            status_str,
+            dim(&progress_str)
+        ));
 
         if let Some(ref desc) = phase.description {
-            println!();
-            println!("{desc}");
+            lines.push(String::new());
+            lines.push(desc.clone());
         }
 
         if !phase.success_criteria.is_empty() {
-            println!();
-            println!("{}", "### Success Criteria".bold());
-            println!();
+            lines.push(String::new());
+            lines.push(bold("### Success Criteria"));
+            lines.push(String::new());
             for criterion in &phase.success_criteria {
-                println!("- {criterion}");
+                lines.push(format!("- {criterion}"));
             }
         }
 
         if !phase.ticket_list.tickets.is_empty() {
-            println!();
-            println!("{}", "### Tickets".bold());
-            println!();
+            lines.push(String::new());
+            lines.push(bold("### Tickets"));
+            lines.push(String::new());
             let full_summary = verbose_phases.contains(&phase.number);
             for (i, ticket_id) in phase.ticket_list.tickets.iter().enumerate() {
-                print_ticket_line(i + 1, ticket_id, ticket_map, full_summary);
+                lines.extend(format_plan_ticket(
+                    i + 1,
+                    ticket_id,
+                    ticket_map,
+                    full_summary,
+                ));
             }
         }
     }
 
-    fn print_tickets_section(tickets: &[String], ticket_map: &HashMap<String, TicketMetadata>) {
-        println!("{}", "## Tickets".bold());
-        println!();
+    fn format_tickets_section(
+        tickets: &[String],
+        ticket_map: &HashMap<String, TicketMetadata>,
+        lines: &mut Vec<String>,
+    ) {
+        lines.push(bold("## Tickets"));
+        lines.push(String::new());
         for (i, ticket_id) in tickets.iter().enumerate() {
-            print_ticket_line(i + 1, ticket_id, ticket_map, false);
+            lines.extend(format_plan_ticket(i + 1, ticket_id, ticket_map, false));
         }
     }
 
-    fn print_freeform_section(freeform: &crate::plan::types::FreeFormSection) {
-        println!("{}", format!("## {}", freeform.heading).bold());
+    fn format_freeform_section(
+        freeform: &crate::plan::types::FreeFormSection,
+        lines: &mut Vec<String>,
+    ) {
+        lines.push(bold(&format!("## {}", freeform.heading)));
         if !freeform.content.is_empty() {
-            println!();
-            println!("{}", freeform.content);
+            lines.push(String::new());
+            lines.push(freeform.content.clone());
         }
     }
 }
@@ -209,6 +226,8 @@ impl JsonFormatter {
                     "status": ps.status.to_string(),
                     "completed_count": ps.completed_count,
                     "total_count": ps.total_count,
+                    "completed_points": ps.completed_points,
+                    "total_points": ps.total_points,
                     "tickets": phase_tickets,
                 })
             })
@@ -224,6 +243,8 @@ impl JsonFormatter {
             "completed_count": plan_status.completed_count,
             "total_count": plan_status.total_count,
             "progress_percent": plan_status.progress_percent(),
+            "completed_points": plan_status.completed_points,
+            "total_points": plan_status.total_points,
             "acceptance_criteria": metadata.acceptance_criteria,
             "is_phased": metadata.is_phased(),
             "phases": phases_info,
@@ -247,9 +268,12 @@ impl TicketsOnlyFormatter {
             return Ok(());
         }
 
-        for (i, ticket_id) in all_tickets.iter().enumerate() {
-            print_ticket_line(i + 1, ticket_id, ticket_map, false);
-        }
+        let lines: Vec<String> = all_tickets
+            .iter()
+            .enumerate()
+            .flat_map(|(i, ticket_id)| format_plan_ticket(i + 1, ticket_id, ticket_map, false))
+            .collect();
+        print_paged(&lines.join("\n"));
 
         Ok(())
     }
@@ -279,18 +303,22 @@ impl PhasesOnlyFormatter {
             .unwrap_or(0)
             .max(12);
 
-        for ps in &phase_statuses {
-            let status_badge = format_status_colored(ps.status);
-            let progress = format!("({}/{})", ps.completed_count, ps.total_count);
-            println!(
-                "{}. {} {:width$} {}",
-                ps.phase_number,
-                status_badge,
-                ps.phase_name,
-                progress.dimmed(),
-                width = max_name_len
-            );
-        }
+        let lines: Vec<String> = phase_statuses
+            .iter()
+            .map(|ps| {
+                let status_badge = format_status_colored(ps.status);
+                let progress = format!("({}/{})", ps.completed_count, ps.total_count);
+                format!(
+                    "{}. {} {:width$} {}",
+                    ps.phase_number,
+                    status_badge,
+                    ps.phase_name,
+                    dim(&progress),
+                    width = max_name_len
+                )
+            })
+            .collect();
+        print_paged(&lines.join("\n"));
 
         Ok(())
     }
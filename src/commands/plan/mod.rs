@@ -10,23 +10,31 @@
 //! - `plan move-ticket` - Move a ticket between phases
 //! - `plan add-phase` - Add a new phase to a plan
 //! - `plan remove-phase` - Remove a phase from a plan
+//! - `plan rename-phase` - Rename a phase
+//! - `plan annotate-phase` - Set a phase's description
 //! - `plan reorder` - Reorder tickets or phases
 //! - `plan delete` - Delete a plan
 //! - `plan rename` - Rename a plan
 //! - `plan next` - Show the next actionable item(s)
 //! - `plan status` - Show plan status summary
+//! - `plan diff` - Show phase and ticket changes since a git revision
+//! - `plan forecast` - Estimate a plan's completion date range
 //! - `plan import` - Import an AI-generated plan document
 //! - `plan import-spec` - Show the importable plan format specification
+//! - `plan push` - Push a plan's tickets to the default remote
 
 mod create;
 mod delete;
+mod diff;
 mod edit;
+mod forecast;
 mod formatters;
 mod hud;
 mod import;
 mod ls;
 mod next;
 mod phases;
+mod push;
 mod reorder;
 mod show;
 mod status;
@@ -35,12 +43,17 @@ mod verify;
 
 pub use create::cmd_plan_create;
 pub use delete::{cmd_plan_delete, cmd_plan_rename};
+pub use diff::cmd_plan_diff;
 pub use edit::cmd_plan_edit;
+pub use forecast::cmd_plan_forecast;
 pub use hud::cmd_plan_hud;
-pub use import::{cmd_plan_import, cmd_show_import_spec};
+pub use import::{PLAN_FORMAT_SPECIFICATION, cmd_plan_import, cmd_show_import_spec};
 pub use ls::cmd_plan_ls;
 pub use next::{NextItemResult, cmd_plan_next, get_next_items_phased, get_next_items_simple};
-pub use phases::{cmd_plan_add_phase, cmd_plan_remove_phase};
+pub use phases::{
+    cmd_plan_add_phase, cmd_plan_annotate_phase, cmd_plan_remove_phase, cmd_plan_rename_phase,
+};
+pub use push::cmd_plan_push;
 pub use reorder::cmd_plan_reorder;
 pub use show::cmd_plan_show;
 pub use status::cmd_plan_status;
@@ -50,9 +63,8 @@ pub use verify::cmd_plan_verify;
 use std::collections::HashMap;
 use std::io::{Read, Write};
 
-use owo_colors::OwoColorize;
-
-use crate::display::format_status_colored;
+use crate::config::ThemeColor;
+use crate::display::{accent, dim, format_status_colored, paint};
 use crate::error::{JanusError, Result};
 use crate::types::{TicketMetadata, TicketStatus};
 use crate::utils::{is_stdin_tty, open_in_editor};
@@ -61,53 +73,54 @@ use crate::utils::{is_stdin_tty, open_in_editor};
 // Shared Helper Functions
 // ============================================================================
 
-/// Print a ticket line with status for plan show command
+/// Format a ticket line with status for plan show command
 ///
 /// # Arguments
 /// * `index` - The 1-based index of the ticket in the list
 /// * `ticket_id` - The ticket ID
 /// * `ticket_map` - Map of ticket IDs to metadata
 /// * `full_summary` - If true, show full completion summary; if false, show only first 2 lines
-pub(crate) fn print_ticket_line(
+pub(crate) fn format_plan_ticket(
     index: usize,
     ticket_id: &str,
     ticket_map: &HashMap<String, TicketMetadata>,
     full_summary: bool,
-) {
-    if let Some(ticket) = ticket_map.get(ticket_id) {
-        let status = ticket.status.unwrap_or_default();
-        let status_badge = format_status_colored(status);
-        let title = ticket.title.as_deref().unwrap_or("");
-
-        println!(
-            "{}. {} {} - {}",
+) -> Vec<String> {
+    let Some(ticket) = ticket_map.get(ticket_id) else {
+        // Missing ticket
+        return vec![format!(
+            "{}. {} {}",
             index,
-            status_badge,
-            ticket_id.cyan(),
-            title
+            paint("[missing]", ThemeColor::Red),
+            dim(ticket_id)
+        )];
+    };
+
+    let status = ticket.status.unwrap_or_default();
+    let status_badge = format_status_colored(status);
+    let title = ticket.title.as_deref().unwrap_or("");
+    let mut lines = vec![format!(
+        "{}. {} {} - {}",
+        index,
+        status_badge,
+        accent(ticket_id),
+        title
+    )];
+
+    // Show completion summary as an indented blockquote if complete and has one
+    if status == TicketStatus::Complete
+        && let Some(ref summary) = ticket.completion_summary
+    {
+        // Only the first 2 lines unless the full summary was asked for
+        let shown = if full_summary { usize::MAX } else { 2 };
+        lines.extend(
+            summary
+                .lines()
+                .take(shown)
+                .map(|line| format!("   > {}", dim(line))),
         );
-
-        // Print completion summary if complete and has one
-        if status == TicketStatus::Complete
-            && let Some(ref summary) = ticket.completion_summary
-        {
-            // Print as indented blockquote
-            if full_summary {
-                // Print all lines
-                for line in summary.lines() {
-                    println!("   > {}", line.dimmed());
-                }
-            } else {
-                // Print only first 2 lines
-                for line in summary.lines().take(2) {
-                    println!("   > {}", line.dimmed());
-                }
-            }
-        }
-    } else {
-        // Missing ticket
-        println!("{}. {} {}", index, "[missing]".red(), ticket_id.dimmed());
     }
+    lines
 }
 
 /// Open content in an editor and return the edited content
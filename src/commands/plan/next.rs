@@ -1,6 +1,6 @@
 //! Plan next command
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde_json::json;
 
@@ -8,6 +8,7 @@ use crate::cli::OutputOptions;
 use crate::commands::CommandOutput;
 use crate::display::PlanNextFormatter;
 use crate::error::Result;
+use crate::graph::external_blockers;
 use crate::plan::types::PlanMetadata;
 use crate::plan::{Plan, compute_phase_status};
 use crate::status::all_deps_satisfied;
@@ -66,7 +67,13 @@ pub async fn cmd_plan_next(
         })
         .collect();
 
-    let text = if next_items.is_empty() {
+    let blocked = get_externally_blocked(&metadata, &ticket_map);
+    let blocked_json: Vec<_> = blocked
+        .iter()
+        .map(|(ticket_id, blockers)| json!({ "id": ticket_id, "blocked_by": blockers }))
+        .collect();
+
+    let mut text = if next_items.is_empty() {
         "No actionable items remaining".to_string()
     } else {
         let mut text_parts = Vec::new();
@@ -75,10 +82,20 @@ pub async fn cmd_plan_next(
         }
         text_parts.join("")
     };
+    if !blocked.is_empty() {
+        text = format!(
+            "{}\n\nBlocked by tickets outside this plan:\n",
+            text.trim_end()
+        );
+        for (ticket_id, blockers) in &blocked {
+            text.push_str(&format!("  {ticket_id} <- {}\n", blockers.join(", ")));
+        }
+    }
 
     CommandOutput::new(json!({
         "plan_id": plan.id,
         "next_items": next_items_json,
+        "externally_blocked": blocked_json,
     }))
     .with_text(text.trim_end())
     .print(output)
@@ -219,6 +236,29 @@ pub fn get_next_items_simple(
     }]
 }
 
+/// Open plan tickets waiting on unfinished tickets that are not in the plan,
+/// with the IDs of those blockers
+///
+/// These tickets are never suggested by `plan next`; listing them explains
+/// why and points at the work that has to happen elsewhere first.
+pub fn get_externally_blocked(
+    metadata: &PlanMetadata,
+    ticket_map: &HashMap<String, TicketMetadata>,
+) -> Vec<(String, Vec<String>)> {
+    let plan_tickets = metadata.all_tickets();
+    let scope: HashSet<&str> = plan_tickets.iter().copied().collect();
+
+    plan_tickets
+        .iter()
+        .filter_map(|ticket_id| ticket_map.get(*ticket_id).map(|meta| (*ticket_id, meta)))
+        .filter(|(_, meta)| !meta.status.unwrap_or_default().is_terminal())
+        .filter_map(|(ticket_id, meta)| {
+            let blockers = external_blockers(meta, &scope, ticket_map);
+            (!blockers.is_empty()).then(|| (ticket_id.to_string(), blockers))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -395,4 +435,31 @@ mod tests {
         let results = get_next_items_phased(&metadata, &ticket_map, false, false, 1);
         assert!(results.is_empty());
     }
+
+    #[test]
+    fn test_next_items_skip_tickets_blocked_outside_plan() {
+        let metadata = make_phased_plan(vec![("1", "Phase One", vec!["t1", "t2"])]);
+        let mut ticket_map = HashMap::new();
+        let mut blocked = make_ticket("t1", TicketStatus::New);
+        blocked.deps = vec![TicketId::new_unchecked("other")];
+        ticket_map.insert("t1".to_string(), blocked);
+        ticket_map.insert("t2".to_string(), make_ticket("t2", TicketStatus::New));
+        ticket_map.insert("other".to_string(), make_ticket("other", TicketStatus::New));
+
+        let results = get_next_items_phased(&metadata, &ticket_map, false, false, 1);
+        assert_eq!(results[0].tickets[0].0, "t2");
+        assert_eq!(
+            get_externally_blocked(&metadata, &ticket_map),
+            vec![("t1".to_string(), vec!["other".to_string()])]
+        );
+
+        // Once the outside ticket is done, t1 is actionable again
+        ticket_map.insert(
+            "other".to_string(),
+            make_ticket("other", TicketStatus::Complete),
+        );
+        let results = get_next_items_phased(&metadata, &ticket_map, false, false, 1);
+        assert_eq!(results[0].tickets[0].0, "t1");
+        assert!(get_externally_blocked(&metadata, &ticket_map).is_empty());
+    }
 }
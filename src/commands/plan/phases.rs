@@ -1,4 +1,4 @@
-//! Plan phase management commands (add, remove)
+//! Plan phase management commands (add, remove, rename, annotate)
 
 use serde_json::json;
 
@@ -220,3 +220,92 @@ pub async fn cmd_plan_remove_phase(
     .with_text(text_output)
     .print(output)
 }
+
+/// Rename a phase, keeping its number, tickets, and other content
+///
+/// # Arguments
+/// * `plan_id` - The plan ID (can be partial)
+/// * `phase` - Phase name or number to rename
+/// * `new_name` - New name for the phase
+/// * `output_json` - If true, output result as JSON
+pub async fn cmd_plan_rename_phase(
+    plan_id: &str,
+    phase: &str,
+    new_name: &str,
+    output: OutputOptions,
+) -> Result<()> {
+    let plan = Plan::find(plan_id).await?;
+    let mut metadata = plan.read()?;
+
+    validate_plan_title(new_name)?;
+    let new_name = new_name.trim();
+
+    let phase_obj = metadata
+        .find_phase_mut(phase)
+        .ok_or_else(|| JanusError::PhaseNotFound(phase.to_string()))?;
+    let old_name = std::mem::replace(&mut phase_obj.name, new_name.to_string());
+    let phase_number = phase_obj.number.clone();
+
+    plan.write_metadata(&metadata)?;
+
+    CommandOutput::new(json!({
+        "plan_id": plan.id,
+        "action": "phase_renamed",
+        "phase_number": phase_number,
+        "old_name": old_name,
+        "new_name": new_name,
+    }))
+    .with_text(format!(
+        "Renamed phase {phase_number} from '{old_name}' to '{new_name}' in plan {}",
+        plan.id
+    ))
+    .print(output)
+}
+
+/// Set or clear the description of a phase
+///
+/// # Arguments
+/// * `plan_id` - The plan ID (can be partial)
+/// * `phase` - Phase name or number to annotate
+/// * `description` - New description; an empty string clears it
+/// * `output_json` - If true, output result as JSON
+pub async fn cmd_plan_annotate_phase(
+    plan_id: &str,
+    phase: &str,
+    description: &str,
+    output: OutputOptions,
+) -> Result<()> {
+    let plan = Plan::find(plan_id).await?;
+    let mut metadata = plan.read()?;
+
+    let description = description.trim();
+    let phase_obj = metadata
+        .find_phase_mut(phase)
+        .ok_or_else(|| JanusError::PhaseNotFound(phase.to_string()))?;
+    phase_obj.description = (!description.is_empty()).then(|| description.to_string());
+    let phase_number = phase_obj.number.clone();
+    let phase_name = phase_obj.name.clone();
+
+    plan.write_metadata(&metadata)?;
+
+    let text = if description.is_empty() {
+        format!(
+            "Cleared description of phase '{phase_name}' in plan {}",
+            plan.id
+        )
+    } else {
+        format!(
+            "Updated description of phase '{phase_name}' in plan {}",
+            plan.id
+        )
+    };
+    CommandOutput::new(json!({
+        "plan_id": plan.id,
+        "action": "phase_annotated",
+        "phase_number": phase_number,
+        "phase_name": phase_name,
+        "description": (!description.is_empty()).then_some(description),
+    }))
+    .with_text(text)
+    .print(output)
+}
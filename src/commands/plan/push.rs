@@ -0,0 +1,144 @@
+//! Plan push command
+//!
+//! Pushes every ticket of a plan to the default remote. On Linear the issues
+//! can be placed in a project, and the phases of a phased plan map to
+//! consecutive cycles.
+
+use owo_colors::OwoColorize;
+use serde_json::json;
+
+use crate::cli::OutputOptions;
+use crate::commands::CommandOutput;
+use crate::commands::sync::{linear_placement, push_ticket};
+use crate::config::Config;
+use crate::error::{JanusError, Result};
+use crate::plan::Plan;
+use crate::remote::{Platform, RemoteProvider, RemoteRef, create_provider};
+use crate::ticket::Ticket;
+
+/// Push a plan's tickets to the default remote
+///
+/// Unlinked tickets get a new remote issue. Tickets that are already linked to
+/// a Linear issue are moved into the project and cycle instead. With
+/// `first_cycle`, phase 1 goes to that cycle number, phase 2 to the next, and
+/// so on; the tickets of a simple plan all go to `first_cycle`.
+///
+/// Pushed tickets are linked as they go, so rerunning after a failure does not
+/// create duplicate issues.
+pub async fn cmd_plan_push(
+    id: &str,
+    project: Option<&str>,
+    first_cycle: Option<u32>,
+    output: OutputOptions,
+) -> Result<()> {
+    let config = Config::load()?;
+    let plan = Plan::find(id).await?;
+    let metadata = plan.read()?;
+
+    let default_remote = config.default_remote.as_ref().ok_or_else(|| {
+        JanusError::Config(
+            "No default.remote configured. Run: janus config set default.remote <platform:org>"
+                .to_string(),
+        )
+    })?;
+    let platform = default_remote.platform;
+    // Validates that project and cycle are only used with Linear
+    linear_placement(
+        platform,
+        project.map(str::to_string),
+        first_cycle.map(|n| n.to_string()),
+    )?;
+
+    // Tickets in plan order, each with the cycle its phase maps to
+    let assignments: Vec<(String, Option<String>)> = if metadata.is_phased() {
+        metadata
+            .phases()
+            .iter()
+            .enumerate()
+            .flat_map(|(index, phase)| {
+                let cycle = first_cycle.map(|n| (n + index as u32).to_string());
+                phase
+                    .tickets()
+                    .iter()
+                    .map(move |ticket_id| (ticket_id.clone(), cycle.clone()))
+            })
+            .collect()
+    } else {
+        metadata
+            .all_tickets()
+            .into_iter()
+            .map(|ticket_id| (ticket_id.to_string(), first_cycle.map(|n| n.to_string())))
+            .collect()
+    };
+
+    let provider = create_provider(&platform, &config)?;
+    let mut results = Vec::with_capacity(assignments.len());
+    let mut text_lines = Vec::with_capacity(assignments.len());
+
+    for (ticket_id, cycle) in assignments {
+        let ticket = Ticket::find(&ticket_id).await?;
+        let ticket_metadata = ticket.read()?;
+        let placement = linear_placement(platform, project.map(str::to_string), cycle.clone())?;
+
+        let (action, remote_ref) = match &ticket_metadata.remote {
+            None => {
+                let remote_ref =
+                    push_ticket(&ticket, &ticket_metadata, &config, &provider, placement).await?;
+                ("created", remote_ref.to_string())
+            }
+            Some(remote) => {
+                let remote_ref = RemoteRef::parse(remote, Some(&config))?;
+                if placement.is_empty() || remote_ref.platform() != Platform::Linear {
+                    ("unchanged", remote.clone())
+                } else {
+                    provider.update_issue(&remote_ref, placement).await?;
+                    ("updated", remote.clone())
+                }
+            }
+        };
+
+        let placed_in = cycle
+            .as_deref()
+            .map(|c| format!(" (cycle {c})"))
+            .unwrap_or_default();
+        text_lines.push(match action {
+            "created" => format!(
+                "Created {} for {}{placed_in}",
+                remote_ref.green(),
+                ticket.id.cyan()
+            ),
+            "updated" => format!(
+                "Updated {} for {}{placed_in}",
+                remote_ref.green(),
+                ticket.id.cyan()
+            ),
+            _ => format!(
+                "{} {} already linked to {}",
+                "Skipped".dimmed(),
+                ticket.id.cyan(),
+                remote_ref
+            ),
+        });
+        results.push(json!({
+            "id": ticket.id,
+            "action": action,
+            "remote_ref": remote_ref,
+            "cycle": cycle,
+        }));
+    }
+
+    let text = if text_lines.is_empty() {
+        format!("Plan {} has no tickets to push", plan.id.cyan())
+    } else {
+        text_lines.join("\n")
+    };
+
+    CommandOutput::new(json!({
+        "plan_id": plan.id,
+        "action": "plan_pushed",
+        "project": project,
+        "tickets": results,
+    }))
+    .with_text(text)
+    .print(output)
+}
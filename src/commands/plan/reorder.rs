@@ -1,6 +1,7 @@
 //! Plan reorder command
 
 use std::collections::HashSet;
+use std::io::Read;
 
 use serde_json::json;
 
@@ -11,56 +12,104 @@ use crate::error::{JanusError, Result};
 use crate::plan::Plan;
 use crate::plan::types::PlanSection;
 
+/// Parse a ticket order, one ticket per line.
+///
+/// Lines may be numbered as in the editor template (`1. j-a1b2 Title`) or be
+/// bare IDs (`j-a1b2`); only the ID is kept.
 fn parse_and_validate_ticket_order(
     new_order: &str,
     original_tickets: &[String],
 ) -> Result<Vec<String>> {
     let new_ticket_order: Vec<String> = new_order
         .lines()
-        .filter(|l| !l.trim().is_empty())
-        .filter_map(|l| {
-            l.split('.')
-                .nth(1)
-                .map(|s| s.split_whitespace().next().unwrap_or("").to_string())
-        })
-        .filter(|s| !s.is_empty())
+        .map(|l| strip_list_number(l.trim()))
+        .filter_map(|l| l.split_whitespace().next().map(str::to_string))
         .collect();
 
     let original_set: HashSet<_> = original_tickets.iter().collect();
     let new_set: HashSet<_> = new_ticket_order.iter().collect();
-    if original_set != new_set {
+    if original_set != new_set || new_set.len() != new_ticket_order.len() {
         return Err(JanusError::ReorderTicketMismatch);
     }
 
     Ok(new_ticket_order)
 }
 
+/// Strip a leading `N.` list number from a line
+fn strip_list_number(line: &str) -> &str {
+    match line.split_once('.') {
+        Some((num, rest)) if !num.is_empty() && num.chars().all(|c| c.is_ascii_digit()) => {
+            rest.trim_start()
+        }
+        _ => line,
+    }
+}
+
+/// Parse a phase order, one phase per line.
+///
+/// Each line is a phase number, optionally followed by `: name` as in the
+/// editor template, or a phase name on its own.
 fn parse_and_validate_phase_order(
     new_order: &str,
     original_phases: &[(String, String)],
 ) -> Result<Vec<String>> {
     let new_phase_order: Vec<String> = new_order
         .lines()
-        .filter(|l| !l.trim().is_empty())
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
         .map(|l| {
             // Extract phase number (before colon or the whole line)
-            l.split(':').next().unwrap_or(l).trim().to_string()
+            let num = l.split(':').next().unwrap_or(l).trim();
+            original_phases
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(num))
+                .or_else(|| {
+                    original_phases
+                        .iter()
+                        .find(|(_, name)| !name.is_empty() && name.eq_ignore_ascii_case(l))
+                })
+                .map(|(n, _)| n.clone())
+                .unwrap_or_else(|| num.to_string())
         })
-        .filter(|s| !s.is_empty())
         .collect();
 
     // Build set of original phase numbers
     let original_set: HashSet<_> = original_phases.iter().map(|(num, _)| num).collect();
     let new_set: HashSet<_> = new_phase_order.iter().collect();
 
-    // Validate set equality - no phases dropped or added
-    if original_set != new_set {
+    // Validate set equality - no phases dropped, added, or repeated
+    if original_set != new_set || new_set.len() != new_phase_order.len() {
         return Err(JanusError::ReorderPhaseMismatch);
     }
 
     Ok(new_phase_order)
 }
 
+/// Get the new order, either from `from_file` (`-` for stdin) or by opening
+/// `current` in the editor.
+///
+/// Returns `None` when the order was edited interactively and left unchanged.
+fn read_new_order(from_file: Option<&str>, current: &str) -> Result<Option<String>> {
+    let Some(input) = from_file else {
+        let edited = edit_in_editor(current)?;
+        return Ok((edited.trim() != current.trim()).then_some(edited));
+    };
+
+    let content = if input == "-" {
+        let mut buffer = String::new();
+        std::io::stdin().read_to_string(&mut buffer)?;
+        buffer
+    } else {
+        std::fs::read_to_string(input).map_err(|e| {
+            JanusError::Io(std::io::Error::new(
+                e.kind(),
+                format!("Failed to read order file at {input}: {e}"),
+            ))
+        })?
+    };
+    Ok(Some(content))
+}
+
 /// Reorder phase sections within a plan's section list.
 ///
 /// Non-phase sections (FreeForm, Tickets) remain in their original positions.
@@ -128,17 +177,20 @@ fn reorder_phase_sections(
     new_sections
 }
 
-/// Reorder tickets or phases interactively
+/// Reorder tickets or phases, in the editor or from a file
 ///
 /// # Arguments
 /// * `plan_id` - The plan ID (can be partial)
 /// * `phase` - Optional phase to reorder tickets within
 /// * `reorder_phases` - If true, reorder phases instead of tickets
+/// * `from_file` - Read the new order from this file (`-` for stdin) instead
+///   of opening the editor
 /// * `output_json` - If true, output result as JSON
 pub async fn cmd_plan_reorder(
     plan_id: &str,
     phase: Option<&str>,
     reorder_phases: bool,
+    from_file: Option<&str>,
     output: OutputOptions,
 ) -> Result<()> {
     let plan = Plan::find(plan_id).await?;
@@ -167,12 +219,10 @@ pub async fn cmd_plan_reorder(
             }
         }
 
-        // Open in editor
-        let new_order = edit_in_editor(&temp_content)?;
-        if new_order.trim() == temp_content.trim() {
+        let Some(new_order) = read_new_order(from_file, &temp_content)? else {
             println!("No changes made");
             return Ok(());
-        }
+        };
 
         // Parse and validate new phase order
         let new_phase_order = parse_and_validate_phase_order(&new_order, &phases)?;
@@ -198,12 +248,10 @@ pub async fn cmd_plan_reorder(
             .map(|(i, t)| format!("{}. {}\n", i + 1, t))
             .collect();
 
-        // Open in editor
-        let new_order = edit_in_editor(&temp_content)?;
-        if new_order.trim() == temp_content.trim() {
+        let Some(new_order) = read_new_order(from_file, &temp_content)? else {
             println!("No changes made");
             return Ok(());
-        }
+        };
 
         phase_obj.ticket_list.tickets =
             parse_and_validate_ticket_order(&new_order, &phase_obj.ticket_list.tickets)?;
@@ -228,12 +276,10 @@ pub async fn cmd_plan_reorder(
             .map(|(i, t)| format!("{}. {}\n", i + 1, t))
             .collect();
 
-        // Open in editor
-        let new_order = edit_in_editor(&temp_content)?;
-        if new_order.trim() == temp_content.trim() {
+        let Some(new_order) = read_new_order(from_file, &temp_content)? else {
             println!("No changes made");
             return Ok(());
-        }
+        };
 
         ts.ticket_list.tickets =
             parse_and_validate_ticket_order(&new_order, &ts.ticket_list.tickets)?;
@@ -543,6 +589,17 @@ mod tests {
         assert_eq!(result, vec!["j-a1b2", "j-c3d4", "j-e5f6"]);
     }
 
+    #[test]
+    fn test_parse_and_validate_ticket_order_bare_ids() {
+        let order = "j-c3d4\n\nj-a1b2  Add login\n";
+        let original = vec!["j-a1b2".to_string(), "j-c3d4".to_string()];
+        let result = parse_and_validate_ticket_order(order, &original).unwrap();
+        assert_eq!(result, vec!["j-c3d4", "j-a1b2"]);
+
+        let repeated = "j-a1b2\nj-a1b2\nj-c3d4\n";
+        assert!(parse_and_validate_ticket_order(repeated, &original).is_err());
+    }
+
     #[test]
     fn test_parse_and_validate_ticket_order_mismatch() {
         let order = "1. j-a1b2\n2. j-new\n";
@@ -562,6 +619,17 @@ mod tests {
         assert_eq!(result, vec!["2", "1"]);
     }
 
+    #[test]
+    fn test_parse_and_validate_phase_order_by_name() {
+        let order = "implementation\n1\n";
+        let original = vec![
+            ("1".to_string(), "Infrastructure".to_string()),
+            ("2".to_string(), "Implementation".to_string()),
+        ];
+        let result = parse_and_validate_phase_order(order, &original).unwrap();
+        assert_eq!(result, vec!["2", "1"]);
+    }
+
     #[test]
     fn test_parse_and_validate_phase_order_mismatch() {
         let order = "3: New Phase\n";
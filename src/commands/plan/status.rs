@@ -34,6 +34,8 @@ pub async fn cmd_plan_status(id: &str, output: OutputOptions) -> Result<()> {
                 "status": ps.status.to_string(),
                 "completed_count": ps.completed_count,
                 "total_count": ps.total_count,
+                "completed_points": ps.completed_points,
+                "total_points": ps.total_points,
             })
         })
         .collect();
@@ -46,6 +48,9 @@ pub async fn cmd_plan_status(id: &str, output: OutputOptions) -> Result<()> {
         "completed_count": plan_status.completed_count,
         "total_count": plan_status.total_count,
         "progress_percent": plan_status.progress_percent(),
+        "completed_points": plan_status.completed_points,
+        "total_points": plan_status.total_points,
+        "points_percent": plan_status.points_progress().percent(),
         "phases": phases_json,
     });
 
@@ -61,8 +66,10 @@ pub async fn cmd_plan_status(id: &str, output: OutputOptions) -> Result<()> {
         format_status_colored(plan_status.status)
     ));
     text_output.push_str(&format!(
-        "Progress: {} tickets\n",
-        plan_status.progress_string()
+        "Progress: {} points, {}/{} tickets\n",
+        plan_status.points_progress().format(),
+        plan_status.completed_count,
+        plan_status.total_count
     ));
 
     // If phased, show breakdown by phase
@@ -80,7 +87,10 @@ pub async fn cmd_plan_status(id: &str, output: OutputOptions) -> Result<()> {
 
         for ps in &phase_statuses {
             let status_badge = format_status_colored(ps.status);
-            let progress = format!("({}/{})", ps.completed_count, ps.total_count);
+            let progress = format!(
+                "({}/{}, {}/{} pts)",
+                ps.completed_count, ps.total_count, ps.completed_points, ps.total_points
+            );
             text_output.push_str(&format!(
                 "  {}. {} {:width$} {}\n",
                 ps.phase_number,
@@ -0,0 +1,182 @@
+//! Pull request linking (`janus pr`)
+
+use owo_colors::OwoColorize;
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::config::{Config, TransitionVia};
+use crate::error::{JanusError, Result};
+use crate::remote::github::GitHubProvider;
+use crate::remote::pull_request::{PullRequest, PullRequestRef, PullRequestState, default_pr_repo};
+use crate::status::{TransitionVerdict, evaluate_transition};
+use crate::ticket::Ticket;
+use crate::types::{TicketMetadata, TicketStatus};
+
+/// Link a pull request to a ticket
+///
+/// Without `pr`, links every pull request in the ticket's repository whose
+/// title or body mentions the ticket ID.
+pub async fn cmd_pr_link(id: &str, pr: Option<&str>, output: OutputOptions) -> Result<()> {
+    let config = Config::load()?;
+    let (ticket, metadata) = Ticket::find_and_read(id).await?;
+    let provider = GitHubProvider::from_config(&config)?;
+    let default_repo = default_pr_repo(&config, metadata.remote.as_deref());
+
+    let found = match pr {
+        Some(pr) => {
+            let reference = PullRequestRef::parse(
+                pr,
+                default_repo
+                    .as_ref()
+                    .map(|(owner, repo)| (owner.as_str(), repo.as_str())),
+            )?;
+            vec![provider.fetch_pull_request(&reference).await?]
+        }
+        None => {
+            let (owner, repo) = default_repo.ok_or_else(|| {
+                JanusError::Config(
+                    "cannot detect pull requests without a GitHub remote on the ticket or a GitHub default.remote with a repository".to_string(),
+                )
+            })?;
+            let mut found = Vec::new();
+            for reference in provider
+                .find_pull_requests(&owner, &repo, &ticket.id)
+                .await?
+            {
+                found.push(provider.fetch_pull_request(&reference).await?);
+            }
+            found
+        }
+    };
+
+    let mut linked = metadata.pull_requests.clone();
+    let mut added = Vec::new();
+    for pull in &found {
+        let reference = pull.reference.to_string();
+        if !linked.contains(&reference) {
+            linked.push(reference.clone());
+            added.push(reference);
+        }
+    }
+    if !added.is_empty() {
+        ticket.update_field("pull-requests", &serde_json::to_string(&linked)?)?;
+    }
+
+    let text = if found.is_empty() {
+        format!("No pull requests mention {}", ticket.id.cyan())
+    } else {
+        found
+            .iter()
+            .map(|pull| {
+                let verb = if added.contains(&pull.reference.to_string()) {
+                    "Linked"
+                } else {
+                    "Already linked"
+                };
+                format!(
+                    "{verb} {} -> {}",
+                    ticket.id.cyan(),
+                    format_pull_request(pull)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "pr_linked",
+        "added": added,
+        "pull_requests": found.iter().map(pull_request_json).collect::<Vec<_>>(),
+    }))
+    .with_text(text)
+    .print(output)
+}
+
+/// Fetch the current state of each linked pull request.
+///
+/// Status is best-effort: references that can't be parsed or fetched (for
+/// example without a GitHub token) map to `None`.
+pub async fn fetch_linked_pull_requests(
+    config: &Config,
+    refs: &[String],
+) -> Vec<(String, Option<PullRequest>)> {
+    let provider = GitHubProvider::from_config(config).ok();
+    let mut results = Vec::with_capacity(refs.len());
+    for raw in refs {
+        let pull = match (&provider, PullRequestRef::parse(raw, None)) {
+            (Some(provider), Ok(reference)) => provider.fetch_pull_request(&reference).await.ok(),
+            _ => None,
+        };
+        results.push((raw.clone(), pull));
+    }
+    results
+}
+
+/// Complete the ticket if one of its linked pull requests has merged and
+/// `pull_requests.complete_on_merge` is enabled.
+///
+/// Returns the merged pull request and whether the ticket was completed (it is
+/// not in a dry run, or when a transition rule requires confirmation or forbids
+/// it).
+pub async fn complete_on_merge(
+    ticket: &Ticket,
+    metadata: &TicketMetadata,
+    config: &Config,
+    dry_run: bool,
+) -> Result<Option<(PullRequest, bool)>> {
+    let status = metadata.status.unwrap_or_default();
+    if !config.pull_requests.complete_on_merge
+        || metadata.pull_requests.is_empty()
+        || status.is_terminal()
+    {
+        return Ok(None);
+    }
+
+    let merged = fetch_linked_pull_requests(config, &metadata.pull_requests)
+        .await
+        .into_iter()
+        .filter_map(|(_, pull)| pull)
+        .find(|pull| pull.state == PullRequestState::Merged);
+    let Some(merged) = merged else {
+        return Ok(None);
+    };
+    if dry_run {
+        return Ok(Some((merged, false)));
+    }
+
+    let verdict = evaluate_transition(
+        &ticket.id,
+        status,
+        TicketStatus::Complete,
+        TransitionVia::Sync,
+    )
+    .await?;
+    if verdict != TransitionVerdict::Allowed {
+        return Ok(Some((merged, false)));
+    }
+
+    ticket.update_status(TicketStatus::Complete, None)?;
+    Ok(Some((merged, true)))
+}
+
+/// JSON representation of a pull request
+pub fn pull_request_json(pull: &PullRequest) -> serde_json::Value {
+    json!({
+        "ref": pull.reference.to_string(),
+        "url": pull.url,
+        "title": pull.title,
+        "state": pull.state.to_string(),
+    })
+}
+
+/// One-line text representation of a pull request
+pub fn format_pull_request(pull: &PullRequest) -> String {
+    let state = match pull.state {
+        PullRequestState::Open => pull.state.to_string().green().to_string(),
+        PullRequestState::Merged => pull.state.to_string().magenta().to_string(),
+        PullRequestState::Closed => pull.state.to_string().red().to_string(),
+    };
+    format!("{} [{}] {}", pull.reference, state, pull.title)
+}
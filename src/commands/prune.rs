@@ -0,0 +1,248 @@
+//! `janus prune` command.
+//!
+//! Removes references to tickets that no longer exist: entries in a ticket's
+//! `deps` and `links`, a `parent` pointing at a deleted ticket, and ticket
+//! entries in plans. The orphaned references are always reported first;
+//! nothing is modified with `--dry-run`, and otherwise the removal has to be
+//! confirmed (or `--yes` passed).
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use serde_json::{Value, json};
+
+use super::{CommandOutput, interactive};
+use crate::cli::OutputOptions;
+use crate::error::{JanusError, Result};
+use crate::events::log_ticket_removed_from_plan;
+use crate::fs::transaction;
+use crate::plan::types::{PlanMetadata, PlanSection};
+use crate::plan::{Plan, get_all_plans_from_disk};
+use crate::ticket::{Ticket, TicketLoadResult, get_all_tickets_from_disk};
+use crate::types::ArrayField;
+use crate::utils::is_stdin_tty;
+
+/// Ticket frontmatter field holding an orphaned reference
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum RefField {
+    Deps,
+    Links,
+    Parent,
+}
+
+enum_display!(
+    RefField,
+    {
+        Deps => "deps",
+        Links => "links",
+        Parent => "parent",
+    }
+);
+
+/// A reference from a ticket to a ticket that does not exist
+pub(super) struct TicketRef {
+    pub(super) ticket_id: String,
+    pub(super) file_path: PathBuf,
+    pub(super) field: RefField,
+    pub(super) target: String,
+}
+
+impl TicketRef {
+    pub(super) fn describe(&self) -> String {
+        format!(
+            "{}: {} -> {} (missing)",
+            self.ticket_id, self.field, self.target
+        )
+    }
+}
+
+/// A plan entry for a ticket that does not exist
+pub(super) struct PlanRef {
+    pub(super) plan_id: String,
+    pub(super) target: String,
+}
+
+impl PlanRef {
+    pub(super) fn describe(&self) -> String {
+        format!("{}: ticket {} (missing)", self.plan_id, self.target)
+    }
+}
+
+/// Find deps, links, parents, and plan entries pointing at tickets that are
+/// not on disk.
+pub(super) fn find_orphaned_references(
+    tickets: &TicketLoadResult,
+    plans: &[PlanMetadata],
+) -> (Vec<TicketRef>, Vec<PlanRef>) {
+    // Files that fail to parse still exist; never treat their IDs as deleted
+    let existing: HashSet<String> = tickets
+        .items
+        .iter()
+        .filter_map(|t| t.id.as_ref().map(|id| id.to_string()))
+        .chain(
+            tickets
+                .failed
+                .iter()
+                .filter_map(|(file, _)| file.strip_suffix(".md").map(String::from)),
+        )
+        .collect();
+
+    let mut ticket_refs = Vec::new();
+    for ticket in &tickets.items {
+        let (Some(id), Some(file_path)) = (&ticket.id, &ticket.file_path) else {
+            continue;
+        };
+        let fields = [
+            (RefField::Deps, ticket.deps.iter().collect::<Vec<_>>()),
+            (RefField::Links, ticket.links.iter().collect()),
+            (RefField::Parent, ticket.parent.iter().collect()),
+        ];
+        for (field, targets) in fields {
+            for target in targets {
+                if !existing.contains(target.as_ref()) {
+                    ticket_refs.push(TicketRef {
+                        ticket_id: id.to_string(),
+                        file_path: file_path.clone(),
+                        field,
+                        target: target.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    let mut plan_refs = Vec::new();
+    for plan in plans {
+        let Some(plan_id) = &plan.id else {
+            continue;
+        };
+        for target in plan.all_tickets() {
+            if !existing.contains(target) {
+                plan_refs.push(PlanRef {
+                    plan_id: plan_id.to_string(),
+                    target: target.to_string(),
+                });
+            }
+        }
+    }
+
+    (ticket_refs, plan_refs)
+}
+
+/// Remove deps, links, parents, and plan entries that point at deleted tickets
+pub async fn cmd_prune(dry_run: bool, yes: bool, output: OutputOptions) -> Result<()> {
+    let (ticket_refs, plan_refs) = find_orphaned_references(
+        &get_all_tickets_from_disk(),
+        &get_all_plans_from_disk().items,
+    );
+
+    let total = ticket_refs.len() + plan_refs.len();
+    let report = format_report(&ticket_refs, &plan_refs);
+
+    if total == 0 || dry_run {
+        let text = if total == 0 {
+            "No orphaned references found.".to_string()
+        } else {
+            format!("{report}\nWould remove {total} orphaned reference(s).")
+        };
+        return CommandOutput::new(refs_json(&ticket_refs, &plan_refs, dry_run, false))
+            .with_text(text)
+            .print(output);
+    }
+
+    if !yes {
+        if output.json || !is_stdin_tty() {
+            return Err(JanusError::ConfirmationRequired(
+                "Pruning requires -y/--yes flag in non-interactive contexts. Use --dry-run to preview or -y to confirm.".to_string(),
+            ));
+        }
+        println!("{report}");
+        if !interactive::confirm(&format!("Remove {total} orphaned reference(s)"))? {
+            println!("Cancelled");
+            return Ok(());
+        }
+    }
+
+    // Tickets and plans are pruned together or not at all
+    transaction("prune", || async {
+        for r in &ticket_refs {
+            let ticket = Ticket::new(r.file_path.clone())?;
+            match r.field {
+                RefField::Deps => {
+                    ticket.remove_from_array_field(ArrayField::Deps, &r.target)?;
+                }
+                RefField::Links => {
+                    ticket.remove_from_array_field(ArrayField::Links, &r.target)?;
+                }
+                RefField::Parent => ticket.remove_field("parent")?,
+            }
+        }
+
+        let mut plan_ids: Vec<&str> = plan_refs.iter().map(|r| r.plan_id.as_str()).collect();
+        plan_ids.dedup();
+        for plan_id in plan_ids {
+            let plan = Plan::find(plan_id).await?;
+            let mut metadata = plan.read()?;
+            let mut removed = Vec::new();
+            for r in plan_refs.iter().filter(|r| r.plan_id == plan_id) {
+                for section in &mut metadata.sections {
+                    let (found, phase) = match section {
+                        PlanSection::Phase(phase) => {
+                            (phase.remove_ticket(&r.target), Some(phase.name.clone()))
+                        }
+                        PlanSection::Tickets(ts) => (ts.remove_ticket(&r.target), None),
+                        PlanSection::FreeForm(_) => (false, None),
+                    };
+                    if found {
+                        removed.push((r.target.as_str(), phase));
+                        break;
+                    }
+                }
+            }
+            plan.write_metadata(&metadata)?;
+            for (target, phase) in removed {
+                log_ticket_removed_from_plan(&plan.id, target, phase.as_deref());
+            }
+        }
+        Ok(())
+    })
+    .await?;
+
+    CommandOutput::new(refs_json(&ticket_refs, &plan_refs, false, true))
+        .with_text(format!("{report}\nRemoved {total} orphaned reference(s)."))
+        .print(output)
+}
+
+fn refs_json(
+    ticket_refs: &[TicketRef],
+    plan_refs: &[PlanRef],
+    dry_run: bool,
+    pruned: bool,
+) -> Value {
+    json!({
+        "tickets": ticket_refs
+            .iter()
+            .map(|r| json!({"id": r.ticket_id, "field": r.field.to_string(), "target": r.target}))
+            .collect::<Vec<_>>(),
+        "plans": plan_refs
+            .iter()
+            .map(|r| json!({"plan_id": r.plan_id, "target": r.target}))
+            .collect::<Vec<_>>(),
+        "dry_run": dry_run,
+        "pruned": pruned,
+    })
+}
+
+fn format_report(ticket_refs: &[TicketRef], plan_refs: &[PlanRef]) -> String {
+    let mut lines = Vec::new();
+    for r in ticket_refs {
+        lines.push(format!("  {}", r.describe()));
+    }
+    for r in plan_refs {
+        lines.push(format!("  {}", r.describe()));
+    }
+    if lines.is_empty() {
+        return String::new();
+    }
+    format!("Orphaned references:\n{}\n", lines.join("\n"))
+}
@@ -1,11 +1,18 @@
 use std::io::{BufWriter, ErrorKind, Write, stdout};
-use std::process::{Command, Stdio};
+use std::process::{Child, Command, ExitStatus, Stdio};
 
+use jiff::Timestamp;
 use serde_json::json;
 
-use crate::commands::ticket_to_json;
+use crate::cli::OutputOptions;
+use crate::commands::{CommandOutput, ticket_to_json};
 use crate::error::{JanusError, Result};
+use crate::query::{
+    ClosedSinceFilter, GroupField, NotArchivedFilter, TicketFilter, TicketFilterContext,
+    group_tickets,
+};
 use crate::ticket::{get_all_children_counts, get_all_tickets};
+use crate::types::TicketMetadata;
 
 /// Enrich a ticket JSON value with its children_count from the pre-fetched map.
 fn enrich_with_children_count(
@@ -27,71 +34,332 @@ fn write_ticket_json(writer: &mut impl Write, json_val: &serde_json::Value) -> R
 }
 
 /// Output tickets as JSON, optionally filtered with jq's select() function
-pub async fn cmd_query(filter: Option<&str>) -> Result<()> {
+///
+/// With `group_by`, one JSON line is printed per group instead of per ticket;
+/// `count` drops the tickets from each group (or, without `group_by`, prints
+/// only the total). `nl` is a plain-English request that the configured LLM
+/// turns into the filter; the generated filter is printed to stderr.
+///
+/// Archived tickets are left out unless `include_archived` is set.
+/// `closed_since` keeps only tickets closed (or archived) since that time,
+/// so history can be queried without un-archiving it.
+pub async fn cmd_query(
+    filter: Option<&str>,
+    nl: Option<&str>,
+    group_by: Option<GroupField>,
+    count: bool,
+    include_archived: bool,
+    closed_since: Option<Timestamp>,
+) -> Result<()> {
+    let translated;
+    let filter = match nl {
+        Some(request) => {
+            translated = translate_nl(request).await?;
+            eprintln!("Filter: {translated}");
+            Some(translated.as_str())
+        }
+        None => filter,
+    };
+
     let result = get_all_tickets().await?;
-    let tickets = result.items;
+    let tickets = select_history(result.items, include_archived, closed_since);
 
     // Get all children counts in a single query (avoids N+1 pattern)
     let children_counts = get_all_children_counts().await?;
 
-    if let Some(filter_expr) = filter {
-        // Spawn jq to process the filter
-        // NOTE: The filter expression is passed directly to the jq binary via
-        // Command::args(), which does NOT perform shell interpolation. This
-        // prevents shell injection attacks since arguments are passed directly
-        // to the process without being interpreted by a shell.
-        let filter_str = format!("select({filter_expr})");
-
-        let mut child = Command::new("jq")
-            .args(["-c", &filter_str])
-            .stdin(Stdio::piped())
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .spawn()
-            .map_err(|e| {
-                if e.kind() == ErrorKind::NotFound {
-                    JanusError::JqFilter(
-                        "jq is not installed. Install jq (https://jqlang.github.io/jq/) or omit the --filter flag to get raw JSON output.".to_string()
-                    )
-                } else {
-                    JanusError::Io(e)
+    let ticket_values = tickets.iter().map(|t| {
+        let mut json_val = ticket_to_json(t);
+        if let Some(id) = &t.id {
+            enrich_with_children_count(&mut json_val, id, &children_counts);
+        }
+        json_val
+    });
+
+    if group_by.is_none() && !count {
+        return match filter {
+            Some(filter_expr) => {
+                // jq writes its results straight to our stdout
+                let mut child = spawn_jq(filter_expr, Stdio::inherit())?;
+
+                // Stream each ticket as a JSON line directly to jq's stdin
+                if let Some(stdin) = child.stdin.take() {
+                    let mut writer = BufWriter::new(stdin);
+                    for json_val in ticket_values {
+                        write_ticket_json(&mut writer, &json_val)?;
+                    }
+                    writer.flush()?;
+                    // stdin is dropped here, closing the pipe so jq can finish
                 }
-            })?;
-
-        // Stream each ticket as a JSON line directly to jq's stdin
-        if let Some(stdin) = child.stdin.take() {
-            let mut writer = BufWriter::new(stdin);
-            for t in &tickets {
-                let mut json_val = ticket_to_json(t);
-                if let Some(id) = &t.id {
-                    enrich_with_children_count(&mut json_val, id, &children_counts);
+
+                check_jq_status(child.wait()?)
+            }
+            None => {
+                // No filter: stream each ticket as a JSON line directly to stdout
+                let stdout = stdout();
+                let mut writer = BufWriter::new(stdout.lock());
+                for json_val in ticket_values {
+                    write_ticket_json(&mut writer, &json_val)?;
                 }
-                write_ticket_json(&mut writer, &json_val)?;
+                writer.flush()?;
+                Ok(())
             }
-            writer.flush()?;
-            // stdin is dropped here, closing the pipe so jq can finish
-        }
+        };
+    }
 
-        let status = child.wait()?;
-        if !status.success() {
-            return Err(JanusError::JqFilter(format!(
-                "jq filter failed with exit code {}",
-                status.code().unwrap_or(-1)
-            )));
-        }
-    } else {
-        // No filter: stream each ticket as a JSON line directly to stdout
-        let stdout = stdout();
-        let mut writer = BufWriter::new(stdout.lock());
-        for t in &tickets {
-            let mut json_val = ticket_to_json(t);
-            if let Some(id) = &t.id {
-                enrich_with_children_count(&mut json_val, id, &children_counts);
+    let selected: Vec<serde_json::Value> = match filter {
+        Some(filter_expr) => filter_with_jq(filter_expr, ticket_values)?,
+        None => ticket_values.collect(),
+    };
+
+    let stdout = stdout();
+    let mut writer = BufWriter::new(stdout.lock());
+    match group_by {
+        Some(field) => {
+            for group in group_tickets(selected, field) {
+                write_ticket_json(&mut writer, &group.to_json(field, count))?;
             }
-            write_ticket_json(&mut writer, &json_val)?;
         }
-        writer.flush()?;
+        None => write_ticket_json(&mut writer, &json!({ "count": selected.len() }))?,
     }
+    writer.flush()?;
 
     Ok(())
 }
+
+/// Drop archived tickets unless `include_archived`, and with `closed_since`
+/// keep only tickets closed since then.
+fn select_history(
+    tickets: Vec<TicketMetadata>,
+    include_archived: bool,
+    closed_since: Option<Timestamp>,
+) -> Vec<TicketMetadata> {
+    let context = TicketFilterContext::new(Default::default());
+    let closed_since = closed_since.map(ClosedSinceFilter::new);
+    tickets
+        .into_iter()
+        .filter(|t| include_archived || NotArchivedFilter.matches(t, &context))
+        .filter(|t| {
+            closed_since.as_ref().is_none_or(|since| {
+                t.status.is_some_and(|s| s.is_terminal()) && since.matches(t, &context)
+            })
+        })
+        .collect()
+}
+
+/// Count tickets, optionally broken down by a field. Like `query`, archived
+/// tickets are not counted.
+pub async fn cmd_count(by: Option<GroupField>, output: OutputOptions) -> Result<()> {
+    let tickets = select_history(get_all_tickets().await?.items, false, None);
+    let total = tickets.len();
+
+    let Some(field) = by else {
+        return CommandOutput::new(json!({ "total": total }))
+            .with_text(total.to_string())
+            .print(output);
+    };
+
+    let groups = group_tickets(tickets.iter().map(ticket_to_json).collect(), field);
+    let labels: Vec<String> = groups.iter().map(|g| g.label(field)).collect();
+    let width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let mut lines: Vec<String> = groups
+        .iter()
+        .zip(&labels)
+        .map(|(group, label)| format!("{label:<width$}  {}", group.tickets.len()))
+        .collect();
+    lines.push(format!("{:<width$}  {total}", "total"));
+
+    CommandOutput::new(json!({
+        "total": total,
+        "by": field.to_string(),
+        "groups": groups
+            .iter()
+            .map(|g| g.to_json(field, true))
+            .collect::<Vec<_>>(),
+    }))
+    .with_text(lines.join("\n"))
+    .print(output)
+}
+
+#[cfg(feature = "llm")]
+const NL_SYSTEM_PROMPT: &str = "You translate requests about tickets into a jq boolean \
+expression that will be wrapped in select(...) and run against one JSON object per ticket. \
+Ticket keys: id, title, status (new, next, in_progress, complete, cancelled), type (bug, \
+feature, task, epic, chore), priority (number 0-4, 0 is highest; \"P1\" means 1), size \
+(xsmall, small, medium, large, xlarge), assignee, parent, deps (array of ids), created, \
+started_at, closed_at (ISO 8601 strings, may be null), resolution (done, wontfix, \
+duplicate, may be null), external_ref, remote, docs, \
+pull_requests. \"Open\" means status is not complete or cancelled. Compare dates as \
+strings against ISO 8601 prefixes. Reply with only the expression, without select().";
+
+/// Translate a plain-English request into a jq filter with the configured LLM.
+#[cfg(feature = "llm")]
+async fn translate_nl(request: &str) -> Result<String> {
+    let config = crate::config::Config::load()?;
+    let today = jiff::Zoned::now().date();
+    let prompt = format!("Today is {today}.\n\nRequest: {request}");
+    let reply = crate::llm::complete(&config, NL_SYSTEM_PROMPT, &prompt).await?;
+
+    clean_filter(&reply).ok_or_else(|| {
+        JanusError::Api(format!(
+            "could not read a jq filter from the LLM reply: {reply}"
+        ))
+    })
+}
+
+#[cfg(not(feature = "llm"))]
+async fn translate_nl(_request: &str) -> Result<String> {
+    Err(JanusError::Config(
+        "`janus query --nl` requires janus to be built with the `llm` feature".to_string(),
+    ))
+}
+
+/// Strip code fences and a `select(...)` wrapper from an LLM reply.
+#[cfg(feature = "llm")]
+fn clean_filter(reply: &str) -> Option<String> {
+    let filter = reply
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with("```"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let filter = filter.trim().trim_matches('`').trim();
+    let filter = filter
+        .strip_prefix("select(")
+        .and_then(|f| f.strip_suffix(')'))
+        .unwrap_or(filter)
+        .trim();
+    (!filter.is_empty()).then(|| filter.to_string())
+}
+
+/// Spawn jq with `select(<filter>)`, sending its output to `stdout`.
+fn spawn_jq(filter_expr: &str, stdout: Stdio) -> Result<Child> {
+    // NOTE: The filter expression is passed directly to the jq binary via
+    // Command::args(), which does NOT perform shell interpolation. This
+    // prevents shell injection attacks since arguments are passed directly
+    // to the process without being interpreted by a shell.
+    let filter_str = format!("select({filter_expr})");
+
+    Command::new("jq")
+        .args(["-c", &filter_str])
+        .stdin(Stdio::piped())
+        .stdout(stdout)
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|e| {
+            if e.kind() == ErrorKind::NotFound {
+                JanusError::JqFilter(
+                    "jq is not installed. Install jq (https://jqlang.github.io/jq/) or omit the --filter flag to get raw JSON output.".to_string()
+                )
+            } else {
+                JanusError::Io(e)
+            }
+        })
+}
+
+fn check_jq_status(status: ExitStatus) -> Result<()> {
+    if status.success() {
+        Ok(())
+    } else {
+        Err(JanusError::JqFilter(format!(
+            "jq filter failed with exit code {}",
+            status.code().unwrap_or(-1)
+        )))
+    }
+}
+
+/// Run tickets through a jq filter and collect the tickets it selects.
+fn filter_with_jq(
+    filter_expr: &str,
+    tickets: impl Iterator<Item = serde_json::Value>,
+) -> Result<Vec<serde_json::Value>> {
+    let mut input = Vec::new();
+    for json_val in tickets {
+        write_ticket_json(&mut input, &json_val)?;
+    }
+
+    let mut child = spawn_jq(filter_expr, Stdio::piped())?;
+
+    // Feed jq from a separate thread so a full stdout pipe can't deadlock us
+    let writer = child.stdin.take().map(|mut stdin| {
+        std::thread::spawn(move || {
+            // jq exiting early closes the pipe; its exit status reports why
+            let _ = stdin.write_all(&input);
+        })
+    });
+    let output = child.wait_with_output()?;
+    if let Some(writer) = writer {
+        let _ = writer.join();
+    }
+    check_jq_status(output.status)?;
+
+    output
+        .stdout
+        .split(|&b| b == b'\n')
+        .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+        .map(|line| {
+            serde_json::from_slice(line)
+                .map_err(|e| JanusError::JqFilter(format!("jq produced invalid JSON: {e}")))
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "llm"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_history() {
+        use crate::types::{TicketId, TicketStatus};
+
+        let ticket = |id: &str, status, closed: Option<&str>| TicketMetadata {
+            id: Some(TicketId::new_unchecked(id)),
+            status: Some(status),
+            closed_at: closed.map(|c| c.parse().unwrap()),
+            ..Default::default()
+        };
+        let tickets = vec![
+            ticket("j-open", TicketStatus::New, None),
+            ticket(
+                "j-done",
+                TicketStatus::Complete,
+                Some("2024-06-01T00:00:00Z"),
+            ),
+            ticket(
+                "j-old",
+                TicketStatus::Archived,
+                Some("2023-01-01T00:00:00Z"),
+            ),
+        ];
+        let ids = |selected: Vec<TicketMetadata>| -> Vec<String> {
+            selected
+                .into_iter()
+                .filter_map(|t| t.id.map(|id| id.to_string()))
+                .collect()
+        };
+
+        assert_eq!(
+            ids(select_history(tickets.clone(), false, None)),
+            ["j-open", "j-done"]
+        );
+        let since = "2022-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(
+            ids(select_history(tickets.clone(), true, Some(since))),
+            ["j-done", "j-old"]
+        );
+        let since = "2024-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(ids(select_history(tickets, true, Some(since))), ["j-done"]);
+    }
+
+    #[test]
+    fn test_clean_filter() {
+        assert_eq!(
+            clean_filter("```jq\nselect(.priority == 1 and .type == \"bug\")\n```").as_deref(),
+            Some(".priority == 1 and .type == \"bug\"")
+        );
+        assert_eq!(
+            clean_filter("`.assignee == \"sam\"`").as_deref(),
+            Some(".assignee == \"sam\"")
+        );
+        assert_eq!(clean_filter("```\n```"), None);
+    }
+}
@@ -0,0 +1,88 @@
+//! Ready queue ordered by the scoring policy (`janus ready`)
+
+use std::fmt::Write;
+
+use jiff::Timestamp;
+use serde_json::json;
+
+use super::{CommandOutput, FormatOptions, format_ticket_line, ticket_to_json};
+use crate::cli::OutputOptions;
+use crate::config::Config;
+use crate::display::dim;
+use crate::error::Result;
+use crate::plan::get_all_plans;
+use crate::query::{NotLockedFilter, ReadyFilter, SizeFilter, TicketQueryBuilder};
+use crate::ready::ReadyScorer;
+use crate::ticket::get_all_tickets_with_map;
+use crate::types::TicketSize;
+
+/// List ready tickets, highest score first
+///
+/// With `explain`, each ticket is followed by its score breakdown.
+pub async fn cmd_ready(
+    limit: Option<usize>,
+    sizes: Option<Vec<TicketSize>>,
+    explain: bool,
+    output: OutputOptions,
+) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let (tickets, ticket_map) = get_all_tickets_with_map().await?;
+    let plans = get_all_plans().await?.into_plans();
+    let now = Timestamp::now();
+
+    let mut builder = TicketQueryBuilder::new()
+        .with_filter(Box::new(ReadyFilter::new(config.ready.require, now)))
+        .with_filter(Box::new(NotLockedFilter::new(now)));
+    if let Some(sizes) = sizes {
+        builder = builder.with_filter(Box::new(SizeFilter::new(sizes)));
+    }
+    let ready = builder.execute(tickets).await?;
+
+    let scorer = ReadyScorer::new(config.ready, now, &plans, &ticket_map);
+    let mut ranked = scorer.rank(ready);
+    if let Some(limit) = limit {
+        ranked.truncate(limit);
+    }
+
+    if ranked.is_empty() {
+        return CommandOutput::new(json!([]))
+            .with_text("No tickets ready to work on.")
+            .print(output);
+    }
+
+    let mut json_tickets = Vec::with_capacity(ranked.len());
+    let mut text = String::new();
+    for (i, (ticket, score)) in ranked.iter().enumerate() {
+        let mut json = ticket_to_json(ticket);
+        json["score"] = json!(score.total());
+        if explain {
+            json["score_breakdown"] = json!(score);
+        }
+        json_tickets.push(json);
+
+        if i > 0 {
+            text.push('\n');
+        }
+        let opts = FormatOptions {
+            show_priority: true,
+            suffix: Some(format!(" {}", dim(&format!("({:.1})", score.total())))),
+        };
+        text.push_str(&format_ticket_line(ticket, opts));
+        if explain {
+            write!(
+                text,
+                "\n{}",
+                dim(&format!(
+                    "         priority {:.1} + age {:.1} + phase {:.1} + size {:.1}",
+                    score.priority, score.age, score.phase, score.size
+                ))
+            )
+            .unwrap();
+        }
+    }
+
+    CommandOutput::new(json!(json_tickets))
+        .with_text(text)
+        .paged()
+        .print(output)
+}
@@ -0,0 +1,311 @@
+//! `janus report` command.
+//!
+//! A one-shot summary of tracker health for CI pipelines: how many open
+//! tickets are ready or blocked, which P0 tickets have gone stale, and lint
+//! findings (ticket and plan files that fail to parse, and references to
+//! deleted tickets). With `--github-summary` the report is rendered as
+//! markdown suitable for appending to `$GITHUB_STEP_SUMMARY`.
+
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use jiff::Timestamp;
+use serde_json::{Value, json};
+
+use super::CommandOutput;
+use super::prune::find_orphaned_references;
+use crate::cli::OutputOptions;
+use crate::error::Result;
+use crate::plan::get_all_plans_from_disk;
+use crate::status::all_deps_satisfied;
+use crate::ticket::get_all_tickets_from_disk;
+use crate::types::{TicketMetadata, TicketPriority, TicketStatus};
+
+/// A P0 ticket whose file has not changed for `stale_days` or more
+struct StaleTicket {
+    id: String,
+    title: String,
+    idle_days: u64,
+}
+
+/// Something wrong with the tracker's files
+struct LintFinding {
+    kind: &'static str,
+    message: String,
+}
+
+struct Report {
+    open: usize,
+    in_progress: usize,
+    ready: usize,
+    blocked: usize,
+    stale_days: u32,
+    stale_p0: Vec<StaleTicket>,
+    lint: Vec<LintFinding>,
+}
+
+/// Print a tracker health report, as markdown with `github_summary`
+pub async fn cmd_report(
+    github_summary: bool,
+    stale_days: u32,
+    output: OutputOptions,
+) -> Result<()> {
+    let tickets = get_all_tickets_from_disk();
+    let plans = get_all_plans_from_disk();
+
+    let mut lint: Vec<LintFinding> = tickets
+        .failed
+        .iter()
+        .map(|(file, error)| LintFinding {
+            kind: "ticket_parse_error",
+            message: format!("items/{file}: {error}"),
+        })
+        .chain(plans.failed.iter().map(|(file, error)| LintFinding {
+            kind: "plan_parse_error",
+            message: format!("plans/{file}: {error}"),
+        }))
+        .collect();
+    let (ticket_refs, plan_refs) = find_orphaned_references(&tickets, &plans.items);
+    lint.extend(
+        ticket_refs
+            .iter()
+            .map(|r| r.describe())
+            .chain(plan_refs.iter().map(|r| r.describe()))
+            .map(|message| LintFinding {
+                kind: "orphaned_reference",
+                message,
+            }),
+    );
+
+    let report = build_report(&tickets.items, stale_days, lint, SystemTime::now());
+    let text = if github_summary {
+        render_markdown(&report)
+    } else {
+        render_text(&report)
+    };
+
+    CommandOutput::new(report_json(&report))
+        .with_text(text)
+        .print(output)
+}
+
+fn build_report(
+    tickets: &[TicketMetadata],
+    stale_days: u32,
+    lint: Vec<LintFinding>,
+    now: SystemTime,
+) -> Report {
+    let ticket_map: HashMap<String, TicketMetadata> = tickets
+        .iter()
+        .filter_map(|t| Some((t.id.as_ref()?.to_string(), t.clone())))
+        .collect();
+    let now_ts = Timestamp::try_from(now).unwrap_or_else(|_| Timestamp::now());
+    let stale_after = Duration::from_secs(u64::from(stale_days) * 86_400);
+
+    let mut report = Report {
+        open: 0,
+        in_progress: 0,
+        ready: 0,
+        blocked: 0,
+        stale_days,
+        stale_p0: Vec::new(),
+        lint,
+    };
+
+    for ticket in tickets {
+        let status = ticket.status.unwrap_or_default();
+        if status.is_terminal() {
+            continue;
+        }
+        report.open += 1;
+        if status == TicketStatus::InProgress {
+            report.in_progress += 1;
+        }
+        if ticket.is_snoozed_at(now_ts) {
+            continue;
+        }
+
+        if matches!(status, TicketStatus::New | TicketStatus::Next) {
+            if all_deps_satisfied(ticket, &ticket_map) {
+                report.ready += 1;
+            } else {
+                report.blocked += 1;
+            }
+        }
+
+        if ticket.priority.unwrap_or_default() == TicketPriority::P0
+            && let Some(idle) = idle_time(ticket, now)
+            && idle >= stale_after
+        {
+            report.stale_p0.push(StaleTicket {
+                id: ticket.id.as_deref().unwrap_or("").to_string(),
+                title: ticket.title.clone().unwrap_or_default(),
+                idle_days: idle.as_secs() / 86_400,
+            });
+        }
+    }
+
+    report
+        .stale_p0
+        .sort_by(|a, b| b.idle_days.cmp(&a.idle_days).then(a.id.cmp(&b.id)));
+    report
+}
+
+/// Time since the ticket file was last modified
+fn idle_time(ticket: &TicketMetadata, now: SystemTime) -> Option<Duration> {
+    let mtime = std::fs::metadata(ticket.file_path.as_deref()?)
+        .ok()?
+        .modified()
+        .ok()?;
+    now.duration_since(mtime).ok()
+}
+
+fn report_json(report: &Report) -> Value {
+    json!({
+        "open": report.open,
+        "in_progress": report.in_progress,
+        "ready": report.ready,
+        "blocked": report.blocked,
+        "stale_days": report.stale_days,
+        "stale_p0": report.stale_p0.iter().map(|t| json!({
+            "id": t.id,
+            "title": t.title,
+            "idle_days": t.idle_days,
+        })).collect::<Vec<_>>(),
+        "lint": report.lint.iter().map(|l| json!({
+            "kind": l.kind,
+            "message": l.message,
+        })).collect::<Vec<_>>(),
+    })
+}
+
+fn render_text(report: &Report) -> String {
+    let mut lines = vec![format!(
+        "Open: {}  In progress: {}  Ready: {}  Blocked: {}",
+        report.open, report.in_progress, report.ready, report.blocked
+    )];
+
+    if report.stale_p0.is_empty() {
+        lines.push(format!(
+            "No stale P0 tickets (unchanged for {}+ days).",
+            report.stale_days
+        ));
+    } else {
+        lines.push(format!(
+            "Stale P0 tickets (unchanged for {}+ days):",
+            report.stale_days
+        ));
+        for t in &report.stale_p0 {
+            lines.push(format!("  {} {} ({}d idle)", t.id, t.title, t.idle_days));
+        }
+    }
+
+    if report.lint.is_empty() {
+        lines.push("No lint findings.".to_string());
+    } else {
+        lines.push(format!("Lint findings ({}):", report.lint.len()));
+        for l in &report.lint {
+            lines.push(format!("  {}", l.message));
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn render_markdown(report: &Report) -> String {
+    let mut out = String::from("## Janus tracker health\n\n");
+    out.push_str("| Open | In progress | Ready | Blocked | Stale P0 | Lint findings |\n");
+    out.push_str("|---:|---:|---:|---:|---:|---:|\n");
+    out.push_str(&format!(
+        "| {} | {} | {} | {} | {} | {} |\n",
+        report.open,
+        report.in_progress,
+        report.ready,
+        report.blocked,
+        report.stale_p0.len(),
+        report.lint.len()
+    ));
+
+    out.push_str(&format!(
+        "\n### Stale P0 tickets\n\nP0 tickets unchanged for {} or more days.\n\n",
+        report.stale_days
+    ));
+    if report.stale_p0.is_empty() {
+        out.push_str("None :tada:\n");
+    } else {
+        for t in &report.stale_p0 {
+            out.push_str(&format!(
+                "- `{}` {} ({} days idle)\n",
+                t.id,
+                escape_markdown(&t.title),
+                t.idle_days
+            ));
+        }
+    }
+
+    out.push_str("\n### Lint findings\n\n");
+    if report.lint.is_empty() {
+        out.push_str("None :tada:\n");
+    } else {
+        for l in &report.lint {
+            out.push_str(&format!("- `{}`\n", l.message.replace('`', "'")));
+        }
+    }
+
+    out
+}
+
+/// Keep ticket titles from being read as markdown or HTML
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TicketId;
+
+    fn ticket(id: &str, status: TicketStatus, deps: &[&str]) -> TicketMetadata {
+        TicketMetadata {
+            id: Some(TicketId::new_unchecked(id)),
+            status: Some(status),
+            deps: deps.iter().map(|d| TicketId::new_unchecked(*d)).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_report_counts() {
+        let tickets = vec![
+            ticket("j-a", TicketStatus::New, &[]),
+            ticket("j-b", TicketStatus::Next, &["j-a"]),
+            ticket("j-c", TicketStatus::InProgress, &[]),
+            ticket("j-d", TicketStatus::Complete, &[]),
+            ticket("j-e", TicketStatus::New, &["j-d"]),
+        ];
+        let report = build_report(&tickets, 7, Vec::new(), SystemTime::now());
+        assert_eq!(report.open, 4);
+        assert_eq!(report.in_progress, 1);
+        assert_eq!(report.ready, 2);
+        assert_eq!(report.blocked, 1);
+        assert!(report.stale_p0.is_empty());
+
+        let markdown = render_markdown(&report);
+        assert!(markdown.contains("| 4 | 1 | 2 | 1 | 0 | 0 |"));
+    }
+
+    #[test]
+    fn test_escape_markdown() {
+        assert_eq!(escape_markdown("a_b | <c>"), "a\\_b \\| \\<c\\>");
+    }
+}
@@ -0,0 +1,125 @@
+//! Small constructors for JSON Schema fragments, so the output schemas in
+//! [`super::outputs`] read close to the `json!` calls they describe.
+
+use serde_json::{Map, Value, json};
+
+pub(super) fn string() -> Value {
+    json!({"type": "string"})
+}
+
+pub(super) fn integer() -> Value {
+    json!({"type": "integer"})
+}
+
+pub(super) fn number() -> Value {
+    json!({"type": "number"})
+}
+
+pub(super) fn boolean() -> Value {
+    json!({"type": "boolean"})
+}
+
+/// Any JSON value
+pub(super) fn any() -> Value {
+    json!({})
+}
+
+/// A string that is always `value`, such as an `action` tag
+pub(super) fn constant(value: &str) -> Value {
+    json!({"const": value})
+}
+
+/// A string that is one of `values`
+pub(super) fn string_enum(values: &[&str]) -> Value {
+    json!({"type": "string", "enum": values})
+}
+
+/// `schema`, or `null`
+pub(super) fn nullable(schema: Value) -> Value {
+    json!({"anyOf": [schema, {"type": "null"}]})
+}
+
+pub(super) fn array(items: Value) -> Value {
+    json!({"type": "array", "items": items})
+}
+
+/// An object whose keys are not fixed, with values matching `values`
+pub(super) fn map(values: Value) -> Value {
+    json!({"type": "object", "additionalProperties": values})
+}
+
+/// Any one of `schemas`
+pub(super) fn any_of(schemas: Vec<Value>) -> Value {
+    json!({"anyOf": schemas})
+}
+
+/// A reference to a shared definition in `$defs`
+pub(super) fn reference(name: &str) -> Value {
+    json!({"$ref": format!("#/$defs/{name}")})
+}
+
+/// An object that always has the `required` properties
+pub(super) fn object(required: Vec<(&str, Value)>) -> Value {
+    object_with(required, Vec::new())
+}
+
+/// An object that always has the `required` properties, and has the
+/// `optional` ones in some cases (for example only with `--dry-run`)
+///
+/// Objects stay open to other properties, so fields can be added to an output
+/// without breaking validators built from an older schema.
+pub(super) fn object_with(required: Vec<(&str, Value)>, optional: Vec<(&str, Value)>) -> Value {
+    let names: Vec<&str> = required.iter().map(|(name, _)| *name).collect();
+    let properties: Map<String, Value> = required
+        .into_iter()
+        .chain(optional)
+        .map(|(name, schema)| (name.to_string(), schema))
+        .collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": names,
+    })
+}
+
+/// `schema` extended with `extra` properties that are always present
+///
+/// `schema` must be an object schema built by [`object`] or [`object_with`],
+/// or a reference to one.
+pub(super) fn extend(schema: Value, extra: Vec<(&str, Value)>) -> Value {
+    extend_with(schema, extra, Vec::new())
+}
+
+/// `schema` extended with `required` and `optional` properties
+pub(super) fn extend_with(
+    schema: Value,
+    required: Vec<(&str, Value)>,
+    optional: Vec<(&str, Value)>,
+) -> Value {
+    json!({"allOf": [schema, object_with(required, optional)]})
+}
+
+/// `schema` with a description
+pub(super) fn describe(mut schema: Value, description: &str) -> Value {
+    schema["description"] = json!(description);
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_with_lists_only_required_properties() {
+        let schema = object_with(
+            vec![("id", string()), ("dry_run", boolean())],
+            vec![("candidates", array(string()))],
+        );
+        assert_eq!(schema["required"], json!(["id", "dry_run"]));
+        assert_eq!(
+            schema["properties"]["candidates"],
+            json!({"type": "array", "items": {"type": "string"}})
+        );
+        assert!(schema.get("additionalProperties").is_none());
+    }
+}
@@ -0,0 +1,199 @@
+//! `janus schema dump`: JSON Schemas for command output and MCP tool input.
+//!
+//! Command schemas describe what each command prints with `--json`, and share
+//! definitions such as `Ticket` through `$defs`. MCP tool schemas are the ones
+//! the server advertises in `tools/list`.
+
+mod builders;
+mod outputs;
+
+use std::path::Path;
+
+use serde_json::{Map, Value, json};
+
+use crate::error::{JanusError, Result};
+use crate::mcp::tools::JanusTools;
+use crate::mcp::types::SERVER_VERSION;
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Each MCP tool's name, description, and input schema
+fn mcp_tool_schemas() -> Vec<(String, Option<String>, Value)> {
+    JanusTools::new()
+        .router()
+        .list_all()
+        .into_iter()
+        .map(|tool| {
+            let mut input = Value::Object(tool.input_schema.as_ref().clone());
+            // Tool schemas carry their own `$defs`, so give each its own base
+            // URI for `#/$defs/...` references to resolve against
+            input["$id"] = json!(format!("mcp/{}.json", tool.name));
+            (
+                tool.name.to_string(),
+                tool.description.map(|d| d.to_string()),
+                input,
+            )
+        })
+        .collect()
+}
+
+/// All schemas as one document
+fn schema_bundle() -> Value {
+    let commands: Map<String, Value> = outputs::command_schemas()
+        .into_iter()
+        .map(|(path, schema)| (path.to_string(), schema))
+        .collect();
+    let mcp_tools: Map<String, Value> = mcp_tool_schemas()
+        .into_iter()
+        .map(|(name, description, input)| {
+            (name, json!({"description": description, "input": input}))
+        })
+        .collect();
+
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "title": "Janus JSON output and MCP tool schemas",
+        "version": SERVER_VERSION,
+        "$defs": outputs::definitions(),
+        "commands": commands,
+        "mcp_tools": mcp_tools,
+    })
+}
+
+/// A command's schema as a standalone document, with the shared definitions
+fn standalone_command_schema(path: &str, schema: Value, defs: &Map<String, Value>) -> Value {
+    let mut document = Map::new();
+    document.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+    document.insert("title".to_string(), json!(format!("janus {path} --json")));
+    if let Value::Object(properties) = schema {
+        document.extend(properties);
+    }
+    document.insert("$defs".to_string(), Value::Object(defs.clone()));
+    Value::Object(document)
+}
+
+fn write_schema(path: &Path, schema: &Value) -> Result<()> {
+    let content = serde_json::to_string_pretty(schema)?;
+    std::fs::write(path, content + "\n").map_err(|e| JanusError::StorageError {
+        operation: "write",
+        item_type: "schema",
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+fn create_dir(path: &Path) -> Result<()> {
+    std::fs::create_dir_all(path).map_err(|e| JanusError::StorageError {
+        operation: "create",
+        item_type: "directory",
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+/// Print all schemas as one JSON document, or write one file per schema
+/// under `dir`
+pub fn cmd_schema_dump(dir: Option<&Path>) -> Result<()> {
+    let Some(dir) = dir else {
+        println!("{}", serde_json::to_string_pretty(&schema_bundle())?);
+        return Ok(());
+    };
+
+    let commands_dir = dir.join("commands");
+    let mcp_dir = dir.join("mcp");
+    create_dir(&commands_dir)?;
+    create_dir(&mcp_dir)?;
+
+    let defs = outputs::definitions();
+    let mut written = 0;
+    for (path, schema) in outputs::command_schemas() {
+        let file = commands_dir.join(format!("{}.json", path.replace(' ', "-")));
+        write_schema(&file, &standalone_command_schema(path, schema, &defs))?;
+        written += 1;
+    }
+    for (name, description, mut input) in mcp_tool_schemas() {
+        if let Some(description) = description {
+            input["description"] = json!(description);
+        }
+        write_schema(&mcp_dir.join(format!("{name}.json")), &input)?;
+        written += 1;
+    }
+
+    println!("Wrote {written} schema(s) to {}", dir.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use clap::CommandFactory;
+
+    use super::*;
+    use crate::cli::Cli;
+
+    /// Paths of every command that takes `--json`, such as `plan status`
+    fn json_command_paths(command: &clap::Command, prefix: &str, paths: &mut Vec<String>) {
+        for sub in command.get_subcommands() {
+            let path = if prefix.is_empty() {
+                sub.get_name().to_string()
+            } else {
+                format!("{prefix} {}", sub.get_name())
+            };
+            if sub.get_arguments().any(|arg| arg.get_id() == "json") {
+                paths.push(path.clone());
+            }
+            json_command_paths(sub, &path, paths);
+        }
+    }
+
+    #[test]
+    fn test_every_json_command_has_a_schema() {
+        let mut paths = Vec::new();
+        json_command_paths(&Cli::command(), "", &mut paths);
+        assert!(paths.contains(&"plan status".to_string()));
+
+        let documented: HashSet<&str> = outputs::command_schemas()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        let missing: Vec<&String> = paths
+            .iter()
+            .filter(|path| !documented.contains(path.as_str()))
+            .collect();
+        assert!(missing.is_empty(), "commands without a schema: {missing:?}");
+    }
+
+    #[test]
+    fn test_command_paths_are_unique() {
+        let schemas = outputs::command_schemas();
+        let unique: HashSet<&str> = schemas.iter().map(|(path, _)| *path).collect();
+        assert_eq!(unique.len(), schemas.len());
+    }
+
+    #[test]
+    fn test_bundle_references_resolve() {
+        fn collect_refs<'a>(value: &'a Value, refs: &mut Vec<&'a str>) {
+            match value {
+                Value::Object(map) => {
+                    if let Some(Value::String(r)) = map.get("$ref") {
+                        refs.push(r);
+                    }
+                    map.values().for_each(|v| collect_refs(v, refs));
+                }
+                Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+                _ => {}
+            }
+        }
+
+        let bundle = schema_bundle();
+        let mut refs = Vec::new();
+        collect_refs(&bundle["commands"], &mut refs);
+        collect_refs(&bundle["$defs"], &mut refs);
+        for r in refs {
+            let name = r.strip_prefix("#/$defs/").unwrap();
+            assert!(bundle["$defs"].get(name).is_some(), "unresolved {r}");
+        }
+        assert!(bundle["mcp_tools"].get("create_ticket").is_some());
+    }
+}
@@ -0,0 +1,1721 @@
+//! Schemas of the `--json` output of each command.
+//!
+//! Each entry mirrors the `json!` value its command builds. Fields that a
+//! command only prints in some modes, such as `candidates` with `--dry-run`,
+//! are listed as optional; everything else is required, with `null` allowed
+//! where the command prints `null` for a missing value.
+
+use serde_json::{Map, Value, json};
+
+use super::builders::*;
+use crate::types::{TicketResolution, TicketSize, TicketStatus, TicketType};
+
+fn status() -> Value {
+    string_enum(TicketStatus::ALL_STRINGS)
+}
+
+fn priority() -> Value {
+    json!({"type": "integer", "minimum": 0, "maximum": 4})
+}
+
+fn ids() -> Value {
+    array(string())
+}
+
+fn ticket() -> Value {
+    reference("Ticket")
+}
+
+fn tickets() -> Value {
+    array(ticket())
+}
+
+fn errors() -> Value {
+    array(reference("ItemError"))
+}
+
+/// Output of the `edit` commands under `--json`, which skip the editor
+fn edit_output(id_key: &str) -> Value {
+    object(vec![
+        (id_key, string()),
+        ("file_path", string()),
+        ("action", constant("edit")),
+    ])
+}
+
+/// Output of the commands that validate files: `doctor`, `plan verify`
+fn health_check() -> Value {
+    object(vec![
+        ("valid", boolean()),
+        ("success_count", integer()),
+        ("failure_count", integer()),
+        ("failures", array(reference("FileError"))),
+    ])
+}
+
+fn ticket_definition() -> Value {
+    object(vec![
+        ("id", nullable(string())),
+        ("uuid", nullable(string())),
+        ("title", nullable(string())),
+        ("status", nullable(status())),
+        ("deps", ids()),
+        ("external_deps", array(string())),
+        ("links", ids()),
+        ("created", nullable(string())),
+        ("started_at", nullable(string())),
+        ("closed_at", nullable(string())),
+        (
+            "resolution",
+            nullable(string_enum(TicketResolution::ALL_STRINGS)),
+        ),
+        ("reopen_count", integer()),
+        (
+            "status_history",
+            array(object(vec![("status", status()), ("at", string())])),
+        ),
+        ("snoozed_until", nullable(string())),
+        (
+            "expires",
+            nullable(describe(string(), "Calendar date (YYYY-MM-DD)")),
+        ),
+        ("locked_by", nullable(string())),
+        ("locked_until", nullable(string())),
+        ("worktree", nullable(string())),
+        ("type", nullable(string_enum(TicketType::ALL_STRINGS))),
+        ("priority", nullable(priority())),
+        ("size", nullable(string_enum(TicketSize::ALL_STRINGS))),
+        ("external_ref", nullable(string())),
+        ("parent", nullable(string())),
+        ("file_path", nullable(string())),
+        ("remote", nullable(string())),
+        ("assignee", nullable(string())),
+        ("component", nullable(string())),
+        ("pull_requests", array(string())),
+        ("docs", array(string())),
+        ("attachments", array(reference("Attachment"))),
+        ("completion_summary", nullable(string())),
+        ("spawned_from", nullable(string())),
+        ("depth", nullable(integer())),
+    ])
+}
+
+/// Shared definitions, referenced from command schemas as `#/$defs/<name>`
+pub(super) fn definitions() -> Map<String, Value> {
+    let ticket_ref_with_exists = object(vec![
+        ("id", string()),
+        ("status", nullable(status())),
+        ("title", nullable(string())),
+        ("exists", boolean()),
+    ]);
+    let defs = vec![
+        (
+            "Ticket",
+            describe(
+                ticket_definition(),
+                "A ticket's frontmatter, as printed by `janus ls --json`",
+            ),
+        ),
+        (
+            "TicketRef",
+            object(vec![
+                ("id", nullable(string())),
+                ("title", nullable(string())),
+                ("status", nullable(status())),
+            ]),
+        ),
+        (
+            "TicketRefWithExists",
+            describe(
+                ticket_ref_with_exists,
+                "A referenced ticket, which may have been deleted",
+            ),
+        ),
+        (
+            "DepNode",
+            extend(
+                reference("TicketRefWithExists"),
+                vec![("deps", array(reference("DepNode")))],
+            ),
+        ),
+        (
+            "LineageNode",
+            extend(
+                reference("TicketRefWithExists"),
+                vec![("spawned", array(reference("LineageNode")))],
+            ),
+        ),
+        (
+            "ItemError",
+            object(vec![("id", string()), ("error", string())]),
+        ),
+        (
+            "FileError",
+            object(vec![("file", string()), ("error", string())]),
+        ),
+        (
+            "Attachment",
+            object(vec![
+                ("name", string()),
+                ("hash", string()),
+                ("size", integer()),
+            ]),
+        ),
+        (
+            "Note",
+            object(vec![("timestamp", nullable(string())), ("text", string())]),
+        ),
+        (
+            "PullRequest",
+            object(vec![
+                ("ref", string()),
+                ("url", string()),
+                ("title", string()),
+                ("state", string_enum(&["open", "merged", "closed"])),
+            ]),
+        ),
+        (
+            "SlaFinding",
+            object(vec![
+                ("ticket_id", string()),
+                ("rule", string()),
+                ("kind", string_enum(&["start", "close"])),
+                ("state", string_enum(&["at_risk", "breached"])),
+                ("window", string()),
+                ("deadline", string()),
+                ("remaining_secs", integer()),
+            ]),
+        ),
+        (
+            "Event",
+            object(vec![
+                ("timestamp", string()),
+                ("event_type", string()),
+                ("entity_type", string()),
+                ("entity_id", string()),
+                (
+                    "actor",
+                    string_enum(&["cli", "mcp", "hook", "auto_archive"]),
+                ),
+                ("data", any()),
+            ]),
+        ),
+        (
+            "PhaseStatus",
+            object(vec![
+                ("number", string()),
+                ("name", string()),
+                ("status", string()),
+                ("completed_count", integer()),
+                ("total_count", integer()),
+                ("completed_points", integer()),
+                ("total_points", integer()),
+            ]),
+        ),
+        (
+            "SyncResult",
+            object(vec![
+                ("id", string()),
+                ("remote_ref", string()),
+                ("already_in_sync", boolean()),
+                (
+                    "differences",
+                    array(object(vec![
+                        (
+                            "field",
+                            string_enum(&["title", "status", "labels", "assignee"]),
+                        ),
+                        ("local", any()),
+                        ("remote", any()),
+                    ])),
+                ),
+            ]),
+        ),
+    ];
+    defs.into_iter()
+        .map(|(name, schema)| (name.to_string(), schema))
+        .collect()
+}
+
+fn ticket_commands() -> Vec<(&'static str, Value)> {
+    let status_changed = object(vec![
+        ("id", string()),
+        ("action", constant("status_changed")),
+        ("new_status", status()),
+    ]);
+    let field_updated = object_with(
+        vec![
+            ("id", string()),
+            ("action", constant("field_updated")),
+            ("field", string()),
+            ("previous_value", nullable(string())),
+            ("new_value", string()),
+        ],
+        vec![("points", integer())],
+    );
+    let closed = extend(
+        status_changed.clone(),
+        vec![(
+            "resolution",
+            nullable(string_enum(TicketResolution::ALL_STRINGS)),
+        )],
+    );
+
+    vec![
+        (
+            "create",
+            object(vec![
+                ("id", string()),
+                ("title", string()),
+                ("status", constant("new")),
+                ("type", string_enum(TicketType::ALL_STRINGS)),
+                ("priority", priority()),
+                ("component", nullable(string())),
+                ("file_path", string()),
+            ]),
+        ),
+        (
+            "show",
+            any_of(vec![
+                extend_with(
+                    ticket(),
+                    vec![
+                        ("blockers", array(reference("TicketRef"))),
+                        ("blocking", array(reference("TicketRef"))),
+                        ("children", array(reference("TicketRef"))),
+                        ("linked", array(reference("TicketRef"))),
+                        ("children_count", integer()),
+                        ("sections", map(string())),
+                    ],
+                    vec![
+                        (
+                            "linked_docs",
+                            array(object(vec![
+                                ("label", string()),
+                                ("title", nullable(string())),
+                                ("exists", boolean()),
+                            ])),
+                        ),
+                        (
+                            "pull_request_status",
+                            array(object_with(
+                                vec![("ref", string()), ("state", nullable(string()))],
+                                vec![("url", string()), ("title", string())],
+                            )),
+                        ),
+                        (
+                            "activity",
+                            array(object_with(
+                                vec![
+                                    ("timestamp", nullable(string())),
+                                    (
+                                        "kind",
+                                        string_enum(&[
+                                            "created", "note", "status", "sync", "commit",
+                                        ]),
+                                    ),
+                                    ("summary", string()),
+                                ],
+                                vec![("actor", string())],
+                            )),
+                        ),
+                    ],
+                ),
+                describe(
+                    object(vec![
+                        ("id", string()),
+                        ("section", string()),
+                        ("content", nullable(string())),
+                    ]),
+                    "With --section",
+                ),
+            ]),
+        ),
+        (
+            "context",
+            object(vec![
+                (
+                    "ticket",
+                    object(vec![("metadata", ticket()), ("body", string())]),
+                ),
+                (
+                    "parents",
+                    array(extend(
+                        reference("TicketRef"),
+                        vec![("depth", integer()), ("description", nullable(string()))],
+                    )),
+                ),
+                (
+                    "deps",
+                    array(extend(
+                        reference("TicketRef"),
+                        vec![
+                            ("depth", integer()),
+                            ("completion_summary", nullable(string())),
+                        ],
+                    )),
+                ),
+                (
+                    "docs",
+                    array(object(vec![
+                        ("label", string()),
+                        ("title", nullable(string())),
+                        ("content", string()),
+                    ])),
+                ),
+                (
+                    "plans",
+                    array(object(vec![
+                        ("id", string()),
+                        ("title", nullable(string())),
+                        ("description", nullable(string())),
+                        ("phase", nullable(map(any()))),
+                        ("tickets", array(reference("TicketRef"))),
+                    ])),
+                ),
+                ("notes", array(reference("Note"))),
+            ]),
+        ),
+        (
+            "edit",
+            object_with(
+                vec![
+                    ("id", string()),
+                    (
+                        "action",
+                        string_enum(&["edit", "section_updated", "unchanged"]),
+                    ),
+                ],
+                vec![
+                    ("file_path", string()),
+                    ("section", string()),
+                    ("content", nullable(string())),
+                ],
+            ),
+        ),
+        (
+            "summarize",
+            object(vec![
+                ("id", string()),
+                ("summary", string()),
+                ("appended", boolean()),
+            ]),
+        ),
+        ("diff", {
+            let changes = array(object_with(
+                vec![("id", string()), ("title", string()), ("status", status())],
+                vec![("fields", array(string())), ("resolution", string())],
+            ));
+            object(vec![
+                ("from", string()),
+                ("to", string()),
+                ("created", changes.clone()),
+                ("closed", changes.clone()),
+                ("reopened", changes.clone()),
+                ("deleted", changes.clone()),
+                ("changed", changes),
+            ])
+        }),
+        (
+            "blame",
+            object(vec![
+                ("id", string()),
+                (
+                    "fields",
+                    array(object(vec![
+                        ("field", string()),
+                        ("value", string()),
+                        ("commit", nullable(string())),
+                        ("author", string()),
+                        ("date", string()),
+                        ("summary", string()),
+                    ])),
+                ),
+            ]),
+        ),
+        (
+            "lineage",
+            object(vec![
+                ("id", string()),
+                ("ancestors", array(reference("TicketRefWithExists"))),
+                ("tree", reference("LineageNode")),
+            ]),
+        ),
+        (
+            "path",
+            object(vec![("id", string()), ("file_path", string())]),
+        ),
+        (
+            "open",
+            any_of(vec![
+                object(vec![
+                    ("id", string()),
+                    ("file_path", string()),
+                    ("action", constant("open")),
+                ]),
+                object(vec![
+                    ("id", string()),
+                    ("remote_ref", string()),
+                    ("url", string()),
+                    ("action", constant("open_remote")),
+                ]),
+            ]),
+        ),
+        (
+            "add-note",
+            object(vec![
+                ("id", string()),
+                ("action", constant("note_added")),
+                ("timestamp", string()),
+                ("note", string()),
+            ]),
+        ),
+        (
+            "note ls",
+            object(vec![
+                ("id", string()),
+                (
+                    "notes",
+                    array(extend(reference("Note"), vec![("index", integer())])),
+                ),
+            ]),
+        ),
+        (
+            "note edit",
+            object(vec![
+                ("id", string()),
+                ("action", constant("note_edited")),
+                ("index", integer()),
+                ("note", string()),
+            ]),
+        ),
+        (
+            "note rm",
+            object(vec![
+                ("id", string()),
+                ("action", constant("note_removed")),
+                ("index", integer()),
+            ]),
+        ),
+        ("start", status_changed.clone()),
+        (
+            "snooze",
+            object(vec![
+                ("id", string()),
+                ("action", string_enum(&["snoozed", "unsnoozed"])),
+                ("snoozed_until", nullable(string())),
+            ]),
+        ),
+        (
+            "lock",
+            object(vec![
+                ("id", string()),
+                ("action", constant("locked")),
+                ("locked_by", string()),
+                ("locked_until", string()),
+            ]),
+        ),
+        (
+            "unlock",
+            object(vec![("id", string()), ("action", constant("unlocked"))]),
+        ),
+        (
+            "close",
+            describe(
+                any_of(vec![closed.clone(), array(closed)]),
+                "An object when closing one ticket, an array when closing several",
+            ),
+        ),
+        (
+            "reopen",
+            extend(
+                status_changed.clone(),
+                vec![("note", nullable(string())), ("reopen_count", integer())],
+            ),
+        ),
+        ("status", status_changed),
+        ("set", field_updated.clone()),
+        ("size", field_updated),
+        (
+            "dep add",
+            object_with(
+                vec![
+                    ("id", string()),
+                    ("action", string_enum(&["dep_added", "dep_already_exists"])),
+                    ("dep_id", string()),
+                ],
+                vec![
+                    ("current_deps", ids()),
+                    ("external", boolean()),
+                    ("current_external_deps", array(string())),
+                ],
+            ),
+        ),
+        (
+            "dep resolve",
+            object(vec![
+                ("id", string()),
+                ("action", constant("dep_resolved")),
+                ("dep_id", string()),
+                ("current_external_deps", array(string())),
+            ]),
+        ),
+        (
+            "dep remove",
+            object(vec![
+                ("id", string()),
+                ("action", constant("dep_removed")),
+                ("dep_id", string()),
+                ("current_deps", ids()),
+            ]),
+        ),
+        ("dep tree", object(vec![("root", reference("DepNode"))])),
+        (
+            "link add",
+            object_with(
+                vec![
+                    ("action", string_enum(&["linked", "already_linked"])),
+                    ("tickets", ids()),
+                    ("links_added", integer()),
+                    ("links_updated", map(ids())),
+                ],
+                vec![("warnings", array(string()))],
+            ),
+        ),
+        (
+            "link remove",
+            object_with(
+                vec![
+                    ("action", constant("unlinked")),
+                    ("tickets", ids()),
+                    ("links_updated", map(ids())),
+                ],
+                vec![("warnings", array(string()))],
+            ),
+        ),
+        (
+            "attach add",
+            object(vec![
+                ("id", string()),
+                ("action", constant("attachments_added")),
+                ("attachments", array(reference("Attachment"))),
+            ]),
+        ),
+        (
+            "attach ls",
+            object(vec![
+                ("id", string()),
+                ("attachments", array(reference("Attachment"))),
+            ]),
+        ),
+        (
+            "attach rm",
+            object(vec![
+                ("id", string()),
+                ("action", constant("attachment_removed")),
+                ("attachment", reference("Attachment")),
+            ]),
+        ),
+        (
+            "attach rename",
+            object(vec![
+                ("id", string()),
+                ("action", constant("attachment_renamed")),
+                ("from", string()),
+                ("to", string()),
+            ]),
+        ),
+        (
+            "attach get",
+            object(vec![
+                ("id", string()),
+                ("attachment", reference("Attachment")),
+                ("file", string()),
+            ]),
+        ),
+        (
+            "attach gc",
+            object(vec![
+                ("action", constant("attachments_gc")),
+                ("dry_run", boolean()),
+                ("removed", array(string())),
+                ("bytes_freed", integer()),
+            ]),
+        ),
+    ]
+}
+
+fn listing_commands() -> Vec<(&'static str, Value)> {
+    let ticket_with_sla = extend_with(
+        ticket(),
+        Vec::new(),
+        vec![
+            ("sla", array(reference("SlaFinding"))),
+            ("expired", constant_true()),
+        ],
+    );
+
+    vec![
+        ("ls", array(ticket_with_sla)),
+        (
+            "query",
+            describe(
+                extend_with(ticket(), Vec::new(), vec![("children_count", integer())]),
+                "One ticket per line; with --group-by or --count, one group per line",
+            ),
+        ),
+        (
+            "count",
+            object_with(
+                vec![("total", integer())],
+                vec![
+                    (
+                        "by",
+                        string_enum(&["status", "assignee", "type", "priority"]),
+                    ),
+                    (
+                        "groups",
+                        describe(
+                            array(object(vec![("count", integer())])),
+                            "Each group also has the counted field, keyed by its name",
+                        ),
+                    ),
+                ],
+            ),
+        ),
+        (
+            "sla",
+            array(extend(reference("SlaFinding"), vec![("title", string())])),
+        ),
+        (
+            "stats workload",
+            array(object(vec![
+                ("assignee", nullable(string())),
+                ("open", integer()),
+                ("in_progress", integer()),
+                ("points", integer()),
+                ("in_progress_points", integer()),
+                ("wip_limit", nullable(integer())),
+                ("size_budget", nullable(integer())),
+                ("overloaded", boolean()),
+                ("reasons", array(string())),
+            ])),
+        ),
+        (
+            "triage",
+            any_of(vec![
+                tickets(),
+                describe(
+                    array(object(vec![
+                        ("id", string()),
+                        ("title", nullable(string())),
+                        (
+                            "suggestion",
+                            object(vec![
+                                ("type", nullable(string_enum(TicketType::ALL_STRINGS))),
+                                ("priority", nullable(priority())),
+                                ("size", nullable(string_enum(TicketSize::ALL_STRINGS))),
+                                ("assignee", nullable(string())),
+                                ("reason", nullable(string())),
+                            ]),
+                        ),
+                        ("similar", ids()),
+                        ("applied", boolean()),
+                    ])),
+                    "With --suggest",
+                ),
+            ]),
+        ),
+        (
+            "archive",
+            object_with(
+                vec![
+                    ("archived", ids()),
+                    ("dry_run", boolean()),
+                    ("days", integer()),
+                ],
+                vec![
+                    ("candidates", ids()),
+                    ("errors", errors()),
+                    ("disabled", boolean()),
+                ],
+            ),
+        ),
+        (
+            "escalate",
+            object(vec![
+                (
+                    "escalations",
+                    array(object(vec![
+                        ("id", string()),
+                        ("title", nullable(string())),
+                        ("from", priority()),
+                        ("to", priority()),
+                        ("rule", string()),
+                    ])),
+                ),
+                ("applied", boolean()),
+                ("errors", errors()),
+            ]),
+        ),
+        (
+            "expire",
+            object_with(
+                vec![("expired", ids()), ("dry_run", boolean())],
+                vec![("candidates", ids()), ("errors", errors())],
+            ),
+        ),
+        (
+            "graph",
+            object(vec![
+                ("format", string_enum(&["dot", "mermaid"])),
+                (
+                    "nodes",
+                    array(object(vec![
+                        ("id", string()),
+                        ("title", nullable(string())),
+                        ("status", nullable(status())),
+                    ])),
+                ),
+                (
+                    "edges",
+                    array(object(vec![
+                        ("from", string()),
+                        ("to", string()),
+                        ("type", string_enum(&["blocks", "spawned"])),
+                    ])),
+                ),
+                ("graph", string()),
+            ]),
+        ),
+        (
+            "next",
+            array(object_with(
+                vec![
+                    ("id", string()),
+                    ("priority", priority()),
+                    ("status", string()),
+                    ("title", string()),
+                    ("reason", string()),
+                ],
+                vec![("blocks", string()), ("blocked_by", ids())],
+            )),
+        ),
+        (
+            "ready",
+            array(extend_with(
+                ticket(),
+                vec![("score", number())],
+                vec![(
+                    "score_breakdown",
+                    object(vec![
+                        ("priority", number()),
+                        ("age", number()),
+                        ("phase", number()),
+                        ("size", number()),
+                    ]),
+                )],
+            )),
+        ),
+        (
+            "blocked",
+            array(extend(
+                ticket(),
+                vec![
+                    ("blocked_by", array(reference("TicketRefWithExists"))),
+                    ("root_blockers", array(reference("TicketRefWithExists"))),
+                    ("external_blockers", array(string())),
+                ],
+            )),
+        ),
+        (
+            "suggest",
+            object(vec![
+                ("user", string()),
+                (
+                    "suggestions",
+                    array(object(vec![
+                        ("id", string()),
+                        ("title", string()),
+                        ("score", number()),
+                        ("reasons", array(string())),
+                    ])),
+                ),
+            ]),
+        ),
+        (
+            "search",
+            array(object_with(
+                vec![
+                    (
+                        "type",
+                        string_enum(&["ticket", "note", "objective", "plan", "phase", "doc"]),
+                    ),
+                    ("similarity", number()),
+                    (
+                        "snippet",
+                        nullable(object(vec![
+                            ("heading", nullable(string())),
+                            ("text", string()),
+                        ])),
+                    ),
+                ],
+                vec![
+                    ("ticket", map(any())),
+                    ("note", reference("Note")),
+                    ("objective", map(any())),
+                    ("plan", map(any())),
+                ],
+            )),
+        ),
+        (
+            "report",
+            object(vec![
+                ("open", integer()),
+                ("in_progress", integer()),
+                ("ready", integer()),
+                ("blocked", integer()),
+                ("stale_days", integer()),
+                (
+                    "stale_p0",
+                    array(object(vec![
+                        ("id", string()),
+                        ("title", string()),
+                        ("idle_days", integer()),
+                    ])),
+                ),
+                (
+                    "lint",
+                    array(object(vec![("kind", string()), ("message", string())])),
+                ),
+            ]),
+        ),
+    ]
+}
+
+/// `true`, for flags that are only printed when set
+fn constant_true() -> Value {
+    json!({"const": true})
+}
+
+fn integration_commands() -> Vec<(&'static str, Value)> {
+    vec![
+        (
+            "remote adopt",
+            object(vec![
+                ("id", string()),
+                ("action", constant("adopted")),
+                ("remote_ref", string()),
+                ("title", string()),
+                ("url", string()),
+                ("status", status()),
+            ]),
+        ),
+        (
+            "remote push",
+            object(vec![
+                ("id", string()),
+                ("action", constant("pushed")),
+                ("remote_ref", string()),
+            ]),
+        ),
+        (
+            "remote link",
+            object(vec![
+                ("id", string()),
+                ("action", constant("remote_linked")),
+                ("remote_ref", string()),
+            ]),
+        ),
+        (
+            "remote sync",
+            any_of(vec![
+                extend_with(
+                    reference("SyncResult"),
+                    Vec::new(),
+                    vec![
+                        ("dry_run", boolean()),
+                        ("pulled", array(string())),
+                        (
+                            "merged_pull_request",
+                            object(vec![
+                                ("pull_request", reference("PullRequest")),
+                                ("completed", boolean()),
+                            ]),
+                        ),
+                    ],
+                ),
+                describe(
+                    object(vec![
+                        ("dry_run", boolean()),
+                        (
+                            "pulled",
+                            array(object(vec![("id", string()), ("fields", array(string()))])),
+                        ),
+                        ("unchanged", ids()),
+                        ("skipped", ids()),
+                        ("cached", ids()),
+                        (
+                            "offline",
+                            array(object(vec![
+                                ("id", string()),
+                                ("cache_age_secs", integer()),
+                            ])),
+                        ),
+                        ("failed", errors()),
+                    ]),
+                    "With --all",
+                ),
+            ]),
+        ),
+        (
+            "worktree start",
+            object(vec![
+                ("id", string()),
+                ("action", constant("worktree_started")),
+                ("worktree", string()),
+                ("branch", string()),
+            ]),
+        ),
+        (
+            "worktree ls",
+            array(object(vec![
+                ("id", string()),
+                ("title", nullable(string())),
+                ("status", status()),
+                ("worktree", string()),
+                ("branch", nullable(string())),
+                ("exists", boolean()),
+            ])),
+        ),
+        (
+            "worktree cleanup",
+            object_with(
+                vec![("removed", ids()), ("dry_run", boolean())],
+                vec![
+                    (
+                        "candidates",
+                        array(object(vec![("id", string()), ("worktree", string())])),
+                    ),
+                    ("errors", errors()),
+                ],
+            ),
+        ),
+        (
+            "commit-msg",
+            object(vec![("id", string()), ("message", string())]),
+        ),
+        (
+            "pr-body",
+            object(vec![("id", string()), ("body", string())]),
+        ),
+        (
+            "pr link",
+            object(vec![
+                ("id", string()),
+                ("action", constant("pr_linked")),
+                ("added", array(string())),
+                ("pull_requests", array(reference("PullRequest"))),
+            ]),
+        ),
+        (
+            "ac suggest",
+            object(vec![
+                ("id", string()),
+                ("action", constant("acceptance_criteria_added")),
+                ("criteria", array(string())),
+            ]),
+        ),
+    ]
+}
+
+fn maintenance_commands() -> Vec<(&'static str, Value)> {
+    let hooks_toggled = object(vec![
+        ("action", string_enum(&["enabled", "disabled", "no_change"])),
+        ("hooks_enabled", boolean()),
+    ]);
+    let backup = object(vec![
+        ("file", string()),
+        ("created", string()),
+        ("files", integer()),
+        ("size_bytes", integer()),
+    ]);
+
+    vec![
+        (
+            "config show",
+            object(vec![
+                (
+                    "default_remote",
+                    nullable(object(vec![
+                        ("platform", string()),
+                        ("org", string()),
+                        ("repo", nullable(string())),
+                    ])),
+                ),
+                (
+                    "auth",
+                    object(vec![
+                        ("github_token_configured", boolean()),
+                        ("linear_api_key_configured", boolean()),
+                        ("encrypted", boolean()),
+                    ]),
+                ),
+                ("semantic_search", object(vec![("enabled", boolean())])),
+                ("remote_timeout", integer()),
+                ("git", object(vec![("auto_commit", boolean())])),
+                (
+                    "encryption",
+                    nullable(object(vec![
+                        ("tool", string()),
+                        ("recipients", array(string())),
+                    ])),
+                ),
+                ("config_file", string()),
+            ]),
+        ),
+        (
+            "config set",
+            object_with(
+                vec![
+                    ("action", constant("config_set")),
+                    ("key", string()),
+                    ("success", boolean()),
+                ],
+                vec![("value", any())],
+            ),
+        ),
+        (
+            "config get",
+            object_with(
+                vec![
+                    ("key", string()),
+                    ("value", any()),
+                    ("configured", boolean()),
+                ],
+                vec![("masked", boolean())],
+            ),
+        ),
+        (
+            "config encrypt",
+            object(vec![
+                ("action", constant("config_encrypt")),
+                ("tool", string()),
+                ("recipients", integer()),
+                ("encrypted_existing_secrets", boolean()),
+                ("success", boolean()),
+            ]),
+        ),
+        (
+            "cache status",
+            object(vec![
+                ("ticket_count", integer()),
+                ("status", string()),
+                (
+                    "embedding_coverage",
+                    object(vec![
+                        ("with_embedding", integer()),
+                        ("total", integer()),
+                        ("percentage", integer()),
+                    ]),
+                ),
+                ("embedding_model", string()),
+                ("embeddings_directory", string()),
+                ("embeddings_directory_size_bytes", integer()),
+            ]),
+        ),
+        (
+            "cache prune",
+            object(vec![
+                ("action", constant("cache_prune")),
+                ("pruned_count", integer()),
+                ("bytes_freed", integer()),
+                ("valid_keys_count", integer()),
+                ("success", boolean()),
+            ]),
+        ),
+        (
+            "cache rebuild",
+            object(vec![
+                ("action", constant("cache_rebuilt")),
+                ("ticket_count", integer()),
+                ("embedded_count", integer()),
+                ("total_time_ms", integer()),
+                ("success", boolean()),
+                ("embedding_model", string()),
+            ]),
+        ),
+        (
+            "cache sync",
+            object(vec![
+                ("action", constant("cache_synced")),
+                ("tickets_embedded", integer()),
+                ("objectives_embedded", integer()),
+                ("plans_embedded", integer()),
+                ("notes_embedded", integer()),
+                ("doc_count", integer()),
+                ("changed_docs", integer()),
+                ("removed_docs", integer()),
+                ("chunks_embedded", integer()),
+                ("chunks_reused", integer()),
+                ("total_time_ms", integer()),
+                ("embedding_model", string()),
+            ]),
+        ),
+        ("events", describe(reference("Event"), "One event per line")),
+        (
+            "events prune",
+            object(vec![
+                ("action", constant("events_prune")),
+                ("removed_count", integer()),
+                ("success", boolean()),
+            ]),
+        ),
+        (
+            "hook list",
+            object(vec![
+                ("enabled", boolean()),
+                ("timeout", integer()),
+                ("scripts", map(string())),
+            ]),
+        ),
+        (
+            "hook install",
+            object(vec![
+                ("action", constant("hook_install")),
+                ("recipe", string()),
+                ("success", boolean()),
+                ("installed_files", array(string())),
+                ("skipped_files", array(string())),
+                ("config_updated", boolean()),
+            ]),
+        ),
+        ("hook enable", hooks_toggled.clone()),
+        ("hook disable", hooks_toggled),
+        (
+            "hook log",
+            object_with(
+                vec![(
+                    "entries",
+                    array(object(vec![
+                        ("timestamp", string()),
+                        ("hook", string()),
+                        ("error", string()),
+                    ])),
+                )],
+                vec![("total", integer()), ("message", string())],
+            ),
+        ),
+        ("doctor", health_check()),
+        (
+            "fmt",
+            object(vec![
+                ("check", boolean()),
+                ("files", integer()),
+                ("changed", array(string())),
+                ("failed", array(reference("FileError"))),
+            ]),
+        ),
+        (
+            "prune",
+            object(vec![
+                (
+                    "tickets",
+                    array(object(vec![
+                        ("id", string()),
+                        ("field", string()),
+                        ("target", string()),
+                    ])),
+                ),
+                (
+                    "plans",
+                    array(object(vec![("plan_id", string()), ("target", string())])),
+                ),
+                ("dry_run", boolean()),
+                ("pruned", boolean()),
+            ]),
+        ),
+        ("backup", backup.clone()),
+        ("restore", backup),
+    ]
+}
+
+fn plan_commands() -> Vec<(&'static str, Value)> {
+    let plan_ticket = reference("TicketRefWithExists");
+    let phase_change = |action: &str, extra: Vec<(&'static str, Value)>| {
+        let mut properties = vec![
+            ("plan_id", string()),
+            ("action", constant(action)),
+            ("phase_number", string()),
+        ];
+        properties.extend(extra);
+        object(properties)
+    };
+
+    vec![
+        (
+            "plan create",
+            object(vec![
+                ("id", string()),
+                ("uuid", string()),
+                ("title", string()),
+                ("created", string()),
+                ("is_phased", boolean()),
+                ("phases", array(string())),
+            ]),
+        ),
+        (
+            "plan show",
+            object(vec![
+                ("id", nullable(string())),
+                ("uuid", nullable(string())),
+                ("title", nullable(string())),
+                ("created", nullable(string())),
+                ("description", nullable(string())),
+                ("status", string()),
+                ("completed_count", integer()),
+                ("total_count", integer()),
+                ("progress_percent", number()),
+                ("completed_points", integer()),
+                ("total_points", integer()),
+                ("acceptance_criteria", array(string())),
+                ("is_phased", boolean()),
+                (
+                    "phases",
+                    array(extend(
+                        reference("PhaseStatus"),
+                        vec![("tickets", array(plan_ticket.clone()))],
+                    )),
+                ),
+                ("tickets", array(plan_ticket)),
+            ]),
+        ),
+        ("plan edit", edit_output("id")),
+        (
+            "plan ls",
+            array(object(vec![
+                ("id", nullable(string())),
+                ("uuid", nullable(string())),
+                ("title", nullable(string())),
+                ("created", nullable(string())),
+                ("status", string()),
+                ("completed_count", integer()),
+                ("total_count", integer()),
+                ("progress_percent", number()),
+                ("is_phased", boolean()),
+            ])),
+        ),
+        (
+            "plan add-ticket",
+            object(vec![
+                ("plan_id", string()),
+                ("ticket_id", string()),
+                ("action", constant("ticket_added")),
+                ("phase", nullable(string())),
+                ("position", nullable(integer())),
+            ]),
+        ),
+        (
+            "plan remove-ticket",
+            object(vec![
+                ("plan_id", string()),
+                ("ticket_id", string()),
+                ("action", constant("ticket_removed")),
+                ("phase", nullable(string())),
+            ]),
+        ),
+        (
+            "plan move-ticket",
+            object(vec![
+                ("plan_id", string()),
+                ("ticket_id", string()),
+                ("action", constant("ticket_moved")),
+                ("from_phase", nullable(string())),
+                ("to_phase", string()),
+            ]),
+        ),
+        (
+            "plan add-phase",
+            phase_change("phase_added", vec![("phase_name", string())]),
+        ),
+        (
+            "plan remove-phase",
+            phase_change(
+                "phase_removed",
+                vec![("phase_name", string()), ("migrated_tickets", integer())],
+            ),
+        ),
+        (
+            "plan rename-phase",
+            phase_change(
+                "phase_renamed",
+                vec![("old_name", string()), ("new_name", string())],
+            ),
+        ),
+        (
+            "plan annotate-phase",
+            phase_change(
+                "phase_annotated",
+                vec![
+                    ("phase_name", string()),
+                    ("description", nullable(string())),
+                ],
+            ),
+        ),
+        (
+            "plan reorder",
+            object(vec![
+                ("plan_id", string()),
+                ("action", constant("reordered")),
+                ("type", string_enum(&["phases", "tickets"])),
+                ("phase", nullable(string())),
+            ]),
+        ),
+        (
+            "plan delete",
+            object(vec![
+                ("plan_id", string()),
+                ("action", constant("deleted")),
+                ("success", boolean()),
+            ]),
+        ),
+        (
+            "plan rename",
+            object(vec![
+                ("plan_id", string()),
+                ("action", constant("renamed")),
+                ("old_title", string()),
+                ("new_title", string()),
+            ]),
+        ),
+        (
+            "plan next",
+            object(vec![
+                ("plan_id", string()),
+                (
+                    "next_items",
+                    array(object(vec![
+                        ("phase_number", string()),
+                        ("phase_name", string()),
+                        (
+                            "tickets",
+                            array(object(vec![
+                                ("id", string()),
+                                ("title", nullable(string())),
+                                ("status", nullable(status())),
+                                ("priority", nullable(priority())),
+                                ("deps", ids()),
+                                ("exists", boolean()),
+                            ])),
+                        ),
+                    ])),
+                ),
+                (
+                    "externally_blocked",
+                    array(object(vec![
+                        ("id", string()),
+                        ("blocked_by", array(string())),
+                    ])),
+                ),
+            ]),
+        ),
+        (
+            "plan status",
+            object(vec![
+                ("plan_id", string()),
+                ("title", nullable(string())),
+                ("status", string()),
+                ("completed_count", integer()),
+                ("total_count", integer()),
+                ("progress_percent", number()),
+                ("completed_points", integer()),
+                ("total_points", integer()),
+                ("points_percent", number()),
+                ("phases", array(reference("PhaseStatus"))),
+            ]),
+        ),
+        ("plan diff", {
+            let phase_ref = object(vec![("number", string()), ("name", string())]);
+            let entry = object(vec![("id", string()), ("phase", nullable(string()))]);
+            let change = |key: &'static str| {
+                object(vec![(key, string()), ("from", string()), ("to", string())])
+            };
+            object(vec![
+                ("plan_id", string()),
+                ("ref", string()),
+                ("tickets_added", array(entry.clone())),
+                ("tickets_removed", array(entry)),
+                ("tickets_moved", array(change("id"))),
+                ("phases_added", array(phase_ref.clone())),
+                ("phases_removed", array(phase_ref)),
+                ("phases_renamed", array(change("number"))),
+                ("phases_reordered", boolean()),
+                ("tickets_reordered", array(string())),
+            ])
+        }),
+        (
+            "plan forecast",
+            object(vec![
+                ("plan_id", string()),
+                ("title", nullable(string())),
+                ("remaining_tickets", integer()),
+                ("remaining_points", integer()),
+                ("history_weeks", integer()),
+                ("weekly_throughput", array(integer())),
+                ("average_throughput", number()),
+                (
+                    "forecast",
+                    nullable(array(object(vec![
+                        ("confidence", integer()),
+                        ("weeks", integer()),
+                        ("date", string()),
+                    ]))),
+                ),
+            ]),
+        ),
+        (
+            "plan push",
+            object(vec![
+                ("plan_id", string()),
+                ("action", constant("plan_pushed")),
+                ("project", nullable(string())),
+                (
+                    "tickets",
+                    array(object(vec![
+                        ("id", string()),
+                        ("action", string_enum(&["created", "updated", "skipped"])),
+                        ("remote_ref", string()),
+                        ("cycle", nullable(string())),
+                    ])),
+                ),
+            ]),
+        ),
+        (
+            "plan import",
+            any_of(vec![
+                object(vec![
+                    ("id", string()),
+                    ("uuid", string()),
+                    ("title", string()),
+                    ("created", string()),
+                    ("is_phased", boolean()),
+                    ("tickets_created", array(object(vec![("id", string())]))),
+                    ("verification_ticket", nullable(string())),
+                ]),
+                describe(
+                    object(vec![
+                        ("dry_run", constant_true()),
+                        ("valid", boolean()),
+                        ("title", string()),
+                        ("description", nullable(string())),
+                        ("acceptance_criteria", array(string())),
+                        ("acceptance_criteria_count", integer()),
+                        ("is_phased", boolean()),
+                        ("phase_count", integer()),
+                        ("task_count", integer()),
+                        (
+                            "phases",
+                            array(object(vec![
+                                ("number", string()),
+                                ("name", string()),
+                                (
+                                    "tasks",
+                                    array(object(vec![
+                                        ("title", string()),
+                                        ("is_complete", boolean()),
+                                    ])),
+                                ),
+                            ])),
+                        ),
+                        ("would_create", map(any())),
+                    ]),
+                    "With --dry-run",
+                ),
+            ]),
+        ),
+        ("plan verify", health_check()),
+    ]
+}
+
+fn doc_and_objective_commands() -> Vec<(&'static str, Value)> {
+    let doc_link = |action: &str| {
+        object(vec![
+            ("label", string()),
+            ("action", constant(action)),
+            ("tickets", ids()),
+        ])
+    };
+    let objective_refs =
+        |key: &'static str, value: Value| object(vec![("id", string()), (key, value)]);
+
+    vec![
+        (
+            "doc ls",
+            array(object(vec![
+                ("label", nullable(string())),
+                ("title", nullable(string())),
+                ("description", nullable(string())),
+                ("tags", array(string())),
+                ("created", nullable(string())),
+                ("updated", nullable(string())),
+            ])),
+        ),
+        (
+            "doc show",
+            object(vec![
+                ("label", string()),
+                ("title", nullable(string())),
+                ("description", nullable(string())),
+                ("tags", array(string())),
+                ("tickets", array(reference("TicketRefWithExists"))),
+                ("created", nullable(string())),
+                ("updated", nullable(string())),
+                ("source", nullable(string())),
+                ("content", string()),
+                ("file_path", string()),
+            ]),
+        ),
+        (
+            "doc create",
+            object(vec![
+                ("label", string()),
+                ("title", nullable(string())),
+                ("file_path", string()),
+            ]),
+        ),
+        (
+            "doc add",
+            object(vec![
+                ("action", constant("doc_added")),
+                ("label", string()),
+                ("title", nullable(string())),
+                ("source", string()),
+                ("file_path", string()),
+                ("embedded_chunks", integer()),
+            ]),
+        ),
+        ("doc edit", edit_output("label")),
+        ("doc link", doc_link("doc_linked")),
+        ("doc unlink", doc_link("doc_unlinked")),
+        (
+            "doc search",
+            array(object(vec![
+                ("label", string()),
+                ("title", nullable(string())),
+                ("description", nullable(string())),
+                ("chunk", string()),
+                ("heading_path", array(string())),
+                ("content_snippet", string()),
+                ("line_range", array(integer())),
+                ("similarity", number()),
+                ("match", string()),
+            ])),
+        ),
+        (
+            "objective create",
+            object(vec![("id", string()), ("title", string())]),
+        ),
+        (
+            "objective show",
+            any_of(vec![
+                object(vec![
+                    ("id", nullable(string())),
+                    ("uuid", nullable(string())),
+                    ("created", nullable(string())),
+                    ("title", nullable(string())),
+                    ("status", string_enum(&["unrealized", "achieved"])),
+                    ("satisfied_by", array(string())),
+                    ("description", nullable(string())),
+                    ("acceptance_criteria", array(string())),
+                ]),
+                describe(
+                    object(vec![("id", string()), ("raw", string())]),
+                    "With --raw",
+                ),
+            ]),
+        ),
+        (
+            "objective ls",
+            array(object(vec![
+                ("id", nullable(string())),
+                ("title", nullable(string())),
+                ("status", string_enum(&["unrealized", "achieved"])),
+                ("satisfied_by", array(string())),
+            ])),
+        ),
+        ("objective edit", edit_output("id")),
+        (
+            "objective delete",
+            object(vec![
+                ("id", string()),
+                ("action", constant("deleted")),
+                ("success", boolean()),
+            ]),
+        ),
+        ("objective ref add", objective_refs("ref_added", string())),
+        ("objective ref del", objective_refs("ref_removed", string())),
+        (
+            "objective ref reset",
+            objective_refs("refs_cleared", integer()),
+        ),
+        (
+            "objective add-note",
+            object(vec![
+                ("id", string()),
+                ("action", constant("note_added")),
+                ("timestamp", string()),
+                ("note", string()),
+            ]),
+        ),
+        (
+            "objective add-criterion",
+            object(vec![
+                ("id", string()),
+                ("action", constant("criterion_added")),
+                ("criterion", string()),
+            ]),
+        ),
+    ]
+}
+
+/// The output schema of every command with JSON output, keyed by command
+/// path (`ls`, `plan status`)
+pub(super) fn command_schemas() -> Vec<(&'static str, Value)> {
+    let mut schemas = ticket_commands();
+    schemas.extend(listing_commands());
+    schemas.extend(integration_commands());
+    schemas.extend(maintenance_commands());
+    schemas.extend(plan_commands());
+    schemas.extend(doc_and_objective_commands());
+    schemas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::ticket_to_json;
+    use crate::types::TicketMetadata;
+
+    #[test]
+    fn test_ticket_definition_matches_ticket_json() {
+        let definition = ticket_definition();
+        let mut documented: Vec<&str> = definition["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+        documented.sort_unstable();
+
+        let json = ticket_to_json(&TicketMetadata::default());
+        let mut printed: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        printed.sort_unstable();
+
+        assert_eq!(documented, printed);
+    }
+}
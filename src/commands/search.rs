@@ -1,34 +1,31 @@
 //! Semantic search command implementation
 //!
-//! This command performs semantic search over tickets using vector embeddings
-//! to find tickets semantically similar to the query text.
+//! This command performs semantic search using vector embeddings to find
+//! tickets, individual ticket notes, objectives, plans, and plan phases
+//! semantically similar to the query text.
 
 use crate::cli::OutputOptions;
 use crate::commands::print_json;
 use crate::config::Config;
+use crate::display::{accent, dim, highlight_terms};
 use crate::error::{JanusError, Result};
+use crate::store::doc_search::{lexical_snippet, query_terms};
 use crate::store::get_or_init_store;
-use crate::store::search::SearchResult;
+use crate::store::search::UnifiedSearchResult;
+use crate::types::EntityType;
 use serde_json::json;
-use tabled::settings::Style;
-use tabled::{Table, Tabled};
-
-/// A row in the search results table
-#[derive(Tabled)]
-struct SearchResultRow {
-    #[tabled(rename = "ID")]
-    id: String,
-    #[tabled(rename = "Similarity")]
-    similarity: String,
-    #[tabled(rename = "Title")]
-    title: String,
-    #[tabled(rename = "Status")]
-    status: String,
+
+/// The part of a result that best matches the query
+struct Snippet {
+    /// Heading of the section the excerpt came from
+    heading: Option<String>,
+    /// Single-line excerpt around the first matched term
+    text: String,
 }
 
 /// Execute the search command
 ///
-/// Performs semantic search over all tickets with embeddings and displays
+/// Performs semantic search over everything with an embedding and displays
 /// results ordered by similarity score.
 pub async fn cmd_search(
     query: &str,
@@ -79,64 +76,44 @@ pub async fn cmd_search(
     let query_embedding = crate::embedding::model::generate_embedding(query)
         .await
         .map_err(JanusError::EmbeddingModel)?;
-    let results = store.semantic_search(&query_embedding, limit);
+    let results = store.unified_semantic_search(&query_embedding, limit);
 
     // Filter by threshold if specified
-    let results: Vec<SearchResult> = if let Some(t) = threshold {
+    let results: Vec<UnifiedSearchResult> = if let Some(t) = threshold {
         results.into_iter().filter(|r| r.similarity >= t).collect()
     } else {
         results
     };
 
+    let terms = query_terms(query);
+
     // Output results
     if output.json {
-        // Output as JSON
-        let json_results: Vec<serde_json::Value> = results
-            .iter()
-            .map(|r| {
-                json!({
-                    "ticket": {
-                        "id": r.ticket.id.as_ref(),
-                        "title": r.ticket.title.as_ref(),
-                        "status": r.ticket.status.map(|s| s.to_string()),
-                        "type": r.ticket.ticket_type.map(|t| t.to_string()),
-                        "priority": r.ticket.priority.map(|p| p.to_string()),
-                        "size": r.ticket.size.map(|s| s.to_string()),
-                    },
-                    "similarity": r.similarity,
-                })
-            })
-            .collect();
+        let json_results: Vec<serde_json::Value> =
+            results.iter().map(|r| result_json(r, &terms)).collect();
         print_json(&json!(json_results))?;
     } else {
-        // Output as formatted table
-        println!("Search results for: \"{query}\"\n");
+        println!("Search results for: \"{query}\"");
 
         if results.is_empty() {
-            println!("No matching tickets found.");
-        } else {
-            let rows: Vec<SearchResultRow> = results
-                .iter()
-                .map(|r| SearchResultRow {
-                    id: r.ticket.id.as_deref().unwrap_or("unknown").to_string(),
-                    similarity: format!("{:.2}", r.similarity),
-                    title: r
-                        .ticket
-                        .title
-                        .as_deref()
-                        .unwrap_or("(no title)")
-                        .to_string(),
-                    status: r
-                        .ticket
-                        .status
-                        .map(|s| s.to_string())
-                        .unwrap_or_else(|| "unknown".to_string()),
-                })
-                .collect();
-
-            let mut table = Table::new(rows);
-            table.with(Style::rounded());
-            println!("{table}");
+            println!("\nNo matches found.");
+        }
+        for r in &results {
+            let (id, title, status) = result_summary(r);
+            println!(
+                "\n{} {} {}  {title}{}",
+                accent(&id),
+                dim(result_kind(r)),
+                dim(&format!("[{:.2}]", r.similarity)),
+                status.map(|s| format!(" [{s}]")).unwrap_or_default()
+            );
+            if let Some(snippet) = result_snippet(r, &terms) {
+                let heading = snippet
+                    .heading
+                    .map(|h| dim(&format!("{h} > ")))
+                    .unwrap_or_default();
+                println!("    {heading}{}", highlight_terms(&snippet.text, &terms));
+            }
         }
 
         println!("\n{} result(s)", results.len());
@@ -145,10 +122,217 @@ pub async fn cmd_search(
     Ok(())
 }
 
+/// The JSON for one result. Ticket results keep the `ticket` object; note
+/// results add a `note` object next to their ticket.
+fn result_json(r: &UnifiedSearchResult, terms: &[String]) -> serde_json::Value {
+    let mut value = json!({
+        "type": result_kind(r),
+        "similarity": r.similarity,
+        "snippet": result_snippet(r, terms).map(|snippet| json!({
+            "heading": snippet.heading,
+            "text": snippet.text,
+        })),
+    });
+    if let Some(ticket) = &r.ticket {
+        value["ticket"] = json!({
+            "id": ticket.id.as_ref(),
+            "title": ticket.title.as_ref(),
+            "status": ticket.status.map(|s| s.to_string()),
+            "type": ticket.ticket_type.map(|t| t.to_string()),
+            "priority": ticket.priority.map(|p| p.to_string()),
+            "size": ticket.size.map(|s| s.to_string()),
+        });
+    }
+    if let Some(note) = &r.note {
+        value["note"] = json!({
+            "timestamp": note.timestamp,
+            "text": note.text,
+        });
+    }
+    if let Some(objective) = &r.objective {
+        value["objective"] = json!({
+            "id": objective.id.as_ref(),
+            "title": objective.title.as_ref(),
+        });
+    }
+    if let Some(plan) = &r.plan {
+        value["plan"] = json!({
+            "id": plan.id.as_ref(),
+            "title": plan.title.as_ref(),
+            "phase": r.phase.as_ref().map(|number| json!({
+                "number": number,
+                "name": phase_name(r, number),
+            })),
+        });
+    }
+    value
+}
+
+/// The ID, title, and status (tickets only) shown for a result
+fn result_summary(r: &UnifiedSearchResult) -> (String, String, Option<String>) {
+    match (&r.ticket, &r.objective, &r.plan) {
+        (Some(ticket), _, _) => (
+            ticket.id.as_deref().unwrap_or("unknown").to_string(),
+            ticket.title.as_deref().unwrap_or("(no title)").to_string(),
+            Some(
+                ticket
+                    .status
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| "unknown".to_string()),
+            ),
+        ),
+        (_, Some(objective), _) => (
+            objective.id.as_deref().unwrap_or("unknown").to_string(),
+            objective
+                .title
+                .as_deref()
+                .unwrap_or("(no title)")
+                .to_string(),
+            None,
+        ),
+        (_, _, Some(plan)) => (
+            plan.id.as_deref().unwrap_or("unknown").to_string(),
+            plan.title.as_deref().unwrap_or("(no title)").to_string(),
+            None,
+        ),
+        _ => ("unknown".to_string(), String::new(), None),
+    }
+}
+
+/// The excerpt of a result to show under it: the matched note or phase, or
+/// the section of the ticket, objective, or plan that contains the most
+/// query terms
+fn result_snippet(r: &UnifiedSearchResult, terms: &[String]) -> Option<Snippet> {
+    let (heading, content) = if let Some(note) = &r.note {
+        (
+            Some(note_heading(note.timestamp.as_deref())),
+            note.text.clone(),
+        )
+    } else if let Some(ticket) = &r.ticket {
+        best_section(ticket.body.as_deref()?, terms)?
+    } else if let Some(objective) = &r.objective {
+        (None, objective.description.clone()?)
+    } else {
+        let plan = r.plan.as_ref()?;
+        match &r.phase {
+            Some(number) => (
+                Some(format!("Phase {number}: {}", phase_name(r, number))),
+                plan.phases()
+                    .into_iter()
+                    .find(|p| &p.number == number)?
+                    .description
+                    .clone()
+                    .unwrap_or_default(),
+            ),
+            None => (None, plan.description.clone()?),
+        }
+    };
+
+    let text = lexical_snippet(&content, terms);
+    if text.is_empty() && heading.is_none() {
+        return None;
+    }
+    Some(Snippet { heading, text })
+}
+
+fn note_heading(timestamp: Option<&str>) -> String {
+    match timestamp {
+        Some(timestamp) => format!("Notes ({timestamp})"),
+        None => "Notes".to_string(),
+    }
+}
+
+/// The section of a markdown body containing the most query terms, as
+/// `(heading, content)`, or the first non-empty section if none contains
+/// any. The title (`#` heading) is skipped, and fenced code is kept as
+/// content rather than read as headings.
+fn best_section(body: &str, terms: &[String]) -> Option<(Option<String>, String)> {
+    let mut sections: Vec<(Option<String>, String)> = vec![(None, String::new())];
+    let mut in_fence = false;
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if !in_fence && level > 0 && trimmed[level..].starts_with(' ') {
+            let heading = (level > 1).then(|| trimmed[level..].trim().to_string());
+            sections.push((heading, String::new()));
+            continue;
+        }
+        if let Some((_, content)) = sections.last_mut() {
+            content.push_str(line);
+            content.push('\n');
+        }
+    }
+
+    let score = |(heading, content): &(Option<String>, String)| {
+        let haystack =
+            format!("{} {content}", heading.as_deref().unwrap_or_default()).to_lowercase();
+        terms
+            .iter()
+            .filter(|t| haystack.contains(t.as_str()))
+            .count()
+    };
+    let sections: Vec<_> = sections
+        .into_iter()
+        .filter(|(_, content)| !content.trim().is_empty())
+        .collect();
+    // `max_by_key` keeps the last maximum; reverse so ties go to the first
+    sections.into_iter().rev().max_by_key(score)
+}
+
+/// `ticket`, `note`, `objective`, `plan`, or `phase`
+fn result_kind(r: &UnifiedSearchResult) -> &'static str {
+    match r.entity_type {
+        EntityType::Ticket if r.note.is_some() => "note",
+        EntityType::Plan if r.phase.is_some() => "phase",
+        EntityType::Ticket => "ticket",
+        EntityType::Objective => "objective",
+        EntityType::Plan => "plan",
+        EntityType::Doc => "doc",
+        EntityType::Cache => "cache",
+    }
+}
+
+fn phase_name<'a>(r: &'a UnifiedSearchResult, number: &str) -> &'a str {
+    r.plan
+        .as_ref()
+        .and_then(|plan| {
+            plan.phases()
+                .into_iter()
+                .find(|p| p.number == number)
+                .map(|p| p.name.as_str())
+        })
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_best_section() {
+        let body = "# Title\n\nIntro text.\n\n## Design\n\nShard by tenant.\n\n```\n# not a heading\n```\n\n## Notes\n\nSharding rollout plan.\n";
+        let terms = vec!["rollout".to_string()];
+        assert_eq!(
+            best_section(body, &terms),
+            Some((
+                Some("Notes".to_string()),
+                "\nSharding rollout plan.\n".to_string()
+            ))
+        );
+
+        let (heading, content) = best_section(body, &["shard".to_string()]).unwrap();
+        assert_eq!(heading.as_deref(), Some("Design"));
+        assert!(content.contains("# not a heading"));
+
+        // Nothing matches: the first section
+        let (heading, content) = best_section(body, &["kubernetes".to_string()]).unwrap();
+        assert_eq!(heading, None);
+        assert_eq!(content.trim(), "Intro text.");
+    }
+
     #[tokio::test]
     async fn test_empty_query_error() {
         let result = cmd_search("", 10, None, OutputOptions { json: false }).await;
@@ -1,11 +1,15 @@
 use serde_json::json;
 
 use super::CommandOutput;
+use super::status::confirm_transition;
 use crate::cli::OutputOptions;
+use crate::config::TransitionVia;
 use crate::error::{JanusError, Result};
+use crate::plan::forecast::size_points;
 use crate::ticket::Ticket;
 
-use crate::types::{TicketPriority, TicketSize, TicketStatus, TicketType};
+use crate::types::{TicketDate, TicketPriority, TicketSize, TicketStatus, TicketType};
+use crate::utils::parse_until;
 
 /// Supported fields for the set command
 const SUPPORTED_FIELDS: &[&str] = &[
@@ -19,6 +23,9 @@ const SUPPORTED_FIELDS: &[&str] = &[
     "acceptance",
     "description",
     "labels",
+    "assignee",
+    "component",
+    "expires",
 ];
 
 macro_rules! define_validator {
@@ -75,6 +82,18 @@ async fn validate_parent(value: &str, ticket: &Ticket) -> Result<String> {
     Ok(parent_ticket.id)
 }
 
+/// Parse an expiry given as a date (`2025-08-01`) or a duration ahead from
+/// today (`2w`), as a calendar date in the local time zone
+fn parse_expiry(value: &str) -> Result<TicketDate> {
+    if let Ok(date) = value.parse::<TicketDate>() {
+        return Ok(date);
+    }
+    let until = parse_until(value)?;
+    Ok(TicketDate::new(
+        until.to_zoned(jiff::tz::TimeZone::system()).date(),
+    ))
+}
+
 /// Format a field change for display
 fn format_field_change(prev: Option<&str>, new: &str) -> (String, String) {
     let prev_display = prev.unwrap_or("(none)").to_string();
@@ -91,6 +110,7 @@ pub async fn cmd_set(
     id: &str,
     field: &str,
     value: Option<&str>,
+    yes: bool,
     output: OutputOptions,
 ) -> Result<()> {
     let ticket = Ticket::find(id).await?;
@@ -160,6 +180,20 @@ pub async fn cmd_set(
                     .collect(),
             })?;
             let new_status = validate_status(value)?;
+            let old_status = metadata.status.unwrap_or_default();
+            if !confirm_transition(
+                &ticket.id,
+                old_status,
+                new_status,
+                TransitionVia::Set,
+                yes,
+                output,
+            )
+            .await?
+            {
+                println!("Cancelled");
+                return Ok(());
+            }
             new_value = value.to_string();
             ticket.update_status(new_status, None)?;
         }
@@ -173,6 +207,36 @@ pub async fn cmd_set(
                 new_value = String::new();
             }
         }
+        "assignee" => {
+            previous_value = metadata.assignee.clone();
+            if let Some(value) = value {
+                new_value = value.to_string();
+                ticket.update_field("assignee", value)?;
+            } else {
+                ticket.remove_field("assignee")?;
+                new_value = String::new();
+            }
+        }
+        "component" => {
+            previous_value = metadata.component.clone();
+            if let Some(value) = value {
+                new_value = value.to_string();
+                ticket.update_field("component", value)?;
+            } else {
+                ticket.remove_field("component")?;
+                new_value = String::new();
+            }
+        }
+        "expires" => {
+            previous_value = metadata.expires.map(|d| d.to_string());
+            if let Some(value) = value {
+                new_value = parse_expiry(value)?.to_string();
+                ticket.update_field("expires", &new_value)?;
+            } else {
+                ticket.remove_field("expires")?;
+                new_value = String::new();
+            }
+        }
         "size" => {
             previous_value = metadata.size.map(|s| s.to_string());
             if let Some(value) = value {
@@ -259,3 +323,29 @@ pub async fn cmd_set(
     ))
     .print(output)
 }
+
+/// Set a ticket's size (`janus size`), a shortcut for `janus set <id> size`
+pub async fn cmd_size(id: &str, size: TicketSize, output: OutputOptions) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+    let metadata = ticket.read()?;
+    let previous = metadata.size;
+    if previous != Some(size) {
+        ticket.update_field("size", &size.to_string())?;
+    }
+
+    let points = size_points(Some(size));
+    let previous_display = previous.map_or("(none)".to_string(), |s| s.to_string());
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "field_updated",
+        "field": "size",
+        "previous_value": previous.map(|s| s.to_string()),
+        "new_value": size.to_string(),
+        "points": points,
+    }))
+    .with_text(format!(
+        "Updated {} size: {} -> {} ({} points)",
+        ticket.id, previous_display, size, points
+    ))
+    .print(output)
+}
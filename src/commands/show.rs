@@ -1,17 +1,50 @@
-use owo_colors::OwoColorize;
 use serde_json::json;
 
 use super::CommandOutput;
+use super::pr::{fetch_linked_pull_requests, format_pull_request, pull_request_json};
+use crate::attachment::format_size;
 use crate::cli::OutputOptions;
-use crate::display::TicketFormatter;
+use crate::config::Config;
+use crate::display::{
+    TicketFormatter, accent, dim, format_status_colored, heading, render_markdown,
+};
+use crate::doc::related_docs;
 use crate::error::Result;
+use crate::parser::split_frontmatter;
 use crate::status::is_dependency_satisfied;
-use crate::ticket::{Ticket, build_ticket_map, get_children_count};
-use crate::types::{TicketMetadata, TicketStatus};
+use crate::store::get_or_init_store;
+use crate::ticket::{Ticket, build_ticket_map, get_children_count, ticket_activity};
+use crate::types::{TicketMetadata, TicketSection, TicketStatus};
 
-/// Display a ticket with its relationships
-pub async fn cmd_show(id: &str, output: OutputOptions) -> Result<()> {
+/// Display a ticket with its relationships, or just one of its body sections.
+///
+/// With `render`, the markdown is styled for the terminal instead of printed raw.
+/// With `activity`, a chronological feed of the ticket's history is appended.
+pub async fn cmd_show(
+    id: &str,
+    section: Option<TicketSection>,
+    render: bool,
+    activity: bool,
+    output: OutputOptions,
+) -> Result<()> {
     let (ticket, metadata) = Ticket::find_and_read(id).await?;
+
+    if let Some(section) = section {
+        let content = metadata.sections.get(section);
+        return CommandOutput::new(json!({
+            "id": ticket.id,
+            "section": section.to_string(),
+            "content": content,
+        }))
+        .with_text(if render {
+            render_markdown(content.unwrap_or_default())
+        } else {
+            content.unwrap_or_default().to_string()
+        })
+        .paged()
+        .print(output);
+    }
+
     let content = ticket.read_content()?;
     let ticket_map = build_ticket_map().await?;
 
@@ -50,6 +83,36 @@ pub async fn cmd_show(id: &str, output: OutputOptions) -> Result<()> {
     // Get count of tickets spawned from this ticket
     let spawned_count = get_children_count(&ticket.id).await?;
 
+    // Pull request state is fetched live; it is unavailable without a token
+    let pull_requests = if metadata.pull_requests.is_empty() {
+        Vec::new()
+    } else {
+        let config = Config::load()?;
+        fetch_linked_pull_requests(&config, &metadata.pull_requests).await
+    };
+
+    // Docs linked from the ticket or listing it in their `tickets` frontmatter
+    let store = get_or_init_store().await?;
+    let docs: Vec<(String, Option<String>)> = {
+        let all_docs: Vec<_> = store.docs().iter().map(|e| e.value().clone()).collect();
+        related_docs(&ticket.id, &metadata.docs, &all_docs)
+            .into_iter()
+            .map(|label| {
+                let title = store
+                    .docs()
+                    .get(&label)
+                    .map(|doc| doc.title().unwrap_or_default().to_string());
+                (label, title)
+            })
+            .collect()
+    };
+
+    let activity = if activity {
+        Some(ticket_activity(&ticket.id, &metadata, &ticket.notes()?)?)
+    } else {
+        None
+    };
+
     // Build JSON data (needed for both output formats)
     let blockers_json: Vec<_> = blockers
         .iter()
@@ -84,25 +147,58 @@ pub async fn cmd_show(id: &str, output: OutputOptions) -> Result<()> {
         obj.insert("children".to_string(), json!(children_json));
         obj.insert("linked".to_string(), json!(linked_json));
         obj.insert("children_count".to_string(), json!(spawned_count));
+        obj.insert("sections".to_string(), json!(metadata.sections));
+        if !docs.is_empty() {
+            let docs_json: Vec<_> = docs
+                .iter()
+                .map(|(label, title)| {
+                    json!({ "label": label, "title": title, "exists": title.is_some() })
+                })
+                .collect();
+            obj.insert("linked_docs".to_string(), json!(docs_json));
+        }
+        if !pull_requests.is_empty() {
+            let status_json: Vec<_> = pull_requests
+                .iter()
+                .map(|(reference, pull)| match pull {
+                    Some(pull) => pull_request_json(pull),
+                    None => json!({ "ref": reference, "state": null }),
+                })
+                .collect();
+            obj.insert("pull_request_status".to_string(), json!(status_json));
+        }
+        if let Some(activity) = &activity {
+            obj.insert("activity".to_string(), json!(activity));
+        }
     }
 
     // Build text output
     let text_output = {
-        let mut output = content;
+        let mut output = if render {
+            render_ticket(&metadata, &content)?
+        } else {
+            content
+        };
 
         // Print completion summary if ticket is complete and has one
         if metadata.status == Some(TicketStatus::Complete)
             && let Some(ref summary) = metadata.completion_summary
         {
             output.push('\n');
-            output.push_str(&format!("{}", "Completion Summary:".green().bold()));
+            output.push_str(&heading("Completion Summary:"));
             for line in summary.lines() {
-                output.push_str(&format!("\n  {}", line.dimmed()));
+                output.push_str(&format!("\n  {}", dim(line)));
             }
         }
 
         // Print sections
         output.push_str(&TicketFormatter::format_section("Blockers", &blockers));
+        if !metadata.external_deps.is_empty() {
+            output.push_str("\n\n## External Blockers");
+            for url in &metadata.external_deps {
+                output.push_str(&format!("\n- {url}"));
+            }
+        }
         output.push_str(&TicketFormatter::format_section("Blocking", &blocking));
         output.push_str(&TicketFormatter::format_section("Children", &children));
 
@@ -119,11 +215,55 @@ pub async fn cmd_show(id: &str, output: OutputOptions) -> Result<()> {
             }
         }
 
+        if !pull_requests.is_empty() {
+            output.push_str("\n\n## Pull Requests");
+            for (reference, pull) in &pull_requests {
+                let line = match pull {
+                    Some(pull) => format_pull_request(pull),
+                    None => format!("{reference} {}", dim("(status unavailable)")),
+                };
+                output.push_str(&format!("\n- {line}"));
+            }
+        }
+
+        if !docs.is_empty() {
+            output.push_str("\n\n## Docs");
+            for (label, title) in &docs {
+                let line = match title {
+                    Some(title) => format!("{} {title}", accent(label)),
+                    None => format!("{} {}", accent(label), dim("(not found)")),
+                };
+                output.push_str(&format!("\n- {line}"));
+            }
+        }
+
+        if !metadata.attachments.is_empty() {
+            output.push_str("\n\n## Attachments");
+            for attachment in &metadata.attachments {
+                output.push_str(&format!(
+                    "\n- {} {}",
+                    attachment.name,
+                    dim(&format!("({})", format_size(attachment.size)))
+                ));
+            }
+        }
+
+        if let Some(activity) = &activity {
+            output.push_str("\n\n## Activity");
+            for item in activity {
+                output.push_str(&format!(
+                    "\n{}  {}",
+                    dim(&format!("{:<16}", item.display_time())),
+                    item.describe()
+                ));
+            }
+        }
+
         // Print spawned children count (only if > 0)
         if spawned_count > 0 {
             output.push_str(&format!(
                 "\n\n{} {} spawned from this ticket",
-                "Children:".green().bold(),
+                heading("Children:"),
                 spawned_count
             ));
         }
@@ -133,5 +273,32 @@ pub async fn cmd_show(id: &str, output: OutputOptions) -> Result<()> {
 
     CommandOutput::new(json_output)
         .with_text(text_output)
+        .paged()
         .print(output)
 }
+
+/// The ticket body rendered for the terminal, under a one-line summary of its
+/// frontmatter (ID, status, type, priority, assignee)
+fn render_ticket(metadata: &TicketMetadata, content: &str) -> Result<String> {
+    let (_, body) = split_frontmatter(content)?;
+
+    let mut summary = vec![accent(&metadata.id_str().unwrap_or_default())];
+    if let Some(status) = metadata.status {
+        summary.push(format_status_colored(status));
+    }
+    if let Some(ticket_type) = metadata.ticket_type {
+        summary.push(ticket_type.to_string());
+    }
+    if let Some(priority) = metadata.priority {
+        summary.push(format!("P{priority}"));
+    }
+    if let Some(assignee) = &metadata.assignee {
+        summary.push(dim(&format!("@{assignee}")));
+    }
+
+    Ok(format!(
+        "{}\n\n{}",
+        summary.join(" "),
+        render_markdown(body.trim())
+    ))
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use jiff::Timestamp;
+use owo_colors::OwoColorize;
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::config::Config;
+use crate::error::Result;
+use crate::sla::{SlaFinding, SlaState, evaluate_tickets, format_duration};
+use crate::ticket::get_all_tickets;
+
+/// List open tickets that are at risk of missing, or have missed, an SLA
+///
+/// Breached deadlines come first, then at-risk ones, each ordered by deadline.
+pub async fn cmd_sla(output: OutputOptions) -> Result<()> {
+    let config = Config::load()?;
+    let tickets = get_all_tickets().await?.items;
+    let now = Timestamp::now();
+    let findings = evaluate_tickets(&tickets, &config.sla, now);
+
+    let titles: HashMap<&str, &str> = tickets
+        .iter()
+        .filter_map(|t| Some((t.id.as_deref()?, t.title.as_deref().unwrap_or(""))))
+        .collect();
+
+    let json_findings: Vec<_> = findings
+        .iter()
+        .map(|f| {
+            let mut value = f.to_json(now);
+            value["title"] = json!(titles.get(f.ticket_id.as_str()).copied().unwrap_or(""));
+            value
+        })
+        .collect();
+
+    let text = if config.sla.rules.is_empty() {
+        "No SLA rules configured. Add rules under `sla` in .janus/config.yaml.".to_string()
+    } else if findings.is_empty() {
+        "All open tickets are within their SLAs.".to_string()
+    } else {
+        findings
+            .iter()
+            .map(|f| format_finding(f, titles.get(f.ticket_id.as_str()).copied(), now))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    CommandOutput::new(serde_json::Value::Array(json_findings))
+        .with_text(text)
+        .print(output)
+}
+
+/// Format one finding as a line of text output.
+fn format_finding(finding: &SlaFinding, title: Option<&str>, now: Timestamp) -> String {
+    let state = format!("{:<8}", finding.state.to_string());
+    let state = match finding.state {
+        SlaState::Breached => state.red().bold().to_string(),
+        SlaState::AtRisk => state.yellow().to_string(),
+    };
+    let remaining = finding.remaining(now);
+    let timing = if remaining.is_negative() {
+        format!("overdue by {}", format_duration(remaining))
+    } else {
+        format!("due in {}", format_duration(remaining))
+    };
+    let rule = format!(
+        "{} within {} ({})",
+        finding.kind, finding.window, finding.rule
+    );
+
+    format!(
+        "{state} {} {rule:<32} {timing:<20} {}",
+        format!("{:8}", finding.ticket_id).cyan(),
+        title.unwrap_or("").dimmed()
+    )
+}
@@ -0,0 +1,57 @@
+use jiff::Timestamp;
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::display::format_date_for_display;
+use crate::error::{JanusError, Result};
+use crate::ticket::Ticket;
+
+/// Snooze a ticket until `until`, or wake it when `until` is `None`
+///
+/// Snoozed tickets are hidden from `janus ls` (including `--ready`), `janus
+/// next`, and the TUI until the snooze passes. `janus ls --snoozed` shows them.
+pub async fn cmd_snooze(id: &str, until: Option<Timestamp>, output: OutputOptions) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+    let metadata = ticket.read()?;
+
+    let Some(until) = until else {
+        if metadata.snoozed_until.is_some() {
+            ticket.remove_field("snoozed-until")?;
+        }
+        return CommandOutput::new(json!({
+            "id": ticket.id,
+            "action": "unsnoozed",
+            "snoozed_until": null,
+        }))
+        .with_text(format!("Woke {}", ticket.id))
+        .print(output);
+    };
+
+    if until <= Timestamp::now() {
+        return Err(JanusError::InvalidInput(format!(
+            "cannot snooze until {until}: that time has already passed"
+        )));
+    }
+    if metadata.status.unwrap_or_default().is_terminal() {
+        return Err(JanusError::InvalidInput(format!(
+            "cannot snooze {}: ticket is already closed",
+            ticket.id
+        )));
+    }
+
+    let value = until.strftime("%Y-%m-%dT%H:%M:%SZ").to_string();
+    ticket.update_field("snoozed-until", &value)?;
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "snoozed",
+        "snoozed_until": value,
+    }))
+    .with_text(format!(
+        "Snoozed {} until {}",
+        ticket.id,
+        format_date_for_display(&value)
+    ))
+    .print(output)
+}
@@ -0,0 +1,263 @@
+//! `janus stats` commands.
+//!
+//! `janus stats workload` shows how open work is spread across assignees:
+//! how many tickets each person has waiting and in progress, and their total
+//! size in points. Anyone over their configured capacity (see
+//! [`CapacityConfig`]) is flagged as overloaded. Without any capacity limits,
+//! people carrying more than [`OVERLOAD_FACTOR`] times the average load are
+//! flagged instead.
+
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::config::{CapacityConfig, Config, ThemeColor};
+use crate::display::{dim, paint};
+use crate::error::Result;
+use crate::plan::forecast::size_points;
+use crate::ticket::get_all_tickets;
+use crate::types::{TicketMetadata, TicketStatus};
+
+/// How far above the average load someone must be to count as overloaded
+/// when no capacity limits are configured
+const OVERLOAD_FACTOR: f64 = 1.5;
+
+/// Label for open tickets without an assignee
+const UNASSIGNED: &str = "(unassigned)";
+
+/// Open work held by one assignee
+#[derive(Debug, Default)]
+struct Workload {
+    /// `None` for unassigned tickets
+    assignee: Option<String>,
+    /// Tickets that are new or next
+    open: usize,
+    in_progress: usize,
+    /// Size points of open and in-progress tickets
+    points: u32,
+    /// Size points of in-progress tickets only
+    in_progress_points: u32,
+    /// Why the assignee counts as overloaded, if they do
+    overloaded: Vec<String>,
+}
+
+impl Workload {
+    fn name(&self) -> &str {
+        self.assignee.as_deref().unwrap_or(UNASSIGNED)
+    }
+}
+
+/// Group open tickets by assignee, matching names ignoring case and keeping
+/// the first spelling seen. The unassigned row, if any, comes last.
+fn workloads(tickets: &[TicketMetadata]) -> Vec<Workload> {
+    let mut rows: Vec<Workload> = Vec::new();
+    let mut unassigned = Workload::default();
+    for ticket in tickets {
+        let in_progress = match ticket.status {
+            Some(TicketStatus::InProgress) => true,
+            Some(TicketStatus::New | TicketStatus::Next) | None => false,
+            _ => continue,
+        };
+        let row = match ticket.assignee.as_deref().filter(|a| !a.is_empty()) {
+            Some(assignee) => {
+                match rows.iter().position(|r| {
+                    r.assignee
+                        .as_deref()
+                        .is_some_and(|a| a.eq_ignore_ascii_case(assignee))
+                }) {
+                    Some(i) => &mut rows[i],
+                    None => {
+                        rows.push(Workload {
+                            assignee: Some(assignee.to_string()),
+                            ..Default::default()
+                        });
+                        rows.last_mut().unwrap()
+                    }
+                }
+            }
+            None => &mut unassigned,
+        };
+        let points = size_points(ticket.size);
+        row.points += points;
+        if in_progress {
+            row.in_progress += 1;
+            row.in_progress_points += points;
+        } else {
+            row.open += 1;
+        }
+    }
+
+    rows.sort_by(|a, b| b.points.cmp(&a.points).then_with(|| a.name().cmp(b.name())));
+    if unassigned.open + unassigned.in_progress > 0 {
+        rows.push(unassigned);
+    }
+    rows
+}
+
+/// Flag assignees who are over their capacity limits, or, when no limits are
+/// configured, well above the average load
+fn flag_overloaded(rows: &mut [Workload], capacity: &CapacityConfig) {
+    if !capacity.is_default() {
+        for row in rows.iter_mut() {
+            let Some(assignee) = row.assignee.as_deref() else {
+                continue;
+            };
+            let limits = capacity.limits_for(assignee);
+            if let Some(limit) = limits.wip_limit
+                && row.in_progress > limit
+            {
+                row.overloaded
+                    .push(format!("{} in progress, limit is {limit}", row.in_progress));
+            }
+            if let Some(budget) = limits.size_budget
+                && row.in_progress_points > budget
+            {
+                row.overloaded.push(format!(
+                    "{} points in progress, budget is {budget}",
+                    row.in_progress_points
+                ));
+            }
+        }
+        return;
+    }
+
+    let assigned: Vec<u32> = rows
+        .iter()
+        .filter(|r| r.assignee.is_some())
+        .map(|r| r.points)
+        .collect();
+    if assigned.len() < 2 {
+        return;
+    }
+    let average = assigned.iter().sum::<u32>() as f64 / assigned.len() as f64;
+    for row in rows.iter_mut().filter(|r| r.assignee.is_some()) {
+        if row.points as f64 > average * OVERLOAD_FACTOR {
+            row.overloaded.push(format!(
+                "{} points, team average is {average:.1}",
+                row.points
+            ));
+        }
+    }
+}
+
+/// Show open and in-progress ticket counts and total size per assignee
+pub async fn cmd_stats_workload(output: OutputOptions) -> Result<()> {
+    let config = Config::load()?;
+    let tickets = get_all_tickets().await?.items;
+    let mut rows = workloads(&tickets);
+    flag_overloaded(&mut rows, &config.capacity);
+
+    let json_rows: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            let limits = row
+                .assignee
+                .as_deref()
+                .map(|a| config.capacity.limits_for(a))
+                .unwrap_or_default();
+            json!({
+                "assignee": row.assignee,
+                "open": row.open,
+                "in_progress": row.in_progress,
+                "points": row.points,
+                "in_progress_points": row.in_progress_points,
+                "wip_limit": limits.wip_limit,
+                "size_budget": limits.size_budget,
+                "overloaded": !row.overloaded.is_empty(),
+                "reasons": row.overloaded,
+            })
+        })
+        .collect();
+
+    let text = if rows.is_empty() {
+        "No open tickets.".to_string()
+    } else {
+        let width = rows.iter().map(|r| r.name().len()).max().unwrap_or(0);
+        let mut lines = vec![dim(&format!(
+            "{:<width$}  {:>4}  {:>11}  {:>6}",
+            "ASSIGNEE", "OPEN", "IN PROGRESS", "POINTS"
+        ))];
+        for row in &rows {
+            let line = format!(
+                "{:<width$}  {:>4}  {:>11}  {:>6}",
+                row.name(),
+                row.open,
+                row.in_progress,
+                row.points
+            );
+            if row.overloaded.is_empty() {
+                lines.push(line);
+            } else {
+                let line = format!("{line}  overloaded: {}", row.overloaded.join("; "));
+                lines.push(paint(&line, ThemeColor::Red));
+            }
+        }
+        lines.join("\n")
+    };
+
+    CommandOutput::new(serde_json::Value::Array(json_rows))
+        .with_text(text)
+        .print(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CapacityLimits;
+    use crate::types::TicketSize;
+
+    fn ticket(assignee: Option<&str>, status: TicketStatus, size: TicketSize) -> TicketMetadata {
+        TicketMetadata {
+            assignee: assignee.map(String::from),
+            status: Some(status),
+            size: Some(size),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_workloads_and_overload() {
+        let tickets = vec![
+            ticket(Some("Sam"), TicketStatus::InProgress, TicketSize::Large),
+            ticket(Some("sam"), TicketStatus::InProgress, TicketSize::XLarge),
+            ticket(Some("sam"), TicketStatus::New, TicketSize::Small),
+            ticket(Some("kai"), TicketStatus::Next, TicketSize::XSmall),
+            ticket(Some("ana"), TicketStatus::New, TicketSize::Small),
+            ticket(Some("kai"), TicketStatus::Complete, TicketSize::XLarge),
+            ticket(None, TicketStatus::New, TicketSize::Medium),
+        ];
+        let mut rows = workloads(&tickets);
+        let names: Vec<&str> = rows.iter().map(|r| r.name()).collect();
+        assert_eq!(names, vec!["Sam", "ana", "kai", UNASSIGNED]);
+        assert_eq!((rows[0].open, rows[0].in_progress), (1, 2));
+        assert_eq!((rows[0].points, rows[0].in_progress_points), (15, 13));
+
+        flag_overloaded(&mut rows, &CapacityConfig::default());
+        assert!(!rows[0].overloaded.is_empty());
+        assert!(rows[1..].iter().all(|r| r.overloaded.is_empty()));
+
+        let capacity = CapacityConfig {
+            default: CapacityLimits {
+                wip_limit: Some(2),
+                size_budget: None,
+            },
+            ..Default::default()
+        };
+        let mut rows = workloads(&tickets);
+        flag_overloaded(&mut rows, &capacity);
+        assert!(rows.iter().all(|r| r.overloaded.is_empty()));
+
+        let capacity = CapacityConfig {
+            default: CapacityLimits {
+                wip_limit: None,
+                size_budget: Some(10),
+            },
+            ..Default::default()
+        };
+        flag_overloaded(&mut rows, &capacity);
+        assert_eq!(
+            rows[0].overloaded,
+            vec!["13 points in progress, budget is 10"]
+        );
+    }
+}
@@ -1,50 +1,132 @@
 use serde_json::json;
 
-use super::CommandOutput;
+use super::{CommandOutput, interactive};
 use crate::cli::OutputOptions;
+use crate::config::{Config, TransitionVia};
 use crate::error::{JanusError, Result};
+use crate::fs::transaction;
+use crate::status::{TransitionVerdict, evaluate_transition};
 use crate::ticket::Ticket;
-use crate::types::TicketStatus;
+use crate::types::{TicketResolution, TicketStatus};
+use crate::utils::is_stdin_tty;
 
 /// Update a ticket's status
-async fn update_status(id: &str, new_status: TicketStatus, output: OutputOptions) -> Result<()> {
-    update_status_with_summary(id, new_status, None, output).await
+async fn update_status(
+    id: &str,
+    new_status: TicketStatus,
+    via: TransitionVia,
+    yes: bool,
+    output: OutputOptions,
+) -> Result<()> {
+    update_status_with_summary(id, new_status, None, via, yes, output).await
 }
 
 /// Update a ticket's status with an optional completion summary
+///
+/// Protected transitions configured under `transitions` in config.yaml are
+/// enforced here: forbidden transitions fail, and transitions requiring
+/// confirmation prompt interactively unless `yes` is set.
 async fn update_status_with_summary(
     id: &str,
     new_status: TicketStatus,
     summary: Option<&str>,
+    via: TransitionVia,
+    yes: bool,
     output: OutputOptions,
 ) -> Result<()> {
-    let ticket = Ticket::find(id).await?;
-
-    // Use the domain method that handles status updates and event logging
-    ticket.update_status(new_status, summary)?;
+    let Some(id) = change_status(id, new_status, summary, None, via, yes, output).await? else {
+        println!("Cancelled");
+        return Ok(());
+    };
 
     CommandOutput::new(json!({
-        "id": ticket.id,
+        "id": id,
         "action": "status_changed",
         "new_status": new_status.to_string(),
     }))
-    .with_text(format!("Updated {} -> {}", ticket.id, new_status))
+    .with_text(format!("Updated {id} -> {new_status}"))
     .print(output)
 }
 
+/// Change a ticket's status, recording `summary` and `resolution` if given
+///
+/// Returns the ticket's full ID, or `None` if the user declined a
+/// transition that needed confirmation.
+async fn change_status(
+    id: &str,
+    new_status: TicketStatus,
+    summary: Option<&str>,
+    resolution: Option<TicketResolution>,
+    via: TransitionVia,
+    yes: bool,
+    output: OutputOptions,
+) -> Result<Option<String>> {
+    let ticket = Ticket::find(id).await?;
+    let old_status = ticket.read()?.status.unwrap_or_default();
+
+    if !confirm_transition(&ticket.id, old_status, new_status, via, yes, output).await? {
+        return Ok(None);
+    }
+
+    // Use the domain method that handles status updates and event logging
+    ticket.update_status(new_status, summary)?;
+    if let Some(resolution) = resolution {
+        ticket.update_field("resolution", &resolution.to_string())?;
+    }
+
+    Ok(Some(ticket.id))
+}
+
+/// Check a status change against the `transitions` rules in config.yaml
+///
+/// Forbidden transitions fail. Transitions requiring confirmation prompt
+/// interactively unless `yes` is set, and fail when there is no terminal to
+/// prompt on. Returns `false` if the user declined.
+pub(crate) async fn confirm_transition(
+    id: &str,
+    old_status: TicketStatus,
+    new_status: TicketStatus,
+    via: TransitionVia,
+    yes: bool,
+    output: OutputOptions,
+) -> Result<bool> {
+    match evaluate_transition(id, old_status, new_status, via).await? {
+        TransitionVerdict::NeedsConfirmation(reason) if !yes && !output.json && is_stdin_tty() => {
+            interactive::confirm(&format!(
+                "{reason}. Change {id} from {old_status} to {new_status}"
+            ))
+        }
+        verdict => {
+            verdict.into_result(id, old_status, new_status, yes)?;
+            Ok(true)
+        }
+    }
+}
+
 /// Set a ticket's status to "in_progress" (start working on it)
-pub async fn cmd_start(id: &str, output: OutputOptions) -> Result<()> {
-    update_status(id, TicketStatus::InProgress, output).await
+pub async fn cmd_start(id: &str, yes: bool, output: OutputOptions) -> Result<()> {
+    update_status(
+        id,
+        TicketStatus::InProgress,
+        TransitionVia::Start,
+        yes,
+        output,
+    )
+    .await
 }
 
-/// Set a ticket's status to "complete" or "cancelled"
+/// Set one or more tickets' status to "complete" or "cancelled"
 ///
-/// Requires either a summary or explicit --no-summary flag.
+/// Requires either a summary or explicit --no-summary flag. A `resolution`
+/// is written to each ticket's `resolution` field; `wontfix` and `duplicate`
+/// close the ticket as cancelled.
 pub async fn cmd_close(
-    id: &str,
+    ids: &[String],
     summary: Option<&str>,
     no_summary: bool,
     cancel: bool,
+    resolution: Option<TicketResolution>,
+    yes: bool,
     output: OutputOptions,
 ) -> Result<()> {
     // Require either --summary or --no-summary
@@ -55,18 +137,126 @@ pub async fn cmd_close(
     let new_status = if cancel {
         TicketStatus::Cancelled
     } else {
-        TicketStatus::Complete
+        resolution.map_or(TicketStatus::Complete, |r| r.closed_status())
     };
 
-    update_status_with_summary(id, new_status, summary, output).await
+    // Resolve every ID up front so a typo doesn't leave the batch half-closed
+    let mut tickets = Vec::with_capacity(ids.len());
+    for id in ids {
+        tickets.push(Ticket::find(id).await?.id);
+    }
+
+    // Close every ticket or none: a forbidden transition or failed write
+    // partway through rolls back the tickets already closed
+    let (mut closed, lines) = transaction("close", || async {
+        let mut closed = Vec::new();
+        let mut lines = Vec::new();
+        for id in &tickets {
+            let Some(id) = change_status(
+                id,
+                new_status,
+                summary,
+                resolution,
+                TransitionVia::Close,
+                yes,
+                output,
+            )
+            .await?
+            else {
+                lines.push(format!("Cancelled closing {id}"));
+                continue;
+            };
+            lines.push(match resolution {
+                Some(resolution) => format!("Updated {id} -> {new_status} ({resolution})"),
+                None => format!("Updated {id} -> {new_status}"),
+            });
+            closed.push(json!({
+                "id": id,
+                "action": "status_changed",
+                "new_status": new_status.to_string(),
+                "resolution": resolution.map(|r| r.to_string()),
+            }));
+        }
+        Ok((closed, lines))
+    })
+    .await?;
+
+    // Closing a single ticket keeps the plain object output
+    let json = if ids.len() == 1 && closed.len() == 1 {
+        closed.remove(0)
+    } else {
+        json!(closed)
+    };
+    CommandOutput::new(json)
+        .with_text(lines.join("\n"))
+        .print(output)
 }
 
 /// Reopen a ticket (set status back to "new")
-pub async fn cmd_reopen(id: &str, output: OutputOptions) -> Result<()> {
-    update_status(id, TicketStatus::New, output).await
+///
+/// Reopening a closed ticket adds a note recording why: `reason` if given,
+/// otherwise a generated one, unless `transitions.require_reopen_reason` makes
+/// the reason mandatory.
+pub async fn cmd_reopen(
+    id: &str,
+    reason: Option<&str>,
+    yes: bool,
+    output: OutputOptions,
+) -> Result<()> {
+    let ticket = Ticket::find(id).await?;
+    let metadata = ticket.read()?;
+    let old_status = metadata.status.unwrap_or_default();
+    let was_closed = old_status.is_terminal();
+
+    if let Some(reason) = reason
+        && reason.trim().is_empty()
+    {
+        return Err(JanusError::EmptyNote);
+    }
+    if was_closed && reason.is_none() && Config::load()?.transitions.require_reopen_reason {
+        return Err(JanusError::ReopenReasonRequired(ticket.id));
+    }
+
+    let Some(id) = change_status(
+        &ticket.id,
+        TicketStatus::New,
+        None,
+        None,
+        TransitionVia::Reopen,
+        yes,
+        output,
+    )
+    .await?
+    else {
+        println!("Cancelled");
+        return Ok(());
+    };
+
+    let note = was_closed.then(|| match reason {
+        Some(reason) => format!("Reopened (was {old_status}): {reason}"),
+        None => format!("Reopened (was {old_status})"),
+    });
+    if let Some(note) = &note {
+        ticket.add_note(note)?;
+    }
+
+    CommandOutput::new(json!({
+        "id": id,
+        "action": "status_changed",
+        "new_status": TicketStatus::New.to_string(),
+        "note": note,
+        "reopen_count": metadata.reopen_count.unwrap_or(0) + u32::from(was_closed),
+    }))
+    .with_text(format!("Updated {id} -> {}", TicketStatus::New))
+    .print(output)
 }
 
 /// Set a ticket's status to an arbitrary value
-pub async fn cmd_status(id: &str, status: TicketStatus, output: OutputOptions) -> Result<()> {
-    update_status(id, status, output).await
+pub async fn cmd_status(
+    id: &str,
+    status: TicketStatus,
+    yes: bool,
+    output: OutputOptions,
+) -> Result<()> {
+    update_status(id, status, TransitionVia::Status, yes, output).await
 }
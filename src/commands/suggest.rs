@@ -0,0 +1,134 @@
+//! Personalized work suggestions (`janus suggest`)
+
+use std::collections::HashMap;
+
+use jiff::Timestamp;
+use owo_colors::OwoColorize;
+use serde_json::json;
+
+use crate::cli::OutputOptions;
+use crate::commands::CommandOutput;
+use crate::config::Config;
+use crate::error::Result;
+use crate::plan::get_all_plans;
+use crate::store::get_or_init_store;
+use crate::suggest::{candidates, most_similar, plan_urgency, rank, score};
+use crate::ticket::build_ticket_map;
+use crate::types::{TicketMetadata, TicketStatus};
+use crate::utils::get_git_user_name;
+
+/// Suggest the tickets the current user should pick up next
+///
+/// # Arguments
+/// * `user` - Whose suggestions to show (defaults to git `user.name`)
+/// * `limit` - Number of suggestions
+/// * `output` - Output options
+pub async fn cmd_suggest(user: Option<&str>, limit: usize, output: OutputOptions) -> Result<()> {
+    let user = user.map(String::from).or_else(get_git_user_name);
+    let ticket_map = build_ticket_map().await?;
+    let plans = get_all_plans().await?.items;
+    let now = Timestamp::now();
+
+    let urgency = plan_urgency(&plans, &ticket_map);
+    let embeddings = match user.as_deref() {
+        Some(user) => completed_embeddings(user, &ticket_map).await,
+        None => Embeddings::default(),
+    };
+
+    let require = Config::load().map(|c| c.ready.require).unwrap_or_default();
+    let suggestions = candidates(&ticket_map, user.as_deref(), &require, now)
+        .into_iter()
+        .map(|ticket| {
+            let id = ticket.id_str().unwrap_or_default();
+            let similar = embeddings
+                .candidates
+                .get(&id)
+                .and_then(|embedding| most_similar(embedding, &embeddings.history));
+            score(ticket, now, urgency.get(&id), similar.as_ref())
+        })
+        .collect();
+    let suggestions = rank(suggestions, limit);
+
+    let json_output = json!({
+        "user": user,
+        "suggestions": suggestions
+            .iter()
+            .map(|s| json!({
+                "id": s.ticket_id,
+                "title": s.title,
+                "score": s.score,
+                "reasons": s.reasons,
+            }))
+            .collect::<Vec<_>>(),
+    });
+
+    let text = if suggestions.is_empty() {
+        "No ready tickets to suggest.".to_string()
+    } else {
+        suggestions
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                format!(
+                    "{}. {} {}\n   {}",
+                    i + 1,
+                    s.ticket_id.cyan(),
+                    s.title,
+                    s.reasons.join(", ").dimmed()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    CommandOutput::new(json_output)
+        .with_text(text)
+        .print(output)
+}
+
+/// Embeddings used for the similarity signal
+#[derive(Default)]
+struct Embeddings {
+    /// Tickets the user has completed, with their embeddings
+    history: Vec<(String, Vec<f32>)>,
+    /// Embeddings of every other ticket, by ID
+    candidates: HashMap<String, Vec<f32>>,
+}
+
+/// Look up stored embeddings for the similarity signal.
+///
+/// Only embeddings that already exist are used; none are generated here.
+/// Similarity is a bonus, so when semantic search is disabled or the store is
+/// unavailable the other signals rank the tickets on their own.
+async fn completed_embeddings(
+    user: &str,
+    ticket_map: &HashMap<String, TicketMetadata>,
+) -> Embeddings {
+    let enabled = Config::load()
+        .map(|c| c.semantic_search_enabled())
+        .unwrap_or(false);
+    if !enabled {
+        return Embeddings::default();
+    }
+    let Ok(store) = get_or_init_store().await else {
+        return Embeddings::default();
+    };
+
+    let mut embeddings = Embeddings::default();
+    for (id, ticket) in ticket_map {
+        let Some(embedding) = store.embeddings().get(id).map(|e| e.value().dequantize()) else {
+            continue;
+        };
+        let completed_by_user = ticket.status == Some(TicketStatus::Complete)
+            && ticket
+                .assignee
+                .as_deref()
+                .is_some_and(|a| a.eq_ignore_ascii_case(user));
+        if completed_by_user {
+            embeddings.history.push((id.clone(), embedding));
+        } else {
+            embeddings.candidates.insert(id.clone(), embedding);
+        }
+    }
+    embeddings
+}
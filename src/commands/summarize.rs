@@ -0,0 +1,68 @@
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::config::Config;
+use crate::error::Result;
+use crate::parser::{parse_document_raw, update_section_in_body};
+use crate::plan::Plan;
+use crate::ticket::Ticket;
+
+/// Section that `--append` writes the summary to
+const SUMMARY_SECTION: &str = "Summary";
+
+const SYSTEM_PROMPT: &str = "You summarize tickets and plans from a plain-text issue tracker. \
+The document is markdown with YAML frontmatter. Reply with a concise summary in markdown: \
+the goal, the current state, decisions made, and open questions or next steps. \
+Use at most 8 short bullet points and do not add a heading.";
+
+enum Target {
+    Ticket(Ticket),
+    Plan(Plan),
+}
+
+/// Summarize a ticket or plan with the configured LLM
+///
+/// IDs starting with `plan-` are resolved as plans, everything else as
+/// tickets. With `append`, the summary replaces (or adds) the `## Summary`
+/// section of the document; otherwise it is only printed.
+pub async fn cmd_summarize(id: &str, append: bool, output: OutputOptions) -> Result<()> {
+    let config = Config::load()?;
+
+    let target = if id.starts_with("plan-") {
+        Target::Plan(Plan::find(id).await?)
+    } else {
+        Target::Ticket(Ticket::find(id).await?)
+    };
+    let (id, content) = match &target {
+        Target::Plan(plan) => (plan.id.clone(), plan.read_content()?),
+        Target::Ticket(ticket) => (ticket.id.clone(), ticket.read_content()?),
+    };
+
+    let summary = crate::llm::complete(&config, SYSTEM_PROMPT, &content).await?;
+
+    if append {
+        match &target {
+            Target::Plan(plan) => {
+                let (frontmatter_raw, body) = parse_document_raw(&content)?;
+                let body = update_section_in_body(&body, SUMMARY_SECTION, &summary)?;
+                plan.write_validated(&format!("---\n{frontmatter_raw}\n---\n{body}"))?;
+            }
+            Target::Ticket(ticket) => ticket.update_section(SUMMARY_SECTION, Some(&summary))?,
+        }
+    }
+
+    let text = if append {
+        format!("{summary}\n\nAdded summary to {id}")
+    } else {
+        summary.clone()
+    };
+
+    CommandOutput::new(json!({
+        "id": id,
+        "summary": summary,
+        "appended": append,
+    }))
+    .with_text(text)
+    .print(output)
+}
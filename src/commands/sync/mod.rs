@@ -4,20 +4,33 @@ pub mod sync_strategy;
 pub mod sync_ui;
 
 pub use sync_executor::apply_sync_changes;
-pub use sync_strategy::{StatusDiff, SyncPlan, TitleDiff, compute_sync_state};
-pub use sync_ui::{SyncChoice, SyncDecision, generate_sync_json, prompt_user_for_action};
-
+pub use sync_strategy::{
+    AssigneeDiff, LabelsDiff, StatusDiff, SyncPlan, TitleDiff, compute_sync_state,
+};
+pub use sync_ui::{
+    SyncChoice, SyncDecision, format_sync_diff, generate_sync_json, prompt_user_for_action,
+    pull_decisions,
+};
+
+use jiff::{SignedDuration, Timestamp};
 use owo_colors::OwoColorize;
 use serde_json::json;
 
+use super::pr::{complete_on_merge, pull_request_json};
 use super::{CommandOutput, print_json};
 use crate::cli::OutputOptions;
 use crate::config::Config;
 use crate::error::{JanusError, Result};
-use crate::remote::{RemoteIssue, RemoteProvider, RemoteRef, create_provider};
+use crate::events::log_ticket_synced;
+use crate::remote::cache::{self, Freshness};
+use crate::remote::mirror::PullGuard;
+use crate::remote::{
+    IssueUpdates, Platform, Provider, RemoteIssue, RemoteProvider, RemoteRef, create_provider,
+};
+use crate::sla::format_duration;
 use crate::ticket::extract_body;
-use crate::ticket::{Ticket, TicketBuilder};
-use crate::types::{TicketPriority, TicketType};
+use crate::ticket::{Ticket, TicketBuilder, get_all_tickets_from_disk};
+use crate::types::{TicketMetadata, TicketPriority, TicketStatus, TicketType};
 use std::str::FromStr;
 
 pub async fn cmd_adopt(
@@ -35,12 +48,14 @@ pub async fn cmd_adopt(
     let provider = create_provider(&remote_ref.platform(), &config)?;
     let remote_issue = provider.fetch_issue(&remote_ref).await?;
 
-    let status = remote_issue.status.to_ticket_status();
+    let status = config
+        .status_mapping
+        .to_local(remote_ref.platform(), &remote_issue);
     let title = remote_issue.title.clone();
     let url = remote_issue.url.clone();
     let remote_ref_str = remote_ref.to_string();
 
-    let id = create_ticket_from_remote(&remote_issue, &remote_ref, prefix)?;
+    let id = create_ticket_from_remote(&remote_issue, &remote_ref, prefix, &config)?;
     let text = format!(
         "Created {} from {}\n  Title: {}\n  URL: {}",
         id.cyan(),
@@ -65,8 +80,10 @@ fn create_ticket_from_remote(
     remote_issue: &RemoteIssue,
     remote_ref: &RemoteRef,
     prefix: Option<&str>,
+    config: &Config,
 ) -> Result<String> {
-    let status = remote_issue.status.to_ticket_status();
+    let platform = remote_ref.platform();
+    let status = config.status_mapping.to_local(platform, remote_issue);
 
     let priority = TicketPriority::from_str(&remote_issue.priority.unwrap_or(2).to_string())?;
 
@@ -84,6 +101,14 @@ fn create_ticket_from_remote(
         .ticket_type(TicketType::Task)
         .status(status)
         .priority(priority)
+        .labels(
+            config.labels.to_local(
+                &config
+                    .status_mapping
+                    .strip_status_labels(platform, &remote_issue.labels),
+            ),
+        )
+        .assignee(config.users.to_local(platform, remote_issue))
         .remote(Some(remote_ref.to_string()))
         .run_hooks(false)
         .build()?;
@@ -91,7 +116,15 @@ fn create_ticket_from_remote(
     Ok(id)
 }
 
-pub async fn cmd_push(local_id: &str, output: OutputOptions) -> Result<()> {
+/// Push a local ticket to create a remote issue
+///
+/// `project` and `cycle` place the new issue in a Linear project and cycle.
+pub async fn cmd_push(
+    local_id: &str,
+    project: Option<&str>,
+    cycle: Option<&str>,
+    output: OutputOptions,
+) -> Result<()> {
     let config = Config::load()?;
 
     let ticket = Ticket::find(local_id).await?;
@@ -103,22 +136,21 @@ pub async fn cmd_push(local_id: &str, output: OutputOptions) -> Result<()> {
         ));
     }
 
-    let title = metadata.title.unwrap_or_else(|| "Untitled".to_string());
-    let content = ticket.read_content()?;
-    let body = extract_body(&content)?;
-
     let default_remote = config.default_remote.as_ref().ok_or_else(|| {
         JanusError::Config(
             "No default.remote configured. Run: janus config set default.remote <platform:org>"
                 .to_string(),
         )
     })?;
+    let placement = linear_placement(
+        default_remote.platform,
+        project.map(str::to_string),
+        cycle.map(str::to_string),
+    )?;
 
     let provider = create_provider(&default_remote.platform, &config)?;
-    let remote_ref = provider.create_issue(&title, &body).await?;
-
+    let remote_ref = push_ticket(&ticket, &metadata, &config, &provider, placement).await?;
     let remote_ref_str = remote_ref.to_string();
-    ticket.update_field("remote", &remote_ref_str)?;
 
     let ticket_id = ticket.id.clone();
     let text = format!(
@@ -137,6 +169,59 @@ pub async fn cmd_push(local_id: &str, output: OutputOptions) -> Result<()> {
     .print(output)
 }
 
+/// Create a remote issue for a ticket and link the ticket to it.
+///
+/// Issues are created bare; labels (creating missing ones), the assignee, and
+/// `placement` are attached in a follow-up update.
+pub(crate) async fn push_ticket(
+    ticket: &Ticket,
+    metadata: &TicketMetadata,
+    config: &Config,
+    provider: &Provider,
+    placement: IssueUpdates,
+) -> Result<RemoteRef> {
+    let title = metadata.title.as_deref().unwrap_or("Untitled");
+    let content = ticket.read_content()?;
+    let body = extract_body(&content)?;
+
+    let remote_ref = provider.create_issue(title, &body).await?;
+
+    let updates = IssueUpdates {
+        labels: (!metadata.labels.is_empty()).then(|| config.labels.to_remote(&metadata.labels)),
+        assignee: metadata
+            .assignee
+            .as_deref()
+            .map(|a| config.users.to_remote(remote_ref.platform(), a)),
+        ..placement
+    };
+    if !updates.is_empty() {
+        provider.update_issue(&remote_ref, updates).await?;
+    }
+
+    ticket.update_field("remote", &remote_ref.to_string())?;
+    Ok(remote_ref)
+}
+
+/// Updates placing an issue in a Linear project and cycle.
+///
+/// Fails if either is given for another platform.
+pub(crate) fn linear_placement(
+    platform: Platform,
+    project: Option<String>,
+    cycle: Option<String>,
+) -> Result<IssueUpdates> {
+    if platform != Platform::Linear && (project.is_some() || cycle.is_some()) {
+        return Err(JanusError::Config(format!(
+            "projects and cycles are only supported on Linear, not {platform}"
+        )));
+    }
+    Ok(IssueUpdates {
+        project,
+        cycle,
+        ..Default::default()
+    })
+}
+
 pub async fn cmd_remote_link(
     local_id: &str,
     remote_ref_str: &str,
@@ -173,17 +258,80 @@ pub async fn cmd_remote_link(
     .print(output)
 }
 
-pub async fn cmd_sync(local_id: &str, output: OutputOptions) -> Result<()> {
+/// Sync a local ticket with its remote issue
+///
+/// With `dry_run`, only the differences are reported; nothing is written to the
+/// ticket file or the remote issue.
+///
+/// With `pull_requests.complete_on_merge`, the ticket is first completed if a
+/// linked pull request has merged, so the completion syncs to the remote too.
+///
+/// Tickets whose remote is in mirror mode are pulled without prompting, and
+/// merged pull requests are left for the remote to act on.
+pub async fn cmd_sync(local_id: &str, dry_run: bool, output: OutputOptions) -> Result<()> {
     let config = Config::load()?;
 
     let ticket = Ticket::find(local_id).await?;
-    let metadata = ticket.read()?;
+    let mut metadata = ticket.read()?;
+
+    let mirror = metadata
+        .remote
+        .as_deref()
+        .and_then(|remote| RemoteRef::parse(remote, Some(&config)).ok())
+        .is_some_and(|remote_ref| config.remotes.is_mirror(remote_ref.platform()));
+    let merge = if mirror {
+        None
+    } else {
+        complete_on_merge(&ticket, &metadata, &config, dry_run).await?
+    };
+    let merge_json = merge.as_ref().map(|(pull, completed)| {
+        json!({
+            "pull_request": pull_request_json(pull),
+            "completed": completed,
+        })
+    });
+    if let Some((pull, completed)) = &merge {
+        if *completed {
+            metadata.status = Some(TicketStatus::Complete);
+        }
+        if !output.json {
+            let message = if *completed {
+                format!("Completed {}: {} merged", ticket.id.cyan(), pull.reference)
+            } else if dry_run {
+                format!(
+                    "Would complete {}: {} merged",
+                    ticket.id.cyan(),
+                    pull.reference
+                )
+            } else {
+                format!(
+                    "{} merged, but a transition rule prevents completing {} during sync",
+                    pull.reference,
+                    ticket.id.cyan()
+                )
+            };
+            println!("{message}");
+        }
+    }
 
-    let remote_ref_str = metadata.remote.as_ref().ok_or(JanusError::NotLinked)?;
+    let Some(remote_ref_str) = metadata.remote.as_ref() else {
+        if merge_json.is_none() {
+            return Err(JanusError::NotLinked);
+        }
+        if output.json {
+            print_json(&json!({
+                "id": ticket.id,
+                "dry_run": dry_run,
+                "merged_pull_request": merge_json,
+            }))?;
+        }
+        return Ok(());
+    };
     let remote_ref = RemoteRef::parse(remote_ref_str, Some(&config))?;
 
     let provider = create_provider(&remote_ref.platform(), &config)?;
     let remote_issue = provider.fetch_issue(&remote_ref).await?;
+    cache::store_issue(&remote_ref, &remote_issue);
 
     let local_title = metadata
         .title
@@ -199,10 +347,54 @@ pub async fn cmd_sync(local_id: &str, output: OutputOptions) -> Result<()> {
     let local_content = ticket.read_content()?;
     let _local_body = extract_body(&local_content)?;
 
-    let sync_plan = compute_sync_state(local_title, local_status, &remote_issue);
+    let sync_plan = compute_sync_state(
+        local_title,
+        local_status,
+        &metadata.labels,
+        metadata.assignee.as_deref(),
+        &remote_issue,
+        remote_ref.platform(),
+        &config,
+    );
+
+    if dry_run {
+        let mut json_output = generate_sync_json(ticket.id.clone(), &remote_ref, &sync_plan);
+        json_output["dry_run"] = json!(true);
+        if let Some(merge_json) = merge_json {
+            json_output["merged_pull_request"] = merge_json;
+        }
+        let text = format_sync_diff(&ticket.id, &remote_ref, &sync_plan);
+        return CommandOutput::new(json_output)
+            .with_text(text)
+            .print(output);
+    }
+
+    if mirror {
+        let fields = sync_plan.fields();
+        let decisions = pull_decisions(&sync_plan, &local_content)?;
+        apply_pull(&decisions, &ticket, &remote_ref, &config).await?;
+
+        let text = if fields.is_empty() {
+            "Already in sync.".green().to_string()
+        } else {
+            format!(
+                "Pulled {} from {}",
+                fields.join(", "),
+                remote_ref.to_string().green()
+            )
+        };
+        let mut json_output = generate_sync_json(ticket.id.clone(), &remote_ref, &sync_plan);
+        json_output["pulled"] = json!(fields);
+        return CommandOutput::new(json_output)
+            .with_text(text)
+            .print(output);
+    }
 
     if output.json {
-        let json_output = generate_sync_json(ticket.id.clone(), &remote_ref, &sync_plan);
+        let mut json_output = generate_sync_json(ticket.id.clone(), &remote_ref, &sync_plan);
+        if let Some(merge_json) = merge_json {
+            json_output["merged_pull_request"] = merge_json;
+        }
         print_json(&json_output)?;
         return Ok(());
     }
@@ -210,6 +402,7 @@ pub async fn cmd_sync(local_id: &str, output: OutputOptions) -> Result<()> {
     let (decisions, changes_made) = prompt_user_for_action(&sync_plan, &local_content)?;
 
     apply_sync_changes(&decisions, &ticket, &remote_ref, &config).await?;
+    log_ticket_synced(&ticket.id, &remote_ref.to_string(), changes_made);
 
     if changes_made {
         println!("\n{}", "Sync complete.".green());
@@ -219,3 +412,240 @@ pub async fn cmd_sync(local_id: &str, output: OutputOptions) -> Result<()> {
 
     Ok(())
 }
+
+/// Pull every ticket linked to a mirrored remote
+///
+/// With `every`, keeps pulling at that interval (e.g. `15m`) until interrupted;
+/// a failed pass is reported and the next one runs as scheduled.
+/// Tickets on remotes that are not mirrored are skipped, since their
+/// differences need `janus remote sync <id>` to pick a direction.
+///
+/// Issues fetched within `remotes.cache_ttl` (capped at the `every` interval)
+/// are not fetched again. Tickets whose remote cannot be reached are left as
+/// they are and reported with the age of the last fetched copy.
+pub async fn cmd_sync_all(dry_run: bool, every: Option<&str>, output: OutputOptions) -> Result<()> {
+    let interval = every
+        .map(|every| {
+            let interval = crate::utils::parse_duration(every)?;
+            if interval.is_zero() {
+                return Err(JanusError::InvalidInput(
+                    "--every must be longer than zero".to_string(),
+                ));
+            }
+            Ok(interval.unsigned_abs())
+        })
+        .transpose()?;
+
+    let Some(interval) = interval else {
+        return pull_mirrored_tickets(dry_run, None, output).await;
+    };
+
+    // A failed pass (e.g. the network is down) is reported and retried on
+    // the next one; each pass's changes are committed before sleeping
+    loop {
+        if let Err(e) = pull_mirrored_tickets(dry_run, Some(interval), output).await {
+            eprintln!("{}: {e}", "Sync failed".red());
+        }
+        crate::fs::commit_pending_changes().await;
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// One pass of `janus remote sync --all`
+async fn pull_mirrored_tickets(
+    dry_run: bool,
+    interval: Option<std::time::Duration>,
+    output: OutputOptions,
+) -> Result<()> {
+    let config = Config::load()?;
+    if !config.remotes.is_mirror(Platform::GitHub) && !config.remotes.is_mirror(Platform::Linear) {
+        return Err(JanusError::Config(
+            "no remote is in mirror mode; set remotes.<platform>.mode: mirror in .janus/config.yaml"
+                .to_string(),
+        ));
+    }
+
+    let mut ttl = config.remotes.cache_ttl()?;
+    if let Some(interval) = interval.and_then(|i| SignedDuration::try_from(i).ok()) {
+        ttl = ttl.min(interval);
+    }
+
+    let mut providers: Vec<(Platform, Provider)> = Vec::new();
+    let mut pulled = Vec::new();
+    let mut failed = Vec::new();
+    let mut offline: Vec<(String, SignedDuration)> = Vec::new();
+    let mut unchanged = 0;
+    let mut skipped = 0;
+    let mut cached = 0;
+
+    for metadata in get_all_tickets_from_disk().items {
+        let (Some(remote), Some(id), Some(file_path)) = (
+            metadata.remote.as_deref(),
+            metadata.id.as_ref(),
+            metadata.file_path.clone(),
+        ) else {
+            continue;
+        };
+        let remote_ref = match RemoteRef::parse(remote, Some(&config)) {
+            Ok(remote_ref) => remote_ref,
+            Err(e) => {
+                failed.push((id.to_string(), e.to_string()));
+                continue;
+            }
+        };
+        let platform = remote_ref.platform();
+        if !config.remotes.is_mirror(platform) {
+            skipped += 1;
+            continue;
+        }
+        if !providers.iter().any(|(p, _)| *p == platform) {
+            providers.push((platform, create_provider(&platform, &config)?));
+        }
+        let provider = &providers.iter().find(|(p, _)| *p == platform).unwrap().1;
+
+        let (issue, freshness) = match cache::fetch_issue_cached(provider, &remote_ref, ttl).await {
+            Ok(fetched) => fetched,
+            Err(e) => {
+                failed.push((id.to_string(), e.to_string()));
+                continue;
+            }
+        };
+        match freshness {
+            Freshness::Live => {}
+            Freshness::Cached => cached += 1,
+            Freshness::Stale => {
+                offline.push((id.to_string(), issue.age(Timestamp::now())));
+                continue;
+            }
+        }
+
+        let result = async {
+            let ticket = Ticket::new(file_path)?;
+            pull_ticket(
+                &ticket,
+                &metadata,
+                &remote_ref,
+                &issue.value,
+                &config,
+                dry_run,
+            )
+            .await
+        }
+        .await;
+        match result {
+            Ok(fields) if fields.is_empty() => unchanged += 1,
+            Ok(fields) => pulled.push((id.to_string(), fields)),
+            Err(e) => failed.push((id.to_string(), e.to_string())),
+        }
+    }
+
+    let verb = if dry_run { "Would pull" } else { "Pulled" };
+    let mut lines: Vec<String> = pulled
+        .iter()
+        .map(|(id, fields)| format!("{verb} {} ({})", id.cyan(), fields.join(", ")))
+        .collect();
+    lines.extend(
+        failed
+            .iter()
+            .map(|(id, error)| format!("{} {}: {error}", "Failed".red(), id.cyan())),
+    );
+    lines.extend(offline.iter().map(|(id, age)| {
+        format!(
+            "{} {}: remote unreachable, last fetched {} ago",
+            "Offline".yellow(),
+            id.cyan(),
+            format_duration(*age)
+        )
+    }));
+    let mut summary = format!(
+        "{} pulled, {unchanged} already in sync, {} failed",
+        pulled.len(),
+        failed.len()
+    );
+    if !offline.is_empty() {
+        summary.push_str(&format!(", {} offline", offline.len()));
+    }
+    lines.push(summary);
+    if cached > 0 {
+        lines.push(
+            format!("{cached} issue(s) fetched within remotes.cache_ttl were not fetched again")
+                .dimmed()
+                .to_string(),
+        );
+    }
+    if skipped > 0 {
+        lines.push(
+            format!("{skipped} ticket(s) on two-way remotes skipped; use 'janus remote sync <id>'")
+                .dimmed()
+                .to_string(),
+        );
+    }
+
+    CommandOutput::new(json!({
+        "dry_run": dry_run,
+        "pulled": pulled
+            .iter()
+            .map(|(id, fields)| json!({ "id": id, "fields": fields }))
+            .collect::<Vec<_>>(),
+        "unchanged": unchanged,
+        "skipped": skipped,
+        "cached": cached,
+        "offline": offline
+            .iter()
+            .map(|(id, age)| json!({ "id": id, "cache_age_secs": age.as_secs() }))
+            .collect::<Vec<_>>(),
+        "failed": failed
+            .iter()
+            .map(|(id, error)| json!({ "id": id, "error": error }))
+            .collect::<Vec<_>>(),
+    }))
+    .with_text(lines.join("\n"))
+    .print(output)
+}
+
+/// Pull the differing synced fields of a mirrored ticket from its remote issue
+///
+/// Returns the fields that were (or, with `dry_run`, would be) updated.
+async fn pull_ticket(
+    ticket: &Ticket,
+    metadata: &TicketMetadata,
+    remote_ref: &RemoteRef,
+    remote_issue: &RemoteIssue,
+    config: &Config,
+    dry_run: bool,
+) -> Result<Vec<&'static str>> {
+    let corrupted = |field: &str| JanusError::CorruptedTicket {
+        id: ticket.id.clone(),
+        field: field.to_string(),
+    };
+    let sync_plan = compute_sync_state(
+        metadata.title.clone().ok_or_else(|| corrupted("title"))?,
+        metadata.status.ok_or_else(|| corrupted("status"))?,
+        &metadata.labels,
+        metadata.assignee.as_deref(),
+        remote_issue,
+        remote_ref.platform(),
+        config,
+    );
+
+    let fields = sync_plan.fields();
+    if !dry_run {
+        let decisions = pull_decisions(&sync_plan, &ticket.read_content()?)?;
+        apply_pull(&decisions, ticket, remote_ref, config).await?;
+    }
+    Ok(fields)
+}
+
+/// Apply decisions pulled from a mirrored remote, bypassing the mirror-mode
+/// checks on local edits.
+async fn apply_pull(
+    decisions: &[SyncDecision],
+    ticket: &Ticket,
+    remote_ref: &RemoteRef,
+    config: &Config,
+) -> Result<()> {
+    let _pull = PullGuard::new();
+    apply_sync_changes(decisions, ticket, remote_ref, config).await?;
+    log_ticket_synced(&ticket.id, &remote_ref.to_string(), !decisions.is_empty());
+    Ok(())
+}
@@ -1,16 +1,34 @@
 use crate::config::Config;
 use crate::error::Result;
+use crate::hooks::{HookEvent, run_post_hooks_async, run_pre_hooks_async};
 use crate::remote::{RemoteProvider, RemoteRef, create_provider};
 use crate::ticket::Ticket;
 
 use super::sync_ui::SyncDecision;
 
+/// Apply the user's sync decisions to the ticket and its remote issue.
+///
+/// When anything changes, the `pre_sync` hook runs first (and can abort the
+/// sync) and `post_sync` runs afterwards, both with the provider, remote
+/// reference, and the fields changed on each side.
 pub async fn apply_sync_changes(
     decisions: &[SyncDecision],
     ticket: &Ticket,
     remote_ref: &RemoteRef,
     config: &Config,
 ) -> Result<()> {
+    let (changed_fields, pushed_fields) = sync_fields(decisions);
+    if changed_fields.is_empty() && pushed_fields.is_empty() {
+        return Ok(());
+    }
+    let context = ticket
+        .hook_context()
+        .with_provider(remote_ref.platform().to_string())
+        .with_remote_ref(remote_ref.to_string())
+        .with_changed_fields(changed_fields)
+        .with_pushed_fields(pushed_fields);
+    run_pre_hooks_async(HookEvent::PreSync, &context).await?;
+
     let mut remote_updates_to_apply: Option<crate::remote::IssueUpdates> = None;
 
     for decision in decisions {
@@ -18,6 +36,9 @@ pub async fn apply_sync_changes(
             SyncDecision::UpdateLocal { field, value } => {
                 ticket.update_field(field, value)?;
             }
+            SyncDecision::ClearLocal { field } => {
+                ticket.remove_field(field)?;
+            }
             SyncDecision::UpdateRemote(updates) => {
                 if remote_updates_to_apply.is_none() {
                     remote_updates_to_apply = Some(crate::remote::IssueUpdates::default());
@@ -29,6 +50,15 @@ pub async fn apply_sync_changes(
                 if updates.status.is_some() {
                     remote_updates.status = updates.status.clone();
                 }
+                if updates.status_label.is_some() {
+                    remote_updates.status_label = updates.status_label.clone();
+                }
+                if updates.labels.is_some() {
+                    remote_updates.labels = updates.labels.clone();
+                }
+                if updates.assignee.is_some() {
+                    remote_updates.assignee = updates.assignee.clone();
+                }
             }
             SyncDecision::UpdateLocalTitle { new_content } => {
                 ticket.write(new_content)?;
@@ -42,5 +72,78 @@ pub async fn apply_sync_changes(
         provider.update_issue(remote_ref, remote_updates).await?;
     }
 
+    run_post_hooks_async(HookEvent::PostSync, &context).await;
+
     Ok(())
 }
+
+/// Fields changed by `decisions`: local fields updated from the remote, and
+/// fields pushed to the remote.
+fn sync_fields(decisions: &[SyncDecision]) -> (Vec<String>, Vec<String>) {
+    let mut changed: Vec<String> = Vec::new();
+    let mut pushed: Vec<String> = Vec::new();
+    let add = |fields: &mut Vec<String>, field: &str| {
+        if !fields.iter().any(|f| f == field) {
+            fields.push(field.to_string());
+        }
+    };
+
+    for decision in decisions {
+        match decision {
+            SyncDecision::UpdateLocal { field, .. } | SyncDecision::ClearLocal { field } => {
+                add(&mut changed, field)
+            }
+            SyncDecision::UpdateLocalTitle { .. } => add(&mut changed, "title"),
+            SyncDecision::UpdateRemote(updates) => {
+                if updates.title.is_some() {
+                    add(&mut pushed, "title");
+                }
+                if updates.status.is_some() || updates.status_label.is_some() {
+                    add(&mut pushed, "status");
+                }
+                if updates.labels.is_some() {
+                    add(&mut pushed, "labels");
+                }
+                if updates.assignee.is_some() {
+                    add(&mut pushed, "assignee");
+                }
+            }
+            SyncDecision::Skip => {}
+        }
+    }
+    (changed, pushed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::remote::{IssueUpdates, RemoteStatus};
+
+    #[test]
+    fn test_sync_fields() {
+        let decisions = vec![
+            SyncDecision::UpdateLocal {
+                field: "status".to_string(),
+                value: "complete".to_string(),
+            },
+            SyncDecision::ClearLocal {
+                field: "assignee".to_string(),
+            },
+            SyncDecision::Skip,
+            SyncDecision::UpdateRemote(IssueUpdates {
+                status: Some(RemoteStatus::Open),
+                status_label: Some("in-progress".to_string()),
+                ..Default::default()
+            }),
+            SyncDecision::UpdateRemote(IssueUpdates {
+                title: Some("New title".to_string()),
+                ..Default::default()
+            }),
+        ];
+
+        let (changed, pushed) = sync_fields(&decisions);
+        assert_eq!(changed, vec!["status", "assignee"]);
+        assert_eq!(pushed, vec!["status", "title"]);
+        assert_eq!(sync_fields(&[SyncDecision::Skip]), (vec![], vec![]));
+    }
+}
@@ -1,9 +1,27 @@
-use crate::remote::RemoteIssue;
+use crate::config::Config;
+use crate::remote::{IssueUpdates, Platform, RemoteIssue};
 use crate::types::TicketStatus;
 
 pub struct SyncPlan {
     pub title_diff: Option<TitleDiff>,
     pub status_diff: Option<StatusDiff>,
+    pub labels_diff: Option<LabelsDiff>,
+    pub assignee_diff: Option<AssigneeDiff>,
+}
+
+impl SyncPlan {
+    /// Names of the fields that differ
+    pub fn fields(&self) -> Vec<&'static str> {
+        [
+            ("title", self.title_diff.is_some()),
+            ("status", self.status_diff.is_some()),
+            ("labels", self.labels_diff.is_some()),
+            ("assignee", self.assignee_diff.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(field, differs)| differs.then_some(field))
+        .collect()
+    }
 }
 
 pub struct TitleDiff {
@@ -15,12 +33,39 @@ pub struct StatusDiff {
     pub local: TicketStatus,
     pub remote_status: TicketStatus,
     pub remote_raw: crate::remote::RemoteStatus,
+    /// Local status translated through `status_mapping` (what local-to-remote pushes)
+    pub local_as_remote: IssueUpdates,
+}
+
+/// Labels differ between the ticket and the remote issue.
+///
+/// Both sides are expressed as local label names (after applying the label
+/// mapping), so an unchanged mapped label never shows up as a difference.
+pub struct LabelsDiff {
+    pub local: Vec<String>,
+    pub remote: Vec<String>,
+    /// Local labels translated to remote names (what local-to-remote pushes)
+    pub local_as_remote: Vec<String>,
+}
+
+/// Assignees differ between the ticket and the remote issue.
+///
+/// Both sides are local names (after applying the `users` mapping).
+pub struct AssigneeDiff {
+    pub local: Option<String>,
+    pub remote: Option<String>,
+    /// Local assignee translated to the platform's user (what local-to-remote pushes)
+    pub local_as_remote: Option<String>,
 }
 
 pub fn compute_sync_state(
     local_title: String,
     local_status: TicketStatus,
+    local_labels: &[String],
+    local_assignee: Option<&str>,
     remote_issue: &RemoteIssue,
+    platform: Platform,
+    config: &Config,
 ) -> SyncPlan {
     let title_diff = if local_title != remote_issue.title {
         Some(TitleDiff {
@@ -31,15 +76,47 @@ pub fn compute_sync_state(
         None
     };
 
-    // Use resolve_with_local to avoid lossy round-trip status corruption.
-    // This preserves more-specific local statuses (e.g., InProgress, Cancelled)
-    // when the remote only has coarse-grained states (Open/Closed).
-    let resolved_status = remote_issue.status.resolve_with_local(local_status);
+    // Configured status mappings win; otherwise resolve_with_local avoids lossy
+    // round-trip status corruption by preserving more-specific local statuses
+    // (e.g., InProgress, Cancelled) when the remote only has Open/Closed.
+    let resolved_status =
+        config
+            .status_mapping
+            .resolve_with_local(platform, remote_issue, local_status);
     let status_diff = if local_status != resolved_status {
         Some(StatusDiff {
             local: local_status,
             remote_status: resolved_status,
             remote_raw: remote_issue.status.clone(),
+            local_as_remote: config.status_mapping.to_remote(platform, local_status),
+        })
+    } else {
+        None
+    };
+
+    let mut local = local_labels.to_vec();
+    local.sort();
+    local.dedup();
+    let remote_labels = config
+        .status_mapping
+        .strip_status_labels(platform, &remote_issue.labels);
+    let remote = config.labels.to_local(&remote_labels);
+    let labels_diff = if local != remote {
+        Some(LabelsDiff {
+            local_as_remote: config.labels.to_remote(&local),
+            local,
+            remote,
+        })
+    } else {
+        None
+    };
+
+    let remote_assignee = config.users.to_local(platform, remote_issue);
+    let assignee_diff = if local_assignee != remote_assignee.as_deref() {
+        Some(AssigneeDiff {
+            local: local_assignee.map(str::to_string),
+            remote: remote_assignee,
+            local_as_remote: local_assignee.map(|a| config.users.to_remote(platform, a)),
         })
     } else {
         None
@@ -48,5 +125,7 @@ pub fn compute_sync_state(
     SyncPlan {
         title_diff,
         status_diff,
+        labels_diff,
+        assignee_diff,
     }
 }
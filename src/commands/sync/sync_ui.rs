@@ -1,10 +1,10 @@
 use owo_colors::OwoColorize;
 
 use crate::error::Result;
-use crate::remote::{IssueUpdates, RemoteRef, RemoteStatus};
+use crate::remote::{IssueUpdates, RemoteRef};
 use crate::ticket::update_title;
 
-use super::sync_strategy::SyncPlan;
+use super::sync_strategy::{AssigneeDiff, LabelsDiff, StatusDiff, SyncPlan, TitleDiff};
 use crate::commands::interactive::prompt_choice;
 
 #[derive(Debug, Clone, Copy)]
@@ -17,6 +17,7 @@ pub enum SyncChoice {
 #[derive(Debug, Clone)]
 pub enum SyncDecision {
     UpdateLocal { field: String, value: String },
+    ClearLocal { field: String },
     UpdateRemote(IssueUpdates),
     Skip,
     UpdateLocalTitle { new_content: String },
@@ -60,8 +61,7 @@ pub fn prompt_user_for_action(
                 changes_made = true;
             }
             SyncChoice::RemoteToLocal => {
-                let new_content = update_title(local_content, &diff.remote);
-                decisions.push(SyncDecision::UpdateLocalTitle { new_content });
+                decisions.push(pull_title(diff, local_content));
                 println!("  -> Will update local title");
                 changes_made = true;
             }
@@ -77,22 +77,69 @@ pub fn prompt_user_for_action(
         println!("  Local:  {}", diff.local);
         println!("  Remote: {} ({})", diff.remote_status, diff.remote_raw);
 
+        match prompt_sync_choice()? {
+            SyncChoice::LocalToRemote => {
+                decisions.push(SyncDecision::UpdateRemote(diff.local_as_remote.clone()));
+                println!("  -> Will update remote status");
+                changes_made = true;
+            }
+            SyncChoice::RemoteToLocal => {
+                decisions.push(pull_status(diff));
+                println!("  -> Will update local status");
+                changes_made = true;
+            }
+            SyncChoice::Skip => {
+                decisions.push(SyncDecision::Skip);
+                println!("  -> Skipped");
+            }
+        }
+    }
+
+    if let Some(ref diff) = sync_plan.labels_diff {
+        println!("\n{}", "Labels differ:".yellow());
+        println!("  Local:  {}", format_labels(&diff.local));
+        println!("  Remote: {}", format_labels(&diff.remote));
+
         match prompt_sync_choice()? {
             SyncChoice::LocalToRemote => {
                 let updates = IssueUpdates {
-                    status: Some(RemoteStatus::from_ticket_status(diff.local)),
+                    labels: Some(diff.local_as_remote.clone()),
                     ..Default::default()
                 };
                 decisions.push(SyncDecision::UpdateRemote(updates));
-                println!("  -> Will update remote status");
+                println!("  -> Will update remote labels");
                 changes_made = true;
             }
             SyncChoice::RemoteToLocal => {
-                decisions.push(SyncDecision::UpdateLocal {
-                    field: "status".to_string(),
-                    value: diff.remote_status.to_string(),
-                });
-                println!("  -> Will update local status");
+                decisions.push(pull_labels(diff)?);
+                println!("  -> Will update local labels");
+                changes_made = true;
+            }
+            SyncChoice::Skip => {
+                decisions.push(SyncDecision::Skip);
+                println!("  -> Skipped");
+            }
+        }
+    }
+
+    if let Some(ref diff) = sync_plan.assignee_diff {
+        println!("\n{}", "Assignee differs:".yellow());
+        println!("  Local:  {}", format_assignee(diff.local.as_deref()));
+        println!("  Remote: {}", format_assignee(diff.remote.as_deref()));
+
+        match prompt_sync_choice()? {
+            SyncChoice::LocalToRemote => {
+                let updates = IssueUpdates {
+                    assignee: Some(diff.local_as_remote.clone().unwrap_or_default()),
+                    ..Default::default()
+                };
+                decisions.push(SyncDecision::UpdateRemote(updates));
+                println!("  -> Will update remote assignee");
+                changes_made = true;
+            }
+            SyncChoice::RemoteToLocal => {
+                decisions.push(pull_assignee(diff));
+                println!("  -> Will update local assignee");
                 changes_made = true;
             }
             SyncChoice::Skip => {
@@ -105,6 +152,128 @@ pub fn prompt_user_for_action(
     Ok((decisions, changes_made))
 }
 
+/// Decisions that take every differing field from the remote, for tickets
+/// whose remote is in mirror mode.
+pub fn pull_decisions(sync_plan: &SyncPlan, local_content: &str) -> Result<Vec<SyncDecision>> {
+    let mut decisions = Vec::new();
+    if let Some(ref diff) = sync_plan.title_diff {
+        decisions.push(pull_title(diff, local_content));
+    }
+    if let Some(ref diff) = sync_plan.status_diff {
+        decisions.push(pull_status(diff));
+    }
+    if let Some(ref diff) = sync_plan.labels_diff {
+        decisions.push(pull_labels(diff)?);
+    }
+    if let Some(ref diff) = sync_plan.assignee_diff {
+        decisions.push(pull_assignee(diff));
+    }
+    Ok(decisions)
+}
+
+fn pull_title(diff: &TitleDiff, local_content: &str) -> SyncDecision {
+    SyncDecision::UpdateLocalTitle {
+        new_content: update_title(local_content, &diff.remote),
+    }
+}
+
+fn pull_status(diff: &StatusDiff) -> SyncDecision {
+    SyncDecision::UpdateLocal {
+        field: "status".to_string(),
+        value: diff.remote_status.to_string(),
+    }
+}
+
+fn pull_labels(diff: &LabelsDiff) -> Result<SyncDecision> {
+    Ok(SyncDecision::UpdateLocal {
+        field: "labels".to_string(),
+        value: serde_json::to_string(&diff.remote)?,
+    })
+}
+
+fn pull_assignee(diff: &AssigneeDiff) -> SyncDecision {
+    match &diff.remote {
+        Some(remote) => SyncDecision::UpdateLocal {
+            field: "assignee".to_string(),
+            value: remote.clone(),
+        },
+        None => SyncDecision::ClearLocal {
+            field: "assignee".to_string(),
+        },
+    }
+}
+
+fn format_assignee(assignee: Option<&str>) -> String {
+    assignee.unwrap_or("(none)").to_string()
+}
+
+fn format_labels(labels: &[String]) -> String {
+    if labels.is_empty() {
+        "(none)".to_string()
+    } else {
+        labels.join(", ")
+    }
+}
+
+/// Render a colored field-by-field diff of a sync plan for `--dry-run`.
+///
+/// Each differing field shows the local value (what local-to-remote would push)
+/// and the remote value (what remote-to-local would write to the ticket).
+pub fn format_sync_diff(ticket_id: &str, remote_ref: &RemoteRef, sync_plan: &SyncPlan) -> String {
+    let mut lines = vec![format!(
+        "{} {} <-> {} {}",
+        "Dry run:".bold(),
+        ticket_id.cyan(),
+        remote_ref.to_string().green(),
+        "(nothing will be written)".dimmed()
+    )];
+    let mut count = 0;
+
+    if let Some(ref diff) = sync_plan.title_diff {
+        lines.push(format!("\n{}", "title".yellow()));
+        lines.push(format!("  {} {}", "- local: ".red(), diff.local.red()));
+        lines.push(format!("  {} {}", "+ remote:".green(), diff.remote.green()));
+        count += 1;
+    }
+
+    if let Some(ref diff) = sync_plan.status_diff {
+        let local = diff.local.to_string();
+        let remote = format!("{} ({})", diff.remote_status, diff.remote_raw);
+        lines.push(format!("\n{}", "status".yellow()));
+        lines.push(format!("  {} {}", "- local: ".red(), local.red()));
+        lines.push(format!("  {} {}", "+ remote:".green(), remote.green()));
+        count += 1;
+    }
+
+    if let Some(ref diff) = sync_plan.labels_diff {
+        let local = format_labels(&diff.local);
+        let remote = format_labels(&diff.remote);
+        lines.push(format!("\n{}", "labels".yellow()));
+        lines.push(format!("  {} {}", "- local: ".red(), local.red()));
+        lines.push(format!("  {} {}", "+ remote:".green(), remote.green()));
+        count += 1;
+    }
+
+    if let Some(ref diff) = sync_plan.assignee_diff {
+        let local = format_assignee(diff.local.as_deref());
+        let remote = format_assignee(diff.remote.as_deref());
+        lines.push(format!("\n{}", "assignee".yellow()));
+        lines.push(format!("  {} {}", "- local: ".red(), local.red()));
+        lines.push(format!("  {} {}", "+ remote:".green(), remote.green()));
+        count += 1;
+    }
+
+    if count == 0 {
+        lines.push(format!("\n{}", "Already in sync.".green()));
+    } else {
+        lines.push(format!(
+            "\n{count} field(s) differ. Run without --dry-run to choose a direction for each."
+        ));
+    }
+
+    lines.join("\n")
+}
+
 pub fn generate_sync_json(
     ticket_id: String,
     remote_ref: &RemoteRef,
@@ -129,6 +298,22 @@ pub fn generate_sync_json(
         }));
     }
 
+    if let Some(ref diff) = sync_plan.labels_diff {
+        differences.push(json!({
+            "field": "labels",
+            "local": diff.local,
+            "remote": diff.remote,
+        }));
+    }
+
+    if let Some(ref diff) = sync_plan.assignee_diff {
+        differences.push(json!({
+            "field": "assignee",
+            "local": diff.local,
+            "remote": diff.remote,
+        }));
+    }
+
     json!({
         "id": ticket_id,
         "remote_ref": remote_ref.to_string(),
@@ -136,3 +321,68 @@ pub fn generate_sync_json(
         "differences": differences,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::sync::sync_strategy::{AssigneeDiff, LabelsDiff, StatusDiff, TitleDiff};
+    use crate::remote::RemoteStatus;
+    use crate::types::TicketStatus;
+
+    fn github_ref() -> RemoteRef {
+        RemoteRef::GitHub {
+            owner: "org".to_string(),
+            repo: "repo".to_string(),
+            issue_number: 7,
+        }
+    }
+
+    #[test]
+    fn test_format_sync_diff_lists_each_field() {
+        let plan = SyncPlan {
+            title_diff: Some(TitleDiff {
+                local: "Local title".to_string(),
+                remote: "Remote title".to_string(),
+            }),
+            status_diff: Some(StatusDiff {
+                local: TicketStatus::InProgress,
+                remote_status: TicketStatus::Complete,
+                remote_raw: RemoteStatus::Closed,
+                local_as_remote: IssueUpdates::default(),
+            }),
+            labels_diff: Some(LabelsDiff {
+                local: vec!["bug".to_string()],
+                remote: vec![],
+                local_as_remote: vec!["type: bug".to_string()],
+            }),
+            assignee_diff: Some(AssigneeDiff {
+                local: None,
+                remote: Some("alice".to_string()),
+                local_as_remote: None,
+            }),
+        };
+
+        let text = format_sync_diff("j-a1b2", &github_ref(), &plan);
+        assert!(text.contains("Local title"));
+        assert!(text.contains("Remote title"));
+        assert!(text.contains("in_progress"));
+        assert!(text.contains("complete (closed)"));
+        assert!(text.contains("(none)"));
+        assert!(text.contains("alice"));
+        assert!(text.contains("4 field(s) differ"));
+    }
+
+    #[test]
+    fn test_format_sync_diff_in_sync() {
+        let plan = SyncPlan {
+            title_diff: None,
+            status_diff: None,
+            labels_diff: None,
+            assignee_diff: None,
+        };
+
+        let text = format_sync_diff("j-a1b2", &github_ref(), &plan);
+        assert!(text.contains("Already in sync."));
+        assert!(!text.contains("differ"));
+    }
+}
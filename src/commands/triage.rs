@@ -0,0 +1,455 @@
+//! Triage command: review tickets that have not been triaged yet.
+//!
+//! Without flags this lists open, untriaged tickets, oldest first. With
+//! `--suggest` (requires the `llm` feature) each ticket is sent to the
+//! configured LLM along with the most similar already-triaged tickets, and
+//! the proposed type, priority, size, and assignee are offered for review.
+//! `--auto` applies every suggestion without prompting.
+
+use serde_json::json;
+
+use super::{CommandOutput, ticket_to_json};
+use crate::cli::OutputOptions;
+use crate::display::{FormatOptions, format_ticket_line};
+use crate::error::Result;
+use crate::ticket::get_all_tickets;
+use crate::types::TicketMetadata;
+
+/// List untriaged tickets, or suggest triage fields for them with `suggest`
+pub async fn cmd_triage(
+    suggest: bool,
+    auto: bool,
+    limit: Option<usize>,
+    output: OutputOptions,
+) -> Result<()> {
+    let tickets = get_all_tickets().await?.items;
+    let mut untriaged: Vec<TicketMetadata> = tickets
+        .iter()
+        .filter(|t| is_untriaged(t))
+        .cloned()
+        .collect();
+    untriaged.sort_by(|a, b| a.created.cmp(&b.created).then_with(|| a.id.cmp(&b.id)));
+    if let Some(limit) = limit {
+        untriaged.truncate(limit);
+    }
+
+    if suggest {
+        return suggest::run(&tickets, &untriaged, auto, output).await;
+    }
+
+    let text = if untriaged.is_empty() {
+        "No untriaged tickets.".to_string()
+    } else {
+        untriaged
+            .iter()
+            .map(|t| {
+                format_ticket_line(
+                    t,
+                    FormatOptions {
+                        show_priority: true,
+                        suffix: None,
+                    },
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    CommandOutput::new(json!(
+        untriaged.iter().map(ticket_to_json).collect::<Vec<_>>()
+    ))
+    .with_text(text)
+    .print(output)
+}
+
+/// Open tickets that nobody has marked as triaged.
+fn is_untriaged(ticket: &TicketMetadata) -> bool {
+    ticket.triaged != Some(true) && !ticket.status.unwrap_or_default().is_terminal()
+}
+
+#[cfg(not(feature = "llm"))]
+mod suggest {
+    use super::*;
+    use crate::error::JanusError;
+
+    pub async fn run(
+        _tickets: &[TicketMetadata],
+        _untriaged: &[TicketMetadata],
+        _auto: bool,
+        _output: OutputOptions,
+    ) -> Result<()> {
+        Err(JanusError::Config(
+            "`janus triage --suggest` requires janus to be built with the `llm` feature"
+                .to_string(),
+        ))
+    }
+}
+
+#[cfg(feature = "llm")]
+mod suggest {
+    use std::collections::BTreeSet;
+    use std::str::FromStr;
+
+    use serde_json::Value;
+
+    use super::*;
+    use crate::commands::interactive::{prompt_choice, prompt_text};
+    use crate::config::Config;
+    use crate::store::get_or_init_store;
+    use crate::ticket::Ticket;
+    use crate::types::{TicketPriority, TicketSize, TicketType};
+    use crate::utils::is_stdin_tty;
+
+    /// Number of similar triaged tickets shown to the LLM as examples
+    const SIMILAR_LIMIT: usize = 5;
+
+    const SYSTEM_PROMPT: &str = "You triage tickets in an issue tracker. Given a ticket, \
+similar tickets that were already triaged, and the known assignees, propose the ticket's \
+type (bug, feature, task, epic, chore), priority (0 = most urgent to 4), size (xsmall, small, \
+medium, large, xlarge), and assignee (one of the known assignees, or null). Follow the \
+conventions of the similar tickets. Reply with only a JSON object with the keys \"type\", \
+\"priority\", \"size\", \"assignee\", and \"reason\" (one short sentence).";
+
+    /// Triage fields proposed for one ticket. Values the LLM got wrong are
+    /// dropped rather than guessed.
+    #[derive(Debug, Default, Clone, PartialEq)]
+    pub(super) struct Suggestion {
+        pub ticket_type: Option<TicketType>,
+        pub priority: Option<TicketPriority>,
+        pub size: Option<TicketSize>,
+        pub assignee: Option<String>,
+        pub reason: Option<String>,
+    }
+
+    impl Suggestion {
+        /// Parse the JSON object in an LLM reply, tolerating code fences and
+        /// surrounding prose.
+        pub(super) fn parse(reply: &str) -> Option<Self> {
+            let start = reply.find('{')?;
+            let end = reply.rfind('}')?;
+            let value: Value = serde_json::from_str(reply.get(start..=end)?).ok()?;
+            let text = |key: &str| match value.get(key)? {
+                Value::String(s) => Some(s.trim().to_string()).filter(|s| !s.is_empty()),
+                Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            };
+
+            Some(Suggestion {
+                ticket_type: text("type").and_then(|s| s.parse().ok()),
+                priority: text("priority")
+                    .and_then(|s| s.trim_start_matches(['P', 'p']).parse().ok()),
+                size: text("size").and_then(|s| s.parse().ok()),
+                assignee: text("assignee").filter(|s| s != "null"),
+                reason: text("reason"),
+            })
+        }
+
+        /// Frontmatter updates needed to apply this suggestion to `ticket`.
+        pub(super) fn changes(&self, ticket: &TicketMetadata) -> Vec<(&'static str, String)> {
+            let mut changes = Vec::new();
+            if let Some(t) = self.ticket_type
+                && ticket.ticket_type != Some(t)
+            {
+                changes.push(("type", t.to_string()));
+            }
+            if let Some(p) = self.priority
+                && ticket.priority != Some(p)
+            {
+                changes.push(("priority", p.to_string()));
+            }
+            if let Some(s) = self.size
+                && ticket.size != Some(s)
+            {
+                changes.push(("size", s.to_string()));
+            }
+            if let Some(a) = &self.assignee
+                && ticket.assignee.as_ref() != Some(a)
+            {
+                changes.push(("assignee", a.clone()));
+            }
+            changes
+        }
+
+        fn to_json(&self) -> Value {
+            json!({
+                "type": self.ticket_type.map(|t| t.to_string()),
+                "priority": self.priority,
+                "size": self.size.map(|s| s.to_string()),
+                "assignee": self.assignee,
+                "reason": self.reason,
+            })
+        }
+    }
+
+    pub async fn run(
+        tickets: &[TicketMetadata],
+        untriaged: &[TicketMetadata],
+        auto: bool,
+        output: OutputOptions,
+    ) -> Result<()> {
+        let config = Config::load()?;
+        let interactive = !auto && !output.json && is_stdin_tty();
+        let assignees: BTreeSet<&str> = tickets
+            .iter()
+            .filter_map(|t| t.assignee.as_deref())
+            .collect();
+
+        let mut results = Vec::new();
+        let mut applied_count = 0;
+
+        for ticket in untriaged {
+            let Some(id) = ticket.id.as_deref() else {
+                continue;
+            };
+            let similar = similar_triaged(&config, ticket).await;
+            let prompt = build_prompt(ticket, &similar, &assignees);
+            let reply = crate::llm::complete(&config, SYSTEM_PROMPT, &prompt).await?;
+            let Some(mut suggestion) = Suggestion::parse(&reply) else {
+                eprintln!("Warning: could not parse a suggestion for {id}, skipping");
+                continue;
+            };
+
+            let apply = if auto {
+                true
+            } else if interactive {
+                match review(ticket, &mut suggestion)? {
+                    Review::Apply => true,
+                    Review::Skip => false,
+                    Review::Quit => break,
+                }
+            } else {
+                false
+            };
+
+            if apply {
+                let handle = Ticket::find(id).await?;
+                for (field, value) in suggestion.changes(ticket) {
+                    handle.update_field(field, &value)?;
+                }
+                handle.update_field("triaged", "true")?;
+                applied_count += 1;
+            }
+
+            results.push(json!({
+                "id": id,
+                "title": ticket.title,
+                "suggestion": suggestion.to_json(),
+                "similar": similar.iter().filter_map(|t| t.id.as_deref()).collect::<Vec<_>>(),
+                "applied": apply,
+            }));
+        }
+
+        let text = if untriaged.is_empty() {
+            "No untriaged tickets.".to_string()
+        } else if interactive || auto {
+            format!("Triaged {applied_count} of {} tickets", untriaged.len())
+        } else {
+            // Non-interactive without --auto: report the suggestions only
+            results
+                .iter()
+                .map(|r| {
+                    format!(
+                        "{}: {}",
+                        r["id"].as_str().unwrap_or(""),
+                        describe(&r["suggestion"])
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        CommandOutput::new(Value::Array(results))
+            .with_text(text)
+            .print(output)
+    }
+
+    /// Triaged tickets most similar to `ticket`, when semantic search is
+    /// available. Similarity only improves the prompt, so failures are ignored.
+    async fn similar_triaged(config: &Config, ticket: &TicketMetadata) -> Vec<TicketMetadata> {
+        if !config.semantic_search_enabled() {
+            return Vec::new();
+        }
+        let Ok(store) = get_or_init_store().await else {
+            return Vec::new();
+        };
+        if store.embedding_coverage().0 == 0 {
+            return Vec::new();
+        }
+        let text = format!(
+            "{}\n\n{}",
+            ticket.title.as_deref().unwrap_or(""),
+            ticket.body.as_deref().unwrap_or("")
+        );
+        let Ok(embedding) = crate::embedding::model::generate_embedding(&text).await else {
+            return Vec::new();
+        };
+
+        store
+            .semantic_search(&embedding, SIMILAR_LIMIT * 3)
+            .into_iter()
+            .map(|r| r.ticket)
+            .filter(|t| t.triaged == Some(true) && t.id != ticket.id)
+            .take(SIMILAR_LIMIT)
+            .collect()
+    }
+
+    fn build_prompt(
+        ticket: &TicketMetadata,
+        similar: &[TicketMetadata],
+        assignees: &BTreeSet<&str>,
+    ) -> String {
+        let mut prompt = format!(
+            "Ticket: {}\n\n{}\n",
+            ticket.title.as_deref().unwrap_or(""),
+            ticket.body.as_deref().unwrap_or("").trim()
+        );
+
+        if !similar.is_empty() {
+            prompt.push_str("\nSimilar triaged tickets:\n");
+            for t in similar {
+                prompt.push_str(&format!(
+                    "- {} (type={}, priority={}, size={}, assignee={})\n",
+                    t.title.as_deref().unwrap_or(""),
+                    t.ticket_type.unwrap_or_default(),
+                    t.priority.unwrap_or_default(),
+                    t.size.map(|s| s.to_string()).as_deref().unwrap_or("none"),
+                    t.assignee.as_deref().unwrap_or("none"),
+                ));
+            }
+        }
+
+        if !assignees.is_empty() {
+            let names: Vec<&str> = assignees.iter().copied().collect();
+            prompt.push_str(&format!("\nKnown assignees: {}\n", names.join(", ")));
+        }
+        prompt
+    }
+
+    enum Review {
+        Apply,
+        Skip,
+        Quit,
+    }
+
+    /// Show a suggestion and let the user accept, edit, or skip it.
+    fn review(ticket: &TicketMetadata, suggestion: &mut Suggestion) -> Result<Review> {
+        println!(
+            "\n{}",
+            format_ticket_line(
+                ticket,
+                FormatOptions {
+                    show_priority: true,
+                    suffix: None,
+                },
+            )
+        );
+        println!("  Suggested: {}", describe(&suggestion.to_json()));
+
+        let choices = [
+            ("a", "Accept"),
+            ("m", "Modify"),
+            ("s", "Skip"),
+            ("q", "Quit"),
+        ];
+        match prompt_choice("  [a]ccept, [m]odify, [s]kip, [q]uit", &choices, Some("a"))? {
+            0 => Ok(Review::Apply),
+            1 => {
+                modify(suggestion)?;
+                Ok(Review::Apply)
+            }
+            2 => Ok(Review::Skip),
+            _ => Ok(Review::Quit),
+        }
+    }
+
+    /// Prompt for each field, defaulting to the suggested value. An empty
+    /// answer with no suggestion leaves the field unchanged.
+    fn modify(suggestion: &mut Suggestion) -> Result<()> {
+        suggestion.ticket_type = prompt_field("  Type", suggestion.ticket_type)?;
+        suggestion.priority = prompt_field("  Priority", suggestion.priority)?;
+        suggestion.size = prompt_field("  Size", suggestion.size)?;
+        let assignee = prompt_text("  Assignee", suggestion.assignee.as_deref())?;
+        suggestion.assignee = Some(assignee).filter(|a| !a.is_empty());
+        Ok(())
+    }
+
+    fn prompt_field<T>(label: &str, current: Option<T>) -> Result<Option<T>>
+    where
+        T: FromStr + ToString,
+    {
+        loop {
+            let default = current.as_ref().map(T::to_string);
+            let input = prompt_text(label, default.as_deref())?;
+            if input.is_empty() {
+                return Ok(None);
+            }
+            match input.parse() {
+                Ok(value) => return Ok(Some(value)),
+                Err(_) => println!("  Invalid value '{input}'"),
+            }
+        }
+    }
+
+    /// One-line description of a suggestion's JSON form.
+    fn describe(suggestion: &Value) -> String {
+        let field = |key: &str| match &suggestion[key] {
+            Value::Null => "-".to_string(),
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        let mut text = format!(
+            "type={} priority={} size={} assignee={}",
+            field("type"),
+            field("priority"),
+            field("size"),
+            field("assignee")
+        );
+        if let Some(reason) = suggestion["reason"].as_str() {
+            text.push_str(&format!(" ({reason})"));
+        }
+        text
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_suggestion_parse() {
+            let reply = "```json\n{\"type\": \"bug\", \"priority\": \"P1\", \"size\": \"s\", \"assignee\": null, \"reason\": \"Crash on login\"}\n```";
+            let suggestion = Suggestion::parse(reply).unwrap();
+            assert_eq!(suggestion.ticket_type, Some(TicketType::Bug));
+            assert_eq!(suggestion.priority, Some(TicketPriority::P1));
+            assert_eq!(suggestion.size, Some(TicketSize::Small));
+            assert_eq!(suggestion.assignee, None);
+
+            // Invalid values are dropped
+            let suggestion = Suggestion::parse("{\"type\": \"story\", \"priority\": 9}").unwrap();
+            assert_eq!(suggestion, Suggestion::default());
+
+            assert!(Suggestion::parse("no json here").is_none());
+        }
+
+        #[test]
+        fn test_suggestion_changes() {
+            let ticket = TicketMetadata {
+                ticket_type: Some(TicketType::Bug),
+                priority: Some(TicketPriority::P2),
+                ..Default::default()
+            };
+            let suggestion = Suggestion {
+                ticket_type: Some(TicketType::Bug),
+                priority: Some(TicketPriority::P0),
+                assignee: Some("alice".to_string()),
+                ..Default::default()
+            };
+            assert_eq!(
+                suggestion.changes(&ticket),
+                vec![
+                    ("priority", "0".to_string()),
+                    ("assignee", "alice".to_string())
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Unified TUI command (`janus tui`)
+//!
+//! Runs the issue browser, kanban board, plan HUD, and remote view as tabs of
+//! one application over a single store and watcher.
+
+use iocraft::prelude::*;
+
+use crate::archive::sweep_completed_tickets;
+use crate::error::{JanusError, Result};
+use crate::events::Actor;
+use crate::plan::{Plan, compute_plan_status, get_all_plans};
+use crate::store::{get_or_init_store, start_watching, stop_watching};
+use crate::ticket::build_ticket_map;
+use crate::tui::TuiApp;
+use crate::tui::app::set_tabbed;
+use crate::types::TicketStatus;
+
+/// Launch the tabbed TUI
+///
+/// # Arguments
+/// * `plan` - Plan for the Plan tab (defaults to the plan in progress)
+/// * `provider` - Provider for the Remote tab
+pub async fn cmd_tui(plan: Option<&str>, provider: Option<&str>) -> Result<()> {
+    // Resolve the plan before entering fullscreen so errors display cleanly
+    let plan_id = match plan {
+        Some(plan) => Some(Plan::find(plan).await?.id),
+        None => plan_in_progress().await?,
+    };
+
+    // Initialize store and start filesystem watcher for live updates
+    let store = get_or_init_store().await?;
+    let _ = start_watching(store).await;
+
+    // Same auto-archive sweep as `janus board`, so the board tab matches it
+    if !crate::read_only::is_enabled() {
+        let tickets = store.get_all_tickets();
+        let _ = sweep_completed_tickets(&tickets, Actor::AutoArchive).await;
+    }
+
+    set_tabbed(true);
+    let result = element!(TuiApp(
+        plan_id: plan_id,
+        provider: provider.map(|p| p.to_string()),
+    ))
+    .fullscreen()
+    .await
+    .map_err(|e| JanusError::TuiError(format!("{e}")));
+    set_tabbed(false);
+
+    // Stop the watcher to release OS-level file watch handles (FSEvents
+    // streams on macOS, inotify descriptors on Linux). Without this,
+    // resources accumulate across process invocations.
+    stop_watching();
+
+    result
+}
+
+/// The plan in progress with the lowest ID, if any
+async fn plan_in_progress() -> Result<Option<String>> {
+    let plans = get_all_plans().await?.items;
+    let ticket_map = build_ticket_map().await?;
+
+    Ok(plans
+        .iter()
+        .filter(|plan| compute_plan_status(plan, &ticket_map).status == TicketStatus::InProgress)
+        .filter_map(|plan| plan.id.as_deref().map(String::from))
+        .min())
+}
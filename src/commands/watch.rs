@@ -0,0 +1,69 @@
+//! Live re-rendering of list commands (`janus ls --watch`, `janus ready --watch`)
+
+use std::future::Future;
+use std::io::{Write, stdout};
+
+use jiff::Zoned;
+use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+
+use crate::display::{dim, disable_pager};
+use crate::error::Result;
+use crate::store::{get_or_init_store, start_watching, stop_watching};
+
+/// Render the output of a list command, then render it again every time the
+/// tickets in `.janus/` change, until the process is interrupted.
+///
+/// The screen is cleared before each render when stdout is a terminal, so the
+/// output can be kept open in a pane. An error on the first render is
+/// returned; later errors are printed and watching continues, since they are
+/// usually caused by a file caught mid-write.
+pub async fn watch_output<F, Fut>(command: &str, mut render: F) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    disable_pager();
+    let store = get_or_init_store().await?;
+    let mut rx = start_watching(store).await?;
+    let is_tty = atty::is(atty::Stream::Stdout);
+
+    let mut first = true;
+    loop {
+        if is_tty {
+            print!("\x1b[2J\x1b[H");
+        }
+        println!(
+            "{}\n",
+            dim(&format!(
+                "{command} · updated {} · Ctrl-C to stop",
+                Zoned::now().strftime("%H:%M:%S")
+            ))
+        );
+        match render().await {
+            Ok(()) => {}
+            Err(e) if first => {
+                stop_watching();
+                return Err(e);
+            }
+            Err(e) => eprintln!("Error: {e}"),
+        }
+        stdout().flush()?;
+        first = false;
+
+        // Wait for the next change, then skip any already queued behind it
+        // so a burst of writes renders once
+        match rx.recv().await {
+            Ok(_) | Err(RecvError::Lagged(_)) => {}
+            Err(RecvError::Closed) => break,
+        }
+        loop {
+            match rx.try_recv() {
+                Ok(_) | Err(TryRecvError::Lagged(_)) => {}
+                Err(_) => break,
+            }
+        }
+    }
+
+    stop_watching();
+    Ok(())
+}
@@ -0,0 +1,307 @@
+//! Git worktrees for tickets (`janus worktree`)
+//!
+//! `janus worktree start` gives a ticket its own branch and worktree, so
+//! several agents can work on different tickets of the same repository in
+//! parallel. The worktree path is recorded in the ticket's `worktree` field;
+//! `janus worktree cleanup` removes worktrees once their tickets are closed.
+//! Branches are left in place, since they may still need to be merged.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+
+use super::CommandOutput;
+use crate::cli::OutputOptions;
+use crate::error::{JanusError, Result};
+use crate::ticket::{Ticket, get_all_tickets};
+use crate::types::TicketMetadata;
+use crate::utils::git;
+
+/// Longest branch name generated from a ticket title, before the ID prefix
+const MAX_SLUG_LEN: usize = 40;
+
+/// Default branch for a ticket: its ID followed by its title, lowercased and
+/// with runs of other characters replaced by `-`
+fn branch_name(id: &str, title: Option<&str>) -> String {
+    let mut slug = String::new();
+    for word in title
+        .unwrap_or_default()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        if !slug.is_empty() && slug.len() + word.len() + 1 > MAX_SLUG_LEN {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word.to_ascii_lowercase());
+    }
+    slug.truncate(MAX_SLUG_LEN);
+    if slug.is_empty() {
+        id.to_string()
+    } else {
+        format!("{id}-{slug}")
+    }
+}
+
+/// Default worktree location: next to the repository, named after it and the
+/// ticket (`../app-j-a1b2`)
+fn default_worktree_path(id: &str) -> Result<PathBuf> {
+    let root = PathBuf::from(git(None, &["rev-parse", "--show-toplevel"])?.trim());
+    let name = root
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "worktree".to_string());
+    let parent = root.parent().unwrap_or(&root);
+    Ok(parent.join(format!("{name}-{id}")))
+}
+
+/// Branch checked out in each worktree, keyed by path
+fn worktree_branches() -> HashMap<PathBuf, String> {
+    let Ok(list) = git(None, &["worktree", "list", "--porcelain"]) else {
+        return HashMap::new();
+    };
+    let mut branches = HashMap::new();
+    let mut path = None;
+    for line in list.lines() {
+        if let Some(p) = line.strip_prefix("worktree ") {
+            path = Some(PathBuf::from(p));
+        } else if let Some(branch) = line.strip_prefix("branch ")
+            && let Some(path) = path.take()
+        {
+            let branch = branch.strip_prefix("refs/heads/").unwrap_or(branch);
+            branches.insert(path, branch.to_string());
+        }
+    }
+    branches
+}
+
+/// Look up a worktree's branch, tolerating symlinks in either path
+fn branch_of<'a>(branches: &'a HashMap<PathBuf, String>, path: &Path) -> Option<&'a str> {
+    let canonical = path.canonicalize().ok();
+    branches
+        .iter()
+        .find(|(p, _)| {
+            p.as_path() == path || (canonical.is_some() && p.canonicalize().ok() == canonical)
+        })
+        .map(|(_, branch)| branch.as_str())
+}
+
+/// Create a branch and worktree for a ticket and record the worktree path
+pub async fn cmd_worktree_start(
+    id: &str,
+    path: Option<&str>,
+    branch: Option<&str>,
+    base: Option<&str>,
+    output: OutputOptions,
+) -> Result<()> {
+    let (ticket, metadata) = Ticket::find_and_read(id).await?;
+    if let Some(existing) = metadata.worktree.as_deref()
+        && Path::new(existing).exists()
+    {
+        return Err(JanusError::WorktreeExists {
+            id: ticket.id,
+            path: existing.to_string(),
+        });
+    }
+
+    let path = match path {
+        Some(path) => std::path::absolute(path)?,
+        None => default_worktree_path(&ticket.id)?,
+    };
+    let branch = branch.map_or_else(
+        || branch_name(&ticket.id, metadata.title.as_deref()),
+        str::to_string,
+    );
+    let path_str = path.to_string_lossy().into_owned();
+    let mut args = vec!["worktree", "add", "-b", &branch, &path_str];
+    if let Some(base) = base {
+        args.push(base);
+    }
+    git(None, &args)?;
+    ticket.update_field("worktree", &path_str)?;
+
+    CommandOutput::new(json!({
+        "id": ticket.id,
+        "action": "worktree_started",
+        "worktree": path_str,
+        "branch": branch,
+    }))
+    .with_text(format!(
+        "Created worktree for {} at {path_str} on branch {branch}",
+        ticket.id
+    ))
+    .print(output)
+}
+
+/// Tickets with a recorded worktree, ordered by ID
+async fn tickets_with_worktrees() -> Result<Vec<TicketMetadata>> {
+    let mut tickets: Vec<TicketMetadata> = get_all_tickets()
+        .await?
+        .items
+        .into_iter()
+        .filter(|t| t.worktree.is_some())
+        .collect();
+    tickets.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(tickets)
+}
+
+/// List tickets with worktrees, with each worktree's branch
+pub async fn cmd_worktree_ls(output: OutputOptions) -> Result<()> {
+    let tickets = tickets_with_worktrees().await?;
+    let branches = worktree_branches();
+
+    let mut json_rows = Vec::with_capacity(tickets.len());
+    let mut lines = Vec::with_capacity(tickets.len());
+    for ticket in &tickets {
+        let id = ticket.id.as_deref().unwrap_or_default();
+        let path = ticket.worktree.as_deref().unwrap_or_default();
+        let exists = Path::new(path).exists();
+        let branch = branch_of(&branches, Path::new(path));
+        let status = ticket.status.unwrap_or_default();
+        json_rows.push(json!({
+            "id": id,
+            "title": ticket.title,
+            "status": status.to_string(),
+            "worktree": path,
+            "branch": branch,
+            "exists": exists,
+        }));
+        let mut line = format!("{id}  [{status}]  {path}");
+        if let Some(branch) = branch {
+            line.push_str(&format!("  ({branch})"));
+        }
+        if !exists {
+            line.push_str("  [missing]");
+        }
+        lines.push(line);
+    }
+
+    let text = if lines.is_empty() {
+        "No tickets have worktrees.".to_string()
+    } else {
+        lines.join("\n")
+    };
+    CommandOutput::new(serde_json::Value::Array(json_rows))
+        .with_text(text)
+        .print(output)
+}
+
+/// Remove one ticket's worktree, if it still exists, and clear its field
+async fn remove_worktree(id: &str, path: &str, force: bool) -> Result<()> {
+    if Path::new(path).exists() {
+        let mut args = vec!["worktree", "remove"];
+        if force {
+            args.push("--force");
+        }
+        args.push(path);
+        git(None, &args)?;
+    }
+    Ticket::find(id).await?.remove_field("worktree")
+}
+
+/// Remove the worktrees of closed tickets, and forget worktrees that no
+/// longer exist
+pub async fn cmd_worktree_cleanup(dry_run: bool, force: bool, output: OutputOptions) -> Result<()> {
+    let candidates: Vec<(String, String)> = tickets_with_worktrees()
+        .await?
+        .into_iter()
+        .filter_map(|t| {
+            let path = t.worktree?;
+            let closed = t.status.unwrap_or_default().is_terminal();
+            if !closed && Path::new(&path).exists() {
+                return None;
+            }
+            Some((t.id?.to_string(), path))
+        })
+        .collect();
+
+    if dry_run {
+        let text = if candidates.is_empty() {
+            "No worktrees to clean up.".to_string()
+        } else {
+            let mut lines = vec![format!("Would remove {} worktree(s):", candidates.len())];
+            lines.extend(
+                candidates
+                    .iter()
+                    .map(|(id, path)| format!("  {id}  {path}")),
+            );
+            lines.join("\n")
+        };
+        return CommandOutput::new(json!({
+            "removed": [],
+            "candidates": candidates
+                .iter()
+                .map(|(id, path)| json!({"id": id, "worktree": path}))
+                .collect::<Vec<_>>(),
+            "dry_run": true,
+        }))
+        .with_text(text)
+        .print(output);
+    }
+
+    let mut removed = Vec::new();
+    let mut errors = Vec::new();
+    for (id, path) in candidates {
+        match remove_worktree(&id, &path, force).await {
+            Ok(()) => removed.push(id),
+            Err(e) => errors.push((id, e.to_string())),
+        }
+    }
+    // Drop git's records of worktrees deleted by hand
+    let _ = git(None, &["worktree", "prune"]);
+
+    let mut parts = Vec::new();
+    if removed.is_empty() {
+        parts.push("No worktrees to clean up.".to_string());
+    } else {
+        parts.push(format!(
+            "Removed {} worktree(s): {}",
+            removed.len(),
+            removed.join(", ")
+        ));
+    }
+    if !errors.is_empty() {
+        parts.push(format!(
+            "Errors on {} ticket(s): {}",
+            errors.len(),
+            errors
+                .iter()
+                .map(|(id, err)| format!("{id} ({err})"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    CommandOutput::new(json!({
+        "removed": removed,
+        "errors": errors
+            .iter()
+            .map(|(id, err)| json!({"id": id, "error": err}))
+            .collect::<Vec<_>>(),
+        "dry_run": false,
+    }))
+    .with_text(parts.join("\n"))
+    .print(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_branch_name() {
+        assert_eq!(
+            branch_name("j-a1b2", Some("Fix login: handle expired tokens!")),
+            "j-a1b2-fix-login-handle-expired-tokens"
+        );
+        assert_eq!(branch_name("j-a1b2", Some("  ")), "j-a1b2");
+        assert_eq!(branch_name("j-a1b2", None), "j-a1b2");
+
+        let long = branch_name("j-a1b2", Some(&"word ".repeat(20)));
+        assert!(long.len() <= "j-a1b2-".len() + MAX_SLUG_LEN);
+        assert!(!long.ends_with('-'));
+    }
+}
@@ -0,0 +1,111 @@
+//! Component suggestion for new tickets (`components.suggest`).
+//!
+//! When a ticket is created without `--component`, the component is taken
+//! from the current directory if it lies inside one of the paths in
+//! `components.paths`. Otherwise the files changed in the git working tree
+//! (staged or not) are mapped to components and the component owning the
+//! most of them wins.
+
+use std::collections::BTreeMap;
+
+use crate::config::ComponentsConfig;
+use crate::error::{JanusError, Result};
+use crate::utils::git;
+
+/// Where a suggested component came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuggestionSource {
+    CurrentDir,
+    ChangedFiles,
+}
+
+enum_display!(
+    SuggestionSource,
+    {
+        CurrentDir => "current directory",
+        ChangedFiles => "changed files",
+    }
+);
+
+/// Suggest a component for a new ticket, if suggestions are enabled and
+/// either the current directory or the changed files map to one.
+///
+/// Outside a git work tree there is nothing to suggest from, and before the
+/// first commit there are no changed files to compare. Fails when git cannot
+/// report the current directory or the changed files of a repository.
+pub fn suggest_component(config: &ComponentsConfig) -> Result<Option<(String, SuggestionSource)>> {
+    if !config.suggest || config.paths.is_empty() {
+        return Ok(None);
+    }
+
+    // git fails outside a repository (or when it isn't installed)
+    match git(None, ["rev-parse", "--is-inside-work-tree"]) {
+        Ok(inside) if inside.trim() == "true" => {}
+        Ok(_) | Err(JanusError::Git(_)) => return Ok(None),
+        Err(e) => return Err(e),
+    }
+
+    // Path of the current directory relative to the repository root
+    let prefix = git(None, ["rev-parse", "--show-prefix"])?;
+    if let Some(component) = config.component_for_path(prefix.trim()) {
+        return Ok(Some((component.to_string(), SuggestionSource::CurrentDir)));
+    }
+
+    // An unborn HEAD has nothing to diff against
+    if git(None, ["rev-parse", "--verify", "-q", "HEAD"]).is_err() {
+        return Ok(None);
+    }
+
+    // `--name-only` paths are relative to the repository root
+    let changed = git(None, ["diff", "--name-only", "HEAD"])?;
+    Ok(most_common_component(config, changed.lines())
+        .map(|component| (component, SuggestionSource::ChangedFiles)))
+}
+
+/// The component owning the most of `files`; ties go to the name that sorts
+/// first.
+fn most_common_component<'a>(
+    config: &ComponentsConfig,
+    files: impl Iterator<Item = &'a str>,
+) -> Option<String> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for file in files {
+        if let Some(component) = config.component_for_path(file) {
+            *counts.entry(component).or_default() += 1;
+        }
+    }
+    // `max_by_key` keeps the last maximum, so iterate in reverse name order
+    counts
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map(|(component, _)| component.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_most_common_component() {
+        let config: ComponentsConfig =
+            serde_yaml_ng::from_str("paths:\n  api: [src/api]\n  web: [web]\n").unwrap();
+
+        let files = ["src/api/a.rs", "web/index.ts", "web/app.ts", "README.md"];
+        assert_eq!(
+            most_common_component(&config, files.into_iter()).as_deref(),
+            Some("web")
+        );
+
+        let files = ["src/api/a.rs", "web/index.ts"];
+        assert_eq!(
+            most_common_component(&config, files.into_iter()).as_deref(),
+            Some("api")
+        );
+
+        assert_eq!(
+            most_common_component(&config, ["docs/x.md"].into_iter()),
+            None
+        );
+    }
+}
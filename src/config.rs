@@ -5,8 +5,19 @@
 //! - Authentication tokens for GitHub and Linear
 //! - Hook script configuration
 //! - Semantic search settings
-
-use std::collections::HashMap;
+//! - Document chunking for search and embeddings
+//! - Proxy and custom CA settings for remote sync
+//! - Label and user mapping for remote sync
+//! - Per-platform mirror mode for remote sync
+//! - Protected status transitions
+//! - SLA policies for open tickets
+//! - Per-assignee WIP limits and size budgets for `janus next --for`
+//! - Components and the repository paths they own
+//! - LLM provider for `janus summarize`
+//! - Optional age/gpg encryption of authentication tokens
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::fmt;
 use std::fs;
@@ -15,8 +26,12 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{JanusError, Result};
-use crate::remote::config::{DefaultRemote, Platform};
-use crate::types::janus_root;
+use crate::remote::config::{
+    DefaultRemote, LabelMappingConfig, NetworkConfig, Platform, PullRequestConfig, RemotesConfig,
+    StatusMappingConfig, UserMappingConfig,
+};
+use crate::secrets::{self, EncryptionTool};
+use crate::types::{TicketPriority, TicketStatus, TicketType, janus_root};
 
 /// Main configuration structure
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -26,9 +41,18 @@ pub struct Config {
     pub default_remote: Option<DefaultRemote>,
 
     /// Authentication tokens
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "AuthConfig::is_default")]
     pub auth: AuthConfig,
 
+    /// Encryption settings for the `auth` section
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<EncryptionConfig>,
+
+    /// ASCII-armored ciphertext of the `auth` section, written instead of
+    /// plaintext tokens when `encryption` is configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypted_auth: Option<String>,
+
     /// Hooks configuration
     #[serde(default, skip_serializing_if = "HooksConfig::is_default")]
     pub hooks: HooksConfig,
@@ -37,13 +61,89 @@ pub struct Config {
     #[serde(default, skip_serializing_if = "SemanticSearchConfig::is_default")]
     pub semantic_search: SemanticSearchConfig,
 
+    /// How documents are split into chunks for search and embeddings
+    #[serde(default, skip_serializing_if = "DocChunkingConfig::is_default")]
+    pub doc_chunking: DocChunkingConfig,
+
     /// Remote operation timeout in seconds (default: 30)
     #[serde(default = "default_remote_timeout")]
     pub remote_timeout: u64,
 
+    /// Proxy and custom CA settings for remote API clients
+    #[serde(default, skip_serializing_if = "NetworkConfig::is_default")]
+    pub network: NetworkConfig,
+
+    /// Mapping between local ticket labels and remote issue labels
+    #[serde(default, skip_serializing_if = "LabelMappingConfig::is_default")]
+    pub labels: LabelMappingConfig,
+
+    /// Mapping between local assignee names and GitHub/Linear users
+    #[serde(default, skip_serializing_if = "UserMappingConfig::is_default")]
+    pub users: UserMappingConfig,
+
+    /// Mapping between Janus statuses and GitHub labels / Linear workflow states
+    #[serde(default, skip_serializing_if = "StatusMappingConfig::is_default")]
+    pub status_mapping: StatusMappingConfig,
+
+    /// Per-platform sync mode (two-way sync or a read-only mirror)
+    #[serde(default, skip_serializing_if = "RemotesConfig::is_default")]
+    pub remotes: RemotesConfig,
+
+    /// Pull request integration (auto-completing tickets on merge)
+    #[serde(default, skip_serializing_if = "PullRequestConfig::is_default")]
+    pub pull_requests: PullRequestConfig,
+
     /// Auto-archive configuration
     #[serde(default, skip_serializing_if = "ArchiveConfig::is_default")]
     pub archive: ArchiveConfig,
+
+    /// Protected status transition rules
+    #[serde(default, skip_serializing_if = "TransitionsConfig::is_default")]
+    pub transitions: TransitionsConfig,
+
+    /// SLA policies for open tickets
+    #[serde(default, skip_serializing_if = "SlaConfig::is_default")]
+    pub sla: SlaConfig,
+
+    /// Rules for `janus escalate`, which raises the priority of old tickets
+    #[serde(default, skip_serializing_if = "EscalationConfig::is_default")]
+    pub escalation: EscalationConfig,
+
+    /// WIP limits and size budgets used by `janus next --for`
+    #[serde(default, skip_serializing_if = "CapacityConfig::is_default")]
+    pub capacity: CapacityConfig,
+
+    /// Scoring policy that orders `janus ready`
+    #[serde(default, skip_serializing_if = "ReadyConfig::is_default")]
+    pub ready: ReadyConfig,
+
+    /// How new ticket IDs are generated
+    #[serde(default, skip_serializing_if = "IdsConfig::is_default")]
+    pub ids: IdsConfig,
+
+    /// Colors used in CLI output and the TUIs
+    #[serde(default, skip_serializing_if = "DisplayConfig::is_default")]
+    pub display: DisplayConfig,
+
+    /// Components and the repository paths they own
+    #[serde(default, skip_serializing_if = "ComponentsConfig::is_default")]
+    pub components: ComponentsConfig,
+
+    /// Git integration (auto-committing `.janus` changes)
+    #[serde(default, skip_serializing_if = "GitConfig::is_default")]
+    pub git: GitConfig,
+
+    /// Templates for `janus add-note --template`
+    #[serde(default, skip_serializing_if = "NotesConfig::is_default")]
+    pub notes: NotesConfig,
+
+    /// Templates for `janus commit-msg` and `janus pr-body`
+    #[serde(default, skip_serializing_if = "MessagesConfig::is_default")]
+    pub messages: MessagesConfig,
+
+    /// LLM provider used by `janus summarize`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llm: Option<LlmConfig>,
 }
 
 fn default_remote_timeout() -> u64 {
@@ -59,6 +159,55 @@ pub struct AuthConfig {
     pub linear: Option<LinearAuth>,
 }
 
+impl AuthConfig {
+    pub fn is_default(&self) -> bool {
+        self.github.is_none() && self.linear.is_none()
+    }
+}
+
+/// Encryption of the `auth` section with an external tool.
+///
+/// When set, `Config::save` encrypts the tokens to every recipient and stores
+/// the result in `encrypted_auth`. It is decrypted only when a token is
+/// needed, so commands that never talk to a remote work without the key.
+///
+/// ```yaml
+/// encryption:
+///   tool: age
+///   recipients: [age1ql3z7hjy54pw3hyww5ayyfg7zqgvc7w3j2elw8zmrj2kg5sfn9aqmcac8p]
+///   identity: ~/.config/age/keys.txt
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptionConfig {
+    /// Tool used to encrypt and decrypt (`age` or `gpg`)
+    pub tool: EncryptionTool,
+
+    /// age public keys or gpg key IDs/emails that can decrypt the secrets
+    pub recipients: Vec<String>,
+
+    /// age identity file used for decryption (overridden by `JANUS_AGE_IDENTITY`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<PathBuf>,
+}
+
+impl EncryptionConfig {
+    /// Resolve the age identity file, preferring the `JANUS_AGE_IDENTITY` env var
+    /// and expanding a leading `~/` in the configured path.
+    pub fn identity_path(&self) -> Option<PathBuf> {
+        if let Ok(path) = env::var("JANUS_AGE_IDENTITY")
+            && !path.is_empty()
+        {
+            return Some(PathBuf::from(path));
+        }
+
+        let identity = self.identity.as_ref()?;
+        match identity.strip_prefix("~") {
+            Ok(rest) => env::var_os("HOME").map(|home| PathBuf::from(home).join(rest)),
+            Err(_) => Some(identity.clone()),
+        }
+    }
+}
+
 /// GitHub authentication
 ///
 /// SECURITY NOTE: Tokens are stored as plain `String` in the config file.
@@ -146,6 +295,57 @@ impl SemanticSearchConfig {
     }
 }
 
+/// Document chunking configuration.
+///
+/// Chunks are built from whole markdown blocks, so a code block or table is
+/// never split; a single block longer than `chunk_size` becomes its own chunk.
+///
+/// ```yaml
+/// doc_chunking:
+///   chunk_size: 2000
+///   overlap: 200
+///   split_on_headings: true
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocChunkingConfig {
+    /// Maximum chunk length in characters. `0` disables size-based splitting.
+    #[serde(default = "default_chunk_size")]
+    pub chunk_size: usize,
+
+    /// Characters of trailing blocks repeated at the start of the next chunk
+    /// when a section is split by size (default: 0)
+    #[serde(default)]
+    pub overlap: usize,
+
+    /// Start a new chunk at every heading (default: true)
+    #[serde(default = "default_split_on_headings")]
+    pub split_on_headings: bool,
+}
+
+fn default_chunk_size() -> usize {
+    2000
+}
+
+fn default_split_on_headings() -> bool {
+    true
+}
+
+impl Default for DocChunkingConfig {
+    fn default() -> Self {
+        Self {
+            chunk_size: default_chunk_size(),
+            overlap: 0,
+            split_on_headings: default_split_on_headings(),
+        }
+    }
+}
+
+impl DocChunkingConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// Auto-archive configuration.
 ///
 /// Controls how long a completed ticket stays in the Complete column before the
@@ -186,6 +386,837 @@ impl ArchiveConfig {
     }
 }
 
+/// Git integration configuration.
+///
+/// With `auto_commit` enabled, every mutating command stages and commits the
+/// `.janus` files it changed (see `crate::fs::commit_pending_changes`).
+///
+/// ```yaml
+/// git:
+///   auto_commit: true
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitConfig {
+    /// Commit changed `.janus` files after each mutating command (default: false)
+    #[serde(default)]
+    pub auto_commit: bool,
+}
+
+impl GitConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Note templates, used with `janus add-note <id> --template <name>`.
+///
+/// `{user}` (git user.name, else `$USER`), `{date}` (today, YYYY-MM-DD),
+/// `{id}`, and `{title}` are replaced before the rest of the note is added.
+///
+/// ```yaml
+/// notes:
+///   templates:
+///     review: "Reviewed by {user} on {date}: "
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotesConfig {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, String>,
+}
+
+impl NotesConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Templates for the commit messages and pull request descriptions rendered
+/// by `janus commit-msg` and `janus pr-body`, replacing the built-in ones.
+///
+/// Both use the same `{variable}`s: `id`, `title`, `type`, `kind` (the
+/// conventional commit type), `scope` (`(component)`, or empty),
+/// `description`, `design`, and `acceptance`.
+///
+/// ```yaml
+/// messages:
+///   commit: "{kind}{scope}: {title}\n\nRefs: {id}"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessagesConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_body: Option<String>,
+}
+
+impl MessagesConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Components (areas of the codebase) and the repository paths they own.
+///
+/// Paths are relative to the git repository root. With `suggest` enabled,
+/// `janus create` fills in the component of a new ticket from the current
+/// directory, or else from the files changed in git (see `crate::component`).
+/// A component listed under `prefixes` gives its new tickets that ID prefix
+/// unless `--prefix` is passed.
+///
+/// ```yaml
+/// components:
+///   suggest: true
+///   paths:
+///     api: [src/api, crates/server]
+///     web: [web]
+///   prefixes:
+///     api: api
+///     web: ui
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentsConfig {
+    /// Repository paths owned by each component
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub paths: BTreeMap<String, Vec<String>>,
+
+    /// Suggest a component for new tickets created without `--component`
+    /// (default: false)
+    #[serde(default)]
+    pub suggest: bool,
+
+    /// ID prefix for new tickets in each component
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub prefixes: BTreeMap<String, String>,
+}
+
+impl ComponentsConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The configured ID prefix for `component` (matched case-insensitively)
+    pub fn prefix_for(&self, component: &str) -> Option<&str> {
+        self.prefixes
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(component))
+            .map(|(_, prefix)| prefix.as_str())
+    }
+
+    /// The component owning `path` (relative to the repository root). When
+    /// several components match, the one with the longest path wins.
+    pub fn component_for_path(&self, path: &str) -> Option<&str> {
+        let path = path.trim_start_matches("./").trim_end_matches('/');
+        self.paths
+            .iter()
+            .flat_map(|(name, prefixes)| prefixes.iter().map(move |p| (name, p)))
+            .filter_map(|(name, prefix)| {
+                let prefix = prefix.trim_start_matches("./").trim_end_matches('/');
+                let owns = prefix.is_empty()
+                    || path == prefix
+                    || path
+                        .strip_prefix(prefix)
+                        .is_some_and(|rest| rest.starts_with('/'));
+                owns.then_some((name.as_str(), prefix.len()))
+            })
+            .max_by_key(|(_, len)| *len)
+            .map(|(name, _)| name)
+    }
+}
+
+/// Protected status transition configuration.
+///
+/// Rules are evaluated by `crate::status::transitions` whenever a ticket's status
+/// changes through `janus start/close/reopen/status`, the TUI status cycler, the
+/// board, or the MCP `update_status` tool.
+///
+/// ```yaml
+/// transitions:
+///   rules:
+///     - from: complete
+///       to: new
+///       policy: forbid
+///       allow_via: [reopen]
+///   open_children: confirm
+///   require_reopen_reason: true
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransitionsConfig {
+    /// Per-transition rules. A missing `from` or `to` matches any status.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<TransitionRule>,
+
+    /// Policy applied when closing a ticket that still has open child tickets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_children: Option<TransitionPolicy>,
+
+    /// Make `janus reopen` of a closed ticket fail without `--reason`
+    /// instead of writing a generated note (default: false)
+    #[serde(default)]
+    pub require_reopen_reason: bool,
+}
+
+impl TransitionsConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// A single protected transition rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransitionRule {
+    /// Status the ticket is leaving (any status if omitted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<TicketStatus>,
+
+    /// Status the ticket is entering (any status if omitted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<TicketStatus>,
+
+    /// Whether the transition is forbidden or requires confirmation
+    pub policy: TransitionPolicy,
+
+    /// Entry points that are exempt from this rule (e.g. `reopen`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow_via: Vec<TransitionVia>,
+
+    /// Optional explanation shown when the rule blocks a transition
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// How a protected transition is enforced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransitionPolicy {
+    /// The transition proceeds only after explicit confirmation
+    Confirm,
+    /// The transition is rejected outright
+    Forbid,
+}
+
+/// The entry point through which a status change was requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransitionVia {
+    Start,
+    Close,
+    Reopen,
+    Status,
+    Set,
+    Expire,
+    Tui,
+    Mcp,
+    Sync,
+}
+
+enum_display!(
+    TransitionVia,
+    {
+        Start => "start",
+        Close => "close",
+        Reopen => "reopen",
+        Status => "status",
+        Set => "set",
+        Expire => "expire",
+        Tui => "tui",
+        Mcp => "mcp",
+        Sync => "sync",
+    }
+);
+
+/// LLM provider configuration for `janus summarize`.
+///
+/// The command is only available in builds with the `llm` cargo feature. The
+/// API key is read from the environment variable named by `api_key_env`
+/// (`ANTHROPIC_API_KEY` or `OPENAI_API_KEY` by default); Ollama needs none.
+///
+/// ```yaml
+/// llm:
+///   provider: anthropic
+///   model: <model name>
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LlmConfig {
+    pub provider: LlmProvider,
+
+    /// Model name passed to the provider
+    pub model: String,
+
+    /// API base URL, for proxies and OpenAI-compatible servers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+
+    /// Environment variable holding the API key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key_env: Option<String>,
+
+    /// Upper bound on the length of the generated summary
+    #[serde(default = "default_llm_max_tokens")]
+    pub max_tokens: u32,
+}
+
+fn default_llm_max_tokens() -> u32 {
+    1024
+}
+
+impl LlmConfig {
+    /// The API base URL, falling back to the provider's public endpoint.
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or(match self.provider {
+                LlmProvider::Anthropic => "https://api.anthropic.com",
+                LlmProvider::Openai => "https://api.openai.com",
+                LlmProvider::Ollama => "http://localhost:11434",
+            })
+            .trim_end_matches('/')
+    }
+
+    /// The environment variable holding the API key, if the provider needs one.
+    pub fn api_key_env(&self) -> Option<&str> {
+        self.api_key_env.as_deref().or(match self.provider {
+            LlmProvider::Anthropic => Some("ANTHROPIC_API_KEY"),
+            LlmProvider::Openai => Some("OPENAI_API_KEY"),
+            LlmProvider::Ollama => None,
+        })
+    }
+}
+
+/// Supported LLM API flavors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LlmProvider {
+    /// Anthropic Messages API
+    Anthropic,
+    /// OpenAI Chat Completions API, also served by many compatible servers
+    Openai,
+    /// Local Ollama server
+    Ollama,
+}
+
+enum_display!(
+    LlmProvider,
+    {
+        Anthropic => "anthropic",
+        Openai => "openai",
+        Ollama => "ollama",
+    }
+);
+
+/// SLA policy configuration.
+///
+/// Rules are evaluated by `crate::sla` against open tickets; `janus sla`,
+/// `janus ls`, and the board flag tickets that are at risk of missing a rule's
+/// window or have already missed it. Windows are measured from the ticket's
+/// `created` timestamp.
+///
+/// ```yaml
+/// sla:
+///   at_risk_percent: 75
+///   rules:
+///     - name: p0-response
+///       priority: 0
+///       start_within: 24h
+///     - type: bug
+///       close_within: 14d
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SlaConfig {
+    /// SLA rules. A ticket is checked against every rule it matches.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<SlaRule>,
+
+    /// Percentage of a window that may elapse before a ticket is at risk
+    #[serde(default = "default_sla_at_risk_percent")]
+    pub at_risk_percent: u8,
+}
+
+fn default_sla_at_risk_percent() -> u8 {
+    80
+}
+
+impl Default for SlaConfig {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            at_risk_percent: default_sla_at_risk_percent(),
+        }
+    }
+}
+
+impl SlaConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Priority escalation configuration.
+///
+/// Rules are applied by `janus escalate --apply` (see
+/// `crate::status::escalation`). A ticket that has been open longer than a
+/// matching rule's `older_than` is raised one priority level, but never above
+/// the rule's `max_priority`. After an escalation the clock restarts, so a
+/// ticket climbs at most one level per window.
+///
+/// ```yaml
+/// escalation:
+///   rules:
+///     - type: bug
+///       older_than: 30d
+///       max_priority: 1
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EscalationConfig {
+    /// Escalation rules. The first rule that matches a ticket applies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<EscalationRule>,
+}
+
+impl EscalationConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Colors used in CLI output (`show`, `ls`, `dep tree`, ...) and the TUIs.
+///
+/// ```yaml
+/// display:
+///   accent: blue
+///   status:
+///     in_progress: green
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplayConfig {
+    /// Color of ticket IDs and labels (default: cyan)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<ThemeColor>,
+
+    /// Per-status badge colors; unset statuses keep their defaults
+    #[serde(default, skip_serializing_if = "StatusColors::is_default")]
+    pub status: StatusColors,
+}
+
+impl DisplayConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Badge color overrides, one per ticket status.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusColors {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complete: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancelled: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived: Option<ThemeColor>,
+}
+
+impl StatusColors {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The configured color for `status`, if any
+    pub fn get(&self, status: TicketStatus) -> Option<ThemeColor> {
+        match status {
+            TicketStatus::New => self.new,
+            TicketStatus::Next => self.next,
+            TicketStatus::InProgress => self.in_progress,
+            TicketStatus::Complete => self.complete,
+            TicketStatus::Cancelled => self.cancelled,
+            TicketStatus::Archived => self.archived,
+        }
+    }
+}
+
+/// A terminal color that can be named in the `display` config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    /// Rendered dimmed rather than as a fixed color
+    Gray,
+}
+
+/// Capacity limits applied when recommending work to an assignee.
+///
+/// Used by `crate::next` for `janus next --for <name>`: nothing is suggested
+/// to someone already at their WIP limit, and tickets whose size would push
+/// their in-progress work over the size budget are skipped. Sizes are counted
+/// in points (xsmall 1, small 2, medium or unsized 3, large 5, xlarge 8).
+///
+/// ```yaml
+/// capacity:
+///   wip_limit: 3
+///   size_budget: 10
+///   assignees:
+///     sam:
+///       wip_limit: 2
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapacityConfig {
+    /// Limits for everyone without an entry in `assignees`
+    #[serde(flatten)]
+    pub default: CapacityLimits,
+
+    /// Per-assignee overrides. Unset fields fall back to the defaults.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub assignees: BTreeMap<String, CapacityLimits>,
+}
+
+impl CapacityConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Effective limits for `assignee` (matched ignoring case)
+    pub fn limits_for(&self, assignee: &str) -> CapacityLimits {
+        let overrides = self
+            .assignees
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(assignee))
+            .map(|(_, limits)| limits);
+        CapacityLimits {
+            wip_limit: overrides
+                .and_then(|l| l.wip_limit)
+                .or(self.default.wip_limit),
+            size_budget: overrides
+                .and_then(|l| l.size_budget)
+                .or(self.default.size_budget),
+        }
+    }
+}
+
+/// Scoring policy for the `janus ready` queue.
+///
+/// Each ready ticket scores the sum of four components, each scaled by its
+/// weight (see `crate::ready`): priority (P0 highest), age in weeks, urgency
+/// of its plan phase (the earliest unfinished phase is most urgent), and size
+/// (smaller tickets score higher). A weight of `0` turns a component off.
+///
+/// `require` defines which tickets count as ready in the first place; see
+/// [`ReadyRequirements`].
+///
+/// ```yaml
+/// ready:
+///   priority_weight: 10
+///   age_weight: 1
+///   phase_weight: 5
+///   size_weight: 2
+///   require:
+///     triaged: true
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ReadyConfig {
+    #[serde(default = "default_priority_weight")]
+    pub priority_weight: f64,
+    #[serde(default = "default_age_weight")]
+    pub age_weight: f64,
+    #[serde(default = "default_phase_weight")]
+    pub phase_weight: f64,
+    #[serde(default = "default_size_weight")]
+    pub size_weight: f64,
+    #[serde(default, skip_serializing_if = "ReadyRequirements::is_default")]
+    pub require: ReadyRequirements,
+}
+
+/// What a new or next ticket needs to count as ready (`ready.require`).
+///
+/// `janus ready`, `janus next`, `janus ls --ready`, and the ready counts in
+/// the TUI headers all check tickets against these through
+/// [`crate::status::is_ready`]. By default a ticket is ready once its
+/// dependencies are done, unless it is snoozed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReadyRequirements {
+    /// Every dependency is complete or cancelled (default: true)
+    pub deps_complete: bool,
+    /// The ticket is not snoozed (default: true)
+    pub not_snoozed: bool,
+    /// The ticket has been triaged (default: false)
+    pub triaged: bool,
+    /// The ticket has an acceptance criteria section (default: false)
+    pub acceptance_criteria: bool,
+}
+
+impl Default for ReadyRequirements {
+    fn default() -> Self {
+        Self {
+            deps_complete: true,
+            not_snoozed: true,
+            triaged: false,
+            acceptance_criteria: false,
+        }
+    }
+}
+
+impl ReadyRequirements {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+fn default_priority_weight() -> f64 {
+    10.0
+}
+
+fn default_age_weight() -> f64 {
+    1.0
+}
+
+fn default_phase_weight() -> f64 {
+    5.0
+}
+
+fn default_size_weight() -> f64 {
+    2.0
+}
+
+impl Default for ReadyConfig {
+    fn default() -> Self {
+        Self {
+            priority_weight: default_priority_weight(),
+            age_weight: default_age_weight(),
+            phase_weight: default_phase_weight(),
+            size_weight: default_size_weight(),
+            require: ReadyRequirements::default(),
+        }
+    }
+}
+
+impl ReadyConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// How new ticket IDs are generated.
+///
+/// By default an ID is its prefix plus a random hex suffix (`task-a1b2`). In
+/// `sequential` mode the suffix is the next number for that prefix instead,
+/// zero-padded to `width` digits (`task-0001`, `task-0002`), so IDs sort in
+/// creation order.
+///
+/// ```yaml
+/// ids:
+///   mode: sequential
+///   width: 4
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdsConfig {
+    #[serde(default)]
+    pub mode: IdMode,
+    #[serde(default = "default_id_width")]
+    pub width: usize,
+}
+
+/// Ticket ID suffix style (see [`IdsConfig`])
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdMode {
+    #[default]
+    Random,
+    Sequential,
+}
+
+fn default_id_width() -> usize {
+    4
+}
+
+impl Default for IdsConfig {
+    fn default() -> Self {
+        Self {
+            mode: IdMode::default(),
+            width: default_id_width(),
+        }
+    }
+}
+
+impl IdsConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// WIP limit and size budget for one assignee.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapacityLimits {
+    /// Most tickets in progress at once
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wip_limit: Option<usize>,
+
+    /// Most size points in progress at once
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_budget: Option<u32>,
+}
+
+/// A single SLA rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SlaRule {
+    /// Name shown when the rule is at risk or breached
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Priority the rule applies to (any priority if omitted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<TicketPriority>,
+
+    /// Ticket type the rule applies to (any type if omitted)
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub ticket_type: Option<TicketType>,
+
+    /// Time allowed before work starts (the ticket leaves new/next)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_within: Option<SlaWindow>,
+
+    /// Time allowed before the ticket is closed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close_within: Option<SlaWindow>,
+}
+
+impl SlaRule {
+    /// Check whether the rule applies to a ticket with the given priority and type.
+    pub fn matches(&self, priority: TicketPriority, ticket_type: TicketType) -> bool {
+        self.priority.is_none_or(|p| p == priority)
+            && self.ticket_type.is_none_or(|t| t == ticket_type)
+    }
+
+    /// The rule's name, or a description built from its criteria (`P0 bug`).
+    pub fn label(&self) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+        let parts: Vec<String> = [
+            self.priority.map(|p| format!("P{p}")),
+            self.ticket_type.map(|t| t.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if parts.is_empty() {
+            "all tickets".to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+}
+
+/// An SLA window such as `24h` or `14d`, kept in its written form for
+/// serialization and display.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct SlaWindow {
+    text: String,
+    duration: jiff::SignedDuration,
+}
+
+impl SlaWindow {
+    pub fn duration(&self) -> jiff::SignedDuration {
+        self.duration
+    }
+}
+
+impl TryFrom<String> for SlaWindow {
+    type Error = JanusError;
+
+    fn try_from(text: String) -> Result<Self> {
+        let duration = crate::utils::parse_duration(&text)?;
+        if duration.is_zero() {
+            return Err(JanusError::InvalidInput(format!(
+                "invalid SLA window '{text}': must be greater than zero"
+            )));
+        }
+        Ok(Self {
+            text: text.trim().to_string(),
+            duration,
+        })
+    }
+}
+
+impl From<SlaWindow> for String {
+    fn from(window: SlaWindow) -> Self {
+        window.text
+    }
+}
+
+impl fmt::Display for SlaWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// One priority escalation rule (see [`EscalationConfig`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EscalationRule {
+    /// Name shown in the escalation report and note
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Current priority the rule applies to (any priority if omitted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<TicketPriority>,
+
+    /// Ticket type the rule applies to (any type if omitted)
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub ticket_type: Option<TicketType>,
+
+    /// How long a ticket must be open, or unchanged since its last
+    /// escalation, before it is escalated
+    pub older_than: SlaWindow,
+
+    /// Highest priority the rule escalates to (default: 0)
+    #[serde(default = "default_max_priority")]
+    pub max_priority: TicketPriority,
+}
+
+fn default_max_priority() -> TicketPriority {
+    TicketPriority::P0
+}
+
+impl EscalationRule {
+    /// Check whether the rule applies to a ticket with the given priority and type.
+    pub fn matches(&self, priority: TicketPriority, ticket_type: TicketType) -> bool {
+        self.priority.is_none_or(|p| p == priority)
+            && self.ticket_type.is_none_or(|t| t == ticket_type)
+    }
+
+    /// The rule's name, or a description built from its criteria
+    /// (`bug older than 30d`).
+    pub fn label(&self) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+        let mut parts: Vec<String> = [
+            self.priority.map(|p| format!("P{p}")),
+            self.ticket_type.map(|t| t.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if parts.is_empty() {
+            parts.push("tickets".to_string());
+        }
+        format!("{} older than {}", parts.join(" "), self.older_than)
+    }
+}
+
 fn default_hooks_enabled() -> bool {
     true
 }
@@ -225,6 +1256,9 @@ impl Config {
     }
 
     /// Load configuration from file, or return default if not found
+    ///
+    /// An encrypted `auth` section is left encrypted; see [`Config::github_token`]
+    /// and [`Config::linear_api_key`].
     pub fn load() -> Result<Self> {
         let path = Self::config_path();
         if !path.exists() {
@@ -241,8 +1275,65 @@ impl Config {
                 ),
             ))
         })?;
-        let config: Config = serde_yaml_ng::from_str(&content)?;
-        Ok(config)
+        Ok(serde_yaml_ng::from_str(&content)?)
+    }
+
+    /// The `auth` section, decrypting `encrypted_auth` if present.
+    ///
+    /// This runs the encryption tool, so it is reached only through the token
+    /// getters that the remote providers call.
+    fn auth(&self) -> Result<Cow<'_, AuthConfig>> {
+        let Some(ciphertext) = self.encrypted_auth.as_deref() else {
+            return Ok(Cow::Borrowed(&self.auth));
+        };
+        let encryption = self.encryption.as_ref().ok_or_else(|| {
+            JanusError::Config(
+                "encrypted_auth is present but no encryption settings are configured".to_string(),
+            )
+        })?;
+
+        let identity = encryption.identity_path();
+        let plaintext = secrets::decrypt(encryption.tool, identity.as_deref(), ciphertext)?;
+        Ok(Cow::Owned(serde_yaml_ng::from_str(&plaintext)?))
+    }
+
+    /// The `auth` section for modification.
+    ///
+    /// Decrypts `encrypted_auth` into `auth` and drops the ciphertext, so the
+    /// next `save` encrypts the changed tokens.
+    fn auth_mut(&mut self) -> Result<&mut AuthConfig> {
+        if self.encrypted_auth.is_some() {
+            self.auth = self.auth()?.into_owned();
+            self.encrypted_auth = None;
+        }
+        Ok(&mut self.auth)
+    }
+
+    /// Build the representation written to disk: with encryption configured,
+    /// the `auth` section is replaced by its ciphertext.
+    ///
+    /// Ciphertext that was loaded and never decrypted is written back as is.
+    fn to_stored(&self) -> Result<Config> {
+        let mut stored = self.clone();
+        if let Some(encryption) = &self.encryption {
+            if stored.encrypted_auth.is_none() && !self.auth.is_default() {
+                let plaintext = serde_yaml_ng::to_string(&self.auth)?;
+                stored.encrypted_auth = Some(secrets::encrypt(
+                    encryption.tool,
+                    &encryption.recipients,
+                    &plaintext,
+                )?);
+            }
+            stored.auth = AuthConfig::default();
+        }
+        Ok(stored)
+    }
+
+    /// Whether any token is stored in the config file, encrypted or not.
+    ///
+    /// Unlike the token getters, this never decrypts.
+    pub fn has_stored_auth(&self) -> bool {
+        self.encrypted_auth.is_some() || !self.auth.is_default()
     }
 
     /// Save configuration to file
@@ -256,6 +1347,7 @@ impl Config {
     /// to disk. Consider using environment variables instead.
     pub fn save(&self) -> Result<()> {
         let path = Self::config_path();
+        crate::read_only::ensure_writable(|| format!("write {}", path.display()))?;
 
         // Ensure .janus directory exists
         if let Some(parent) = path.parent() {
@@ -271,7 +1363,7 @@ impl Config {
             })?;
         }
 
-        let content = serde_yaml_ng::to_string(self)?;
+        let content = serde_yaml_ng::to_string(&self.to_stored()?)?;
         fs::write(&path, content).map_err(|e| {
             JanusError::Io(std::io::Error::new(
                 e.kind(),
@@ -307,29 +1399,33 @@ impl Config {
     }
 
     /// Get GitHub token from config or environment variable
-    pub fn github_token(&self) -> Option<String> {
+    ///
+    /// Decrypts the stored tokens unless the environment variable is set.
+    pub fn github_token(&self) -> Result<Option<String>> {
         // First check environment variable
         if let Ok(token) = env::var("GITHUB_TOKEN")
             && !token.is_empty()
         {
-            return Some(token);
+            return Ok(Some(token));
         }
 
         // Fall back to config file
-        self.auth.github.as_ref().map(|g| g.token.clone())
+        Ok(self.auth()?.github.as_ref().map(|g| g.token.clone()))
     }
 
     /// Get Linear API key from config or environment variable
-    pub fn linear_api_key(&self) -> Option<String> {
+    ///
+    /// Decrypts the stored tokens unless the environment variable is set.
+    pub fn linear_api_key(&self) -> Result<Option<String>> {
         // First check environment variable
         if let Ok(key) = env::var("LINEAR_API_KEY")
             && !key.is_empty()
         {
-            return Some(key);
+            return Ok(Some(key));
         }
 
         // Fall back to config file
-        self.auth.linear.as_ref().map(|l| l.api_key.clone())
+        Ok(self.auth()?.linear.as_ref().map(|l| l.api_key.clone()))
     }
 
     /// Set GitHub token
@@ -337,8 +1433,9 @@ impl Config {
     /// SECURITY WARNING: This stores the token in the config file. While the file is protected
     /// with 0o600 permissions, it is recommended to use the `GITHUB_TOKEN` environment variable
     /// instead, which takes precedence over the config file value.
-    pub fn set_github_token(&mut self, token: String) {
-        self.auth.github = Some(GitHubAuth { token });
+    pub fn set_github_token(&mut self, token: String) -> Result<()> {
+        self.auth_mut()?.github = Some(GitHubAuth { token });
+        Ok(())
     }
 
     /// Set Linear API key
@@ -346,8 +1443,19 @@ impl Config {
     /// SECURITY WARNING: This stores the API key in the config file. While the file is protected
     /// with 0o600 permissions, it is recommended to use the `LINEAR_API_KEY` environment variable
     /// instead, which takes precedence over the config file value.
-    pub fn set_linear_api_key(&mut self, api_key: String) {
-        self.auth.linear = Some(LinearAuth { api_key });
+    pub fn set_linear_api_key(&mut self, api_key: String) -> Result<()> {
+        self.auth_mut()?.linear = Some(LinearAuth { api_key });
+        Ok(())
+    }
+
+    /// Enable encryption of the `auth` section; takes effect on the next `save`
+    ///
+    /// Tokens already encrypted are decrypted with the previous settings so
+    /// they are re-encrypted for the new recipients.
+    pub fn set_encryption(&mut self, encryption: EncryptionConfig) -> Result<()> {
+        self.auth_mut()?;
+        self.encryption = Some(encryption);
+        Ok(())
     }
 
     /// Set default remote
@@ -369,6 +1477,11 @@ impl Config {
         self.semantic_search.enabled = enabled;
     }
 
+    /// Check if `.janus` changes are committed automatically
+    pub fn git_auto_commit(&self) -> bool {
+        self.git.auto_commit
+    }
+
     /// Get the remote operation timeout duration
     pub fn remote_timeout(&self) -> std::time::Duration {
         std::time::Duration::from_secs(self.remote_timeout)
@@ -395,7 +1508,7 @@ mod tests {
     #[test]
     fn test_config_serialization() {
         let mut config = Config::default();
-        config.set_github_token("ghp_test123".to_string());
+        config.set_github_token("ghp_test123".to_string()).unwrap();
         config.set_default_remote(
             Platform::GitHub,
             "myorg".to_string(),
@@ -405,13 +1518,71 @@ mod tests {
         let yaml = serde_yaml_ng::to_string(&config).unwrap();
         let parsed: Config = serde_yaml_ng::from_str(&yaml).unwrap();
 
-        assert_eq!(parsed.github_token(), Some("ghp_test123".to_string()));
+        assert_eq!(
+            parsed.auth.github.map(|g| g.token),
+            Some("ghp_test123".to_string())
+        );
         let default = parsed.default_remote.unwrap();
         assert_eq!(default.platform, Platform::GitHub);
         assert_eq!(default.org, "myorg");
         assert_eq!(default.repo, Some("myrepo".to_string()));
     }
 
+    #[test]
+    fn test_config_to_stored_without_encryption_keeps_auth() {
+        let mut config = Config::default();
+        config.set_github_token("ghp_test123".to_string()).unwrap();
+
+        let stored = config.to_stored().unwrap();
+        assert_eq!(
+            stored.auth.github.map(|g| g.token),
+            Some("ghp_test123".to_string())
+        );
+        assert!(stored.encrypted_auth.is_none());
+    }
+
+    #[test]
+    fn test_config_to_stored_encrypted_without_secrets() {
+        let mut config = Config::default();
+        config
+            .set_encryption(EncryptionConfig {
+                tool: EncryptionTool::Age,
+                recipients: vec!["age1example".to_string()],
+                identity: None,
+            })
+            .unwrap();
+
+        // No secrets to encrypt, so the external tool is never invoked
+        let stored = config.to_stored().unwrap();
+        assert!(stored.auth.is_default());
+        assert!(stored.encrypted_auth.is_none());
+
+        let yaml = serde_yaml_ng::to_string(&stored).unwrap();
+        assert!(yaml.contains("tool: age"));
+        assert!(!yaml.contains("auth:"));
+    }
+
+    #[test]
+    fn test_config_encrypted_auth_requires_encryption_settings() {
+        let yaml = "encrypted_auth: |\n  -----BEGIN AGE ENCRYPTED FILE-----\n";
+        let config: Config = serde_yaml_ng::from_str(yaml).unwrap();
+        assert!(matches!(config.auth(), Err(JanusError::Config(_))));
+    }
+
+    #[test]
+    fn test_config_to_stored_keeps_untouched_ciphertext() {
+        let yaml =
+            "encryption:\n  tool: age\n  recipients: [age1example]\nencrypted_auth: ciphertext\n";
+        let mut config: Config = serde_yaml_ng::from_str(yaml).unwrap();
+        assert!(config.has_stored_auth());
+
+        // Changing an unrelated setting neither decrypts nor re-encrypts
+        config.remote_timeout = 10;
+        let stored = config.to_stored().unwrap();
+        assert_eq!(stored.encrypted_auth.as_deref(), Some("ciphertext"));
+        assert!(stored.auth.is_default());
+    }
+
     #[test]
     fn test_config_semantic_search_default() {
         // Test that configs without semantic_search field default to enabled
@@ -470,6 +1641,34 @@ semantic_search:
         assert!(config.semantic_search_enabled());
     }
 
+    #[test]
+    fn test_doc_chunking_config_partial() {
+        let yaml = r#"
+doc_chunking:
+  overlap: 150
+"#;
+
+        let config: Config = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(config.doc_chunking.chunk_size, 2000);
+        assert_eq!(config.doc_chunking.overlap, 150);
+        assert!(config.doc_chunking.split_on_headings);
+        assert!(!config.doc_chunking.is_default());
+        assert!(Config::default().doc_chunking.is_default());
+    }
+
+    #[test]
+    fn test_git_config() {
+        assert!(!Config::default().git_auto_commit());
+
+        let config: Config = serde_yaml_ng::from_str("git:\n  auto_commit: true\n").unwrap();
+        assert!(config.git_auto_commit());
+        assert!(
+            serde_yaml_ng::to_string(&config)
+                .unwrap()
+                .contains("auto_commit: true")
+        );
+    }
+
     #[test]
     fn test_hooks_config_default() {
         let config = HooksConfig::default();
@@ -491,4 +1690,146 @@ semantic_search:
         config.timeout = 60;
         assert!(!config.is_default());
     }
+
+    #[test]
+    fn test_sla_config_parse() {
+        let yaml = r#"
+sla:
+  rules:
+    - name: p0-response
+      priority: 0
+      start_within: 24h
+    - type: bug
+      close_within: 14d
+"#;
+        let config: Config = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(config.sla.at_risk_percent, 80);
+        assert_eq!(config.sla.rules.len(), 2);
+
+        let p0 = &config.sla.rules[0];
+        assert_eq!(p0.label(), "p0-response");
+        assert_eq!(
+            p0.start_within.as_ref().unwrap().duration().as_secs(),
+            86_400
+        );
+        assert!(p0.matches(TicketPriority::P0, TicketType::Task));
+        assert!(!p0.matches(TicketPriority::P1, TicketType::Task));
+
+        let bug = &config.sla.rules[1];
+        assert_eq!(bug.label(), "bug");
+        assert_eq!(bug.close_within.as_ref().unwrap().to_string(), "14d");
+
+        let yaml = serde_yaml_ng::to_string(&config).unwrap();
+        assert!(yaml.contains("close_within: 14d"));
+        assert!(!yaml.contains("at_risk_percent"));
+
+        let invalid = "sla:\n  rules:\n    - start_within: soon\n";
+        assert!(serde_yaml_ng::from_str::<Config>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_escalation_config_parse() {
+        let yaml = r#"
+escalation:
+  rules:
+    - type: bug
+      older_than: 30d
+      max_priority: 1
+    - name: stale
+      older_than: 8w
+"#;
+        let config: Config = serde_yaml_ng::from_str(yaml).unwrap();
+        let bug = &config.escalation.rules[0];
+        assert_eq!(bug.label(), "bug older than 30d");
+        assert_eq!(bug.max_priority, TicketPriority::P1);
+        assert!(bug.matches(TicketPriority::P3, TicketType::Bug));
+        assert!(!bug.matches(TicketPriority::P3, TicketType::Task));
+        assert_eq!(config.escalation.rules[1].max_priority, TicketPriority::P0);
+
+        let invalid = "escalation:\n  rules:\n    - type: bug\n";
+        assert!(serde_yaml_ng::from_str::<Config>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_capacity_config_parse() {
+        let yaml = r#"
+capacity:
+  wip_limit: 3
+  size_budget: 10
+  assignees:
+    sam:
+      wip_limit: 2
+"#;
+        let config: Config = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(
+            config.capacity.limits_for("Sam"),
+            CapacityLimits {
+                wip_limit: Some(2),
+                size_budget: Some(10),
+            }
+        );
+        assert_eq!(config.capacity.limits_for("alex").wip_limit, Some(3));
+
+        let yaml = serde_yaml_ng::to_string(&config).unwrap();
+        assert!(yaml.contains("size_budget: 10"));
+        assert!(Config::default().capacity.is_default());
+    }
+
+    #[test]
+    fn test_display_config_parse() {
+        let yaml = r#"
+display:
+  accent: blue
+  status:
+    in_progress: green
+    cancelled: gray
+"#;
+        let config: Config = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(config.display.accent, Some(ThemeColor::Blue));
+        assert_eq!(
+            config.display.status.get(TicketStatus::InProgress),
+            Some(ThemeColor::Green)
+        );
+        assert_eq!(config.display.status.get(TicketStatus::New), None);
+        assert!(Config::default().display.is_default());
+
+        let bad = "display:\n  accent: chartreuse\n";
+        assert!(serde_yaml_ng::from_str::<Config>(bad).is_err());
+    }
+
+    #[test]
+    fn test_component_for_path() {
+        let yaml = r#"
+components:
+  paths:
+    api: [src/api/, crates/server]
+    auth: [src/api/auth]
+"#;
+        let config: Config = serde_yaml_ng::from_str(yaml).unwrap();
+        let components = &config.components;
+        assert!(!components.suggest);
+        assert_eq!(
+            components.component_for_path("src/api/routes.rs"),
+            Some("api")
+        );
+        assert_eq!(components.component_for_path("src/api"), Some("api"));
+        assert_eq!(
+            components.component_for_path("src/api/auth/token.rs"),
+            Some("auth")
+        );
+        assert_eq!(components.component_for_path("crates/server-utils"), None);
+        assert_eq!(components.component_for_path("README.md"), None);
+        assert_eq!(components.prefix_for("api"), None);
+    }
+
+    #[test]
+    fn test_component_prefixes() {
+        let yaml = "components:\n  prefixes:\n    api: api\n    web: ui\n";
+        let config: Config = serde_yaml_ng::from_str(yaml).unwrap();
+        let components = &config.components;
+        assert_eq!(components.prefix_for("web"), Some("ui"));
+        assert_eq!(components.prefix_for("API"), Some("api"));
+        assert_eq!(components.prefix_for("perf"), None);
+        assert!(!components.is_default());
+    }
 }
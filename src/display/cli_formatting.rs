@@ -1,5 +1,5 @@
+use crate::config::ThemeColor;
 use crate::types::TicketMetadata;
-use owo_colors::OwoColorize;
 
 /// Format a ticket for single-line display with colors
 pub fn format_ticket_line(
@@ -27,13 +27,13 @@ pub fn format_ticket_line(
 
     let colored_status = super::format_status_colored(status);
 
-    let colored_id = id_padded.cyan().to_string();
+    let colored_id = super::accent(&id_padded);
 
     // Color priority if P0 or P1
     let colored_priority = if options.show_priority {
         match ticket.priority.map(|p| p.as_num()) {
-            Some(0) => priority_str.red().to_string(),
-            Some(1) => priority_str.yellow().to_string(),
+            Some(0) => super::paint(&priority_str, ThemeColor::Red),
+            Some(1) => super::paint(&priority_str, ThemeColor::Yellow),
             _ => priority_str,
         }
     } else {
@@ -50,7 +50,7 @@ pub fn format_ticket_bullet(ticket: &TicketMetadata) -> String {
     let title = ticket.title.as_deref().unwrap_or("");
     format!(
         "- {} {} {}",
-        id.cyan(),
+        super::accent(id),
         super::format_status_colored(status),
         title
     )
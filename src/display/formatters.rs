@@ -4,7 +4,6 @@ use std::collections::HashMap;
 
 use crate::commands::NextItemResult;
 use crate::types::TicketMetadata;
-use owo_colors::OwoColorize;
 
 /// Ticket display formatters
 pub struct TicketFormatter;
@@ -33,10 +32,10 @@ impl PlanNextFormatter {
         ticket_map: &HashMap<String, TicketMetadata>,
     ) -> String {
         let mut output = String::new();
-        output.push_str(&format!(
-            "{}",
-            format!("## Next: Phase {} - {}", item.phase_number, item.phase_name).bold()
-        ));
+        output.push_str(&crate::display::bold(&format!(
+            "## Next: Phase {} - {}",
+            item.phase_number, item.phase_name
+        )));
         output.push('\n');
 
         for (i, (ticket_id, ticket_meta)) in item.tickets.iter().enumerate() {
@@ -75,7 +74,7 @@ impl PlanNextFormatter {
         output.push_str(&format!(
             "{} {} {}\n",
             status_badge,
-            ticket_id.cyan(),
+            crate::display::accent(ticket_id),
             title
         ));
 
@@ -0,0 +1,265 @@
+//! Terminal rendering of markdown (`janus show --render`).
+//!
+//! The body is parsed with comrak and each block is rendered to lines with
+//! styling from the display theme: headings are bold and underlined, list
+//! items get bullets, code blocks are indented, and tables are aligned into
+//! columns. Only the styling depends on [`colors_enabled`]; the layout is the
+//! same when color is off, so piped output still reads well.
+
+use std::cell::RefCell;
+
+use comrak::arena_tree::Node;
+use comrak::nodes::{Ast, ListType, NodeList, NodeValue};
+use comrak::{Arena, Options};
+use owo_colors::OwoColorize;
+
+use super::theme::{accent, bold, colors_enabled, dim, paint, strip_colors};
+use crate::config::ThemeColor;
+
+type AstNode<'a> = Node<'a, RefCell<Ast>>;
+
+/// Width of the line drawn for a thematic break
+const RULE_WIDTH: usize = 40;
+
+/// Render markdown for the terminal.
+pub fn render_markdown(markdown: &str) -> String {
+    let mut options = Options::default();
+    options.extension.tasklist = true;
+    options.extension.strikethrough = true;
+    options.extension.table = true;
+
+    let arena = Arena::new();
+    let root = comrak::parse_document(&arena, markdown, &options);
+    render_blocks(root, false).join("\n")
+}
+
+/// Render the block children of `node`, separated by blank lines unless
+/// `tight` (the paragraphs of a tight list item)
+fn render_blocks<'a>(node: &'a AstNode<'a>, tight: bool) -> Vec<String> {
+    let mut lines = Vec::new();
+    for child in node.children() {
+        let block = render_block(child);
+        if block.is_empty() {
+            continue;
+        }
+        if !tight && !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.extend(block);
+    }
+    lines
+}
+
+fn render_block<'a>(node: &'a AstNode<'a>) -> Vec<String> {
+    match &node.data.borrow().value {
+        NodeValue::Heading(heading) => {
+            let (color, rule) = match heading.level {
+                1 => (ThemeColor::Magenta, Some("═")),
+                2 => (ThemeColor::Blue, Some("─")),
+                _ => (ThemeColor::Cyan, None),
+            };
+            let text = render_inline(node);
+            let mut lines = vec![bold(&paint(&text, color))];
+            if let Some(rule) = rule {
+                lines.push(dim(&rule.repeat(width(&text))));
+            }
+            lines
+        }
+        NodeValue::Paragraph => render_inline(node).lines().map(String::from).collect(),
+        NodeValue::CodeBlock(code) => code
+            .literal
+            .lines()
+            .map(|line| format!("    {}", paint(line, ThemeColor::Yellow)))
+            .collect(),
+        NodeValue::List(list) => render_list(node, list),
+        NodeValue::BlockQuote => render_blocks(node, false)
+            .into_iter()
+            .map(|line| format!("{} {line}", dim("│")))
+            .collect(),
+        NodeValue::ThematicBreak => vec![dim(&"─".repeat(RULE_WIDTH))],
+        NodeValue::Table(_) => render_table(node),
+        NodeValue::HtmlBlock(html) => html.literal.lines().map(String::from).collect(),
+        NodeValue::FrontMatter(_) => Vec::new(),
+        _ => render_blocks(node, false),
+    }
+}
+
+/// Render list items with their bullet (or number) and the item's remaining
+/// lines indented beneath it
+fn render_list<'a>(node: &'a AstNode<'a>, list: &NodeList) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (i, item) in node.children().enumerate() {
+        let marker = match list.list_type {
+            ListType::Bullet => "•".to_string(),
+            ListType::Ordered => format!("{}.", list.start + i),
+        };
+        let marker = match &item.data.borrow().value {
+            NodeValue::TaskItem(Some(_)) => format!("{marker} [x]"),
+            NodeValue::TaskItem(None) => format!("{marker} [ ]"),
+            _ => marker,
+        };
+        let indent = " ".repeat(marker.chars().count() + 1);
+
+        if !list.tight && !lines.is_empty() {
+            lines.push(String::new());
+        }
+        let mut body = render_blocks(item, list.tight).into_iter();
+        lines.push(format!(
+            "{} {}",
+            accent(&marker),
+            body.next().unwrap_or_default()
+        ));
+        lines.extend(body.map(|line| {
+            if line.is_empty() {
+                line
+            } else {
+                format!("{indent}{line}")
+            }
+        }));
+    }
+    lines
+}
+
+/// Render a table with its columns padded to a common width
+fn render_table<'a>(node: &'a AstNode<'a>) -> Vec<String> {
+    let rows: Vec<(bool, Vec<String>)> = node
+        .children()
+        .map(|row| {
+            let header = matches!(row.data.borrow().value, NodeValue::TableRow(true));
+            (header, row.children().map(render_inline).collect())
+        })
+        .collect();
+
+    let columns = rows.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| {
+            rows.iter()
+                .filter_map(|(_, cells)| cells.get(i))
+                .map(|cell| width(cell))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let separator = dim(" │ ");
+    let mut lines = Vec::new();
+    for (header, cells) in &rows {
+        let padded: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(i, &column_width)| {
+                let cell = cells.get(i).map(String::as_str).unwrap_or_default();
+                let cell = if *header {
+                    bold(cell)
+                } else {
+                    cell.to_string()
+                };
+                if i + 1 == columns {
+                    cell
+                } else {
+                    format!("{cell}{}", " ".repeat(column_width - width(&cell)))
+                }
+            })
+            .collect();
+        lines.push(padded.join(&separator));
+        if *header {
+            let rules: Vec<String> = widths.iter().map(|&w| "─".repeat(w)).collect();
+            lines.push(dim(&rules.join("─┼─")));
+        }
+    }
+    lines
+}
+
+/// Render the inline children of `node` to a single styled string
+fn render_inline<'a>(node: &'a AstNode<'a>) -> String {
+    node.children().map(render_inline_node).collect()
+}
+
+fn render_inline_node<'a>(node: &'a AstNode<'a>) -> String {
+    match &node.data.borrow().value {
+        NodeValue::Text(text) => text.to_string(),
+        NodeValue::Code(code) => paint(&code.literal, ThemeColor::Yellow),
+        NodeValue::Strong => bold(&render_inline(node)),
+        NodeValue::Emph => styled(&render_inline(node), |s| s.italic().to_string()),
+        NodeValue::Strikethrough => styled(&render_inline(node), |s| s.strikethrough().to_string()),
+        NodeValue::Link(link) => {
+            let text = render_inline(node);
+            let label = styled(&text, |s| s.underline().to_string());
+            if link.url.is_empty() || strip_colors(&text) == link.url {
+                label
+            } else {
+                format!("{label} {}", dim(&format!("({})", link.url)))
+            }
+        }
+        NodeValue::Image(link) => {
+            format!(
+                "[image: {}] {}",
+                render_inline(node),
+                dim(&format!("({})", link.url))
+            )
+        }
+        NodeValue::SoftBreak => " ".to_string(),
+        NodeValue::LineBreak => "\n".to_string(),
+        NodeValue::HtmlInline(html) => html.to_string(),
+        _ => render_inline(node),
+    }
+}
+
+/// Apply a text style, or return `text` unchanged when color is off
+fn styled(text: &str, style: impl Fn(&str) -> String) -> String {
+    if colors_enabled() {
+        style(text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Visible width of `text`, ignoring color codes
+fn width(text: &str) -> usize {
+    strip_colors(text).chars().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_plain(markdown: &str) -> String {
+        strip_colors(&render_markdown(markdown))
+    }
+
+    #[test]
+    fn test_render_blocks() {
+        let markdown = "# Title\n\nSome **bold** and `code`.\n\n## Design\n\n```rust\nlet x = 1;\n```\n\n> quoted\n";
+        assert_eq!(
+            render_plain(markdown),
+            "Title\n═════\n\nSome bold and code.\n\nDesign\n──────\n\n    let x = 1;\n\n│ quoted"
+        );
+    }
+
+    #[test]
+    fn test_render_lists() {
+        let markdown =
+            "- one\n- two\n  - nested\n\n1. first\n2. second\n\n- [x] done\n- [ ] todo\n";
+        assert_eq!(
+            render_plain(markdown),
+            "• one\n• two\n  • nested\n\n1. first\n2. second\n\n• [x] done\n• [ ] todo"
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let markdown = "| Name | Size |\n|------|------|\n| a | 10 |\n| longer | 2 |\n";
+        assert_eq!(
+            render_plain(markdown),
+            "Name   │ Size\n───────┼─────\na      │ 10\nlonger │ 2"
+        );
+    }
+
+    #[test]
+    fn test_render_link() {
+        assert_eq!(
+            render_plain("See [docs](https://example.com) or <https://example.com>."),
+            "See docs (https://example.com) or https://example.com."
+        );
+    }
+}
@@ -1,13 +1,21 @@
 use crate::types::TicketStatus;
-use owo_colors::OwoColorize;
 
 pub mod cli_formatting;
 pub mod data_formatting;
 pub mod formatters;
+pub mod markdown;
+pub mod pager;
+pub mod theme;
 
 pub use cli_formatting::*;
 pub use data_formatting::*;
 pub use formatters::*;
+pub use markdown::render_markdown;
+pub use pager::{disable_pager, print_paged};
+pub use theme::{
+    ColorChoice, accent, bold, cli_theme, colors_enabled, dim, heading, highlight_terms, paint,
+    set_color_choice, strip_colors,
+};
 
 pub fn format_status_colored(status: TicketStatus) -> String {
     format_status_colored_with_format(status, |s| format!("[{s}]"))
@@ -18,12 +26,5 @@ where
     F: Fn(&str) -> String,
 {
     let badge = format_fn(&status.to_string());
-    match status {
-        TicketStatus::New => badge.yellow().to_string(),
-        TicketStatus::Next => badge.magenta().to_string(),
-        TicketStatus::InProgress => badge.cyan().to_string(),
-        TicketStatus::Complete => badge.green().to_string(),
-        TicketStatus::Cancelled => badge.dimmed().to_string(),
-        TicketStatus::Archived => badge.dimmed().to_string(),
-    }
+    paint(&badge, cli_theme().status_color(status))
 }
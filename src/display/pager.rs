@@ -0,0 +1,107 @@
+//! Paging long output through `$PAGER`, the way git does.
+//!
+//! Output that fits in the terminal is printed directly. Longer output goes to
+//! `$PAGER` (default `less`, run with `LESS=FRX` unless `LESS` is already set so
+//! colors survive and short output still exits immediately). Nothing is paged
+//! when stdout is not a terminal or `--no-pager` was passed.
+
+use std::io::{ErrorKind, Write};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set by `--no-pager` for the rest of the process
+static PAGER_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Pager used when `$PAGER` is unset
+const DEFAULT_PAGER: &str = "less";
+
+/// Never page output for the rest of the process.
+pub fn disable_pager() {
+    PAGER_DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Print `text`, through the pager when it is taller than the terminal.
+pub fn print_paged(text: &str) {
+    if let Some(pager) = pager_for(text)
+        && page(&pager, text)
+    {
+        return;
+    }
+    println!("{text}");
+}
+
+/// The pager command to run for `text`, if it should be paged at all
+fn pager_for(text: &str) -> Option<Vec<String>> {
+    if PAGER_DISABLED.load(Ordering::Relaxed) || !atty::is(atty::Stream::Stdout) {
+        return None;
+    }
+    let (_, rows) = crossterm::terminal::size().ok()?;
+    if !exceeds_height(text, rows) {
+        return None;
+    }
+    pager_command(std::env::var("PAGER").ok().as_deref())
+}
+
+/// Whether `text` needs more rows than the terminal has, leaving one for the
+/// prompt
+fn exceeds_height(text: &str, rows: u16) -> bool {
+    text.lines().count() >= usize::from(rows)
+}
+
+/// Split `$PAGER` into a command and its arguments. An empty value or `cat`
+/// turns paging off.
+fn pager_command(pager: Option<&str>) -> Option<Vec<String>> {
+    let pager = pager.unwrap_or(DEFAULT_PAGER);
+    let args: Vec<String> = pager.split_whitespace().map(String::from).collect();
+    match args.first().map(String::as_str) {
+        None | Some("cat") => None,
+        Some(_) => Some(args),
+    }
+}
+
+/// Run the pager with `text` on its stdin. Returns false when the pager could
+/// not be started, so the caller can print directly instead.
+fn page(pager: &[String], text: &str) -> bool {
+    let mut command = Command::new(&pager[0]);
+    command.args(&pager[1..]).stdin(Stdio::piped());
+    if std::env::var_os("LESS").is_none() {
+        command.env("LESS", "FRX");
+    }
+    let Ok(mut child) = command.spawn() else {
+        return false;
+    };
+
+    // Quitting the pager before the end closes the pipe, which is fine; any
+    // other write error means the output never reached the pager
+    if let Some(mut stdin) = child.stdin.take()
+        && let Err(e) = writeln!(stdin, "{text}")
+        && e.kind() != ErrorKind::BrokenPipe
+    {
+        let _ = child.wait();
+        return false;
+    }
+    let _ = child.wait();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exceeds_height() {
+        assert!(!exceeds_height("one\ntwo", 24));
+        assert!(exceeds_height(&"line\n".repeat(24), 24));
+    }
+
+    #[test]
+    fn test_pager_command() {
+        assert_eq!(pager_command(None), Some(vec!["less".to_string()]));
+        assert_eq!(
+            pager_command(Some("less -S")),
+            Some(vec!["less".to_string(), "-S".to_string()])
+        );
+        assert_eq!(pager_command(Some("")), None);
+        assert_eq!(pager_command(Some("cat")), None);
+    }
+}
@@ -0,0 +1,258 @@
+//! Color control (`--color`, `NO_COLOR`) and the CLI color theme.
+//!
+//! Everything the display module colors goes through [`paint`], which
+//! applies the `display` config and emits nothing when color is off.
+//! [`strip_colors`] is the backstop for text colored elsewhere.
+
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use owo_colors::{AnsiColors, OwoColorize};
+use regex::Regex;
+
+use crate::config::{Config, DisplayConfig, ThemeColor};
+use crate::types::TicketStatus;
+
+/// When to color output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal and `NO_COLOR` is unset
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// All valid string representations of this enum.
+    pub const ALL_STRINGS: &[&str] = &["auto", "always", "never"];
+}
+
+enum_display_fromstr!(
+    ColorChoice,
+    crate::error::JanusError::invalid_color_choice,
+    ["auto", "always", "never"],
+    {
+        Auto => "auto",
+        Always => "always",
+        Never => "never",
+    }
+);
+
+/// Set by `--color` for the rest of the process
+static COLOR_CHOICE: AtomicU8 = AtomicU8::new(0);
+
+/// Choose when to color output for the rest of the process.
+pub fn set_color_choice(choice: ColorChoice) {
+    let value = match choice {
+        ColorChoice::Auto => 0,
+        ColorChoice::Always => 1,
+        ColorChoice::Never => 2,
+    };
+    COLOR_CHOICE.store(value, Ordering::Relaxed);
+}
+
+/// Whether output should be colored.
+///
+/// `--color always` and `--color never` win; otherwise color is used when
+/// stdout is a terminal and `NO_COLOR` is unset or empty.
+pub fn colors_enabled() -> bool {
+    match COLOR_CHOICE.load(Ordering::Relaxed) {
+        1 => true,
+        2 => false,
+        _ => {
+            std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+                && atty::is(atty::Stream::Stdout)
+        }
+    }
+}
+
+/// Colors for CLI output, from the `display` config over the defaults.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliTheme {
+    pub accent: ThemeColor,
+    display: DisplayConfig,
+}
+
+impl CliTheme {
+    pub fn from_config(display: &DisplayConfig) -> Self {
+        Self {
+            accent: display.accent.unwrap_or(ThemeColor::Cyan),
+            display: display.clone(),
+        }
+    }
+
+    /// Badge color for a ticket status
+    pub fn status_color(&self, status: TicketStatus) -> ThemeColor {
+        self.display
+            .status
+            .get(status)
+            .unwrap_or_else(|| default_status_color(status))
+    }
+}
+
+impl Default for CliTheme {
+    fn default() -> Self {
+        Self::from_config(&DisplayConfig::default())
+    }
+}
+
+/// Status colors used when the config sets none
+pub fn default_status_color(status: TicketStatus) -> ThemeColor {
+    match status {
+        TicketStatus::New => ThemeColor::Yellow,
+        TicketStatus::Next => ThemeColor::Magenta,
+        TicketStatus::InProgress => ThemeColor::Cyan,
+        TicketStatus::Complete => ThemeColor::Green,
+        TicketStatus::Cancelled | TicketStatus::Archived => ThemeColor::Gray,
+    }
+}
+
+static THEME: LazyLock<CliTheme> = LazyLock::new(|| {
+    Config::load()
+        .map(|config| CliTheme::from_config(&config.display))
+        .unwrap_or_default()
+});
+
+/// The theme for this process, loaded from the config on first use
+pub fn cli_theme() -> &'static CliTheme {
+    &THEME
+}
+
+/// Color `text`, or return it unchanged when color is off.
+pub fn paint(text: &str, color: ThemeColor) -> String {
+    if !colors_enabled() {
+        return text.to_string();
+    }
+    let ansi = match color {
+        ThemeColor::Gray => return text.dimmed().to_string(),
+        ThemeColor::Black => AnsiColors::Black,
+        ThemeColor::Red => AnsiColors::Red,
+        ThemeColor::Green => AnsiColors::Green,
+        ThemeColor::Yellow => AnsiColors::Yellow,
+        ThemeColor::Blue => AnsiColors::Blue,
+        ThemeColor::Magenta => AnsiColors::Magenta,
+        ThemeColor::Cyan => AnsiColors::Cyan,
+        ThemeColor::White => AnsiColors::White,
+    };
+    text.color(ansi).to_string()
+}
+
+/// Color `text` with the theme's accent (ticket IDs, labels)
+pub fn accent(text: &str) -> String {
+    paint(text, cli_theme().accent)
+}
+
+/// Bold `text` (headings)
+pub fn bold(text: &str) -> String {
+    if colors_enabled() {
+        text.bold().to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// Label for a block of details, such as `Children:` in `janus show`
+pub fn heading(text: &str) -> String {
+    bold(&paint(text, ThemeColor::Green))
+}
+
+/// Dim `text` (tree guides, secondary details)
+pub fn dim(text: &str) -> String {
+    paint(text, ThemeColor::Gray)
+}
+
+/// Highlight every case-insensitive occurrence of `terms` in `text`, for
+/// search snippets. `terms` must be lowercase.
+pub fn highlight_terms(text: &str, terms: &[String]) -> String {
+    // ASCII lowercasing keeps byte offsets aligned with `text`
+    let lower = text.to_ascii_lowercase();
+    let mut out = String::with_capacity(text.len());
+    let mut pos = 0;
+
+    while pos < text.len() {
+        let next = terms
+            .iter()
+            .filter(|term| !term.is_empty())
+            .filter_map(|term| {
+                lower[pos..]
+                    .find(term.as_str())
+                    .map(|i| (pos + i, term.len()))
+            })
+            .min_by_key(|&(start, len)| (start, std::cmp::Reverse(len)));
+
+        match next {
+            Some((start, len)) if text.is_char_boundary(start + len) => {
+                out.push_str(&text[pos..start]);
+                out.push_str(&text[start..start + len].yellow().bold().to_string());
+                pos = start + len;
+            }
+            _ => {
+                out.push_str(&text[pos..]);
+                break;
+            }
+        }
+    }
+
+    out
+}
+
+static ANSI_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\x1b\[[0-9;]*m").expect("ANSI regex should be valid"));
+
+/// Remove ANSI color codes from `text`.
+pub fn strip_colors(text: &str) -> String {
+    ANSI_RE.replace_all(text, "").into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_terms() {
+        let terms = vec!["retry".to_string(), "policy".to_string()];
+        assert_eq!(
+            highlight_terms("Retry Policy: retry twice", &terms),
+            format!(
+                "{} {}: {} twice",
+                "Retry".yellow().bold(),
+                "Policy".yellow().bold(),
+                "retry".yellow().bold()
+            )
+        );
+        assert_eq!(highlight_terms("no match here", &terms), "no match here");
+    }
+    use crate::config::StatusColors;
+
+    #[test]
+    fn test_theme_status_colors() {
+        let display = DisplayConfig {
+            accent: Some(ThemeColor::Blue),
+            status: StatusColors {
+                in_progress: Some(ThemeColor::Green),
+                ..Default::default()
+            },
+        };
+        let theme = CliTheme::from_config(&display);
+        assert_eq!(theme.accent, ThemeColor::Blue);
+        assert_eq!(
+            theme.status_color(TicketStatus::InProgress),
+            ThemeColor::Green
+        );
+        assert_eq!(theme.status_color(TicketStatus::New), ThemeColor::Yellow);
+        assert_eq!(CliTheme::default().accent, ThemeColor::Cyan);
+    }
+
+    #[test]
+    fn test_strip_colors() {
+        let colored = format!("{} {}", "j-a1b2".cyan(), "[new]".dimmed());
+        assert_eq!(strip_colors(&colored), "j-a1b2 [new]");
+    }
+
+    #[test]
+    fn test_color_choice_parse() {
+        assert_eq!("NEVER".parse::<ColorChoice>().unwrap(), ColorChoice::Never);
+        assert!("sometimes".parse::<ColorChoice>().is_err());
+    }
+}
@@ -1,157 +1,191 @@
 //! AST-based document chunker using comrak.
 //!
 //! This module provides document chunking by parsing markdown into an AST
-//! and creating chunks at heading boundaries. Each chunk tracks its heading
-//! path, content, and line numbers for precise referencing.
+//! and grouping its top-level blocks into chunks. Chunks start at heading
+//! boundaries and, when a section exceeds the configured size, between
+//! blocks, so code blocks, lists, and tables are never cut in half. Each
+//! chunk tracks its heading path, source text, and line numbers for precise
+//! referencing.
 
 use comrak::nodes::{Ast, NodeValue};
 use comrak::{Arena, Options};
 
+use crate::config::DocChunkingConfig;
 use crate::doc::types::DocChunk;
 use crate::error::Result;
 
-/// Chunk a document into sections based on heading boundaries.
+/// Chunk a document into sections.
 ///
-/// Uses comrak to parse the markdown into an AST, then walks the tree
-/// to identify chunk boundaries at heading nodes. Each chunk includes:
+/// Uses comrak to parse the markdown body into an AST, then walks its
+/// top-level blocks. Each chunk includes:
 /// - The heading path (hierarchy of headings)
-/// - The chunk content
-/// - Start and end line numbers
+/// - The chunk content, copied verbatim from the source
+/// - Start and end line numbers in the file, including frontmatter
 ///
 /// Headerless regions (intro paragraphs before first heading) are handled
 /// as chunks with an empty heading path.
-pub fn chunk_document(label: &str, content: &str) -> Result<Vec<DocChunk>> {
-    let mut options = Options::default();
-    options.extension.front_matter_delimiter = Some("---".to_string());
+pub fn chunk_document(
+    label: &str,
+    content: &str,
+    config: &DocChunkingConfig,
+) -> Result<Vec<DocChunk>> {
+    let (line_offset, body) = split_frontmatter_lines(content);
+    let lines: Vec<&str> = body.lines().collect();
 
     let arena = Arena::new();
-    let root = comrak::parse_document(&arena, content, &options);
-
-    let mut chunks = Vec::new();
-    let mut current_chunk_start: Option<usize> = None;
-    let mut current_chunk_content = String::new();
-    let mut heading_stack: Vec<String> = Vec::new();
-    let mut last_heading_level: u8 = 0;
-
-    // Walk the AST and collect chunks
-    walk_node(
-        root,
-        content,
-        &mut chunks,
-        &mut current_chunk_start,
-        &mut current_chunk_content,
-        &mut heading_stack,
-        &mut last_heading_level,
+    let root = comrak::parse_document(&arena, body, &Options::default());
+
+    let mut builder = ChunkBuilder {
         label,
-    );
-
-    // Handle any remaining content as a final chunk
-    if let Some(start_line) = current_chunk_start
-        && !current_chunk_content.trim().is_empty()
-    {
-        let end_line = content.lines().count();
-        chunks.push(DocChunk::new(
-            label,
-            heading_stack.clone(),
-            current_chunk_content.trim().to_string(),
-            start_line,
-            end_line,
-        ));
+        config,
+        lines: &lines,
+        line_offset,
+        heading_stack: Vec::new(),
+        heading_path: Vec::new(),
+        blocks: Vec::new(),
+        len: 0,
+        chunks: Vec::new(),
+    };
+
+    for node in root.children() {
+        let data = node.data.borrow();
+        let start = data.sourcepos.start.line.max(1);
+        let end = data.sourcepos.end.line.clamp(start, lines.len().max(start));
+        let block = Block {
+            start,
+            end,
+            len: builder.source(start, end).len(),
+        };
+
+        if let NodeValue::Heading(ref heading) = data.value {
+            builder.heading(heading.level, extract_heading_text(node), block);
+        } else {
+            builder.push(block);
+        }
     }
 
-    Ok(chunks)
+    builder.flush();
+    Ok(builder.chunks)
 }
 
-/// Walk a node and collect chunks.
-#[allow(clippy::too_many_arguments)]
-fn walk_node<'a>(
-    node: &'a comrak::arena_tree::Node<'a, std::cell::RefCell<Ast>>,
-    _content: &str,
-    chunks: &mut Vec<DocChunk>,
-    current_chunk_start: &mut Option<usize>,
-    current_chunk_content: &mut String,
-    heading_stack: &mut Vec<String>,
-    last_heading_level: &mut u8,
-    label: &str,
-) {
-    let data = node.data.borrow();
+/// Number of frontmatter lines and the body that follows them.
+///
+/// Chunk line numbers are relative to the file, so the frontmatter is
+/// stripped here rather than handed to comrak.
+fn split_frontmatter_lines(content: &str) -> (usize, &str) {
+    let mut lines = content.split_inclusive('\n');
+    if lines.next().map(str::trim_end) != Some("---") {
+        return (0, content);
+    }
 
-    // Process heading nodes - these create chunk boundaries
-    if let NodeValue::Heading(ref heading) = data.value {
-        // Finish the current chunk before starting a new one
-        if let Some(start_line) = *current_chunk_start
-            && !current_chunk_content.trim().is_empty()
-        {
-            let end_line = data.sourcepos.start.line.saturating_sub(1).max(start_line);
-            chunks.push(DocChunk::new(
-                label,
-                heading_stack.clone(),
-                current_chunk_content.trim().to_string(),
-                start_line,
-                end_line,
-            ));
+    let mut offset = content.find('\n').map_or(content.len(), |i| i + 1);
+    for (i, line) in lines.enumerate() {
+        offset += line.len();
+        if line.trim_end() == "---" {
+            return (i + 2, &content[offset..]);
         }
+    }
+    (0, content)
+}
+
+/// A top-level markdown block, by 1-indexed body line range.
+#[derive(Debug, Clone, Copy)]
+struct Block {
+    start: usize,
+    end: usize,
+    len: usize,
+}
+
+/// Groups consecutive blocks into chunks.
+struct ChunkBuilder<'a> {
+    label: &'a str,
+    config: &'a DocChunkingConfig,
+    lines: &'a [&'a str],
+    line_offset: usize,
+    /// Headings enclosing the current position
+    heading_stack: Vec<String>,
+    /// Heading path of the chunk being built
+    heading_path: Vec<String>,
+    blocks: Vec<Block>,
+    len: usize,
+    chunks: Vec<DocChunk>,
+}
 
-        // Extract heading text
-        let heading_text = extract_heading_text(node);
+impl ChunkBuilder<'_> {
+    /// Source text of body lines `start..=end`.
+    fn source(&self, start: usize, end: usize) -> String {
+        self.lines
+            .get(start.saturating_sub(1)..end.min(self.lines.len()))
+            .map(|lines| lines.join("\n"))
+            .unwrap_or_default()
+    }
 
-        // Update heading stack based on level
-        let level = heading.level;
-        while heading_stack.len() >= level as usize {
-            heading_stack.pop();
+    fn heading(&mut self, level: u8, text: String, block: Block) {
+        if self.config.split_on_headings {
+            self.flush();
         }
-        heading_stack.push(heading_text);
-        *last_heading_level = level;
-
-        // Start a new chunk from this heading
-        *current_chunk_start = Some(data.sourcepos.start.line);
-        *current_chunk_content = format!("{}\n", render_heading(node));
-    } else if is_content_node(&data.value) {
-        // This is content - include it in current chunk
-        if current_chunk_start.is_none() {
-            *current_chunk_start = Some(data.sourcepos.start.line);
+
+        while self.heading_stack.len() >= level as usize {
+            self.heading_stack.pop();
         }
+        self.heading_stack.push(text);
+        self.push(block);
+    }
 
-        // Extract the text content
-        let node_content = render_node_to_markdown(node);
-        if !node_content.is_empty() {
-            if !current_chunk_content.is_empty() && !current_chunk_content.ends_with('\n') {
-                current_chunk_content.push('\n');
+    fn push(&mut self, block: Block) {
+        let limit = self.config.chunk_size;
+        if limit > 0 && !self.blocks.is_empty() && self.len + block.len > limit {
+            let carried = self.overlap_blocks();
+            self.flush();
+            for carried_block in carried {
+                self.add(carried_block);
             }
-            current_chunk_content.push_str(&node_content);
         }
+        self.add(block);
     }
 
-    // Recurse into children
-    for child in node.children() {
-        walk_node(
-            child,
-            _content,
-            chunks,
-            current_chunk_start,
-            current_chunk_content,
-            heading_stack,
-            last_heading_level,
-            label,
-        );
+    fn add(&mut self, block: Block) {
+        if self.blocks.is_empty() {
+            self.heading_path = self.heading_stack.clone();
+        }
+        self.len += block.len;
+        self.blocks.push(block);
+    }
+
+    /// Trailing whole blocks that fit in the overlap budget.
+    ///
+    /// The first block is never carried, so consecutive chunks always start
+    /// on different lines.
+    fn overlap_blocks(&self) -> Vec<Block> {
+        let mut carried = Vec::new();
+        let mut len = 0;
+        for block in self.blocks.iter().skip(1).rev() {
+            if len + block.len > self.config.overlap {
+                break;
+            }
+            len += block.len;
+            carried.push(*block);
+        }
+        carried.reverse();
+        carried
     }
-}
 
-/// Check if a node type represents content that should be included in chunks.
-fn is_content_node(value: &NodeValue) -> bool {
-    matches!(
-        value,
-        NodeValue::Paragraph
-            | NodeValue::Text(_)
-            | NodeValue::Code(_)
-            | NodeValue::CodeBlock(_)
-            | NodeValue::List(_)
-            | NodeValue::BlockQuote
-            | NodeValue::Item(_)
-            | NodeValue::Table(_)
-            | NodeValue::LineBreak
-            | NodeValue::SoftBreak
-    )
+    fn flush(&mut self) {
+        if let (Some(first), Some(last)) = (self.blocks.first(), self.blocks.last()) {
+            let content = self.source(first.start, last.end);
+            if !content.trim().is_empty() {
+                self.chunks.push(DocChunk::new(
+                    self.label,
+                    std::mem::take(&mut self.heading_path),
+                    content.trim().to_string(),
+                    first.start + self.line_offset,
+                    last.end + self.line_offset,
+                ));
+            }
+        }
+        self.blocks.clear();
+        self.len = 0;
+    }
 }
 
 /// Extract the text content of a heading node.
@@ -201,116 +235,6 @@ fn extract_text_from_node<'a>(
     text
 }
 
-/// Render a heading node back to markdown.
-fn render_heading<'a>(node: &'a comrak::arena_tree::Node<'a, std::cell::RefCell<Ast>>) -> String {
-    let data = node.data.borrow();
-    if let NodeValue::Heading(heading) = &data.value {
-        let level = heading.level;
-        let text = extract_heading_text(node);
-        format!("{} {}", "#".repeat(level as usize), text)
-    } else {
-        String::new()
-    }
-}
-
-/// Render a node back to markdown format.
-///
-/// This is a simplified renderer that handles common node types.
-fn render_node_to_markdown<'a>(
-    node: &'a comrak::arena_tree::Node<'a, std::cell::RefCell<Ast>>,
-) -> String {
-    let data = node.data.borrow();
-
-    match &data.value {
-        NodeValue::Text(text) => text.clone(),
-        NodeValue::LineBreak => "\n".to_string(),
-        NodeValue::SoftBreak => " ".to_string(),
-        NodeValue::Paragraph => {
-            let mut content = String::new();
-            for child in node.children() {
-                content.push_str(&render_node_to_markdown(child));
-            }
-            content.push('\n');
-            content
-        }
-        NodeValue::CodeBlock(code) => {
-            format!("```{}\n{}```\n", code.info, code.literal)
-        }
-        NodeValue::Code(node_code) => {
-            format!("`{}`", node_code.literal)
-        }
-        NodeValue::BlockQuote => {
-            let mut content = String::new();
-            for child in node.children() {
-                let child_text = render_node_to_markdown(child);
-                for line in child_text.lines() {
-                    content.push_str("> ");
-                    content.push_str(line);
-                    content.push('\n');
-                }
-            }
-            content
-        }
-        NodeValue::List(list) => {
-            let mut content = String::new();
-            let marker = if list.list_type == comrak::nodes::ListType::Ordered {
-                "1. "
-            } else {
-                "- "
-            };
-            for child in node.children() {
-                let item_text = render_node_to_markdown(child);
-                for line in item_text.lines() {
-                    content.push_str(marker);
-                    content.push_str(line);
-                    content.push('\n');
-                }
-            }
-            content
-        }
-        NodeValue::Item(_) => {
-            let mut content = String::new();
-            for child in node.children() {
-                content.push_str(&render_node_to_markdown(child));
-            }
-            content
-        }
-        NodeValue::Strong => {
-            let mut content = String::new();
-            for child in node.children() {
-                content.push_str(&render_node_to_markdown(child));
-            }
-            format!("**{content}**")
-        }
-        NodeValue::Emph => {
-            let mut content = String::new();
-            for child in node.children() {
-                content.push_str(&render_node_to_markdown(child));
-            }
-            format!("*{content}*")
-        }
-        NodeValue::Link(link) => {
-            let mut content = String::new();
-            for child in node.children() {
-                content.push_str(&render_node_to_markdown(child));
-            }
-            format!("[{}]({})", content, link.url)
-        }
-        NodeValue::FrontMatter(_) => {
-            // Skip frontmatter in chunk content
-            String::new()
-        }
-        _ => {
-            // For other node types, just recurse
-            let mut content = String::new();
-            for child in node.children() {
-                content.push_str(&render_node_to_markdown(child));
-            }
-            content
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -335,7 +259,7 @@ More content.
 Content in section 2.
 "#;
 
-        let chunks = chunk_document("test", content).unwrap();
+        let chunks = chunk_document("test", content, &DocChunkingConfig::default()).unwrap();
         // 3 chunks: Title section, Section 1, Section 2
         assert_eq!(chunks.len(), 3);
 
@@ -381,7 +305,7 @@ Content A2.
 Content B.
 "#;
 
-        let chunks = chunk_document("test", content).unwrap();
+        let chunks = chunk_document("test", content, &DocChunkingConfig::default()).unwrap();
 
         // Should have chunks for: Main Title, Section A, Subsection A1, Subsection A2, Section B
         assert_eq!(chunks.len(), 5);
@@ -422,7 +346,7 @@ fn main() {
 > Blockquote here
 "#;
 
-        let chunks = chunk_document("test", content).unwrap();
+        let chunks = chunk_document("test", content, &DocChunkingConfig::default()).unwrap();
         assert_eq!(chunks.len(), 2);
 
         // First chunk (Document section) should have formatting
@@ -451,7 +375,7 @@ Line 2.\n\
 Section line 1.\n\
 Section line 2.\n";
 
-        let chunks = chunk_document("test", content).unwrap();
+        let chunks = chunk_document("test", content, &DocChunkingConfig::default()).unwrap();
         assert_eq!(chunks.len(), 2);
 
         // Line numbers should be reasonable and in order
@@ -465,7 +389,7 @@ Section line 2.\n";
     #[test]
     fn test_chunk_empty_doc() {
         let content = "---\nlabel: test\n---\n";
-        let chunks = chunk_document("test", content).unwrap();
+        let chunks = chunk_document("test", content, &DocChunkingConfig::default()).unwrap();
         // Empty doc (just frontmatter) should produce no chunks
         assert!(chunks.is_empty());
     }
@@ -480,11 +404,70 @@ label: test
 Content right after title.
 "#;
 
-        let chunks = chunk_document("test", content).unwrap();
+        let chunks = chunk_document("test", content, &DocChunkingConfig::default()).unwrap();
         // Should have one chunk: the Title section
         assert_eq!(chunks.len(), 1);
         assert_eq!(chunks[0].heading_path, vec!["Title"]);
         assert!(chunks[0].content.contains("# Title"));
         assert!(chunks[0].content.contains("Content right after title"));
     }
+
+    const GUIDE: &str = "# Guide\n\nFirst paragraph of prose.\n\n```rust\nfn main() {\n    println!(\"hi\");\n}\n```\n\nClosing paragraph.\n";
+
+    #[test]
+    fn test_chunk_line_numbers_include_frontmatter() {
+        let content = "---\nlabel: test\n---\n# Title\n\nBody.\n";
+        let chunks = chunk_document("test", content, &DocChunkingConfig::default()).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!((chunks[0].start_line, chunks[0].end_line), (4, 6));
+        assert_eq!(chunks[0].content, "# Title\n\nBody.");
+    }
+
+    #[test]
+    fn test_chunk_size_keeps_code_blocks_whole() {
+        let config = DocChunkingConfig {
+            chunk_size: 40,
+            overlap: 0,
+            split_on_headings: true,
+        };
+        let chunks = chunk_document("test", GUIDE, &config).unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().all(|c| c.heading_path == vec!["Guide"]));
+
+        let code: Vec<_> = chunks
+            .iter()
+            .filter(|c| c.content.contains("fn main"))
+            .collect();
+        assert_eq!(code.len(), 1);
+        assert!(code[0].content.starts_with("```rust"));
+        assert!(code[0].content.ends_with("```"));
+    }
+
+    #[test]
+    fn test_chunk_overlap_repeats_trailing_blocks() {
+        let config = DocChunkingConfig {
+            chunk_size: 40,
+            overlap: 30,
+            split_on_headings: true,
+        };
+        let chunks = chunk_document("test", GUIDE, &config).unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks[1].content.starts_with("First paragraph"));
+        assert_eq!(chunks[1].start_line, chunks[0].end_line);
+        assert!(chunks[1].content.contains("fn main"));
+    }
+
+    #[test]
+    fn test_chunk_without_heading_splits() {
+        let content = "# Title\n\nIntro.\n\n## Section\n\nMore.\n";
+        let config = DocChunkingConfig {
+            chunk_size: 0,
+            overlap: 0,
+            split_on_headings: false,
+        };
+        let chunks = chunk_document("test", content, &config).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].heading_path, vec!["Title"]);
+        assert!(chunks[0].content.contains("## Section"));
+    }
 }
@@ -0,0 +1,142 @@
+//! HTML to markdown conversion for documents ingested from URLs.
+//!
+//! Pages are reduced to their main content (`<main>` or `<article>` when
+//! present), page chrome such as navigation and scripts is dropped, and the
+//! rest is converted to markdown.
+
+use htmd::HtmlToMarkdown;
+use url::Url;
+
+use crate::error::{JanusError, Result};
+
+/// Elements whose content is never part of the document text
+const SKIPPED_TAGS: &[&str] = &[
+    "script", "style", "noscript", "nav", "header", "footer", "aside", "form", "iframe", "svg",
+];
+
+/// Convert an HTML page to markdown.
+pub fn html_to_markdown(html: &str) -> Result<String> {
+    let converter = HtmlToMarkdown::builder()
+        .skip_tags(SKIPPED_TAGS.to_vec())
+        .build();
+    let markdown = converter
+        .convert(main_content(html))
+        .map_err(|e| JanusError::InvalidFormat(format!("failed to convert HTML: {e}")))?;
+    Ok(markdown.trim().to_string())
+}
+
+/// The page's `<title>`, with whitespace collapsed and basic entities decoded.
+pub fn html_title(html: &str) -> Option<String> {
+    let inner = element_inner(html, "title")?;
+    let title = decode_entities(&inner.split_whitespace().collect::<Vec<_>>().join(" "));
+    (!title.is_empty()).then_some(title)
+}
+
+/// Last non-empty path segment of a URL without its extension
+/// (`https://example.com/rfcs/0042-retries.html` -> `0042-retries`), or the
+/// host for bare domains.
+pub fn url_slug(url: &Url) -> Option<String> {
+    let segment = url
+        .path_segments()
+        .and_then(|mut segments| segments.rfind(|s| !s.is_empty()))
+        .map(|segment| {
+            segment
+                .rsplit_once('.')
+                .map_or(segment, |(stem, _)| stem)
+                .to_string()
+        })
+        .filter(|s| !s.is_empty());
+    segment.or_else(|| url.host_str().map(str::to_string))
+}
+
+/// The `<main>` or `<article>` element of a page, falling back to `<body>`
+/// and then the whole page.
+fn main_content(html: &str) -> &str {
+    ["main", "article", "body"]
+        .iter()
+        .find_map(|tag| element_inner(html, tag))
+        .unwrap_or(html)
+}
+
+/// Inner HTML of the first `<tag>` element, up to the last closing tag so
+/// nested elements of the same name stay intact.
+fn element_inner<'a>(html: &'a str, tag: &str) -> Option<&'a str> {
+    // ASCII lowercasing keeps byte offsets aligned with `html`
+    let lower = html.to_ascii_lowercase();
+    let open = format!("<{tag}");
+    let start = lower.match_indices(&open).find_map(|(i, _)| {
+        let after = i + open.len();
+        matches!(
+            lower.as_bytes().get(after).copied(),
+            Some(b'>' | b' ' | b'\t' | b'\n' | b'\r')
+        )
+        .then_some(after)
+    })?;
+    let content_start = start + lower[start..].find('>')? + 1;
+    let content_end = lower.rfind(&format!("</{tag}>"))?;
+    (content_end >= content_start).then(|| &html[content_start..content_end])
+}
+
+/// Decode the entities that commonly appear in page titles.
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_title() {
+        let html = "<html><head><TITLE>\n  RFC 42:  Retries &amp; Backoff\n</TITLE></head></html>";
+        assert_eq!(
+            html_title(html).as_deref(),
+            Some("RFC 42: Retries & Backoff")
+        );
+        assert_eq!(html_title("<html><title> </title></html>"), None);
+        assert_eq!(html_title("<p>no title</p>"), None);
+    }
+
+    #[test]
+    fn test_main_content_prefers_main_element() {
+        let html = "<body><nav>Menu</nav><main class=\"content\"><h1>Spec</h1></main></body>";
+        assert_eq!(main_content(html), "<h1>Spec</h1>");
+
+        let html = "<body><p>Only body</p></body>";
+        assert_eq!(main_content(html), "<p>Only body</p>");
+
+        // <mainframe> is not <main>
+        assert_eq!(
+            main_content("<mainframe>x</mainframe>"),
+            "<mainframe>x</mainframe>"
+        );
+    }
+
+    #[test]
+    fn test_html_to_markdown_drops_page_chrome() {
+        let html = "<html><body><nav>Home | Docs</nav><script>alert(1)</script>\
+                    <h2>Retry Policy</h2><p>Jobs retry <strong>twice</strong>.</p></body></html>";
+        let markdown = html_to_markdown(html).unwrap();
+        assert!(markdown.contains("Retry Policy"));
+        assert!(markdown.contains("**twice**"));
+        assert!(!markdown.contains("alert"));
+        assert!(!markdown.contains("Home | Docs"));
+    }
+
+    #[test]
+    fn test_url_slug() {
+        let slug = |s: &str| url_slug(&Url::parse(s).unwrap());
+        assert_eq!(
+            slug("https://example.com/rfcs/0042-retries.html").as_deref(),
+            Some("0042-retries")
+        );
+        assert_eq!(slug("https://example.com/guide/").as_deref(), Some("guide"));
+        assert_eq!(slug("https://example.com").as_deref(), Some("example.com"));
+    }
+}
@@ -6,6 +6,7 @@
 //! and tags.
 
 pub mod chunker;
+pub mod html;
 pub mod parser;
 pub mod types;
 
@@ -13,11 +14,13 @@ pub use chunker::chunk_document;
 pub use parser::{parse_doc_content, serialize_doc};
 pub use types::{DocChunk, DocLabel, DocLoadResult, DocMetadata};
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
 use crate::entity::Entity;
 use crate::error::{JanusError, Result};
+use crate::types::TicketMetadata;
 use crate::utils::find_markdown_files;
 
 /// A document handle for reading and writing document files.
@@ -112,7 +115,7 @@ impl Doc {
     /// Write content to the document file.
     pub fn write(&self, content: &str) -> Result<()> {
         self.ensure_parent_dir()?;
-        crate::fs::write_file_atomic(&self.file_path, content)
+        crate::fs::write_document_atomic(&self.file_path, content)
     }
 
     /// Write metadata to the document file.
@@ -143,6 +146,7 @@ impl Doc {
                 source: e,
             });
         }
+        crate::fs::record_path(&self.file_path);
         Ok(())
     }
 
@@ -160,6 +164,7 @@ impl Doc {
                 JanusError::InvalidFormat("file not found".to_string())
             }
         })?;
+        crate::fs::record_path(&self.file_path);
         Ok(())
     }
 }
@@ -307,6 +312,47 @@ pub fn sanitize_label(title: &str) -> String {
     label.trim_matches('-').to_string()
 }
 
+/// IDs of the tickets related to a document, sorted.
+///
+/// A ticket is related when the document lists it in its `tickets` frontmatter
+/// or when the ticket lists the document's label in its `docs` field.
+pub fn related_tickets(
+    label: &str,
+    doc: &DocMetadata,
+    tickets: &HashMap<String, TicketMetadata>,
+) -> Vec<String> {
+    let mut ids: Vec<String> = doc.tickets.clone();
+    ids.extend(
+        tickets
+            .iter()
+            .filter(|(_, ticket)| ticket.docs.iter().any(|d| d == label))
+            .map(|(id, _)| id.clone()),
+    );
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+/// Labels of the documents related to a ticket, sorted.
+///
+/// The inverse of [`related_tickets`]: the labels in the ticket's `docs` field
+/// plus every document whose `tickets` frontmatter lists the ticket.
+pub fn related_docs<'a>(
+    ticket_id: &str,
+    ticket_docs: &[String],
+    docs: impl IntoIterator<Item = &'a DocMetadata>,
+) -> Vec<String> {
+    let mut labels: Vec<String> = ticket_docs.to_vec();
+    labels.extend(
+        docs.into_iter()
+            .filter(|doc| doc.tickets.iter().any(|t| t == ticket_id))
+            .filter_map(|doc| doc.label().map(str::to_string)),
+    );
+    labels.sort();
+    labels.dedup();
+    labels
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,4 +384,43 @@ mod tests {
         let label = sanitize_label(&long_title);
         assert!(label.len() <= 50);
     }
+
+    #[test]
+    fn test_related_tickets_and_docs() {
+        let doc = DocMetadata {
+            label: Some(DocLabel::new_unchecked("auth-design")),
+            tickets: vec!["j-c3d4".to_string()],
+            ..Default::default()
+        };
+        let other = DocMetadata {
+            label: Some(DocLabel::new_unchecked("roadmap")),
+            ..Default::default()
+        };
+        let mut tickets = HashMap::new();
+        tickets.insert(
+            "j-a1b2".to_string(),
+            TicketMetadata {
+                docs: vec!["auth-design".to_string()],
+                ..Default::default()
+            },
+        );
+        tickets.insert(
+            "j-c3d4".to_string(),
+            TicketMetadata {
+                docs: vec!["auth-design".to_string()],
+                ..Default::default()
+            },
+        );
+        tickets.insert("j-e5f6".to_string(), TicketMetadata::default());
+
+        assert_eq!(
+            related_tickets("auth-design", &doc, &tickets),
+            vec!["j-a1b2", "j-c3d4"]
+        );
+        assert_eq!(
+            related_docs("j-c3d4", &["roadmap".to_string()], [&doc, &other]),
+            vec!["auth-design", "roadmap"]
+        );
+        assert!(related_docs("j-e5f6", &[], [&doc, &other]).is_empty());
+    }
 }
@@ -21,10 +21,14 @@ struct DocFrontmatter {
     description: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tickets: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     created: Option<crate::types::CreatedAt>,
     #[serde(skip_serializing_if = "Option::is_none")]
     updated: Option<crate::types::CreatedAt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
 }
 
 /// Parse a document file's content into DocMetadata.
@@ -50,8 +54,10 @@ fn doc_metadata_from_document(frontmatter_raw: &str, body: &str) -> Result<DocMe
         label: frontmatter.label,
         description: frontmatter.description,
         tags: frontmatter.tags,
+        tickets: frontmatter.tickets,
         created: frontmatter.created,
         updated: frontmatter.updated,
+        source: frontmatter.source,
         title: extract_title(body),
         file_path: None,
         extra_frontmatter: None,
@@ -101,6 +107,19 @@ pub fn serialize_doc(metadata: &DocMetadata) -> Result<String> {
         frontmatter_map.insert("tags".to_string(), serde_yaml_ng::Value::Sequence(tags));
     }
 
+    // Add related tickets
+    if !metadata.tickets.is_empty() {
+        let tickets: Vec<serde_yaml_ng::Value> = metadata
+            .tickets
+            .iter()
+            .map(|t| serde_yaml_ng::Value::String(t.clone()))
+            .collect();
+        frontmatter_map.insert(
+            "tickets".to_string(),
+            serde_yaml_ng::Value::Sequence(tickets),
+        );
+    }
+
     // Add created timestamp
     if let Some(created) = &metadata.created {
         frontmatter_map.insert(
@@ -117,6 +136,14 @@ pub fn serialize_doc(metadata: &DocMetadata) -> Result<String> {
         );
     }
 
+    // Add source URL
+    if let Some(source) = &metadata.source {
+        frontmatter_map.insert(
+            "source".to_string(),
+            serde_yaml_ng::Value::String(source.clone()),
+        );
+    }
+
     // Serialize frontmatter
     let frontmatter_yaml = serde_yaml_ng::to_string(&frontmatter_map)
         .map_err(|e| JanusError::InvalidFormat(format!("Failed to serialize frontmatter: {e}")))?;
@@ -177,6 +204,41 @@ This document describes the system architecture.
         );
         assert_eq!(metadata.tags, vec!["design", "system"]);
         assert_eq!(metadata.title, Some("Architecture".to_string()));
+        assert!(metadata.tickets.is_empty());
+    }
+
+    #[test]
+    fn test_parse_doc_with_tickets() {
+        let content = r#"---
+label: auth-design
+tickets:
+  - j-a1b2
+  - j-c3d4
+---
+# Auth Design
+"#;
+
+        let metadata = parse_doc_content(content).unwrap();
+        assert_eq!(metadata.tickets, vec!["j-a1b2", "j-c3d4"]);
+    }
+
+    #[test]
+    fn test_source_roundtrip() {
+        let content = r#"---
+label: rfc-42
+source: https://example.com/rfcs/42.html
+---
+# RFC 42
+"#;
+
+        let metadata = parse_doc_content(content).unwrap();
+        assert_eq!(
+            metadata.source.as_deref(),
+            Some("https://example.com/rfcs/42.html")
+        );
+
+        let serialized = serialize_doc(&metadata).unwrap();
+        assert!(serialized.contains("source: https://example.com/rfcs/42.html"));
     }
 
     #[test]
@@ -206,10 +268,12 @@ Content.\r\n";
             label: Some(DocLabel::new_unchecked("test-doc")),
             description: Some("A test document".to_string()),
             tags: vec!["test".to_string(), "example".to_string()],
+            tickets: vec!["j-a1b2".to_string()],
             created: Some(crate::types::CreatedAt::new_unchecked(
                 "2024-01-01T00:00:00Z",
             )),
             updated: None,
+            source: None,
             title: Some("Test Document".to_string()),
             file_path: None,
             extra_frontmatter: None,
@@ -220,6 +284,7 @@ Content.\r\n";
         assert!(content.contains("description: A test document"));
         assert!(content.contains("- test"));
         assert!(content.contains("- example"));
+        assert!(content.contains("- j-a1b2"));
         assert!(content.contains("created: 2024-01-01T00:00:00Z"));
         assert!(content.contains("# Test Document"));
     }
@@ -230,8 +295,10 @@ Content.\r\n";
             label: Some(DocLabel::new_unchecked("minimal")),
             description: None,
             tags: vec![],
+            tickets: vec![],
             created: None,
             updated: None,
+            source: None,
             title: Some("Minimal".to_string()),
             file_path: None,
             extra_frontmatter: None,
@@ -144,6 +144,10 @@ pub struct DocMetadata {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
 
+    /// IDs of related tickets
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tickets: Vec<String>,
+
     /// Creation timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<CreatedAt>,
@@ -152,6 +156,10 @@ pub struct DocMetadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated: Option<CreatedAt>,
 
+    /// URL the document was fetched from, for documents added with `--url`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
     /// Title extracted from first H1 heading (runtime-only)
     #[serde(skip)]
     pub title: Option<String>,
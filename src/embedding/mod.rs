@@ -4,5 +4,7 @@
 //! and performing semantic search over ticket content.
 
 pub mod model;
+pub mod quantize;
 
 pub use model::*;
+pub use quantize::QuantizedEmbedding;
@@ -168,11 +168,46 @@ pub async fn generate_ticket_embedding(
     title: &str,
     body: Option<&str>,
 ) -> Result<Vec<f32>, String> {
-    let full_text = match body {
-        Some(b) => format!("{title}\n\n{b}"),
-        None => title.to_string(),
-    };
-    generate_embedding(&full_text).await
+    generate_embedding(&ticket_embedding_text(title, body)).await
+}
+
+/// Text embedded for a ticket: its title, then its body if it has one
+pub fn ticket_embedding_text(title: &str, body: Option<&str>) -> String {
+    match body {
+        Some(b) if !b.is_empty() => format!("{title}\n\n{b}"),
+        _ => title.to_string(),
+    }
+}
+
+/// Text embedded for an objective: its title, description, and acceptance
+/// criteria
+pub fn objective_embedding_text(
+    title: &str,
+    description: &str,
+    acceptance_criteria: &[String],
+) -> String {
+    let mut text = title.to_string();
+    if !description.is_empty() {
+        text.push_str("\n\n");
+        text.push_str(description);
+    }
+    if !acceptance_criteria.is_empty() {
+        text.push_str("\n\n");
+        text.push_str(&acceptance_criteria.join("\n"));
+    }
+    text
+}
+
+/// Text embedded for a plan or one of its phases: the heading, description,
+/// and acceptance (or success) criteria. Phases are prefixed with the plan
+/// title so they stay in context.
+pub fn plan_embedding_text(heading: &str, description: &str, criteria: &[String]) -> String {
+    objective_embedding_text(heading, description, criteria)
+}
+
+/// Text embedded for a single ticket note: the ticket title, then the note
+pub fn note_embedding_text(ticket_title: &str, note: &str) -> String {
+    format!("{ticket_title}\n\n{note}")
 }
 
 /// Compute cosine similarity between two embedding vectors.
@@ -0,0 +1,189 @@
+//! Int8 quantization of embeddings
+//!
+//! Embeddings are kept as one signed byte per dimension plus a per-vector
+//! scale, a quarter of the size of raw f32 vectors. Each value is
+//! `round(x / scale)` with `scale = max(|x|) / 127`, so the largest component
+//! maps to ±127.
+//!
+//! Cosine similarity does not depend on the scale, so vectors are compared
+//! directly on their int8 values. Search ranks with a quantized query first
+//! and then rescores the best candidates against the full-precision query
+//! (see [`QuantizedEmbedding::similarity_to`]), which recovers nearly all of
+//! the ranking quality of f32 vectors.
+
+/// Size in bytes of the serialized scale that precedes the values
+const SCALE_BYTES: usize = 4;
+
+/// An embedding quantized to int8
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizedEmbedding {
+    /// Multiplier that maps the int8 values back to the original range
+    scale: f32,
+    values: Vec<i8>,
+    /// Euclidean norm of `values`, cached for similarity
+    norm: f32,
+}
+
+impl QuantizedEmbedding {
+    /// Quantize a vector. Non-finite components are treated as zero.
+    pub fn quantize(vector: &[f32]) -> Self {
+        let max = vector
+            .iter()
+            .filter(|v| v.is_finite())
+            .fold(0.0_f32, |max, v| max.max(v.abs()));
+        let scale = if max == 0.0 { 1.0 } else { max / 127.0 };
+        let values = vector
+            .iter()
+            .map(|v| {
+                if v.is_finite() {
+                    (v / scale).round().clamp(-127.0, 127.0) as i8
+                } else {
+                    0
+                }
+            })
+            .collect();
+        Self::new(scale, values)
+    }
+
+    fn new(scale: f32, values: Vec<i8>) -> Self {
+        let norm = (values.iter().map(|&v| i32::from(v).pow(2)).sum::<i32>() as f32).sqrt();
+        Self {
+            scale,
+            values,
+            norm,
+        }
+    }
+
+    /// Number of dimensions
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Approximate f32 vector
+    pub fn dequantize(&self) -> Vec<f32> {
+        self.values
+            .iter()
+            .map(|&v| f32::from(v) * self.scale)
+            .collect()
+    }
+
+    /// Cosine similarity between two quantized vectors, computed in integers.
+    ///
+    /// Returns `0.0` for mismatched dimensions or zero vectors, like
+    /// [`cosine_similarity`](super::cosine_similarity).
+    pub fn similarity(&self, other: &QuantizedEmbedding) -> f32 {
+        if self.len() != other.len() || self.norm == 0.0 || other.norm == 0.0 {
+            return 0.0;
+        }
+        let dot: i32 = self
+            .values
+            .iter()
+            .zip(&other.values)
+            .map(|(&a, &b)| i32::from(a) * i32::from(b))
+            .sum();
+        dot as f32 / (self.norm * other.norm)
+    }
+
+    /// Cosine similarity against a full-precision query, used to rescore the
+    /// top candidates of a quantized search.
+    pub fn similarity_to(&self, query: &[f32]) -> f32 {
+        if self.len() != query.len() || self.norm == 0.0 {
+            return 0.0;
+        }
+        let query_norm = query.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if query_norm == 0.0 {
+            return 0.0;
+        }
+        let dot: f32 = query
+            .iter()
+            .zip(&self.values)
+            .map(|(q, &v)| q * f32::from(v))
+            .sum();
+        dot / (query_norm * self.norm)
+    }
+
+    /// Serialize as the little-endian f32 scale followed by the int8 values.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(SCALE_BYTES + self.values.len());
+        bytes.extend_from_slice(&self.scale.to_le_bytes());
+        bytes.extend(self.values.iter().map(|&v| v as u8));
+        bytes
+    }
+
+    /// Parse bytes written by [`to_bytes`](Self::to_bytes) for a vector of
+    /// `dimensions` values. Returns `None` for the wrong length or an invalid
+    /// scale.
+    pub fn from_bytes(data: &[u8], dimensions: usize) -> Option<Self> {
+        if data.len() != SCALE_BYTES + dimensions {
+            return None;
+        }
+        let (scale, values) = data.split_at(SCALE_BYTES);
+        let scale = f32::from_le_bytes(scale.try_into().ok()?);
+        if !scale.is_finite() || scale <= 0.0 {
+            return None;
+        }
+        Some(Self::new(scale, values.iter().map(|&b| b as i8).collect()))
+    }
+
+    /// Serialized size of a vector with `dimensions` values
+    pub const fn encoded_len(dimensions: usize) -> usize {
+        SCALE_BYTES + dimensions
+    }
+}
+
+impl From<Vec<f32>> for QuantizedEmbedding {
+    fn from(vector: Vec<f32>) -> Self {
+        Self::quantize(&vector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embedding::model::cosine_similarity;
+
+    #[test]
+    fn test_quantize_roundtrip() {
+        let vector = vec![0.5_f32, -1.0, 0.25, 0.0];
+        let quantized = QuantizedEmbedding::quantize(&vector);
+        for (original, restored) in vector.iter().zip(quantized.dequantize()) {
+            assert!((original - restored).abs() < 1.0 / 200.0);
+        }
+
+        let bytes = quantized.to_bytes();
+        assert_eq!(bytes.len(), QuantizedEmbedding::encoded_len(4));
+        assert_eq!(QuantizedEmbedding::from_bytes(&bytes, 4), Some(quantized));
+        assert_eq!(QuantizedEmbedding::from_bytes(&bytes, 5), None);
+    }
+
+    #[test]
+    fn test_similarity_tracks_f32() {
+        let a: Vec<f32> = (0..64).map(|i| (i as f32 * 0.37).sin()).collect();
+        let b: Vec<f32> = (0..64).map(|i| (i as f32 * 0.41).cos()).collect();
+        let exact = cosine_similarity(&a, &b);
+
+        let (qa, qb) = (
+            QuantizedEmbedding::quantize(&a),
+            QuantizedEmbedding::quantize(&b),
+        );
+        assert!((qa.similarity(&qb) - exact).abs() < 0.01);
+        assert!((qb.similarity_to(&a) - exact).abs() < 0.01);
+        assert!((qa.similarity(&qa) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_zero_and_mismatched_vectors() {
+        let zero = QuantizedEmbedding::quantize(&[0.0, 0.0]);
+        let one = QuantizedEmbedding::quantize(&[1.0, 0.0]);
+        assert_eq!(zero.similarity(&one), 0.0);
+        assert_eq!(one.similarity_to(&[1.0, 0.0, 0.0]), 0.0);
+        assert_eq!(
+            QuantizedEmbedding::quantize(&[f32::NAN, 1.0]).dequantize()[0],
+            0.0
+        );
+    }
+}
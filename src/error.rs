@@ -51,6 +51,16 @@ fn format_ambiguous_ticket_id(id: &str, matches: &[String]) -> String {
     format_error_with_list("ambiguous ID", id, "matches multiple tickets:", matches)
 }
 
+/// Format the AmbiguousTicketTitle error message
+fn format_ambiguous_ticket_title(query: &str, matches: &[String]) -> String {
+    format_error_with_list(
+        "ambiguous title",
+        query,
+        "matches multiple tickets:",
+        matches,
+    )
+}
+
 /// Format the AmbiguousPlanId error message
 fn format_ambiguous_plan_id(id: &str, matches: &[String]) -> String {
     format_error_with_list("ambiguous plan ID", id, "matches multiple plans:", matches)
@@ -124,6 +134,27 @@ impl JanusError {
         }
     }
 
+    pub fn invalid_ticket_section(value: impl Into<String>, valid_values: &[&str]) -> Self {
+        JanusError::InvalidTicketSection {
+            value: value.into(),
+            valid_values: valid_values.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    pub fn invalid_color_choice(value: impl Into<String>, valid_values: &[&str]) -> Self {
+        JanusError::InvalidColorChoice {
+            value: value.into(),
+            valid_values: valid_values.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    pub fn invalid_group_field(value: impl Into<String>, valid_values: &[&str]) -> Self {
+        JanusError::InvalidGroupField {
+            value: value.into(),
+            valid_values: valid_values.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
     pub fn invalid_hook_event(value: impl Into<String>, valid_values: &[&str]) -> Self {
         JanusError::InvalidHookEvent {
             value: value.into(),
@@ -144,6 +175,20 @@ impl JanusError {
             valid_values: valid_values.iter().map(|s| s.to_string()).collect(),
         }
     }
+
+    pub fn invalid_encryption_tool(value: impl Into<String>, valid_values: &[&str]) -> Self {
+        JanusError::InvalidEncryptionTool {
+            value: value.into(),
+            valid_values: valid_values.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    pub fn invalid_completion_kind(value: impl Into<String>, valid_values: &[&str]) -> Self {
+        JanusError::InvalidCompletionKind {
+            value: value.into(),
+            valid_values: valid_values.iter().map(|s| s.to_string()).collect(),
+        }
+    }
 }
 
 fn format_retry_errors(attempts: &u32, errors: &[String]) -> String {
@@ -187,6 +232,12 @@ pub enum JanusError {
     #[error("{}", format_ambiguous_ticket_id(.0, .1))]
     AmbiguousTicketId(String, Vec<String>),
 
+    #[error("no ticket title matches '{0}'")]
+    TicketTitleNotFound(String),
+
+    #[error("{}", format_ambiguous_ticket_title(.0, .1))]
+    AmbiguousTicketTitle(String, Vec<String>),
+
     #[error(
         "invalid ticket ID format '{0}': must be non-empty and match '<prefix>-<hash>' pattern"
     )]
@@ -273,6 +324,9 @@ pub enum JanusError {
     #[error("jq filter error: {0}")]
     JqFilter(String),
 
+    #[error("git error: {0}")]
+    Git(String),
+
     #[error("parse error: {0}")]
     ParseError(String),
 
@@ -284,10 +338,16 @@ pub enum JanusError {
     #[error("invalid format: {0}")]
     InvalidFormat(String),
 
+    #[error("invalid batch file: {0}")]
+    InvalidBatch(String),
+
     // Configuration errors
     #[error("configuration error: {0}")]
     Config(String),
 
+    #[error("secret encryption error: {0}")]
+    Encryption(String),
+
     #[error("invalid field name: '{0}'")]
     InvalidFieldName(String),
 
@@ -319,6 +379,9 @@ pub enum JanusError {
     #[error("invalid remote reference '{0}': {1}")]
     InvalidRemoteRef(String, String),
 
+    #[error("invalid pull request reference '{0}': {1}")]
+    InvalidPullRequestRef(String, String),
+
     #[error("remote issue not found: {0}")]
     RemoteIssueNotFound(String),
 
@@ -328,6 +391,15 @@ pub enum JanusError {
     #[error("ticket not linked to any remote")]
     NotLinked,
 
+    #[error(
+        "cannot edit {field} of '{id}': it is mirrored from {remote}; change it there and run 'janus remote sync {id}'"
+    )]
+    MirroredField {
+        id: String,
+        field: String,
+        remote: String,
+    },
+
     #[error("authentication error: {0}")]
     Auth(String),
 
@@ -397,6 +469,18 @@ pub enum JanusError {
         valid_values: Vec<String>,
     },
 
+    #[error("{}", format_invalid_enum_value(.value, .valid_values))]
+    InvalidEncryptionTool {
+        value: String,
+        valid_values: Vec<String>,
+    },
+
+    #[error("{}", format_invalid_enum_value(.value, .valid_values))]
+    InvalidCompletionKind {
+        value: String,
+        valid_values: Vec<String>,
+    },
+
     #[error("hook recipe '{0}' not found")]
     HookRecipeNotFound(String),
 
@@ -445,6 +529,30 @@ pub enum JanusError {
     #[error("note text exceeds maximum length of {max} characters (got {actual})")]
     NoteTooLong { max: usize, actual: usize },
 
+    #[error("note template '{0}' is not defined under notes.templates in .janus/config.yaml")]
+    NoteTemplateNotFound(String),
+
+    #[error("template '{template}' uses unknown variable '{{{variable}}}' (expected {expected})")]
+    UnknownTemplateVariable {
+        template: String,
+        variable: String,
+        expected: String,
+    },
+
+    #[error("ticket '{id}' has no note {index} (it has {count})")]
+    NoteNotFound {
+        id: String,
+        index: usize,
+        count: usize,
+    },
+
+    #[error("ticket '{id}' has no acceptance criterion {index} (it has {count})")]
+    CriterionNotFound {
+        id: String,
+        index: usize,
+        count: usize,
+    },
+
     // Dependency/Link errors
     #[error("dependency '{0}' not found in ticket")]
     DependencyNotFound(String),
@@ -458,6 +566,15 @@ pub enum JanusError {
     #[error("a ticket cannot depend on itself")]
     SelfDependency,
 
+    // Attachment errors
+    #[error("ticket '{id}' has no attachment named '{name}'")]
+    AttachmentNotFound { id: String, name: String },
+
+    #[error(
+        "attachment object {0} is missing or corrupt; re-attach the file or restore .janus/objects/"
+    )]
+    AttachmentObjectInvalid(String),
+
     // Business logic errors
     #[error("at least {expected} ticket IDs are required, got {provided}")]
     InsufficientTicketIds { expected: usize, provided: usize },
@@ -494,6 +611,24 @@ pub enum JanusError {
         valid_values: Vec<String>,
     },
 
+    #[error("{}", format_invalid_enum_value(.value, .valid_values))]
+    InvalidTicketSection {
+        value: String,
+        valid_values: Vec<String>,
+    },
+
+    #[error("{}", format_invalid_enum_value(.value, .valid_values))]
+    InvalidColorChoice {
+        value: String,
+        valid_values: Vec<String>,
+    },
+
+    #[error("{}", format_invalid_enum_value(.value, .valid_values))]
+    InvalidGroupField {
+        value: String,
+        valid_values: Vec<String>,
+    },
+
     #[error("reordered list must contain the same tickets")]
     ReorderTicketMismatch,
 
@@ -522,15 +657,42 @@ pub enum JanusError {
     #[error("editor exited with code {0}")]
     EditorFailed(i32),
 
+    #[error("browser launcher exited with code {0}")]
+    BrowserFailed(i32),
+
     #[error("Cannot open editor in non-interactive mode: {0}")]
     InteractiveTerminalRequired(std::path::PathBuf),
 
     #[error("Not an interactive terminal: {0}")]
     NotInteractive(String),
 
+    #[error("unknown ticket reference(s): {}", .0.join(", "))]
+    UnknownTicketReferences(Vec<String>),
+
     #[error("closing a ticket requires either --summary <TEXT> or --no-summary")]
     SummaryRequired,
 
+    #[error("reopening '{0}' requires a reason (pass --reason <TEXT>)")]
+    ReopenReasonRequired(String),
+
+    #[error("ticket '{id}' is locked by {owner} until {until} (pass --force to override)")]
+    TicketLocked {
+        id: String,
+        owner: String,
+        until: String,
+    },
+
+    #[error("ticket '{id}' already has a worktree at {path}")]
+    WorktreeExists { id: String, path: String },
+
+    #[error("status transition {from} -> {to} is forbidden for '{id}': {reason}")]
+    TransitionForbidden {
+        id: String,
+        from: String,
+        to: String,
+        reason: String,
+    },
+
     #[error("--verbose-phase can only be used with phased plans")]
     VerbosePhaseRequiresPhasedPlan,
 
@@ -558,9 +720,6 @@ pub enum JanusError {
     #[error("semantic search not available: embeddings not generated")]
     EmbeddingsNotAvailable,
 
-    #[error("embedding key error: path '{path}' is not within Janus root '{root}'")]
-    EmbeddingKeyError { path: String, root: String },
-
     #[error("embedding generation failed: {0}")]
     EmbeddingGenerationFailed(String),
 
@@ -627,6 +786,13 @@ pub enum JanusError {
         valid_values: Vec<String>,
     },
 
+    #[error("read-only mode is enabled; refusing to {0}")]
+    ReadOnly(String),
+
+    // Backup errors
+    #[error("invalid backup {}: {1}", .0.display())]
+    InvalidBackup(std::path::PathBuf, String),
+
     // General errors
     #[error("internal error: {0}")]
     InternalError(String),
@@ -634,11 +800,199 @@ pub enum JanusError {
 
 pub type Result<T> = std::result::Result<T, JanusError>;
 
+impl JanusError {
+    /// Stable machine-readable code for this error, e.g. `TICKET_NOT_FOUND`.
+    ///
+    /// Codes are part of the `--json-errors` output and never change once
+    /// published, even if the message does.
+    pub fn code(&self) -> &'static str {
+        match self {
+            JanusError::TicketNotFound { .. } => "TICKET_NOT_FOUND",
+            JanusError::AmbiguousTicketId { .. } => "AMBIGUOUS_TICKET_ID",
+            JanusError::TicketTitleNotFound { .. } => "TICKET_TITLE_NOT_FOUND",
+            JanusError::AmbiguousTicketTitle { .. } => "AMBIGUOUS_TICKET_TITLE",
+            JanusError::InvalidTicketIdFormat { .. } => "INVALID_TICKET_ID_FORMAT",
+            JanusError::PlanNotFound { .. } => "PLAN_NOT_FOUND",
+            JanusError::InvalidPlanId { .. } => "INVALID_PLAN_ID",
+            JanusError::AmbiguousPlanId { .. } => "AMBIGUOUS_PLAN_ID",
+            JanusError::InvalidPlanIdFormat { .. } => "INVALID_PLAN_ID_FORMAT",
+            JanusError::PhaseNotFound { .. } => "PHASE_NOT_FOUND",
+            JanusError::PhaseNotEmpty { .. } => "PHASE_NOT_EMPTY",
+            JanusError::DuplicatePlanTitle { .. } => "DUPLICATE_PLAN_TITLE",
+            JanusError::PlanLoadFailed { .. } => "PLAN_LOAD_FAILED",
+            JanusError::PlanNoTicketsSection { .. } => "PLAN_NO_TICKETS_SECTION",
+            JanusError::PlanNoTicketsOrPhases { .. } => "PLAN_NO_TICKETS_OR_PHASES",
+            JanusError::TicketAlreadyInPlan { .. } => "TICKET_ALREADY_IN_PLAN",
+            JanusError::TicketAlreadyInPhase { .. } => "TICKET_ALREADY_IN_PHASE",
+            JanusError::TicketNotInPlan { .. } => "TICKET_NOT_IN_PLAN",
+            JanusError::SimpleplanNoPhase { .. } => "SIMPLE_PLAN_NO_PHASE",
+            JanusError::PhasedPlanRequiresPhase { .. } => "PHASED_PLAN_REQUIRES_PHASE",
+            JanusError::CannotMoveInSimplePlan { .. } => "CANNOT_MOVE_IN_SIMPLE_PLAN",
+            JanusError::TicketLoadFailed { .. } => "TICKET_LOAD_FAILED",
+            JanusError::Io { .. } => "IO",
+            JanusError::StorageError { .. } => "STORAGE_ERROR",
+            JanusError::WatcherError { .. } => "WATCHER_ERROR",
+            JanusError::YamlParse { .. } => "YAML_PARSE",
+            JanusError::Json { .. } => "JSON",
+            JanusError::JqFilter { .. } => "JQ_FILTER",
+            JanusError::Git { .. } => "GIT",
+            JanusError::ParseError { .. } => "PARSE_ERROR",
+            JanusError::EmptyFrontmatter { .. } => "EMPTY_FRONTMATTER",
+            JanusError::InvalidFormat { .. } => "INVALID_FORMAT",
+            JanusError::Config { .. } => "CONFIG",
+            JanusError::Encryption { .. } => "ENCRYPTION",
+            JanusError::InvalidFieldName { .. } => "INVALID_FIELD_NAME",
+            JanusError::InvalidLabel { .. } => "INVALID_LABEL",
+            JanusError::InvalidFieldValue { .. } => "INVALID_FIELD_VALUE",
+            JanusError::InvalidPrefix { .. } => "INVALID_PREFIX",
+            JanusError::InvalidStatus { .. } => "INVALID_STATUS",
+            JanusError::InvalidTimestamp { .. } => "INVALID_TIMESTAMP",
+            JanusError::InvalidRemoteRef { .. } => "INVALID_REMOTE_REF",
+            JanusError::InvalidPullRequestRef { .. } => "INVALID_PULL_REQUEST_REF",
+            JanusError::RemoteIssueNotFound { .. } => "REMOTE_ISSUE_NOT_FOUND",
+            JanusError::AlreadyLinked { .. } => "ALREADY_LINKED",
+            JanusError::NotLinked { .. } => "NOT_LINKED",
+            JanusError::MirroredField { .. } => "MIRRORED_FIELD",
+            JanusError::Auth { .. } => "AUTH",
+            JanusError::Api { .. } => "API",
+            JanusError::GraphQlErrors { .. } => "GRAPHQL_ERRORS",
+            JanusError::RateLimited { .. } => "RATE_LIMITED",
+            JanusError::Http { .. } => "HTTP",
+            JanusError::RemoteTimeout { .. } => "REMOTE_TIMEOUT",
+            JanusError::UnsupportedSyncField { .. } => "UNSUPPORTED_SYNC_FIELD",
+            JanusError::ImportFailed { .. } => "IMPORT_FAILED",
+            JanusError::InvalidBatch { .. } => "INVALID_BATCH",
+            JanusError::RetryFailed { .. } => "RETRY_FAILED",
+            JanusError::PreHookFailed { .. } => "PRE_HOOK_FAILED",
+            JanusError::PostHookFailed { .. } => "POST_HOOK_FAILED",
+            JanusError::HookScriptNotFound { .. } => "HOOK_SCRIPT_NOT_FOUND",
+            JanusError::HookTimeout { .. } => "HOOK_TIMEOUT",
+            JanusError::InvalidHookEvent { .. } => "INVALID_HOOK_EVENT",
+            JanusError::InvalidEventType { .. } => "INVALID_EVENT_TYPE",
+            JanusError::InvalidActor { .. } => "INVALID_ACTOR",
+            JanusError::InvalidEncryptionTool { .. } => "INVALID_ENCRYPTION_TOOL",
+            JanusError::InvalidCompletionKind { .. } => "INVALID_COMPLETION_KIND",
+            JanusError::HookRecipeNotFound { .. } => "HOOK_RECIPE_NOT_FOUND",
+            JanusError::HookFetchFailed { .. } => "HOOK_FETCH_FAILED",
+            JanusError::HookSecurity { .. } => "HOOK_SECURITY",
+            JanusError::ValidationEmpty { .. } => "VALIDATION_EMPTY",
+            JanusError::EmptyTicketId { .. } => "EMPTY_TICKET_ID",
+            JanusError::InvalidTicketIdCharacters { .. } => "INVALID_TICKET_ID_CHARACTERS",
+            JanusError::SelfParentTicket { .. } => "SELF_PARENT_TICKET",
+            JanusError::EmptyTicketMap { .. } => "EMPTY_TICKET_MAP",
+            JanusError::SelfLink { .. } => "SELF_LINK",
+            JanusError::EmptyTitle { .. } => "EMPTY_TITLE",
+            JanusError::EmptyPlanTitle { .. } => "EMPTY_PLAN_TITLE",
+            JanusError::PlanTitleTooLong { .. } => "PLAN_TITLE_TOO_LONG",
+            JanusError::TicketTitleTooLong { .. } => "TICKET_TITLE_TOO_LONG",
+            JanusError::EmptyNote { .. } => "EMPTY_NOTE",
+            JanusError::NoteTemplateNotFound { .. } => "NOTE_TEMPLATE_NOT_FOUND",
+            JanusError::UnknownTemplateVariable { .. } => "UNKNOWN_TEMPLATE_VARIABLE",
+            JanusError::NoteTooLong { .. } => "NOTE_TOO_LONG",
+            JanusError::NoteNotFound { .. } => "NOTE_NOT_FOUND",
+            JanusError::CriterionNotFound { .. } => "CRITERION_NOT_FOUND",
+            JanusError::DependencyNotFound { .. } => "DEPENDENCY_NOT_FOUND",
+            JanusError::CircularDependency { .. } => "CIRCULAR_DEPENDENCY",
+            JanusError::LinkNotFound { .. } => "LINK_NOT_FOUND",
+            JanusError::SelfDependency { .. } => "SELF_DEPENDENCY",
+            JanusError::AttachmentNotFound { .. } => "ATTACHMENT_NOT_FOUND",
+            JanusError::AttachmentObjectInvalid { .. } => "ATTACHMENT_OBJECT_INVALID",
+            JanusError::InsufficientTicketIds { .. } => "INSUFFICIENT_TICKET_IDS",
+            JanusError::UnknownArrayField { .. } => "UNKNOWN_ARRAY_FIELD",
+            JanusError::InvalidTicketType { .. } => "INVALID_TICKET_TYPE",
+            JanusError::InvalidEntityType { .. } => "INVALID_ENTITY_TYPE",
+            JanusError::InvalidPriority { .. } => "INVALID_PRIORITY",
+            JanusError::InvalidSize { .. } => "INVALID_SIZE",
+            JanusError::InvalidSortField { .. } => "INVALID_SORT_FIELD",
+            JanusError::InvalidTicketSection { .. } => "INVALID_TICKET_SECTION",
+            JanusError::InvalidColorChoice { .. } => "INVALID_COLOR_CHOICE",
+            JanusError::InvalidGroupField { .. } => "INVALID_GROUP_FIELD",
+            JanusError::ReorderTicketMismatch { .. } => "REORDER_TICKET_MISMATCH",
+            JanusError::ReorderPhaseMismatch { .. } => "REORDER_PHASE_MISMATCH",
+            JanusError::ImmutableField { .. } => "IMMUTABLE_FIELD",
+            JanusError::ConflictingFlags { .. } => "CONFLICTING_FLAGS",
+            JanusError::IdGenerationFailed { .. } => "ID_GENERATION_FAILED",
+            JanusError::CorruptedTicket { .. } => "CORRUPTED_TICKET",
+            JanusError::ItemNotFound { .. } => "ITEM_NOT_FOUND",
+            JanusError::FileNotFound { .. } => "FILE_NOT_FOUND",
+            JanusError::EditorFailed { .. } => "EDITOR_FAILED",
+            JanusError::BrowserFailed { .. } => "BROWSER_FAILED",
+            JanusError::InteractiveTerminalRequired { .. } => "INTERACTIVE_TERMINAL_REQUIRED",
+            JanusError::NotInteractive { .. } => "NOT_INTERACTIVE",
+            JanusError::UnknownTicketReferences { .. } => "UNKNOWN_TICKET_REFERENCES",
+            JanusError::SummaryRequired { .. } => "SUMMARY_REQUIRED",
+            JanusError::ReopenReasonRequired { .. } => "REOPEN_REASON_REQUIRED",
+            JanusError::TicketLocked { .. } => "TICKET_LOCKED",
+            JanusError::WorktreeExists { .. } => "WORKTREE_EXISTS",
+            JanusError::TransitionForbidden { .. } => "TRANSITION_FORBIDDEN",
+            JanusError::VerbosePhaseRequiresPhasedPlan { .. } => {
+                "VERBOSE_PHASE_REQUIRES_PHASED_PLAN"
+            }
+            JanusError::RawWithOtherFlags { .. } => "RAW_WITH_OTHER_FLAGS",
+            JanusError::EofOnStdin { .. } => "EOF_ON_STDIN",
+            JanusError::ConfirmationRequired { .. } => "CONFIRMATION_REQUIRED",
+            JanusError::InvalidInput { .. } => "INVALID_INPUT",
+            JanusError::InvalidGraphFormat { .. } => "INVALID_GRAPH_FORMAT",
+            JanusError::EmbeddingModel { .. } => "EMBEDDING_MODEL",
+            JanusError::EmbeddingsNotAvailable { .. } => "EMBEDDINGS_NOT_AVAILABLE",
+            JanusError::EmbeddingGenerationFailed { .. } => "EMBEDDING_GENERATION_FAILED",
+            JanusError::EmbeddingSaveFailed { .. } => "EMBEDDING_SAVE_FAILED",
+            JanusError::EmbeddingNoFilePath { .. } => "EMBEDDING_NO_FILE_PATH",
+            JanusError::BlockingTaskFailed { .. } => "BLOCKING_TASK_FAILED",
+            JanusError::TuiError { .. } => "TUI_ERROR",
+            JanusError::McpServerError { .. } => "MCP_SERVER_ERROR",
+            JanusError::DocNotFound { .. } => "DOC_NOT_FOUND",
+            JanusError::AmbiguousDocLabel { .. } => "AMBIGUOUS_DOC_LABEL",
+            JanusError::InvalidDocLabel { .. } => "INVALID_DOC_LABEL",
+            JanusError::DocAlreadyExists { .. } => "DOC_ALREADY_EXISTS",
+            JanusError::DocLoadFailed { .. } => "DOC_LOAD_FAILED",
+            JanusError::ObjectiveNotFound { .. } => "OBJECTIVE_NOT_FOUND",
+            JanusError::AmbiguousObjectiveId { .. } => "AMBIGUOUS_OBJECTIVE_ID",
+            JanusError::InvalidObjectiveIdFormat { .. } => "INVALID_OBJECTIVE_ID_FORMAT",
+            JanusError::ObjectiveLoadFailed { .. } => "OBJECTIVE_LOAD_FAILED",
+            JanusError::DuplicateObjectiveRef { .. } => "DUPLICATE_OBJECTIVE_REF",
+            JanusError::ObjectiveRefNotFound { .. } => "OBJECTIVE_REF_NOT_FOUND",
+            JanusError::InvalidObjectiveStatus { .. } => "INVALID_OBJECTIVE_STATUS",
+            JanusError::ReadOnly { .. } => "READ_ONLY",
+            JanusError::InvalidBackup { .. } => "INVALID_BACKUP",
+            JanusError::InternalError { .. } => "INTERNAL_ERROR",
+        }
+    }
+
+    /// The error as printed by `--json-errors`:
+    /// `{"error": {"code": "...", "message": "..."}}`
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "error": {
+                "code": self.code(),
+                "message": self.to_string(),
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_error_codes() {
+        let error = JanusError::TicketNotFound(TicketId::new_unchecked("j-a1b2"));
+        assert_eq!(error.code(), "TICKET_NOT_FOUND");
+        assert_eq!(
+            error.to_json(),
+            serde_json::json!({
+                "error": {
+                    "code": "TICKET_NOT_FOUND",
+                    "message": "ticket 'j-a1b2' not found",
+                }
+            })
+        );
+        assert_eq!(JanusError::NotLinked.code(), "NOT_LINKED");
+        assert_eq!(JanusError::SimpleplanNoPhase.code(), "SIMPLE_PLAN_NO_PHASE");
+    }
+
     #[test]
     fn test_pre_hook_failed_error_message() {
         let error = JanusError::PreHookFailed {
@@ -30,7 +30,7 @@ pub mod types;
 pub use types::{Actor, EntityType, Event, EventType};
 
 use std::fs::OpenOptions;
-use std::io::Write;
+use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::PathBuf;
 
 use crate::types::janus_root;
@@ -60,6 +60,7 @@ pub fn events_file_path() -> PathBuf {
 /// - The event cannot be serialized
 /// - The write operation fails
 pub fn log_event(event: Event) {
+    crate::fs::record_change(event.summary());
     if let Err(e) = log_event_impl(event) {
         eprintln!("Warning: failed to log event: {e}");
     }
@@ -160,6 +161,83 @@ pub fn read_events() -> std::io::Result<Vec<Event>> {
     Ok(events)
 }
 
+/// Incremental reader for the event log, used to follow it as it grows
+///
+/// Each call to [`EventTail::poll`] returns the events appended since the
+/// previous call. A trailing line without a newline is held back until the
+/// writer finishes it, and the log shrinking (e.g. `janus events prune`)
+/// restarts reading from the beginning.
+#[derive(Debug)]
+pub struct EventTail {
+    path: PathBuf,
+    offset: u64,
+    partial: String,
+}
+
+impl EventTail {
+    /// Create a tail that starts at the beginning of the event log
+    pub fn new() -> Self {
+        Self {
+            path: events_file_path(),
+            offset: 0,
+            partial: String::new(),
+        }
+    }
+
+    /// Read the events appended since the last poll
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the events file exists but cannot be read.
+    pub fn poll(&mut self) -> std::io::Result<Vec<Event>> {
+        let mut file = match std::fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                self.offset = 0;
+                self.partial.clear();
+                return Ok(Vec::new());
+            }
+            Err(e) => return Err(e),
+        };
+
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            // The log was pruned or rewritten; start over
+            self.offset = 0;
+            self.partial.clear();
+        }
+        if len == self.offset {
+            return Ok(Vec::new());
+        }
+
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        self.offset += bytes.len() as u64;
+        self.partial.push_str(&String::from_utf8_lossy(&bytes));
+
+        let Some(end) = self.partial.rfind('\n') else {
+            return Ok(Vec::new());
+        };
+        let complete: String = self.partial.drain(..=end).collect();
+
+        let mut events = Vec::new();
+        for line in complete.lines().filter(|line| !line.trim().is_empty()) {
+            match serde_json::from_str::<Event>(line) {
+                Ok(event) => events.push(event),
+                Err(e) => eprintln!("Warning: failed to parse event: {e}"),
+            }
+        }
+        Ok(events)
+    }
+}
+
+impl Default for EventTail {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Clear the events log
 ///
 /// Removes the events log file if it exists.
@@ -263,6 +341,37 @@ pub fn log_note_added(ticket_id: &str, note_preview: &str, actor: Option<Actor>)
     );
 }
 
+/// Log a note edited event (`index` is 1-based)
+pub fn log_note_edited(ticket_id: &str, index: usize, note_preview: &str, actor: Option<Actor>) {
+    let preview = truncate_string(note_preview, 100);
+
+    log_event(
+        Event::new(
+            EventType::NoteEdited,
+            EntityType::Ticket,
+            ticket_id,
+            serde_json::json!({
+                "index": index,
+                "content_preview": preview,
+            }),
+        )
+        .with_actor(actor.unwrap_or_default()),
+    );
+}
+
+/// Log a note removed event (`index` is 1-based)
+pub fn log_note_removed(ticket_id: &str, index: usize, actor: Option<Actor>) {
+    log_event(
+        Event::new(
+            EventType::NoteRemoved,
+            EntityType::Ticket,
+            ticket_id,
+            serde_json::json!({ "index": index }),
+        )
+        .with_actor(actor.unwrap_or_default()),
+    );
+}
+
 /// Log a field update event
 pub fn log_field_updated(
     ticket_id: &str,
@@ -376,6 +485,19 @@ pub fn log_label_removed(ticket_id: &str, label: &str, actor: Option<Actor>) {
     );
 }
 
+/// Log the result of syncing a ticket with its remote issue
+pub fn log_ticket_synced(ticket_id: &str, remote_ref: &str, changes_made: bool) {
+    log_event(Event::new(
+        EventType::TicketSynced,
+        EntityType::Ticket,
+        ticket_id,
+        serde_json::json!({
+            "remote_ref": remote_ref,
+            "changes_made": changes_made,
+        }),
+    ));
+}
+
 /// Log a plan creation event
 pub fn log_plan_created(plan_id: &str, title: &str, is_phased: bool, phases: &[String]) {
     log_event(Event::new(
@@ -674,6 +796,53 @@ mod tests {
         assert!(read_events().unwrap().is_empty());
     }
 
+    #[test]
+    fn test_event_tail() {
+        let (_temp, _guard) = setup_test_dir();
+        let mut tail = EventTail::new();
+        assert!(tail.poll().unwrap().is_empty());
+
+        log_event(Event::new(
+            EventType::TicketCreated,
+            EntityType::Ticket,
+            "j-1",
+            serde_json::json!({}),
+        ));
+        let events = tail.poll().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].entity_id, "j-1");
+        assert!(tail.poll().unwrap().is_empty());
+
+        // A half-written line is held back until it is finished
+        let line = serde_json::to_string(&Event::new(
+            EventType::TicketCreated,
+            EntityType::Ticket,
+            "j-2",
+            serde_json::json!({}),
+        ))
+        .unwrap();
+        let (head, rest) = line.split_at(10);
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(events_file_path())
+            .unwrap();
+        write!(file, "{head}").unwrap();
+        assert!(tail.poll().unwrap().is_empty());
+        writeln!(file, "{rest}").unwrap();
+        assert_eq!(tail.poll().unwrap()[0].entity_id, "j-2");
+
+        // Pruning restarts the tail
+        clear_events().unwrap();
+        assert!(tail.poll().unwrap().is_empty());
+        log_event(Event::new(
+            EventType::TicketCreated,
+            EntityType::Ticket,
+            "j-3",
+            serde_json::json!({}),
+        ));
+        assert_eq!(tail.poll().unwrap()[0].entity_id, "j-3");
+    }
+
     #[test]
     fn test_ndjson_format() {
         let (_temp, _guard) = setup_test_dir();
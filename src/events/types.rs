@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::utils::text::truncate_string;
+
 pub use crate::types::EntityType;
 
 /// The type of event being logged
@@ -16,6 +18,8 @@ pub enum EventType {
     TicketCreated,
     StatusChanged,
     NoteAdded,
+    NoteEdited,
+    NoteRemoved,
     FieldUpdated,
     DependencyAdded,
     DependencyRemoved,
@@ -23,6 +27,7 @@ pub enum EventType {
     LinkRemoved,
     LabelAdded,
     LabelRemoved,
+    TicketSynced,
 
     // Plan events
     PlanCreated,
@@ -49,11 +54,13 @@ pub enum EventType {
 enum_display_fromstr!(
     EventType,
     crate::error::JanusError::invalid_event_type,
-    ["ticket_created", "status_changed", "note_added", "field_updated", "dependency_added", "dependency_removed", "link_added", "link_removed", "label_added", "label_removed", "plan_created", "ticket_added_to_plan", "ticket_removed_from_plan", "phase_added", "phase_removed", "ticket_moved", "objective_created", "objective_updated", "objective_deleted", "objective_field_updated", "objective_note_added", "doc_created", "cache_rebuilt"],
+    ["ticket_created", "status_changed", "note_added", "note_edited", "note_removed", "field_updated", "dependency_added", "dependency_removed", "link_added", "link_removed", "label_added", "label_removed", "ticket_synced", "plan_created", "ticket_added_to_plan", "ticket_removed_from_plan", "phase_added", "phase_removed", "ticket_moved", "objective_created", "objective_updated", "objective_deleted", "objective_field_updated", "objective_note_added", "doc_created", "cache_rebuilt"],
     {
         TicketCreated => "ticket_created",
         StatusChanged => "status_changed",
         NoteAdded => "note_added",
+        NoteEdited => "note_edited",
+        NoteRemoved => "note_removed",
         FieldUpdated => "field_updated",
         DependencyAdded => "dependency_added",
         DependencyRemoved => "dependency_removed",
@@ -61,6 +68,7 @@ enum_display_fromstr!(
         LinkRemoved => "link_removed",
         LabelAdded => "label_added",
         LabelRemoved => "label_removed",
+        TicketSynced => "ticket_synced",
         PlanCreated => "plan_created",
         TicketAddedToPlan => "ticket_added_to_plan",
         TicketRemovedFromPlan => "ticket_removed_from_plan",
@@ -149,6 +157,47 @@ impl Event {
         self.actor = actor;
         self
     }
+
+    /// One-line description of the change, used for auto-commit messages
+    /// (e.g., `j-a1b2 status new→in_progress`)
+    pub fn summary(&self) -> String {
+        let id = &self.entity_id;
+        let field = |key: &str| {
+            let value = self.data.get(key).and_then(|v| v.as_str()).unwrap_or("");
+            truncate_string(value, 40)
+        };
+
+        match self.event_type {
+            EventType::TicketCreated
+            | EventType::PlanCreated
+            | EventType::ObjectiveCreated
+            | EventType::DocCreated => format!("{id} created"),
+            EventType::StatusChanged => format!("{id} status {}→{}", field("from"), field("to")),
+            EventType::FieldUpdated | EventType::ObjectiveFieldUpdated => {
+                let name = field("field");
+                match self.data.get("old_value").and_then(|v| v.as_str()) {
+                    Some(_) => format!("{id} {name} {}→{}", field("old_value"), field("new_value")),
+                    None => format!("{id} {name} set to {}", field("new_value")),
+                }
+            }
+            EventType::DependencyAdded => format!("{id} dep add {}", field("dependency_id")),
+            EventType::DependencyRemoved => format!("{id} dep remove {}", field("dependency_id")),
+            EventType::LinkAdded => format!("{id} link {}", field("linked_id")),
+            EventType::LinkRemoved => format!("{id} unlink {}", field("linked_id")),
+            EventType::LabelAdded => format!("{id} label +{}", field("label")),
+            EventType::LabelRemoved => format!("{id} label -{}", field("label")),
+            EventType::TicketSynced => format!("{id} synced with {}", field("remote_ref")),
+            EventType::TicketAddedToPlan => format!("{id} add {}", field("ticket_id")),
+            EventType::TicketRemovedFromPlan => format!("{id} remove {}", field("ticket_id")),
+            EventType::TicketMoved => format!(
+                "{id} move {} {}→{}",
+                field("ticket_id"),
+                field("from_phase"),
+                field("to_phase")
+            ),
+            _ => format!("{id} {}", self.event_type.to_string().replace('_', " ")),
+        }
+    }
 }
 
 /// Get the current timestamp in ISO 8601 format with milliseconds
@@ -203,6 +252,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_event_summary() {
+        let event = Event::new(
+            EventType::StatusChanged,
+            EntityType::Ticket,
+            "j-a1b2",
+            json!({"from": "new", "to": "in_progress"}),
+        );
+        assert_eq!(event.summary(), "j-a1b2 status new→in_progress");
+
+        let event = Event::new(
+            EventType::FieldUpdated,
+            EntityType::Ticket,
+            "j-a1b2",
+            json!({"field": "priority", "old_value": null, "new_value": "1"}),
+        );
+        assert_eq!(event.summary(), "j-a1b2 priority set to 1");
+
+        let event = Event::new(
+            EventType::PhaseAdded,
+            EntityType::Plan,
+            "plan-c3d4",
+            json!({"phase_number": "2", "phase_name": "Rollout"}),
+        );
+        assert_eq!(event.summary(), "plan-c3d4 phase added");
+    }
+
     #[test]
     fn test_event_creation() {
         let event = Event::new(
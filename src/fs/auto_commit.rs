@@ -0,0 +1,213 @@
+//! Automatic git commits for `.janus` changes (`git.auto_commit`).
+//!
+//! Every file write and delete in the fs layer records its path, and every
+//! logged event records a one-line summary. When a command finishes (or an
+//! MCP tool call returns), `commit_pending_changes` stages exactly the
+//! recorded paths and commits them with a message built from the summaries,
+//! e.g. `janus: j-a1b2 status new→in_progress`.
+//!
+//! Changes are recorded per task inside [`with_auto_commit`], so concurrent
+//! MCP tool calls each commit only their own files. Changes made outside it
+//! are not recorded.
+//!
+//! Changes are recorded regardless of the setting and discarded at commit
+//! time when it is off, so recording never has to read the config.
+
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::error::Result;
+use crate::types::janus_root;
+use crate::utils::git;
+
+/// Prefix of every auto-commit subject line
+pub const COMMIT_PREFIX: &str = "janus:";
+
+/// Changes made since the last commit
+#[derive(Debug, Default)]
+struct PendingChanges {
+    paths: BTreeSet<PathBuf>,
+    summaries: Vec<String>,
+}
+
+tokio::task_local! {
+    static PENDING: RefCell<PendingChanges>;
+}
+
+/// Run `op`, then commit the changes it recorded.
+pub async fn with_auto_commit<T>(op: impl Future<Output = T>) -> T {
+    PENDING
+        .scope(RefCell::new(PendingChanges::default()), async {
+            let result = op.await;
+            commit_pending_changes().await;
+            result
+        })
+        .await
+}
+
+/// Record a file written or deleted by the current command.
+pub fn record_path(path: &Path) {
+    let _ = PENDING.try_with(|pending| {
+        pending.borrow_mut().paths.insert(path.to_path_buf());
+    });
+}
+
+/// Record a description of a change made by the current command.
+pub fn record_change(summary: String) {
+    let _ = PENDING.try_with(|pending| {
+        let mut pending = pending.borrow_mut();
+        if !pending.summaries.contains(&summary) {
+            pending.summaries.push(summary);
+        }
+    });
+}
+
+/// Commit the `.janus` files recorded so far by the current task if
+/// `git.auto_commit` is enabled.
+///
+/// Only the recorded paths are staged and committed, so unrelated staged
+/// changes stay out of the commit. Failures are printed as warnings rather
+/// than returned, since the command itself already succeeded.
+pub async fn commit_pending_changes() {
+    let Ok(pending) = PENDING.try_with(RefCell::take) else {
+        return;
+    };
+    if pending.paths.is_empty() {
+        return;
+    }
+    if !Config::load().is_ok_and(|config| config.git_auto_commit()) {
+        return;
+    }
+
+    let root = janus_root();
+    let paths: Vec<PathBuf> = pending
+        .paths
+        .iter()
+        .filter_map(|path| path.strip_prefix(&root).ok())
+        .map(Path::to_path_buf)
+        .collect();
+    if paths.is_empty() {
+        return;
+    }
+
+    let message = commit_message(&pending.summaries, &paths);
+    // git runs as a blocking subprocess; keep it off the async workers
+    let committed = tokio::task::spawn_blocking(move || commit_paths(&root, &paths, &message))
+        .await
+        .unwrap_or_else(|e| Err(std::io::Error::other(e).into()));
+    if let Err(e) = committed {
+        eprintln!("Warning: git.auto_commit failed: {e}");
+    }
+}
+
+/// Stage and commit `paths` (relative to `root`).
+fn commit_paths(root: &Path, paths: &[PathBuf], message: &str) -> Result<()> {
+    // `-A` stages deletions as well as new and modified files
+    git_with_paths(root, &["add", "-A", "--"], paths)?;
+
+    // Rewriting a file with identical content leaves nothing to commit;
+    // `--quiet` exits non-zero when there are staged changes
+    if git_with_paths(root, &["diff", "--cached", "--quiet", "--"], paths).is_ok() {
+        return Ok(());
+    }
+
+    // Passing the paths commits only them, not everything in the index
+    git_with_paths(root, &["commit", "--quiet", "-m", message, "--"], paths)
+}
+
+fn git_with_paths(root: &Path, args: &[&str], paths: &[PathBuf]) -> Result<()> {
+    let args = args.iter().map(OsStr::new);
+    git(
+        Some(root),
+        args.chain(paths.iter().map(|path| path.as_os_str())),
+    )?;
+    Ok(())
+}
+
+/// Build the commit message: the first change as the subject, and every
+/// change listed in the body when there is more than one. Without event
+/// summaries, the subject names the changed files.
+fn commit_message(summaries: &[String], paths: &[PathBuf]) -> String {
+    match summaries {
+        [] => {
+            let names: Vec<String> = paths
+                .iter()
+                .take(3)
+                .map(|path| path.to_string_lossy().into_owned())
+                .collect();
+            let more = paths.len().saturating_sub(names.len());
+            let suffix = if more > 0 {
+                format!(" (+{more} more)")
+            } else {
+                String::new()
+            };
+            format!("{COMMIT_PREFIX} update {}{suffix}", names.join(", "))
+        }
+        [single] => format!("{COMMIT_PREFIX} {single}"),
+        [first, rest @ ..] => {
+            let body: Vec<String> = summaries.iter().map(|s| format!("- {s}")).collect();
+            format!(
+                "{COMMIT_PREFIX} {first} (+{} more)\n\n{}",
+                rest.len(),
+                body.join("\n")
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commit_message() {
+        let paths = vec![PathBuf::from("items/j-a1b2.md")];
+        assert_eq!(
+            commit_message(&["j-a1b2 status new→in_progress".to_string()], &paths),
+            "janus: j-a1b2 status new→in_progress"
+        );
+
+        let summaries = vec![
+            "j-a1b2 created".to_string(),
+            "j-a1b2 dep add j-c3d4".to_string(),
+        ];
+        assert_eq!(
+            commit_message(&summaries, &paths),
+            "janus: j-a1b2 created (+1 more)\n\n- j-a1b2 created\n- j-a1b2 dep add j-c3d4"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_changes_are_recorded_per_task() {
+        let record = |path: &'static str| {
+            PENDING.scope(RefCell::new(PendingChanges::default()), async move {
+                record_path(Path::new(path));
+                tokio::task::yield_now().await;
+                PENDING.with(|pending| pending.take().paths)
+            })
+        };
+        let (a, b) = tokio::join!(record("a.md"), record("b.md"));
+        assert_eq!(a, BTreeSet::from([PathBuf::from("a.md")]));
+        assert_eq!(b, BTreeSet::from([PathBuf::from("b.md")]));
+
+        // Outside a scope nothing is recorded
+        record_path(Path::new("c.md"));
+        assert!(PENDING.try_with(|_| ()).is_err());
+    }
+
+    #[test]
+    fn test_commit_message_without_summaries() {
+        let paths: Vec<PathBuf> = ["docs/a.md", "docs/b.md", "docs/c.md", "docs/d.md"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        assert_eq!(commit_message(&[], &paths[..1]), "janus: update docs/a.md");
+        assert_eq!(
+            commit_message(&[], &paths),
+            "janus: update docs/a.md, docs/b.md, docs/c.md (+1 more)"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Named locks shared between Janus processes.
+//!
+//! Ticket files are replaced atomically, which swaps their inode, so the
+//! files themselves can't be locked (see the module docs of [`crate::fs`]).
+//! A read-check-write sequence that must not interleave with the same
+//! sequence in another process instead holds an exclusive lock on a file
+//! under `.janus/.locks/`. The lock is released when the process exits, so a
+//! crash never leaves it held.
+
+use std::fs::File;
+use std::path::PathBuf;
+
+use crate::error::{JanusError, Result};
+use crate::read_only::ensure_writable;
+use crate::types::janus_root;
+
+/// Lock files never belong in version control
+const LOCKS_GITIGNORE: &str = "*\n";
+
+/// Take the exclusive lock named `name`, waiting while another process holds it.
+///
+/// The lock is held until the returned file is dropped.
+pub fn exclusive_lock(name: &str) -> Result<File> {
+    ensure_writable(|| format!("take the {name} lock"))?;
+    let dir = locks_dir();
+    std::fs::create_dir_all(&dir).map_err(|e| lock_error("create", dir.clone(), e))?;
+    let gitignore = dir.join(".gitignore");
+    if !gitignore.exists() {
+        std::fs::write(&gitignore, LOCKS_GITIGNORE)
+            .map_err(|e| lock_error("write", gitignore.clone(), e))?;
+    }
+
+    let path = dir.join(format!("{name}.lock"));
+    let file = File::create(&path).map_err(|e| lock_error("create", path.clone(), e))?;
+    file.lock().map_err(|e| lock_error("lock", path, e))?;
+    Ok(file)
+}
+
+fn locks_dir() -> PathBuf {
+    janus_root().join(".locks")
+}
+
+fn lock_error(operation: &'static str, path: PathBuf, source: std::io::Error) -> JanusError {
+    JanusError::StorageError {
+        operation,
+        item_type: "lock",
+        path,
+        source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths::JanusRootGuard;
+    use std::fs::TryLockError;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_exclusive_lock() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().join(".janus");
+        let _guard = JanusRootGuard::new(&root);
+
+        let held = exclusive_lock("ticket-j-a1b2").unwrap();
+        let path = root.join(".locks/ticket-j-a1b2.lock");
+        let other = File::open(&path).unwrap();
+        assert!(matches!(other.try_lock(), Err(TryLockError::WouldBlock)));
+
+        drop(held);
+        assert!(other.try_lock().is_ok());
+        assert_eq!(
+            std::fs::read_to_string(root.join(".locks/.gitignore")).unwrap(),
+            "*\n"
+        );
+    }
+}
@@ -1,5 +1,8 @@
 //! Simple file I/O utilities with hook support
 //!
+//! Writes and deletes are recorded for `git.auto_commit` (see `auto_commit`),
+//! and refused in read-only mode (see [`crate::read_only`]).
+//!
 //! # Concurrency Model
 //!
 //! All writes use atomic replace (write to a temp file, then rename onto the
@@ -9,9 +12,27 @@
 //! one update may silently overwrite the other. No advisory locking is
 //! performed because atomic-replace swaps the file's inode, which makes
 //! `flock(2)`-style locks ineffective.
+//!
+//! Operations that write several files run inside a [`transaction`], which
+//! journals the originals so a failure or crash never leaves them half-applied.
+//! Read-check-write sequences that must exclude other processes take a named
+//! [`exclusive_lock`] instead of locking the file itself.
+
+mod auto_commit;
+mod lock;
+mod transaction;
+
+pub use auto_commit::{
+    COMMIT_PREFIX, commit_pending_changes, record_change, record_path, with_auto_commit,
+};
+pub use lock::exclusive_lock;
+pub(crate) use transaction::before_change;
+pub use transaction::{recover_transactions, transaction};
 
 use crate::error::{JanusError, Result};
 use crate::hooks::{HookContext, HookEvent, run_post_hooks, run_pre_hooks};
+use crate::parser::{FrontmatterFormat, convert_frontmatter};
+use crate::read_only::ensure_writable;
 use std::io::Write;
 use std::path::Path;
 use tempfile::NamedTempFile;
@@ -47,6 +68,13 @@ pub fn write_file(path: &Path, content: &str) -> Result<()> {
 /// **Concurrency note**: no advisory locking is performed. Concurrent
 /// read-modify-write cycles follow last-writer-wins semantics.
 pub fn write_file_atomic(path: &Path, content: &str) -> Result<()> {
+    write_bytes_atomic(path, content.as_bytes())
+}
+
+/// Write binary content atomically, like [`write_file_atomic`].
+pub fn write_bytes_atomic(path: &Path, content: &[u8]) -> Result<()> {
+    ensure_writable(|| format!("write {}", path.display()))?;
+    before_change(path)?;
     ensure_parent_dir(path)?;
 
     let parent = path.parent().unwrap_or(Path::new("."));
@@ -61,7 +89,7 @@ pub fn write_file_atomic(path: &Path, content: &str) -> Result<()> {
 
     // Write content to the temp file
     temp_file
-        .write_all(content.as_bytes())
+        .write_all(content)
         .map_err(|e| JanusError::StorageError {
             operation: "write",
             item_type: "file",
@@ -79,9 +107,25 @@ pub fn write_file_atomic(path: &Path, content: &str) -> Result<()> {
             source: e.into(),
         })?;
 
+    record_path(path);
     Ok(())
 }
 
+/// Write a markdown document atomically, keeping the frontmatter format of
+/// the file being replaced.
+///
+/// Documents are always built with YAML frontmatter. When the existing file
+/// uses TOML frontmatter, `content` is converted to TOML before writing so
+/// the file stays in the format its author chose.
+pub fn write_document_atomic(path: &Path, content: &str) -> Result<()> {
+    let format = match std::fs::read_to_string(path) {
+        Ok(existing) => FrontmatterFormat::detect(&existing),
+        Err(_) => FrontmatterFormat::Yaml,
+    };
+    let content = convert_frontmatter(content, format)?;
+    write_file_atomic(path, &content)
+}
+
 /// Ensure parent directory exists
 pub fn ensure_parent_dir(path: &Path) -> Result<()> {
     if let Some(parent) = path.parent()
@@ -99,12 +143,16 @@ pub fn ensure_parent_dir(path: &Path) -> Result<()> {
 
 /// Delete a file with error handling
 pub fn delete_file(path: &Path) -> Result<()> {
+    ensure_writable(|| format!("delete {}", path.display()))?;
+    before_change(path)?;
     std::fs::remove_file(path).map_err(|e| JanusError::StorageError {
         operation: "delete",
         item_type: "file",
         path: path.to_path_buf(),
         source: e,
-    })
+    })?;
+    record_path(path);
+    Ok(())
 }
 
 /// Execute an operation with standard write hooks.
@@ -157,6 +205,8 @@ pub async fn write_file_async(path: &Path, content: &str) -> Result<()> {
 /// **Concurrency note**: no advisory locking is performed. Concurrent
 /// read-modify-write cycles follow last-writer-wins semantics.
 pub async fn write_file_async_atomic(path: &Path, content: &str) -> Result<()> {
+    ensure_writable(|| format!("write {}", path.display()))?;
+    before_change(path)?;
     ensure_parent_dir_async(path).await?;
 
     let parent = path.parent().unwrap_or(Path::new("."));
@@ -185,6 +235,7 @@ pub async fn write_file_async_atomic(path: &Path, content: &str) -> Result<()> {
             source: e,
         })?;
 
+    record_path(path);
     Ok(())
 }
 
@@ -207,6 +258,8 @@ pub async fn ensure_parent_dir_async(path: &Path) -> Result<()> {
 
 /// Delete a file with error handling (async version)
 pub async fn delete_file_async(path: &Path) -> Result<()> {
+    ensure_writable(|| format!("delete {}", path.display()))?;
+    before_change(path)?;
     tokio_fs::remove_file(path)
         .await
         .map_err(|e| JanusError::StorageError {
@@ -214,5 +267,7 @@ pub async fn delete_file_async(path: &Path) -> Result<()> {
             item_type: "file",
             path: path.to_path_buf(),
             source: e,
-        })
+        })?;
+    record_path(path);
+    Ok(())
 }
@@ -0,0 +1,390 @@
+//! Multi-file transactions for compound operations.
+//!
+//! A single write is already atomic (see the module docs of [`crate::fs`]),
+//! but operations such as linking several tickets or pruning references
+//! across tickets and plans touch many files. [`transaction`] makes such an
+//! operation all-or-nothing: before the first write or delete of each path,
+//! the original file (or the fact that it did not exist) is saved to an
+//! undo journal under `.janus/.txn/<id>/`. If the operation fails, every
+//! touched path is restored from the journal. If the process dies instead,
+//! the journal is left behind and [`recover_transactions`] rolls it back the
+//! next time Janus starts.
+//!
+//! While it runs, a transaction holds an exclusive lock on
+//! `.janus/.txn/<id>.lock`. The lock is released when the process exits, so
+//! recovery can tell an abandoned journal from one that another Janus process
+//! is still writing, and leaves the latter alone.
+//!
+//! The active transaction is scoped to the current task, so concurrent tasks
+//! (parallel tests, MCP tool calls) never record into each other's journal.
+//! Nested calls join the outermost transaction.
+
+use std::cell::RefCell;
+use std::fs::{File, TryLockError};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::{JanusError, Result};
+use crate::types::janus_root;
+
+/// Name of the journal file inside a transaction directory
+const MANIFEST: &str = "journal.json";
+
+tokio::task_local! {
+    static ACTIVE: RefCell<Journal>;
+}
+
+/// A path touched by a transaction and how to restore it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    path: PathBuf,
+    /// File name of the saved original inside the journal directory, or
+    /// `None` if the path did not exist before the transaction
+    backup: Option<String>,
+}
+
+/// What is written to `journal.json`
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    label: String,
+    entries: Vec<Entry>,
+}
+
+/// The undo journal of the transaction running in the current task
+#[derive(Debug, Default)]
+struct Journal {
+    /// Created on the first write, so read-only transactions leave no trace
+    dir: Option<PathBuf>,
+    /// Held from the creation of `dir` until the journal is discarded
+    lock: Option<File>,
+    manifest: Manifest,
+}
+
+impl Journal {
+    fn new(label: &str) -> Self {
+        Journal {
+            dir: None,
+            lock: None,
+            manifest: Manifest {
+                label: label.to_string(),
+                entries: Vec::new(),
+            },
+        }
+    }
+
+    /// Save the current state of `path` unless it was already saved.
+    fn save(&mut self, path: &Path) -> Result<()> {
+        if self.manifest.entries.iter().any(|e| e.path == path) {
+            return Ok(());
+        }
+        let dir = match &self.dir {
+            Some(dir) => dir.clone(),
+            None => {
+                let dir = transactions_dir().join(Uuid::new_v4().to_string());
+                // Lock first, so recovery never sees the directory unowned
+                self.lock = Some(acquire_lock(&dir)?);
+                std::fs::create_dir_all(&dir).map_err(|e| storage_error("create", &dir, e))?;
+                self.dir = Some(dir.clone());
+                dir
+            }
+        };
+
+        let backup = if path.exists() {
+            let name = self.manifest.entries.len().to_string();
+            std::fs::copy(path, dir.join(&name)).map_err(|e| storage_error("back up", path, e))?;
+            Some(name)
+        } else {
+            None
+        };
+        self.manifest.entries.push(Entry {
+            path: path.to_path_buf(),
+            backup,
+        });
+        // The journal must be on disk before the change it protects
+        write_manifest(&dir, &self.manifest)
+    }
+
+    /// Discard the journal, making the transaction's changes permanent.
+    fn commit(self) -> Result<()> {
+        let Some(dir) = self.dir else {
+            return Ok(());
+        };
+        std::fs::remove_dir_all(&dir).map_err(|e| storage_error("delete", &dir, e))?;
+        remove_lock(&dir);
+        Ok(())
+    }
+
+    /// Restore every touched path, then discard the journal.
+    ///
+    /// On failure the journal is kept so [`recover_transactions`] can retry.
+    fn roll_back(self) -> Result<()> {
+        let Some(dir) = self.dir else {
+            return Ok(());
+        };
+        roll_back_dir(&dir, &self.manifest)
+    }
+}
+
+/// Run `op` as a transaction named `label`.
+///
+/// Every file written or deleted through [`crate::fs`] while `op` runs is
+/// journaled first. If `op` returns an error, all of those files are put
+/// back the way they were and the error is returned. Files written without
+/// going through [`crate::fs`] are not covered.
+pub async fn transaction<T, F, Fut>(label: &str, op: F) -> Result<T>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    if ACTIVE.try_with(|_| ()).is_ok() {
+        return op().await;
+    }
+
+    let (result, journal) = ACTIVE
+        .scope(RefCell::new(Journal::new(label)), async {
+            let result = op().await;
+            (result, ACTIVE.with(|journal| journal.take()))
+        })
+        .await;
+
+    match result {
+        Ok(value) => {
+            journal.commit()?;
+            Ok(value)
+        }
+        Err(e) => {
+            if let Err(rollback_error) = journal.roll_back() {
+                eprintln!(
+                    "Warning: failed to roll back '{label}': {rollback_error}; it will be retried on the next run"
+                );
+            }
+            Err(e)
+        }
+    }
+}
+
+/// Journal `path` in the current task's transaction, if there is one.
+///
+/// Called by the write and delete helpers before they touch `path`.
+pub(crate) fn before_change(path: &Path) -> Result<()> {
+    ACTIVE
+        .try_with(|journal| journal.borrow_mut().save(path))
+        .unwrap_or(Ok(()))
+}
+
+/// Roll back transactions left behind by a process that died mid-operation.
+///
+/// Transactions whose owner is still running are skipped. Returns the labels
+/// of the transactions that were rolled back. Does nothing in read-only mode,
+/// since rolling back writes files.
+pub fn recover_transactions() -> Result<Vec<String>> {
+    let root = transactions_dir();
+    if crate::read_only::is_enabled() || !root.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut recovered = Vec::new();
+    let entries = std::fs::read_dir(&root).map_err(|e| storage_error("read", &root, e))?;
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        if !dir.is_dir() {
+            continue;
+        }
+        let Some(_lock) = take_over(&dir)? else {
+            continue;
+        };
+        // Another recovery may have finished with it before we got the lock
+        if !dir.is_dir() {
+            remove_lock(&dir);
+            continue;
+        }
+        // Without a manifest nothing was changed yet
+        let manifest = std::fs::read_to_string(dir.join(MANIFEST))
+            .ok()
+            .and_then(|content| serde_json::from_str::<Manifest>(&content).ok());
+        match manifest {
+            Some(manifest) => {
+                roll_back_dir(&dir, &manifest)?;
+                recovered.push(manifest.label);
+            }
+            None => {
+                std::fs::remove_dir_all(&dir).map_err(|e| storage_error("delete", &dir, e))?;
+                remove_lock(&dir);
+            }
+        }
+    }
+    Ok(recovered)
+}
+
+fn transactions_dir() -> PathBuf {
+    janus_root().join(".txn")
+}
+
+/// The lock file of the transaction journaled in `dir`
+fn lock_path(dir: &Path) -> PathBuf {
+    dir.with_extension("lock")
+}
+
+/// Create and lock the lock file of a new transaction journaled in `dir`.
+fn acquire_lock(dir: &Path) -> Result<File> {
+    let path = lock_path(dir);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| storage_error("create", parent, e))?;
+    }
+    let file = File::create(&path).map_err(|e| storage_error("create", &path, e))?;
+    file.lock().map_err(|e| storage_error("lock", &path, e))?;
+    Ok(file)
+}
+
+/// Lock the transaction journaled in `dir` for recovery.
+///
+/// Returns `None` if its owner still holds the lock. Journals without a lock
+/// file get one, so concurrent recoveries still exclude each other.
+fn take_over(dir: &Path) -> Result<Option<File>> {
+    let path = lock_path(dir);
+    let file = File::create(&path).map_err(|e| storage_error("create", &path, e))?;
+    match file.try_lock() {
+        Ok(()) => Ok(Some(file)),
+        Err(TryLockError::WouldBlock) => Ok(None),
+        Err(TryLockError::Error(e)) => Err(storage_error("lock", &path, e)),
+    }
+}
+
+/// Delete the lock file of the transaction journaled in `dir`.
+///
+/// A leftover lock file is harmless, so failures are ignored.
+fn remove_lock(dir: &Path) {
+    let _ = std::fs::remove_file(lock_path(dir));
+}
+
+fn roll_back_dir(dir: &Path, manifest: &Manifest) -> Result<()> {
+    for entry in manifest.entries.iter().rev() {
+        match &entry.backup {
+            Some(name) => {
+                // Copy beside the target, then rename, so the restore is atomic too
+                let temp = entry
+                    .path
+                    .with_file_name(format!(".tmp-restore-{}", Uuid::new_v4()));
+                std::fs::copy(dir.join(name), &temp)
+                    .map_err(|e| storage_error("restore", &entry.path, e))?;
+                std::fs::rename(&temp, &entry.path)
+                    .map_err(|e| storage_error("restore", &entry.path, e))?;
+            }
+            None => {
+                if let Err(e) = std::fs::remove_file(&entry.path)
+                    && e.kind() != std::io::ErrorKind::NotFound
+                {
+                    return Err(storage_error("restore", &entry.path, e));
+                }
+            }
+        }
+        super::record_path(&entry.path);
+    }
+    std::fs::remove_dir_all(dir).map_err(|e| storage_error("delete", dir, e))?;
+    remove_lock(dir);
+    Ok(())
+}
+
+fn write_manifest(dir: &Path, manifest: &Manifest) -> Result<()> {
+    let path = dir.join(MANIFEST);
+    let temp = dir.join(format!("{MANIFEST}.tmp"));
+    let content = serde_json::to_string(manifest)?;
+    std::fs::write(&temp, content).map_err(|e| storage_error("write", &temp, e))?;
+    std::fs::rename(&temp, &path).map_err(|e| storage_error("write", &path, e))
+}
+
+fn storage_error(operation: &'static str, path: &Path, source: std::io::Error) -> JanusError {
+    JanusError::StorageError {
+        operation,
+        item_type: "file",
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths::JanusRootGuard;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_transaction_rolls_back_on_error() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().join(".janus");
+        let _guard = JanusRootGuard::new(&root);
+        let existing = root.join("items/a.md");
+        let created = root.join("items/b.md");
+        crate::fs::write_file(&existing, "original").unwrap();
+
+        let result: Result<()> = transaction("test", || async {
+            crate::fs::write_file(&existing, "changed")?;
+            crate::fs::write_file(&created, "new")?;
+            Err(JanusError::InvalidFormat("boom".to_string()))
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_to_string(&existing).unwrap(), "original");
+        assert!(!created.exists());
+        assert_eq!(std::fs::read_dir(transactions_dir()).unwrap().count(), 0);
+
+        transaction("test", || async { crate::fs::delete_file(&existing) })
+            .await
+            .unwrap();
+        assert!(!existing.exists());
+        assert_eq!(std::fs::read_dir(transactions_dir()).unwrap().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_recover_transactions() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().join(".janus");
+        let _guard = JanusRootGuard::new(&root);
+        let existing = root.join("items/a.md");
+        let created = root.join("items/b.md");
+        crate::fs::write_file(&existing, "original").unwrap();
+
+        // Simulate a crash: journal and change both on disk, never committed
+        let mut journal = Journal::new("link j-1 j-2");
+        journal.save(&existing).unwrap();
+        journal.save(&created).unwrap();
+        std::fs::write(&existing, "changed").unwrap();
+        std::fs::write(&created, "new").unwrap();
+        drop(journal);
+
+        let recovered = recover_transactions().unwrap();
+        assert_eq!(recovered, vec!["link j-1 j-2".to_string()]);
+        assert_eq!(std::fs::read_to_string(&existing).unwrap(), "original");
+        assert!(!created.exists());
+        assert!(recover_transactions().unwrap().is_empty());
+        assert_eq!(std::fs::read_dir(transactions_dir()).unwrap().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_recover_skips_open_transaction() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().join(".janus");
+        let _guard = JanusRootGuard::new(&root);
+        let existing = root.join("items/a.md");
+        crate::fs::write_file(&existing, "original").unwrap();
+
+        // Another process is still in the middle of this transaction
+        let mut journal = Journal::new("link j-1 j-2");
+        journal.save(&existing).unwrap();
+        std::fs::write(&existing, "changed").unwrap();
+
+        assert!(recover_transactions().unwrap().is_empty());
+        assert_eq!(std::fs::read_to_string(&existing).unwrap(), "changed");
+        assert!(journal.dir.as_ref().unwrap().join(MANIFEST).exists());
+
+        // Once its owner is gone, the journal is rolled back
+        drop(journal);
+        let recovered = recover_transactions().unwrap();
+        assert_eq!(recovered, vec!["link j-1 j-2".to_string()]);
+        assert_eq!(std::fs::read_to_string(&existing).unwrap(), "original");
+    }
+}
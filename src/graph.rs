@@ -125,6 +125,94 @@ pub fn check_circular_dependency(
     Ok(())
 }
 
+/// Unsatisfied dependencies of a ticket that live outside a set of tickets.
+///
+/// Used to find work that a plan cannot finish on its own: a dep is reported
+/// when it is not in `scope` and is not satisfied per
+/// [`crate::status::is_dependency_satisfied`] (including deps that do not
+/// exist in the map).
+pub fn external_blockers(
+    ticket: &TicketMetadata,
+    scope: &HashSet<&str>,
+    ticket_map: &HashMap<String, TicketMetadata>,
+) -> Vec<String> {
+    ticket
+        .deps
+        .iter()
+        .map(|dep| dep.as_ref())
+        .filter(|dep| !scope.contains(dep))
+        .filter(|dep| !crate::status::is_dependency_satisfied(dep, ticket_map))
+        .map(String::from)
+        .collect()
+}
+
+/// Unsatisfied dependencies of a ticket, in dependency order.
+pub fn direct_blockers(
+    ticket: &TicketMetadata,
+    ticket_map: &HashMap<String, TicketMetadata>,
+) -> Vec<String> {
+    ticket
+        .deps
+        .iter()
+        .map(|dep| dep.as_ref())
+        .filter(|dep| !crate::status::is_dependency_satisfied(dep, ticket_map))
+        .map(String::from)
+        .collect()
+}
+
+/// The tickets at the bottom of a ticket's chains of unsatisfied dependencies.
+///
+/// Following unsatisfied deps transitively, a root blocker is one with no
+/// unsatisfied deps of its own: a missing ticket, or one that can be worked on
+/// now. When a chain loops back on itself, the ticket that closes the cycle is
+/// reported instead. Results are sorted and deduplicated.
+pub fn root_blockers(
+    ticket: &TicketMetadata,
+    ticket_map: &HashMap<String, TicketMetadata>,
+) -> Vec<String> {
+    fn walk(
+        id: &str,
+        ticket_map: &HashMap<String, TicketMetadata>,
+        path: &mut HashSet<String>,
+        done: &mut HashSet<String>,
+        roots: &mut HashSet<String>,
+    ) {
+        if done.contains(id) {
+            return;
+        }
+        let blockers = ticket_map
+            .get(id)
+            .map(|t| direct_blockers(t, ticket_map))
+            .unwrap_or_default();
+        path.insert(id.to_string());
+        let mut onward = false;
+        for blocker in blockers {
+            if !path.contains(&blocker) {
+                onward = true;
+                walk(&blocker, ticket_map, path, done, roots);
+            }
+        }
+        path.remove(id);
+        done.insert(id.to_string());
+        if !onward {
+            roots.insert(id.to_string());
+        }
+    }
+
+    let mut path: HashSet<String> = ticket.id.iter().map(|id| id.to_string()).collect();
+    let mut done = HashSet::new();
+    let mut roots = HashSet::new();
+    for blocker in direct_blockers(ticket, ticket_map) {
+        if !path.contains(&blocker) {
+            walk(&blocker, ticket_map, &mut path, &mut done, &mut roots);
+        }
+    }
+
+    let mut roots: Vec<String> = roots.into_iter().collect();
+    roots.sort();
+    roots
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,4 +261,64 @@ mod tests {
         let result = resolve_id_from_map("j-a1b2", &map);
         assert!(matches!(result, Err(JanusError::EmptyTicketMap)));
     }
+
+    #[test]
+    fn test_external_blockers() {
+        use crate::types::TicketStatus;
+
+        let ticket = |id: &str, status: TicketStatus, deps: &[&str]| TicketMetadata {
+            id: Some(TicketId::new_unchecked(id)),
+            status: Some(status),
+            deps: deps.iter().map(|d| TicketId::new_unchecked(*d)).collect(),
+            ..Default::default()
+        };
+        let mut map = HashMap::new();
+        for t in [
+            ticket("j-in", TicketStatus::New, &[]),
+            ticket("j-open", TicketStatus::InProgress, &[]),
+            ticket("j-done", TicketStatus::Complete, &[]),
+        ] {
+            map.insert(t.id.as_ref().unwrap().to_string(), t);
+        }
+        let scope: HashSet<&str> = ["j-in", "j-a"].into_iter().collect();
+
+        let candidate = ticket(
+            "j-a",
+            TicketStatus::New,
+            &["j-in", "j-open", "j-done", "j-missing"],
+        );
+        assert_eq!(
+            external_blockers(&candidate, &scope, &map),
+            vec!["j-open", "j-missing"]
+        );
+    }
+
+    #[test]
+    fn test_root_blockers() {
+        use crate::types::TicketStatus;
+
+        let ticket = |id: &str, status: TicketStatus, deps: &[&str]| TicketMetadata {
+            id: Some(TicketId::new_unchecked(id)),
+            status: Some(status),
+            deps: deps.iter().map(|d| TicketId::new_unchecked(*d)).collect(),
+            ..Default::default()
+        };
+        let mut map = HashMap::new();
+        for t in [
+            ticket("j-a", TicketStatus::New, &["j-b", "j-c", "j-done"]),
+            ticket("j-b", TicketStatus::New, &["j-c", "j-missing"]),
+            ticket("j-c", TicketStatus::InProgress, &[]),
+            ticket("j-done", TicketStatus::Complete, &[]),
+            ticket("j-x", TicketStatus::New, &["j-y"]),
+            ticket("j-y", TicketStatus::New, &["j-z"]),
+            ticket("j-z", TicketStatus::New, &["j-y"]),
+        ] {
+            map.insert(t.id.as_ref().unwrap().to_string(), t);
+        }
+
+        assert_eq!(direct_blockers(&map["j-a"], &map), vec!["j-b", "j-c"]);
+        assert_eq!(root_blockers(&map["j-a"], &map), vec!["j-c", "j-missing"]);
+        assert_eq!(root_blockers(&map["j-x"], &map), vec!["j-z"]);
+        assert!(root_blockers(&map["j-c"], &map).is_empty());
+    }
 }
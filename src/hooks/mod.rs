@@ -6,9 +6,10 @@
 //!
 //! # Hook Types
 //!
-//! - **Pre-hooks** (`pre_write`, `pre_delete`): Run before operations and can abort
-//!   them by returning a non-zero exit code.
-//! - **Post-hooks** (`post_write`, `post_delete`, `*_created`, `*_updated`, `*_deleted`):
+//! - **Pre-hooks** (`pre_write`, `pre_delete`, `pre_sync`): Run before operations and
+//!   can abort them by returning a non-zero exit code.
+//! - **Post-hooks** (`post_write`, `post_delete`, `post_sync`, `*_created`, `*_updated`,
+//!   `*_deleted`):
 //!   Run after operations. Failures are logged as warnings but don't abort.
 //!
 //! # Hook Failure Logging
@@ -27,6 +28,10 @@
 //! - `JANUS_FIELD_NAME`: Field being modified (for updates)
 //! - `JANUS_OLD_VALUE`: Previous value (for updates)
 //! - `JANUS_NEW_VALUE`: New value (for updates)
+//! - `JANUS_PROVIDER`: Remote provider, "github" or "linear" (for sync events)
+//! - `JANUS_REMOTE_REF`: Linked remote issue (for sync events)
+//! - `JANUS_CHANGED_FIELDS`: Comma-separated local fields updated from the remote
+//! - `JANUS_PUSHED_FIELDS`: Comma-separated fields pushed to the remote
 //! - `JANUS_ROOT`: Path to the .janus directory
 
 mod runner;
@@ -212,6 +217,30 @@ mod tests {
         assert_eq!(env.get("JANUS_ROOT"), Some(&".janus".to_string()));
     }
 
+    #[test]
+    fn test_context_to_env_sync() {
+        let context = HookContext::new()
+            .with_event(HookEvent::PostSync)
+            .with_item_id("j-1234")
+            .with_provider("github")
+            .with_remote_ref("github:acme/app/42")
+            .with_changed_fields(vec!["status".to_string(), "labels".to_string()]);
+
+        let env = context_to_env(&context, &PathBuf::from(".janus"));
+
+        assert_eq!(env.get("JANUS_EVENT"), Some(&"post_sync".to_string()));
+        assert_eq!(env.get("JANUS_PROVIDER"), Some(&"github".to_string()));
+        assert_eq!(
+            env.get("JANUS_REMOTE_REF"),
+            Some(&"github:acme/app/42".to_string())
+        );
+        assert_eq!(
+            env.get("JANUS_CHANGED_FIELDS"),
+            Some(&"status,labels".to_string())
+        );
+        assert_eq!(env.get("JANUS_PUSHED_FIELDS"), None);
+    }
+
     #[test]
     fn test_context_to_env_minimal() {
         let context = HookContext::new().with_event(HookEvent::PostWrite);
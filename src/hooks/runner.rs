@@ -400,6 +400,28 @@ pub fn context_to_env(context: &HookContext, janus_root: &Path) -> HashMap<Strin
         env.insert("JANUS_NEW_VALUE".to_string(), new_value.clone());
     }
 
+    if let Some(provider) = &context.provider {
+        env.insert("JANUS_PROVIDER".to_string(), provider.clone());
+    }
+
+    if let Some(remote_ref) = &context.remote_ref {
+        env.insert("JANUS_REMOTE_REF".to_string(), remote_ref.clone());
+    }
+
+    if !context.changed_fields.is_empty() {
+        env.insert(
+            "JANUS_CHANGED_FIELDS".to_string(),
+            context.changed_fields.join(","),
+        );
+    }
+
+    if !context.pushed_fields.is_empty() {
+        env.insert(
+            "JANUS_PUSHED_FIELDS".to_string(),
+            context.pushed_fields.join(","),
+        );
+    }
+
     env.insert("JANUS_ROOT".to_string(), janus_root.display().to_string());
 
     env
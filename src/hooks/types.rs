@@ -36,12 +36,19 @@ pub enum HookEvent {
     PreDelete,
     /// Fired after deleting any item
     PostDelete,
+    /// Fired before sync changes are applied to a ticket or its remote issue (can abort)
+    PreSync,
+    /// Fired after sync changes are applied to a ticket or its remote issue
+    PostSync,
 }
 
 impl HookEvent {
     /// Returns true if this is a pre-operation hook that can abort the operation.
     pub fn is_pre_hook(&self) -> bool {
-        matches!(self, HookEvent::PreWrite | HookEvent::PreDelete)
+        matches!(
+            self,
+            HookEvent::PreWrite | HookEvent::PreDelete | HookEvent::PreSync
+        )
     }
 
     /// Returns the event name as used in configuration and environment variables.
@@ -59,6 +66,8 @@ impl HookEvent {
             HookEvent::PostWrite => "post_write",
             HookEvent::PreDelete => "pre_delete",
             HookEvent::PostDelete => "post_delete",
+            HookEvent::PreSync => "pre_sync",
+            HookEvent::PostSync => "post_sync",
         }
     }
 
@@ -77,6 +86,8 @@ impl HookEvent {
             HookEvent::PostWrite,
             HookEvent::PreDelete,
             HookEvent::PostDelete,
+            HookEvent::PreSync,
+            HookEvent::PostSync,
         ]
     }
 }
@@ -84,7 +95,7 @@ impl HookEvent {
 enum_display_fromstr!(
     HookEvent,
     crate::error::JanusError::invalid_hook_event,
-    ["ticket_created", "ticket_updated", "plan_created", "plan_updated", "plan_deleted", "objective_created", "objective_updated", "objective_deleted", "pre_write", "post_write", "pre_delete", "post_delete"],
+    ["ticket_created", "ticket_updated", "plan_created", "plan_updated", "plan_deleted", "objective_created", "objective_updated", "objective_deleted", "pre_write", "post_write", "pre_delete", "post_delete", "pre_sync", "post_sync"],
     {
         TicketCreated => "ticket_created",
         TicketUpdated => "ticket_updated",
@@ -98,6 +109,8 @@ enum_display_fromstr!(
         PostWrite => "post_write",
         PreDelete => "pre_delete",
         PostDelete => "post_delete",
+        PreSync => "pre_sync",
+        PostSync => "post_sync",
     }
 );
 
@@ -118,6 +131,14 @@ pub struct HookContext {
     pub old_value: Option<String>,
     /// The new value (for updates)
     pub new_value: Option<String>,
+    /// The remote provider (for sync events)
+    pub provider: Option<String>,
+    /// The linked remote issue (for sync events)
+    pub remote_ref: Option<String>,
+    /// Local fields updated from the remote issue (for sync events)
+    pub changed_fields: Vec<String>,
+    /// Fields pushed to the remote issue (for sync events)
+    pub pushed_fields: Vec<String>,
 }
 
 impl HookContext {
@@ -167,6 +188,30 @@ impl HookContext {
         self.new_value = Some(new_value.into());
         self
     }
+
+    /// Set the remote provider.
+    pub fn with_provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+
+    /// Set the remote reference.
+    pub fn with_remote_ref(mut self, remote_ref: impl Into<String>) -> Self {
+        self.remote_ref = Some(remote_ref.into());
+        self
+    }
+
+    /// Set the local fields updated from the remote.
+    pub fn with_changed_fields(mut self, fields: Vec<String>) -> Self {
+        self.changed_fields = fields;
+        self
+    }
+
+    /// Set the fields pushed to the remote.
+    pub fn with_pushed_fields(mut self, fields: Vec<String>) -> Self {
+        self.pushed_fields = fields;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -198,6 +243,8 @@ mod tests {
     fn test_hook_event_is_pre_hook() {
         assert!(HookEvent::PreWrite.is_pre_hook());
         assert!(HookEvent::PreDelete.is_pre_hook());
+        assert!(HookEvent::PreSync.is_pre_hook());
+        assert!(!HookEvent::PostSync.is_pre_hook());
         assert!(!HookEvent::PostWrite.is_pre_hook());
         assert!(!HookEvent::TicketCreated.is_pre_hook());
     }
@@ -205,7 +252,7 @@ mod tests {
     #[test]
     fn test_hook_event_all() {
         let all = HookEvent::all();
-        assert_eq!(all.len(), 12);
+        assert_eq!(all.len(), 14);
         assert!(all.contains(&HookEvent::TicketCreated));
         assert!(all.contains(&HookEvent::ObjectiveCreated));
         assert!(all.contains(&HookEvent::ObjectiveUpdated));
@@ -264,6 +311,8 @@ mod tests {
             ("post_write", HookEvent::PostWrite),
             ("pre_delete", HookEvent::PreDelete),
             ("post_delete", HookEvent::PostDelete),
+            ("pre_sync", HookEvent::PreSync),
+            ("post_sync", HookEvent::PostSync),
         ];
 
         for (s, expected) in events {
@@ -2,8 +2,11 @@
 pub mod macros;
 
 pub mod archive;
+pub mod attachment;
+pub mod backup;
 pub mod cli;
 pub mod commands;
+pub mod component;
 pub mod config;
 pub mod display;
 pub mod doc;
@@ -13,6 +16,8 @@ pub mod events;
 pub mod fs;
 pub mod graph;
 pub mod hooks;
+#[cfg(feature = "llm")]
+pub mod llm;
 pub mod locator;
 pub mod markdown_formatter;
 pub mod mcp;
@@ -22,8 +27,13 @@ pub mod parser;
 pub mod paths;
 pub mod plan;
 pub mod query;
+pub mod read_only;
+pub mod ready;
 pub mod remote;
+pub mod secrets;
+pub mod sla;
 pub mod status;
+pub mod suggest;
 pub mod ticket;
 pub mod tui;
 pub mod types;
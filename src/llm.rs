@@ -0,0 +1,190 @@
+//! Minimal LLM client for `janus summarize` (the `llm` cargo feature).
+//!
+//! Sends a single system + user prompt to the provider configured under `llm`
+//! in `.janus/config.yaml` and returns the text of the reply. Anthropic, the
+//! OpenAI Chat Completions API (and compatible servers), and Ollama are
+//! supported.
+
+use std::time::Duration;
+
+use serde_json::{Value, json};
+
+use crate::config::{Config, LlmConfig, LlmProvider};
+use crate::error::{JanusError, Result};
+use crate::remote::http::configure_reqwest;
+
+/// Version header required by the Anthropic Messages API
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// Send `prompt` to the configured provider and return the reply text.
+pub async fn complete(config: &Config, system: &str, prompt: &str) -> Result<String> {
+    let llm = config.llm.as_ref().ok_or_else(|| {
+        JanusError::Config(
+            "no LLM provider configured. Add an `llm` section with `provider` and `model` to .janus/config.yaml"
+                .to_string(),
+        )
+    })?;
+
+    let builder = reqwest::Client::builder()
+        .timeout(Duration::from_secs(120))
+        .connect_timeout(Duration::from_secs(30));
+    let client = configure_reqwest(builder, &config.network)?.build()?;
+
+    let (url, body) = request_body(llm, system, prompt);
+    let mut request = client.post(&url).json(&body);
+
+    if let Some(key) = api_key(llm)? {
+        request = match llm.provider {
+            LlmProvider::Anthropic => request
+                .header("x-api-key", key)
+                .header("anthropic-version", ANTHROPIC_VERSION),
+            LlmProvider::Openai | LlmProvider::Ollama => request.bearer_auth(key),
+        };
+    }
+
+    let response = request.send().await?;
+    let status = response.status();
+    let text = response.text().await?;
+    // Error responses are not always JSON (e.g. from a proxy)
+    let reply: Value = serde_json::from_str(&text).unwrap_or(Value::String(text));
+    if !status.is_success() {
+        let message = reply
+            .pointer("/error/message")
+            .or_else(|| reply.get("error"))
+            .or(Some(&reply))
+            .and_then(Value::as_str)
+            .unwrap_or("request failed");
+        return Err(JanusError::Api(format!(
+            "{} returned {status}: {message}",
+            llm.provider
+        )));
+    }
+
+    reply_text(llm.provider, &reply).ok_or_else(|| {
+        JanusError::Api(format!(
+            "unexpected response from {}: {reply}",
+            llm.provider
+        ))
+    })
+}
+
+/// Read the API key from the environment, if the provider needs one.
+fn api_key(llm: &LlmConfig) -> Result<Option<String>> {
+    let Some(var) = llm.api_key_env() else {
+        return Ok(None);
+    };
+    match std::env::var(var) {
+        Ok(key) if !key.trim().is_empty() => Ok(Some(key)),
+        _ => Err(JanusError::Config(format!(
+            "{var} is not set; export the {} API key or set llm.api_key_env",
+            llm.provider
+        ))),
+    }
+}
+
+/// Endpoint URL and JSON body for a single-turn request.
+fn request_body(llm: &LlmConfig, system: &str, prompt: &str) -> (String, Value) {
+    let base = llm.base_url();
+    match llm.provider {
+        LlmProvider::Anthropic => (
+            format!("{base}/v1/messages"),
+            json!({
+                "model": llm.model,
+                "max_tokens": llm.max_tokens,
+                "system": system,
+                "messages": [{"role": "user", "content": prompt}],
+            }),
+        ),
+        LlmProvider::Openai => (
+            format!("{base}/v1/chat/completions"),
+            json!({
+                "model": llm.model,
+                "max_tokens": llm.max_tokens,
+                "messages": [
+                    {"role": "system", "content": system},
+                    {"role": "user", "content": prompt},
+                ],
+            }),
+        ),
+        LlmProvider::Ollama => (
+            format!("{base}/api/chat"),
+            json!({
+                "model": llm.model,
+                "stream": false,
+                "options": {"num_predict": llm.max_tokens},
+                "messages": [
+                    {"role": "system", "content": system},
+                    {"role": "user", "content": prompt},
+                ],
+            }),
+        ),
+    }
+}
+
+/// Extract the reply text from a provider response.
+fn reply_text(provider: LlmProvider, reply: &Value) -> Option<String> {
+    let text = match provider {
+        LlmProvider::Anthropic => reply
+            .get("content")?
+            .as_array()?
+            .iter()
+            .filter(|block| block.get("type").and_then(Value::as_str) == Some("text"))
+            .filter_map(|block| block.get("text").and_then(Value::as_str))
+            .collect::<Vec<_>>()
+            .join(""),
+        LlmProvider::Openai => reply
+            .pointer("/choices/0/message/content")?
+            .as_str()?
+            .to_string(),
+        LlmProvider::Ollama => reply.pointer("/message/content")?.as_str()?.to_string(),
+    };
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn llm(provider: LlmProvider) -> LlmConfig {
+        LlmConfig {
+            provider,
+            model: "test-model".to_string(),
+            base_url: None,
+            api_key_env: None,
+            max_tokens: 512,
+        }
+    }
+
+    #[test]
+    fn test_request_body() {
+        let (url, body) = request_body(&llm(LlmProvider::Anthropic), "sys", "hi");
+        assert_eq!(url, "https://api.anthropic.com/v1/messages");
+        assert_eq!(body["system"], "sys");
+        assert_eq!(body["messages"][0]["content"], "hi");
+
+        let mut openai = llm(LlmProvider::Openai);
+        openai.base_url = Some("http://localhost:8080/".to_string());
+        let (url, body) = request_body(&openai, "sys", "hi");
+        assert_eq!(url, "http://localhost:8080/v1/chat/completions");
+        assert_eq!(body["messages"][0]["role"], "system");
+    }
+
+    #[test]
+    fn test_reply_text() {
+        let reply = json!({"content": [{"type": "text", "text": " Summary. "}]});
+        assert_eq!(
+            reply_text(LlmProvider::Anthropic, &reply).as_deref(),
+            Some("Summary.")
+        );
+
+        let reply = json!({"choices": [{"message": {"content": "Done"}}]});
+        assert_eq!(
+            reply_text(LlmProvider::Openai, &reply).as_deref(),
+            Some("Done")
+        );
+
+        let reply = json!({"message": {"content": ""}});
+        assert_eq!(reply_text(LlmProvider::Ollama, &reply), None);
+    }
+}
@@ -1,14 +1,53 @@
 use clap::Parser;
 use std::process::ExitCode;
 
-use janus::cli::Cli;
+use janus::cli::{Cli, expand_title_flag};
 
 #[tokio::main]
 async fn main() -> ExitCode {
-    let cli = Cli::parse();
+    let args = expand_title_flag(std::env::args_os());
+    let cli = match Cli::try_parse_from(&args) {
+        Ok(cli) => cli,
+        Err(e) if e.use_stderr() && args.iter().any(|arg| arg == "--json-errors") => {
+            let rendered = e.to_string();
+            let message = rendered.lines().next().unwrap_or_default();
+            let message = message.strip_prefix("error: ").unwrap_or(message);
+            eprintln!(
+                "{}",
+                serde_json::json!({ "error": { "code": "USAGE", "message": message } })
+            );
+            return ExitCode::from(2);
+        }
+        Err(e) => e.exit(),
+    };
+    if cli.read_only {
+        janus::read_only::enable();
+    }
+    janus::display::set_color_choice(cli.color);
+    if cli.no_pager {
+        janus::display::disable_pager();
+    }
 
-    match cli.command.run().await {
+    // Undo any multi-file operation a previous run died in the middle of
+    match janus::fs::recover_transactions() {
+        Ok(labels) => {
+            for label in labels {
+                eprintln!("Rolled back interrupted operation: {label}");
+            }
+        }
+        Err(e) => eprintln!("Warning: failed to roll back interrupted operation: {e}"),
+    }
+
+    let json_errors = cli.json_errors;
+    // Commit whatever the command changed, even if it failed partway
+    let result = janus::fs::with_auto_commit(cli.command.run()).await;
+
+    match result {
         Ok(_) => ExitCode::SUCCESS,
+        Err(e) if json_errors => {
+            eprintln!("{}", e.to_json());
+            ExitCode::FAILURE
+        }
         Err(e) => {
             eprintln!("{e}");
             ExitCode::FAILURE
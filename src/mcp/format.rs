@@ -232,6 +232,12 @@ pub fn format_ticket_as_markdown(
             .join(", ");
         output.push_str(&format!("| Dependencies | {deps_str} |\n"));
     }
+    if !metadata.external_deps.is_empty() {
+        output.push_str(&format!(
+            "| External Dependencies | {} |\n",
+            metadata.external_deps.join(", ")
+        ));
+    }
     if !metadata.links.is_empty() {
         let links_str = metadata
             .links
@@ -723,3 +729,55 @@ pub fn format_next_work_as_markdown(
 
     output
 }
+
+/// Format the kanban board as markdown: a count per column followed by each
+/// column's tickets, highest priority first.
+///
+/// At most `limit_per_column` tickets are listed per column; the counts always
+/// cover every ticket.
+pub fn format_board_summary_as_markdown(
+    columns: &[(&str, Vec<&TicketMetadata>)],
+    limit_per_column: usize,
+) -> String {
+    let mut output = String::new();
+    output.push_str("# Board Summary\n\n");
+
+    output.push_str("| Column | Count |\n");
+    output.push_str("|--------|-------|\n");
+    for (name, tickets) in columns {
+        output.push_str(&format!("| {name} | {} |\n", tickets.len()));
+    }
+    let total: usize = columns.iter().map(|(_, tickets)| tickets.len()).sum();
+    output.push_str(&format!("\n**Total:** {total} tickets\n"));
+
+    for (name, tickets) in columns {
+        output.push_str(&format!("\n## {name} ({})\n\n", tickets.len()));
+        if tickets.is_empty() {
+            output.push_str("No tickets.\n");
+            continue;
+        }
+
+        let mut sorted = tickets.clone();
+        sorted.sort_by(|a, b| {
+            a.priority_num()
+                .cmp(&b.priority_num())
+                .then_with(|| format_ticket_id(a).cmp(format_ticket_id(b)))
+        });
+        for ticket in sorted.iter().take(limit_per_column) {
+            output.push_str(&format!(
+                "- **{}**: {} [{}]\n",
+                format_ticket_id(ticket),
+                format_ticket_title(ticket),
+                format_ticket_priority(ticket)
+            ));
+        }
+        if sorted.len() > limit_per_column {
+            output.push_str(&format!(
+                "- ...and {} more\n",
+                sorted.len() - limit_per_column
+            ));
+        }
+    }
+
+    output
+}
@@ -8,6 +8,7 @@
 //! - `mod.rs` - Server setup and initialization
 //! - `tools.rs` - Tool implementations (13 tools for ticket/plan operations)
 //! - `resources.rs` - Resource implementations
+//! - `prompts.rs` - Prompt templates for common workflows
 //! - `types.rs` - MCP-specific types
 //! - `format.rs` - Centralized ticket and plan formatting utilities
 //!
@@ -35,8 +36,10 @@
 //! | `get_plan_status` | Get plan progress information |
 //! | `get_children` | Get tickets spawned from a parent |
 //! | `get_next_available_ticket` | Query the backlog for the next ticket(s) to work on |
+//! | `board_summary` | Get ticket counts and lists per board column |
 
 pub mod format;
+pub mod prompts;
 pub mod requests;
 pub mod resources;
 pub mod tools;
@@ -46,15 +49,17 @@ use rmcp::{
     RoleServer, ServerHandler, ServiceExt,
     handler::server::tool::ToolCallContext,
     model::{
-        CallToolRequestParams, CallToolResult, ErrorData, ListResourceTemplatesResult,
-        ListResourcesResult, ListToolsResult, PaginatedRequestParams, ProtocolVersion,
-        ReadResourceRequestParams, ReadResourceResult, ServerCapabilities, ServerInfo,
+        CallToolRequestParams, CallToolResult, ErrorData, GetPromptRequestParams, GetPromptResult,
+        ListPromptsResult, ListResourceTemplatesResult, ListResourcesResult, ListToolsResult,
+        PaginatedRequestParams, ProtocolVersion, ReadResourceRequestParams, ReadResourceResult,
+        ServerCapabilities, ServerInfo,
     },
     service::RequestContext,
     transport::stdio,
 };
 
 use crate::error::Result;
+use prompts::{PromptError, get_prompt, list_all_prompts};
 use resources::{ResourceError, list_all_resource_templates, list_all_resources, read_resource};
 use tools::JanusTools;
 use types::{SERVER_NAME, SERVER_VERSION};
@@ -71,6 +76,7 @@ impl ServerHandler for JanusTools {
             capabilities: ServerCapabilities::builder()
                 .enable_tools()
                 .enable_resources()
+                .enable_prompts()
                 .build(),
             server_info: rmcp::model::Implementation {
                 name: SERVER_NAME.to_string(),
@@ -105,7 +111,8 @@ impl ServerHandler for JanusTools {
         context: RequestContext<RoleServer>,
     ) -> std::result::Result<CallToolResult, ErrorData> {
         let tcc = ToolCallContext::new(self, request, context);
-        self.router().call(tcc).await
+        // One auto-commit per tool call rather than one for the whole session
+        crate::fs::with_auto_commit(self.router().call(tcc)).await
     }
 
     /// List available resources.
@@ -156,6 +163,38 @@ impl ServerHandler for JanusTools {
             }),
         }
     }
+
+    /// List available prompts.
+    async fn list_prompts(
+        &self,
+        _pagination: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> std::result::Result<ListPromptsResult, ErrorData> {
+        Ok(list_all_prompts())
+    }
+
+    /// Render a prompt with the current ticket data.
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> std::result::Result<GetPromptResult, ErrorData> {
+        match get_prompt(&request.name, request.arguments.as_ref()).await {
+            Ok(result) => Ok(result),
+            Err(e @ (PromptError::NotFound(_) | PromptError::InvalidArguments(_))) => {
+                Err(ErrorData {
+                    code: rmcp::model::ErrorCode::INVALID_PARAMS,
+                    message: std::borrow::Cow::Owned(e.to_string()),
+                    data: None,
+                })
+            }
+            Err(e @ PromptError::Internal(_)) => Err(ErrorData {
+                code: rmcp::model::ErrorCode::INTERNAL_ERROR,
+                message: std::borrow::Cow::Owned(e.to_string()),
+                data: None,
+            }),
+        }
+    }
 }
 
 /// Start the MCP server with STDIO transport.
@@ -245,8 +284,8 @@ mod tests {
     fn test_tools_router_has_tools() {
         let server = JanusTools::new();
         let tools = server.router().list_all();
-        // We should have 29 tools (20 ticket/plan/doc tools + 9 objective tools)
-        assert_eq!(tools.len(), 29);
+        // We should have 33 tools (24 ticket/plan/doc tools + 9 objective tools)
+        assert_eq!(tools.len(), 33);
 
         // Verify tool names
         let tool_names: Vec<&str> = tools.iter().map(|t| t.name.as_ref()).collect();
@@ -254,6 +293,9 @@ mod tests {
         assert!(tool_names.contains(&"spawn_subtask"));
         assert!(tool_names.contains(&"update_status"));
         assert!(tool_names.contains(&"add_note"));
+        assert!(tool_names.contains(&"get_acceptance_criteria"));
+        assert!(tool_names.contains(&"set_acceptance_criteria"));
+        assert!(tool_names.contains(&"check_criterion"));
         assert!(tool_names.contains(&"list_tickets"));
         assert!(tool_names.contains(&"show_ticket"));
         assert!(tool_names.contains(&"add_dependency"));
@@ -269,6 +311,7 @@ mod tests {
         assert!(tool_names.contains(&"show_plan_details"));
         assert!(tool_names.contains(&"get_children"));
         assert!(tool_names.contains(&"get_next_available_ticket"));
+        assert!(tool_names.contains(&"board_summary"));
         assert!(tool_names.contains(&"semantic_search"));
         assert!(tool_names.contains(&"create_objective"));
         assert!(tool_names.contains(&"show_objective"));
@@ -0,0 +1,352 @@
+//! MCP prompt templates for Janus.
+//!
+//! Prompts give MCP clients a consistent starting point for common Janus
+//! workflows. Each one bundles the repository's conventions (statuses, types,
+//! priorities, labels in use, knowledge documents) with the current ticket
+//! data the task needs, and names the tools to finish the task with.
+//!
+//! ## Available Prompts
+//!
+//! | Prompt | Arguments | Description |
+//! |--------|-----------|-------------|
+//! | `triage_ticket` | `id` | Classify, prioritize, and size a ticket |
+//! | `decompose_into_plan` | `id` | Break a ticket into subtasks and a plan |
+//! | `write_standup` | `since` (optional) | Summarize recent progress |
+
+use std::collections::{BTreeMap, HashMap};
+
+use jiff::Timestamp;
+use rmcp::model::{
+    GetPromptResult, ListPromptsResult, Prompt, PromptArgument, PromptMessage, PromptMessageRole,
+};
+use serde_json::{Map, Value};
+
+use crate::commands::plan::PLAN_FORMAT_SPECIFICATION;
+use crate::doc::get_all_docs_from_disk;
+use crate::status::has_unsatisfied_dep;
+use crate::ticket::{Ticket, get_all_tickets_with_map};
+use crate::types::{TicketMetadata, TicketStatus, VALID_SIZES, VALID_STATUSES, VALID_TYPES};
+use crate::utils::parse_since;
+
+use super::format::{format_ticket_id, format_ticket_table_row};
+
+/// Default look-back window of `write_standup`
+const DEFAULT_STANDUP_SINCE: &str = "1d";
+
+// ============================================================================
+// Prompt Definitions
+// ============================================================================
+
+/// Get all prompt definitions for the MCP server.
+pub fn list_all_prompts() -> ListPromptsResult {
+    let prompts = vec![
+        Prompt::new(
+            "triage_ticket",
+            Some("Classify, prioritize, and size a ticket, and spot duplicates"),
+            Some(vec![argument("id", "ID of the ticket to triage", true)]),
+        ),
+        Prompt::new(
+            "decompose_into_plan",
+            Some("Break a ticket into subtasks with dependencies and draft a plan"),
+            Some(vec![argument("id", "ID of the ticket to decompose", true)]),
+        ),
+        Prompt::new(
+            "write_standup",
+            Some("Write a standup update from recently closed, in-progress, and blocked tickets"),
+            Some(vec![argument(
+                "since",
+                "How far back to look: a date, a timestamp, or a duration like 1d (default: 1d)",
+                false,
+            )]),
+        ),
+    ];
+    ListPromptsResult::with_all_items(prompts)
+}
+
+fn argument(name: &str, description: &str, required: bool) -> PromptArgument {
+    PromptArgument {
+        name: name.to_string(),
+        title: None,
+        description: Some(description.to_string()),
+        required: Some(required),
+    }
+}
+
+// ============================================================================
+// Prompt Handlers
+// ============================================================================
+
+/// Render a prompt with the given arguments.
+pub async fn get_prompt(
+    name: &str,
+    arguments: Option<&Map<String, Value>>,
+) -> Result<GetPromptResult, PromptError> {
+    let arg = |key: &str| {
+        arguments
+            .and_then(|args| args.get(key))
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+    };
+    let required = |key: &str| {
+        arg(key).ok_or_else(|| {
+            PromptError::InvalidArguments(format!("prompt '{name}' requires the '{key}' argument"))
+        })
+    };
+
+    let (description, text) = match name {
+        "triage_ticket" => {
+            let id = required("id")?;
+            (format!("Triage ticket {id}"), triage_ticket(id).await?)
+        }
+        "decompose_into_plan" => {
+            let id = required("id")?;
+            (
+                format!("Decompose ticket {id} into a plan"),
+                decompose_into_plan(id).await?,
+            )
+        }
+        "write_standup" => {
+            let since = arg("since").unwrap_or(DEFAULT_STANDUP_SINCE);
+            let since_ts =
+                parse_since(since).map_err(|e| PromptError::InvalidArguments(e.to_string()))?;
+            (
+                format!("Standup update since {since}"),
+                write_standup(since_ts).await?,
+            )
+        }
+        _ => return Err(PromptError::NotFound(name.to_string())),
+    };
+
+    Ok(GetPromptResult {
+        description: Some(description),
+        messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+    })
+}
+
+/// Error type for prompt operations
+#[derive(Debug)]
+pub enum PromptError {
+    /// No prompt with this name
+    NotFound(String),
+    /// Missing or malformed arguments
+    InvalidArguments(String),
+    /// Internal error
+    Internal(String),
+}
+
+impl std::fmt::Display for PromptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PromptError::NotFound(name) => write!(f, "Prompt not found: {name}"),
+            PromptError::InvalidArguments(msg) => write!(f, "Invalid arguments: {msg}"),
+            PromptError::Internal(msg) => write!(f, "Internal error: {msg}"),
+        }
+    }
+}
+
+// ============================================================================
+// Individual Prompts
+// ============================================================================
+
+async fn triage_ticket(id: &str) -> Result<String, PromptError> {
+    let (ticket, content) = find_ticket(id).await?;
+    let (tickets, _) = load_tickets().await?;
+
+    let mut text = format!(
+        "Triage Janus ticket {}. Decide its type, priority, size, and labels, \
+         and check whether it duplicates an existing open ticket.\n\n",
+        ticket.id
+    );
+    text.push_str(&conventions(&tickets));
+    text.push_str(&ticket_section(&ticket.id, &content));
+
+    let open: Vec<&TicketMetadata> = tickets
+        .iter()
+        .filter(|t| !t.status.unwrap_or_default().is_terminal())
+        .filter(|t| t.id.as_deref() != Some(ticket.id.as_str()))
+        .collect();
+    text.push_str(&ticket_table("Other open tickets", &open));
+
+    text.push_str(
+        "## Steps\n\n\
+         1. If the ticket duplicates one above, say which, add a note linking the two with add_note, and stop.\n\
+         2. Otherwise pick the type, priority, and size that fit and explain each choice in one line.\n\
+         3. Add labels with add_label, preferring labels already in use.\n\
+         4. If the ticket has no acceptance criteria, propose some and record them with set_acceptance_criteria.\n\
+         5. Record the chosen type, priority, and size with add_note (they can be applied with `janus set`), and move the ticket to `next` with update_status if it should be picked up soon.\n",
+    );
+    Ok(text)
+}
+
+async fn decompose_into_plan(id: &str) -> Result<String, PromptError> {
+    let (ticket, content) = find_ticket(id).await?;
+    let (tickets, _) = load_tickets().await?;
+
+    let mut text = format!(
+        "Break Janus ticket {} into subtasks small enough to finish in a day or two, \
+         ordered by their dependencies, and draft a plan for them.\n\n",
+        ticket.id
+    );
+    text.push_str(&conventions(&tickets));
+    text.push_str(&ticket_section(&ticket.id, &content));
+
+    let children: Vec<&TicketMetadata> = tickets
+        .iter()
+        .filter(|t| t.spawned_from.as_deref() == Some(ticket.id.as_str()))
+        .collect();
+    text.push_str(&ticket_table("Existing subtasks", &children));
+
+    text.push_str(&format!(
+        "## Plan format\n\n{}\n\n",
+        PLAN_FORMAT_SPECIFICATION.trim()
+    ));
+    text.push_str(
+        "## Steps\n\n\
+         1. List the subtasks, grouped into phases when the work has natural stages. Do not repeat existing subtasks.\n\
+         2. Create each subtask with spawn_subtask, giving it a description and acceptance criteria (set_acceptance_criteria).\n\
+         3. Record the order between subtasks with add_dependency.\n\
+         4. Add the subtasks to an existing plan with add_ticket_to_plan, or write a plan in the format above for `janus plan import`.\n",
+    );
+    Ok(text)
+}
+
+async fn write_standup(since: Timestamp) -> Result<String, PromptError> {
+    let (tickets, ticket_map) = load_tickets().await?;
+
+    let closed: Vec<&TicketMetadata> = tickets
+        .iter()
+        .filter(|t| t.status.unwrap_or_default().is_terminal())
+        .filter(|t| t.status_since().is_some_and(|at| at >= since))
+        .collect();
+    let in_progress: Vec<&TicketMetadata> = tickets
+        .iter()
+        .filter(|t| t.status == Some(TicketStatus::InProgress))
+        .collect();
+    let blocked: Vec<&TicketMetadata> = tickets
+        .iter()
+        .filter(|t| !t.status.unwrap_or_default().is_terminal())
+        .filter(|t| has_unsatisfied_dep(t, &ticket_map))
+        .collect();
+
+    let mut text = format!(
+        "Write a short standup update for the work tracked in Janus since {}. \
+         Use three headings: Done, In progress, and Blocked. Mention ticket IDs, \
+         group related tickets, and keep each bullet to one line. For blocked \
+         tickets, name what they are waiting on.\n\n",
+        since.strftime("%Y-%m-%d %H:%M UTC")
+    );
+    text.push_str(&ticket_table("Closed since then", &closed));
+    text.push_str(&ticket_table("In progress", &in_progress));
+    text.push_str(&ticket_table("Blocked", &blocked));
+    text.push_str("Use show_ticket for notes and details on any ticket before summarizing it.\n");
+    Ok(text)
+}
+
+// ============================================================================
+// Shared Sections
+// ============================================================================
+
+async fn find_ticket(id: &str) -> Result<(Ticket, String), PromptError> {
+    let ticket = Ticket::find(id)
+        .await
+        .map_err(|e| PromptError::InvalidArguments(format!("Ticket '{id}' not found: {e}")))?;
+    let content = ticket
+        .read_content()
+        .map_err(|e| PromptError::Internal(format!("Failed to read ticket: {e}")))?;
+    Ok((ticket, content))
+}
+
+async fn load_tickets()
+-> Result<(Vec<TicketMetadata>, HashMap<String, TicketMetadata>), PromptError> {
+    get_all_tickets_with_map()
+        .await
+        .map_err(|e| PromptError::Internal(format!("Failed to load tickets: {e}")))
+}
+
+/// The repository's vocabulary: valid field values, the labels already in
+/// use, and the knowledge documents worth reading first.
+fn conventions(tickets: &[TicketMetadata]) -> String {
+    let mut text = String::from("## Janus conventions\n\n");
+    text.push_str(&format!("- Statuses: {}\n", VALID_STATUSES.join(", ")));
+    text.push_str(&format!("- Types: {}\n", VALID_TYPES.join(", ")));
+    text.push_str("- Priorities: 0 (critical) to 4 (lowest); 2 is the default\n");
+    text.push_str(&format!("- Sizes: {}\n", VALID_SIZES.join(", ")));
+
+    let mut labels: BTreeMap<&str, usize> = BTreeMap::new();
+    for label in tickets.iter().flat_map(|t| &t.labels) {
+        *labels.entry(label.as_str()).or_default() += 1;
+    }
+    if labels.is_empty() {
+        text.push_str("- Labels in use: none yet\n");
+    } else {
+        let labels: Vec<String> = labels
+            .iter()
+            .map(|(label, count)| format!("{label} ({count})"))
+            .collect();
+        text.push_str(&format!("- Labels in use: {}\n", labels.join(", ")));
+    }
+
+    let docs = get_all_docs_from_disk().into_docs();
+    if !docs.is_empty() {
+        text.push_str("- Knowledge documents (read with doc_show):\n");
+        for doc in &docs {
+            let label = doc.label().unwrap_or("(no label)");
+            match doc.description.as_deref().or(doc.title()) {
+                Some(about) => text.push_str(&format!("  - {label}: {about}\n")),
+                None => text.push_str(&format!("  - {label}\n")),
+            }
+        }
+    }
+    text.push('\n');
+    text
+}
+
+fn ticket_section(id: &str, content: &str) -> String {
+    format!("## Ticket {id}\n\n```markdown\n{}\n```\n\n", content.trim())
+}
+
+fn ticket_table(heading: &str, tickets: &[&TicketMetadata]) -> String {
+    if tickets.is_empty() {
+        return format!("## {heading}\n\nNone.\n\n");
+    }
+    let mut sorted = tickets.to_vec();
+    sorted.sort_by_key(|t| format_ticket_id(t).to_string());
+
+    let mut text = format!("## {heading}\n\n");
+    text.push_str("| ID | Title | Status | Type | Priority | Size | Labels |\n");
+    text.push_str("|----|-------|--------|------|----------|------|--------|\n");
+    for ticket in sorted {
+        text.push_str(&format_ticket_table_row(ticket));
+    }
+    text.push('\n');
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TicketId;
+
+    #[test]
+    fn test_list_all_prompts() {
+        let prompts = list_all_prompts().prompts;
+        let names: Vec<&str> = prompts.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["triage_ticket", "decompose_into_plan", "write_standup"]
+        );
+    }
+
+    #[test]
+    fn test_conventions_counts_labels() {
+        let ticket = |id: &str, labels: &[&str]| TicketMetadata {
+            id: Some(TicketId::new_unchecked(id)),
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+            ..Default::default()
+        };
+        let text = conventions(&[ticket("j-1", &["ui", "auth"]), ticket("j-2", &["auth"])]);
+        assert!(text.contains("- Labels in use: auth (2), ui (1)\n"));
+        assert!(text.contains("- Types: bug, feature, task, epic, chore\n"));
+    }
+}
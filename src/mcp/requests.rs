@@ -154,6 +154,12 @@ pub struct UpdateStatusRequest {
         description = "Optional completion summary (max 40000 chars, recommended when closing tickets)"
     )]
     pub summary: Option<String>,
+
+    /// Confirm a status transition that the project config marks as requiring confirmation
+    #[schemars(
+        description = "If true, confirm a status transition that project config marks as requiring confirmation"
+    )]
+    pub confirm: Option<bool>,
 }
 
 impl UpdateStatusRequest {
@@ -351,6 +357,19 @@ pub struct GetNextAvailableTicketRequest {
     pub limit: Option<usize>,
 }
 
+/// Request parameters for the board summary
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Default)]
+pub struct BoardSummaryRequest {
+    /// Include the archived column, which the board hides by default (default: false)
+    #[schemars(description = "Include the archived column, which the board hides by default")]
+    pub include_archived: Option<bool>,
+    /// Maximum number of tickets listed per column (default: 20)
+    #[schemars(
+        description = "Maximum number of tickets to list per column. Counts always cover every ticket."
+    )]
+    pub limit_per_column: Option<usize>,
+}
+
 /// Request parameters for semantic search
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct SemanticSearchRequest {
@@ -707,6 +726,67 @@ impl AddObjectiveCriterionRequest {
     }
 }
 
+/// Request parameters for reading a ticket's acceptance criteria
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct GetAcceptanceCriteriaRequest {
+    /// Ticket ID (can be partial)
+    #[schemars(description = "ID of the ticket whose acceptance criteria to read")]
+    pub id: String,
+}
+
+/// Request parameters for replacing a ticket's acceptance criteria
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct SetAcceptanceCriteriaRequest {
+    /// Ticket ID (can be partial)
+    #[schemars(description = "ID of the ticket whose acceptance criteria to replace")]
+    pub id: String,
+
+    /// The new criteria, in order
+    #[schemars(
+        description = "The complete list of acceptance criteria, replacing the existing section. Start an item with '[x] ' to mark it met. An empty list removes the section."
+    )]
+    pub criteria: Vec<String>,
+}
+
+impl SetAcceptanceCriteriaRequest {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("Ticket ID cannot be empty".to_string());
+        }
+        for criterion in &self.criteria {
+            if criterion.trim().is_empty() {
+                return Err("Criterion text cannot be empty".to_string());
+            }
+            if criterion.len() > MAX_DESCRIPTION_LENGTH {
+                return Err(format!(
+                    "Criterion text is too long ({} chars, max {})",
+                    criterion.len(),
+                    MAX_DESCRIPTION_LENGTH
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Request parameters for checking off an acceptance criterion
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct CheckCriterionRequest {
+    /// Ticket ID (can be partial)
+    #[schemars(description = "ID of the ticket the criterion belongs to")]
+    pub id: String,
+
+    /// 1-based position of the criterion
+    #[schemars(
+        description = "Position of the criterion, starting at 1, as numbered by get_acceptance_criteria"
+    )]
+    pub index: usize,
+
+    /// Whether the criterion is met (default: true)
+    #[schemars(description = "Set to false to uncheck the criterion (default: true)")]
+    pub checked: Option<bool>,
+}
+
 /// Request parameters for adding a label to a ticket
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct AddLabelRequest {
@@ -946,6 +1026,7 @@ mod tests {
             id: "j-a1b2".to_string(),
             status: "complete".to_string(),
             summary: Some("Completed successfully".to_string()),
+            confirm: None,
         };
         assert!(request.validate().is_ok());
     }
@@ -967,4 +1048,19 @@ mod tests {
         };
         assert!(request.validate().is_err());
     }
+
+    #[test]
+    fn test_set_acceptance_criteria_request_validate() {
+        let request = SetAcceptanceCriteriaRequest {
+            id: "j-a1b2".to_string(),
+            criteria: vec!["Login works".to_string(), "[x] Docs updated".to_string()],
+        };
+        assert!(request.validate().is_ok());
+
+        let request = SetAcceptanceCriteriaRequest {
+            id: "j-a1b2".to_string(),
+            criteria: vec!["  ".to_string()],
+        };
+        assert!(request.validate().is_err());
+    }
 }
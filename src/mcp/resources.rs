@@ -30,10 +30,11 @@ use serde_json::json;
 
 use crate::commands::graph::{RelationshipFilter, build_edges, generate_dot};
 use crate::commands::{get_next_items_phased, get_next_items_simple, ticket_to_json};
+use crate::config::Config;
 use crate::mcp::format::format_plan_details_as_markdown;
 
 use crate::plan::{Plan, compute_all_phase_statuses, compute_plan_status};
-use crate::status::{all_deps_satisfied, has_unsatisfied_dep, is_dependency_satisfied};
+use crate::status::{has_unsatisfied_dep, is_dependency_satisfied, is_ready};
 use crate::ticket::{Ticket, build_ticket_map, get_all_tickets_with_map};
 use crate::types::TicketStatus;
 
@@ -297,22 +298,17 @@ async fn read_ticket(id: &str) -> Result<ReadResourceResult, ResourceError> {
     })
 }
 
-/// Read list of ready tickets (new/next with all deps complete)
+/// Read list of ready tickets (as defined by `ready.require`)
 async fn read_ready_tickets() -> Result<ReadResourceResult, ResourceError> {
     let (tickets, ticket_map) = get_all_tickets_with_map()
         .await
         .map_err(|e| ResourceError::Internal(e.to_string()))?;
+    let require = Config::load().map(|c| c.ready.require).unwrap_or_default();
+    let now = jiff::Timestamp::now();
 
     let ready: Vec<serde_json::Value> = tickets
         .iter()
-        .filter(|t| {
-            // Must be new or next status
-            if !matches!(t.status, Some(TicketStatus::New) | Some(TicketStatus::Next)) {
-                return false;
-            }
-            // All deps must be satisfied (terminal status; orphans block)
-            all_deps_satisfied(t, &ticket_map)
-        })
+        .filter(|t| is_ready(t, &ticket_map, &require, now))
         .map(ticket_to_json)
         .collect();
 
@@ -345,7 +341,7 @@ async fn read_blocked_tickets() -> Result<ReadResourceResult, ResourceError> {
                 return false;
             }
             // Must have deps
-            if t.deps.is_empty() {
+            if t.deps.is_empty() && t.external_deps.is_empty() {
                 return false;
             }
             // At least one dep must be unsatisfied
@@ -439,6 +435,8 @@ async fn read_plan(id: &str) -> Result<ReadResourceResult, ResourceError> {
                     "status": ps.status.to_string(),
                     "completed_count": ps.completed_count,
                     "total_count": ps.total_count,
+                    "completed_points": ps.completed_points,
+                    "total_points": ps.total_points,
                 })
             })
             .collect()
@@ -469,6 +467,8 @@ async fn read_plan(id: &str) -> Result<ReadResourceResult, ResourceError> {
         "total_count": plan_status.total_count,
         "progress_percent": plan_status.progress_percent(),
         "progress_string": plan_status.progress_string(),
+        "completed_points": plan_status.completed_points,
+        "total_points": plan_status.total_points,
         "is_phased": metadata.is_phased(),
         "phases": phases_json,
         "tickets": tickets_json,
@@ -12,6 +12,9 @@
 //! | `spawn_subtask` | Create a ticket as a child of another |
 //! | `update_status` | Change a ticket's status |
 //! | `add_note` | Add a timestamped note to a ticket |
+//! | `get_acceptance_criteria` | Read a ticket's acceptance criteria as a checklist |
+//! | `set_acceptance_criteria` | Replace a ticket's acceptance criteria |
+//! | `check_criterion` | Mark one acceptance criterion as met (or not) |
 //! | `list_tickets` | Query tickets with filters |
 //! | `show_ticket` | Get full ticket content |
 //! | `add_dependency` | Add a dependency between tickets |
@@ -21,6 +24,7 @@
 //! | `show_plan_details` | Get full plan details with all sections |
 //! | `get_children` | Get tickets spawned from a parent |
 //! | `get_next_available_ticket` | Query the backlog for the next ticket(s) to work on |
+//! | `board_summary` | Get ticket counts and lists per board column |
 //! | `semantic_search` | Find tickets semantically similar to a query (requires semantic-search config) |
 //! | `doc_list` | List all project knowledge documents |
 //! | `doc_show` | Show a document's content (optionally a line range) |
@@ -43,7 +47,7 @@ use tracing::warn;
 use std::str::FromStr;
 use tokio::time::timeout;
 
-use crate::config::Config;
+use crate::config::{Config, TransitionVia};
 use crate::doc::{Doc, DocMetadata, get_all_docs_from_disk};
 use crate::embedding::model::EMBEDDING_TIMEOUT;
 use crate::events::Actor;
@@ -51,28 +55,30 @@ use crate::graph::check_circular_dependency;
 use crate::next::NextWorkFinder;
 use crate::plan::parser::serialize_plan;
 use crate::plan::{Plan, compute_plan_status};
-use crate::status::is_dependency_satisfied;
+use crate::status::{enforce_transition, is_dependency_satisfied};
 use crate::store::get_or_init_store;
 use crate::ticket::{
-    ArrayField, Ticket, TicketBuilder, build_ticket_map, get_all_tickets_with_map,
+    ArrayField, Criterion, Ticket, TicketBuilder, build_ticket_map, get_all_tickets_with_map,
 };
+use crate::tui::board::model::{COLUMN_NAMES, COLUMNS, DEFAULT_VISIBLE_COLUMNS};
 use crate::types::{TicketMetadata, TicketPriority, TicketSize, TicketStatus, TicketType};
 use crate::utils::iso_date;
 
 use super::format::{
-    build_filter_summary, format_children_as_markdown, format_next_work_as_markdown,
-    format_plan_details_as_markdown, format_plan_status_as_markdown, format_ticket_as_markdown,
-    format_ticket_list_as_markdown,
+    build_filter_summary, format_board_summary_as_markdown, format_children_as_markdown,
+    format_next_work_as_markdown, format_plan_details_as_markdown, format_plan_status_as_markdown,
+    format_ticket_as_markdown, format_ticket_list_as_markdown,
 };
 use super::requests::{
     AddDependencyRequest, AddLabelRequest, AddNoteRequest, AddObjectiveCriterionRequest,
-    AddObjectiveNoteRequest, AddTicketToPlanRequest, CreateObjectiveRequest, CreateTicketRequest,
-    DeleteObjectiveRequest, DocListRequest, DocSearchRequest, DocSetRequest, DocShowRequest,
+    AddObjectiveNoteRequest, AddTicketToPlanRequest, BoardSummaryRequest, CheckCriterionRequest,
+    CreateObjectiveRequest, CreateTicketRequest, DeleteObjectiveRequest, DocListRequest,
+    DocSearchRequest, DocSetRequest, DocShowRequest, GetAcceptanceCriteriaRequest,
     GetChildrenRequest, GetNextAvailableTicketRequest, GetPlanStatusRequest, ListObjectivesRequest,
     ListTicketsRequest, ObjectiveRefAddRequest, ObjectiveRefRemoveRequest, ObjectiveRefResetRequest,
     RemoveDependencyRequest, RemoveLabelRequest, SemanticSearchRequest,
-    ShowObjectiveRequest, ShowPlanDetailsRequest, ShowTicketRequest, SpawnSubtaskRequest,
-    UpdateStatusRequest,
+    SetAcceptanceCriteriaRequest, ShowObjectiveRequest, ShowPlanDetailsRequest, ShowTicketRequest,
+    SpawnSubtaskRequest, UpdateStatusRequest,
 };
 
 /// Helper to create ToolAnnotations with all fields set
@@ -134,13 +140,28 @@ macro_rules! register_tool {
                 $name
             ),
         };
+        let annotations: rmcp::model::ToolAnnotations = $annotations;
+        // Tools not annotated as read-only are refused in read-only mode
+        let mutating = annotations.read_only_hint != Some(true);
         let tool = Tool::new($name.to_string(), $desc.to_string(), Arc::new(schema_obj))
-            .annotate($annotations);
+            .annotate(annotations);
         let route =
             ToolRoute::new_dyn(
                 tool,
-                |ctx: rmcp::handler::server::tool::ToolCallContext<'_, JanusTools>| {
+                move |ctx: rmcp::handler::server::tool::ToolCallContext<'_, JanusTools>| {
                     Box::pin(async move {
+                        if mutating
+                            && let Err(e) = crate::read_only::ensure_writable(|| {
+                                format!("run the '{}' tool", $name)
+                            })
+                        {
+                            return Ok(rmcp::model::CallToolResult {
+                                content: vec![rmcp::model::Content::text(e.to_string())],
+                                structured_content: None,
+                                is_error: Some(true),
+                                meta: None,
+                            });
+                        }
                         let this = ctx.service;
                         let args = if $optional {
                             ctx.arguments.unwrap_or_default()
@@ -284,6 +305,36 @@ impl JanusTools {
             tool_annotations(false, false, false, false)
         );
 
+        register_tool!(
+            router,
+            "get_acceptance_criteria",
+            "Read a ticket's acceptance criteria as a numbered checklist showing which are met. Use the numbers with check_criterion.",
+            GetAcceptanceCriteriaRequest,
+            get_acceptance_criteria_impl,
+            false,
+            tool_annotations(true, false, true, false)
+        );
+
+        register_tool!(
+            router,
+            "set_acceptance_criteria",
+            "Replace a ticket's '## Acceptance Criteria' section with the given criteria, written as a task list. Start an item with '[x] ' to mark it met. Input is sanitized: newlines are collapsed, markdown headings are stripped, and leading bullet markers are removed.",
+            SetAcceptanceCriteriaRequest,
+            set_acceptance_criteria_impl,
+            false,
+            tool_annotations(false, true, true, false)
+        );
+
+        register_tool!(
+            router,
+            "check_criterion",
+            "Mark one of a ticket's acceptance criteria as met (or, with checked=false, as not met). Other text in the section is left unchanged.",
+            CheckCriterionRequest,
+            check_criterion_impl,
+            false,
+            tool_annotations(false, false, true, false)
+        );
+
         register_tool!(
             router,
             "list_tickets",
@@ -364,6 +415,16 @@ impl JanusTools {
             tool_annotations(true, false, true, false)
         );
 
+        register_tool!(
+            router,
+            "board_summary",
+            "Get an overview of the kanban board: ticket counts and ticket lists per status column (new, next, in progress, complete, cancelled), highest priority first. Use this to reason about overall workload in one call.",
+            BoardSummaryRequest,
+            board_summary_impl,
+            true,
+            tool_annotations(true, false, true, false)
+        );
+
         register_tool!(
             router,
             "semantic_search",
@@ -695,6 +756,22 @@ impl JanusTools {
             )
         })?;
 
+        // Enforce protected transitions from config
+        let current_status = ticket
+            .read()
+            .map_err(|e| e.to_string())?
+            .status
+            .unwrap_or_default();
+        enforce_transition(
+            &ticket.id,
+            current_status,
+            new_status,
+            TransitionVia::Mcp,
+            request.confirm.unwrap_or(false),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
         // Use the domain method with Actor::Mcp to log the event correctly
         ticket
             .update_status_with_actor(new_status, request.summary.as_deref(), Some(Actor::Mcp))
@@ -747,6 +824,100 @@ impl JanusTools {
         Ok(format!("Added note to **{}** at {}", ticket.id, timestamp))
     }
 
+    /// Read a ticket's acceptance criteria.
+    async fn get_acceptance_criteria_impl(
+        &self,
+        Parameters(request): Parameters<GetAcceptanceCriteriaRequest>,
+    ) -> Result<String, String> {
+        let ticket = Ticket::find(&request.id)
+            .await
+            .map_err(|e| format!("Ticket not found: {e}"))?;
+        let criteria = ticket.acceptance_criteria().map_err(|e| e.to_string())?;
+
+        if criteria.is_empty() {
+            return Ok(format!(
+                "Ticket **{}** has no acceptance criteria",
+                ticket.id
+            ));
+        }
+        let met = criteria.iter().filter(|c| c.checked).count();
+        let mut output = format!("# Acceptance Criteria: {}\n\n", ticket.id);
+        for (i, criterion) in criteria.iter().enumerate() {
+            let mark = if criterion.checked { "x" } else { " " };
+            output.push_str(&format!("{}. [{mark}] {}\n", i + 1, criterion.text));
+        }
+        output.push_str(&format!("\n{met} of {} met\n", criteria.len()));
+        Ok(output)
+    }
+
+    /// Replace a ticket's acceptance criteria.
+    async fn set_acceptance_criteria_impl(
+        &self,
+        Parameters(request): Parameters<SetAcceptanceCriteriaRequest>,
+    ) -> Result<String, String> {
+        request.validate()?;
+
+        let ticket = Ticket::find(&request.id)
+            .await
+            .map_err(|e| format!("Ticket not found: {e}"))?;
+        let criteria: Vec<Criterion> = request
+            .criteria
+            .iter()
+            .map(|c| Criterion::from_input(c))
+            .filter(|c| !c.text.is_empty())
+            .collect();
+        ticket
+            .set_acceptance_criteria(&criteria)
+            .map_err(|e| e.to_string())?;
+
+        if let Ok(store) = get_or_init_store().await {
+            store.refresh_ticket_in_store(&ticket.id).await;
+        } else {
+            warn!(
+                "Failed to refresh ticket {} in store - store initialization failed",
+                &ticket.id
+            );
+        }
+
+        Ok(format!(
+            "Set {} acceptance criteria on **{}**",
+            criteria.len(),
+            ticket.id
+        ))
+    }
+
+    /// Mark one acceptance criterion as met or not.
+    async fn check_criterion_impl(
+        &self,
+        Parameters(request): Parameters<CheckCriterionRequest>,
+    ) -> Result<String, String> {
+        let ticket = Ticket::find(&request.id)
+            .await
+            .map_err(|e| format!("Ticket not found: {e}"))?;
+        let criterion = ticket
+            .check_criterion(request.index, request.checked.unwrap_or(true))
+            .map_err(|e| e.to_string())?;
+
+        if let Ok(store) = get_or_init_store().await {
+            store.refresh_ticket_in_store(&ticket.id).await;
+        } else {
+            warn!(
+                "Failed to refresh ticket {} in store - store initialization failed",
+                &ticket.id
+            );
+        }
+
+        let verb = if criterion.checked {
+            "Checked"
+        } else {
+            "Unchecked"
+        };
+        Ok(format!(
+            "{verb} criterion {} on **{}**: {}",
+            request.index, ticket.id, criterion.text
+        ))
+    }
+
     /// List tickets with optional filters.
     async fn list_tickets_impl(
         &self,
@@ -843,7 +1014,9 @@ impl JanusTools {
 
         // Add ready filter
         if request.ready == Some(true) {
-            query_builder = query_builder.with_filter(Box::new(ReadyFilter));
+            let require = Config::load().map(|c| c.ready.require).unwrap_or_default();
+            query_builder = query_builder
+                .with_filter(Box::new(ReadyFilter::new(require, jiff::Timestamp::now())));
         }
 
         // Add blocked filter
@@ -1266,7 +1439,8 @@ impl JanusTools {
             return Ok("All tickets are complete. Nothing to work on.".to_string());
         }
 
-        let finder = NextWorkFinder::new(&ticket_map);
+        let require = Config::load().map(|c| c.ready.require).unwrap_or_default();
+        let finder = NextWorkFinder::new(&ticket_map).with_requirements(require);
         let work_items = finder.get_next_work(limit);
 
         if work_items.is_empty() {
@@ -1276,6 +1450,35 @@ impl JanusTools {
         Ok(format_next_work_as_markdown(&work_items, &ticket_map))
     }
 
+    /// Summarize the tickets in each board column.
+    async fn board_summary_impl(
+        &self,
+        Parameters(request): Parameters<BoardSummaryRequest>,
+    ) -> Result<String, String> {
+        let include_archived = request.include_archived.unwrap_or(false);
+        let limit = request.limit_per_column.unwrap_or(20);
+
+        let (tickets, _) = get_all_tickets_with_map()
+            .await
+            .map_err(|e| format!("failed to load tickets: {e}"))?;
+
+        let columns: Vec<(&str, Vec<&TicketMetadata>)> = COLUMNS
+            .iter()
+            .zip(COLUMN_NAMES)
+            .zip(DEFAULT_VISIBLE_COLUMNS)
+            .filter(|(_, visible)| *visible || include_archived)
+            .map(|((status, name), _)| {
+                let column_tickets = tickets
+                    .iter()
+                    .filter(|t| t.status.unwrap_or_default() == *status)
+                    .collect();
+                (name, column_tickets)
+            })
+            .collect();
+
+        Ok(format_board_summary_as_markdown(&columns, limit))
+    }
+
     /// Find tickets semantically similar to a natural language query.
     async fn semantic_search_impl(
         &self,
@@ -2324,6 +2527,8 @@ mod tests {
             status: TicketStatus::InProgress,
             completed_count: 1,
             total_count: 3,
+            completed_points: 3,
+            total_points: 9,
         };
 
         let output =
@@ -2424,6 +2629,8 @@ mod tests {
             status: TicketStatus::InProgress,
             completed_count: 3,
             total_count: 5,
+            completed_points: 9,
+            total_points: 15,
         };
 
         let output =
@@ -2508,6 +2715,34 @@ mod tests {
         assert!(!output.contains("| ID |"));
     }
 
+    #[test]
+    fn test_format_board_summary_as_markdown() {
+        let urgent = TicketMetadata {
+            id: Some(TicketId::new_unchecked("j-b2c3")),
+            title: Some("Fix crash".to_string()),
+            status: Some(TicketStatus::New),
+            priority: Some(TicketPriority::P0),
+            ..Default::default()
+        };
+        let routine = TicketMetadata {
+            id: Some(TicketId::new_unchecked("j-a1b2")),
+            title: Some("Update docs".to_string()),
+            status: Some(TicketStatus::New),
+            priority: Some(TicketPriority::P3),
+            ..Default::default()
+        };
+        let columns = vec![("NEW", vec![&routine, &urgent]), ("NEXT", vec![])];
+
+        let output = format_board_summary_as_markdown(&columns, 1);
+        assert!(output.contains("| NEW | 2 |"));
+        assert!(output.contains("| NEXT | 0 |"));
+        assert!(output.contains("**Total:** 2 tickets"));
+        assert!(output.contains("- **j-b2c3**: Fix crash [P0]"));
+        assert!(!output.contains("Update docs"));
+        assert!(output.contains("- ...and 1 more"));
+        assert!(output.contains("## NEXT (0)\n\nNo tickets."));
+    }
+
     #[test]
     fn test_format_children_as_markdown_with_children() {
         use crate::types::TicketStatus;
@@ -2739,6 +2974,7 @@ mod annotation_tests {
             "get_children",
             "get_plan_status",
             "get_next_available_ticket",
+            "board_summary",
             "semantic_search",
             "doc_list",
             "doc_show",
@@ -2802,6 +3038,8 @@ mod annotation_tests {
             "spawn_subtask",
             "update_status",
             "add_note",
+            "set_acceptance_criteria",
+            "check_criterion",
             "add_dependency",
             "remove_dependency",
             "add_label",
@@ -1,11 +1,16 @@
 //! Core algorithm for the `janus next` command.
 //!
 //! This module provides the `NextWorkFinder` which computes the optimal work queue
-//! based on ticket priorities, dependencies, and status.
+//! based on ticket priorities, dependencies, and status. With a [`Capacity`]
+//! attached, the queue is limited to what one assignee can take on.
 
 use std::collections::{HashMap, HashSet};
 
-use crate::status::all_deps_satisfied;
+use jiff::Timestamp;
+
+use crate::config::{CapacityLimits, ReadyRequirements};
+use crate::plan::forecast::size_points;
+use crate::status::{is_ready, is_workable};
 use crate::types::{TicketData, TicketMetadata, TicketStatus};
 
 /// Reason why a ticket is included in the next work queue
@@ -28,22 +33,115 @@ pub struct WorkItem {
     pub blocks: Option<String>,
 }
 
+/// An assignee's current workload measured against their configured limits
+#[derive(Debug, Clone)]
+pub struct Capacity {
+    pub assignee: String,
+    pub limits: CapacityLimits,
+    /// Tickets in progress and assigned to `assignee`
+    pub in_progress: usize,
+    /// Size points of those tickets
+    pub in_progress_points: u32,
+}
+
+impl Capacity {
+    /// Measure the in-progress work of `assignee` (matched ignoring case)
+    pub fn new(
+        assignee: &str,
+        limits: CapacityLimits,
+        ticket_map: &HashMap<String, TicketMetadata>,
+    ) -> Self {
+        let active: Vec<&TicketMetadata> = ticket_map
+            .values()
+            .filter(|t| t.status == Some(TicketStatus::InProgress))
+            .filter(|t| {
+                t.assignee
+                    .as_deref()
+                    .is_some_and(|a| a.eq_ignore_ascii_case(assignee))
+            })
+            .collect();
+
+        Self {
+            assignee: assignee.to_string(),
+            limits,
+            in_progress: active.len(),
+            in_progress_points: active.iter().map(|t| size_points(t.size)).sum(),
+        }
+    }
+
+    /// Whether the assignee already has as many tickets in progress as allowed
+    pub fn at_wip_limit(&self) -> bool {
+        self.limits
+            .wip_limit
+            .is_some_and(|limit| self.in_progress >= limit)
+    }
+
+    /// Size points left before the size budget is used up, if there is one
+    pub fn remaining_points(&self) -> Option<u32> {
+        self.limits
+            .size_budget
+            .map(|budget| budget.saturating_sub(self.in_progress_points))
+    }
+
+    /// Whether `ticket` can be recommended: it must be unassigned or assigned
+    /// to this assignee, and fit in the remaining size budget.
+    pub fn accepts(&self, ticket: &TicketMetadata) -> bool {
+        let assignable = ticket
+            .assignee
+            .as_deref()
+            .is_none_or(|a| a.eq_ignore_ascii_case(&self.assignee));
+        let fits = self
+            .remaining_points()
+            .is_none_or(|left| size_points(ticket.size) <= left);
+        assignable && fits
+    }
+}
+
 /// Core algorithm for finding the next work items
 pub struct NextWorkFinder<'a> {
     ticket_map: &'a HashMap<String, TicketMetadata>,
+    capacity: Option<Capacity>,
+    require: ReadyRequirements,
+    now: Timestamp,
 }
 
 impl<'a> NextWorkFinder<'a> {
     /// Create a new NextWorkFinder with a reference to the ticket map
     pub fn new(ticket_map: &'a HashMap<String, TicketMetadata>) -> Self {
-        Self { ticket_map }
+        Self {
+            ticket_map,
+            capacity: None,
+            require: ReadyRequirements::default(),
+            now: Timestamp::now(),
+        }
+    }
+
+    /// Decide readiness by the given requirements (`ready.require`) instead
+    /// of the defaults
+    pub fn with_requirements(mut self, require: ReadyRequirements) -> Self {
+        self.require = require;
+        self
+    }
+
+    /// Only recommend work that fits the given assignee's capacity
+    pub fn with_capacity(mut self, capacity: Capacity) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Whether a ticket may be recommended: it must not be locked by anyone
+    /// (see `janus lock`) and must fit the attached capacity
+    fn accepts(&self, ticket: &TicketMetadata) -> bool {
+        ticket.lock_holder_at(self.now).is_none()
+            && self.capacity.as_ref().is_none_or(|c| c.accepts(ticket))
     }
 
     /// Get the next work items up to the specified limit
     ///
     /// The algorithm:
-    /// 1. Get all workable tickets (status new or next)
-    /// 2. Separate into ready (no incomplete deps) and blocked
+    /// 1. Get all workable tickets (status new or next, meeting `ready.require`
+    ///    apart from dependencies, not locked)
+    /// 2. Separate into ready and blocked
     /// 3. For each blocked ticket in priority order (shorter chains first):
     ///    - Find ready dependencies via DFS
     ///    - Add them with Blocking reason (pointing to this blocked ticket)
@@ -52,8 +150,11 @@ impl<'a> NextWorkFinder<'a> {
     ///    - Add with Ready reason
     /// 5. Use visited set to avoid duplicates
     /// 6. Truncate to limit
+    ///
+    /// With a capacity attached, nothing is returned once the assignee is at
+    /// their WIP limit, and tickets the capacity does not accept are left out.
     pub fn get_next_work(&self, limit: usize) -> Vec<WorkItem> {
-        if limit == 0 {
+        if limit == 0 || self.capacity.as_ref().is_some_and(|c| c.at_wip_limit()) {
             return Vec::new();
         }
 
@@ -126,7 +227,9 @@ impl<'a> NextWorkFinder<'a> {
                     continue;
                 }
 
-                if let Some(dep_metadata) = self.ticket_map.get(&dep_id) {
+                if let Some(dep_metadata) = self.ticket_map.get(&dep_id)
+                    && self.accepts(dep_metadata)
+                {
                     visited.insert(dep_id.clone());
                     result.push(WorkItem {
                         ticket_id: dep_id.clone(),
@@ -180,12 +283,13 @@ impl<'a> NextWorkFinder<'a> {
         result
     }
 
-    /// Get all workable tickets (status new or next)
+    /// Get all workable tickets (see [`is_workable`])
     fn get_workable_tickets(&self) -> Vec<&TicketMetadata> {
         let mut workable: Vec<&TicketMetadata> = self
             .ticket_map
             .values()
-            .filter(|t| matches!(t.status, Some(TicketStatus::New) | Some(TicketStatus::Next)))
+            .filter(|t| is_workable(t, &self.require, self.now))
+            .filter(|t| self.accepts(t))
             .collect();
 
         // Sort by priority (lower number = higher priority), then by created date
@@ -305,20 +409,9 @@ impl<'a> NextWorkFinder<'a> {
         result
     }
 
-    /// Check if a ticket is ready (all dependencies are satisfied AND ticket is workable)
+    /// Check if a ticket is ready under the finder's requirements
     fn is_ready(&self, ticket: &TicketMetadata) -> bool {
-        // Ticket must have workable status (New or Next)
-        let is_workable = matches!(
-            ticket.status,
-            Some(TicketStatus::New) | Some(TicketStatus::Next)
-        );
-
-        if !is_workable {
-            return false;
-        }
-
-        // All dependencies must be satisfied (terminal status; orphans block)
-        all_deps_satisfied(ticket, self.ticket_map)
+        is_ready(ticket, self.ticket_map, &self.require, self.now)
     }
 
     /// Detect if a ticket is part of a circular dependency
@@ -396,23 +489,41 @@ mod tests {
             title: Some(format!("Ticket {id}")),
             status: Some(status),
             deps: deps.iter().map(|s| TicketId::new_unchecked(*s)).collect(),
+            external_deps: Vec::new(),
             links: Vec::new(),
             created: Some(crate::types::CreatedAt::new_unchecked(created)),
             completed_at: None,
+            started_at: None,
+            closed_at: None,
+            resolution: None,
+            reopen_count: None,
+            status_history: Vec::new(),
+            snoozed_until: None,
+            expires: None,
+            escalated_at: None,
+            locked_by: None,
+            locked_until: None,
+            worktree: None,
             ticket_type: Some(crate::types::TicketType::Task),
             priority: Some(ticket_priority),
             size: None,
             external_ref: None,
             remote: None,
+            assignee: None,
+            component: None,
             parent: None,
             spawned_from: None,
             spawn_context: None,
             depth: None,
             triaged: None,
             labels: Vec::new(),
+            pull_requests: Vec::new(),
+            docs: Vec::new(),
+            attachments: Vec::new(),
             file_path: None,
             completion_summary: None,
             body: None,
+            sections: Default::default(),
         }
     }
 
@@ -1047,4 +1158,71 @@ mod tests {
 
         assert!(cycle.is_some());
     }
+
+    #[test]
+    fn test_capacity_skips_oversized_and_others_tickets() {
+        use crate::types::TicketSize;
+
+        let ticket = |id: &str, status: TicketStatus, size: TicketSize, assignee: Option<&str>| {
+            let mut t = create_test_ticket(id, status, 2, vec![], "2024-01-01T00:00:00Z");
+            t.size = Some(size);
+            t.assignee = assignee.map(String::from);
+            (id.to_string(), t)
+        };
+        let mut map: HashMap<String, TicketMetadata> = [
+            ticket(
+                "j-wip1",
+                TicketStatus::InProgress,
+                TicketSize::Medium,
+                Some("sam"),
+            ),
+            ticket(
+                "j-wip2",
+                TicketStatus::InProgress,
+                TicketSize::Small,
+                Some("Sam"),
+            ),
+            ticket("j-big", TicketStatus::New, TicketSize::Large, None),
+            ticket("j-small", TicketStatus::New, TicketSize::Small, None),
+            ticket(
+                "j-alex",
+                TicketStatus::New,
+                TicketSize::XSmall,
+                Some("alex"),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let limits = CapacityLimits {
+            wip_limit: Some(3),
+            size_budget: Some(8),
+        };
+        let capacity = Capacity::new("sam", limits, &map);
+        assert_eq!(capacity.in_progress, 2);
+        assert_eq!(capacity.remaining_points(), Some(3));
+
+        let result = NextWorkFinder::new(&map)
+            .with_capacity(capacity)
+            .get_next_work(5);
+        let ids: Vec<_> = result.iter().map(|w| w.ticket_id.as_str()).collect();
+        assert_eq!(ids, vec!["j-small"]);
+
+        // A third in-progress ticket reaches the WIP limit
+        let (id, wip3) = ticket(
+            "j-wip3",
+            TicketStatus::InProgress,
+            TicketSize::XSmall,
+            Some("sam"),
+        );
+        map.insert(id, wip3);
+        let capacity = Capacity::new("sam", limits, &map);
+        assert!(capacity.at_wip_limit());
+        assert!(
+            NextWorkFinder::new(&map)
+                .with_capacity(capacity)
+                .get_next_work(5)
+                .is_empty()
+        );
+    }
 }
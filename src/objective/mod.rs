@@ -115,7 +115,7 @@ impl Objective {
     /// Write raw content without hooks.
     fn write_raw(&self, content: &str) -> Result<()> {
         self.ensure_parent_dir()?;
-        crate::fs::write_file_atomic(&self.file_path, content)
+        crate::fs::write_document_atomic(&self.file_path, content)
     }
 
     /// Ensure the parent directory exists.
@@ -142,6 +142,7 @@ impl Objective {
                 source: e,
             });
         }
+        crate::fs::record_path(&self.file_path);
 
         run_post_hooks(HookEvent::PostDelete, &context);
         run_post_hooks(HookEvent::ObjectiveDeleted, &context);
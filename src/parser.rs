@@ -262,6 +262,152 @@ pub fn split_frontmatter_comrak(content: &str) -> Result<(String, String)> {
     ))
 }
 
+/// The syntax of a document's frontmatter.
+///
+/// YAML (`---` delimiters) is the default. TOML (`+++` delimiters) is accepted
+/// for teams that standardize on it; it is converted to YAML when read and
+/// converted back when a TOML file is rewritten, so the rest of Janus only
+/// ever sees YAML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FrontmatterFormat {
+    #[default]
+    Yaml,
+    Toml,
+}
+
+impl FrontmatterFormat {
+    /// Detect the frontmatter format from the opening delimiter.
+    pub fn detect(content: &str) -> Self {
+        if content.starts_with("+++\n") || content.starts_with("+++\r\n") {
+            FrontmatterFormat::Toml
+        } else {
+            FrontmatterFormat::Yaml
+        }
+    }
+}
+
+/// Split `+++`-delimited TOML frontmatter from the body.
+///
+/// The closing delimiter is the first line consisting of exactly `+++`.
+fn split_toml_frontmatter(content: &str) -> Result<(String, String)> {
+    let after_first = content
+        .strip_prefix("+++\n")
+        .ok_or_else(|| JanusError::InvalidFormat("missing TOML frontmatter".to_string()))?;
+
+    let mut offset = 0;
+    for line in after_first.split_inclusive('\n') {
+        if line.trim_end_matches('\n') == "+++" {
+            let frontmatter = after_first[..offset].trim_end_matches('\n');
+            let body = &after_first[offset + line.len()..];
+            return Ok((frontmatter.to_string(), body.to_string()));
+        }
+        offset += line.len();
+    }
+
+    Err(JanusError::InvalidFormat(
+        "unterminated TOML frontmatter".to_string(),
+    ))
+}
+
+fn toml_to_yaml(value: toml::Value) -> yaml::Value {
+    match value {
+        toml::Value::String(s) => yaml::Value::String(s),
+        toml::Value::Integer(i) => yaml::Value::Number(i.into()),
+        toml::Value::Float(f) => yaml::Value::Number(f.into()),
+        toml::Value::Boolean(b) => yaml::Value::Bool(b),
+        toml::Value::Datetime(dt) => yaml::Value::String(dt.to_string()),
+        toml::Value::Array(items) => {
+            yaml::Value::Sequence(items.into_iter().map(toml_to_yaml).collect())
+        }
+        toml::Value::Table(table) => yaml::Value::Mapping(
+            table
+                .into_iter()
+                .map(|(k, v)| (yaml::Value::String(k), toml_to_yaml(v)))
+                .collect(),
+        ),
+    }
+}
+
+/// Convert YAML to TOML. TOML has no null, so null values (and null array
+/// entries) are dropped; `None` is returned for a null at the top.
+fn yaml_to_toml(value: yaml::Value) -> Result<Option<toml::Value>> {
+    Ok(Some(match value {
+        yaml::Value::Null => return Ok(None),
+        yaml::Value::Bool(b) => toml::Value::Boolean(b),
+        yaml::Value::Number(n) => match n.as_i64() {
+            Some(i) => toml::Value::Integer(i),
+            None => toml::Value::Float(n.as_f64().unwrap_or_default()),
+        },
+        yaml::Value::String(s) => toml::Value::String(s),
+        yaml::Value::Sequence(items) => {
+            let mut array = Vec::with_capacity(items.len());
+            for item in items {
+                array.extend(yaml_to_toml(item)?);
+            }
+            toml::Value::Array(array)
+        }
+        yaml::Value::Mapping(mapping) => {
+            let mut table = toml::Table::new();
+            for (k, v) in mapping {
+                let key = match k {
+                    yaml::Value::String(s) => s,
+                    other => yaml::to_string(&other)?.trim_end().to_string(),
+                };
+                if let Some(v) = yaml_to_toml(v)? {
+                    table.insert(key, v);
+                }
+            }
+            toml::Value::Table(table)
+        }
+        yaml::Value::Tagged(tagged) => return yaml_to_toml(tagged.value),
+    }))
+}
+
+/// Convert TOML frontmatter text to the equivalent YAML text.
+fn toml_frontmatter_to_yaml(frontmatter: &str) -> Result<String> {
+    if frontmatter.trim().is_empty() {
+        return Ok(String::new());
+    }
+    let table: toml::Table = toml::from_str(frontmatter)
+        .map_err(|e| JanusError::InvalidFormat(format!("failed to parse TOML frontmatter: {e}")))?;
+    let yaml = yaml::to_string(&toml_to_yaml(toml::Value::Table(table)))?;
+    Ok(yaml.trim_end().to_string())
+}
+
+/// Rewrite a document so its frontmatter uses `format`.
+///
+/// Content that already uses `format` is returned unchanged, so this is
+/// cheap to call on every write. Converting to TOML drops null fields, since
+/// TOML cannot represent them.
+pub fn convert_frontmatter(content: &str, format: FrontmatterFormat) -> Result<String> {
+    if FrontmatterFormat::detect(content) == format {
+        return Ok(content.to_string());
+    }
+
+    let (frontmatter, body) = split_frontmatter(content)?;
+    match format {
+        FrontmatterFormat::Yaml => Ok(format!("---\n{frontmatter}\n---\n{body}")),
+        FrontmatterFormat::Toml => {
+            let mapping: yaml::Value = if frontmatter.trim().is_empty() {
+                yaml::Value::Mapping(yaml::Mapping::new())
+            } else {
+                yaml::from_str(&frontmatter)?
+            };
+            let toml = match yaml_to_toml(mapping)? {
+                Some(toml::Value::Table(table)) => toml::to_string(&table).map_err(|e| {
+                    JanusError::InternalError(format!("failed to serialize TOML frontmatter: {e}"))
+                })?,
+                _ => {
+                    return Err(JanusError::InvalidFormat(
+                        "frontmatter is not a mapping".to_string(),
+                    ));
+                }
+            };
+            Ok(format!("+++\n{toml}+++\n{body}"))
+        }
+    }
+}
+
 /// Split content into YAML frontmatter and markdown body.
 ///
 /// Handles CRLF and CR line endings by normalizing to LF. Returns a tuple of
@@ -269,9 +415,18 @@ pub fn split_frontmatter_comrak(content: &str) -> Result<(String, String)> {
 ///
 /// This function now uses comrak's robust AST-based parsing instead of regex,
 /// correctly handling edge cases like "---" in YAML comments or multi-line strings.
+///
+/// `+++`-delimited TOML frontmatter is also accepted and returned as YAML,
+/// so callers never need to know which format the file uses.
 pub fn split_frontmatter(content: &str) -> Result<(String, String)> {
     let normalized = content.replace("\r\n", "\n").replace("\r", "\n");
-    split_frontmatter_comrak(&normalized)
+    match FrontmatterFormat::detect(&normalized) {
+        FrontmatterFormat::Yaml => split_frontmatter_comrak(&normalized),
+        FrontmatterFormat::Toml => {
+            let (frontmatter, body) = split_toml_frontmatter(&normalized)?;
+            Ok((toml_frontmatter_to_yaml(&frontmatter)?, body))
+        }
+    }
 }
 
 impl ParsedDocument {
@@ -474,6 +629,47 @@ mod tests {
         assert_eq!(body, "# Title");
     }
 
+    #[test]
+    fn test_split_frontmatter_toml() {
+        let content =
+            "+++\nid = \"test\"\ndeps = [\"a\", \"b\"]\npriority = 2\n+++\n# Title\n\nBody";
+        assert_eq!(FrontmatterFormat::detect(content), FrontmatterFormat::Toml);
+        let (yaml, body) = split_frontmatter(content).unwrap();
+        assert_eq!(yaml, "id: test\ndeps:\n- a\n- b\npriority: 2");
+        assert_eq!(body, "# Title\n\nBody");
+    }
+
+    #[test]
+    fn test_split_frontmatter_toml_unterminated() {
+        let content = "+++\nid = \"test\"\n# Title\n";
+        assert!(split_frontmatter(content).is_err());
+    }
+
+    #[test]
+    fn test_convert_frontmatter_round_trip() {
+        let toml =
+            "+++\nid = \"test\"\ncreated = 2026-01-01T00:00:00Z\ntags = [\"x\"]\n+++\n# Title\n";
+        let yaml = convert_frontmatter(toml, FrontmatterFormat::Yaml).unwrap();
+        assert!(yaml.starts_with("---\nid: test\n"));
+        assert!(yaml.contains("2026-01-01T00:00:00Z"));
+        assert!(yaml.ends_with("tags:\n- x\n---\n# Title\n"));
+
+        let back = convert_frontmatter(&yaml, FrontmatterFormat::Toml).unwrap();
+        assert!(back.starts_with("+++\nid = \"test\"\n"));
+        assert!(back.ends_with("+++\n# Title\n"));
+        assert_eq!(
+            split_frontmatter(&back).unwrap().0,
+            split_frontmatter(&yaml).unwrap().0
+        );
+    }
+
+    #[test]
+    fn test_convert_frontmatter_drops_nulls_for_toml() {
+        let yaml = "---\nid: test\nassignee: null\n---\n# Title\n";
+        let toml = convert_frontmatter(yaml, FrontmatterFormat::Toml).unwrap();
+        assert_eq!(toml, "+++\nid = \"test\"\n+++\n# Title\n");
+    }
+
     #[test]
     fn test_parse_document_returns_generic_structure() {
         let content = r#"---
@@ -102,6 +102,11 @@ pub fn objectives_dir() -> PathBuf {
     janus_root().join("objectives")
 }
 
+/// Returns the path to the content-addressable attachment store.
+pub fn objects_dir() -> PathBuf {
+    janus_root().join("objects")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
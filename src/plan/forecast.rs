@@ -0,0 +1,175 @@
+//! Plan completion forecasts (`janus plan forecast`)
+//!
+//! Throughput is measured in size points per week from the close timestamps
+//! of completed tickets. A Monte Carlo simulation then replays randomly
+//! sampled historical weeks until the plan's remaining points are used up;
+//! the spread of the simulated finish times gives the confidence bands.
+
+use jiff::{SignedDuration, Timestamp};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::types::{TicketMetadata, TicketSize, TicketStatus};
+
+/// Weeks of history used when none is given
+pub const DEFAULT_HISTORY_WEEKS: u32 = 12;
+
+/// Confidence levels reported by a forecast, in percent
+pub const CONFIDENCE_LEVELS: [u32; 3] = [50, 85, 95];
+
+/// Number of simulated futures per forecast
+const TRIALS: usize = 10_000;
+
+/// Simulations that have not finished after this many weeks are cut off
+const MAX_WEEKS: u32 = 520;
+
+/// Fixed seed so the same history always yields the same forecast
+const SEED: u64 = 0x6a61_6e75_73;
+
+const WEEK: SignedDuration = SignedDuration::from_hours(24 * 7);
+
+/// Relative effort of a ticket. Unsized tickets count as medium.
+pub fn size_points(size: Option<TicketSize>) -> u32 {
+    match size {
+        Some(TicketSize::XSmall) => 1,
+        Some(TicketSize::Small) => 2,
+        Some(TicketSize::Medium) | None => 3,
+        Some(TicketSize::Large) => 5,
+        Some(TicketSize::XLarge) => 8,
+    }
+}
+
+/// Size points completed in each of the last `weeks` weeks, most recent
+/// first. Week 0 is the seven days ending at `now`.
+///
+/// Only completed tickets count; cancelled work is not throughput.
+pub fn weekly_throughput<'a>(
+    tickets: impl IntoIterator<Item = &'a TicketMetadata>,
+    now: Timestamp,
+    weeks: u32,
+) -> Vec<u32> {
+    let mut history = vec![0; weeks as usize];
+    for ticket in tickets {
+        if ticket.status != Some(TicketStatus::Complete) {
+            continue;
+        }
+        let Some(closed) = ticket
+            .closed_at
+            .as_ref()
+            .or(ticket.completed_at.as_ref())
+            .and_then(|c| c.to_timestamp())
+        else {
+            continue;
+        };
+        let elapsed = now.duration_since(closed);
+        if elapsed.is_negative() {
+            continue;
+        }
+        let week = (elapsed.as_secs() / WEEK.as_secs()) as usize;
+        if let Some(bucket) = history.get_mut(week) {
+            *bucket += size_points(ticket.size);
+        }
+    }
+    history
+}
+
+/// Weeks until completion at each of [`CONFIDENCE_LEVELS`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forecast {
+    pub weeks: [u32; CONFIDENCE_LEVELS.len()],
+}
+
+impl Forecast {
+    /// Date (YYYY-MM-DD) reached `weeks` weeks after `now`.
+    pub fn date(now: Timestamp, weeks: u32) -> String {
+        now.checked_add(WEEK * weeks as i32)
+            .unwrap_or(Timestamp::MAX)
+            .strftime("%Y-%m-%d")
+            .to_string()
+    }
+}
+
+/// Simulate finishing `remaining` points by drawing weeks from `history`.
+///
+/// Returns `None` when nothing was completed in the history window, since
+/// there is no throughput to extrapolate from.
+pub fn simulate(history: &[u32], remaining: u32) -> Option<Forecast> {
+    if history.iter().all(|&points| points == 0) {
+        return None;
+    }
+
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let mut outcomes: Vec<u32> = (0..TRIALS)
+        .map(|_| {
+            let mut left = remaining;
+            let mut weeks = 0;
+            while left > 0 && weeks < MAX_WEEKS {
+                left = left.saturating_sub(history[rng.random_range(0..history.len())]);
+                weeks += 1;
+            }
+            weeks
+        })
+        .collect();
+    outcomes.sort_unstable();
+
+    let weeks = CONFIDENCE_LEVELS.map(|level| {
+        let rank = (TRIALS * level as usize).div_ceil(100);
+        outcomes[rank.saturating_sub(1)]
+    });
+    Some(Forecast { weeks })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CreatedAt;
+
+    fn closed(days_ago: i64, size: Option<TicketSize>, now: Timestamp) -> TicketMetadata {
+        let stamp = now - SignedDuration::from_hours(24 * days_ago);
+        TicketMetadata {
+            status: Some(TicketStatus::Complete),
+            closed_at: Some(CreatedAt::new_unchecked(
+                stamp.strftime("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            )),
+            size,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_weekly_throughput_buckets_by_week() {
+        let now: Timestamp = "2026-03-01T12:00:00Z".parse().unwrap();
+        let mut cancelled = closed(1, None, now);
+        cancelled.status = Some(TicketStatus::Cancelled);
+        let tickets = [
+            closed(1, Some(TicketSize::Small), now),
+            closed(3, None, now),
+            closed(8, Some(TicketSize::XLarge), now),
+            closed(100, Some(TicketSize::Large), now),
+            cancelled,
+        ];
+
+        assert_eq!(weekly_throughput(&tickets, now, 3), vec![5, 8, 0]);
+    }
+
+    #[test]
+    fn test_simulate_steady_throughput() {
+        // Ten points every week: 25 points always take three weeks
+        let forecast = simulate(&[10, 10, 10], 25).unwrap();
+        assert_eq!(forecast.weeks, [3, 3, 3]);
+    }
+
+    #[test]
+    fn test_simulate_bands_widen_with_variance() {
+        let forecast = simulate(&[0, 2, 10, 4], 30).unwrap();
+        assert!(forecast.weeks[0] <= forecast.weeks[1]);
+        assert!(forecast.weeks[1] <= forecast.weeks[2]);
+        assert!(forecast.weeks[0] < forecast.weeks[2]);
+    }
+
+    #[test]
+    fn test_simulate_without_history() {
+        assert_eq!(simulate(&[0, 0], 5), None);
+        assert_eq!(simulate(&[], 5), None);
+    }
+}
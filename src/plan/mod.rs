@@ -4,6 +4,7 @@
 //! similar to the `Ticket` type. Plans are stored as Markdown files with
 //! YAML frontmatter in the `.janus/plans/` directory.
 
+pub mod forecast;
 pub mod parser;
 pub mod types;
 
@@ -242,7 +243,7 @@ impl Plan {
     /// Write raw content without hooks
     fn write_raw(&self, content: &str) -> Result<()> {
         self.ensure_parent_dir()?;
-        crate::fs::write_file_atomic(&self.file_path, content)
+        crate::fs::write_document_atomic(&self.file_path, content)
     }
 
     /// Ensure the parent directory exists
@@ -269,6 +270,7 @@ impl Plan {
                 source: e,
             });
         }
+        crate::fs::record_path(&self.file_path);
 
         run_post_hooks(HookEvent::PostDelete, &context);
         run_post_hooks(HookEvent::PlanDeleted, &context);
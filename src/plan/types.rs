@@ -574,6 +574,12 @@ pub struct PlanStatus {
 
     /// Total number of tickets
     pub total_count: usize,
+
+    /// Size points of completed tickets
+    pub completed_points: u32,
+
+    /// Size points of all tickets
+    pub total_points: u32,
 }
 
 impl PlanStatus {
@@ -600,6 +606,14 @@ impl PlanStatus {
         self.progress().percent()
     }
 
+    /// Get progress weighted by ticket size points
+    pub fn points_progress(&self) -> Progress {
+        Progress {
+            completed: self.completed_points as usize,
+            total: self.total_points as usize,
+        }
+    }
+
     /// Check if all tickets are complete
     pub fn is_complete(&self) -> bool {
         self.total_count > 0 && self.completed_count == self.total_count
@@ -622,6 +636,10 @@ impl Default for PlanStatus {
             status: TicketStatus::New,
             completed_count: 0,
             total_count: 0,
+            completed_points: 0,
+            total_points: 0,
+            completed_points: 0,
+            total_points: 0,
         }
     }
 }
@@ -643,6 +661,12 @@ pub struct PhaseStatus {
 
     /// Total number of tickets in this phase
     pub total_count: usize,
+
+    /// Size points of completed tickets in this phase
+    pub completed_points: u32,
+
+    /// Size points of all tickets in this phase
+    pub total_points: u32,
 }
 
 impl PhaseStatus {
@@ -669,6 +693,14 @@ impl PhaseStatus {
         self.progress().percent()
     }
 
+    /// Get progress weighted by ticket size points
+    pub fn points_progress(&self) -> Progress {
+        Progress {
+            completed: self.completed_points as usize,
+            total: self.total_points as usize,
+        }
+    }
+
     /// Check if all tickets in this phase are complete
     pub fn is_complete(&self) -> bool {
         self.total_count > 0 && self.completed_count == self.total_count
@@ -1033,6 +1065,8 @@ mod tests {
             status: TicketStatus::InProgress,
             completed_count: 5,
             total_count: 12,
+            completed_points: 15,
+            total_points: 36,
         };
 
         let progress = Progress {
@@ -1063,6 +1097,8 @@ mod tests {
             status: TicketStatus::Complete,
             completed_count: 2,
             total_count: 2,
+            completed_points: 6,
+            total_points: 6,
         };
 
         let progress = Progress {
@@ -0,0 +1,155 @@
+//! Grouping of ticket JSON for aggregate output (`query --group-by`, `count --by`).
+//!
+//! Grouping works on the JSON form of tickets so that the same code handles
+//! tickets straight from the store and tickets that came back from a jq filter.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+/// Ticket field to group by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupField {
+    Status,
+    Assignee,
+    Type,
+    Priority,
+}
+
+impl GroupField {
+    /// All valid string representations of this enum.
+    pub const ALL_STRINGS: &[&str] = &["status", "assignee", "type", "priority"];
+
+    /// Key of this field in ticket JSON.
+    pub fn json_key(&self) -> &'static str {
+        match self {
+            GroupField::Status => "status",
+            GroupField::Assignee => "assignee",
+            GroupField::Type => "type",
+            GroupField::Priority => "priority",
+        }
+    }
+}
+
+enum_display_fromstr!(
+    GroupField,
+    crate::error::JanusError::invalid_group_field,
+    ["status", "assignee", "type", "priority"],
+    {
+        Status => "status",
+        Assignee => "assignee",
+        Type => "type",
+        Priority => "priority",
+    }
+);
+
+/// Tickets sharing one value of the grouped field.
+#[derive(Debug, Clone)]
+pub struct TicketGroup {
+    /// The field's value, `Value::Null` for tickets without one
+    pub value: Value,
+    pub tickets: Vec<Value>,
+}
+
+impl TicketGroup {
+    /// Label for text output (`P0` for priorities, `(none)` for missing values).
+    pub fn label(&self, field: GroupField) -> String {
+        match (&self.value, field) {
+            (Value::Null, _) => "(none)".to_string(),
+            (Value::Number(n), GroupField::Priority) => format!("P{n}"),
+            (Value::String(s), _) => s.clone(),
+            (other, _) => other.to_string(),
+        }
+    }
+
+    /// JSON summary of the group, with the tickets themselves unless
+    /// `count_only` is set.
+    pub fn to_json(&self, field: GroupField, count_only: bool) -> Value {
+        let mut map = serde_json::Map::new();
+        map.insert(field.json_key().to_string(), self.value.clone());
+        map.insert("count".to_string(), self.tickets.len().into());
+        if !count_only {
+            map.insert("tickets".to_string(), Value::Array(self.tickets.clone()));
+        }
+        Value::Object(map)
+    }
+}
+
+/// Group ticket JSON values by `field`.
+///
+/// Groups are ordered largest first, ties by value, with tickets missing the
+/// field last. Tickets keep their input order within a group.
+pub fn group_tickets(tickets: Vec<Value>, field: GroupField) -> Vec<TicketGroup> {
+    let mut groups: Vec<TicketGroup> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+
+    for ticket in tickets {
+        let value = ticket.get(field.json_key()).cloned().unwrap_or(Value::Null);
+        let slot = *index.entry(value.to_string()).or_insert_with(|| {
+            groups.push(TicketGroup {
+                value,
+                tickets: Vec::new(),
+            });
+            groups.len() - 1
+        });
+        groups[slot].tickets.push(ticket);
+    }
+
+    groups.sort_by(|a, b| {
+        a.value
+            .is_null()
+            .cmp(&b.value.is_null())
+            .then_with(|| b.tickets.len().cmp(&a.tickets.len()))
+            .then_with(|| a.label(field).cmp(&b.label(field)))
+    });
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_group_tickets_orders_by_count() {
+        let tickets = vec![
+            json!({"id": "j-1", "status": "new", "priority": 2}),
+            json!({"id": "j-2", "status": "complete", "priority": 0}),
+            json!({"id": "j-3", "status": "new", "priority": 2}),
+            json!({"id": "j-4", "status": "in_progress", "priority": 1}),
+        ];
+
+        let groups = group_tickets(tickets.clone(), GroupField::Status);
+        let labels: Vec<String> = groups.iter().map(|g| g.label(GroupField::Status)).collect();
+        assert_eq!(labels, vec!["new", "complete", "in_progress"]);
+        assert_eq!(groups[0].tickets[1]["id"], "j-3");
+
+        let groups = group_tickets(tickets, GroupField::Priority);
+        assert_eq!(groups[0].label(GroupField::Priority), "P2");
+        assert_eq!(
+            groups[0].to_json(GroupField::Priority, true),
+            json!({"priority": 2, "count": 2})
+        );
+    }
+
+    #[test]
+    fn test_group_tickets_missing_values_last() {
+        let tickets = vec![
+            json!({"id": "j-1", "assignee": null}),
+            json!({"id": "j-2"}),
+            json!({"id": "j-3", "assignee": "alice"}),
+        ];
+        let groups = group_tickets(tickets, GroupField::Assignee);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].label(GroupField::Assignee), "alice");
+        assert_eq!(groups[1].label(GroupField::Assignee), "(none)");
+        assert_eq!(groups[1].tickets.len(), 2);
+    }
+
+    #[test]
+    fn test_group_field_parse() {
+        assert_eq!("Status".parse::<GroupField>().unwrap(), GroupField::Status);
+        assert!("size".parse::<GroupField>().is_err());
+    }
+}
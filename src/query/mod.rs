@@ -6,14 +6,18 @@
 use std::collections::HashMap;
 
 use dashmap::DashSet;
+use jiff::Timestamp;
 
+use crate::config::ReadyRequirements;
 use crate::error::Result;
-use crate::status::{all_deps_satisfied, has_unsatisfied_dep};
+use crate::status::{has_unsatisfied_dep, is_ready, is_workable};
 use crate::ticket::build_ticket_map;
 use crate::types::{TicketData, TicketMetadata, TicketSize, TicketStatus, TicketType};
 
+pub mod group;
 pub mod sort;
 
+pub use group::{GroupField, TicketGroup, group_tickets};
 pub use sort::{SortField, sort_by_created, sort_by_id, sort_by_priority, sort_tickets_by};
 
 /// Context passed to filters containing shared state
@@ -222,15 +226,83 @@ impl TicketFilter for LabelFilter {
     }
 }
 
-/// Filter tickets that are "ready" (New/Next status with all deps satisfied)
-pub struct ReadyFilter;
+/// Filter tickets by component (OR matching, case-insensitive)
+pub struct ComponentFilter {
+    components: Vec<String>,
+}
+
+impl ComponentFilter {
+    pub fn new(components: Vec<String>) -> Self {
+        Self { components }
+    }
+}
+
+impl TicketFilter for ComponentFilter {
+    fn matches(&self, ticket: &TicketMetadata, _context: &TicketFilterContext) -> bool {
+        if self.components.is_empty() {
+            return true;
+        }
+        ticket.component.as_deref().is_some_and(|component| {
+            self.components
+                .iter()
+                .any(|filter| filter.eq_ignore_ascii_case(component))
+        })
+    }
+}
+
+/// Filter tickets by ID prefix namespace (OR matching)
+///
+/// A prefix matches IDs of the form `<prefix>-...`, so `api` matches
+/// `api-a1b2` but not `apiv2-a1b2`.
+pub struct PrefixFilter {
+    prefixes: Vec<String>,
+}
+
+impl PrefixFilter {
+    pub fn new(prefixes: Vec<String>) -> Self {
+        Self { prefixes }
+    }
+}
+
+impl TicketFilter for PrefixFilter {
+    fn matches(&self, ticket: &TicketMetadata, _context: &TicketFilterContext) -> bool {
+        if self.prefixes.is_empty() {
+            return true;
+        }
+        ticket.id.as_deref().is_some_and(|id| {
+            self.prefixes.iter().any(|prefix| {
+                id.strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.starts_with('-'))
+            })
+        })
+    }
+}
+
+/// Filter tickets that have been reopened at least once
+pub struct ReopenedFilter;
+
+impl TicketFilter for ReopenedFilter {
+    fn matches(&self, ticket: &TicketMetadata, _context: &TicketFilterContext) -> bool {
+        ticket.reopen_count.is_some_and(|count| count > 0)
+    }
+}
+
+/// Filter tickets that are "ready" under the configured definition
+/// (`ready.require`; by default New/Next, not snoozed, all deps satisfied)
+pub struct ReadyFilter {
+    require: ReadyRequirements,
+    now: Timestamp,
+}
+
+impl ReadyFilter {
+    pub fn new(require: ReadyRequirements, now: Timestamp) -> Self {
+        Self { require, now }
+    }
+}
 
 impl TicketFilter for ReadyFilter {
     fn matches(&self, ticket: &TicketMetadata, context: &TicketFilterContext) -> bool {
-        if !matches!(
-            ticket.status,
-            Some(TicketStatus::New) | Some(TicketStatus::Next)
-        ) {
+        if !is_workable(ticket, &self.require, self.now) {
             return false;
         }
 
@@ -242,8 +314,7 @@ impl TicketFilter for ReadyFilter {
             }
         }
 
-        // All deps must be satisfied (terminal status; orphans block)
-        all_deps_satisfied(ticket, &context.ticket_map)
+        is_ready(ticket, &context.ticket_map, &self.require, self.now)
     }
 }
 
@@ -260,7 +331,7 @@ impl TicketFilter for BlockedFilter {
         }
 
         // Must have deps
-        if ticket.deps.is_empty() {
+        if ticket.deps.is_empty() && ticket.external_deps.is_empty() {
             return false;
         }
 
@@ -289,6 +360,72 @@ impl TicketFilter for ClosedFilter {
     }
 }
 
+/// Filter tickets closed at or after a point in time.
+///
+/// Uses `closed-at`, falling back to `completed-at` and then the file's
+/// modification time for tickets closed before `closed-at` was recorded.
+pub struct ClosedSinceFilter {
+    since: Timestamp,
+}
+
+impl ClosedSinceFilter {
+    pub fn new(since: Timestamp) -> Self {
+        Self { since }
+    }
+}
+
+impl TicketFilter for ClosedSinceFilter {
+    fn matches(&self, ticket: &TicketMetadata, _context: &TicketFilterContext) -> bool {
+        let stamp = ticket
+            .closed_at
+            .as_ref()
+            .or(ticket.completed_at.as_ref())
+            .and_then(|c| c.to_timestamp())
+            .or_else(|| {
+                let mtime = std::fs::metadata(ticket.file_path.as_deref()?)
+                    .ok()?
+                    .modified()
+                    .ok()?;
+                Timestamp::try_from(mtime).ok()
+            });
+        stamp.is_some_and(|stamp| stamp >= self.since)
+    }
+}
+
+/// Filter out tickets that are snoozed (`snoozed-until` in the future)
+pub struct NotSnoozedFilter {
+    now: Timestamp,
+}
+
+impl NotSnoozedFilter {
+    pub fn new(now: Timestamp) -> Self {
+        Self { now }
+    }
+}
+
+impl TicketFilter for NotSnoozedFilter {
+    fn matches(&self, ticket: &TicketMetadata, _context: &TicketFilterContext) -> bool {
+        !ticket.is_snoozed_at(self.now)
+    }
+}
+
+/// Filter out tickets with an unexpired advisory lock (see `janus lock`)
+pub struct NotLockedFilter {
+    now: Timestamp,
+}
+
+impl NotLockedFilter {
+    pub fn new(now: Timestamp) -> Self {
+        Self { now }
+    }
+}
+
+impl TicketFilter for NotLockedFilter {
+    fn matches(&self, ticket: &TicketMetadata, _context: &TicketFilterContext) -> bool {
+        ticket.lock_holder_at(self.now).is_none()
+    }
+}
+
 /// Filter tickets that are active (not closed)
 pub struct ActiveFilter;
 
@@ -301,6 +438,16 @@ impl TicketFilter for ActiveFilter {
     }
 }
 
+/// Filter out archived tickets, which listings skip unless asked to include
+/// them (`--include-archived`)
+pub struct NotArchivedFilter;
+
+impl TicketFilter for NotArchivedFilter {
+    fn matches(&self, ticket: &TicketMetadata, _context: &TicketFilterContext) -> bool {
+        ticket.status != Some(TicketStatus::Archived)
+    }
+}
+
 /// An executed query configuration that can be applied to ticket data.
 /// This separates query configuration from execution, following SRP.
 pub struct TicketQuery {
@@ -468,6 +615,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_not_archived_filter() {
+        let context = empty_context();
+        let filter = NotArchivedFilter;
+        assert!(filter.matches(
+            &make_ticket_with_status("t-1", TicketStatus::Complete),
+            &context
+        ));
+        assert!(!filter.matches(
+            &make_ticket_with_status("t-2", TicketStatus::Archived),
+            &context
+        ));
+    }
+
     #[test]
     fn test_status_filter_matches_correct_status() {
         let context = empty_context();
@@ -527,4 +688,45 @@ mod tests {
         assert!("".parse::<TicketStatus>().is_err());
         assert!("in-progress".parse::<TicketStatus>().is_err()); // hyphen instead of underscore
     }
+
+    #[test]
+    fn test_prefix_filter() {
+        let filter = PrefixFilter::new(vec!["api".to_string(), "ui".to_string()]);
+        let ctx = empty_context();
+        for (id, expected) in [
+            ("api-a1b2", true),
+            ("ui-0001", true),
+            ("apiv2-a1b2", false),
+            ("task-a1b2", false),
+        ] {
+            let ticket = make_ticket_with_status(id, TicketStatus::New);
+            assert_eq!(filter.matches(&ticket, &ctx), expected, "{id}");
+        }
+    }
+
+    #[test]
+    fn test_not_snoozed_filter() {
+        let context = empty_context();
+        let now: Timestamp = "2025-06-01T00:00:00Z".parse().unwrap();
+        let filter = NotSnoozedFilter::new(now);
+        let snoozed = |status, until: &str| TicketMetadata {
+            snoozed_until: Some(crate::types::CreatedAt::new_unchecked(until)),
+            ..make_ticket_with_status("t-1", status)
+        };
+
+        assert!(filter.matches(&make_ticket_with_status("t-1", TicketStatus::New), &context));
+        assert!(!filter.matches(
+            &snoozed(TicketStatus::New, "2025-07-01T00:00:00Z"),
+            &context
+        ));
+        assert!(filter.matches(
+            &snoozed(TicketStatus::New, "2025-05-01T00:00:00Z"),
+            &context
+        ));
+        // Closed tickets are never hidden by a leftover snooze
+        assert!(filter.matches(
+            &snoozed(TicketStatus::Complete, "2025-07-01T00:00:00Z"),
+            &context
+        ));
+    }
 }
@@ -0,0 +1,40 @@
+//! Read-only mode (`--read-only` or `JANUS_READONLY=1`).
+//!
+//! Meant for CI jobs and demo environments that should only ever query.
+//! Mutating CLI commands and MCP tools fail before doing any work, and the
+//! write helpers in [`crate::fs`] refuse to touch disk as a backstop for
+//! anything that slips past those checks.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::error::{JanusError, Result};
+
+/// Environment variable that enables read-only mode
+pub const READONLY_ENV: &str = "JANUS_READONLY";
+
+/// Set by `--read-only` for the rest of the process
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turn on read-only mode for the rest of the process.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether read-only mode is on, via `--read-only` or `JANUS_READONLY`.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+        || std::env::var(READONLY_ENV)
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false)
+}
+
+/// Fail with [`JanusError::ReadOnly`] if read-only mode is on.
+///
+/// `action` completes the sentence "refusing to ...", e.g. "write <path>".
+pub fn ensure_writable(action: impl FnOnce() -> String) -> Result<()> {
+    if is_enabled() {
+        Err(JanusError::ReadOnly(action()))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+//! Scoring policy for the `janus ready` queue.
+//!
+//! Every ready ticket is scored from four components, each in `0.0..=1.0`
+//! (age is unbounded) and scaled by its weight from the `ready` config:
+//!
+//! - **priority**: P0 scores 1.0, P4 scores 0.0
+//! - **age**: weeks since the ticket was created
+//! - **phase**: urgency of the ticket's plan phase. The earliest unfinished
+//!   phase of a phased plan scores 1.0, the one after it 0.5, then 0.33, and
+//!   so on. Tickets outside phased plans score 0.0.
+//! - **size**: xsmall scores 1.0, xlarge 0.0, so quick wins rise to the top
+//!
+//! The queue is sorted by total score, highest first.
+
+use std::collections::HashMap;
+
+use jiff::Timestamp;
+use serde::Serialize;
+
+use crate::config::ReadyConfig;
+use crate::plan::PlanMetadata;
+use crate::plan::forecast::size_points;
+use crate::types::{TicketData, TicketMetadata};
+
+const SECONDS_PER_WEEK: f64 = 7.0 * 24.0 * 3600.0;
+
+/// The weighted components of a ticket's score
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct ScoreBreakdown {
+    pub priority: f64,
+    pub age: f64,
+    pub phase: f64,
+    pub size: f64,
+}
+
+impl ScoreBreakdown {
+    pub fn total(&self) -> f64 {
+        self.priority + self.age + self.phase + self.size
+    }
+}
+
+/// Scores ready tickets under a [`ReadyConfig`] policy
+pub struct ReadyScorer {
+    policy: ReadyConfig,
+    now: Timestamp,
+    /// Phase urgency of every ticket in a phased plan
+    phase_urgency: HashMap<String, f64>,
+}
+
+impl ReadyScorer {
+    pub fn new(
+        policy: ReadyConfig,
+        now: Timestamp,
+        plans: &[PlanMetadata],
+        ticket_map: &HashMap<String, TicketMetadata>,
+    ) -> Self {
+        Self {
+            policy,
+            now,
+            phase_urgency: phase_urgency(plans, ticket_map),
+        }
+    }
+
+    pub fn score(&self, ticket: &TicketMetadata) -> ScoreBreakdown {
+        let priority = f64::from(4 - ticket.priority_num().min(4)) / 4.0;
+        let age = ticket
+            .created
+            .as_ref()
+            .and_then(|c| c.to_timestamp())
+            .map(|created| {
+                let seconds = self.now.duration_since(created).as_secs_f64();
+                (seconds / SECONDS_PER_WEEK).max(0.0)
+            })
+            .unwrap_or(0.0);
+        let phase = ticket
+            .id
+            .as_deref()
+            .and_then(|id| self.phase_urgency.get(id))
+            .copied()
+            .unwrap_or(0.0);
+        let size = f64::from(8 - size_points(ticket.size)) / 7.0;
+
+        ScoreBreakdown {
+            priority: priority * self.policy.priority_weight,
+            age: age * self.policy.age_weight,
+            phase: phase * self.policy.phase_weight,
+            size: size * self.policy.size_weight,
+        }
+    }
+
+    /// Sort tickets by score, highest first. Ties go to the older ticket,
+    /// then the lower ID.
+    pub fn rank(&self, tickets: Vec<TicketMetadata>) -> Vec<(TicketMetadata, ScoreBreakdown)> {
+        let mut scored: Vec<_> = tickets
+            .into_iter()
+            .map(|ticket| {
+                let score = self.score(&ticket);
+                (ticket, score)
+            })
+            .collect();
+        scored.sort_by(|(a, a_score), (b, b_score)| {
+            b_score
+                .total()
+                .total_cmp(&a_score.total())
+                .then_with(|| a.created.cmp(&b.created))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        scored
+    }
+}
+
+/// Urgency of each ticket in a phased plan: `1 / (n + 1)`, where `n` is the
+/// number of unfinished phases before the ticket's phase. A ticket in several
+/// plans takes its most urgent phase.
+fn phase_urgency(
+    plans: &[PlanMetadata],
+    ticket_map: &HashMap<String, TicketMetadata>,
+) -> HashMap<String, f64> {
+    let mut urgency: HashMap<String, f64> = HashMap::new();
+    for plan in plans.iter().filter(|p| p.is_phased()) {
+        let unfinished = plan.phases().into_iter().filter(|phase| {
+            phase.ticket_list.tickets.iter().any(|id| {
+                ticket_map
+                    .get(id)
+                    .is_some_and(|t| !t.status.is_some_and(|s| s.is_terminal()))
+            })
+        });
+        for (n, phase) in unfinished.enumerate() {
+            let value = 1.0 / (n as f64 + 1.0);
+            for id in &phase.ticket_list.tickets {
+                let entry = urgency.entry(id.clone()).or_insert(0.0);
+                *entry = entry.max(value);
+            }
+        }
+    }
+    urgency
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::parser::parse_plan_content;
+    use crate::types::{CreatedAt, TicketId, TicketPriority, TicketSize, TicketStatus};
+
+    fn ticket(id: &str, priority: TicketPriority, created: &str) -> TicketMetadata {
+        TicketMetadata {
+            id: Some(TicketId::new_unchecked(id)),
+            status: Some(TicketStatus::New),
+            priority: Some(priority),
+            created: Some(CreatedAt::new_unchecked(created)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_score_components() {
+        let now: Timestamp = "2024-01-15T00:00:00Z".parse().unwrap();
+        let mut t = ticket("j-a1b2", TicketPriority::P0, "2024-01-01T00:00:00Z");
+        t.size = Some(TicketSize::XSmall);
+        let scorer = ReadyScorer::new(ReadyConfig::default(), now, &[], &HashMap::new());
+
+        let score = scorer.score(&t);
+        assert_eq!(score.priority, 10.0);
+        assert_eq!(score.age, 2.0);
+        assert_eq!(score.phase, 0.0);
+        assert_eq!(score.size, 2.0);
+        assert_eq!(score.total(), 14.0);
+    }
+
+    #[test]
+    fn test_rank_uses_phase_urgency() {
+        let now: Timestamp = "2024-01-15T00:00:00Z".parse().unwrap();
+        let plan = parse_plan_content(
+            "---\nid: plan-a1b2\n---\n# Plan\n\n## Phase 1: First\n\n### Tickets\n\n1. j-done\n2. j-now\n\n## Phase 2: Later\n\n### Tickets\n\n1. j-later\n",
+        )
+        .unwrap();
+        let mut done = ticket("j-done", TicketPriority::P2, "2024-01-01T00:00:00Z");
+        done.status = Some(TicketStatus::Complete);
+        let tickets = vec![
+            ticket("j-later", TicketPriority::P2, "2024-01-01T00:00:00Z"),
+            ticket("j-now", TicketPriority::P2, "2024-01-01T00:00:00Z"),
+            ticket("j-loose", TicketPriority::P2, "2024-01-01T00:00:00Z"),
+        ];
+        let mut ticket_map: HashMap<String, TicketMetadata> = tickets
+            .iter()
+            .map(|t| (t.id.as_deref().unwrap().to_string(), t.clone()))
+            .collect();
+        ticket_map.insert("j-done".to_string(), done);
+
+        let scorer = ReadyScorer::new(ReadyConfig::default(), now, &[plan], &ticket_map);
+        let ranked: Vec<String> = scorer
+            .rank(tickets)
+            .into_iter()
+            .map(|(t, _)| t.id.unwrap().to_string())
+            .collect();
+        assert_eq!(ranked, vec!["j-now", "j-later", "j-loose"]);
+
+        let policy = ReadyConfig {
+            phase_weight: 0.0,
+            ..ReadyConfig::default()
+        };
+        let scorer = ReadyScorer::new(policy, now, &[], &ticket_map);
+        assert_eq!(scorer.score(&ticket_map["j-now"]).phase, 0.0);
+    }
+}
@@ -0,0 +1,234 @@
+//! On-disk cache of fetched remote issues (`.janus/remote-cache/`).
+//!
+//! Each issue fetched by reference is saved with the time it was fetched, and
+//! so is the first page of every remote TUI listing. `janus remote sync --all`
+//! reuses an issue for `remotes.cache_ttl` before fetching it again, and both
+//! it and the remote TUI fall back to cached data when the remote cannot be
+//! reached, reporting how old that data is.
+//!
+//! Like the embeddings cache, the files can be deleted at any time; writes are
+//! best-effort and never fail the operation that produced the data.
+
+use std::fs;
+use std::path::PathBuf;
+
+use jiff::{SignedDuration, Timestamp};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::{PaginatedResult, Platform, Provider, RemoteIssue, RemoteProvider, RemoteRef};
+use crate::error::{JanusError, Result};
+use crate::types::janus_root;
+
+/// Directory name for the cache within the Janus root.
+const REMOTE_CACHE_DIR: &str = "remote-cache";
+
+/// A cached value and when it was fetched
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cached<T> {
+    pub fetched_at: Timestamp,
+    pub value: T,
+}
+
+impl<T> Cached<T> {
+    /// Time since the value was fetched
+    pub fn age(&self, now: Timestamp) -> SignedDuration {
+        now.duration_since(self.fetched_at)
+    }
+}
+
+/// A cached page of a remote listing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedList {
+    pub issues: Vec<RemoteIssue>,
+    pub has_more: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<u64>,
+}
+
+impl CachedList {
+    pub fn into_paginated(self) -> PaginatedResult<RemoteIssue> {
+        let result = PaginatedResult::new(self.issues, self.has_more);
+        match self.total_count {
+            Some(count) => result.with_total_count(count),
+            None => result,
+        }
+    }
+}
+
+/// Where an issue returned by [`fetch_issue_cached`] came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Freshness {
+    /// Fetched from the remote just now
+    Live,
+    /// Read from the cache because it was fetched within the TTL
+    Cached,
+    /// Read from the cache because the remote could not be reached
+    Stale,
+}
+
+/// Return path of the remote cache directory.
+pub fn remote_cache_dir() -> PathBuf {
+    janus_root().join(REMOTE_CACHE_DIR)
+}
+
+fn entry_path(kind: &str, key: &str) -> PathBuf {
+    let hash = blake3::hash(key.as_bytes()).to_hex();
+    remote_cache_dir()
+        .join(kind)
+        .join(format!("{}.json", &hash[..32]))
+}
+
+fn load<T: DeserializeOwned>(kind: &str, key: &str) -> Option<Cached<T>> {
+    let content = fs::read_to_string(entry_path(kind, key)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn store<T: Serialize>(kind: &str, key: &str, value: &T) {
+    let path = entry_path(kind, key);
+    let entry = Cached {
+        fetched_at: Timestamp::now(),
+        value,
+    };
+    let Ok(content) = serde_json::to_string(&entry) else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    crate::utils::ensure_gitignore();
+    let _ = fs::write(path, content);
+}
+
+/// The cached copy of an issue, if any
+pub fn load_issue(remote_ref: &RemoteRef) -> Option<Cached<RemoteIssue>> {
+    load("issues", &remote_ref.to_string())
+}
+
+/// Save a freshly fetched issue
+pub fn store_issue(remote_ref: &RemoteRef, issue: &RemoteIssue) {
+    store("issues", &remote_ref.to_string(), issue);
+}
+
+/// The cached first page of a listing on `platform`, for browsing when
+/// `search` is `None` and for a search otherwise
+pub fn load_list(platform: Platform, search: Option<&str>) -> Option<Cached<CachedList>> {
+    load("lists", &list_key(platform, search))
+}
+
+/// Save the first page of a listing
+pub fn store_list(platform: Platform, search: Option<&str>, page: &PaginatedResult<RemoteIssue>) {
+    let list = CachedList {
+        issues: page.items.clone(),
+        has_more: page.has_more,
+        total_count: page.total_count,
+    };
+    store("lists", &list_key(platform, search), &list);
+}
+
+fn list_key(platform: Platform, search: Option<&str>) -> String {
+    match search {
+        Some(text) => format!("{platform}\0search\0{}", text.trim().to_lowercase()),
+        None => format!("{platform}\0browse"),
+    }
+}
+
+/// Whether an error means the remote could not be reached (or would not
+/// answer), so cached data is the best available, as opposed to the request
+/// itself being wrong
+pub fn is_unreachable(error: &JanusError) -> bool {
+    matches!(
+        error,
+        JanusError::RemoteTimeout { .. }
+            | JanusError::Http(_)
+            | JanusError::RateLimited(_)
+            | JanusError::Api(_)
+    )
+}
+
+/// Fetch an issue, reusing the cached copy while it is younger than `ttl`.
+///
+/// Successful fetches refresh the cache. If the remote cannot be reached, the
+/// cached copy is returned whatever its age, marked [`Freshness::Stale`].
+pub async fn fetch_issue_cached(
+    provider: &Provider,
+    remote_ref: &RemoteRef,
+    ttl: SignedDuration,
+) -> Result<(Cached<RemoteIssue>, Freshness)> {
+    let cached = load_issue(remote_ref);
+    if let Some(cached) = cached.as_ref().filter(|c| c.age(Timestamp::now()) < ttl) {
+        return Ok((cached.clone(), Freshness::Cached));
+    }
+
+    match provider.fetch_issue(remote_ref).await {
+        Ok(issue) => {
+            store_issue(remote_ref, &issue);
+            Ok((
+                Cached {
+                    fetched_at: Timestamp::now(),
+                    value: issue,
+                },
+                Freshness::Live,
+            ))
+        }
+        Err(e) if is_unreachable(&e) => match cached {
+            Some(cached) => Ok((cached, Freshness::Stale)),
+            None => Err(e),
+        },
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths::JanusRootGuard;
+    use crate::remote::RemoteStatus;
+    use tempfile::TempDir;
+
+    fn issue(title: &str) -> RemoteIssue {
+        RemoteIssue {
+            id: "ENG-12".to_string(),
+            title: title.to_string(),
+            body: String::new(),
+            status: RemoteStatus::Open,
+            priority: None,
+            assignee: None,
+            assignee_aliases: Vec::new(),
+            updated_at: "2026-01-01T00:00:00Z".to_string(),
+            url: "https://linear.app/acme/issue/ENG-12".to_string(),
+            labels: vec![],
+            team: None,
+            project: None,
+            milestone: None,
+            cycle: None,
+            due_date: None,
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            creator: None,
+        }
+    }
+
+    #[test]
+    fn test_issue_and_list_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let _guard = JanusRootGuard::new(tmp.path().join(".janus"));
+        let remote_ref = RemoteRef::Linear {
+            org: "acme".to_string(),
+            issue_id: "ENG-12".to_string(),
+        };
+
+        assert!(load_issue(&remote_ref).is_none());
+        store_issue(&remote_ref, &issue("Fix login"));
+        let cached = load_issue(&remote_ref).unwrap();
+        assert_eq!(cached.value.title, "Fix login");
+        assert!(cached.age(Timestamp::now()) < SignedDuration::from_mins(1));
+
+        let page = PaginatedResult::new(vec![issue("Fix login")], true);
+        store_list(Platform::Linear, Some("Login"), &page);
+        assert!(load_list(Platform::Linear, None).is_none());
+        assert!(load_list(Platform::GitHub, Some("login")).is_none());
+        let list = load_list(Platform::Linear, Some("login")).unwrap();
+        assert!(list.value.has_more);
+        assert_eq!(list.value.into_paginated().items.len(), 1);
+    }
+}
@@ -4,9 +4,14 @@
 //! remote settings). The main `Config` struct and non-remote types like
 //! `HooksConfig` and `SemanticSearchConfig` live in `crate::config`.
 
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
 use serde::{Deserialize, Serialize};
 
+use super::{IssueUpdates, RemoteIssue, RemoteStatus};
 use crate::error::{JanusError, Result};
+use crate::types::TicketStatus;
 
 /// Default remote configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +25,445 @@ pub struct DefaultRemote {
     pub repo: Option<String>,
 }
 
+/// Network settings for remote API clients.
+///
+/// `HTTPS_PROXY`/`HTTP_PROXY`/`NO_PROXY` (and their lowercase forms) are honored
+/// from the environment; `proxy` and `no_proxy` here take precedence over them.
+///
+/// ```yaml
+/// network:
+///   proxy: http://proxy.corp.example:3128
+///   no_proxy: localhost,.corp.example
+///   ca_bundle: /etc/ssl/certs/corp-ca.pem
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkConfig {
+    /// Proxy URL used for all remote requests (overrides `HTTPS_PROXY`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+
+    /// Comma-separated hosts that bypass `proxy` or the environment proxies
+    /// (overrides `NO_PROXY`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_proxy: Option<String>,
+
+    /// PEM bundle of additional trusted CA certificates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_bundle: Option<PathBuf>,
+}
+
+impl NetworkConfig {
+    pub fn is_default(&self) -> bool {
+        self.proxy.is_none() && self.no_proxy.is_none() && self.ca_bundle.is_none()
+    }
+}
+
+/// Mapping between local ticket labels and remote issue labels.
+///
+/// Local labels are restricted to lowercase letters, digits, and underscores,
+/// while remote labels are free-form, so `map` translates between the two.
+/// Labels without an entry pass through unchanged on push and are normalized
+/// (lowercased, other characters replaced by `_`) when pulled.
+///
+/// ```yaml
+/// labels:
+///   map:
+///     bug: "type: bug"
+///     needs_design: "Needs Design"
+///   create_missing: true
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelMappingConfig {
+    /// Local label -> remote label name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub map: BTreeMap<String, String>,
+
+    /// Create remote labels that do not exist yet when pushing (default: true).
+    /// When false, labels missing on the remote are dropped instead.
+    #[serde(default = "default_create_missing_labels")]
+    pub create_missing: bool,
+}
+
+fn default_create_missing_labels() -> bool {
+    true
+}
+
+impl Default for LabelMappingConfig {
+    fn default() -> Self {
+        Self {
+            map: BTreeMap::new(),
+            create_missing: default_create_missing_labels(),
+        }
+    }
+}
+
+impl LabelMappingConfig {
+    pub fn is_default(&self) -> bool {
+        self.map.is_empty() && self.create_missing == default_create_missing_labels()
+    }
+
+    /// Translate local labels to remote label names, preserving order.
+    pub fn to_remote(&self, local: &[String]) -> Vec<String> {
+        let mut remote: Vec<String> = Vec::with_capacity(local.len());
+        for label in local {
+            let mapped = self
+                .map
+                .get(label)
+                .cloned()
+                .unwrap_or_else(|| label.clone());
+            if !remote.iter().any(|r| r.eq_ignore_ascii_case(&mapped)) {
+                remote.push(mapped);
+            }
+        }
+        remote
+    }
+
+    /// Translate remote label names to local labels (sorted, deduplicated).
+    ///
+    /// Mapped names match case-insensitively. Labels that normalize to nothing
+    /// (e.g. emoji-only) are dropped.
+    pub fn to_local(&self, remote: &[String]) -> Vec<String> {
+        let mut local: Vec<String> = remote
+            .iter()
+            .filter_map(|label| {
+                self.map
+                    .iter()
+                    .find(|(_, r)| r.eq_ignore_ascii_case(label))
+                    .map(|(l, _)| l.clone())
+                    .or_else(|| normalize_label(label))
+            })
+            .collect();
+        local.sort();
+        local.dedup();
+        local
+    }
+}
+
+/// One person's identity locally and on each remote platform.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserMapping {
+    /// Name used in the ticket `assignee` field
+    pub name: String,
+    /// GitHub login
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github: Option<String>,
+    /// Linear user (matched against name, display name, or email)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub linear: Option<String>,
+}
+
+/// Mapping between local assignee names and remote users.
+///
+/// Names without an entry pass through unchanged, so an unmapped assignee
+/// still survives a round trip when the local name equals the remote one.
+///
+/// ```yaml
+/// users:
+///   - name: alice
+///     github: alice-gh
+///     linear: alice@example.com
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UserMappingConfig(pub Vec<UserMapping>);
+
+impl UserMappingConfig {
+    pub fn is_default(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Translate a local assignee name to the platform's user identifier.
+    pub fn to_remote(&self, platform: Platform, local: &str) -> String {
+        self.0
+            .iter()
+            .find(|u| u.name.eq_ignore_ascii_case(local))
+            .and_then(|u| u.remote(platform))
+            .unwrap_or(local)
+            .to_string()
+    }
+
+    /// Translate a remote issue's assignee to a local assignee name.
+    ///
+    /// The configured identifier may match the assignee or any of its aliases
+    /// (a Linear user's display name or email); an unmapped assignee passes
+    /// through as the platform's name for it.
+    pub fn to_local(&self, platform: Platform, issue: &RemoteIssue) -> Option<String> {
+        let assignee = issue.assignee.as_deref()?;
+        let mapped = self.0.iter().find(|u| {
+            u.remote(platform).is_some_and(|r| {
+                std::iter::once(assignee)
+                    .chain(issue.assignee_aliases.iter().map(String::as_str))
+                    .any(|id| id.eq_ignore_ascii_case(r))
+            })
+        });
+        Some(mapped.map_or(assignee, |u| u.name.as_str()).to_string())
+    }
+}
+
+impl UserMapping {
+    fn remote(&self, platform: Platform) -> Option<&str> {
+        match platform {
+            Platform::GitHub => self.github.as_deref(),
+            Platform::Linear => self.linear.as_deref(),
+        }
+    }
+}
+
+/// Mapping between Janus statuses and remote issue states.
+///
+/// GitHub issues are only open or closed, so a GitHub entry names a label that
+/// marks the status; the issue itself is closed for terminal statuses and open
+/// otherwise. A Linear entry names a workflow state of the issue's team.
+/// Statuses without an entry fall back to the built-in open/closed mapping.
+///
+/// ```yaml
+/// status_mapping:
+///   github:
+///     next: "status: next"
+///     in_progress: "status: in progress"
+///   linear:
+///     new: Backlog
+///     next: Todo
+///     in_progress: In Progress
+///     complete: Done
+///     cancelled: Canceled
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusMappingConfig {
+    /// Janus status -> GitHub label
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub github: BTreeMap<TicketStatus, String>,
+
+    /// Janus status -> Linear workflow state name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub linear: BTreeMap<TicketStatus, String>,
+}
+
+impl StatusMappingConfig {
+    pub fn is_default(&self) -> bool {
+        self.github.is_empty() && self.linear.is_empty()
+    }
+
+    fn entries(&self, platform: Platform) -> &BTreeMap<TicketStatus, String> {
+        match platform {
+            Platform::GitHub => &self.github,
+            Platform::Linear => &self.linear,
+        }
+    }
+
+    /// The status whose entry matches the issue's remote state, if any.
+    ///
+    /// A GitHub label only counts when the issue's open/closed state agrees
+    /// with the status, so closing an issue without touching its labels is
+    /// still picked up.
+    fn mapped_status(&self, platform: Platform, issue: &RemoteIssue) -> Option<TicketStatus> {
+        let entries = self.entries(platform);
+        match platform {
+            Platform::GitHub => {
+                let closed = issue.status == RemoteStatus::Closed;
+                entries
+                    .iter()
+                    .find(|(status, label)| {
+                        status.is_terminal() == closed
+                            && issue.labels.iter().any(|l| l.eq_ignore_ascii_case(label))
+                    })
+                    .map(|(status, _)| *status)
+            }
+            Platform::Linear => {
+                let RemoteStatus::Custom(name) = &issue.status else {
+                    return None;
+                };
+                entries
+                    .iter()
+                    .find(|(_, state)| state.eq_ignore_ascii_case(name))
+                    .map(|(status, _)| *status)
+            }
+        }
+    }
+
+    /// Convert a remote issue's state to a Janus status.
+    pub fn to_local(&self, platform: Platform, issue: &RemoteIssue) -> TicketStatus {
+        self.mapped_status(platform, issue)
+            .unwrap_or_else(|| issue.status.to_ticket_status())
+    }
+
+    /// Like [`Self::to_local`], but unmapped states fall back to
+    /// [`RemoteStatus::resolve_with_local`] so an ambiguous open/closed state
+    /// does not overwrite a more specific local status.
+    pub fn resolve_with_local(
+        &self,
+        platform: Platform,
+        issue: &RemoteIssue,
+        local: TicketStatus,
+    ) -> TicketStatus {
+        self.mapped_status(platform, issue)
+            .unwrap_or_else(|| issue.status.resolve_with_local(local))
+    }
+
+    /// Remote updates that move an issue to `status`.
+    pub fn to_remote(&self, platform: Platform, status: TicketStatus) -> IssueUpdates {
+        match platform {
+            Platform::GitHub => IssueUpdates {
+                status: Some(RemoteStatus::from_ticket_status(status)),
+                // An empty label clears the previous status label
+                status_label: (!self.github.is_empty())
+                    .then(|| self.github.get(&status).cloned().unwrap_or_default()),
+                ..Default::default()
+            },
+            Platform::Linear => IssueUpdates {
+                status: Some(
+                    self.linear
+                        .get(&status)
+                        .map(|state| RemoteStatus::Custom(state.clone()))
+                        .unwrap_or_else(|| RemoteStatus::from_ticket_status(status)),
+                ),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Remote labels without the GitHub labels that mark a status, which are
+    /// synced as the status rather than as ordinary labels.
+    pub fn strip_status_labels(&self, platform: Platform, labels: &[String]) -> Vec<String> {
+        let status_labels = self.entries(platform);
+        if platform != Platform::GitHub || status_labels.is_empty() {
+            return labels.to_vec();
+        }
+        labels
+            .iter()
+            .filter(|l| !status_labels.values().any(|s| s.eq_ignore_ascii_case(l)))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Pull request integration settings.
+///
+/// ```yaml
+/// pull_requests:
+///   complete_on_merge: true
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PullRequestConfig {
+    /// Complete a ticket during `janus remote sync` once a linked pull request
+    /// has merged
+    #[serde(default)]
+    pub complete_on_merge: bool,
+}
+
+impl PullRequestConfig {
+    pub fn is_default(&self) -> bool {
+        !self.complete_on_merge
+    }
+}
+
+/// Per-platform sync behavior.
+///
+/// In `mirror` mode the remote is the source of truth for the synced fields
+/// (title, status, labels, assignee): `janus remote sync` only pulls, and local
+/// edits to those fields are blocked, or allowed with a warning when
+/// `local_edits` is `warn`.
+///
+/// ```yaml
+/// remotes:
+///   linear:
+///     mode: mirror
+///     local_edits: warn
+///   cache_ttl: 5m
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemotesConfig {
+    #[serde(default, skip_serializing_if = "RemoteSettings::is_default")]
+    pub github: RemoteSettings,
+
+    #[serde(default, skip_serializing_if = "RemoteSettings::is_default")]
+    pub linear: RemoteSettings,
+
+    /// How long a fetched issue is reused before it is fetched again
+    /// (e.g. `5m`; `0m` always refetches). Default: 5 minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_ttl: Option<String>,
+}
+
+impl RemotesConfig {
+    pub fn is_default(&self) -> bool {
+        self.github.is_default() && self.linear.is_default() && self.cache_ttl.is_none()
+    }
+
+    /// How long a cached remote issue counts as fresh
+    pub fn cache_ttl(&self) -> Result<jiff::SignedDuration> {
+        match self.cache_ttl.as_deref() {
+            Some(ttl) => crate::utils::parse_duration(ttl),
+            None => Ok(jiff::SignedDuration::from_mins(5)),
+        }
+    }
+
+    pub fn for_platform(&self, platform: Platform) -> &RemoteSettings {
+        match platform {
+            Platform::GitHub => &self.github,
+            Platform::Linear => &self.linear,
+        }
+    }
+
+    /// Whether tickets on `platform` mirror their remote issue
+    pub fn is_mirror(&self, platform: Platform) -> bool {
+        self.for_platform(platform).mode == RemoteMode::Mirror
+    }
+}
+
+/// Sync settings for one platform
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteSettings {
+    #[serde(default)]
+    pub mode: RemoteMode,
+
+    /// What happens to local edits of mirrored fields (mirror mode only)
+    #[serde(default)]
+    pub local_edits: LocalEditPolicy,
+}
+
+impl RemoteSettings {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Which side of a sync is authoritative
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RemoteMode {
+    /// Differences are resolved field by field, in either direction
+    #[default]
+    Sync,
+    /// The remote is the source of truth; sync only pulls
+    Mirror,
+}
+
+/// How local edits to mirrored fields are treated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LocalEditPolicy {
+    /// The edit is rejected
+    #[default]
+    Block,
+    /// The edit is written with a warning; the next sync overwrites it
+    Warn,
+}
+
+/// Normalize a free-form label to the local label format.
+fn normalize_label(label: &str) -> Option<String> {
+    let mut normalized = String::with_capacity(label.len());
+    for c in label.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            normalized.push(c.to_ascii_lowercase());
+        } else if !normalized.ends_with('_') {
+            normalized.push('_');
+        }
+    }
+    let normalized = normalized.trim_matches('_');
+    (!normalized.is_empty()).then(|| normalized.to_string())
+}
+
 /// Supported remote platforms
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -69,4 +513,255 @@ mod tests {
         assert_eq!("Linear".parse::<Platform>().unwrap(), Platform::Linear);
         assert!("invalid".parse::<Platform>().is_err());
     }
+
+    fn mapping() -> LabelMappingConfig {
+        LabelMappingConfig {
+            map: BTreeMap::from([
+                ("bug".to_string(), "type: bug".to_string()),
+                ("needs_design".to_string(), "Needs Design".to_string()),
+            ]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_label_mapping_to_remote() {
+        let local = vec![
+            "bug".to_string(),
+            "backend".to_string(),
+            "needs_design".to_string(),
+        ];
+        assert_eq!(
+            mapping().to_remote(&local),
+            vec!["type: bug", "backend", "Needs Design"]
+        );
+    }
+
+    #[test]
+    fn test_label_mapping_to_local() {
+        let remote = vec![
+            "Type: Bug".to_string(),
+            "Good First Issue".to_string(),
+            "needs design".to_string(),
+            "🚀".to_string(),
+        ];
+        assert_eq!(
+            mapping().to_local(&remote),
+            vec!["bug", "good_first_issue", "needs_design"]
+        );
+    }
+
+    #[test]
+    fn test_label_mapping_round_trip() {
+        let local = vec!["bug".to_string(), "needs_design".to_string()];
+        let mapping = mapping();
+        assert_eq!(mapping.to_local(&mapping.to_remote(&local)), local);
+    }
+
+    fn users() -> UserMappingConfig {
+        UserMappingConfig(vec![UserMapping {
+            name: "alice".to_string(),
+            github: Some("alice-gh".to_string()),
+            linear: Some("Alice Smith".to_string()),
+        }])
+    }
+
+    #[test]
+    fn test_user_mapping_to_remote() {
+        let users = users();
+        assert_eq!(users.to_remote(Platform::GitHub, "alice"), "alice-gh");
+        assert_eq!(users.to_remote(Platform::Linear, "Alice"), "Alice Smith");
+        assert_eq!(users.to_remote(Platform::GitHub, "bob"), "bob");
+    }
+
+    #[test]
+    fn test_user_mapping_to_local() {
+        let users = users();
+        let assigned = |name: &str| RemoteIssue {
+            assignee: Some(name.to_string()),
+            ..issue(RemoteStatus::Open, &[])
+        };
+
+        assert_eq!(
+            users
+                .to_local(Platform::GitHub, &assigned("Alice-GH"))
+                .as_deref(),
+            Some("alice")
+        );
+        assert_eq!(
+            users
+                .to_local(Platform::Linear, &assigned("Alice Smith"))
+                .as_deref(),
+            Some("alice")
+        );
+        assert_eq!(
+            users
+                .to_local(Platform::Linear, &assigned("alice-gh"))
+                .as_deref(),
+            Some("alice-gh")
+        );
+        assert_eq!(
+            users.to_local(Platform::Linear, &issue(RemoteStatus::Open, &[])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_user_mapping_to_local_matches_linear_aliases() {
+        let users = UserMappingConfig(vec![UserMapping {
+            name: "alice".to_string(),
+            github: None,
+            linear: Some("alice@example.com".to_string()),
+        }]);
+        let issue = RemoteIssue {
+            assignee: Some("Alice Smith".to_string()),
+            assignee_aliases: vec!["alice".to_string(), "Alice@Example.com".to_string()],
+            ..issue(RemoteStatus::Open, &[])
+        };
+        assert_eq!(
+            users.to_local(Platform::Linear, &issue).as_deref(),
+            Some("alice")
+        );
+    }
+
+    #[test]
+    fn test_user_mapping_yaml() {
+        let yaml = "- name: alice\n  github: alice-gh\n";
+        let users: UserMappingConfig = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(users.0.len(), 1);
+        assert_eq!(users.0[0].linear, None);
+    }
+
+    fn status_mapping() -> StatusMappingConfig {
+        serde_yaml_ng::from_str(
+            "github:\n  in_progress: \"status: in progress\"\n  cancelled: wontfix\nlinear:\n  next: Todo\n  in_progress: Doing\n",
+        )
+        .unwrap()
+    }
+
+    fn issue(status: RemoteStatus, labels: &[&str]) -> RemoteIssue {
+        RemoteIssue {
+            id: "1".to_string(),
+            title: "Issue".to_string(),
+            body: String::new(),
+            status,
+            priority: None,
+            assignee: None,
+            assignee_aliases: Vec::new(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+            url: String::new(),
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+            team: None,
+            project: None,
+            milestone: None,
+            cycle: None,
+            due_date: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            creator: None,
+        }
+    }
+
+    #[test]
+    fn test_status_mapping_github_to_local() {
+        let mapping = status_mapping();
+        let open = issue(RemoteStatus::Open, &["Status: In Progress"]);
+        assert_eq!(
+            mapping.to_local(Platform::GitHub, &open),
+            TicketStatus::InProgress
+        );
+
+        // A closed issue still carrying the in-progress label is complete
+        let closed = issue(RemoteStatus::Closed, &["status: in progress"]);
+        assert_eq!(
+            mapping.to_local(Platform::GitHub, &closed),
+            TicketStatus::Complete
+        );
+
+        let cancelled = issue(RemoteStatus::Closed, &["wontfix"]);
+        assert_eq!(
+            mapping.resolve_with_local(Platform::GitHub, &cancelled, TicketStatus::New),
+            TicketStatus::Cancelled
+        );
+    }
+
+    #[test]
+    fn test_status_mapping_linear_to_local() {
+        let mapping = status_mapping();
+        let todo = issue(RemoteStatus::Custom("todo".to_string()), &[]);
+        assert_eq!(
+            mapping.to_local(Platform::Linear, &todo),
+            TicketStatus::Next
+        );
+
+        // Unmapped states use the built-in name matching
+        let review = issue(RemoteStatus::Custom("In Review".to_string()), &[]);
+        assert_eq!(
+            mapping.to_local(Platform::Linear, &review),
+            TicketStatus::New
+        );
+    }
+
+    #[test]
+    fn test_status_mapping_to_remote() {
+        let mapping = status_mapping();
+
+        let github = mapping.to_remote(Platform::GitHub, TicketStatus::InProgress);
+        assert_eq!(github.status, Some(RemoteStatus::Open));
+        assert_eq!(github.status_label.as_deref(), Some("status: in progress"));
+
+        let github = mapping.to_remote(Platform::GitHub, TicketStatus::Complete);
+        assert_eq!(github.status, Some(RemoteStatus::Closed));
+        assert_eq!(github.status_label.as_deref(), Some(""));
+
+        let linear = mapping.to_remote(Platform::Linear, TicketStatus::InProgress);
+        assert_eq!(
+            linear.status,
+            Some(RemoteStatus::Custom("Doing".to_string()))
+        );
+        assert_eq!(linear.status_label, None);
+
+        let unmapped =
+            StatusMappingConfig::default().to_remote(Platform::GitHub, TicketStatus::Next);
+        assert_eq!(unmapped.status, Some(RemoteStatus::Open));
+        assert_eq!(unmapped.status_label, None);
+    }
+
+    #[test]
+    fn test_status_mapping_strip_status_labels() {
+        let mapping = status_mapping();
+        let labels = vec!["bug".to_string(), "WontFix".to_string()];
+        assert_eq!(
+            mapping.strip_status_labels(Platform::GitHub, &labels),
+            vec!["bug"]
+        );
+        assert_eq!(
+            mapping.strip_status_labels(Platform::Linear, &labels),
+            labels
+        );
+    }
+
+    #[test]
+    fn test_label_mapping_default_creates_missing() {
+        let config: LabelMappingConfig = serde_yaml_ng::from_str("map: {}").unwrap();
+        assert!(config.create_missing);
+        assert!(config.is_default());
+    }
+
+    #[test]
+    fn test_remotes_cache_ttl() {
+        let config = RemotesConfig::default();
+        assert_eq!(
+            config.cache_ttl().unwrap(),
+            jiff::SignedDuration::from_mins(5)
+        );
+
+        let config: RemotesConfig = serde_yaml_ng::from_str("cache_ttl: 2h").unwrap();
+        assert!(!config.is_default());
+        assert_eq!(
+            config.cache_ttl().unwrap(),
+            jiff::SignedDuration::from_hours(2)
+        );
+        let config: RemotesConfig = serde_yaml_ng::from_str("cache_ttl: soon").unwrap();
+        assert!(config.cache_ttl().is_err());
+    }
 }
@@ -10,6 +10,9 @@ use tracing::warn;
 use crate::config::Config;
 use crate::error::{JanusError, Result};
 
+use super::config::NetworkConfig;
+use super::http::build_github_client;
+use super::pull_request::{PullRequest, PullRequestRef, PullRequestState, mentions_ticket};
 use super::{
     AsHttpError, IssueUpdates, PaginatedResult, RemoteIssue, RemoteProvider, RemoteQuery,
     RemoteRef, RemoteStatus,
@@ -27,8 +30,15 @@ pub struct GitHubProvider {
     default_repo: Option<String>,
     /// Timeout for remote operations
     timeout: std::time::Duration,
+    /// Whether to create labels that don't exist in the repository on update
+    create_missing_labels: bool,
+    /// Labels that mark a status (from `status_mapping.github`)
+    status_labels: Vec<String>,
 }
 
+/// Color given to labels created by Janus (GitHub requires one)
+const NEW_LABEL_COLOR: &str = "ededed";
+
 impl fmt::Debug for GitHubProvider {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("GitHubProvider")
@@ -44,21 +54,16 @@ impl GitHubProvider {
     /// Create a new GitHub provider from configuration
     ///
     /// Note: Octocrab 0.48 does not support custom HTTP client timeout configuration.
-    /// This uses Octocrab's default HTTP client configuration.
+    /// This uses Octocrab's default HTTP client unless a proxy or CA bundle applies
+    /// (see [`super::http::build_github_client`]).
     pub fn from_config(config: &Config) -> Result<Self> {
-        let token = config.github_token().ok_or_else(|| {
+        let token = config.github_token()?.ok_or_else(|| {
             JanusError::Auth(
                 "GitHub token not configured. Set GITHUB_TOKEN environment variable or run: janus config set github.token <token>".to_string()
             )
         })?;
 
-        let client = Octocrab::builder()
-            .personal_token(token.clone())
-            .build()
-            .map_err(|e| {
-                let scrubbed = scrub_token_from_error(&e.to_string(), &token);
-                JanusError::Api(format!("Failed to create GitHub client: {scrubbed}"))
-            })?;
+        let client = build_github_client(&token, &config.network, config.remote_timeout())?;
 
         let (default_owner, default_repo) = if let Some(ref default) = config.default_remote {
             if default.platform == super::Platform::GitHub {
@@ -76,6 +81,8 @@ impl GitHubProvider {
             default_owner,
             default_repo,
             timeout: config.remote_timeout(),
+            create_missing_labels: config.labels.create_missing,
+            status_labels: config.status_mapping.github.values().cloned().collect(),
         })
     }
 
@@ -89,20 +96,17 @@ impl GitHubProvider {
         }
 
         let token_owned = token.to_string();
-        let client = Octocrab::builder()
-            .personal_token(token_owned.clone())
-            .build()
-            .map_err(|e| {
-                let scrubbed = scrub_token_from_error(&e.to_string(), &token_owned);
-                JanusError::Api(format!("Failed to create GitHub client: {scrubbed}"))
-            })?;
+        let timeout = std::time::Duration::from_secs(30);
+        let client = build_github_client(&token_owned, &NetworkConfig::default(), timeout)?;
 
         Ok(Self {
             client,
             token: SecretBox::new(Box::new(token_owned)),
             default_owner: None,
             default_repo: None,
-            timeout: std::time::Duration::from_secs(30),
+            timeout,
+            create_missing_labels: true,
+            status_labels: Vec::new(),
         })
     }
 
@@ -131,13 +135,200 @@ impl GitHubProvider {
     }
 }
 
-impl GitHubProvider {}
+impl GitHubProvider {
+    /// Resolve label names against the repository's labels.
+    ///
+    /// Names match case-insensitively and take the repository's spelling.
+    /// Missing labels are created, or dropped with a warning when
+    /// `labels.create_missing` is disabled.
+    async fn ensure_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        labels: Vec<String>,
+    ) -> Result<Vec<String>> {
+        if labels.is_empty() {
+            return Ok(labels);
+        }
+
+        let client = self.client.clone();
+        let timeout = self.timeout;
+        let existing = super::execute_with_retry(
+            || async {
+                let page = client
+                    .issues(owner, repo)
+                    .list_labels_for_repo()
+                    .per_page(100)
+                    .send()
+                    .await
+                    .map_err(GitHubError::from)?;
+                client.all_pages(page).await.map_err(GitHubError::from)
+            },
+            Some(timeout),
+        )
+        .await?;
+        let existing: Vec<String> = existing.into_iter().map(|l| l.name).collect();
+
+        let mut resolved = Vec::with_capacity(labels.len());
+        for label in labels {
+            if let Some(name) = existing.iter().find(|e| e.eq_ignore_ascii_case(&label)) {
+                resolved.push(name.clone());
+            } else if self.create_missing_labels {
+                let created = super::execute_with_retry(
+                    || async {
+                        client
+                            .issues(owner, repo)
+                            .create_label(&label, NEW_LABEL_COLOR, "")
+                            .await
+                            .map_err(GitHubError::from)
+                    },
+                    Some(timeout),
+                )
+                .await?;
+                resolved.push(created.name);
+            } else {
+                warn!("Skipping label '{label}': it does not exist in {owner}/{repo}");
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Combine a label replacement and a status label change into the final
+    /// label set, so replacing labels keeps the issue's status label and
+    /// changing the status keeps its other labels.
+    async fn merge_status_label(
+        &self,
+        remote_ref: &RemoteRef,
+        labels: Option<Vec<String>>,
+        status_label: Option<String>,
+    ) -> Result<Option<Vec<String>>> {
+        if self.status_labels.is_empty() || (labels.is_none() && status_label.is_none()) {
+            return Ok(labels);
+        }
+
+        let current = if labels.is_none() || status_label.is_none() {
+            self.fetch_issue(remote_ref).await?.labels
+        } else {
+            Vec::new()
+        };
+        let status_label = status_label.or_else(|| {
+            current
+                .iter()
+                .find(|l| self.status_labels.iter().any(|s| s.eq_ignore_ascii_case(l)))
+                .cloned()
+        });
+
+        Ok(Some(apply_status_label(
+            labels.unwrap_or(current),
+            status_label.as_deref(),
+            &self.status_labels,
+        )))
+    }
+}
+
+impl GitHubProvider {
+    /// Fetch a pull request and its current state.
+    pub async fn fetch_pull_request(&self, pr: &PullRequestRef) -> Result<PullRequest> {
+        let client = self.client.clone();
+        let timeout = self.timeout;
+        let pull = super::execute_with_retry(
+            || async {
+                client
+                    .pulls(&pr.owner, &pr.repo)
+                    .get(pr.number)
+                    .await
+                    .map_err(GitHubError::from)
+            },
+            Some(timeout),
+        )
+        .await?;
+
+        let state = if pull.merged_at.is_some() {
+            PullRequestState::Merged
+        } else if matches!(pull.state, Some(octocrab::models::IssueState::Closed)) {
+            PullRequestState::Closed
+        } else {
+            PullRequestState::Open
+        };
+
+        Ok(PullRequest {
+            reference: pr.clone(),
+            title: pull.title.unwrap_or_default(),
+            state,
+            url: pull
+                .html_url
+                .map(|u| u.to_string())
+                .unwrap_or_else(|| pr.web_url()),
+        })
+    }
+
+    /// Find pull requests in `owner/repo` whose title or body mentions `ticket_id`.
+    ///
+    /// Uses the search API, so only the first 100 matches are considered.
+    pub async fn find_pull_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+        ticket_id: &str,
+    ) -> Result<Vec<PullRequestRef>> {
+        let client = self.client.clone();
+        let timeout = self.timeout;
+        let query = format!("\"{ticket_id}\" repo:{owner}/{repo} is:pr");
+        let page = super::execute_with_retry(
+            || async {
+                client
+                    .search()
+                    .issues_and_pull_requests(&query)
+                    .per_page(100)
+                    .send()
+                    .await
+                    .map_err(GitHubError::from)
+            },
+            Some(timeout),
+        )
+        .await?;
+
+        // Search matches loosely (e.g. on tokens of the ID), so confirm the mention
+        Ok(page
+            .items
+            .into_iter()
+            .filter(|issue| {
+                issue.pull_request.is_some()
+                    && (mentions_ticket(&issue.title, ticket_id)
+                        || issue
+                            .body
+                            .as_deref()
+                            .is_some_and(|b| mentions_ticket(b, ticket_id)))
+            })
+            .map(|issue| PullRequestRef {
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+                number: issue.number,
+            })
+            .collect())
+    }
+}
+
+/// Replace any of `status_labels` in `labels` with `status_label` (an empty
+/// or missing label leaves none).
+fn apply_status_label(
+    mut labels: Vec<String>,
+    status_label: Option<&str>,
+    status_labels: &[String],
+) -> Vec<String> {
+    labels.retain(|l| !status_labels.iter().any(|s| s.eq_ignore_ascii_case(l)));
+    if let Some(label) = status_label.filter(|l| !l.is_empty()) {
+        labels.push(label.to_string());
+    }
+    labels
+}
 
 /// Scrub token patterns from error messages to prevent credential leakage.
 ///
 /// This function removes potential token values from error messages as a defense-in-depth
 /// measure, even though Octocrab should not include tokens in error messages.
-fn scrub_token_from_error(error_msg: &str, token: &str) -> String {
+pub(super) fn scrub_token_from_error(error_msg: &str, token: &str) -> String {
     // Common token patterns to scrub
     let patterns = [
         token.to_string(),
@@ -428,7 +619,24 @@ impl RemoteProvider for GitHubProvider {
                 }
             });
 
-            if title.is_none() && body.is_none() && state.is_none() {
+            let labels = self
+                .merge_status_label(remote_ref, updates.labels, updates.status_label)
+                .await?;
+            let labels = match labels {
+                Some(labels) => Some(self.ensure_labels(owner, repo, labels).await?),
+                None => None,
+            };
+
+            let assignees: Option<Vec<String>> = updates
+                .assignee
+                .map(|a| if a.is_empty() { Vec::new() } else { vec![a] });
+
+            if title.is_none()
+                && body.is_none()
+                && state.is_none()
+                && labels.is_none()
+                && assignees.is_none()
+            {
                 return Ok(());
             }
 
@@ -450,6 +658,12 @@ impl RemoteProvider for GitHubProvider {
                     if let Some(s) = &state {
                         builder = builder.state(s.clone());
                     }
+                    if let Some(l) = &labels {
+                        builder = builder.labels(l);
+                    }
+                    if let Some(a) = &assignees {
+                        builder = builder.assignees(a);
+                    }
                     builder.send().await.map_err(GitHubError::from)
                 },
                 Some(timeout),
@@ -624,12 +838,14 @@ impl RemoteProvider for GitHubProvider {
                             },
                             priority: None,
                             assignee: issue.assignee.as_ref().map(|a| a.login.clone()),
+                            assignee_aliases: Vec::new(),
                             updated_at: issue.updated_at.to_rfc3339(),
                             url: issue.html_url.to_string(),
                             labels: issue.labels.iter().map(|l| l.name.clone()).collect(),
                             team: None,
                             project: None,
                             milestone: issue.milestone.as_ref().map(|m| m.title.clone()),
+                            cycle: None,
                             due_date: None,
                             created_at: issue.created_at.to_rfc3339(),
                             creator: Some(issue.user.login.clone()),
@@ -687,6 +903,7 @@ impl RemoteProvider for GitHubProvider {
                                                 .assignee
                                                 .as_ref()
                                                 .map(|a| a.login.clone()),
+                                            assignee_aliases: Vec::new(),
                                             updated_at: issue.updated_at.to_rfc3339(),
                                             url: issue.html_url.to_string(),
                                             labels: issue
@@ -700,6 +917,7 @@ impl RemoteProvider for GitHubProvider {
                                                 .milestone
                                                 .as_ref()
                                                 .map(|m| m.title.clone()),
+                                            cycle: None,
                                             due_date: None,
                                             created_at: issue.created_at.to_rfc3339(),
                                             creator: Some(issue.user.login.clone()),
@@ -762,12 +980,14 @@ impl GitHubProvider {
             status,
             priority: None,
             assignee: issue.assignee.as_ref().map(|a| a.login.clone()),
+            assignee_aliases: Vec::new(),
             updated_at: issue.updated_at.to_rfc3339(),
             url: issue.html_url.to_string(),
             labels,
             team: None,
             project: None,
             milestone: issue.milestone.as_ref().map(|m| m.title.clone()),
+            cycle: None,
             due_date: None,
             created_at: issue.created_at.to_rfc3339(),
             creator: Some(issue.user.login.clone()),
@@ -806,4 +1026,19 @@ mod tests {
         assert_eq!(provider.default_owner, Some("owner".to_string()));
         assert_eq!(provider.default_repo, Some("repo".to_string()));
     }
+
+    #[test]
+    fn test_apply_status_label() {
+        let status_labels = vec!["status: next".to_string(), "status: doing".to_string()];
+        let labels = vec!["bug".to_string(), "Status: Next".to_string()];
+
+        assert_eq!(
+            apply_status_label(labels.clone(), Some("status: doing"), &status_labels),
+            vec!["bug", "status: doing"]
+        );
+        assert_eq!(
+            apply_status_label(labels, Some(""), &status_labels),
+            vec!["bug"]
+        );
+    }
 }
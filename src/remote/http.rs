@@ -0,0 +1,302 @@
+//! HTTP transport configuration shared by the remote providers.
+//!
+//! Both providers honor `HTTPS_PROXY`/`HTTP_PROXY`/`NO_PROXY` from the
+//! environment and the `network` section of the config (explicit proxy and a
+//! custom CA bundle), so `adopt`/`sync` work behind TLS-intercepting corporate
+//! proxies.
+//!
+//! reqwest (Linear) supports both natively. Octocrab (GitHub) does not, so when
+//! a proxy or CA bundle applies it is given a hand-built hyper client instead of
+//! its default one.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use http::Uri;
+use http::header::{HeaderValue, USER_AGENT};
+use hyper_rustls::HttpsConnectorBuilder;
+use hyper_util::client::legacy::Client as HyperClient;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::connect::proxy::Tunnel;
+use hyper_util::client::proxy::matcher::{Intercept, Matcher};
+use hyper_util::rt::TokioExecutor;
+use octocrab::service::middleware::base_uri::BaseUriLayer;
+use octocrab::service::middleware::extra_headers::ExtraHeadersLayer;
+use octocrab::{AuthState, Octocrab, OctocrabBuilder};
+use rustls::pki_types::CertificateDer;
+use rustls::pki_types::pem::PemObject;
+
+use super::config::NetworkConfig;
+use super::github::scrub_token_from_error;
+use crate::error::{JanusError, Result};
+
+/// Base URL of the GitHub REST API
+pub const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Proxy environment variables for plain HTTP, HTTPS, and any scheme, each
+/// under its upper- and lowercase name
+const PROXY_ENV_VARS: [[&str; 2]; 3] = [
+    ["HTTP_PROXY", "http_proxy"],
+    ["HTTPS_PROXY", "https_proxy"],
+    ["ALL_PROXY", "all_proxy"],
+];
+
+/// The proxies set in the environment for plain HTTP, HTTPS, and any scheme,
+/// with `var` looking up a variable
+fn env_proxies(var: impl Fn(&str) -> Option<String>) -> [Option<String>; 3] {
+    PROXY_ENV_VARS.map(|names| {
+        names
+            .into_iter()
+            .find_map(|name| var(name).filter(|value| !value.is_empty()))
+    })
+}
+
+/// Apply proxy and CA settings to a reqwest client builder.
+///
+/// Environment proxies are already honored by reqwest; an explicit
+/// `network.proxy` replaces them, and `network.no_proxy` replaces `NO_PROXY`.
+pub fn configure_reqwest(
+    mut builder: reqwest::ClientBuilder,
+    network: &NetworkConfig,
+) -> Result<reqwest::ClientBuilder> {
+    let no_proxy = network
+        .no_proxy
+        .as_deref()
+        .and_then(reqwest::NoProxy::from_string);
+    if let Some(proxy_url) = &network.proxy {
+        let proxy = reqwest::Proxy::all(proxy_url)
+            .map_err(|e| JanusError::Config(format!("invalid network.proxy '{proxy_url}': {e}")))?
+            .no_proxy(no_proxy);
+        builder = builder.proxy(proxy);
+    } else if network.no_proxy.is_some() {
+        // Setting any proxy turns off reqwest's own lookup of the environment,
+        // which would apply NO_PROXY, so the environment proxies are set here
+        let [http, https, all] = env_proxies(|name| std::env::var(name).ok());
+        let proxies = [
+            (
+                http,
+                reqwest::Proxy::http::<String> as fn(String) -> reqwest::Result<reqwest::Proxy>,
+            ),
+            (https, reqwest::Proxy::https::<String>),
+            (all, reqwest::Proxy::all::<String>),
+        ];
+        for (url, proxy) in proxies {
+            let Some(url) = url else { continue };
+            let proxy = proxy(url.clone()).map_err(|e| {
+                JanusError::Config(format!("invalid proxy '{url}' in the environment: {e}"))
+            })?;
+            builder = builder.proxy(proxy.no_proxy(no_proxy.clone()));
+        }
+    }
+
+    for cert in load_ca_bundle(network)? {
+        let cert = reqwest::Certificate::from_der(cert.as_ref())?;
+        builder = builder.add_root_certificate(cert);
+    }
+
+    Ok(builder)
+}
+
+/// Build an Octocrab client authenticated with `token`.
+///
+/// Without a proxy or CA bundle this is Octocrab's default client. Otherwise the
+/// client connects through a hyper connector that trusts the system roots plus
+/// the bundle and, if a proxy applies to `api.github.com`, tunnels through it
+/// with `CONNECT`.
+pub fn build_github_client(
+    token: &str,
+    network: &NetworkConfig,
+    timeout: Duration,
+) -> Result<Octocrab> {
+    let proxy = resolve_proxy(network, GITHUB_API_URL);
+    if proxy.is_none() && network.ca_bundle.is_none() {
+        return Octocrab::builder()
+            .personal_token(token.to_string())
+            .build()
+            .map_err(|e| github_client_error(e, token));
+    }
+
+    let tls = github_tls_config(network)?;
+    let mut http = HttpConnector::new();
+    http.enforce_http(false);
+    http.set_connect_timeout(Some(timeout));
+
+    let base_uri = Uri::from_static(GITHUB_API_URL);
+    let headers = Arc::new(vec![(
+        USER_AGENT,
+        HeaderValue::from_static(concat!("janus/", env!("CARGO_PKG_VERSION"))),
+    )]);
+    let auth = AuthState::AccessToken {
+        token: token.to_string().into(),
+    };
+
+    let octocrab = match proxy {
+        Some(intercept) => {
+            let mut tunnel = Tunnel::new(intercept.uri().clone(), http);
+            if let Some(basic_auth) = intercept.basic_auth() {
+                tunnel = tunnel.with_auth(basic_auth.clone());
+            }
+            let connector = HttpsConnectorBuilder::new()
+                .with_tls_config(tls)
+                .https_or_http()
+                .enable_http1()
+                .wrap_connector(tunnel);
+            OctocrabBuilder::new_empty()
+                .with_service(HyperClient::builder(TokioExecutor::new()).build(connector))
+                .with_layer(&BaseUriLayer::new(base_uri))
+                .with_layer(&ExtraHeadersLayer::new(headers))
+                .with_auth(auth)
+                .build()
+        }
+        None => {
+            let connector = HttpsConnectorBuilder::new()
+                .with_tls_config(tls)
+                .https_or_http()
+                .enable_http1()
+                .wrap_connector(http);
+            OctocrabBuilder::new_empty()
+                .with_service(HyperClient::builder(TokioExecutor::new()).build(connector))
+                .with_layer(&BaseUriLayer::new(base_uri))
+                .with_layer(&ExtraHeadersLayer::new(headers))
+                .with_auth(auth)
+                .build()
+        }
+    };
+
+    octocrab.map_err(|e| github_client_error(e, token))
+}
+
+fn github_client_error(error: octocrab::Error, token: &str) -> JanusError {
+    let scrubbed = scrub_token_from_error(&error.to_string(), token);
+    JanusError::Api(format!("Failed to create GitHub client: {scrubbed}"))
+}
+
+/// Find the proxy (if any) that requests to `url` should go through.
+fn resolve_proxy(network: &NetworkConfig, url: &'static str) -> Option<Intercept> {
+    proxy_matcher(network, |name| std::env::var(name).ok()).intercept(&Uri::from_static(url))
+}
+
+/// The proxy settings of `network` on top of the environment, with `var`
+/// looking up a variable
+fn proxy_matcher(network: &NetworkConfig, var: impl Fn(&str) -> Option<String>) -> Matcher {
+    match (&network.proxy, &network.no_proxy) {
+        (Some(proxy), no_proxy) => Matcher::builder()
+            .all(proxy.clone())
+            .no(no_proxy.clone().unwrap_or_default())
+            .build(),
+        (None, Some(no_proxy)) => {
+            let [http, https, all] = env_proxies(var);
+            Matcher::builder()
+                .http(http.unwrap_or_default())
+                .https(https.unwrap_or_default())
+                .all(all.unwrap_or_default())
+                .no(no_proxy.clone())
+                .build()
+        }
+        (None, None) => Matcher::from_env(),
+    }
+}
+
+/// rustls config trusting the platform roots plus any configured CA bundle.
+fn github_tls_config(network: &NetworkConfig) -> Result<rustls::ClientConfig> {
+    let mut roots = rustls::RootCertStore::empty();
+    roots.add_parsable_certificates(rustls_native_certs::load_native_certs().certs);
+    roots.add_parsable_certificates(load_ca_bundle(network)?);
+
+    // Pin the provider explicitly so we never depend on a process-wide default
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    Ok(rustls::ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .map_err(|e| JanusError::Config(format!("failed to configure TLS: {e}")))?
+        .with_root_certificates(roots)
+        .with_no_client_auth())
+}
+
+/// Read the certificates in `network.ca_bundle`, if configured.
+fn load_ca_bundle(network: &NetworkConfig) -> Result<Vec<CertificateDer<'static>>> {
+    let Some(path) = &network.ca_bundle else {
+        return Ok(Vec::new());
+    };
+
+    let invalid = |detail: String| {
+        JanusError::Config(format!(
+            "invalid network.ca_bundle '{}': {detail}",
+            path.display()
+        ))
+    };
+    let certs = CertificateDer::pem_file_iter(path)
+        .map_err(|e| invalid(e.to_string()))?
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|e| invalid(e.to_string()))?;
+
+    if certs.is_empty() {
+        return Err(invalid("no PEM certificates found".to_string()));
+    }
+    Ok(certs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_load_ca_bundle_unset() {
+        let network = NetworkConfig::default();
+        assert!(load_ca_bundle(&network).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_load_ca_bundle_rejects_non_pem() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "not a certificate").unwrap();
+
+        let network = NetworkConfig {
+            ca_bundle: Some(file.path().to_path_buf()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            load_ca_bundle(&network),
+            Err(JanusError::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_proxy_respects_no_proxy() {
+        let network = NetworkConfig {
+            proxy: Some("http://proxy.example:3128".to_string()),
+            no_proxy: Some("api.github.com".to_string()),
+            ca_bundle: None,
+        };
+        assert!(resolve_proxy(&network, GITHUB_API_URL).is_none());
+
+        let network = NetworkConfig {
+            no_proxy: None,
+            ..network
+        };
+        let intercept = resolve_proxy(&network, GITHUB_API_URL).unwrap();
+        assert_eq!(intercept.uri().host(), Some("proxy.example"));
+    }
+
+    #[test]
+    fn test_no_proxy_applies_to_environment_proxies() {
+        let env = |name: &str| {
+            (name == "HTTPS_PROXY").then(|| "http://env-proxy.example:8080".to_string())
+        };
+        let network = NetworkConfig {
+            no_proxy: Some("api.github.com".to_string()),
+            ..Default::default()
+        };
+        let matcher = proxy_matcher(&network, env);
+        assert!(
+            matcher
+                .intercept(&Uri::from_static(GITHUB_API_URL))
+                .is_none()
+        );
+
+        let intercept = matcher
+            .intercept(&Uri::from_static("https://api.linear.app/graphql"))
+            .unwrap();
+        assert_eq!(intercept.uri().host(), Some("env-proxy.example"));
+    }
+}
@@ -22,11 +22,14 @@ use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
+use tracing::warn;
 
 use crate::error::{JanusError, Result};
 
 use crate::config::Config;
 
+use super::config::NetworkConfig;
+use super::http::configure_reqwest;
 use super::{
     AsHttpError, IssueUpdates, PaginatedResult, Platform, RemoteIssue, RemoteProvider, RemoteQuery,
     RemoteRef, RemoteStatus,
@@ -37,11 +40,13 @@ const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
 /// Create an HTTP client with Linear's standard configuration.
 ///
 /// This helper centralizes the HTTP client builder configuration to avoid duplication.
-fn build_linear_http_client() -> Result<Client> {
-    Ok(Client::builder()
+/// Proxy and CA settings come from `network` (environment proxies are honored
+/// by reqwest by default).
+fn build_linear_http_client(network: &NetworkConfig) -> Result<Client> {
+    let builder = Client::builder()
         .timeout(Duration::from_secs(60))
-        .connect_timeout(Duration::from_secs(30))
-        .build()?)
+        .connect_timeout(Duration::from_secs(30));
+    Ok(configure_reqwest(builder, network)?.build()?)
 }
 
 /// Zero-knowledge wrapper for sensitive header values.
@@ -219,6 +224,13 @@ mod graphql {
         pub input: IssueUpdateInput,
     }
 
+    /// Variables for clearing an issue's assignee
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueUnassignVariables {
+        pub id: String,
+        pub input: IssueUnassignInput,
+    }
+
     // Input Objects
 
     /// Input for creating an issue
@@ -241,6 +253,42 @@ mod graphql {
         pub title: Option<String>,
         /// The issue description in markdown format
         pub description: Option<String>,
+        /// The complete set of label IDs for the issue (omitted when unchanged)
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub label_ids: Option<Vec<String>>,
+        /// The identifier of the user to assign (omitted when unchanged)
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub assignee_id: Option<String>,
+        /// The identifier of the workflow state to move to (omitted when unchanged)
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub state_id: Option<String>,
+        /// The identifier of the project to move to (omitted when unchanged)
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub project_id: Option<String>,
+        /// The identifier of the cycle to schedule in (omitted when unchanged)
+        #[cynic(skip_serializing_if = "Option::is_none")]
+        pub cycle_id: Option<String>,
+    }
+
+    /// Input that clears an issue's assignee (`assigneeId` is always sent, as null)
+    #[derive(cynic::InputObject, Debug, Clone, Default)]
+    #[cynic(graphql_type = "IssueUpdateInput", rename_all = "camelCase")]
+    pub struct IssueUnassignInput {
+        pub assignee_id: Option<String>,
+    }
+
+    /// Variables for creating an issue label
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueLabelCreateVariables {
+        pub input: IssueLabelCreateInput,
+    }
+
+    /// Input for creating a workspace-level issue label
+    #[derive(cynic::InputObject, Debug, Clone)]
+    #[cynic(rename_all = "camelCase")]
+    pub struct IssueLabelCreateInput {
+        /// The name of the label
+        pub name: String,
     }
 
     // Query Fragments - Issue Query
@@ -291,6 +339,24 @@ mod graphql {
         pub created_at: DateTime,
         pub updated_at: DateTime,
         pub url: String,
+        pub labels: IssueLabelConnection,
+        pub project: Option<IssueProject>,
+        pub cycle: Option<IssueCycle>,
+    }
+
+    /// Project an issue belongs to
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Project")]
+    pub struct IssueProject {
+        pub name: String,
+    }
+
+    /// Cycle an issue is scheduled in
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Cycle")]
+    pub struct IssueCycle {
+        pub number: f64,
+        pub name: Option<String>,
     }
 
     /// Workflow state of an issue
@@ -305,6 +371,177 @@ mod graphql {
     #[derive(cynic::QueryFragment, Debug)]
     pub struct User {
         pub name: String,
+        pub display_name: String,
+        pub email: String,
+    }
+
+    /// Connection of issue labels
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct IssueLabelConnection {
+        pub nodes: Vec<IssueLabel>,
+    }
+
+    /// Issue label (workspace- or team-level)
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct IssueLabel {
+        pub id: cynic::Id,
+        pub name: String,
+    }
+
+    // Query Fragments - Workflow States Query
+
+    /// Query to fetch the workflow states of an issue's team
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "IssueQueryVariables")]
+    pub struct IssueStatesQuery {
+        #[arguments(id: $id)]
+        pub issue: IssueTeam,
+    }
+
+    /// The team an issue belongs to
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Issue")]
+    pub struct IssueTeam {
+        pub team: TeamStates,
+    }
+
+    /// Workflow states of a team
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Team")]
+    pub struct TeamStates {
+        pub states: WorkflowStateConnection,
+    }
+
+    /// Connection of workflow states
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct WorkflowStateConnection {
+        pub nodes: Vec<TeamWorkflowState>,
+    }
+
+    /// Workflow state with the ID needed to move an issue into it
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "WorkflowState")]
+    pub struct TeamWorkflowState {
+        pub id: cynic::Id,
+        pub name: String,
+        #[cynic(rename = "type")]
+        pub state_type: String,
+    }
+
+    // Query Fragments - Cycles Query
+
+    /// Query to fetch the cycles of an issue's team
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "IssueQueryVariables")]
+    pub struct IssueCyclesQuery {
+        #[arguments(id: $id)]
+        pub issue: IssueTeamCycles,
+    }
+
+    /// The team an issue belongs to, with its cycles
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Issue")]
+    pub struct IssueTeamCycles {
+        pub team: TeamCycles,
+    }
+
+    /// Cycles of a team
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Team")]
+    pub struct TeamCycles {
+        #[arguments(first: 100)]
+        pub cycles: CycleConnection,
+    }
+
+    /// Connection of cycles
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct CycleConnection {
+        pub nodes: Vec<Cycle>,
+    }
+
+    /// Cycle with the ID needed to schedule an issue in it
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct Cycle {
+        pub id: cynic::Id,
+        pub number: f64,
+        pub name: Option<String>,
+    }
+
+    // Query Fragments - Projects Query
+
+    /// Variables for fetching projects
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct ProjectsQueryVariables {
+        pub first: Option<i32>,
+    }
+
+    /// Query to fetch the projects in the workspace
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "ProjectsQueryVariables")]
+    pub struct ProjectsQuery {
+        #[arguments(first: $first)]
+        pub projects: ProjectConnection,
+    }
+
+    /// Connection of projects
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ProjectConnection {
+        pub nodes: Vec<Project>,
+    }
+
+    /// Project with the ID needed to move an issue into it
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct Project {
+        pub id: cynic::Id,
+        pub name: String,
+    }
+
+    // Query Fragments - Users Query
+
+    /// Variables for fetching workspace users
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct UsersQueryVariables {
+        pub first: Option<i32>,
+    }
+
+    /// Query to fetch the users in the workspace
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "UsersQueryVariables")]
+    pub struct UsersQuery {
+        #[arguments(first: $first)]
+        pub users: UserConnection,
+    }
+
+    /// Connection of users
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct UserConnection {
+        pub nodes: Vec<WorkspaceUser>,
+    }
+
+    /// User with the identifying fields needed to resolve an assignee
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "User")]
+    pub struct WorkspaceUser {
+        pub id: cynic::Id,
+        pub name: String,
+        pub display_name: String,
+        pub email: String,
+    }
+
+    // Query Fragments - Issue Labels Query
+
+    /// Variables for fetching issue labels
+    #[derive(cynic::QueryVariables, Debug)]
+    pub struct IssueLabelsQueryVariables {
+        pub first: Option<i32>,
+    }
+
+    /// Query to fetch the labels available in the workspace
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "IssueLabelsQueryVariables")]
+    pub struct IssueLabelsQuery {
+        #[arguments(first: $first)]
+        pub issue_labels: IssueLabelConnection,
     }
 
     // Query Fragments - Teams Query
@@ -367,6 +604,31 @@ mod graphql {
         pub issue_update: IssueUpdatePayload,
     }
 
+    /// Mutation to clear an issue's assignee
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "IssueUnassignVariables")]
+    pub struct IssueUnassignMutation {
+        #[arguments(id: $id, input: $input)]
+        pub issue_update: IssueUpdatePayload,
+    }
+
+    // Mutation Fragments - Create Issue Label
+
+    /// Mutation to create an issue label
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "IssueLabelCreateVariables")]
+    pub struct IssueLabelCreateMutation {
+        #[arguments(input: $input)]
+        pub issue_label_create: IssueLabelPayload,
+    }
+
+    /// Payload returned from issue label mutations
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct IssueLabelPayload {
+        pub success: bool,
+        pub issue_label: IssueLabel,
+    }
+
     /// Payload returned from issue update mutation
     #[derive(cynic::QueryFragment, Debug)]
     #[cynic(graphql_type = "IssuePayload")]
@@ -391,6 +653,8 @@ pub struct LinearProvider {
     issue_id_cache: Arc<RwLock<HashMap<String, String>>>,
     /// Timeout for remote operations
     timeout: std::time::Duration,
+    /// Whether to create labels that don't exist in the workspace on update
+    create_missing_labels: bool,
 }
 
 impl LinearProvider {
@@ -398,7 +662,7 @@ impl LinearProvider {
     ///
     /// Configures HTTP client with 30s connect timeout and 60s total timeout.
     pub fn from_config(config: &Config) -> Result<Self> {
-        let api_key = config.linear_api_key().ok_or_else(|| {
+        let api_key = config.linear_api_key()?.ok_or_else(|| {
             JanusError::Auth(
                 "Linear API key not configured. Set LINEAR_API_KEY environment variable or run: janus config set linear.api_key <key>".to_string()
             )
@@ -412,7 +676,7 @@ impl LinearProvider {
             }
         });
 
-        let client = build_linear_http_client()?;
+        let client = build_linear_http_client(&config.network)?;
 
         Ok(Self {
             client,
@@ -421,6 +685,7 @@ impl LinearProvider {
             default_team_id: None,
             issue_id_cache: Arc::new(RwLock::new(HashMap::new())),
             timeout: config.remote_timeout(),
+            create_missing_labels: config.labels.create_missing,
         })
     }
 
@@ -429,7 +694,7 @@ impl LinearProvider {
     /// Configures HTTP client with 30s connect timeout and 60s total timeout.
     /// Returns an error if HTTP client initialization fails.
     pub fn new(api_key: &str) -> Result<Self> {
-        let client = build_linear_http_client()?;
+        let client = build_linear_http_client(&NetworkConfig::default())?;
 
         Ok(Self {
             client,
@@ -438,6 +703,7 @@ impl LinearProvider {
             default_team_id: None,
             issue_id_cache: Arc::new(RwLock::new(HashMap::new())),
             timeout: std::time::Duration::from_secs(30),
+            create_missing_labels: true,
         })
     }
 
@@ -622,6 +888,102 @@ impl LinearProvider {
             .ok_or_else(|| JanusError::Api("No teams found in Linear workspace".to_string()))
     }
 
+    /// Resolve label names to Linear label IDs.
+    ///
+    /// Names match case-insensitively. Missing labels are created at the
+    /// workspace level, or dropped with a warning when `labels.create_missing`
+    /// is disabled. Only the first 250 workspace labels are considered.
+    async fn resolve_label_ids(&self, labels: &[String]) -> Result<Vec<String>> {
+        if labels.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let operation = IssueLabelsQuery::build(IssueLabelsQueryVariables { first: Some(250) });
+        let existing = self.execute(operation).await?.issue_labels.nodes;
+
+        let mut ids = Vec::with_capacity(labels.len());
+        for label in labels {
+            if let Some(found) = existing.iter().find(|l| l.name.eq_ignore_ascii_case(label)) {
+                ids.push(found.id.clone().into_inner());
+            } else if self.create_missing_labels {
+                let operation = IssueLabelCreateMutation::build(IssueLabelCreateVariables {
+                    input: IssueLabelCreateInput {
+                        name: label.clone(),
+                    },
+                });
+                let response = self.execute(operation).await?;
+                if !response.issue_label_create.success {
+                    return Err(JanusError::Api(format!(
+                        "Failed to create Linear label '{label}'"
+                    )));
+                }
+                ids.push(response.issue_label_create.issue_label.id.into_inner());
+            } else {
+                warn!("Skipping label '{label}': it does not exist in Linear");
+            }
+        }
+
+        Ok(ids)
+    }
+
+    /// Resolve a Linear user (name, display name, or email) to its ID.
+    ///
+    /// Only the first 250 workspace users are considered.
+    async fn resolve_user_id(&self, user: &str) -> Result<String> {
+        let operation = UsersQuery::build(UsersQueryVariables { first: Some(250) });
+        let users = self.execute(operation).await?.users.nodes;
+
+        users
+            .into_iter()
+            .find(|u| {
+                u.name.eq_ignore_ascii_case(user)
+                    || u.display_name.eq_ignore_ascii_case(user)
+                    || u.email.eq_ignore_ascii_case(user)
+            })
+            .map(|u| u.id.into_inner())
+            .ok_or_else(|| JanusError::Api(format!("Linear user '{user}' not found")))
+    }
+
+    /// Resolve a remote status to the ID of a workflow state of the issue's team.
+    async fn resolve_state_id(&self, issue_id: &str, status: &RemoteStatus) -> Result<String> {
+        let operation = IssueStatesQuery::build(IssueQueryVariables {
+            id: issue_id.to_string(),
+        });
+        let states = self.execute(operation).await?.issue.team.states.nodes;
+
+        find_workflow_state(&states, status)
+            .map(|s| s.id.clone().into_inner())
+            .ok_or_else(|| JanusError::Api(format!("Linear workflow state '{status}' not found")))
+    }
+
+    /// Resolve a project name to its ID (case-insensitive).
+    ///
+    /// Only the first 250 workspace projects are considered.
+    async fn resolve_project_id(&self, project: &str) -> Result<String> {
+        let operation = ProjectsQuery::build(ProjectsQueryVariables { first: Some(250) });
+        let projects = self.execute(operation).await?.projects.nodes;
+
+        projects
+            .into_iter()
+            .find(|p| p.name.eq_ignore_ascii_case(project))
+            .map(|p| p.id.into_inner())
+            .ok_or_else(|| JanusError::Api(format!("Linear project '{project}' not found")))
+    }
+
+    /// Resolve a cycle (name or number) to the ID of a cycle of the issue's team.
+    ///
+    /// Only the first 100 cycles of the team are considered.
+    async fn resolve_cycle_id(&self, issue_id: &str, cycle: &str) -> Result<String> {
+        let operation = IssueCyclesQuery::build(IssueQueryVariables {
+            id: issue_id.to_string(),
+        });
+        let cycles = self.execute(operation).await?.issue.team.cycles.nodes;
+
+        find_cycle(&cycles, cycle)
+            .map(|c| c.id.clone().into_inner())
+            .ok_or_else(|| JanusError::Api(format!("Linear cycle '{cycle}' not found")))
+    }
+
     /// Check if an error is a NOT_FOUND error from Linear API.
     ///
     /// Linear surfaces not-found conditions in two ways:
@@ -747,12 +1109,52 @@ impl RemoteProvider for LinearProvider {
                 }
             };
 
+            let label_ids = match updates.labels {
+                Some(labels) => Some(self.resolve_label_ids(&labels).await?),
+                None => None,
+            };
+
+            // An empty assignee clears it, which needs an explicit null that
+            // IssueUpdateInput never sends
+            let unassign = updates.assignee.as_deref() == Some("");
+            let assignee_id = match updates.assignee.as_deref() {
+                Some("") | None => None,
+                Some(user) => Some(self.resolve_user_id(user).await?),
+            };
+
+            let state_id = match &updates.status {
+                Some(status) => Some(self.resolve_state_id(issue_id, status).await?),
+                None => None,
+            };
+
+            let project_id = match updates.project.as_deref() {
+                Some(project) => Some(self.resolve_project_id(project).await?),
+                None => None,
+            };
+
+            let cycle_id = match updates.cycle.as_deref() {
+                Some(cycle) => Some(self.resolve_cycle_id(issue_id, cycle).await?),
+                None => None,
+            };
+
             let input = IssueUpdateInput {
                 title: updates.title,
                 description: updates.body,
+                label_ids,
+                assignee_id,
+                state_id,
+                project_id,
+                cycle_id,
             };
 
-            if input.title.is_none() && input.description.is_none() {
+            let has_updates = input.title.is_some()
+                || input.description.is_some()
+                || input.label_ids.is_some()
+                || input.assignee_id.is_some()
+                || input.state_id.is_some()
+                || input.project_id.is_some()
+                || input.cycle_id.is_some();
+            if !has_updates && !unassign {
                 return Ok(());
             }
 
@@ -786,15 +1188,32 @@ impl RemoteProvider for LinearProvider {
                 }
             };
 
-            let operation = IssueUpdateMutation::build(IssueUpdateVariables {
-                id: internal_id,
-                input,
-            });
+            if has_updates {
+                let operation = IssueUpdateMutation::build(IssueUpdateVariables {
+                    id: internal_id.clone(),
+                    input,
+                });
 
-            let response = self.execute(operation).await?;
+                let response = self.execute(operation).await?;
 
-            if !response.issue_update.success {
-                return Err(JanusError::Api("Failed to update Linear issue".to_string()));
+                if !response.issue_update.success {
+                    return Err(JanusError::Api("Failed to update Linear issue".to_string()));
+                }
+            }
+
+            if unassign {
+                let operation = IssueUnassignMutation::build(IssueUnassignVariables {
+                    id: internal_id,
+                    input: IssueUnassignInput::default(),
+                });
+
+                let response = self.execute(operation).await?;
+
+                if !response.issue_update.success {
+                    return Err(JanusError::Api(
+                        "Failed to unassign Linear issue".to_string(),
+                    ));
+                }
             }
 
             Ok(())
@@ -922,6 +1341,47 @@ impl RemoteProvider for LinearProvider {
     }
 }
 
+/// Pick the workflow state for a remote status.
+///
+/// `Custom` statuses match a state by name (case-insensitive); `Open` and
+/// `Closed` take the team's first unstarted and completed states.
+fn find_workflow_state<'a>(
+    states: &'a [TeamWorkflowState],
+    status: &RemoteStatus,
+) -> Option<&'a TeamWorkflowState> {
+    match status {
+        RemoteStatus::Custom(name) => states.iter().find(|s| s.name.eq_ignore_ascii_case(name)),
+        RemoteStatus::Open => states.iter().find(|s| s.state_type == "unstarted"),
+        RemoteStatus::Closed => states.iter().find(|s| s.state_type == "completed"),
+    }
+}
+
+/// Pick a cycle by name (case-insensitive) or number (`12` or `Cycle 12`).
+fn find_cycle<'a>(cycles: &'a [Cycle], cycle: &str) -> Option<&'a Cycle> {
+    let cycle = cycle.trim();
+    if let Some(found) = cycles.iter().find(|c| {
+        c.name
+            .as_deref()
+            .is_some_and(|n| n.eq_ignore_ascii_case(cycle))
+    }) {
+        return Some(found);
+    }
+
+    let number = cycle
+        .get(..6)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("cycle "))
+        .map_or(cycle, |_| cycle[6..].trim())
+        .parse::<u32>()
+        .ok()?;
+    cycles.iter().find(|c| c.number as u32 == number)
+}
+
+/// Display name of a cycle: its name, or `Cycle <number>` for unnamed cycles.
+fn format_cycle(number: f64, name: Option<String>) -> String {
+    name.filter(|n| !n.is_empty())
+        .unwrap_or_else(|| format!("Cycle {}", number as u32))
+}
+
 impl LinearProvider {
     fn convert_linear_issue(&self, issue: Issue) -> RemoteIssue {
         let status = match issue.state.state_type.as_str() {
@@ -948,13 +1408,18 @@ impl LinearProvider {
             body: issue.description.unwrap_or_default(),
             status,
             priority,
-            assignee: issue.assignee.map(|a| a.name),
+            assignee: issue.assignee.as_ref().map(|a| a.name.clone()),
+            assignee_aliases: issue
+                .assignee
+                .map(|a| vec![a.display_name, a.email])
+                .unwrap_or_default(),
             updated_at: issue.updated_at.0,
             url: issue.url,
-            labels: vec![],
+            labels: issue.labels.nodes.into_iter().map(|l| l.name).collect(),
             team: None,
-            project: None,
+            project: issue.project.map(|p| p.name),
             milestone: None,
+            cycle: issue.cycle.map(|c| format_cycle(c.number, c.name)),
             due_date: None,
             created_at: issue.created_at.0,
             creator: None,
@@ -1031,10 +1496,15 @@ mod tests {
             priority: 2.0,
             assignee: Some(User {
                 name: "Test User".to_string(),
+                display_name: "test".to_string(),
+                email: "test@example.com".to_string(),
             }),
             created_at: DateTime("2023-12-01T00:00:00Z".to_string()),
             updated_at: DateTime("2024-01-01T00:00:00Z".to_string()),
             url: "https://linear.app/issue/ENG-123".to_string(),
+            labels: IssueLabelConnection { nodes: vec![] },
+            project: None,
+            cycle: None,
         };
 
         let converted = provider.convert_linear_issue(test_issue);
@@ -1052,10 +1522,15 @@ mod tests {
             priority: 1.0,
             assignee: Some(User {
                 name: "Test User".to_string(),
+                display_name: "test".to_string(),
+                email: "test@example.com".to_string(),
             }),
             created_at: DateTime("2023-12-01T00:00:00Z".to_string()),
             updated_at: DateTime("2024-01-01T00:00:00Z".to_string()),
             url: "https://linear.app/issue/ENG-123".to_string(),
+            labels: IssueLabelConnection { nodes: vec![] },
+            project: None,
+            cycle: None,
         };
         let converted_p0 = provider.convert_linear_issue(test_issue_p0);
         assert_eq!(converted_p0.priority, Some(0));
@@ -1072,10 +1547,15 @@ mod tests {
             priority: 4.0,
             assignee: Some(User {
                 name: "Test User".to_string(),
+                display_name: "test".to_string(),
+                email: "test@example.com".to_string(),
             }),
             created_at: DateTime("2023-12-01T00:00:00Z".to_string()),
             updated_at: DateTime("2024-01-01T00:00:00Z".to_string()),
             url: "https://linear.app/issue/ENG-123".to_string(),
+            labels: IssueLabelConnection { nodes: vec![] },
+            project: None,
+            cycle: None,
         };
         let converted_p4 = provider.convert_linear_issue(test_issue_p4);
         assert_eq!(converted_p4.priority, Some(3));
@@ -1097,10 +1577,15 @@ mod tests {
             priority: -1.0,
             assignee: Some(User {
                 name: "Test User".to_string(),
+                display_name: "test".to_string(),
+                email: "test@example.com".to_string(),
             }),
             created_at: DateTime("2023-12-01T00:00:00Z".to_string()),
             updated_at: DateTime("2024-01-01T00:00:00Z".to_string()),
             url: "https://linear.app/issue/ENG-123".to_string(),
+            labels: IssueLabelConnection { nodes: vec![] },
+            project: None,
+            cycle: None,
         };
         let converted = provider.convert_linear_issue(test_issue_p_negative);
         assert_eq!(converted.priority, Some(4));
@@ -1124,6 +1609,9 @@ mod tests {
             created_at: DateTime("2023-12-01T00:00:00Z".to_string()),
             updated_at: DateTime("2024-01-01T00:00:00Z".to_string()),
             url: "https://linear.app/issue/ENG-123".to_string(),
+            labels: IssueLabelConnection { nodes: vec![] },
+            project: None,
+            cycle: None,
         };
 
         assert_eq!(
@@ -1145,6 +1633,9 @@ mod tests {
             created_at: DateTime("2023-12-01T00:00:00Z".to_string()),
             updated_at: DateTime("2024-01-01T00:00:00Z".to_string()),
             url: "https://linear.app/issue/ENG-123".to_string(),
+            labels: IssueLabelConnection { nodes: vec![] },
+            project: None,
+            cycle: None,
         };
 
         assert_eq!(
@@ -1166,6 +1657,9 @@ mod tests {
             created_at: DateTime("2023-12-01T00:00:00Z".to_string()),
             updated_at: DateTime("2024-01-01T00:00:00Z".to_string()),
             url: "https://linear.app/issue/ENG-123".to_string(),
+            labels: IssueLabelConnection { nodes: vec![] },
+            project: None,
+            cycle: None,
         };
 
         assert_eq!(
@@ -1192,6 +1686,9 @@ mod tests {
             created_at: DateTime("2023-12-01T00:00:00Z".to_string()),
             updated_at: DateTime("2024-01-01T00:00:00Z".to_string()),
             url: "https://linear.app/issue/ENG-123".to_string(),
+            labels: IssueLabelConnection { nodes: vec![] },
+            project: None,
+            cycle: None,
         };
 
         let converted = provider.convert_linear_issue(test_issue);
@@ -1216,6 +1713,9 @@ mod tests {
             created_at: DateTime("2023-12-01T00:00:00Z".to_string()),
             updated_at: DateTime("2024-01-01T00:00:00Z".to_string()),
             url: "https://linear.app/issue/ENG-123".to_string(),
+            labels: IssueLabelConnection { nodes: vec![] },
+            project: None,
+            cycle: None,
         };
 
         let converted = provider.convert_linear_issue(test_issue);
@@ -1238,10 +1738,25 @@ mod tests {
             priority: 2.0,
             assignee: Some(User {
                 name: "Test User".to_string(),
+                display_name: "test".to_string(),
+                email: "test@example.com".to_string(),
             }),
             created_at: DateTime("2023-12-01T00:00:00Z".to_string()),
             updated_at: DateTime("2024-01-01T00:00:00Z".to_string()),
             url: "https://linear.app/issue/ENG-123".to_string(),
+            labels: IssueLabelConnection {
+                nodes: vec![IssueLabel {
+                    id: cynic::Id::new("label-1"),
+                    name: "Bug".to_string(),
+                }],
+            },
+            project: Some(IssueProject {
+                name: "Mobile App".to_string(),
+            }),
+            cycle: Some(IssueCycle {
+                number: 12.0,
+                name: None,
+            }),
         };
 
         let converted = provider.convert_linear_issue(test_issue);
@@ -1251,9 +1766,11 @@ mod tests {
         assert_eq!(converted.body, "Description");
         assert_eq!(converted.url, "https://linear.app/issue/ENG-123");
         assert_eq!(converted.assignee, Some("Test User".to_string()));
-        assert_eq!(converted.labels, Vec::<String>::new());
+        assert_eq!(converted.assignee_aliases, vec!["test", "test@example.com"]);
+        assert_eq!(converted.labels, vec!["Bug".to_string()]);
         assert_eq!(converted.team, None);
-        assert_eq!(converted.project, None);
+        assert_eq!(converted.project, Some("Mobile App".to_string()));
+        assert_eq!(converted.cycle, Some("Cycle 12".to_string()));
         assert_eq!(converted.milestone, None);
         assert_eq!(converted.due_date, None);
         assert_eq!(converted.creator, None);
@@ -1410,4 +1927,49 @@ mod tests {
         let err = JanusError::Auth("unauthorized".to_string());
         assert!(!LinearProvider::is_not_found_error(&err));
     }
+
+    #[test]
+    fn test_find_workflow_state() {
+        let state = |id: &str, name: &str, state_type: &str| TeamWorkflowState {
+            id: cynic::Id::new(id),
+            name: name.to_string(),
+            state_type: state_type.to_string(),
+        };
+        let states = vec![
+            state("s1", "Backlog", "backlog"),
+            state("s2", "Todo", "unstarted"),
+            state("s3", "In Progress", "started"),
+            state("s4", "Done", "completed"),
+        ];
+        let find =
+            |status: RemoteStatus| find_workflow_state(&states, &status).map(|s| s.name.clone());
+
+        assert_eq!(
+            find(RemoteStatus::Custom("in progress".to_string())).as_deref(),
+            Some("In Progress")
+        );
+        assert_eq!(find(RemoteStatus::Open).as_deref(), Some("Todo"));
+        assert_eq!(find(RemoteStatus::Closed).as_deref(), Some("Done"));
+        assert_eq!(find(RemoteStatus::Custom("Review".to_string())), None);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = |id: &str, number: f64, name: Option<&str>| Cycle {
+            id: cynic::Id::new(id),
+            number,
+            name: name.map(str::to_string),
+        };
+        let cycles = vec![
+            cycle("c1", 11.0, None),
+            cycle("c2", 12.0, Some("Launch prep")),
+        ];
+        let find = |c: &str| find_cycle(&cycles, c).map(|c| c.id.clone().into_inner());
+
+        assert_eq!(find("launch PREP").as_deref(), Some("c2"));
+        assert_eq!(find("11").as_deref(), Some("c1"));
+        assert_eq!(find("Cycle 12").as_deref(), Some("c2"));
+        assert_eq!(find("13"), None);
+        assert_eq!(format_cycle(11.0, None), "Cycle 11");
+    }
 }
@@ -0,0 +1,156 @@
+//! Mirror mode for remotes (`remotes.<platform>.mode: mirror`).
+//!
+//! A mirrored ticket takes its title, status, labels, and assignee from its
+//! remote issue. Ticket writes that would change one of those fields locally
+//! are checked by [`check_local_edit`], which rejects them or lets them
+//! through with a warning depending on `local_edits`. Writes made by sync
+//! itself run under a [`PullGuard`] and are never checked.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::config::Config;
+use crate::error::{JanusError, Result};
+use crate::remote::RemoteRef;
+use crate::remote::config::LocalEditPolicy;
+use crate::types::TicketMetadata;
+
+/// Number of pulls in progress in this process
+static PULLING: AtomicUsize = AtomicUsize::new(0);
+
+/// Marks ticket writes as coming from the remote while alive.
+pub struct PullGuard(());
+
+impl PullGuard {
+    pub fn new() -> Self {
+        PULLING.fetch_add(1, Ordering::SeqCst);
+        PullGuard(())
+    }
+}
+
+impl Default for PullGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for PullGuard {
+    fn drop(&mut self) {
+        PULLING.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// The synced fields that differ between two versions of a ticket
+pub fn changed_fields(old: &TicketMetadata, new: &TicketMetadata) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+    if old.title != new.title {
+        fields.push("title");
+    }
+    if old.status != new.status {
+        fields.push("status");
+    }
+    if old.labels != new.labels {
+        fields.push("labels");
+    }
+    if old.assignee != new.assignee {
+        fields.push("assignee");
+    }
+    fields
+}
+
+/// Check a local write that turns ticket `old` into `new`.
+///
+/// Fails with [`JanusError::MirroredField`] when the write changes a synced
+/// field of a ticket whose remote is mirrored and `local_edits` is `block`.
+pub fn check_local_edit(id: &str, old: &TicketMetadata, new: &TicketMetadata) -> Result<()> {
+    if PULLING.load(Ordering::SeqCst) > 0 {
+        return Ok(());
+    }
+    let Some(remote) = old.remote.as_deref() else {
+        return Ok(());
+    };
+    let fields = changed_fields(old, new);
+    let Some(field) = fields.first() else {
+        return Ok(());
+    };
+
+    let config = Config::load()?;
+    if config.remotes.is_default() {
+        return Ok(());
+    }
+    let Ok(remote_ref) = RemoteRef::parse(remote, Some(&config)) else {
+        return Ok(());
+    };
+    if !config.remotes.is_mirror(remote_ref.platform()) {
+        return Ok(());
+    }
+
+    match config
+        .remotes
+        .for_platform(remote_ref.platform())
+        .local_edits
+    {
+        LocalEditPolicy::Block => Err(JanusError::MirroredField {
+            id: id.to_string(),
+            field: field.to_string(),
+            remote: remote.to_string(),
+        }),
+        LocalEditPolicy::Warn => {
+            eprintln!(
+                "Warning: {} of '{id}' is mirrored from {remote}; the next sync will overwrite it",
+                fields.join(", ")
+            );
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths::JanusRootGuard;
+    use crate::types::TicketStatus;
+    use tempfile::TempDir;
+
+    fn mirrored_ticket() -> TicketMetadata {
+        TicketMetadata {
+            title: Some("Fix login".to_string()),
+            status: Some(TicketStatus::New),
+            remote: Some("linear:acme/ENG-12".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_check_local_edit() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().join(".janus");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(
+            root.join("config.yaml"),
+            "remotes:\n  linear:\n    mode: mirror\n",
+        )
+        .unwrap();
+        let _guard = JanusRootGuard::new(&root);
+
+        let old = mirrored_ticket();
+        let mut new = old.clone();
+        new.priority = Some(crate::types::TicketPriority::P0);
+        assert!(check_local_edit("j-1", &old, &new).is_ok());
+
+        new.status = Some(TicketStatus::Complete);
+        assert!(matches!(
+            check_local_edit("j-1", &old, &new),
+            Err(JanusError::MirroredField { field, .. }) if field == "status"
+        ));
+        {
+            let _pull = PullGuard::new();
+            assert!(check_local_edit("j-1", &old, &new).is_ok());
+        }
+
+        let mut github = old.clone();
+        github.remote = Some("github:acme/app/3".to_string());
+        let mut edited = github.clone();
+        edited.status = Some(TicketStatus::Complete);
+        assert!(check_local_edit("j-1", &github, &edited).is_ok());
+    }
+}
@@ -0,0 +1,533 @@
+//! In-memory provider and record/replay fixtures, for testing remote sync
+//! without live credentials.
+//!
+//! A fixture file is the JSON form of [`Fixture`]: the issues one platform
+//! holds, keyed by remote reference. With `JANUS_REMOTE_FIXTURES=<dir>`,
+//! [`create_provider`](super::create_provider) serves each platform from
+//! `<dir>/<platform>.json` instead of the network. Issues created or updated
+//! there are written back to the file, so a sequence of commands sees its own
+//! changes. With `JANUS_REMOTE_RECORD=<dir>`, the real providers are used and
+//! every issue they return is saved to the same files, ready for replay.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    IssueUpdates, PaginatedResult, Platform, Provider, RemoteIssue, RemoteProvider, RemoteQuery,
+    RemoteRef, RemoteStatus,
+};
+use crate::config::Config;
+use crate::error::{JanusError, Result};
+
+/// Directory of fixture files to serve remotes from
+pub const FIXTURES_ENV: &str = "JANUS_REMOTE_FIXTURES";
+
+/// Directory to record fixture files into
+pub const RECORD_ENV: &str = "JANUS_REMOTE_RECORD";
+
+/// The issues of one platform, as stored in a fixture file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Fixture {
+    /// Remote reference (`github:owner/repo/1`) -> issue
+    #[serde(default)]
+    pub issues: BTreeMap<String, RemoteIssue>,
+}
+
+impl Fixture {
+    /// Path of the fixture file for `platform` in `dir`
+    pub fn path(dir: &Path, platform: Platform) -> PathBuf {
+        dir.join(format!("{platform}.json"))
+    }
+
+    /// Load a fixture file; a missing file is an empty fixture.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| {
+            JanusError::InvalidFormat(format!("invalid remote fixture {}: {e}", path.display()))
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| JanusError::InternalError(format!("failed to serialize fixture: {e}")))?;
+        fs::write(path, format!("{content}\n"))?;
+        Ok(())
+    }
+}
+
+/// A remote provider that keeps issues in memory.
+///
+/// It behaves like the real providers as far as sync can tell: issues are
+/// created under the configured default remote, updates are applied field by
+/// field, and unknown references fail with
+/// [`JanusError::RemoteIssueNotFound`].
+pub struct MockProvider {
+    platform: Platform,
+    fixture: Mutex<Fixture>,
+    /// Fixture file that changes are written back to
+    path: Option<PathBuf>,
+    /// `owner/repo` (GitHub) or org (Linear) that new issues are created in
+    namespace: String,
+    /// Labels that mark a status on GitHub (see `status_mapping`)
+    status_labels: Vec<String>,
+}
+
+impl MockProvider {
+    /// An empty provider for `platform` that is not backed by a file
+    pub fn new(platform: Platform) -> Self {
+        let namespace = match platform {
+            Platform::GitHub => "mock/mock",
+            Platform::Linear => "mock",
+        };
+        Self {
+            platform,
+            fixture: Mutex::new(Fixture::default()),
+            path: None,
+            namespace: namespace.to_string(),
+            status_labels: Vec::new(),
+        }
+    }
+
+    /// Serve `platform` from its fixture file in `dir`, writing changes back.
+    pub fn open(platform: Platform, dir: &Path, config: &Config) -> Result<Self> {
+        let path = Fixture::path(dir, platform);
+        let mut provider = Self::new(platform);
+        provider.fixture = Mutex::new(Fixture::load(&path)?);
+        provider.path = Some(path);
+        if let Some(default) = config
+            .default_remote
+            .as_ref()
+            .filter(|d| d.platform == platform)
+        {
+            provider.namespace = match (platform, &default.repo) {
+                (Platform::GitHub, Some(repo)) => format!("{}/{repo}", default.org),
+                _ => default.org.clone(),
+            };
+        }
+        if platform == Platform::GitHub {
+            provider.status_labels = config.status_mapping.github.values().cloned().collect();
+        }
+        Ok(provider)
+    }
+
+    /// Add an issue
+    pub fn with_issue(self, remote_ref: &RemoteRef, issue: RemoteIssue) -> Self {
+        self.insert(remote_ref, issue);
+        self
+    }
+
+    /// The current state of an issue
+    pub fn issue(&self, remote_ref: &RemoteRef) -> Option<RemoteIssue> {
+        self.lock().issues.get(&remote_ref.to_string()).cloned()
+    }
+
+    /// Store an issue, writing the fixture file if there is one
+    pub fn insert(&self, remote_ref: &RemoteRef, issue: RemoteIssue) {
+        let mut fixture = self.lock();
+        fixture.issues.insert(remote_ref.to_string(), issue);
+        self.persist(&fixture);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Fixture> {
+        self.fixture.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn persist(&self, fixture: &Fixture) {
+        if let Some(path) = &self.path
+            && let Err(e) = fixture.save(path)
+        {
+            eprintln!("Warning: failed to write remote fixture: {e}");
+        }
+    }
+
+    fn issues_on_platform(&self) -> Vec<RemoteIssue> {
+        let prefix = format!("{}:", self.platform);
+        self.lock()
+            .issues
+            .iter()
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .map(|(_, issue)| issue.clone())
+            .collect()
+    }
+
+    /// The first free reference in the namespace
+    fn next_ref(&self, fixture: &Fixture) -> Result<RemoteRef> {
+        let reference = (1..)
+            .map(|number| match self.platform {
+                Platform::GitHub => format!("github:{}/{number}", self.namespace),
+                Platform::Linear => format!("linear:{}/MOCK-{number}", self.namespace),
+            })
+            .find(|reference| !fixture.issues.contains_key(reference))
+            .expect("unbounded range");
+        RemoteRef::parse(&reference, None)
+    }
+
+    fn apply(&self, issue: &mut RemoteIssue, updates: IssueUpdates) {
+        if let Some(title) = updates.title {
+            issue.title = title;
+        }
+        if let Some(body) = updates.body {
+            issue.body = body;
+        }
+        if let Some(status) = updates.status {
+            issue.status = status;
+        }
+        if let Some(priority) = updates.priority {
+            issue.priority = Some(priority);
+        }
+        if let Some(assignee) = updates.assignee {
+            issue.assignee = (!assignee.is_empty()).then_some(assignee);
+        }
+        if let Some(labels) = updates.labels {
+            issue.labels = labels;
+        }
+        if let Some(status_label) = updates.status_label {
+            issue.labels.retain(|label| {
+                !self
+                    .status_labels
+                    .iter()
+                    .any(|s| s.eq_ignore_ascii_case(label))
+            });
+            if !status_label.is_empty() {
+                issue.labels.push(status_label);
+            }
+        }
+        if let Some(project) = updates.project {
+            issue.project = Some(project);
+        }
+        if let Some(cycle) = updates.cycle {
+            issue.cycle = Some(cycle);
+        }
+        issue.updated_at = jiff::Timestamp::now().to_string();
+    }
+}
+
+/// First `query.limit` of `issues`
+fn paginate(mut issues: Vec<RemoteIssue>, query: &RemoteQuery) -> PaginatedResult<RemoteIssue> {
+    let total = issues.len() as u64;
+    let limit = if query.limit == 0 {
+        issues.len()
+    } else {
+        query.limit as usize * query.max_pages.max(1) as usize
+    };
+    let has_more = issues.len() > limit;
+    issues.truncate(limit);
+    PaginatedResult::new(issues, has_more).with_total_count(total)
+}
+
+impl RemoteProvider for MockProvider {
+    fn fetch_issue<'a>(
+        &'a self,
+        remote_ref: &'a RemoteRef,
+    ) -> Pin<Box<dyn Future<Output = Result<RemoteIssue>> + Send + 'a>> {
+        Box::pin(async move {
+            self.issue(remote_ref)
+                .ok_or_else(|| JanusError::RemoteIssueNotFound(remote_ref.to_string()))
+        })
+    }
+
+    fn create_issue<'a>(
+        &'a self,
+        title: &str,
+        body: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<RemoteRef>> + Send + 'a>> {
+        let title = title.to_string();
+        let body = body.to_string();
+        Box::pin(async move {
+            let mut fixture = self.lock();
+            let remote_ref = self.next_ref(&fixture)?;
+            let now = jiff::Timestamp::now().to_string();
+            let id = match &remote_ref {
+                RemoteRef::GitHub { issue_number, .. } => issue_number.to_string(),
+                RemoteRef::Linear { issue_id, .. } => issue_id.clone(),
+            };
+            let issue = RemoteIssue {
+                id,
+                title,
+                body,
+                status: RemoteStatus::Open,
+                priority: None,
+                assignee: None,
+                assignee_aliases: Vec::new(),
+                updated_at: now.clone(),
+                url: remote_ref.web_url(),
+                labels: Vec::new(),
+                team: None,
+                project: None,
+                milestone: None,
+                cycle: None,
+                due_date: None,
+                created_at: now,
+                creator: None,
+            };
+            fixture.issues.insert(remote_ref.to_string(), issue);
+            self.persist(&fixture);
+            Ok(remote_ref)
+        })
+    }
+
+    fn update_issue<'a>(
+        &'a self,
+        remote_ref: &'a RemoteRef,
+        updates: IssueUpdates,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut fixture = self.lock();
+            let issue = fixture
+                .issues
+                .get_mut(&remote_ref.to_string())
+                .ok_or_else(|| JanusError::RemoteIssueNotFound(remote_ref.to_string()))?;
+            self.apply(issue, updates);
+            self.persist(&fixture);
+            Ok(())
+        })
+    }
+
+    fn browse_issues<'a>(
+        &'a self,
+        query: &'a RemoteQuery,
+    ) -> Pin<Box<dyn Future<Output = Result<PaginatedResult<RemoteIssue>>> + Send + 'a>> {
+        Box::pin(async move { Ok(paginate(self.issues_on_platform(), query)) })
+    }
+
+    fn search_remote<'a>(
+        &'a self,
+        text: &str,
+        query: &'a RemoteQuery,
+    ) -> Pin<Box<dyn Future<Output = Result<PaginatedResult<RemoteIssue>>> + Send + 'a>> {
+        let text = text.to_lowercase();
+        Box::pin(async move {
+            let issues = self
+                .issues_on_platform()
+                .into_iter()
+                .filter(|issue| {
+                    issue.title.to_lowercase().contains(&text)
+                        || issue.body.to_lowercase().contains(&text)
+                })
+                .collect();
+            Ok(paginate(issues, query))
+        })
+    }
+}
+
+/// Wraps a real provider and records every issue it returns into fixture
+/// files. Issues that are created or updated are fetched again so the
+/// recording holds their final state.
+pub struct RecordingProvider {
+    inner: Box<Provider>,
+    recorder: MockProvider,
+}
+
+impl RecordingProvider {
+    pub fn new(inner: Provider, recorder: MockProvider) -> Self {
+        Self {
+            inner: Box::new(inner),
+            recorder,
+        }
+    }
+
+    async fn record_current(&self, remote_ref: &RemoteRef) -> Result<()> {
+        let issue = self.inner.fetch_issue(remote_ref).await?;
+        self.recorder.insert(remote_ref, issue);
+        Ok(())
+    }
+
+    fn record_page(&self, page: &PaginatedResult<RemoteIssue>) {
+        for issue in &page.items {
+            if let Some(remote_ref) = ref_for_url(&issue.url) {
+                self.recorder.insert(&remote_ref, issue.clone());
+            }
+        }
+    }
+}
+
+/// The remote reference of an issue, from its web URL
+fn ref_for_url(url: &str) -> Option<RemoteRef> {
+    if let Some(rest) = url.strip_prefix("https://github.com/") {
+        let (repo, number) = rest.split_once("/issues/")?;
+        return RemoteRef::parse(&format!("github:{repo}/{number}"), None).ok();
+    }
+    let rest = url.strip_prefix("https://linear.app/")?;
+    let (org, rest) = rest.split_once("/issue/")?;
+    let issue_id = rest.split('/').next()?;
+    RemoteRef::parse(&format!("linear:{org}/{issue_id}"), None).ok()
+}
+
+impl RemoteProvider for RecordingProvider {
+    fn fetch_issue<'a>(
+        &'a self,
+        remote_ref: &'a RemoteRef,
+    ) -> Pin<Box<dyn Future<Output = Result<RemoteIssue>> + Send + 'a>> {
+        Box::pin(async move {
+            let issue = self.inner.fetch_issue(remote_ref).await?;
+            self.recorder.insert(remote_ref, issue.clone());
+            Ok(issue)
+        })
+    }
+
+    fn create_issue<'a>(
+        &'a self,
+        title: &str,
+        body: &str,
+    ) -> Pin<Box<dyn Future<Output = Result<RemoteRef>> + Send + 'a>> {
+        let title = title.to_string();
+        let body = body.to_string();
+        Box::pin(async move {
+            let remote_ref = self.inner.create_issue(&title, &body).await?;
+            self.record_current(&remote_ref).await?;
+            Ok(remote_ref)
+        })
+    }
+
+    fn update_issue<'a>(
+        &'a self,
+        remote_ref: &'a RemoteRef,
+        updates: IssueUpdates,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async move {
+            self.inner.update_issue(remote_ref, updates).await?;
+            self.record_current(remote_ref).await
+        })
+    }
+
+    fn browse_issues<'a>(
+        &'a self,
+        query: &'a RemoteQuery,
+    ) -> Pin<Box<dyn Future<Output = Result<PaginatedResult<RemoteIssue>>> + Send + 'a>> {
+        Box::pin(async move {
+            let page = self.inner.browse_issues(query).await?;
+            self.record_page(&page);
+            Ok(page)
+        })
+    }
+
+    fn search_remote<'a>(
+        &'a self,
+        text: &str,
+        query: &'a RemoteQuery,
+    ) -> Pin<Box<dyn Future<Output = Result<PaginatedResult<RemoteIssue>>> + Send + 'a>> {
+        let text = text.to_string();
+        Box::pin(async move {
+            let page = self.inner.search_remote(&text, query).await?;
+            self.record_page(&page);
+            Ok(page)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn github_ref(number: u64) -> RemoteRef {
+        RemoteRef::GitHub {
+            owner: "acme".to_string(),
+            repo: "app".to_string(),
+            issue_number: number,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_provider_create_update_fetch() {
+        let provider = MockProvider::new(Platform::GitHub);
+        let remote_ref = provider.create_issue("Fix login", "Body").await.unwrap();
+        assert_eq!(remote_ref.to_string(), "github:mock/mock/1");
+
+        let updates = IssueUpdates {
+            status: Some(RemoteStatus::Closed),
+            labels: Some(vec!["bug".to_string()]),
+            assignee: Some("alice".to_string()),
+            ..Default::default()
+        };
+        provider.update_issue(&remote_ref, updates).await.unwrap();
+
+        let issue = provider.fetch_issue(&remote_ref).await.unwrap();
+        assert_eq!(issue.title, "Fix login");
+        assert_eq!(issue.status, RemoteStatus::Closed);
+        assert_eq!(issue.labels, vec!["bug"]);
+        assert_eq!(issue.assignee.as_deref(), Some("alice"));
+
+        assert!(matches!(
+            provider.fetch_issue(&github_ref(9)).await,
+            Err(JanusError::RemoteIssueNotFound(_))
+        ));
+        let found = provider
+            .search_remote("LOGIN", &RemoteQuery::new())
+            .await
+            .unwrap();
+        assert_eq!(found.items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fixture_replay_writes_back() {
+        let tmp = TempDir::new().unwrap();
+        let config = Config::default();
+        let seeded = MockProvider::open(Platform::GitHub, tmp.path(), &config)
+            .unwrap()
+            .with_issue(
+                &github_ref(3),
+                RemoteIssue {
+                    id: "3".to_string(),
+                    title: "Seeded".to_string(),
+                    body: String::new(),
+                    status: RemoteStatus::Open,
+                    priority: None,
+                    assignee: None,
+                    assignee_aliases: Vec::new(),
+                    updated_at: "2026-01-01T00:00:00Z".to_string(),
+                    url: github_ref(3).web_url(),
+                    labels: vec![],
+                    team: None,
+                    project: None,
+                    milestone: None,
+                    cycle: None,
+                    due_date: None,
+                    created_at: "2026-01-01T00:00:00Z".to_string(),
+                    creator: None,
+                },
+            );
+        drop(seeded);
+
+        let replay = MockProvider::open(Platform::GitHub, tmp.path(), &config).unwrap();
+        let updates = IssueUpdates {
+            title: Some("Renamed".to_string()),
+            ..Default::default()
+        };
+        replay.update_issue(&github_ref(3), updates).await.unwrap();
+
+        let fixture = Fixture::load(&Fixture::path(tmp.path(), Platform::GitHub)).unwrap();
+        assert_eq!(fixture.issues["github:acme/app/3"].title, "Renamed");
+        assert!(
+            MockProvider::open(Platform::Linear, tmp.path(), &config)
+                .unwrap()
+                .issues_on_platform()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_ref_for_url() {
+        assert_eq!(
+            ref_for_url("https://github.com/acme/app/issues/3"),
+            Some(github_ref(3))
+        );
+        assert_eq!(
+            ref_for_url("https://linear.app/acme/issue/ENG-12/fix-login").map(|r| r.to_string()),
+            Some("linear:acme/ENG-12".to_string())
+        );
+        assert_eq!(ref_for_url("https://example.com/x"), None);
+    }
+}
@@ -3,10 +3,15 @@
 //! This module provides functionality for synchronizing Janus tickets with
 //! external issue trackers like GitHub Issues and Linear.
 
+pub mod cache;
 pub mod config;
 pub mod error;
 pub mod github;
+pub mod http;
 pub mod linear;
+pub mod mirror;
+pub mod mock;
+pub mod pull_request;
 
 pub use error::{ApiError, build_github_error_message};
 
@@ -21,6 +26,7 @@ use crate::types::TicketStatus;
 
 use crate::remote::github::GitHubProvider;
 use crate::remote::linear::LinearProvider;
+use crate::remote::mock::{MockProvider, RecordingProvider};
 
 pub use config::Platform;
 
@@ -188,6 +194,20 @@ impl RemoteRef {
             RemoteRef::Linear { .. } => Platform::Linear,
         }
     }
+
+    /// Get the web URL for viewing the referenced issue in a browser
+    pub fn web_url(&self) -> String {
+        match self {
+            RemoteRef::GitHub {
+                owner,
+                repo,
+                issue_number,
+            } => format!("https://github.com/{owner}/{repo}/issues/{issue_number}"),
+            RemoteRef::Linear { org, issue_id } => {
+                format!("https://linear.app/{org}/issue/{issue_id}")
+            }
+        }
+    }
 }
 
 impl fmt::Display for RemoteRef {
@@ -275,6 +295,9 @@ pub struct RemoteIssue {
     pub priority: Option<u8>,
     /// Assignee name
     pub assignee: Option<String>,
+    /// Other identifiers of the assignee (a Linear user's display name and email)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignee_aliases: Vec<String>,
     /// Last updated timestamp (ISO 8601)
     pub updated_at: String,
     /// Web URL to view the issue
@@ -291,6 +314,9 @@ pub struct RemoteIssue {
     /// Milestone name (GitHub only)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub milestone: Option<String>,
+    /// Cycle name, or `Cycle <number>` if unnamed (Linear only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<String>,
     /// Due date (ISO 8601)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub due_date: Option<String>,
@@ -443,7 +469,17 @@ pub struct IssueUpdates {
     pub body: Option<String>,
     pub status: Option<RemoteStatus>,
     pub priority: Option<u8>,
+    /// Remote user to assign (an empty string unassigns)
     pub assignee: Option<String>,
+    /// Complete set of remote label names (replaces the issue's labels)
+    pub labels: Option<Vec<String>>,
+    /// Label marking the status (GitHub only); replaces any other mapped
+    /// status label, and an empty string just removes them
+    pub status_label: Option<String>,
+    /// Project to move the issue into, by name (Linear only)
+    pub project: Option<String>,
+    /// Cycle to schedule the issue in, by name or number (Linear only)
+    pub cycle: Option<String>,
 }
 
 impl IssueUpdates {
@@ -453,6 +489,10 @@ impl IssueUpdates {
             && self.status.is_none()
             && self.priority.is_none()
             && self.assignee.is_none()
+            && self.labels.is_none()
+            && self.status_label.is_none()
+            && self.project.is_none()
+            && self.cycle.is_none()
     }
 }
 
@@ -652,17 +692,40 @@ pub trait RemoteProvider: Send + Sync {
 }
 
 /// Enum wrapping all remote provider implementations
+///
+/// A new backend is a type implementing [`RemoteProvider`] plus a variant
+/// here; [`mock::MockProvider`] is the smallest complete example.
 #[enum_dispatch(RemoteProvider)]
 pub enum Provider {
     GitHub(GitHubProvider),
     Linear(LinearProvider),
+    Mock(MockProvider),
+    Recording(RecordingProvider),
 }
 
 /// Create a remote provider instance for the given platform
+///
+/// With `JANUS_REMOTE_FIXTURES` set, the platform is served from fixture
+/// files instead of the network; with `JANUS_REMOTE_RECORD`, the real
+/// provider's responses are recorded into them (see [`mock`]).
 pub fn create_provider(platform: &Platform, config: &Config) -> Result<Provider> {
-    match platform {
-        Platform::GitHub => Ok(Provider::GitHub(GitHubProvider::from_config(config)?)),
-        Platform::Linear => Ok(Provider::Linear(LinearProvider::from_config(config)?)),
+    if let Some(dir) = std::env::var_os(mock::FIXTURES_ENV) {
+        let provider = MockProvider::open(*platform, std::path::Path::new(&dir), config)?;
+        return Ok(Provider::Mock(provider));
+    }
+
+    let provider = match platform {
+        Platform::GitHub => Provider::GitHub(GitHubProvider::from_config(config)?),
+        Platform::Linear => Provider::Linear(LinearProvider::from_config(config)?),
+    };
+    match std::env::var_os(mock::RECORD_ENV) {
+        Some(dir) => {
+            let recorder = MockProvider::open(*platform, std::path::Path::new(&dir), config)?;
+            Ok(Provider::Recording(RecordingProvider::new(
+                provider, recorder,
+            )))
+        }
+        None => Ok(provider),
     }
 }
 
@@ -670,6 +733,15 @@ pub fn create_provider(platform: &Platform, config: &Config) -> Result<Provider>
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_web_url() {
+        let github = RemoteRef::parse("github:owner/repo/123", None).unwrap();
+        assert_eq!(github.web_url(), "https://github.com/owner/repo/issues/123");
+
+        let linear = RemoteRef::parse("linear:my-org/ENG-42", None).unwrap();
+        assert_eq!(linear.web_url(), "https://linear.app/my-org/issue/ENG-42");
+    }
+
     #[test]
     fn test_parse_github_full() {
         let r = RemoteRef::parse("github:owner/repo/123", None).unwrap();
@@ -906,6 +978,10 @@ mod tests {
             status: Some(RemoteStatus::Open),
             priority: Some(1),
             assignee: Some("user@example.com".to_string()),
+            labels: Some(vec!["bug".to_string()]),
+            status_label: None,
+            project: None,
+            cycle: None,
         };
         assert!(!updates.is_empty());
         assert_eq!(updates.title, Some("Title".to_string()));
@@ -913,6 +989,7 @@ mod tests {
         assert_eq!(updates.status, Some(RemoteStatus::Open));
         assert_eq!(updates.priority, Some(1));
         assert_eq!(updates.assignee, Some("user@example.com".to_string()));
+        assert_eq!(updates.labels, Some(vec!["bug".to_string()]));
     }
 
     #[test]
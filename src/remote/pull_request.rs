@@ -0,0 +1,168 @@
+//! GitHub pull requests linked to tickets.
+//!
+//! Tickets record linked pull requests in the `pull-requests` frontmatter field
+//! as `owner/repo#number`. Their state is fetched live rather than stored, so it
+//! never goes stale in the ticket file.
+
+use std::fmt;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::{Platform, RemoteRef};
+use crate::config::Config;
+use crate::error::{JanusError, Result};
+
+static PR_URL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^https?://github\.com/([^/\s]+)/([^/\s]+)/pull/(\d+)(?:[/?#].*)?$")
+        .expect("valid regex")
+});
+
+static PR_SHORT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([^/\s#]+)/([^/\s#]+)#(\d+)$").expect("valid regex"));
+
+/// Reference to a GitHub pull request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullRequestRef {
+    pub owner: String,
+    pub repo: String,
+    pub number: u64,
+}
+
+impl PullRequestRef {
+    /// Parse a pull request URL, `owner/repo#42`, or a bare number (`42` or
+    /// `#42`) in `default_repo`.
+    pub fn parse(s: &str, default_repo: Option<(&str, &str)>) -> Result<Self> {
+        let s = s.trim();
+        let invalid =
+            |reason: &str| JanusError::InvalidPullRequestRef(s.to_string(), reason.into());
+
+        if let Some(caps) = PR_URL_RE.captures(s).or_else(|| PR_SHORT_RE.captures(s)) {
+            let number = caps[3]
+                .parse()
+                .map_err(|_| invalid("pull request number out of range"))?;
+            return Ok(Self {
+                owner: caps[1].to_string(),
+                repo: caps[2].to_string(),
+                number,
+            });
+        }
+
+        let Ok(number) = s.trim_start_matches('#').parse::<u64>() else {
+            return Err(invalid(
+                "expected a pull request URL, owner/repo#42, or a number",
+            ));
+        };
+        let (owner, repo) = default_repo.ok_or_else(|| {
+            invalid("a bare number requires a GitHub remote on the ticket or default.remote")
+        })?;
+        Ok(Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            number,
+        })
+    }
+
+    pub fn web_url(&self) -> String {
+        format!(
+            "https://github.com/{}/{}/pull/{}",
+            self.owner, self.repo, self.number
+        )
+    }
+}
+
+impl fmt::Display for PullRequestRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}#{}", self.owner, self.repo, self.number)
+    }
+}
+
+/// State of a pull request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PullRequestState {
+    Open,
+    Closed,
+    Merged,
+}
+
+enum_display!(
+    PullRequestState,
+    {
+        Open => "open",
+        Closed => "closed",
+        Merged => "merged",
+    }
+);
+
+/// A pull request with its current state
+#[derive(Debug, Clone)]
+pub struct PullRequest {
+    pub reference: PullRequestRef,
+    pub title: String,
+    pub state: PullRequestState,
+    pub url: String,
+}
+
+/// The repository bare pull request numbers and detection refer to: the
+/// ticket's GitHub remote, falling back to a GitHub `default.remote`.
+pub fn default_pr_repo(config: &Config, remote: Option<&str>) -> Option<(String, String)> {
+    if let Some(RemoteRef::GitHub { owner, repo, .. }) =
+        remote.and_then(|r| RemoteRef::parse(r, Some(config)).ok())
+    {
+        return Some((owner, repo));
+    }
+
+    let default = config.default_remote.as_ref()?;
+    if default.platform != Platform::GitHub {
+        return None;
+    }
+    Some((default.org.clone(), default.repo.clone()?))
+}
+
+/// Whether `text` mentions `ticket_id` as a whole word (case-insensitive).
+pub fn mentions_ticket(text: &str, ticket_id: &str) -> bool {
+    let text = text.to_ascii_lowercase();
+    let id = ticket_id.to_ascii_lowercase();
+    let is_id_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+
+    text.match_indices(&id).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + id.len()..].chars().next();
+        !before.is_some_and(is_id_char) && !after.is_some_and(is_id_char)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pr_url() {
+        let pr = PullRequestRef::parse("https://github.com/acme/app/pull/42/files", None).unwrap();
+        assert_eq!(pr.to_string(), "acme/app#42");
+        assert_eq!(pr.web_url(), "https://github.com/acme/app/pull/42");
+    }
+
+    #[test]
+    fn test_parse_pr_short_and_number() {
+        let pr = PullRequestRef::parse("acme/app#7", None).unwrap();
+        assert_eq!(pr.number, 7);
+
+        let pr = PullRequestRef::parse("#12", Some(("acme", "app"))).unwrap();
+        assert_eq!(pr.to_string(), "acme/app#12");
+
+        assert!(matches!(
+            PullRequestRef::parse("12", None),
+            Err(JanusError::InvalidPullRequestRef(..))
+        ));
+        assert!(PullRequestRef::parse("https://github.com/acme/app/issues/3", None).is_err());
+    }
+
+    #[test]
+    fn test_mentions_ticket() {
+        assert!(mentions_ticket("Fix login (J-A1B2)", "j-a1b2"));
+        assert!(mentions_ticket("j-a1b2: fix login", "j-a1b2"));
+        assert!(!mentions_ticket("Fix j-a1b23", "j-a1b2"));
+        assert!(!mentions_ticket("Fix xj-a1b2", "j-a1b2"));
+    }
+}
@@ -0,0 +1,142 @@
+//! Encryption of config secrets with external `age` or `gpg` binaries.
+//!
+//! Janus never links a crypto library for this: teams that require encrypted
+//! tokens already have one of these tools and their keys set up, so the config
+//! simply pipes plaintext through the tool and stores the ASCII-armored output.
+
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{JanusError, Result};
+
+/// External tool used to encrypt config secrets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EncryptionTool {
+    Age,
+    Gpg,
+}
+
+impl EncryptionTool {
+    pub const ALL_STRINGS: &[&str] = &["age", "gpg"];
+}
+
+enum_display_fromstr!(
+    EncryptionTool,
+    JanusError::invalid_encryption_tool,
+    ["age", "gpg"],
+    {
+        Age => "age",
+        Gpg => "gpg",
+    }
+);
+
+/// Encrypt `plaintext` to every recipient, returning ASCII-armored ciphertext.
+pub fn encrypt(tool: EncryptionTool, recipients: &[String], plaintext: &str) -> Result<String> {
+    if recipients.is_empty() {
+        return Err(JanusError::Encryption(
+            "at least one recipient is required".to_string(),
+        ));
+    }
+
+    let mut args: Vec<&str> = match tool {
+        EncryptionTool::Age => vec!["--encrypt", "--armor"],
+        EncryptionTool::Gpg => vec!["--batch", "--yes", "--armor", "--encrypt"],
+    };
+    for recipient in recipients {
+        args.push("--recipient");
+        args.push(recipient);
+    }
+
+    run_with_stdin(tool, &args, plaintext)
+}
+
+/// Decrypt ASCII-armored ciphertext produced by [`encrypt`].
+///
+/// `age` requires an identity file; `gpg` uses the keys in the user's keyring
+/// (and gpg-agent), so `identity` is ignored for it.
+pub fn decrypt(tool: EncryptionTool, identity: Option<&Path>, ciphertext: &str) -> Result<String> {
+    match tool {
+        EncryptionTool::Age => {
+            let identity = identity.ok_or_else(|| {
+                JanusError::Encryption(
+                    "age decryption requires encryption.identity or JANUS_AGE_IDENTITY".to_string(),
+                )
+            })?;
+            let identity = identity.to_string_lossy();
+            run_with_stdin(tool, &["--decrypt", "--identity", &identity], ciphertext)
+        }
+        EncryptionTool::Gpg => {
+            run_with_stdin(tool, &["--batch", "--quiet", "--decrypt"], ciphertext)
+        }
+    }
+}
+
+/// Run the tool with `input` on stdin and return its stdout.
+///
+/// Arguments are passed directly to the process (no shell), so recipients and
+/// paths cannot inject commands.
+fn run_with_stdin(tool: EncryptionTool, args: &[&str], input: &str) -> Result<String> {
+    let program = tool.to_string();
+    let mut child = Command::new(&program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            if e.kind() == ErrorKind::NotFound {
+                JanusError::Encryption(format!("{program} is not installed"))
+            } else {
+                JanusError::Io(e)
+            }
+        })?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(JanusError::Encryption(format!(
+            "{program} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    String::from_utf8(output.stdout)
+        .map_err(|_| JanusError::Encryption(format!("{program} produced non-UTF-8 output")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encryption_tool_parse() {
+        assert_eq!(
+            "age".parse::<EncryptionTool>().unwrap(),
+            EncryptionTool::Age
+        );
+        assert_eq!(
+            "GPG".parse::<EncryptionTool>().unwrap(),
+            EncryptionTool::Gpg
+        );
+        assert!("pgp".parse::<EncryptionTool>().is_err());
+    }
+
+    #[test]
+    fn test_encrypt_requires_recipient() {
+        let result = encrypt(EncryptionTool::Age, &[], "secret");
+        assert!(matches!(result, Err(JanusError::Encryption(_))));
+    }
+
+    #[test]
+    fn test_age_decrypt_requires_identity() {
+        let result = decrypt(EncryptionTool::Age, None, "ciphertext");
+        assert!(matches!(result, Err(JanusError::Encryption(_))));
+    }
+}
@@ -0,0 +1,278 @@
+//! SLA evaluation for open tickets (`sla` config).
+//!
+//! Each rule in `sla.rules` can set a `start_within` window (the ticket must
+//! leave new/next in time) and a `close_within` window (the ticket must reach
+//! a terminal status in time). Both are measured from the ticket's `created`
+//! timestamp. A ticket is at risk once `sla.at_risk_percent` of a window has
+//! elapsed and breached once the whole window has.
+//!
+//! Only tickets that can still meet a window are evaluated: started tickets are
+//! exempt from start windows and closed tickets from both.
+
+use jiff::{SignedDuration, Timestamp};
+use serde::Serialize;
+
+use crate::config::{SlaConfig, SlaRule, SlaWindow};
+use crate::types::TicketMetadata;
+
+/// Which deadline of a rule a finding refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SlaKind {
+    Start,
+    Close,
+}
+
+enum_display!(
+    SlaKind,
+    {
+        Start => "start",
+        Close => "close",
+    }
+);
+
+/// How close a ticket is to missing a deadline. Ordered from least to most
+/// severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlaState {
+    AtRisk,
+    Breached,
+}
+
+enum_display!(
+    SlaState,
+    {
+        AtRisk => "at risk",
+        Breached => "breached",
+    }
+);
+
+/// A ticket that is at risk of missing, or has missed, one SLA deadline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlaFinding {
+    pub ticket_id: String,
+    /// Label of the rule (its name, or its criteria)
+    pub rule: String,
+    pub kind: SlaKind,
+    pub state: SlaState,
+    /// The rule's window as written in the config (`24h`)
+    pub window: String,
+    pub deadline: Timestamp,
+}
+
+impl SlaFinding {
+    /// Time left until the deadline; negative once it has passed.
+    pub fn remaining(&self, now: Timestamp) -> SignedDuration {
+        self.deadline.duration_since(now)
+    }
+
+    pub fn to_json(&self, now: Timestamp) -> serde_json::Value {
+        serde_json::json!({
+            "ticket_id": self.ticket_id,
+            "rule": self.rule,
+            "kind": self.kind,
+            "state": self.state,
+            "window": self.window,
+            "deadline": self.deadline.to_string(),
+            "remaining_secs": self.remaining(now).as_secs(),
+        })
+    }
+}
+
+/// Evaluate every matching rule against a ticket.
+///
+/// Returns an empty list for tickets without an ID or `created` timestamp,
+/// closed tickets, and tickets comfortably inside all their windows.
+pub fn evaluate_ticket(
+    ticket: &TicketMetadata,
+    config: &SlaConfig,
+    now: Timestamp,
+) -> Vec<SlaFinding> {
+    let status = ticket.status.unwrap_or_default();
+    if config.rules.is_empty() || status.is_terminal() {
+        return Vec::new();
+    }
+    let (Some(id), Some(created)) = (
+        ticket.id.as_deref(),
+        ticket.created.as_ref().and_then(|c| c.to_timestamp()),
+    ) else {
+        return Vec::new();
+    };
+
+    let priority = ticket.priority.unwrap_or_default();
+    let ticket_type = ticket.ticket_type.unwrap_or_default();
+    let mut findings = Vec::new();
+
+    for rule in config
+        .rules
+        .iter()
+        .filter(|r| r.matches(priority, ticket_type))
+    {
+        let windows = [
+            (SlaKind::Start, rule.start_within.as_ref()),
+            (SlaKind::Close, rule.close_within.as_ref()),
+        ];
+        for (kind, window) in windows {
+            let Some(window) = window else {
+                continue;
+            };
+            if kind == SlaKind::Start && !status.is_not_started() {
+                continue;
+            }
+            if let Some(finding) =
+                check_window(id, rule, kind, window, created, now, config.at_risk_percent)
+            {
+                findings.push(finding);
+            }
+        }
+    }
+
+    findings
+}
+
+/// Evaluate a list of tickets, most urgent first: breached before at risk,
+/// then by deadline.
+pub fn evaluate_tickets(
+    tickets: &[TicketMetadata],
+    config: &SlaConfig,
+    now: Timestamp,
+) -> Vec<SlaFinding> {
+    let mut findings: Vec<SlaFinding> = tickets
+        .iter()
+        .flat_map(|t| evaluate_ticket(t, config, now))
+        .collect();
+    findings.sort_by(|a, b| {
+        b.state
+            .cmp(&a.state)
+            .then_with(|| a.deadline.cmp(&b.deadline))
+            .then_with(|| a.ticket_id.cmp(&b.ticket_id))
+    });
+    findings
+}
+
+/// The most severe state among a ticket's findings.
+pub fn worst_state(findings: &[SlaFinding]) -> Option<SlaState> {
+    findings.iter().map(|f| f.state).max()
+}
+
+fn check_window(
+    id: &str,
+    rule: &SlaRule,
+    kind: SlaKind,
+    window: &SlaWindow,
+    created: Timestamp,
+    now: Timestamp,
+    at_risk_percent: u8,
+) -> Option<SlaFinding> {
+    let duration = window.duration();
+    let deadline = created.checked_add(duration).ok()?;
+    let elapsed = now.duration_since(created);
+
+    let state = if elapsed >= duration {
+        SlaState::Breached
+    } else if elapsed.as_secs() * 100 >= duration.as_secs() * i64::from(at_risk_percent) {
+        SlaState::AtRisk
+    } else {
+        return None;
+    };
+
+    Some(SlaFinding {
+        ticket_id: id.to_string(),
+        rule: rule.label(),
+        kind,
+        state,
+        window: window.to_string(),
+        deadline,
+    })
+}
+
+/// Format a duration compactly for display (`3d 4h`, `5h 12m`, `40m`).
+pub fn format_duration(duration: SignedDuration) -> String {
+    let total_mins = duration.as_secs().abs() / 60;
+    let (days, hours, mins) = (total_mins / 1440, (total_mins % 1440) / 60, total_mins % 60);
+    if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {mins}m")
+    } else {
+        format!("{mins}m")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CreatedAt, TicketId, TicketPriority, TicketStatus, TicketType};
+
+    const CREATED: &str = "2024-06-01T00:00:00Z";
+
+    fn make_ticket(status: TicketStatus, priority: TicketPriority) -> TicketMetadata {
+        TicketMetadata {
+            id: Some(TicketId::new_unchecked("j-a1b2")),
+            status: Some(status),
+            priority: Some(priority),
+            ticket_type: Some(TicketType::Bug),
+            created: Some(CreatedAt::new_unchecked(CREATED)),
+            ..Default::default()
+        }
+    }
+
+    fn config() -> SlaConfig {
+        serde_yaml_ng::from_str(
+            "rules:\n  - priority: 0\n    start_within: 24h\n  - type: bug\n    close_within: 10d\n",
+        )
+        .unwrap()
+    }
+
+    fn at(offset_hours: i64) -> Timestamp {
+        CREATED.parse::<Timestamp>().unwrap() + SignedDuration::from_hours(offset_hours)
+    }
+
+    #[test]
+    fn test_evaluate_ticket_states() {
+        let config = config();
+        let ticket = make_ticket(TicketStatus::New, TicketPriority::P0);
+
+        assert!(evaluate_ticket(&ticket, &config, at(12)).is_empty());
+
+        let findings = evaluate_ticket(&ticket, &config, at(20));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].kind, SlaKind::Start);
+        assert_eq!(findings[0].state, SlaState::AtRisk);
+        assert_eq!(findings[0].rule, "P0");
+        assert_eq!(findings[0].deadline, at(24));
+
+        let findings = evaluate_ticket(&ticket, &config, at(24 * 9));
+        assert_eq!(worst_state(&findings), Some(SlaState::Breached));
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[1].state, SlaState::AtRisk);
+    }
+
+    #[test]
+    fn test_evaluate_ticket_skips_met_windows() {
+        let config = config();
+
+        // Started tickets no longer have a start deadline
+        let started = make_ticket(TicketStatus::InProgress, TicketPriority::P0);
+        let findings = evaluate_ticket(&started, &config, at(24 * 9));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].kind, SlaKind::Close);
+
+        let closed = make_ticket(TicketStatus::Complete, TicketPriority::P0);
+        assert!(evaluate_ticket(&closed, &config, at(24 * 30)).is_empty());
+
+        let other = TicketMetadata {
+            ticket_type: Some(TicketType::Task),
+            ..make_ticket(TicketStatus::New, TicketPriority::P2)
+        };
+        assert!(evaluate_ticket(&other, &config, at(24 * 30)).is_empty());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(SignedDuration::from_mins(40)), "40m");
+        assert_eq!(format_duration(SignedDuration::from_mins(-312)), "5h 12m");
+        assert_eq!(format_duration(SignedDuration::from_hours(76)), "3d 4h");
+    }
+}
@@ -0,0 +1,139 @@
+//! Priority escalation.
+//!
+//! Evaluates the `escalation` section of `.janus/config.yaml` against open
+//! tickets. A ticket is escalated by the first rule that matches its type and
+//! current priority, once it has been open for the rule's `older_than` window.
+//! Each escalation raises the priority by one level, up to the rule's
+//! `max_priority`, and records `escalated-at` on the ticket so the window
+//! starts over before the next one.
+
+use jiff::Timestamp;
+
+use crate::config::{EscalationConfig, EscalationRule};
+use crate::error::Result;
+use crate::ticket::Ticket;
+use crate::types::{TicketMetadata, TicketPriority};
+
+/// A pending priority change for one ticket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Escalation {
+    pub id: String,
+    pub title: Option<String>,
+    pub from: TicketPriority,
+    pub to: TicketPriority,
+    /// Label of the rule that applies (see [`EscalationRule::label`])
+    pub rule: String,
+}
+
+/// The rule that escalates `ticket` at `now`, if any
+fn matching_rule<'a>(
+    ticket: &TicketMetadata,
+    rules: &'a [EscalationRule],
+    now: Timestamp,
+) -> Option<&'a EscalationRule> {
+    if ticket.status.unwrap_or_default().is_terminal() {
+        return None;
+    }
+    let since = ticket
+        .escalated_at
+        .as_ref()
+        .or(ticket.created.as_ref())
+        .and_then(|c| c.to_timestamp())?;
+    let priority = ticket.priority.unwrap_or_default();
+    let ticket_type = ticket.ticket_type.unwrap_or_default();
+
+    rules.iter().find(|rule| {
+        rule.matches(priority, ticket_type)
+            && priority.as_num() > rule.max_priority.as_num()
+            && now.duration_since(since) >= rule.older_than.duration()
+    })
+}
+
+/// The escalations due at `now`, ordered by ticket ID
+pub fn pending_escalations(
+    tickets: &[TicketMetadata],
+    config: &EscalationConfig,
+    now: Timestamp,
+) -> Vec<Escalation> {
+    let mut escalations: Vec<Escalation> = tickets
+        .iter()
+        .filter_map(|ticket| {
+            let rule = matching_rule(ticket, &config.rules, now)?;
+            let from = ticket.priority.unwrap_or_default();
+            Some(Escalation {
+                id: ticket.id.as_deref()?.to_string(),
+                title: ticket.title.clone(),
+                from,
+                to: from.prev(),
+                rule: rule.label(),
+            })
+        })
+        .collect();
+    escalations.sort_by(|a, b| a.id.cmp(&b.id));
+    escalations
+}
+
+/// Write an escalation to its ticket: the new priority, `escalated-at`, and a
+/// note recording the rule
+pub async fn apply_escalation(escalation: &Escalation, now: Timestamp) -> Result<()> {
+    let ticket = Ticket::find(&escalation.id).await?;
+    ticket.update_field("priority", &escalation.to.to_string())?;
+    ticket.update_field(
+        "escalated-at",
+        &now.strftime("%Y-%m-%dT%H:%M:%SZ").to_string(),
+    )?;
+    ticket.add_note(&format!(
+        "Escalated from P{} to P{} ({})",
+        escalation.from, escalation.to, escalation.rule
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CreatedAt, TicketId, TicketStatus, TicketType};
+
+    fn config() -> EscalationConfig {
+        serde_yaml_ng::from_str("rules:\n  - type: bug\n    older_than: 30d\n    max_priority: 1\n")
+            .unwrap()
+    }
+
+    fn bug(id: &str, priority: TicketPriority, created: &str) -> TicketMetadata {
+        TicketMetadata {
+            id: Some(TicketId::new_unchecked(id)),
+            status: Some(TicketStatus::New),
+            ticket_type: Some(TicketType::Bug),
+            priority: Some(priority),
+            created: Some(CreatedAt::new_unchecked(created)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pending_escalations() {
+        let now: Timestamp = "2025-06-30T00:00:00Z".parse().unwrap();
+        let mut escalated = bug("j-e", TicketPriority::P3, "2025-01-01T00:00:00Z");
+        escalated.escalated_at = Some(CreatedAt::new_unchecked("2025-06-20T00:00:00Z"));
+        let mut closed = bug("j-f", TicketPriority::P3, "2025-01-01T00:00:00Z");
+        closed.status = Some(TicketStatus::Complete);
+        let mut task = bug("j-g", TicketPriority::P3, "2025-01-01T00:00:00Z");
+        task.ticket_type = Some(TicketType::Task);
+        let tickets = vec![
+            bug("j-b", TicketPriority::P2, "2025-05-01T00:00:00Z"),
+            bug("j-a", TicketPriority::P4, "2025-05-01T00:00:00Z"),
+            bug("j-c", TicketPriority::P1, "2025-01-01T00:00:00Z"),
+            bug("j-d", TicketPriority::P2, "2025-06-15T00:00:00Z"),
+            escalated,
+            closed,
+            task,
+        ];
+
+        let escalations = pending_escalations(&tickets, &config(), now);
+        let summary: Vec<(&str, u8, u8)> = escalations
+            .iter()
+            .map(|e| (e.id.as_str(), e.from.as_num(), e.to.as_num()))
+            .collect();
+        assert_eq!(summary, vec![("j-a", 4, 3), ("j-b", 2, 1)]);
+        assert_eq!(escalations[0].rule, "bug older than 30d");
+    }
+}
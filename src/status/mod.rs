@@ -5,14 +5,22 @@
 
 use std::collections::HashMap;
 
+use jiff::Timestamp;
+
+use crate::config::ReadyRequirements;
 use crate::types::{TicketMetadata, TicketStatus};
 
+pub mod escalation;
 pub mod plan;
+pub mod transitions;
 
 pub use plan::{
     compute_aggregate_status, compute_all_phase_statuses, compute_phase_status,
     compute_plan_status, resolve_ticket_or_warn,
 };
+pub use transitions::{
+    TransitionVerdict, check_transition, enforce_transition, evaluate_transition,
+};
 
 /// Returns true if a status represents a terminal state (complete, cancelled, archived).
 ///
@@ -49,29 +57,55 @@ pub fn is_dependency_satisfied(dep_id: &str, ticket_map: &HashMap<String, Ticket
 /// Check whether ALL dependencies of a ticket are satisfied.
 ///
 /// Returns true if the ticket has no deps, or every dep is satisfied per
-/// [`is_dependency_satisfied`].
+/// [`is_dependency_satisfied`]. External deps are never satisfied, so any
+/// entry in `external-deps` makes this false.
 pub fn all_deps_satisfied(
     ticket: &TicketMetadata,
     ticket_map: &HashMap<String, TicketMetadata>,
 ) -> bool {
-    ticket
-        .deps
-        .iter()
-        .all(|dep_id| is_dependency_satisfied(dep_id, ticket_map))
+    ticket.external_deps.is_empty()
+        && ticket
+            .deps
+            .iter()
+            .all(|dep_id| is_dependency_satisfied(dep_id, ticket_map))
 }
 
 /// Check whether ANY dependency of a ticket is unsatisfied (blocking).
 ///
-/// Returns true if at least one dep is NOT satisfied per [`is_dependency_satisfied`].
-/// Returns false if the ticket has no deps.
+/// Returns true if at least one dep is NOT satisfied per [`is_dependency_satisfied`],
+/// or the ticket has any external deps. Returns false if the ticket has no deps.
 pub fn has_unsatisfied_dep(
     ticket: &TicketMetadata,
     ticket_map: &HashMap<String, TicketMetadata>,
 ) -> bool {
-    ticket
-        .deps
-        .iter()
-        .any(|dep_id| !is_dependency_satisfied(dep_id, ticket_map))
+    !ticket.external_deps.is_empty()
+        || ticket
+            .deps
+            .iter()
+            .any(|dep_id| !is_dependency_satisfied(dep_id, ticket_map))
+}
+
+/// Check whether a ticket could be picked up apart from its dependencies: it
+/// is new or next and meets every requirement in `require` except
+/// `deps_complete`.
+pub fn is_workable(ticket: &TicketMetadata, require: &ReadyRequirements, now: Timestamp) -> bool {
+    ticket.status.is_some_and(is_not_started)
+        && !(require.not_snoozed && ticket.is_snoozed_at(now))
+        && !(require.triaged && ticket.triaged != Some(true))
+        && !(require.acceptance_criteria && ticket.sections.acceptance.is_none())
+}
+
+/// Check whether a ticket is ready to work on under the configured
+/// definition (`ready.require`): it is [workable](is_workable) and, unless
+/// `deps_complete` is off, [all of its deps are satisfied](all_deps_satisfied).
+pub fn is_ready(
+    ticket: &TicketMetadata,
+    ticket_map: &HashMap<String, TicketMetadata>,
+    require: &ReadyRequirements,
+    now: Timestamp,
+) -> bool {
+    is_workable(ticket, require, now)
+        && (!require.deps_complete || all_deps_satisfied(ticket, ticket_map))
 }
 
 #[cfg(test)]
@@ -221,4 +255,44 @@ mod tests {
         let ticket = make_ticket("j-a", TicketStatus::New, vec!["j-b"]);
         assert!(has_unsatisfied_dep(&ticket, &map));
     }
+
+    #[test]
+    fn test_external_deps_always_block() {
+        let mut map = HashMap::new();
+        map.insert(
+            "j-b".to_string(),
+            make_ticket("j-b", TicketStatus::Complete, vec![]),
+        );
+        let mut ticket = make_ticket("j-a", TicketStatus::New, vec!["j-b"]);
+        ticket.external_deps = vec!["https://status.example.com".to_string()];
+        assert!(!all_deps_satisfied(&ticket, &map));
+        assert!(has_unsatisfied_dep(&ticket, &map));
+    }
+
+    #[test]
+    fn test_is_ready_requirements() {
+        let mut map = HashMap::new();
+        map.insert(
+            "j-dep".to_string(),
+            make_ticket("j-dep", TicketStatus::InProgress, vec![]),
+        );
+        let now = Timestamp::now();
+        let mut ticket = make_ticket("j-a", TicketStatus::New, vec!["j-dep"]);
+
+        let mut require = ReadyRequirements::default();
+        assert!(!is_ready(&ticket, &map, &require, now));
+        require.deps_complete = false;
+        assert!(is_ready(&ticket, &map, &require, now));
+
+        require.triaged = true;
+        require.acceptance_criteria = true;
+        assert!(!is_ready(&ticket, &map, &require, now));
+        ticket.triaged = Some(true);
+        assert!(!is_workable(&ticket, &require, now));
+        ticket.sections.acceptance = Some("- [ ] Works".to_string());
+        assert!(is_ready(&ticket, &map, &require, now));
+
+        ticket.status = Some(TicketStatus::InProgress);
+        assert!(!is_ready(&ticket, &map, &require, now));
+    }
 }
@@ -6,9 +6,10 @@
 
 use std::collections::HashMap;
 
+use crate::plan::forecast::size_points;
 use crate::plan::types::{Phase, PhaseStatus, PlanMetadata, PlanStatus};
 use crate::status::{is_not_started, is_terminal};
-use crate::types::{TicketMetadata, TicketStatus};
+use crate::types::{TicketMetadata, TicketSize, TicketStatus};
 
 // ============================================================================
 // Missing Ticket Policy
@@ -81,6 +82,8 @@ pub fn compute_plan_status(
             status: TicketStatus::New,
             completed_count: 0,
             total_count: 0,
+            completed_points: 0,
+            total_points: 0,
         };
     }
 
@@ -88,12 +91,14 @@ pub fn compute_plan_status(
 
     // Collect statuses of all referenced tickets, warning about missing ones
     let mut statuses: Vec<TicketStatus> = Vec::new();
+    let mut sizes: Vec<Option<TicketSize>> = Vec::new();
     for id in all_ticket_ids.iter() {
         if let Some(ticket) =
             resolve_ticket_or_warn(id, ticket_map, Some(&format!("in plan '{plan_id}'")))
             && let Some(status) = ticket.status
         {
             statuses.push(status);
+            sizes.push(ticket.size);
         }
     }
 
@@ -106,12 +111,15 @@ pub fn compute_plan_status(
         .filter(|s| matches!(**s, TicketStatus::Complete | TicketStatus::Archived))
         .count();
 
+    let (completed_points, total_points) = sum_points(&statuses, &sizes);
     let status = compute_aggregate_status(&statuses);
 
     PlanStatus {
         status,
         completed_count,
         total_count,
+        completed_points,
+        total_points,
     }
 }
 
@@ -133,23 +141,25 @@ fn compute_phase_status_impl(
             status: TicketStatus::New,
             completed_count: 0,
             total_count: 0,
+            completed_points: 0,
+            total_points: 0,
         };
     }
 
     // Collect statuses of all referenced tickets, warning about missing ones
     let mut statuses: Vec<TicketStatus> = Vec::new();
+    let mut sizes: Vec<Option<TicketSize>> = Vec::new();
     for id in &phase.ticket_list.tickets {
-        if warn_missing {
-            if let Some(ticket) =
-                resolve_ticket_or_warn(id, ticket_map, Some(&format!("in phase '{}'", phase.name)))
-                && let Some(status) = ticket.status
-            {
-                statuses.push(status);
-            }
-        } else if let Some(ticket) = ticket_map.get(id)
+        let ticket = if warn_missing {
+            resolve_ticket_or_warn(id, ticket_map, Some(&format!("in phase '{}'", phase.name)))
+        } else {
+            ticket_map.get(id)
+        };
+        if let Some(ticket) = ticket
             && let Some(status) = ticket.status
         {
             statuses.push(status);
+            sizes.push(ticket.size);
         }
     }
 
@@ -162,6 +172,7 @@ fn compute_phase_status_impl(
         .filter(|s| matches!(**s, TicketStatus::Complete | TicketStatus::Archived))
         .count();
 
+    let (completed_points, total_points) = sum_points(&statuses, &sizes);
     let status = compute_aggregate_status(&statuses);
 
     PhaseStatus {
@@ -170,9 +181,25 @@ fn compute_phase_status_impl(
         status,
         completed_count,
         total_count,
+        completed_points,
+        total_points,
     }
 }
 
+/// Completed and total size points of a set of tickets.
+///
+/// Tickets weigh [`size_points`] each, so unsized tickets count as medium.
+fn sum_points(statuses: &[TicketStatus], sizes: &[Option<TicketSize>]) -> (u32, u32) {
+    statuses
+        .iter()
+        .zip(sizes)
+        .fold((0, 0), |(completed, total), (status, size)| {
+            let points = size_points(*size);
+            let done = matches!(status, TicketStatus::Complete | TicketStatus::Archived);
+            (completed + if done { points } else { 0 }, total + points)
+        })
+}
+
 /// Compute the status of all phases in a plan.
 ///
 /// Returns a vector of `PhaseStatus` for each phase in document order.
@@ -687,6 +714,35 @@ mod tests {
         assert_eq!(status.total_count, 2);
     }
 
+    #[test]
+    fn test_compute_phased_plan_points_are_size_weighted() {
+        let metadata = make_phased_plan(vec![
+            ("1", "Phase One", vec!["t1", "t2"]),
+            ("2", "Phase Two", vec!["t3"]),
+        ]);
+
+        let mut ticket_map = HashMap::new();
+        let mut small = make_ticket("t1", TicketStatus::Complete);
+        small.size = Some(TicketSize::Small);
+        let mut xlarge = make_ticket("t2", TicketStatus::New);
+        xlarge.size = Some(TicketSize::XLarge);
+        ticket_map.insert("t1".to_string(), small);
+        ticket_map.insert("t2".to_string(), xlarge);
+        // Unsized tickets count as medium
+        ticket_map.insert("t3".to_string(), make_ticket("t3", TicketStatus::Complete));
+
+        let status = compute_plan_status(&metadata, &ticket_map);
+        assert_eq!((status.completed_count, status.total_count), (2, 3));
+        assert_eq!((status.completed_points, status.total_points), (5, 13));
+
+        let phases = compute_all_phase_statuses(&metadata, &ticket_map);
+        assert_eq!(
+            (phases[0].completed_points, phases[0].total_points),
+            (2, 10)
+        );
+        assert_eq!((phases[1].completed_points, phases[1].total_points), (3, 3));
+    }
+
     #[test]
     fn test_compute_aggregate_status_with_next() {
         // Test that Next status is treated as "not started"
@@ -716,6 +772,8 @@ mod tests {
             status: TicketStatus::InProgress,
             completed_count: 1,
             total_count: 4,
+            completed_points: 3,
+            total_points: 12,
         };
 
         assert_eq!(status.progress_percent(), 25.0);
@@ -728,6 +786,8 @@ mod tests {
             status: TicketStatus::InProgress,
             completed_count: 3,
             total_count: 10,
+            completed_points: 9,
+            total_points: 30,
         };
 
         assert_eq!(status.progress_percent(), 30.0);
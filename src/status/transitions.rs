@@ -0,0 +1,327 @@
+//! Protected status transitions.
+//!
+//! Evaluates the `transitions` section of `.janus/config.yaml` against a proposed
+//! status change. Every entry point that mutates a ticket's status (CLI commands
+//! including `set` and `expire`, the TUI cycler, board and remote sync, and the
+//! MCP `update_status` tool) calls
+//! [`evaluate_transition`] before writing, then resolves the resulting
+//! [`TransitionVerdict`] in whatever way suits its interaction model.
+
+use crate::config::{Config, TransitionPolicy, TransitionVia, TransitionsConfig};
+use crate::error::{JanusError, Result};
+use crate::store::get_or_init_store;
+use crate::types::TicketStatus;
+
+/// Outcome of checking a status transition against the configured rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransitionVerdict {
+    /// No rule applies; the transition may proceed.
+    Allowed,
+    /// A rule requires explicit confirmation before proceeding.
+    NeedsConfirmation(String),
+    /// A rule forbids the transition.
+    Forbidden(String),
+}
+
+impl TransitionVerdict {
+    /// Convert the verdict into a `Result`, treating `confirmed` as the caller's
+    /// explicit acknowledgement of any confirmation requirement.
+    pub fn into_result(
+        self,
+        id: &str,
+        from: TicketStatus,
+        to: TicketStatus,
+        confirmed: bool,
+    ) -> Result<()> {
+        match self {
+            TransitionVerdict::Allowed => Ok(()),
+            TransitionVerdict::NeedsConfirmation(_) if confirmed => Ok(()),
+            TransitionVerdict::NeedsConfirmation(reason) => {
+                Err(JanusError::ConfirmationRequired(format!(
+                    "status transition {from} -> {to} for '{id}' requires confirmation: {reason}"
+                )))
+            }
+            TransitionVerdict::Forbidden(reason) => Err(JanusError::TransitionForbidden {
+                id: id.to_string(),
+                from: from.to_string(),
+                to: to.to_string(),
+                reason,
+            }),
+        }
+    }
+}
+
+/// Check a transition against the configured rules.
+///
+/// `open_children` lists the IDs of non-terminal child tickets; it is only
+/// consulted when `to` is a terminal status. When several rules match, a
+/// forbidding rule wins over one that merely requires confirmation.
+pub fn check_transition(
+    config: &TransitionsConfig,
+    from: TicketStatus,
+    to: TicketStatus,
+    via: TransitionVia,
+    open_children: &[String],
+) -> TransitionVerdict {
+    if from == to {
+        return TransitionVerdict::Allowed;
+    }
+
+    let mut verdict = TransitionVerdict::Allowed;
+
+    for rule in &config.rules {
+        if rule.from.is_some_and(|s| s != from)
+            || rule.to.is_some_and(|s| s != to)
+            || rule.allow_via.contains(&via)
+        {
+            continue;
+        }
+        let reason = rule
+            .message
+            .clone()
+            .unwrap_or_else(|| format!("{from} -> {to} is protected by config"));
+        match rule.policy {
+            TransitionPolicy::Forbid => return TransitionVerdict::Forbidden(reason),
+            TransitionPolicy::Confirm => {
+                if verdict == TransitionVerdict::Allowed {
+                    verdict = TransitionVerdict::NeedsConfirmation(reason);
+                }
+            }
+        }
+    }
+
+    if let Some(policy) = config.open_children
+        && to.is_terminal()
+        && !open_children.is_empty()
+    {
+        let reason = format!("ticket has open children: {}", open_children.join(", "));
+        match policy {
+            TransitionPolicy::Forbid => return TransitionVerdict::Forbidden(reason),
+            TransitionPolicy::Confirm => {
+                if verdict == TransitionVerdict::Allowed {
+                    verdict = TransitionVerdict::NeedsConfirmation(reason);
+                }
+            }
+        }
+    }
+
+    verdict
+}
+
+/// Load the transition config and evaluate a proposed status change for a ticket.
+///
+/// Open children are only looked up when the config actually has an
+/// `open_children` policy, so unconfigured repositories pay nothing extra.
+pub async fn evaluate_transition(
+    ticket_id: &str,
+    from: TicketStatus,
+    to: TicketStatus,
+    via: TransitionVia,
+) -> Result<TransitionVerdict> {
+    let config = Config::load()?;
+    if config.transitions.is_default() {
+        return Ok(TransitionVerdict::Allowed);
+    }
+
+    let open_children = if config.transitions.open_children.is_some() && to.is_terminal() {
+        get_or_init_store().await?.get_open_children(ticket_id)
+    } else {
+        Vec::new()
+    };
+
+    Ok(check_transition(
+        &config.transitions,
+        from,
+        to,
+        via,
+        &open_children,
+    ))
+}
+
+/// Evaluate a transition and fail unless it is allowed (or `confirmed`).
+///
+/// Used by non-interactive entry points (TUI, MCP) that cannot prompt.
+pub async fn enforce_transition(
+    ticket_id: &str,
+    from: TicketStatus,
+    to: TicketStatus,
+    via: TransitionVia,
+    confirmed: bool,
+) -> Result<()> {
+    evaluate_transition(ticket_id, from, to, via)
+        .await?
+        .into_result(ticket_id, from, to, confirmed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TransitionRule;
+
+    fn rule(
+        from: Option<TicketStatus>,
+        to: Option<TicketStatus>,
+        policy: TransitionPolicy,
+        allow_via: Vec<TransitionVia>,
+    ) -> TransitionRule {
+        TransitionRule {
+            from,
+            to,
+            policy,
+            allow_via,
+            message: None,
+        }
+    }
+
+    #[test]
+    fn test_check_transition_no_rules_allows() {
+        let config = TransitionsConfig::default();
+        assert_eq!(
+            check_transition(
+                &config,
+                TicketStatus::Complete,
+                TicketStatus::New,
+                TransitionVia::Status,
+                &[]
+            ),
+            TransitionVerdict::Allowed
+        );
+    }
+
+    #[test]
+    fn test_check_transition_forbid_except_via_reopen() {
+        let config = TransitionsConfig {
+            rules: vec![rule(
+                Some(TicketStatus::Complete),
+                Some(TicketStatus::New),
+                TransitionPolicy::Forbid,
+                vec![TransitionVia::Reopen],
+            )],
+            open_children: None,
+        };
+
+        assert!(matches!(
+            check_transition(
+                &config,
+                TicketStatus::Complete,
+                TicketStatus::New,
+                TransitionVia::Status,
+                &[]
+            ),
+            TransitionVerdict::Forbidden(_)
+        ));
+        assert_eq!(
+            check_transition(
+                &config,
+                TicketStatus::Complete,
+                TicketStatus::New,
+                TransitionVia::Reopen,
+                &[]
+            ),
+            TransitionVerdict::Allowed
+        );
+    }
+
+    #[test]
+    fn test_check_transition_wildcard_from() {
+        let config = TransitionsConfig {
+            rules: vec![rule(
+                None,
+                Some(TicketStatus::Archived),
+                TransitionPolicy::Confirm,
+                vec![],
+            )],
+            open_children: None,
+        };
+
+        assert!(matches!(
+            check_transition(
+                &config,
+                TicketStatus::New,
+                TicketStatus::Archived,
+                TransitionVia::Tui,
+                &[]
+            ),
+            TransitionVerdict::NeedsConfirmation(_)
+        ));
+    }
+
+    #[test]
+    fn test_check_transition_forbid_wins_over_confirm() {
+        let config = TransitionsConfig {
+            rules: vec![
+                rule(
+                    None,
+                    Some(TicketStatus::New),
+                    TransitionPolicy::Confirm,
+                    vec![],
+                ),
+                rule(
+                    Some(TicketStatus::Complete),
+                    Some(TicketStatus::New),
+                    TransitionPolicy::Forbid,
+                    vec![],
+                ),
+            ],
+            open_children: None,
+        };
+
+        assert!(matches!(
+            check_transition(
+                &config,
+                TicketStatus::Complete,
+                TicketStatus::New,
+                TransitionVia::Mcp,
+                &[]
+            ),
+            TransitionVerdict::Forbidden(_)
+        ));
+    }
+
+    #[test]
+    fn test_check_transition_open_children() {
+        let config = TransitionsConfig {
+            rules: vec![],
+            open_children: Some(TransitionPolicy::Forbid),
+        };
+        let children = vec!["j-c3d4".to_string()];
+
+        match check_transition(
+            &config,
+            TicketStatus::InProgress,
+            TicketStatus::Complete,
+            TransitionVia::Close,
+            &children,
+        ) {
+            TransitionVerdict::Forbidden(reason) => assert!(reason.contains("j-c3d4")),
+            other => panic!("expected Forbidden, got {other:?}"),
+        }
+
+        // Non-terminal targets ignore open children
+        assert_eq!(
+            check_transition(
+                &config,
+                TicketStatus::New,
+                TicketStatus::InProgress,
+                TransitionVia::Start,
+                &children
+            ),
+            TransitionVerdict::Allowed
+        );
+    }
+
+    #[test]
+    fn test_verdict_into_result_confirmed() {
+        let verdict = TransitionVerdict::NeedsConfirmation("why".to_string());
+        assert!(
+            verdict
+                .clone()
+                .into_result("j-a1b2", TicketStatus::New, TicketStatus::Next, true)
+                .is_ok()
+        );
+        assert!(matches!(
+            verdict.into_result("j-a1b2", TicketStatus::New, TicketStatus::Next, false),
+            Err(JanusError::ConfirmationRequired(_))
+        ));
+    }
+}
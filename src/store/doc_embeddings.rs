@@ -0,0 +1,353 @@
+//! Persistent, incremental document chunk embeddings.
+//!
+//! Chunk embeddings are saved in `.janus/embeddings/` alongside ticket
+//! embeddings, keyed by a hash of the chunk text so unchanged chunks are
+//! reused. A manifest (`docs.json`) records a fingerprint of each document's
+//! content and chunking settings together with its chunk keys, so a sync only
+//! re-chunks and re-embeds documents whose fingerprint changed.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tokio::time::timeout;
+
+use super::TicketStore;
+use super::embeddings::{embeddings_dir, read_embedding_file};
+use crate::config::{Config, DocChunkingConfig};
+use crate::doc::{DocChunk, chunk_document};
+use crate::embedding::QuantizedEmbedding;
+use crate::embedding::model::{
+    EMBEDDING_BATCH_SIZE, EMBEDDING_MODEL_NAME, EMBEDDING_TIMEOUT, get_embedding_model,
+};
+use crate::error::{JanusError, Result};
+
+/// Manifest file name within the embeddings directory.
+const DOC_MANIFEST_FILE: &str = "docs.json";
+
+/// Per-document record of the last sync.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct DocManifest {
+    #[serde(default)]
+    docs: HashMap<String, DocManifestEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DocManifestEntry {
+    /// Hash of the file content, chunking settings, and embedding model
+    fingerprint: String,
+    chunks: Vec<ChunkRef>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ChunkRef {
+    start_line: usize,
+    /// Embedding file key (`{key}.bin`)
+    key: String,
+}
+
+/// Outcome of a document embedding sync.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DocSyncStats {
+    /// Documents in the store
+    pub docs: usize,
+    /// Documents that were re-chunked because they changed
+    pub changed: usize,
+    /// Documents dropped from the manifest because they no longer exist
+    pub removed: usize,
+    /// Chunks that needed a new embedding
+    pub embedded: usize,
+    /// Chunks of changed documents whose text was already embedded
+    pub reused: usize,
+}
+
+impl TicketStore {
+    /// Bring document chunk embeddings up to date.
+    ///
+    /// Documents whose content and chunking settings match the manifest are
+    /// skipped without being re-chunked. Changed documents are re-chunked, and
+    /// only chunks whose text has no saved embedding are sent to the model.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JanusError` if the embedding model fails or an embedding or
+    /// the manifest cannot be written. Embeddings saved before the failure
+    /// are reused by the next sync.
+    pub async fn sync_doc_embeddings(&self, chunking: &DocChunkingConfig) -> Result<DocSyncStats> {
+        let emb_dir = embeddings_dir();
+        let mut previous = read_manifest(&emb_dir);
+        let docs = self.doc_files();
+
+        let mut stats = DocSyncStats {
+            docs: docs.len(),
+            ..Default::default()
+        };
+        let mut manifest = DocManifest::default();
+
+        for (label, file_path) in docs {
+            let content = match fs::read_to_string(&file_path) {
+                Ok(content) => content,
+                Err(e) => {
+                    tracing::warn!("Failed to read doc {label} for embedding: {e}");
+                    continue;
+                }
+            };
+            let fingerprint = doc_fingerprint(&content, chunking);
+
+            if let Some(entry) = previous.docs.remove(&label)
+                && entry.fingerprint == fingerprint
+                && entry
+                    .chunks
+                    .iter()
+                    .all(|chunk| emb_dir.join(format!("{}.bin", chunk.key)).exists())
+            {
+                manifest.docs.insert(label, entry);
+                continue;
+            }
+
+            stats.changed += 1;
+            self.remove_doc_chunk_embeddings(&label);
+
+            let chunks = match chunk_document(&label, &content, chunking) {
+                Ok(chunks) => chunks,
+                Err(e) => {
+                    tracing::warn!("Failed to chunk doc {label}: {e}");
+                    continue;
+                }
+            };
+
+            let mut refs = Vec::with_capacity(chunks.len());
+            let mut to_embed: Vec<(usize, String, String)> = Vec::new();
+            for chunk in &chunks {
+                let text = chunk_text(chunk);
+                let key = chunk_key(&label, &text);
+                match read_embedding_file(&emb_dir, &key) {
+                    Some(vector) => {
+                        self.embeddings()
+                            .insert(format!("doc:{label}:c{}", chunk.start_line), vector);
+                        stats.reused += 1;
+                        refs.push(ChunkRef {
+                            start_line: chunk.start_line,
+                            key,
+                        });
+                    }
+                    None => to_embed.push((chunk.start_line, key, text)),
+                }
+            }
+
+            let texts: Vec<&str> = to_embed.iter().map(|(_, _, text)| text.as_str()).collect();
+            let vectors = embed_texts(&texts).await?;
+            for ((start_line, key, _), vector) in to_embed.into_iter().zip(vectors) {
+                Self::save_embedding(&key, &vector).map_err(|source| {
+                    JanusError::EmbeddingSaveFailed {
+                        key: key.clone(),
+                        source,
+                    }
+                })?;
+                self.embeddings()
+                    .insert(format!("doc:{label}:c{start_line}"), vector.into());
+                stats.embedded += 1;
+                refs.push(ChunkRef { start_line, key });
+            }
+
+            refs.sort_by_key(|chunk| chunk.start_line);
+            manifest.docs.insert(
+                label,
+                DocManifestEntry {
+                    fingerprint,
+                    chunks: refs,
+                },
+            );
+        }
+
+        stats.removed = previous.docs.len();
+        write_manifest(&emb_dir, &manifest)?;
+        Ok(stats)
+    }
+
+    /// Embedding file keys referenced by the document manifest.
+    ///
+    /// Pruning must keep these, since they are not derived from ticket files.
+    pub fn doc_embedding_file_keys() -> HashSet<String> {
+        read_manifest(&embeddings_dir())
+            .docs
+            .into_values()
+            .flat_map(|entry| entry.chunks.into_iter().map(|chunk| chunk.key))
+            .collect()
+    }
+
+    /// Read saved chunk embeddings for documents that are unchanged since the
+    /// last sync, as `(doc:{label}:c{line}, vector)` pairs.
+    pub(super) fn load_doc_chunk_embeddings(
+        &self,
+        emb_dir: &Path,
+    ) -> Vec<(String, QuantizedEmbedding)> {
+        if !emb_dir.join(DOC_MANIFEST_FILE).exists() {
+            return Vec::new();
+        }
+
+        let manifest = read_manifest(emb_dir);
+        let chunking = Config::load().map(|c| c.doc_chunking).unwrap_or_default();
+
+        let mut loaded = Vec::new();
+        for (label, file_path) in self.doc_files() {
+            let Some(entry) = manifest.docs.get(&label) else {
+                continue;
+            };
+            let Ok(content) = fs::read_to_string(&file_path) else {
+                continue;
+            };
+            if entry.fingerprint != doc_fingerprint(&content, &chunking) {
+                continue;
+            }
+            for chunk in &entry.chunks {
+                if let Some(vector) = read_embedding_file(emb_dir, &chunk.key) {
+                    loaded.push((format!("doc:{label}:c{}", chunk.start_line), vector));
+                }
+            }
+        }
+        loaded
+    }
+
+    /// Snapshot `(label, file_path)` for every document, releasing the docs
+    /// map before any file I/O.
+    fn doc_files(&self) -> Vec<(String, PathBuf)> {
+        self.docs()
+            .iter()
+            .filter_map(|entry| Some((entry.key().clone(), entry.value().file_path.clone()?)))
+            .collect()
+    }
+
+    /// Drop a document's chunk embeddings from memory.
+    fn remove_doc_chunk_embeddings(&self, label: &str) {
+        let prefix = format!("doc:{label}:c");
+        let keys: Vec<String> = self
+            .embeddings()
+            .iter()
+            .filter(|entry| entry.key().starts_with(&prefix))
+            .map(|entry| entry.key().clone())
+            .collect();
+        for key in keys {
+            self.embeddings().remove(&key);
+        }
+    }
+}
+
+/// Text sent to the model for a chunk: its heading path, then its content.
+fn chunk_text(chunk: &DocChunk) -> String {
+    format!("{}\n\n{}", chunk.heading_path_string(), chunk.content)
+}
+
+/// Content-addressed embedding key for a chunk of a document.
+fn chunk_key(label: &str, text: &str) -> String {
+    let input = format!("{EMBEDDING_MODEL_NAME}\0doc:{label}\0{text}");
+    blake3::hash(input.as_bytes()).to_hex().to_string()
+}
+
+/// Fingerprint of everything that determines a document's chunks and their
+/// embeddings.
+fn doc_fingerprint(content: &str, chunking: &DocChunkingConfig) -> String {
+    let input = format!(
+        "{EMBEDDING_MODEL_NAME}\0{}\0{}\0{}\0{content}",
+        chunking.chunk_size, chunking.overlap, chunking.split_on_headings
+    );
+    blake3::hash(input.as_bytes()).to_hex().to_string()
+}
+
+/// Embed texts in batches, with the same per-item timeout as `cache rebuild`.
+async fn embed_texts(texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+    if texts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let model = get_embedding_model()
+        .await
+        .map_err(JanusError::EmbeddingModel)?;
+    let mut vectors = Vec::with_capacity(texts.len());
+    for batch in texts.chunks(EMBEDDING_BATCH_SIZE) {
+        let batch_timeout = EMBEDDING_TIMEOUT.saturating_mul(batch.len() as u32);
+        let embedded = timeout(batch_timeout, model.embed_batch(batch))
+            .await
+            .map_err(|_| {
+                JanusError::EmbeddingGenerationFailed(format!(
+                    "timed out after {} seconds",
+                    batch_timeout.as_secs()
+                ))
+            })?
+            .map_err(JanusError::EmbeddingGenerationFailed)?;
+        vectors.extend(embedded);
+    }
+    Ok(vectors)
+}
+
+/// Read the manifest, treating a missing or unreadable file as empty so the
+/// next sync rebuilds it.
+fn read_manifest(emb_dir: &Path) -> DocManifest {
+    let path = emb_dir.join(DOC_MANIFEST_FILE);
+    let Ok(data) = fs::read_to_string(&path) else {
+        return DocManifest::default();
+    };
+    serde_json::from_str(&data).unwrap_or_else(|e| {
+        tracing::warn!("Ignoring invalid doc embedding manifest {path:?}: {e}");
+        DocManifest::default()
+    })
+}
+
+fn write_manifest(emb_dir: &Path, manifest: &DocManifest) -> Result<()> {
+    fs::create_dir_all(emb_dir)?;
+    fs::write(
+        emb_dir.join(DOC_MANIFEST_FILE),
+        serde_json::to_string_pretty(manifest)?,
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_doc_fingerprint_tracks_content_and_settings() {
+        let config = DocChunkingConfig::default();
+        let base = doc_fingerprint("# Title\n", &config);
+        assert_eq!(base, doc_fingerprint("# Title\n", &config));
+        assert_ne!(base, doc_fingerprint("# Other\n", &config));
+
+        let smaller = DocChunkingConfig {
+            chunk_size: 500,
+            ..Default::default()
+        };
+        assert_ne!(base, doc_fingerprint("# Title\n", &smaller));
+    }
+
+    #[test]
+    fn test_chunk_key_is_per_document() {
+        assert_eq!(chunk_key("a", "text"), chunk_key("a", "text"));
+        assert_ne!(chunk_key("a", "text"), chunk_key("b", "text"));
+    }
+
+    #[test]
+    fn test_read_manifest_missing_or_invalid() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(read_manifest(dir.path()).docs.is_empty());
+
+        fs::write(dir.path().join(DOC_MANIFEST_FILE), "not json").unwrap();
+        assert!(read_manifest(dir.path()).docs.is_empty());
+
+        let mut manifest = DocManifest::default();
+        manifest.docs.insert(
+            "ops".to_string(),
+            DocManifestEntry {
+                fingerprint: "abc".to_string(),
+                chunks: vec![ChunkRef {
+                    start_line: 4,
+                    key: "k".to_string(),
+                }],
+            },
+        );
+        write_manifest(dir.path(), &manifest).unwrap();
+        let loaded = read_manifest(dir.path());
+        assert_eq!(loaded.docs["ops"].chunks[0].start_line, 4);
+    }
+}
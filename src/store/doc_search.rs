@@ -1,15 +1,40 @@
-//! Semantic search for project knowledge documents.
+//! Search for project knowledge documents.
 //!
 //! This module provides document-level and chunk-level semantic search
 //! capabilities, allowing users to find relevant content within project
-//! knowledge documents.
+//! knowledge documents. Lexical search over the same chunks works without
+//! embeddings, and [`merge_doc_results`] combines both into a hybrid ranking.
 
 use std::cmp::Ordering;
-use std::collections::BinaryHeap;
+use std::collections::HashMap;
 
 use super::TicketStore;
-use crate::doc::types::DocMetadata;
-use crate::embedding::model::cosine_similarity;
+use crate::config::Config;
+use crate::doc::chunk_document;
+use crate::doc::types::{DocChunk, DocMetadata};
+
+/// Maximum length of a lexical match snippet, in bytes
+const SNIPPET_LEN: usize = 200;
+
+/// How a document search result was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocMatchKind {
+    /// Matched by embedding similarity
+    Semantic,
+    /// Matched by query terms appearing in the text
+    Lexical,
+    /// Matched both ways
+    Hybrid,
+}
+
+enum_display!(
+    DocMatchKind,
+    {
+        Semantic => "semantic",
+        Lexical => "lexical",
+        Hybrid => "hybrid",
+    }
+);
 
 /// Result of a document semantic search.
 #[derive(Debug, Clone)]
@@ -25,40 +50,11 @@ pub struct DocSearchResult {
     pub content_snippet: String,
     /// The line range in the original document (start, end)
     pub line_range: (usize, usize),
-    /// Cosine similarity score
+    /// Relevance score: cosine similarity for semantic matches, the fraction
+    /// of query terms found for lexical matches, and their mean for hybrid ones
     pub similarity: f32,
-}
-
-/// A scored candidate for top-K selection via a min-heap.
-///
-/// Wraps a document/chunk key and similarity score.
-struct ScoredDocCandidate {
-    key: String,
-    similarity: f32,
-}
-
-impl PartialEq for ScoredDocCandidate {
-    fn eq(&self, other: &Self) -> bool {
-        self.similarity == other.similarity
-    }
-}
-
-impl Eq for ScoredDocCandidate {}
-
-impl PartialOrd for ScoredDocCandidate {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for ScoredDocCandidate {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Ascending order for min-heap behavior
-        other
-            .similarity
-            .partial_cmp(&self.similarity)
-            .unwrap_or(Ordering::Equal)
-    }
+    /// How the result was found
+    pub match_kind: DocMatchKind,
 }
 
 impl TicketStore {
@@ -77,41 +73,14 @@ impl TicketStore {
     ///
     /// A vector of `DocSearchResult` sorted by similarity (highest first).
     pub fn doc_search(&self, query_embedding: &[f32], limit: usize) -> Vec<DocSearchResult> {
-        if limit == 0 {
-            return Vec::new();
-        }
-
-        let mut heap: BinaryHeap<ScoredDocCandidate> = BinaryHeap::with_capacity(limit + 1);
-
         // Search through all embeddings that start with "doc:"
-        for entry in self.embeddings().iter() {
-            let key = entry.key();
-            if !key.starts_with("doc:") {
-                continue;
-            }
-
-            let similarity = cosine_similarity(query_embedding, entry.value());
-
-            if heap.len() < limit {
-                heap.push(ScoredDocCandidate {
-                    key: key.clone(),
-                    similarity,
-                });
-            } else if let Some(min) = heap.peek()
-                && similarity > min.similarity
-            {
-                heap.pop();
-                heap.push(ScoredDocCandidate {
-                    key: key.clone(),
-                    similarity,
-                });
-            }
-        }
+        let candidates =
+            self.nearest_embeddings(query_embedding, limit, |key| key.starts_with("doc:"));
 
-        // Convert heap entries to results
-        let mut results: Vec<DocSearchResult> = heap
+        // Convert candidates to results
+        let mut results: Vec<DocSearchResult> = candidates
             .into_iter()
-            .filter_map(|candidate| self.build_doc_result(&candidate.key, candidate.similarity))
+            .filter_map(|(key, similarity)| self.build_doc_result(&key, similarity))
             .collect();
 
         // Sort by similarity descending
@@ -166,6 +135,7 @@ impl TicketStore {
                 content_snippet,
                 line_range,
                 similarity,
+                match_kind: DocMatchKind::Semantic,
             })
         } else {
             // This is a document-level result
@@ -176,26 +146,28 @@ impl TicketStore {
                 content_snippet: doc.description.clone().unwrap_or_default(),
                 line_range: (0, 0),
                 similarity,
+                match_kind: DocMatchKind::Semantic,
             })
         }
     }
 
     /// Get chunk information from a document.
     ///
-    /// Re-chunks the document to find the chunk at the given start line.
+    /// Re-chunks the document with the configured chunking to find the chunk
+    /// at the given start line.
     fn get_chunk_info(
         &self,
         label: &str,
         start_line: usize,
         file_path: &std::path::Path,
     ) -> (Vec<String>, String, (usize, usize)) {
-        use crate::doc::chunk_document;
+        let chunking = Config::load().map(|c| c.doc_chunking).unwrap_or_default();
 
         // Read the document content
         match std::fs::read_to_string(file_path) {
             Ok(content) => {
                 // Chunk the document
-                match chunk_document(label, &content) {
+                match chunk_document(label, &content, &chunking) {
                     Ok(chunks) => {
                         // Find the chunk with the matching start line
                         if let Some(chunk) = chunks.iter().find(|c| c.start_line == start_line) {
@@ -271,44 +243,19 @@ impl TicketStore {
         label: &str,
         limit: usize,
     ) -> Vec<DocSearchResult> {
-        if limit == 0 {
-            return Vec::new();
-        }
-
-        let mut heap: BinaryHeap<ScoredDocCandidate> = BinaryHeap::with_capacity(limit + 1);
-
         // Build prefix for this document's embeddings
         let doc_prefix = format!("doc:{label}:");
         let doc_key = format!("doc:{label}");
 
-        // Search through embeddings for this specific document
-        for entry in self.embeddings().iter() {
-            let key = entry.key();
-            // Match either doc:{label} (document-level) or doc:{label}:c{line} (chunk-level)
-            if key == &doc_key || key.starts_with(&doc_prefix) {
-                let similarity = cosine_similarity(query_embedding, entry.value());
-
-                if heap.len() < limit {
-                    heap.push(ScoredDocCandidate {
-                        key: key.clone(),
-                        similarity,
-                    });
-                } else if let Some(min) = heap.peek()
-                    && similarity > min.similarity
-                {
-                    heap.pop();
-                    heap.push(ScoredDocCandidate {
-                        key: key.clone(),
-                        similarity,
-                    });
-                }
-            }
-        }
+        // Match either doc:{label} (document-level) or doc:{label}:c{line} (chunk-level)
+        let candidates = self.nearest_embeddings(query_embedding, limit, |key| {
+            key == doc_key || key.starts_with(&doc_prefix)
+        });
 
-        // Convert heap entries to results
-        let mut results: Vec<DocSearchResult> = heap
+        // Convert candidates to results
+        let mut results: Vec<DocSearchResult> = candidates
             .into_iter()
-            .filter_map(|candidate| self.build_doc_result(&candidate.key, candidate.similarity))
+            .filter_map(|(key, similarity)| self.build_doc_result(&key, similarity))
             .collect();
 
         // Sort by similarity descending
@@ -320,10 +267,167 @@ impl TicketStore {
 
         results
     }
+
+    /// Perform lexical search over document chunks.
+    ///
+    /// Chunks are scored by the fraction of query terms they contain (in the
+    /// content or heading path), so this works without embeddings. Restrict
+    /// the search to one document with `label`.
+    pub fn doc_lexical_search(
+        &self,
+        query: &str,
+        label: Option<&str>,
+        limit: usize,
+    ) -> Vec<DocSearchResult> {
+        let terms = query_terms(query);
+        if limit == 0 || terms.is_empty() {
+            return Vec::new();
+        }
+
+        let chunking = Config::load().map(|c| c.doc_chunking).unwrap_or_default();
+        let docs: Vec<DocMetadata> = self
+            .docs()
+            .iter()
+            .filter(|entry| label.is_none_or(|l| entry.key() == l))
+            .map(|entry| entry.value().clone())
+            .collect();
+
+        let mut results = Vec::new();
+        for doc in docs {
+            let (Some(doc_label), Some(file_path)) = (doc.label(), doc.file_path()) else {
+                continue;
+            };
+            let Ok(content) = std::fs::read_to_string(file_path) else {
+                continue;
+            };
+            let Ok(chunks) = chunk_document(doc_label, &content, &chunking) else {
+                continue;
+            };
+
+            for chunk in chunks {
+                let score = lexical_score(&terms, &chunk);
+                if score > 0.0 {
+                    results.push(DocSearchResult {
+                        label: doc_label.to_string(),
+                        doc: doc.clone(),
+                        content_snippet: lexical_snippet(&chunk.content, &terms),
+                        heading_path: chunk.heading_path,
+                        line_range: (chunk.start_line, chunk.end_line),
+                        similarity: score,
+                        match_kind: DocMatchKind::Lexical,
+                    });
+                }
+            }
+        }
+
+        sort_by_score(&mut results);
+        results.truncate(limit);
+        results
+    }
+}
+
+/// Merge semantic and lexical results into one ranking.
+///
+/// Results for the same chunk (same document and start line) are combined
+/// into a hybrid result scored by the mean of both scores, keeping the
+/// lexical snippet since it is centered on the matched terms.
+pub fn merge_doc_results(
+    semantic: Vec<DocSearchResult>,
+    lexical: Vec<DocSearchResult>,
+    limit: usize,
+) -> Vec<DocSearchResult> {
+    let mut merged: Vec<DocSearchResult> = Vec::with_capacity(semantic.len() + lexical.len());
+    let mut index: HashMap<(String, usize), usize> = HashMap::new();
+
+    for result in semantic.into_iter().chain(lexical) {
+        let key = (result.label.clone(), result.line_range.0);
+        match index.get(&key) {
+            Some(&i) => {
+                let existing = &mut merged[i];
+                existing.similarity = (existing.similarity + result.similarity) / 2.0;
+                existing.match_kind = DocMatchKind::Hybrid;
+                if result.match_kind == DocMatchKind::Lexical {
+                    existing.content_snippet = result.content_snippet;
+                }
+            }
+            None => {
+                index.insert(key, merged.len());
+                merged.push(result);
+            }
+        }
+    }
+
+    sort_by_score(&mut merged);
+    merged.truncate(limit);
+    merged
+}
+
+/// Split a query into lowercase search terms, dropping duplicates.
+pub fn query_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for term in query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+    {
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+/// Fraction of `terms` found in a chunk's content or heading path.
+fn lexical_score(terms: &[String], chunk: &DocChunk) -> f32 {
+    let content = chunk.content.to_lowercase();
+    let headings = chunk.heading_path.join(" ").to_lowercase();
+    let matched = terms
+        .iter()
+        .filter(|term| content.contains(term.as_str()) || headings.contains(term.as_str()))
+        .count();
+    matched as f32 / terms.len() as f32
+}
+
+/// A single-line excerpt of `content` around the first matched term.
+pub fn lexical_snippet(content: &str, terms: &[String]) -> String {
+    let flat = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    if flat.len() <= SNIPPET_LEN {
+        return flat;
+    }
+
+    // ASCII lowercasing keeps byte offsets aligned with `flat`
+    let lower = flat.to_ascii_lowercase();
+    let first_match = terms
+        .iter()
+        .filter_map(|term| lower.find(term.as_str()))
+        .min()
+        .unwrap_or(0);
+
+    let mut start = first_match.saturating_sub(SNIPPET_LEN / 4);
+    while !flat.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (start + SNIPPET_LEN).min(flat.len());
+    while !flat.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    let prefix = if start > 0 { "..." } else { "" };
+    let suffix = if end < flat.len() { "..." } else { "" };
+    format!("{prefix}{}{suffix}", &flat[start..end])
+}
+
+fn sort_by_score(results: &mut [DocSearchResult]) {
+    results.sort_by(|a, b| {
+        b.similarity
+            .partial_cmp(&a.similarity)
+            .unwrap_or(Ordering::Equal)
+    });
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{DocMatchKind, merge_doc_results, query_terms};
     use crate::doc::types::{DocLabel, DocMetadata};
     use crate::store::TicketStore;
 
@@ -340,7 +444,7 @@ mod tests {
         // Embedding that points in the "rust" direction
         store
             .embeddings()
-            .insert("doc:rust-guide".to_string(), vec![0.9, 0.1, 0.0]);
+            .insert("doc:rust-guide".to_string(), vec![0.9, 0.1, 0.0].into());
 
         // Document 2: "python" direction
         store.upsert_doc(DocMetadata {
@@ -352,7 +456,7 @@ mod tests {
         // Embedding that points in the "python" direction
         store
             .embeddings()
-            .insert("doc:python-guide".to_string(), vec![0.0, 0.9, 0.1]);
+            .insert("doc:python-guide".to_string(), vec![0.0, 0.9, 0.1].into());
 
         // Document 3: "javascript" direction
         store.upsert_doc(DocMetadata {
@@ -364,7 +468,7 @@ mod tests {
         // Embedding that points in the "js" direction
         store
             .embeddings()
-            .insert("doc:js-guide".to_string(), vec![0.1, 0.0, 0.9]);
+            .insert("doc:js-guide".to_string(), vec![0.1, 0.0, 0.9].into());
 
         // Document 4: no embedding
         store.upsert_doc(DocMetadata {
@@ -374,9 +478,10 @@ mod tests {
         });
 
         // Add a chunk embedding for rust-guide
-        store
-            .embeddings()
-            .insert("doc:rust-guide:c10".to_string(), vec![0.95, 0.05, 0.0]);
+        store.embeddings().insert(
+            "doc:rust-guide:c10".to_string(),
+            vec![0.95, 0.05, 0.0].into(),
+        );
 
         store
     }
@@ -567,4 +672,67 @@ mod tests {
             assert!(window[0].similarity >= window[1].similarity);
         }
     }
+
+    fn store_with_doc_file(dir: &std::path::Path) -> TicketStore {
+        let file_path = dir.join("ops.md");
+        std::fs::write(
+            &file_path,
+            "---\nlabel: ops\n---\n# Operations\n\nDeploys run nightly.\n\n## Retry Policy\n\nFailed jobs retry three times with backoff.\n",
+        )
+        .unwrap();
+
+        let store = TicketStore::empty();
+        store.upsert_doc(DocMetadata {
+            label: Some(DocLabel::new_unchecked("ops")),
+            title: Some("Operations".to_string()),
+            file_path: Some(file_path),
+            ..Default::default()
+        });
+        store
+    }
+
+    #[test]
+    fn test_query_terms() {
+        assert_eq!(query_terms("Retry policy, retry!"), vec!["retry", "policy"]);
+        assert!(query_terms("  --  ").is_empty());
+    }
+
+    #[test]
+    fn test_doc_lexical_search() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = store_with_doc_file(tmp.path());
+
+        let results = store.doc_lexical_search("retry policy", None, 10);
+        assert!(!results.is_empty());
+        assert_eq!(results[0].label, "ops");
+        assert_eq!(results[0].heading_path.last().unwrap(), "Retry Policy");
+        assert_eq!(results[0].similarity, 1.0);
+        assert_eq!(results[0].match_kind, DocMatchKind::Lexical);
+        assert!(results[0].content_snippet.contains("retry three times"));
+
+        assert!(store.doc_lexical_search("kubernetes", None, 10).is_empty());
+        assert!(
+            store
+                .doc_lexical_search("retry", Some("other"), 10)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_merge_doc_results() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = store_with_doc_file(tmp.path());
+        let lexical = store.doc_lexical_search("retry policy", None, 10);
+
+        let mut semantic = lexical[0].clone();
+        semantic.similarity = 0.5;
+        semantic.match_kind = DocMatchKind::Semantic;
+        semantic.content_snippet = "semantic snippet".to_string();
+
+        let merged = merge_doc_results(vec![semantic], lexical.clone(), 10);
+        assert_eq!(merged.len(), lexical.len());
+        assert_eq!(merged[0].match_kind, DocMatchKind::Hybrid);
+        assert_eq!(merged[0].similarity, 0.75);
+        assert_eq!(merged[0].content_snippet, lexical[0].content_snippet);
+    }
 }
@@ -1,82 +1,145 @@
 use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
-use std::time::UNIX_EPOCH;
 
 use super::TicketStore;
-use crate::embedding::model::EMBEDDING_DIMENSIONS;
+use crate::embedding::QuantizedEmbedding;
+use crate::embedding::model::{
+    EMBEDDING_DIMENSIONS, EMBEDDING_MODEL_NAME, note_embedding_text, objective_embedding_text,
+    plan_embedding_text, ticket_embedding_text,
+};
 use crate::error::JanusError;
-use crate::types::janus_root;
+use crate::objective::types::ObjectiveMetadata;
+use crate::plan::types::PlanMetadata;
+use crate::ticket::parse_notes;
+use crate::types::{TicketMetadata, janus_root};
 
 /// Directory name for embedding storage within the Janus root.
 const EMBEDDINGS_DIR: &str = "embeddings";
 
+/// Prefix of the in-memory embedding IDs of plans (`plan:{id}`) and their
+/// phases (`plan:{id}:p{number}`).
+pub(super) const PLAN_EMBEDDING_PREFIX: &str = "plan:";
+
+/// Prefix of the in-memory embedding IDs of ticket notes
+/// (`note:{ticket_id}:{index}`).
+pub(super) const NOTE_EMBEDDING_PREFIX: &str = "note:";
+
 /// Return the path to the embeddings directory.
-fn embeddings_dir() -> std::path::PathBuf {
+pub(super) fn embeddings_dir() -> std::path::PathBuf {
     janus_root().join(EMBEDDINGS_DIR)
 }
 
 impl TicketStore {
-    /// Compute the embedding key for a ticket file.
-    ///
-    /// The key is `hex(blake3(file_path_string + ":" + mtime_ns_string))`.
-    /// This produces a content-addressable key that changes when the file is modified.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the file path cannot be made relative to the Janus root.
-    /// This ensures cache key consistency - absolute paths can vary between runs
-    /// depending on how the repository is accessed (symlinks, different working
-    /// directories, etc.).
-    ///
-    /// # Filesystem Precision Caveat
-    ///
-    /// This key relies on file modification time (mtime) at nanosecond granularity,
-    /// but filesystem timestamp precision varies:
+    /// Compute the embedding key for the text sent to the model.
     ///
-    /// - **APFS** (modern macOS): nanosecond precision
-    /// - **ext4** (Linux): nanosecond precision
-    /// - **HFS+** (older macOS): 1-second precision
-    /// - **FAT32**: 2-second precision
-    /// - **NFS**: varies, often second-only
-    ///
-    /// On filesystems with low-precision timestamps, rapid successive edits within
-    /// the precision window will not change the mtime, so the embedding key remains
-    /// the same and stale embeddings may be served for modified content. A truly
-    /// robust fix would hash file content instead of mtime, but that defeats the
-    /// purpose of the fast mtime-based cache invalidation check.
+    /// The key is `hex(blake3(model_name + "\0" + text))`, so it only changes
+    /// when the embedded text (or the model) does. Edits that leave a ticket's
+    /// title and body alone, such as a status change or a new tag, keep the
+    /// same key and reuse the saved embedding instead of re-embedding.
+    pub fn embedding_key(text: &str) -> String {
+        let input = format!("{EMBEDDING_MODEL_NAME}\0{text}");
+        blake3::hash(input.as_bytes()).to_hex().to_string()
+    }
+
+    /// Embedding file keys for the current content of every ticket,
+    /// objective, plan, phase, and note backed by a file.
     ///
-    pub fn embedding_key(file_path: &Path, mtime_ns: u128) -> crate::error::Result<String> {
-        // Use a repo-relative path for stability. file_path.display() can vary
-        // between runs depending on absolute/relative handling, symlink resolution,
-        // and platform formatting—causing cache misses and orphaned .bin files.
-        let root = janus_root();
-        let relative =
-            file_path
-                .strip_prefix(&root)
-                .map_err(|_| JanusError::EmbeddingKeyError {
-                    path: file_path.to_string_lossy().to_string(),
-                    root: root.to_string_lossy().to_string(),
-                })?;
-        // Use forward slashes for cross-platform consistency
-        let stable_path: String = relative
-            .components()
-            .map(|c| c.as_os_str().to_string_lossy())
-            .collect::<Vec<_>>()
-            .join("/");
-        let input = format!("{stable_path}:{mtime_ns}");
-        let hash = blake3::hash(input.as_bytes());
-        Ok(hash.to_hex().to_string())
+    /// Pruning must keep these, along with
+    /// [`doc_embedding_file_keys`](Self::doc_embedding_file_keys).
+    pub fn embedding_file_keys(&self) -> HashSet<String> {
+        self.embedding_targets()
+            .into_iter()
+            .map(|(_, key)| key)
+            .collect()
+    }
+
+    /// Snapshot `(id, embedding key)` for every ticket, objective, plan, phase,
+    /// and note backed by a file, releasing the source maps before any
+    /// embeddings map access. This prevents AB/BA deadlocks between the maps under
+    /// concurrent access.
+    fn embedding_targets(&self) -> Vec<(String, String)> {
+        let tickets: Vec<(String, String)> = self
+            .tickets()
+            .iter()
+            .filter_map(|entry| {
+                let ticket = entry.value();
+                ticket.file_path.as_ref()?;
+                let id = ticket.id.clone()?.to_string();
+                Some((id, Self::embedding_key(&ticket_text(ticket))))
+            })
+            .collect();
+
+        let objectives: Vec<(String, String)> = self
+            .objectives()
+            .iter()
+            .filter_map(|entry| {
+                let objective = entry.value();
+                objective.file_path.as_ref()?;
+                let id = objective.id.clone()?.to_string();
+                Some((id, Self::embedding_key(&objective_text(objective))))
+            })
+            .collect();
+
+        let content = self
+            .plan_targets(None)
+            .into_iter()
+            .chain(self.note_targets(None))
+            .map(|(id, text)| (id, Self::embedding_key(&text)));
+
+        tickets
+            .into_iter()
+            .chain(objectives)
+            .chain(content)
+            .collect()
+    }
+
+    /// Snapshot `(embedding id, text)` for every plan backed by a file and
+    /// each of its phases, or only for the plan `only`.
+    fn plan_targets(&self, only: Option<&str>) -> Vec<(String, String)> {
+        self.plans()
+            .iter()
+            .filter(|entry| {
+                entry.value().file_path.is_some()
+                    && only.is_none_or(|id| id == entry.key().as_str())
+            })
+            .flat_map(|entry| plan_texts(entry.key(), entry.value()))
+            .collect()
+    }
+
+    /// Snapshot `(embedding id, text)` for every note of every ticket backed
+    /// by a file, or only for the notes of ticket `only`.
+    fn note_targets(&self, only: Option<&str>) -> Vec<(String, String)> {
+        self.tickets()
+            .iter()
+            .filter(|entry| {
+                entry.value().file_path.is_some()
+                    && only.is_none_or(|id| id == entry.key().as_str())
+            })
+            .flat_map(|entry| note_texts(entry.key(), entry.value()))
+            .collect()
+    }
+
+    /// Remove the in-memory embeddings with ID `owner` or starting with
+    /// `{owner}:`, such as a plan and all of its phases.
+    pub(super) fn remove_embeddings_under(&self, owner: &str) {
+        let nested = format!("{owner}:");
+        self.embeddings()
+            .retain(|key, _| key != owner && !key.starts_with(&nested));
     }
 
-    /// Load all embeddings from `.janus/embeddings/` for current tickets and objectives.
+    /// Load all embeddings from `.janus/embeddings/` for current tickets,
+    /// objectives, plans, and notes.
     ///
-    /// For each ticket and objective, computes the expected key from file_path + mtime,
-    /// checks if `.janus/embeddings/{key}.bin` exists, and loads it into
-    /// the embeddings DashMap.
+    /// For each ticket, objective, plan, phase, and note, computes the expected key from the text
+    /// it would be embedded from, checks if `.janus/embeddings/{key}.bin`
+    /// exists, and loads it into the embeddings DashMap. Document chunk
+    /// embeddings are loaded for documents that are unchanged since the last
+    /// `janus cache sync`.
     ///
-    /// As a secondary validation, embeddings whose dimension count does not
-    /// match [`EMBEDDING_DIMENSIONS`] are silently skipped. This guards
+    /// Files in the older f32 format are quantized as they load. As a secondary
+    /// validation, embeddings whose dimension count does not match
+    /// [`EMBEDDING_DIMENSIONS`] are silently skipped. This guards
     /// against loading corrupted or incompatible `.bin` files (e.g., from a
     /// model change).
     ///
@@ -93,52 +156,19 @@ impl TicketStore {
             return Ok(());
         }
 
-        // Snapshot the ticket data we need (id + file_path) into a local Vec,
-        // so that all tickets DashMap shard locks are released before we touch
-        // the embeddings DashMap. This prevents AB/BA deadlocks between the
-        // two maps under concurrent access.
-        let ticket_info: Vec<(String, std::path::PathBuf)> = self
-            .tickets()
-            .iter()
-            .filter_map(|entry| {
-                let ticket = entry.value();
-                let id = ticket.id.clone()?.to_string();
-                let file_path = ticket.file_path.clone()?;
-                Some((id, file_path))
-            })
-            .collect();
+        let targets = self.embedding_targets();
 
-        // Snapshot objective data similarly.
-        let objective_info: Vec<(String, std::path::PathBuf)> = self
-            .objectives()
-            .iter()
-            .filter_map(|entry| {
-                let objective = entry.value();
-                let id = objective.id.clone()?.to_string();
-                let file_path = objective.file_path.clone()?;
-                Some((id, file_path))
-            })
+        // Phase 2: Perform all disk I/O, collecting valid (id, vector) pairs
+        // into a local Vec. This keeps filesystem work separate from DashMap
+        // inserts, reducing contention for concurrent readers (watcher
+        // upserts, TUI reads).
+        let mut loaded: Vec<(String, QuantizedEmbedding)> = targets
+            .into_iter()
+            .filter_map(|(id, key)| Some((id, read_embedding_file(&emb_dir, &key)?)))
             .collect();
 
-        // Phase 2: Perform all disk I/O (mtime lookups, file reads), collecting
-        // valid (id, vector) pairs into a local Vec. This keeps filesystem work
-        // separate from DashMap inserts, reducing contention for concurrent
-        // readers (watcher upserts, TUI reads).
-        let mut loaded: Vec<(String, Vec<f32>)> = Vec::new();
-
-        // Load ticket embeddings
-        for (id, file_path) in ticket_info {
-            if let Some(vector) = load_embedding_for_file(&emb_dir, &file_path) {
-                loaded.push((id, vector));
-            }
-        }
-
-        // Load objective embeddings
-        for (id, file_path) in objective_info {
-            if let Some(vector) = load_embedding_for_file(&emb_dir, &file_path) {
-                loaded.push((id, vector));
-            }
-        }
+        // Load document chunk embeddings
+        loaded.extend(self.load_doc_chunk_embeddings(&emb_dir));
 
         // Phase 3: Batch-insert into embeddings DashMap now that all disk I/O
         // is complete.
@@ -151,14 +181,23 @@ impl TicketStore {
 
     /// Save a single embedding to disk at `.janus/embeddings/{key}.bin`.
     ///
-    /// The embedding is stored as raw little-endian f32 bytes (4 bytes per value).
+    /// The embedding is quantized to int8 and stored as a little-endian f32
+    /// scale followed by one byte per value, a quarter of the size of the raw
+    /// f32 vector (see [`QuantizedEmbedding`]). Vectors containing NaN or
+    /// infinity are rejected.
     pub fn save_embedding(key: &str, vector: &[f32]) -> std::io::Result<()> {
+        if vector.iter().any(|v| !v.is_finite()) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "embedding contains non-finite values",
+            ));
+        }
+
         let emb_dir = embeddings_dir();
         fs::create_dir_all(&emb_dir)?;
 
         let bin_path = emb_dir.join(format!("{key}.bin"));
-        let bytes = f32_vec_to_bytes(vector);
-        fs::write(bin_path, bytes)
+        fs::write(bin_path, QuantizedEmbedding::quantize(vector).to_bytes())
     }
 
     /// Delete orphaned `.bin` files not in the `valid_keys` set.
@@ -170,7 +209,7 @@ impl TicketStore {
     /// This function is **not safe against concurrent ticket modifications**. It suffers
     /// from a TOCTOU (time-of-check-time-of-use) race: if a ticket file is modified
     /// between when the caller computes `valid_keys` and when this function deletes
-    /// files, a newly-generated embedding (with an updated mtime-based key) could be
+    /// files, a newly-generated embedding (with a new content-based key) could be
     /// incorrectly deleted. Callers should ensure that no concurrent processes are
     /// modifying tickets or generating embeddings (e.g., via `janus cache rebuild`)
     /// while pruning is in progress.
@@ -248,10 +287,11 @@ impl TicketStore {
     ///
     /// This method:
     /// 1. Looks up the ticket from the store by ID
-    /// 2. Gets file_path, title, body from metadata
-    /// 3. Computes embedding key from file_path + current mtime
-    /// 4. Checks if .bin file already exists for that key (optimization)
-    /// 5. If not, generates embedding via generate_ticket_embedding()
+    /// 2. Builds the embedding text from its title and body
+    /// 3. Computes the embedding key from that text
+    /// 4. Loads the .bin file for that key if it exists, so edits that leave
+    ///    the text unchanged are not re-embedded
+    /// 5. If not, generates embedding via generate_embedding()
     /// 6. Saves to disk via save_embedding()
     /// 7. Inserts into in-memory embeddings DashMap (CRITICAL for TUI freshness)
     ///
@@ -260,98 +300,57 @@ impl TicketStore {
     /// Returns `JanusError` if:
     /// - The ticket is not found in the store
     /// - The ticket has no file_path
-    /// - The file modification time cannot be read
-    /// - The embedding key cannot be computed
     /// - The embedding generation fails
     /// - The embedding cannot be saved to disk
     pub async fn ensure_embedding(&self, ticket_id: &str) -> crate::error::Result<()> {
         use crate::types::TicketId;
 
-        // Snapshot required fields from the tickets DashMap into owned locals,
-        // then drop the guard before touching the embeddings DashMap.
+        // Snapshot the embedding text from the tickets DashMap, then drop the
+        // guard before touching the embeddings DashMap.
         // This prevents AB/BA lock-order inversion deadlocks.
-        let (file_path, title, body) = {
+        let text = {
             let ticket = self
                 .tickets()
                 .get(ticket_id)
                 .ok_or_else(|| JanusError::TicketNotFound(TicketId::new(ticket_id).unwrap()))?;
 
-            let file_path = ticket
-                .file_path
-                .clone()
-                .ok_or_else(|| JanusError::EmbeddingNoFilePath(ticket_id.to_string()))?;
-            let title = ticket.title.clone().unwrap_or_default();
-            let body = ticket.body.clone();
-            (file_path, title, body)
+            if ticket.file_path.is_none() {
+                return Err(JanusError::EmbeddingNoFilePath(ticket_id.to_string()));
+            }
+            ticket_text(&ticket)
         }; // ticket guard (Ref) dropped here
 
-        // Get current mtime (fresh, not cached)
-        let mtime_ns = file_mtime_ns(&file_path).ok_or_else(|| JanusError::StorageError {
-            operation: "get mtime",
-            item_type: "file",
-            path: file_path.clone(),
-            source: std::io::Error::other("failed to read file modification time"),
-        })?;
-
-        // Compute embedding key
-        let key = Self::embedding_key(&file_path, mtime_ns)?;
-
-        // Check if .bin file already exists
-        let emb_dir = embeddings_dir();
-        let bin_path = emb_dir.join(format!("{key}.bin"));
-
-        // Phase 1: Perform all disk I/O and .await without touching DashMaps.
-        // This avoids holding DashMap guards (even briefly) while doing blocking
-        // filesystem work, which would increase contention for concurrent readers
-        // (watcher upserts, TUI reads).
-        let final_embedding = if bin_path.exists() {
-            // Embedding already exists on disk - attempt to load it using centralized validation
-            match fs::read(&bin_path)
-                .ok()
-                .and_then(|data| validate_and_parse_embedding(&data))
-            {
-                Some(vector) => vector,
-                None => {
-                    // Loading failed, fall through to regeneration
-                    let embedding =
-                        crate::embedding::model::generate_ticket_embedding(&title, body.as_deref())
-                            .await
-                            .map_err(|e| JanusError::EmbeddingGenerationFailed(e.to_string()))?;
-                    Self::save_embedding(&key, &embedding).map_err(|e| {
-                        JanusError::EmbeddingSaveFailed {
-                            key: key.clone(),
-                            source: e,
-                        }
-                    })?;
-                    embedding
-                }
-            }
-        } else {
-            // Generate new embedding
-            let embedding =
-                crate::embedding::model::generate_ticket_embedding(&title, body.as_deref())
-                    .await
-                    .map_err(|e| JanusError::EmbeddingGenerationFailed(e.to_string()))?;
-            Self::save_embedding(&key, &embedding).map_err(|e| {
-                JanusError::EmbeddingSaveFailed {
-                    key: key.clone(),
-                    source: e,
-                }
-            })?;
-            embedding
-        };
+        let embedding = Self::load_or_generate_embedding(&text).await?;
 
-        // Phase 2: Insert into in-memory store only after all I/O is complete.
+        // Insert into in-memory store only after all I/O is complete.
         // (CRITICAL for TUI freshness)
-        self.embeddings()
-            .insert(ticket_id.to_string(), final_embedding);
+        self.embeddings().insert(ticket_id.to_string(), embedding);
 
         Ok(())
     }
 
+    /// Load the saved embedding for `text`, or generate and save one.
+    ///
+    /// Performs all disk I/O and `.await`s without touching DashMaps, so no
+    /// guards are held during blocking filesystem work.
+    async fn load_or_generate_embedding(text: &str) -> crate::error::Result<QuantizedEmbedding> {
+        let key = Self::embedding_key(text);
+        if let Some(vector) = read_embedding_file(&embeddings_dir(), &key) {
+            return Ok(vector);
+        }
+
+        let embedding = crate::embedding::model::generate_embedding(text)
+            .await
+            .map_err(JanusError::EmbeddingGenerationFailed)?;
+        Self::save_embedding(&key, &embedding)
+            .map_err(|source| JanusError::EmbeddingSaveFailed { key, source })?;
+        Ok(embedding.into())
+    }
+
     /// Ensure all tickets have embeddings generated.
     ///
-    /// Processes tickets in batches of EMBEDDING_BATCH_SIZE (32).
+    /// Processes tickets in batches of EMBEDDING_BATCH_SIZE (32). Only tickets
+    /// with no embedding for their current text are sent to the model.
     /// Returns (generated_count, total_count) for progress reporting.
     ///
     /// # Errors
@@ -359,31 +358,42 @@ impl TicketStore {
     /// Returns `JanusError` if the embedding model cannot be initialized.
     /// Individual batch failures are logged and do not fail the entire operation.
     pub async fn ensure_all_embeddings(&self) -> crate::error::Result<(usize, usize)> {
-        use crate::embedding::model::{EMBEDDING_BATCH_SIZE, get_embedding_model};
-
         // Two-phase collection to avoid nested DashMap guards:
-        // Phase 1: Snapshot all candidate ticket data from the tickets DashMap.
-        let all_candidates: Vec<(String, std::path::PathBuf, String, Option<String>)> = self
+        // Phase 1: Snapshot all candidate ticket texts from the tickets DashMap.
+        let all_candidates: Vec<(String, String)> = self
             .tickets()
             .iter()
             .filter_map(|entry| {
                 let ticket = entry.value();
-                let id = entry.key().clone();
-                let file_path = ticket.file_path.clone()?;
-                let title = ticket.title.clone().unwrap_or_default();
-                let body = ticket.body.clone();
-                Some((id, file_path, title, body))
+                ticket.file_path.as_ref()?;
+                Some((entry.key().clone(), ticket_text(ticket)))
             })
             .collect();
-        // Phase 2: Filter against the embeddings DashMap now that all tickets
+
+        self.embed_missing(all_candidates, |store, id| store.tickets().contains_key(id))
+            .await
+    }
+
+    /// Embed the `(id, text)` candidates that have no embedding in memory, in
+    /// batches of EMBEDDING_BATCH_SIZE. `exists` guards against inserting
+    /// embeddings for items deleted while the batch was being embedded.
+    ///
+    /// Returns (generated_count, total_count).
+    async fn embed_missing(
+        &self,
+        candidates: Vec<(String, String)>,
+        exists: impl Fn(&TicketStore, &str) -> bool,
+    ) -> crate::error::Result<(usize, usize)> {
+        use crate::embedding::model::{EMBEDDING_BATCH_SIZE, get_embedding_model};
+
+        // Phase 2: Filter against the embeddings DashMap now that all source
         // guards are released, preventing AB/BA lock-order inversion deadlocks.
-        let tickets_to_embed: Vec<(String, std::path::PathBuf, String, Option<String>)> =
-            all_candidates
-                .into_iter()
-                .filter(|(id, _, _, _)| !self.has_embedding_for(id))
-                .collect();
+        let to_embed: Vec<(String, String)> = candidates
+            .into_iter()
+            .filter(|(id, _)| !self.has_embedding_for(id))
+            .collect();
 
-        let total = tickets_to_embed.len();
+        let total = to_embed.len();
         if total == 0 {
             return Ok((0, 0));
         }
@@ -391,20 +401,11 @@ impl TicketStore {
         let mut generated = 0usize;
         let model = get_embedding_model()
             .await
-            .map_err(|e| JanusError::EmbeddingGenerationFailed(e.to_string()))?;
+            .map_err(JanusError::EmbeddingGenerationFailed)?;
 
         // Process in batches
-        for batch in tickets_to_embed.chunks(EMBEDDING_BATCH_SIZE) {
-            // Prepare batch texts
-            let texts: Vec<String> = batch
-                .iter()
-                .map(|(_, _, title, body)| match body {
-                    Some(b) if !b.is_empty() => format!("{title}\n\n{b}"),
-                    _ => title.clone(),
-                })
-                .collect();
-
-            let texts_ref: Vec<&str> = texts.iter().map(|s| s.as_str()).collect();
+        for batch in to_embed.chunks(EMBEDDING_BATCH_SIZE) {
+            let texts_ref: Vec<&str> = batch.iter().map(|(_, text)| text.as_str()).collect();
 
             // Generate batch embeddings (awaits model inference)
             match model.embed_batch(&texts_ref).await {
@@ -413,31 +414,20 @@ impl TicketStore {
                     // saved (id, embedding) pairs into a local Vec. This keeps
                     // filesystem work separate from DashMap operations, reducing
                     // contention for concurrent readers (watcher upserts, TUI reads).
-                    let mut saved: Vec<(String, Vec<f32>)> = Vec::with_capacity(embeddings.len());
-
-                    for (i, (id, file_path, _, _)) in batch.iter().enumerate() {
-                        if let Some(embedding) = embeddings.get(i) {
-                            // Get current mtime (may have changed)
-                            let mtime_ns = file_mtime_ns(file_path);
-
-                            if let Some(mtime) = mtime_ns {
-                                let key = match TicketStore::embedding_key(file_path, mtime) {
-                                    Ok(k) => k,
-                                    Err(_) => continue,
-                                };
-
-                                if TicketStore::save_embedding(&key, embedding).is_ok() {
-                                    saved.push((id.clone(), embedding.clone()));
-                                }
-                            }
+                    let mut saved: Vec<(String, QuantizedEmbedding)> =
+                        Vec::with_capacity(embeddings.len());
+
+                    for ((id, text), embedding) in batch.iter().zip(&embeddings) {
+                        let key = Self::embedding_key(text);
+                        if Self::save_embedding(&key, embedding).is_ok() {
+                            saved.push((id.clone(), QuantizedEmbedding::quantize(embedding)));
                         }
                     }
 
                     // Phase 2: Batch-insert into DashMaps now that all disk I/O
-                    // is complete. Check ticket still exists before inserting to
-                    // guard against concurrent deletions.
+                    // is complete.
                     for (id, embedding) in saved {
-                        if self.tickets().contains_key(id.as_str()) {
+                        if exists(self, id.as_str()) {
                             self.embeddings().insert(id, embedding);
                             generated += 1;
                         }
@@ -501,7 +491,7 @@ impl TicketStore {
             .map_err(|e| JanusError::EmbeddingGenerationFailed(e.to_string()))?;
 
         // Insert into in-memory store
-        self.embeddings().insert(doc_key, embedding);
+        self.embeddings().insert(doc_key, embedding.into());
 
         Ok(())
     }
@@ -552,7 +542,7 @@ impl TicketStore {
             match crate::embedding::model::generate_embedding(&embedding_text).await {
                 Ok(embedding) => {
                     let doc_key = format!("doc:{label}");
-                    self.embeddings().insert(doc_key, embedding);
+                    self.embeddings().insert(doc_key, embedding.into());
                     generated += 1;
                 }
                 Err(e) => {
@@ -566,108 +556,38 @@ impl TicketStore {
 
     /// Ensure an objective has an embedding generated and saved.
     ///
-    /// This method:
-    /// 1. Looks up the objective from the store by ID
-    /// 2. Gets file_path, title, description, acceptance_criteria from metadata
-    /// 3. Computes embedding key from file_path + current mtime
-    /// 4. Checks if .bin file already exists for that key (optimization)
-    /// 5. If not, generates embedding via generate_embedding()
-    /// 6. Saves to disk via save_embedding()
-    /// 7. Inserts into in-memory embeddings DashMap
+    /// Like [`ensure_embedding`](Self::ensure_embedding), the embedding is
+    /// keyed by the objective's title, description, and acceptance criteria,
+    /// and reused from disk when they are unchanged.
     ///
     /// # Errors
     ///
     /// Returns `JanusError` if:
     /// - The objective is not found in the store
     /// - The objective has no file_path
-    /// - The file modification time cannot be read
-    /// - The embedding key cannot be computed
     /// - The embedding generation fails
     /// - The embedding cannot be saved to disk
     pub async fn ensure_objective_embedding(&self, objective_id: &str) -> crate::error::Result<()> {
         use crate::types::ObjectiveId;
 
-        // Snapshot required fields from the objectives DashMap into owned locals,
-        // then drop the guard before touching the embeddings DashMap.
-        let (file_path, title, description, acceptance_criteria) = {
+        // Snapshot the embedding text from the objectives DashMap, then drop
+        // the guard before touching the embeddings DashMap.
+        let text = {
             let objective = self.objectives().get(objective_id).ok_or_else(|| {
                 JanusError::ObjectiveNotFound(ObjectiveId::new_unchecked(objective_id))
             })?;
 
-            let file_path = objective
-                .file_path
-                .clone()
-                .ok_or_else(|| JanusError::EmbeddingNoFilePath(objective_id.to_string()))?;
-            let title = objective.title.clone().unwrap_or_default();
-            let description = objective.description.clone().unwrap_or_default();
-            let acceptance_criteria = objective.acceptance_criteria.clone();
-            (file_path, title, description, acceptance_criteria)
+            if objective.file_path.is_none() {
+                return Err(JanusError::EmbeddingNoFilePath(objective_id.to_string()));
+            }
+            objective_text(&objective)
         }; // objective guard (Ref) dropped here
 
-        // Get current mtime (fresh, not cached)
-        let mtime_ns = file_mtime_ns(&file_path).ok_or_else(|| JanusError::StorageError {
-            operation: "get mtime",
-            item_type: "file",
-            path: file_path.clone(),
-            source: std::io::Error::other("failed to read file modification time"),
-        })?;
-
-        // Compute embedding key
-        let key = Self::embedding_key(&file_path, mtime_ns)?;
-
-        // Check if .bin file already exists
-        let emb_dir = embeddings_dir();
-        let bin_path = emb_dir.join(format!("{key}.bin"));
-
-        // Build embedding text from title + description + acceptance criteria
-        let mut embedding_text = title;
-        if !description.is_empty() {
-            embedding_text.push_str("\n\n");
-            embedding_text.push_str(&description);
-        }
-        if !acceptance_criteria.is_empty() {
-            embedding_text.push_str("\n\n");
-            embedding_text.push_str(&acceptance_criteria.join("\n"));
-        }
-
-        let final_embedding = if bin_path.exists() {
-            // Embedding already exists on disk - attempt to load it
-            match fs::read(&bin_path)
-                .ok()
-                .and_then(|data| validate_and_parse_embedding(&data))
-            {
-                Some(vector) => vector,
-                None => {
-                    // Loading failed, fall through to regeneration
-                    let embedding = crate::embedding::model::generate_embedding(&embedding_text)
-                        .await
-                        .map_err(|e| JanusError::EmbeddingGenerationFailed(e.to_string()))?;
-                    Self::save_embedding(&key, &embedding).map_err(|e| {
-                        JanusError::EmbeddingSaveFailed {
-                            key: key.clone(),
-                            source: e,
-                        }
-                    })?;
-                    embedding
-                }
-            }
-        } else {
-            // Generate new embedding
-            let embedding = crate::embedding::model::generate_embedding(&embedding_text)
-                .await
-                .map_err(|e| JanusError::EmbeddingGenerationFailed(e.to_string()))?;
-            Self::save_embedding(&key, &embedding).map_err(|e| {
-                JanusError::EmbeddingSaveFailed {
-                    key: key.clone(),
-                    source: e,
-                }
-            })?;
-            embedding
-        };
+        let embedding = Self::load_or_generate_embedding(&text).await?;
 
         // Insert into in-memory store
         self.embeddings()
-            .insert(objective_id.to_string(), final_embedding);
+            .insert(objective_id.to_string(), embedding);
 
         Ok(())
     }
@@ -676,97 +596,85 @@ impl TicketStore {
     ///
     /// Returns (generated_count, total_count) for progress reporting.
     pub async fn ensure_all_objective_embeddings(&self) -> crate::error::Result<(usize, usize)> {
-        use crate::embedding::model::{EMBEDDING_BATCH_SIZE, get_embedding_model};
-
-        // Phase 1: Snapshot all candidate objective data.
-        let all_candidates: Vec<(String, std::path::PathBuf, String, String, Vec<String>)> = self
+        // Phase 1: Snapshot all candidate objective texts.
+        let all_candidates: Vec<(String, String)> = self
             .objectives()
             .iter()
             .filter_map(|entry| {
                 let objective = entry.value();
-                let id = entry.key().clone();
-                let file_path = objective.file_path.clone()?;
-                let title = objective.title.clone().unwrap_or_default();
-                let description = objective.description.clone().unwrap_or_default();
-                let acceptance_criteria = objective.acceptance_criteria.clone();
-                Some((id, file_path, title, description, acceptance_criteria))
+                objective.file_path.as_ref()?;
+                Some((entry.key().clone(), objective_text(objective)))
             })
             .collect();
 
-        // Phase 2: Filter against embeddings DashMap.
-        let objectives_to_embed: Vec<(String, std::path::PathBuf, String, String, Vec<String>)> =
-            all_candidates
-                .into_iter()
-                .filter(|(id, _, _, _, _)| !self.has_embedding_for(id))
-                .collect();
-
-        let total = objectives_to_embed.len();
-        if total == 0 {
-            return Ok((0, 0));
-        }
+        self.embed_missing(all_candidates, |store, id| {
+            store.objectives().contains_key(id)
+        })
+        .await
+    }
 
-        let mut generated = 0usize;
-        let model = get_embedding_model()
-            .await
-            .map_err(|e| JanusError::EmbeddingGenerationFailed(e.to_string()))?;
+    /// Ensure every plan and each of its phases has an embedding.
+    ///
+    /// Returns (generated_count, total_count) for progress reporting.
+    pub async fn ensure_all_plan_embeddings(&self) -> crate::error::Result<(usize, usize)> {
+        let candidates = self.plan_targets(None);
+        self.embed_missing(candidates, |store, id| {
+            store
+                .plans()
+                .contains_key(embedding_owner(id, PLAN_EMBEDDING_PREFIX))
+        })
+        .await
+    }
 
-        // Process in batches
-        for batch in objectives_to_embed.chunks(EMBEDDING_BATCH_SIZE) {
-            // Prepare batch texts
-            let texts: Vec<String> = batch
-                .iter()
-                .map(|(_, _, title, description, acceptance_criteria)| {
-                    let mut text = title.clone();
-                    if !description.is_empty() {
-                        text.push_str("\n\n");
-                        text.push_str(description);
-                    }
-                    if !acceptance_criteria.is_empty() {
-                        text.push_str("\n\n");
-                        text.push_str(&acceptance_criteria.join("\n"));
-                    }
-                    text
-                })
-                .collect();
+    /// Ensure every note of every ticket has an embedding.
+    ///
+    /// Returns (generated_count, total_count) for progress reporting.
+    pub async fn ensure_all_note_embeddings(&self) -> crate::error::Result<(usize, usize)> {
+        let candidates = self.note_targets(None);
+        self.embed_missing(candidates, |store, id| {
+            store
+                .tickets()
+                .contains_key(embedding_owner(id, NOTE_EMBEDDING_PREFIX))
+        })
+        .await
+    }
 
-            let texts_ref: Vec<&str> = texts.iter().map(|s| s.as_str()).collect();
+    /// Replace the embeddings of a plan and its phases after the plan changed.
+    ///
+    /// Phases whose text is unchanged reuse their saved embedding.
+    pub async fn refresh_plan_embeddings(&self, plan_id: &str) -> crate::error::Result<()> {
+        let targets = self.plan_targets(Some(plan_id));
+        self.refresh_embeddings(&format!("{PLAN_EMBEDDING_PREFIX}{plan_id}"), targets)
+            .await
+    }
 
-            match model.embed_batch(&texts_ref).await {
-                Ok(embeddings) => {
-                    let mut saved: Vec<(String, Vec<f32>)> = Vec::with_capacity(embeddings.len());
-
-                    for (i, (id, file_path, _, _, _)) in batch.iter().enumerate() {
-                        if let Some(embedding) = embeddings.get(i) {
-                            let mtime_ns = file_mtime_ns(file_path);
-
-                            if let Some(mtime) = mtime_ns {
-                                let key = match TicketStore::embedding_key(file_path, mtime) {
-                                    Ok(k) => k,
-                                    Err(_) => continue,
-                                };
-
-                                if TicketStore::save_embedding(&key, embedding).is_ok() {
-                                    saved.push((id.clone(), embedding.clone()));
-                                }
-                            }
-                        }
-                    }
+    /// Replace the embeddings of a ticket's notes after the ticket changed.
+    ///
+    /// Notes whose text is unchanged reuse their saved embedding.
+    pub async fn refresh_note_embeddings(&self, ticket_id: &str) -> crate::error::Result<()> {
+        let targets = self.note_targets(Some(ticket_id));
+        self.refresh_embeddings(&format!("{NOTE_EMBEDDING_PREFIX}{ticket_id}"), targets)
+            .await
+    }
 
-                    // Batch-insert into DashMaps
-                    for (id, embedding) in saved {
-                        if self.objectives().contains_key(id.as_str()) {
-                            self.embeddings().insert(id, embedding);
-                            generated += 1;
-                        }
-                    }
-                }
-                Err(e) => {
-                    tracing::warn!("Batch embedding generation failed for objectives: {e}");
-                }
-            }
+    /// Load or generate an embedding for each target, then swap them in for
+    /// the embeddings under `owner` in one step, so stale entries (e.g. of a
+    /// deleted note) do not linger.
+    async fn refresh_embeddings(
+        &self,
+        owner: &str,
+        targets: Vec<(String, String)>,
+    ) -> crate::error::Result<()> {
+        let mut fresh = Vec::with_capacity(targets.len());
+        for (id, text) in targets {
+            fresh.push((id, Self::load_or_generate_embedding(&text).await?));
         }
 
-        Ok((generated, total))
+        self.remove_embeddings_under(owner);
+        for (id, embedding) in fresh {
+            self.embeddings().insert(id, embedding);
+        }
+        Ok(())
     }
 
     /// Ensure document chunk embeddings are generated.
@@ -807,7 +715,10 @@ impl TicketStore {
         })?;
 
         // Chunk the document
-        let chunks = chunk_document(label, &content)?;
+        let chunking = crate::config::Config::load()
+            .map(|c| c.doc_chunking)
+            .unwrap_or_default();
+        let chunks = chunk_document(label, &content, &chunking)?;
 
         // Generate embeddings for chunks that don't already have them
         let embeddings_to_generate: Vec<(String, String)> = chunks
@@ -827,7 +738,7 @@ impl TicketStore {
         for (chunk_key, chunk_text) in embeddings_to_generate {
             match crate::embedding::model::generate_embedding(&chunk_text).await {
                 Ok(embedding) => {
-                    self.embeddings().insert(chunk_key, embedding);
+                    self.embeddings().insert(chunk_key, embedding.into());
                 }
                 Err(e) => {
                     tracing::warn!("Failed to generate embedding for chunk {chunk_key}: {e}");
@@ -841,49 +752,89 @@ impl TicketStore {
     /// Get all chunk embeddings for a document.
     ///
     /// Returns a vector of (chunk_key, embedding) tuples where chunk_key
-    /// is in the format `doc:{label}:c{start_line}`.
+    /// is in the format `doc:{label}:c{start_line}`. The embeddings are
+    /// dequantized from their stored int8 form.
     pub fn doc_chunk_embeddings(&self, label: &str) -> Vec<(String, Vec<f32>)> {
         let prefix = format!("doc:{label}:c");
         self.embeddings()
             .iter()
             .filter(|entry| entry.key().starts_with(&prefix))
-            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .map(|entry| (entry.key().clone(), entry.value().dequantize()))
             .collect()
     }
 }
 
-/// Load an embedding for a file given its path and the embeddings directory.
-///
-/// Computes the embedding key from file_path + mtime, reads the .bin file,
-/// validates it, and returns the vector if valid.
-fn load_embedding_for_file(emb_dir: &Path, file_path: &Path) -> Option<Vec<f32>> {
-    let mtime_ns = file_mtime_ns(file_path)?;
-
-    let key = match TicketStore::embedding_key(file_path, mtime_ns) {
-        Ok(k) => k,
-        Err(e) => {
-            tracing::warn!("Failed to compute embedding key for {file_path:?}: {e}");
-            return None;
-        }
-    };
-    let bin_path = emb_dir.join(format!("{key}.bin"));
-
-    let data = fs::read(&bin_path).ok()?;
+/// Read and validate `.janus/embeddings/{key}.bin`.
+pub(super) fn read_embedding_file(emb_dir: &Path, key: &str) -> Option<QuantizedEmbedding> {
+    let data = fs::read(emb_dir.join(format!("{key}.bin"))).ok()?;
     validate_and_parse_embedding(&data)
 }
 
-/// Get file modification time as nanoseconds since UNIX epoch.
-///
-/// The actual precision depends on the underlying filesystem (see
-/// [`TicketStore::embedding_key`] for details).
-fn file_mtime_ns(path: &Path) -> Option<u128> {
-    let metadata = fs::metadata(path).ok()?;
-    let modified = metadata.modified().ok()?;
-    let duration = modified.duration_since(UNIX_EPOCH).ok()?;
-    Some(duration.as_nanos())
+/// Text embedded for a ticket.
+fn ticket_text(ticket: &TicketMetadata) -> String {
+    ticket_embedding_text(
+        ticket.title.as_deref().unwrap_or_default(),
+        ticket.body.as_deref(),
+    )
 }
 
-/// Convert a slice of f32 values to little-endian bytes.
+/// Text embedded for an objective.
+fn objective_text(objective: &ObjectiveMetadata) -> String {
+    objective_embedding_text(
+        objective.title.as_deref().unwrap_or_default(),
+        objective.description.as_deref().unwrap_or_default(),
+        &objective.acceptance_criteria,
+    )
+}
+
+/// Embedding IDs and texts for a plan and each of its phases.
+fn plan_texts(id: &str, plan: &PlanMetadata) -> Vec<(String, String)> {
+    let title = plan.title.as_deref().unwrap_or_default();
+    let mut texts = vec![(
+        format!("{PLAN_EMBEDDING_PREFIX}{id}"),
+        plan_embedding_text(
+            title,
+            plan.description.as_deref().unwrap_or_default(),
+            &plan.acceptance_criteria,
+        ),
+    )];
+    for phase in plan.phases() {
+        texts.push((
+            format!("{PLAN_EMBEDDING_PREFIX}{id}:p{}", phase.number),
+            plan_embedding_text(
+                &format!("{title} - Phase {}: {}", phase.number, phase.name),
+                phase.description.as_deref().unwrap_or_default(),
+                &phase.success_criteria,
+            ),
+        ));
+    }
+    texts
+}
+
+/// Embedding IDs and texts for each note of a ticket.
+fn note_texts(id: &str, ticket: &TicketMetadata) -> Vec<(String, String)> {
+    let title = ticket.title.as_deref().unwrap_or_default();
+    parse_notes(ticket.body.as_deref().unwrap_or_default())
+        .iter()
+        .enumerate()
+        .map(|(i, note)| {
+            (
+                format!("{NOTE_EMBEDDING_PREFIX}{id}:{i}"),
+                note_embedding_text(title, &note.text),
+            )
+        })
+        .collect()
+}
+
+/// The plan or ticket ID in a plan or note embedding ID.
+pub(super) fn embedding_owner<'a>(id: &'a str, prefix: &str) -> &'a str {
+    let rest = id.strip_prefix(prefix).unwrap_or(id);
+    rest.split_once(':').map_or(rest, |(owner, _)| owner)
+}
+
+/// Convert a slice of f32 values to little-endian bytes, the format embeddings
+/// were saved in before they were quantized.
+#[cfg(test)]
 fn f32_vec_to_bytes(vector: &[f32]) -> Vec<u8> {
     let mut bytes = Vec::with_capacity(vector.len() * 4);
     for &val in vector {
@@ -914,16 +865,19 @@ fn bytes_to_f32_vec(data: &[u8]) -> Option<Vec<f32>> {
 /// Validate and parse embedding bytes.
 ///
 /// This helper centralizes the validation logic for embedding data:
-/// - Validates the data length matches expected embedding dimensions
-/// - Parses the bytes into f32 values
+/// - Accepts the quantized format, or the older raw f32 format, whose sizes
+///   both follow from [`EMBEDDING_DIMENSIONS`]
+/// - Parses the bytes, quantizing f32 data
 /// - Validates no NaN or infinity values
 ///
-/// Returns `Some(vec)` if valid, `None` if invalid.
-fn validate_and_parse_embedding(data: &[u8]) -> Option<Vec<f32>> {
-    let expected_bytes = EMBEDDING_DIMENSIONS * 4;
+/// Returns `Some(embedding)` if valid, `None` if invalid.
+fn validate_and_parse_embedding(data: &[u8]) -> Option<QuantizedEmbedding> {
+    if data.len() == QuantizedEmbedding::encoded_len(EMBEDDING_DIMENSIONS) {
+        return QuantizedEmbedding::from_bytes(data, EMBEDDING_DIMENSIONS);
+    }
 
     // Validate file size matches expected embedding dimensions
-    if data.len() != expected_bytes {
+    if data.len() != EMBEDDING_DIMENSIONS * 4 {
         return None;
     }
 
@@ -934,7 +888,7 @@ fn validate_and_parse_embedding(data: &[u8]) -> Option<Vec<f32>> {
         return None;
     }
 
-    Some(vector)
+    Some(QuantizedEmbedding::quantize(&vector))
 }
 
 #[cfg(test)]
@@ -951,70 +905,48 @@ mod tests {
 
     #[test]
     fn test_embedding_key_deterministic() {
-        let tmp = TempDir::new().unwrap();
-        let janus = tmp.path().join(".janus");
-        let _guard = JanusRootGuard::new(&janus);
-
-        let ticket_path = janus.join("items/ticket.md");
-        std::fs::create_dir_all(ticket_path.parent().unwrap()).unwrap();
-        std::fs::write(&ticket_path, "# Test").unwrap();
-
-        let mtime = 1234567890_u128;
-
-        let key1 = TicketStore::embedding_key(&ticket_path, mtime).unwrap();
-        let key2 = TicketStore::embedding_key(&ticket_path, mtime).unwrap();
+        let key1 = TicketStore::embedding_key("Test\n\nBody");
+        let key2 = TicketStore::embedding_key("Test\n\nBody");
 
         assert_eq!(key1, key2);
-        assert!(!key1.is_empty());
         // blake3 hex output is 64 chars
         assert_eq!(key1.len(), 64);
     }
 
     #[test]
-    fn test_embedding_key_changes_with_mtime() {
-        let tmp = TempDir::new().unwrap();
-        let janus = tmp.path().join(".janus");
-        let _guard = JanusRootGuard::new(&janus);
-
-        let ticket_path = janus.join("items/ticket.md");
-        std::fs::create_dir_all(ticket_path.parent().unwrap()).unwrap();
-        std::fs::write(&ticket_path, "# Test").unwrap();
-
-        let key1 = TicketStore::embedding_key(&ticket_path, 1000).unwrap();
-        let key2 = TicketStore::embedding_key(&ticket_path, 2000).unwrap();
-
-        assert_ne!(key1, key2);
+    fn test_embedding_key_changes_with_text() {
+        assert_ne!(
+            TicketStore::embedding_key("Test"),
+            TicketStore::embedding_key("Test 2")
+        );
     }
 
     #[test]
-    fn test_embedding_key_changes_with_path() {
-        let tmp = TempDir::new().unwrap();
-        let janus = tmp.path().join(".janus");
-        let _guard = JanusRootGuard::new(&janus);
-
-        let ticket_path1 = janus.join("items/ticket1.md");
-        let ticket_path2 = janus.join("items/ticket2.md");
-        std::fs::create_dir_all(ticket_path1.parent().unwrap()).unwrap();
-        std::fs::write(&ticket_path1, "# Test 1").unwrap();
-        std::fs::write(&ticket_path2, "# Test 2").unwrap();
-
-        let key1 = TicketStore::embedding_key(&ticket_path1, 1000).unwrap();
-        let key2 = TicketStore::embedding_key(&ticket_path2, 1000).unwrap();
-
-        assert_ne!(key1, key2);
-    }
-
-    #[test]
-    fn test_embedding_key_fails_outside_root() {
-        let tmp = TempDir::new().unwrap();
-        let janus = tmp.path().join(".janus");
-        let _guard = JanusRootGuard::new(&janus);
+    fn test_embedding_file_keys_ignore_non_text_edits() {
+        let ticket = TicketMetadata {
+            id: Some(TicketId::new_unchecked("j-1")),
+            file_path: Some("j-1.md".into()),
+            title: Some("Fix login".to_string()),
+            body: Some("Details".to_string()),
+            status: Some(TicketStatus::New),
+            ..Default::default()
+        };
+        let store = TicketStore::empty();
+        store.upsert_ticket(ticket.clone());
+        let before = store.embedding_file_keys();
 
-        // Path outside the Janus root
-        let outside_path = Path::new("/outside/path/ticket.md");
+        // A status change keeps the key, so the saved embedding is reused
+        store.upsert_ticket(TicketMetadata {
+            status: Some(TicketStatus::Complete),
+            ..ticket.clone()
+        });
+        assert_eq!(store.embedding_file_keys(), before);
 
-        let result = TicketStore::embedding_key(outside_path, 1000);
-        assert!(result.is_err());
+        store.upsert_ticket(TicketMetadata {
+            body: Some("New details".to_string()),
+            ..ticket
+        });
+        assert_ne!(store.embedding_file_keys(), before);
     }
 
     #[test]
@@ -1064,16 +996,20 @@ status: new
         )
         .unwrap();
 
-        let mtime_ns = file_mtime_ns(&ticket_path).expect("should get mtime");
-        let key = TicketStore::embedding_key(&ticket_path, mtime_ns).expect("should compute key");
+        // The metadata upserted below has no title or body, so its embedding
+        // text is empty
+        let key = TicketStore::embedding_key("");
 
         // Save embedding (must match EMBEDDING_DIMENSIONS for load validation)
         let vector: Vec<f32> = (0..EMBEDDING_DIMENSIONS).map(|i| i as f32 * 0.1).collect();
         TicketStore::save_embedding(&key, &vector).expect("save should succeed");
 
-        // Verify the file was created
+        // Verify the file was created in the quantized format
         let bin_path = embeddings_dir().join(format!("{key}.bin"));
-        assert!(bin_path.exists());
+        assert_eq!(
+            std::fs::metadata(&bin_path).unwrap().len() as usize,
+            QuantizedEmbedding::encoded_len(EMBEDDING_DIMENSIONS)
+        );
 
         // Load into store
         let store = TicketStore::empty();
@@ -1089,8 +1025,16 @@ status: new
             .expect("load_embeddings should succeed");
         assert_eq!(store.embeddings().len(), 1);
 
-        let loaded = store.embeddings().get("j-test").unwrap();
-        assert_eq!(*loaded.value(), vector);
+        let expected = QuantizedEmbedding::quantize(&vector);
+        assert_eq!(*store.embeddings().get("j-test").unwrap().value(), expected);
+
+        // Files written before quantization hold raw f32 values and still load
+        std::fs::write(&bin_path, f32_vec_to_bytes(&vector)).unwrap();
+        store.embeddings().clear();
+        store
+            .load_embeddings()
+            .expect("load_embeddings should succeed");
+        assert_eq!(*store.embeddings().get("j-test").unwrap().value(), expected);
     }
 
     #[test]
@@ -1160,8 +1104,12 @@ status: new
         });
 
         // Add embeddings for only 2 of 3 tickets
-        store.embeddings().insert("j-1".to_string(), vec![1.0]);
-        store.embeddings().insert("j-2".to_string(), vec![2.0]);
+        store
+            .embeddings()
+            .insert("j-1".to_string(), vec![1.0].into());
+        store
+            .embeddings()
+            .insert("j-2".to_string(), vec![2.0].into());
 
         let (with, total) = store.embedding_coverage();
         assert_eq!(with, 2);
@@ -1229,8 +1177,7 @@ status: new
         )
         .unwrap();
 
-        let mtime_ns = file_mtime_ns(&ticket_path).expect("should get mtime");
-        let key = TicketStore::embedding_key(&ticket_path, mtime_ns).expect("should compute key");
+        let key = TicketStore::embedding_key("");
 
         // Save an embedding with wrong dimensions (4 floats instead of EMBEDDING_DIMENSIONS)
         let wrong_vector = vec![1.0_f32, 2.0, 3.0, 4.0];
@@ -1274,14 +1221,20 @@ status: new
         )
         .unwrap();
 
-        let mtime_ns = file_mtime_ns(&ticket_path).expect("should get mtime");
-        let key = TicketStore::embedding_key(&ticket_path, mtime_ns).expect("should compute key");
+        let key = TicketStore::embedding_key("");
 
         // Build a vector with the correct number of dimensions but containing NaN
         let mut nan_vector: Vec<f32> = (0..EMBEDDING_DIMENSIONS).map(|i| i as f32 * 0.1).collect();
         nan_vector[0] = f32::NAN;
 
-        TicketStore::save_embedding(&key, &nan_vector).expect("save should succeed");
+        assert!(TicketStore::save_embedding(&key, &nan_vector).is_err());
+        // Write a legacy f32 file directly, which save_embedding refuses to do
+        std::fs::create_dir_all(embeddings_dir()).unwrap();
+        std::fs::write(
+            embeddings_dir().join(format!("{key}.bin")),
+            f32_vec_to_bytes(&nan_vector),
+        )
+        .unwrap();
 
         let store = TicketStore::empty();
         store.upsert_ticket(TicketMetadata {
@@ -1311,14 +1264,16 @@ status: new
         )
         .unwrap();
 
-        let mtime_ns2 = file_mtime_ns(&ticket_path2).expect("should get mtime");
-        let key2 =
-            TicketStore::embedding_key(&ticket_path2, mtime_ns2).expect("should compute key");
+        let key2 = TicketStore::embedding_key("");
 
         let mut inf_vector: Vec<f32> = (0..EMBEDDING_DIMENSIONS).map(|i| i as f32 * 0.1).collect();
         inf_vector[5] = f32::INFINITY;
 
-        TicketStore::save_embedding(&key2, &inf_vector).expect("save should succeed");
+        std::fs::write(
+            embeddings_dir().join(format!("{key2}.bin")),
+            f32_vec_to_bytes(&inf_vector),
+        )
+        .unwrap();
 
         let store2 = TicketStore::empty();
         store2.upsert_ticket(TicketMetadata {
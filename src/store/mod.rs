@@ -7,6 +7,7 @@ use tokio::sync::OnceCell;
 
 use crate::doc::parser::parse_doc_content;
 use crate::doc::types::DocMetadata;
+use crate::embedding::QuantizedEmbedding;
 use crate::error::Result;
 use crate::objective::parser::parse_objective_content;
 use crate::objective::types::ObjectiveMetadata;
@@ -173,6 +174,7 @@ impl EntityMetadata for ObjectiveMetadata {
     }
 }
 
+pub mod doc_embeddings;
 pub mod doc_search;
 pub mod embeddings;
 pub mod queries;
@@ -190,10 +192,13 @@ pub use watcher::{
 /// It also manages embedding vectors for semantic search.
 pub struct TicketStore {
     tickets: DashMap<String, TicketMetadata>,
+    /// Ticket UUID (lowercase) -> ticket ID, so references that stored a UUID
+    /// keep resolving after the ticket's ID changes
+    ticket_uuids: DashMap<String, String>,
     plans: DashMap<String, PlanMetadata>,
     docs: DashMap<String, DocMetadata>,
     objectives: DashMap<String, ObjectiveMetadata>,
-    embeddings: DashMap<String, Vec<f32>>,
+    embeddings: DashMap<String, QuantizedEmbedding>,
     /// Warnings captured during initialization
     init_warnings: InitWarnings,
 }
@@ -248,6 +253,28 @@ pub async fn get_or_init_store() -> Result<&'static TicketStore> {
                         tracing::warn!("Failed to generate objective embeddings: {e}");
                     }
                 }
+
+                match store.ensure_all_plan_embeddings().await {
+                    Ok((generated, total)) => {
+                        if generated > 0 {
+                            eprintln!("Generated embeddings for {generated}/{total} plan sections");
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to generate plan embeddings: {e}");
+                    }
+                }
+
+                match store.ensure_all_note_embeddings().await {
+                    Ok((generated, total)) => {
+                        if generated > 0 {
+                            eprintln!("Generated embeddings for {generated}/{total} notes");
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to generate note embeddings: {e}");
+                    }
+                }
             }
 
             Ok(store)
@@ -267,6 +294,7 @@ impl TicketStore {
     pub fn empty() -> Self {
         TicketStore {
             tickets: DashMap::new(),
+            ticket_uuids: DashMap::new(),
             plans: DashMap::new(),
             docs: DashMap::new(),
             objectives: DashMap::new(),
@@ -395,7 +423,7 @@ impl TicketStore {
     async fn load_tickets_from_dir(&self, dir: &Path) {
         self.load_entities_from_dir(dir, "ticket", parse_ticket, |metadata: TicketMetadata| {
             if let Some(id) = metadata.id.clone() {
-                self.tickets.insert(id.to_string(), metadata);
+                self.insert_ticket(id.to_string(), metadata);
             }
         })
         .await;
@@ -439,7 +467,7 @@ impl TicketStore {
     /// Insert or update a ticket in the store.
     pub fn upsert_ticket(&self, metadata: TicketMetadata) {
         if let Some(id) = metadata.id.clone() {
-            self.tickets.insert(id.to_string(), metadata);
+            self.insert_ticket(id.to_string(), metadata);
         } else {
             self.init_warnings.add(InitWarning {
                 file_path: metadata.file_path.clone(),
@@ -449,10 +477,25 @@ impl TicketStore {
         }
     }
 
+    /// Insert a ticket and keep the UUID index in sync with it.
+    fn insert_ticket(&self, id: String, metadata: TicketMetadata) {
+        let uuid = metadata.uuid.as_deref().map(str::to_ascii_lowercase);
+        if let Some(previous) = self.tickets.insert(id.clone(), metadata)
+            && let Some(old_uuid) = previous.uuid.as_deref().map(str::to_ascii_lowercase)
+            && uuid.as_ref() != Some(&old_uuid)
+        {
+            self.ticket_uuids
+                .remove_if(&old_uuid, |_, mapped| *mapped == id);
+        }
+        if let Some(uuid) = uuid {
+            self.ticket_uuids.insert(uuid, id);
+        }
+    }
+
     /// Remove a ticket from the store and cascade delete references from other tickets.
     ///
-    /// Also removes the corresponding embedding entry to prevent orphaned
-    /// embeddings from inflating coverage counts.
+    /// Also removes the embeddings of the ticket and its notes to prevent
+    /// orphaned embeddings from inflating coverage counts.
     ///
     /// Performs cascade deletion to remove references to the deleted ticket
     /// from other tickets' deps and links arrays on disk to maintain referential integrity.
@@ -463,9 +506,15 @@ impl TicketStore {
             .get(id)
             .map(|t| (t.file_path.clone(), t.id.as_ref().map(|id| id.to_string())));
 
-        // Remove ticket and its embedding from the store
-        self.tickets.remove(id);
+        // Remove ticket, its UUID entry, and its embedding from the store
+        if let Some((_, removed)) = self.tickets.remove(id)
+            && let Some(uuid) = removed.uuid.as_deref()
+        {
+            self.ticket_uuids
+                .remove_if(&uuid.to_ascii_lowercase(), |_, mapped| mapped == id);
+        }
         self.embeddings.remove(id);
+        self.remove_embeddings_under(&format!("{}{id}", embeddings::NOTE_EMBEDDING_PREFIX));
 
         // Cascade deletion: remove references from other tickets' deps and links
         if let Some((_, Some(deleted_id))) = ticket_info {
@@ -562,9 +611,11 @@ impl TicketStore {
         }
     }
 
-    /// Remove a plan from the store by ID.
+    /// Remove a plan from the store by ID, along with the embeddings of the
+    /// plan and its phases.
     pub fn remove_plan(&self, id: &str) {
         self.plans.remove(id);
+        self.remove_embeddings_under(&format!("{}{id}", embeddings::PLAN_EMBEDDING_PREFIX));
     }
 
     /// Insert or update a document in the store.
@@ -620,7 +671,7 @@ impl TicketStore {
     }
 
     /// Get a reference to the embeddings DashMap (for use by embeddings/search modules).
-    pub(crate) fn embeddings(&self) -> &DashMap<String, Vec<f32>> {
+    pub(crate) fn embeddings(&self) -> &DashMap<String, QuantizedEmbedding> {
         &self.embeddings
     }
 
@@ -629,6 +680,11 @@ impl TicketStore {
         &self.tickets
     }
 
+    /// Get a reference to the ticket UUID index (for use by query modules).
+    pub(crate) fn ticket_uuids(&self) -> &DashMap<String, String> {
+        &self.ticket_uuids
+    }
+
     /// Get a reference to the plans DashMap (for use by query modules).
     pub(crate) fn plans(&self) -> &DashMap<String, PlanMetadata> {
         &self.plans
@@ -1190,13 +1246,15 @@ mod tests {
         // Add some embeddings for the doc
         store
             .embeddings
-            .insert("doc:doc-to-remove".to_string(), vec![1.0, 2.0, 3.0]);
-        store
-            .embeddings
-            .insert("doc:doc-to-remove:c10".to_string(), vec![4.0, 5.0, 6.0]);
-        store
-            .embeddings
-            .insert("doc:doc-to-remove:c20".to_string(), vec![7.0, 8.0, 9.0]);
+            .insert("doc:doc-to-remove".to_string(), vec![1.0, 2.0, 3.0].into());
+        store.embeddings.insert(
+            "doc:doc-to-remove:c10".to_string(),
+            vec![4.0, 5.0, 6.0].into(),
+        );
+        store.embeddings.insert(
+            "doc:doc-to-remove:c20".to_string(),
+            vec![7.0, 8.0, 9.0].into(),
+        );
 
         store.remove_doc("doc-to-remove");
         assert_eq!(store.docs.len(), 0);
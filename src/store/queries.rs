@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 use dashmap::mapref::multiple::RefMulti;
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
 
 use super::TicketStore;
 use crate::objective::types::ObjectiveMetadata;
@@ -110,6 +112,71 @@ impl TicketStore {
         matches
     }
 
+    /// Find ticket IDs by full UUID or UUID prefix (case-insensitive).
+    ///
+    /// A full UUID match wins outright, so a stored UUID never resolves
+    /// ambiguously.
+    pub fn find_by_uuid(&self, uuid: &str) -> Vec<String> {
+        let uuid = uuid.trim().to_ascii_lowercase();
+        if uuid.is_empty() {
+            return Vec::new();
+        }
+        if let Some(id) = self.ticket_uuids().get(&uuid) {
+            return vec![id.value().clone()];
+        }
+
+        let mut matches: Vec<String> = self
+            .ticket_uuids()
+            .iter()
+            .filter(|r| r.key().starts_with(&uuid))
+            .map(|r| r.value().clone())
+            .collect();
+        matches.sort();
+        matches
+    }
+
+    /// Find tickets whose title fuzzy-matches `query`, returning `(id, title)`
+    /// pairs, best match first.
+    ///
+    /// A case-insensitive exact title match wins outright: when any ticket's
+    /// title equals the query, only those tickets are returned.
+    pub fn find_by_title(&self, query: &str) -> Vec<(String, String)> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let titled: Vec<(String, String)> = self
+            .tickets()
+            .iter()
+            .filter_map(|r| Some((r.key().clone(), r.value().title.clone()?)))
+            .collect();
+
+        let mut exact: Vec<(String, String)> = titled
+            .iter()
+            .filter(|(_, title)| unicase::eq(title.as_str(), query))
+            .cloned()
+            .collect();
+        if !exact.is_empty() {
+            exact.sort();
+            return exact;
+        }
+
+        let matcher = SkimMatcherV2::default().smart_case();
+        let mut scored: Vec<(i64, String, String)> = titled
+            .into_iter()
+            .filter_map(|(id, title)| {
+                let score = matcher.fuzzy_match(&title, query)?;
+                Some((score, id, title))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        scored
+            .into_iter()
+            .map(|(_, id, title)| (id, title))
+            .collect()
+    }
+
     /// Build a HashMap of ticket_id -> metadata.
     pub fn build_ticket_map(&self) -> HashMap<String, TicketMetadata> {
         self.tickets()
@@ -126,6 +193,22 @@ impl TicketStore {
             .count()
     }
 
+    /// Get IDs of non-terminal tickets whose `parent` or `spawned-from` is this ticket.
+    pub fn get_open_children(&self, id: &str) -> Vec<String> {
+        let mut children: Vec<String> = self
+            .tickets()
+            .iter()
+            .filter(|r| {
+                let t = r.value();
+                (t.parent.as_deref() == Some(id) || t.spawned_from.as_deref() == Some(id))
+                    && !t.status.is_some_and(|s| s.is_terminal())
+            })
+            .map(|r| r.key().clone())
+            .collect();
+        children.sort();
+        children
+    }
+
     /// Get children counts for all tickets that have spawned children.
     pub fn get_all_children_counts(&self) -> HashMap<String, usize> {
         let mut counts: HashMap<String, usize> = HashMap::new();
@@ -426,6 +509,66 @@ mod tests {
         assert!(ticket.is_none());
     }
 
+    #[test]
+    fn test_find_by_title() {
+        let store = test_store();
+
+        // Fuzzy match, case-insensitive for lowercase queries
+        let matches = store.find_by_title("login bug");
+        assert_eq!(
+            matches,
+            vec![("j-c3d4".to_string(), "Fix login bug".to_string())]
+        );
+
+        // Exact titles win over fuzzy matches elsewhere
+        let matches = store.find_by_title("add feature flags");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0, "j-e5f6");
+
+        // Multiple fuzzy matches, best first
+        let matches = store.find_by_title("a");
+        assert!(matches.len() > 1);
+
+        assert!(store.find_by_title("zzzz").is_empty());
+        assert!(store.find_by_title("  ").is_empty());
+    }
+
+    #[test]
+    fn test_find_by_uuid() {
+        let store = test_store();
+        store.upsert_ticket(TicketMetadata {
+            id: Some(TicketId::new_unchecked("j-u1v2")),
+            uuid: Some("7F3A9C2E-1B4D-4E5F-8A6B-0C1D2E3F4A5B".to_string()),
+            ..Default::default()
+        });
+        store.upsert_ticket(TicketMetadata {
+            id: Some(TicketId::new_unchecked("j-w3x4")),
+            uuid: Some("7f3a1111-2222-4333-8444-555566667777".to_string()),
+            ..Default::default()
+        });
+
+        // Full UUID, any case
+        assert_eq!(
+            store.find_by_uuid("7f3a9c2e-1b4d-4e5f-8a6b-0c1d2e3f4a5b"),
+            vec!["j-u1v2"]
+        );
+        // Unique and ambiguous prefixes
+        assert_eq!(store.find_by_uuid("7f3a9"), vec!["j-u1v2"]);
+        assert_eq!(store.find_by_uuid("7F3A"), vec!["j-u1v2", "j-w3x4"]);
+        assert!(store.find_by_uuid("0000").is_empty());
+
+        // The index follows ID renames and deletions
+        store.remove_ticket_with_cascade("j-u1v2");
+        store.upsert_ticket(TicketMetadata {
+            id: Some(TicketId::new_unchecked("j-renamed")),
+            uuid: Some("7f3a9c2e-1b4d-4e5f-8a6b-0c1d2e3f4a5b".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(store.find_by_uuid("7f3a9"), vec!["j-renamed"]);
+        store.remove_ticket_with_cascade("j-w3x4");
+        assert!(store.find_by_uuid("7f3a1").is_empty());
+    }
+
     #[test]
     fn test_find_by_partial_id() {
         let store = test_store();
@@ -482,6 +625,18 @@ mod tests {
         assert_eq!(store.get_children_count("j-nonexistent"), 0);
     }
 
+    #[test]
+    fn test_get_open_children() {
+        let store = test_store();
+
+        // j-c3d4 is in progress; j-e5f6 is complete and therefore excluded
+        assert_eq!(
+            store.get_open_children("j-a1b2"),
+            vec!["j-c3d4".to_string()]
+        );
+        assert!(store.get_open_children("j-g7h8").is_empty());
+    }
+
     #[test]
     fn test_get_all_children_counts() {
         let store = test_store();
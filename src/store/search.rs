@@ -2,8 +2,11 @@ use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 
 use super::TicketStore;
-use crate::embedding::model::cosine_similarity;
+use super::embeddings::{NOTE_EMBEDDING_PREFIX, PLAN_EMBEDDING_PREFIX};
+use crate::embedding::QuantizedEmbedding;
 use crate::objective::types::ObjectiveMetadata;
+use crate::plan::types::PlanMetadata;
+use crate::ticket::{Note, parse_notes};
 use crate::types::{EntityType, TicketMetadata};
 
 /// Result of a semantic search, containing the matched ticket and its similarity score.
@@ -15,19 +18,50 @@ pub struct SearchResult {
     pub similarity: f32,
 }
 
-/// Result of a unified semantic search across tickets and objectives.
+/// Result of a unified semantic search across tickets, notes, objectives,
+/// and plans.
 #[derive(Debug, Clone)]
 pub struct UnifiedSearchResult {
     /// The type of entity this result represents.
     pub entity_type: EntityType,
     /// The matched ticket metadata (present when entity_type is Ticket).
     pub ticket: Option<TicketMetadata>,
+    /// The matched note of `ticket`, when the match is a single note rather
+    /// than the whole ticket.
+    pub note: Option<Note>,
     /// The matched objective metadata (present when entity_type is Objective).
     pub objective: Option<ObjectiveMetadata>,
+    /// The matched plan metadata (present when entity_type is Plan).
+    pub plan: Option<PlanMetadata>,
+    /// The number of the matched phase of `plan`, when the match is a phase
+    /// rather than the plan as a whole.
+    pub phase: Option<String>,
     /// Cosine similarity score.
     pub similarity: f32,
 }
 
+impl UnifiedSearchResult {
+    fn new(entity_type: EntityType, similarity: f32) -> Self {
+        UnifiedSearchResult {
+            entity_type,
+            ticket: None,
+            note: None,
+            objective: None,
+            plan: None,
+            phase: None,
+            similarity,
+        }
+    }
+}
+
+/// Whether an embedding ID belongs to a whole ticket or objective, rather
+/// than to a document, plan, or note.
+fn is_entity_embedding(key: &str) -> bool {
+    !key.starts_with("doc:")
+        && !key.starts_with(PLAN_EMBEDDING_PREFIX)
+        && !key.starts_with(NOTE_EMBEDDING_PREFIX)
+}
+
 /// A scored candidate for top-K selection via a min-heap.
 ///
 /// Wraps a ticket ID and similarity score, ordered by similarity ascending
@@ -63,31 +97,44 @@ impl Ord for ScoredCandidate {
     }
 }
 
+/// Candidates kept per requested result for rescoring at full precision
+const RESCORE_FACTOR: usize = 4;
+
 impl TicketStore {
-    /// Perform semantic search using brute-force cosine similarity with top-K selection.
+    /// Find the `limit` embeddings most similar to `query_embedding` among the
+    /// keys accepted by `include`, as `(key, similarity)` sorted best first.
     ///
-    /// 1. Iterates all entries in the embeddings DashMap
-    /// 2. Computes cosine similarity for each against `query_embedding`
-    /// 3. Maintains a bounded min-heap of size `limit` for O(N log K) top-K selection
-    /// 4. Sorts the final K results by similarity descending for presentation
-    pub fn semantic_search(&self, query_embedding: &[f32], limit: usize) -> Vec<SearchResult> {
+    /// 1. Quantizes the query and scores every embedding in int8, keeping the
+    ///    top `limit * RESCORE_FACTOR` in a bounded min-heap (O(N log K))
+    /// 2. Rescores those candidates against the full-precision query, which
+    ///    corrects the ordering errors introduced by quantization
+    /// 3. Returns the best `limit` by the rescored similarity
+    ///
+    /// Only keys and scores are collected, so all embeddings DashMap shard
+    /// locks are released before callers touch the tickets, objectives, or
+    /// docs maps. This prevents AB/BA deadlocks between the maps under
+    /// concurrent access (e.g., watcher upserts + semantic search).
+    pub(super) fn nearest_embeddings(
+        &self,
+        query_embedding: &[f32],
+        limit: usize,
+        include: impl Fn(&str) -> bool,
+    ) -> Vec<(String, f32)> {
         if limit == 0 {
             return Vec::new();
         }
 
-        // Snapshot embedding data (ID + similarity score) into a local Vec first,
-        // so that all embeddings DashMap shard locks are released before we touch
-        // the tickets DashMap. This prevents AB/BA deadlocks between the two maps
-        // under concurrent access (e.g., watcher upserts + semantic search).
-        //
-        // While iterating, use a bounded min-heap of size `limit` to select the
-        // top-K scored candidates in O(N log K) instead of sorting all N in O(N log N).
-        let mut heap: BinaryHeap<ScoredCandidate> = BinaryHeap::with_capacity(limit + 1);
+        let query = QuantizedEmbedding::quantize(query_embedding);
+        let capacity = limit.saturating_mul(RESCORE_FACTOR);
+        let mut heap: BinaryHeap<ScoredCandidate> = BinaryHeap::with_capacity(capacity + 1);
 
         for entry in self.embeddings().iter() {
-            let similarity = cosine_similarity(query_embedding, entry.value());
+            if !include(entry.key()) {
+                continue;
+            }
+            let similarity = query.similarity(entry.value());
 
-            if heap.len() < limit {
+            if heap.len() < capacity {
                 heap.push(ScoredCandidate {
                     ticket_id: entry.key().clone(),
                     similarity,
@@ -103,15 +150,37 @@ impl TicketStore {
             }
         }
 
-        // Drain the heap and look up ticket metadata without holding any embeddings guards.
-        let mut results: Vec<SearchResult> = heap
+        let mut rescored: Vec<(String, f32)> = heap
             .into_iter()
             .filter_map(|candidate| {
+                let similarity = self
+                    .embeddings()
+                    .get(&candidate.ticket_id)?
+                    .similarity_to(query_embedding);
+                Some((candidate.ticket_id, similarity))
+            })
+            .collect();
+        rescored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+        rescored.truncate(limit);
+        rescored
+    }
+
+    /// Perform semantic search over ticket embeddings.
+    ///
+    /// Candidates come from [`nearest_embeddings`](Self::nearest_embeddings);
+    /// the results are sorted by similarity descending for presentation.
+    pub fn semantic_search(&self, query_embedding: &[f32], limit: usize) -> Vec<SearchResult> {
+        let candidates = self.nearest_embeddings(query_embedding, limit, is_entity_embedding);
+
+        // Look up ticket metadata without holding any embeddings guards.
+        let mut results: Vec<SearchResult> = candidates
+            .into_iter()
+            .filter_map(|(ticket_id, similarity)| {
                 self.tickets()
-                    .get(&candidate.ticket_id)
+                    .get(&ticket_id)
                     .map(|ticket_ref| SearchResult {
                         ticket: ticket_ref.value().clone(),
-                        similarity: candidate.similarity,
+                        similarity,
                     })
             })
             .collect();
@@ -126,11 +195,13 @@ impl TicketStore {
         results
     }
 
-    /// Perform unified semantic search across both tickets and objectives.
+    /// Perform unified semantic search across tickets, ticket notes,
+    /// objectives, plans, and plan phases.
     ///
     /// Unlike `semantic_search` which only returns ticket matches, this method
-    /// searches the shared embeddings DashMap and resolves matches against both
-    /// the tickets and objectives DashMaps.
+    /// searches the shared embeddings DashMap and resolves matches against the
+    /// tickets, objectives, and plans DashMaps. A note or phase match carries
+    /// its ticket or plan, so several results can share one.
     ///
     /// Doc embeddings (keyed with `doc:` prefix) are excluded — use `doc_search`
     /// for document-level search.
@@ -139,62 +210,13 @@ impl TicketStore {
         query_embedding: &[f32],
         limit: usize,
     ) -> Vec<UnifiedSearchResult> {
-        if limit == 0 {
-            return Vec::new();
-        }
-
-        let mut heap: BinaryHeap<ScoredCandidate> = BinaryHeap::with_capacity(limit + 1);
+        // Skip doc embeddings — they have their own search
+        let candidates =
+            self.nearest_embeddings(query_embedding, limit, |key| !key.starts_with("doc:"));
 
-        for entry in self.embeddings().iter() {
-            // Skip doc embeddings — they have their own search
-            if entry.key().starts_with("doc:") {
-                continue;
-            }
-
-            let similarity = cosine_similarity(query_embedding, entry.value());
-
-            if heap.len() < limit {
-                heap.push(ScoredCandidate {
-                    ticket_id: entry.key().clone(),
-                    similarity,
-                });
-            } else if let Some(min) = heap.peek()
-                && similarity > min.similarity
-            {
-                heap.pop();
-                heap.push(ScoredCandidate {
-                    ticket_id: entry.key().clone(),
-                    similarity,
-                });
-            }
-        }
-
-        // Drain the heap and look up entity metadata in both tickets and objectives.
-        let mut results: Vec<UnifiedSearchResult> = heap
+        let mut results: Vec<UnifiedSearchResult> = candidates
             .into_iter()
-            .filter_map(|candidate| {
-                // Try ticket lookup first
-                if let Some(ticket_ref) = self.tickets().get(&candidate.ticket_id) {
-                    return Some(UnifiedSearchResult {
-                        entity_type: EntityType::Ticket,
-                        ticket: Some(ticket_ref.value().clone()),
-                        objective: None,
-                        similarity: candidate.similarity,
-                    });
-                }
-
-                // Try objective lookup
-                if let Some(objective_ref) = self.objectives().get(&candidate.ticket_id) {
-                    return Some(UnifiedSearchResult {
-                        entity_type: EntityType::Objective,
-                        ticket: None,
-                        objective: Some(objective_ref.value().clone()),
-                        similarity: candidate.similarity,
-                    });
-                }
-
-                None
-            })
+            .filter_map(|(id, similarity)| self.resolve_search_match(&id, similarity))
             .collect();
 
         results.sort_by(|a, b| {
@@ -205,6 +227,56 @@ impl TicketStore {
 
         results
     }
+
+    /// Look up what an embedding ID from the unified search belongs to.
+    ///
+    /// Returns `None` if the entity was removed, or the note or phase no
+    /// longer exists, since the embedding was made.
+    fn resolve_search_match(&self, id: &str, similarity: f32) -> Option<UnifiedSearchResult> {
+        if let Some(rest) = id.strip_prefix(PLAN_EMBEDDING_PREFIX) {
+            let (plan_id, phase) = match rest.split_once(":p") {
+                Some((plan_id, phase)) => (plan_id, Some(phase.to_string())),
+                None => (rest, None),
+            };
+            let plan = self.plans().get(plan_id)?.value().clone();
+            if let Some(number) = &phase
+                && !plan.phases().iter().any(|p| &p.number == number)
+            {
+                return None;
+            }
+            return Some(UnifiedSearchResult {
+                plan: Some(plan),
+                phase,
+                ..UnifiedSearchResult::new(EntityType::Plan, similarity)
+            });
+        }
+
+        if let Some(rest) = id.strip_prefix(NOTE_EMBEDDING_PREFIX) {
+            let (ticket_id, index) = rest.rsplit_once(':')?;
+            let ticket = self.tickets().get(ticket_id)?.value().clone();
+            let note = parse_notes(ticket.body.as_deref().unwrap_or_default())
+                .into_iter()
+                .nth(index.parse().ok()?)?;
+            return Some(UnifiedSearchResult {
+                ticket: Some(ticket),
+                note: Some(note),
+                ..UnifiedSearchResult::new(EntityType::Ticket, similarity)
+            });
+        }
+
+        if let Some(ticket_ref) = self.tickets().get(id) {
+            return Some(UnifiedSearchResult {
+                ticket: Some(ticket_ref.value().clone()),
+                ..UnifiedSearchResult::new(EntityType::Ticket, similarity)
+            });
+        }
+
+        let objective = self.objectives().get(id)?.value().clone();
+        Some(UnifiedSearchResult {
+            objective: Some(objective),
+            ..UnifiedSearchResult::new(EntityType::Objective, similarity)
+        })
+    }
 }
 
 #[cfg(test)]
@@ -227,7 +299,7 @@ mod tests {
         // Embedding that points in the "auth" direction
         store
             .embeddings()
-            .insert("j-auth".to_string(), vec![0.9, 0.1, 0.0]);
+            .insert("j-auth".to_string(), vec![0.9, 0.1, 0.0].into());
 
         // Ticket 2: "database" direction
         store.upsert_ticket(TicketMetadata {
@@ -239,7 +311,7 @@ mod tests {
         // Embedding that points in the "db" direction
         store
             .embeddings()
-            .insert("j-db".to_string(), vec![0.0, 0.9, 0.1]);
+            .insert("j-db".to_string(), vec![0.0, 0.9, 0.1].into());
 
         // Ticket 3: "ui" direction
         store.upsert_ticket(TicketMetadata {
@@ -251,7 +323,7 @@ mod tests {
         // Embedding that points in the "ui" direction
         store
             .embeddings()
-            .insert("j-ui".to_string(), vec![0.0, 0.0, 1.0]);
+            .insert("j-ui".to_string(), vec![0.0, 0.0, 1.0].into());
 
         // Ticket 4: no embedding
         store.upsert_ticket(TicketMetadata {
@@ -360,10 +432,9 @@ mod tests {
     }
 
     /// Helper: naive full-sort approach for comparison with top-K.
-    /// Collects all scores, sorts descending, and truncates.
+    /// Scores every embedding against the full-precision query, sorts
+    /// descending, and truncates.
     fn naive_sorted_search(store: &TicketStore, query: &[f32], limit: usize) -> Vec<(String, f32)> {
-        use crate::embedding::model::cosine_similarity;
-
         // Phase 1: Snapshot embedding data into owned locals so all embeddings
         // DashMap shard locks are released before touching the tickets DashMap.
         let scored_candidates: Vec<(String, f32)> = store
@@ -371,7 +442,7 @@ mod tests {
             .iter()
             .map(|entry| {
                 let ticket_id = entry.key().clone();
-                let similarity = cosine_similarity(query, entry.value());
+                let similarity = entry.value().similarity_to(query);
                 (ticket_id, similarity)
             })
             .collect();
@@ -453,7 +524,7 @@ mod tests {
             let secondary = (i as f32) * 0.05;
             store
                 .embeddings()
-                .insert(id, vec![primary, secondary, 0.0, 0.0]);
+                .insert(id, vec![primary, secondary, 0.0, 0.0].into());
         }
 
         let query = vec![1.0_f32, 0.0, 0.0, 0.0];
@@ -536,7 +607,7 @@ mod tests {
         });
         store
             .embeddings()
-            .insert("j-auth".to_string(), vec![0.9, 0.1, 0.0]);
+            .insert("j-auth".to_string(), vec![0.9, 0.1, 0.0].into());
 
         // Add an objective
         store.upsert_objective(ObjectiveMetadata {
@@ -546,7 +617,7 @@ mod tests {
         });
         store
             .embeddings()
-            .insert("objv-goal".to_string(), vec![0.8, 0.2, 0.0]);
+            .insert("objv-goal".to_string(), vec![0.8, 0.2, 0.0].into());
 
         // Unified search should find both
         let query = vec![1.0_f32, 0.0, 0.0];
@@ -581,12 +652,12 @@ mod tests {
         });
         store
             .embeddings()
-            .insert("j-test".to_string(), vec![0.9, 0.1, 0.0]);
+            .insert("j-test".to_string(), vec![0.9, 0.1, 0.0].into());
 
         // Add a doc embedding (should be excluded from unified search)
         store
             .embeddings()
-            .insert("doc:test-doc".to_string(), vec![0.95, 0.05, 0.0]);
+            .insert("doc:test-doc".to_string(), vec![0.95, 0.05, 0.0].into());
 
         let query = vec![1.0_f32, 0.0, 0.0];
         let results = store.unified_semantic_search(&query, 10);
@@ -595,4 +666,45 @@ mod tests {
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].entity_type, EntityType::Ticket);
     }
+
+    #[test]
+    fn test_unified_semantic_search_includes_plans_and_notes() {
+        let store = TicketStore::empty();
+        store.upsert_ticket(TicketMetadata {
+            id: Some(TicketId::new_unchecked("j-db")),
+            title: Some("Database".to_string()),
+            body: Some(
+                "# Database\n\n## Notes\n\n**2024-06-01T10:00:00Z**\n\nConsider sharding\n"
+                    .to_string(),
+            ),
+            ..Default::default()
+        });
+        store.upsert_plan(
+            crate::plan::parser::parse_plan_content(
+                "---\nid: plan-a1b2\n---\n# Scale out\n\n## Phase 1: Sharding\n\n### Tickets\n\n1. j-db\n",
+            )
+            .unwrap(),
+        );
+        store
+            .embeddings()
+            .insert("note:j-db:0".to_string(), vec![1.0, 0.0, 0.0].into());
+        store
+            .embeddings()
+            .insert("plan:plan-a1b2:p1".to_string(), vec![0.9, 0.1, 0.0].into());
+        // Stale: the note no longer exists
+        store
+            .embeddings()
+            .insert("note:j-db:5".to_string(), vec![1.0, 0.0, 0.0].into());
+
+        let query = vec![1.0_f32, 0.0, 0.0];
+        let results = store.unified_semantic_search(&query, 10);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].entity_type, EntityType::Ticket);
+        assert_eq!(results[0].note.as_ref().unwrap().text, "Consider sharding");
+        assert_eq!(results[1].entity_type, EntityType::Plan);
+        assert_eq!(results[1].phase.as_deref(), Some("1"));
+
+        // Ticket-only search ignores note embeddings
+        assert!(store.semantic_search(&query, 10).is_empty());
+    }
 }
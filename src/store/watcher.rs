@@ -1005,7 +1005,9 @@ async fn process_ticket_file(path: &Path, store: &TicketStore) -> ParseOutcome {
             let ticket_id = metadata.id.clone();
             store.upsert_ticket(metadata);
 
-            // Remove stale embedding and regenerate
+            // Remove stale embedding and regenerate. Edits that leave the
+            // title and body unchanged reload the saved embedding instead of
+            // calling the model.
             if let Some(id) = &ticket_id {
                 store.embeddings().remove(id.as_ref());
 
@@ -1029,6 +1031,11 @@ async fn process_ticket_file(path: &Path, store: &TicketStore) -> ParseOutcome {
                         if let Err(e) = store.ensure_embedding(&id_clone).await {
                             tracing::warn!("Failed to generate embedding for {id_clone}: {e}");
                         }
+                        if let Err(e) = store.refresh_note_embeddings(&id_clone).await {
+                            tracing::warn!(
+                                "Failed to generate note embeddings for {id_clone}: {e}"
+                            );
+                        }
                     }
                 });
             }
@@ -1077,7 +1084,29 @@ async fn process_plan_file(path: &Path, store: &TicketStore) -> ParseOutcome {
                 metadata.id = Some(crate::types::PlanId::new_unchecked(stem.to_string_lossy()));
             }
             metadata.file_path = Some(path.to_path_buf());
+            let plan_id = metadata.id.clone();
             store.upsert_plan(metadata);
+
+            // Re-embed the plan and its phases; unchanged sections reload
+            // their saved embedding
+            if let Some(id) = plan_id {
+                tokio::spawn(async move {
+                    let permit = EMBEDDING_SEMAPHORE.acquire().await;
+                    let _permit = match permit {
+                        Ok(p) => p,
+                        Err(e) => {
+                            tracing::warn!("Failed to acquire embedding semaphore: {e}");
+                            return;
+                        }
+                    };
+
+                    if let Ok(store) = crate::store::get_or_init_store().await
+                        && let Err(e) = store.refresh_plan_embeddings(id.as_ref()).await
+                    {
+                        tracing::warn!("Failed to generate embeddings for {id}: {e}");
+                    }
+                });
+            }
             ParseOutcome::Success
         }
         Err(_) => {
@@ -0,0 +1,334 @@
+//! Personalized work suggestions (`janus suggest`).
+//!
+//! Ready tickets that are unassigned or assigned to the user are scored on
+//! four signals, each between 0 and 1:
+//!
+//! - priority (P0 scores highest)
+//! - age, saturating after [`MAX_AGE_DAYS`]
+//! - plan urgency: tickets in the current phase of a plan score highest
+//! - similarity to work the user has already completed, from ticket embeddings
+//!
+//! The weighted sum ranks the tickets, and the strongest signals of each
+//! become its one-line justification.
+
+use std::collections::HashMap;
+
+use jiff::Timestamp;
+
+use crate::config::ReadyRequirements;
+use crate::embedding::model::cosine_similarity;
+use crate::plan::compute_phase_status;
+use crate::plan::types::PlanMetadata;
+use crate::status::is_ready;
+use crate::types::{TicketData, TicketMetadata};
+
+/// Tickets suggested when no count is given
+pub const DEFAULT_SUGGESTIONS: usize = 3;
+
+/// Age at which a ticket gets the full age score
+const MAX_AGE_DAYS: f32 = 30.0;
+
+const PRIORITY_WEIGHT: f32 = 0.35;
+const AGE_WEIGHT: f32 = 0.15;
+const PLAN_WEIGHT: f32 = 0.25;
+const SIMILARITY_WEIGHT: f32 = 0.25;
+
+/// Signals below this contribute to the score but are not worth mentioning
+const MENTION_THRESHOLD: f32 = 0.05;
+
+/// How pressing a ticket is because of the plan it belongs to
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanUrgency {
+    pub plan_id: String,
+    /// Whether the ticket is in the first incomplete phase of its plan
+    pub current_phase: bool,
+}
+
+impl PlanUrgency {
+    fn score(&self) -> f32 {
+        if self.current_phase { 1.0 } else { 0.4 }
+    }
+}
+
+/// A completed ticket of the user's that a candidate resembles
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarWork {
+    pub ticket_id: String,
+    pub similarity: f32,
+}
+
+/// A ranked suggestion
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    pub ticket_id: String,
+    pub title: String,
+    pub score: f32,
+    /// Short phrases explaining the score, strongest first
+    pub reasons: Vec<String>,
+}
+
+/// Ready tickets the user could pick up: ready under `require` (see
+/// [`is_ready`]), and unassigned or assigned to `user`.
+pub fn candidates<'a>(
+    ticket_map: &'a HashMap<String, TicketMetadata>,
+    user: Option<&str>,
+    require: &ReadyRequirements,
+    now: Timestamp,
+) -> Vec<&'a TicketMetadata> {
+    ticket_map
+        .values()
+        .filter(|t| t.id.is_some())
+        .filter(|t| match (t.assignee.as_deref(), user) {
+            (None, _) | (_, None) => true,
+            (Some(assignee), Some(user)) => assignee.eq_ignore_ascii_case(user),
+        })
+        .filter(|t| is_ready(t, ticket_map, require, now))
+        .collect()
+}
+
+/// Plan urgency for every ticket in `plans`, keyed by ticket ID.
+///
+/// Tickets in a simple (unphased) plan count as current. A ticket in several
+/// plans keeps its most urgent entry.
+pub fn plan_urgency(
+    plans: &[PlanMetadata],
+    ticket_map: &HashMap<String, TicketMetadata>,
+) -> HashMap<String, PlanUrgency> {
+    let mut urgency: HashMap<String, PlanUrgency> = HashMap::new();
+    for plan in plans {
+        let Some(plan_id) = plan.id.as_deref() else {
+            continue;
+        };
+
+        let mut entries: Vec<(&str, bool)> = Vec::new();
+        if plan.is_phased() {
+            let mut seen_current = false;
+            for phase in plan.phases() {
+                if compute_phase_status(phase, ticket_map).status.is_terminal() {
+                    continue;
+                }
+                entries.extend(
+                    phase
+                        .ticket_list
+                        .tickets
+                        .iter()
+                        .map(|id| (id.as_str(), !seen_current)),
+                );
+                seen_current = true;
+            }
+        } else {
+            entries.extend(plan.all_tickets().into_iter().map(|id| (id, true)));
+        }
+
+        for (ticket_id, current_phase) in entries {
+            let entry = PlanUrgency {
+                plan_id: plan_id.to_string(),
+                current_phase,
+            };
+            match urgency.get(ticket_id) {
+                Some(existing) if existing.score() >= entry.score() => {}
+                _ => {
+                    urgency.insert(ticket_id.to_string(), entry);
+                }
+            }
+        }
+    }
+    urgency
+}
+
+/// The completed ticket in `history` most similar to `embedding`.
+pub fn most_similar(embedding: &[f32], history: &[(String, Vec<f32>)]) -> Option<SimilarWork> {
+    history
+        .iter()
+        .map(|(ticket_id, other)| SimilarWork {
+            ticket_id: ticket_id.clone(),
+            similarity: cosine_similarity(embedding, other),
+        })
+        .max_by(|a, b| a.similarity.total_cmp(&b.similarity))
+}
+
+/// Score a candidate from its signals.
+pub fn score(
+    ticket: &TicketMetadata,
+    now: Timestamp,
+    plan: Option<&PlanUrgency>,
+    similar: Option<&SimilarWork>,
+) -> Suggestion {
+    let priority = ticket.priority_num();
+    let days = ticket
+        .created_timestamp()
+        .map(|created| now.duration_since(created).as_secs_f64() as f32 / 86_400.0)
+        .unwrap_or(0.0)
+        .max(0.0);
+
+    let mut signals: Vec<(f32, String)> = vec![(
+        PRIORITY_WEIGHT * (4 - priority.min(4)) as f32 / 4.0,
+        format!("P{priority}"),
+    )];
+    signals.push((
+        AGE_WEIGHT * (days / MAX_AGE_DAYS).min(1.0),
+        format!("waiting {} days", days.floor() as u32),
+    ));
+    if let Some(plan) = plan {
+        let phrase = if plan.current_phase {
+            format!("current phase of {}", plan.plan_id)
+        } else {
+            format!("in plan {}", plan.plan_id)
+        };
+        signals.push((PLAN_WEIGHT * plan.score(), phrase));
+    }
+    if let Some(similar) = similar {
+        signals.push((
+            SIMILARITY_WEIGHT * similar.similarity.clamp(0.0, 1.0),
+            format!("like {} you completed", similar.ticket_id),
+        ));
+    }
+
+    let score = signals.iter().map(|(weight, _)| weight).sum();
+    signals.sort_by(|a, b| b.0.total_cmp(&a.0));
+    let reasons = signals
+        .into_iter()
+        .filter(|(weight, _)| *weight >= MENTION_THRESHOLD)
+        .map(|(_, phrase)| phrase)
+        .collect();
+
+    Suggestion {
+        ticket_id: ticket.id_str().unwrap_or_default(),
+        title: ticket.title.clone().unwrap_or_default(),
+        score,
+        reasons,
+    }
+}
+
+/// Sort suggestions best first and keep the top `limit`. Ties go to the
+/// lower ticket ID so the output is stable.
+pub fn rank(mut suggestions: Vec<Suggestion>, limit: usize) -> Vec<Suggestion> {
+    suggestions.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.ticket_id.cmp(&b.ticket_id))
+    });
+    suggestions.truncate(limit);
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::types::{Phase, PlanSection, TicketList};
+    use crate::types::{CreatedAt, PlanId, TicketId, TicketPriority, TicketStatus};
+
+    fn ticket(id: &str, priority: TicketPriority, created: &str) -> TicketMetadata {
+        TicketMetadata {
+            id: Some(TicketId::new_unchecked(id)),
+            title: Some(format!("Ticket {id}")),
+            status: Some(TicketStatus::New),
+            priority: Some(priority),
+            created: Some(CreatedAt::new_unchecked(created)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_candidates_filters_assignee_and_deps() {
+        let now: Timestamp = "2026-03-01T00:00:00Z".parse().unwrap();
+        let mut mine = ticket("j-mine", TicketPriority::P2, "2026-02-01T00:00:00Z");
+        mine.assignee = Some("Sam".to_string());
+        let mut theirs = ticket("j-theirs", TicketPriority::P2, "2026-02-01T00:00:00Z");
+        theirs.assignee = Some("alex".to_string());
+        let mut blocked = ticket("j-blocked", TicketPriority::P2, "2026-02-01T00:00:00Z");
+        blocked.deps = vec![TicketId::new_unchecked("j-mine")];
+        let free = ticket("j-free", TicketPriority::P2, "2026-02-01T00:00:00Z");
+
+        let map: HashMap<String, TicketMetadata> = [mine, theirs, blocked, free]
+            .into_iter()
+            .map(|t| (t.id_str().unwrap(), t))
+            .collect();
+
+        let mut ids: Vec<_> = candidates(&map, Some("sam"), &ReadyRequirements::default(), now)
+            .into_iter()
+            .filter_map(|t| t.id_str())
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["j-free", "j-mine"]);
+    }
+
+    #[test]
+    fn test_plan_urgency_prefers_current_phase() {
+        let phase = |number: &str, tickets: &[&str]| {
+            PlanSection::Phase(Phase {
+                number: number.to_string(),
+                name: format!("Phase {number}"),
+                ticket_list: TicketList {
+                    tickets: tickets.iter().map(|s| s.to_string()).collect(),
+                    tickets_raw: None,
+                },
+                ..Default::default()
+            })
+        };
+        let plan = PlanMetadata {
+            id: Some(PlanId::new_unchecked("plan-a1b2")),
+            sections: vec![
+                phase("1", &["j-done"]),
+                phase("2", &["j-now"]),
+                phase("3", &["j-later"]),
+            ],
+            ..Default::default()
+        };
+        let mut done = ticket("j-done", TicketPriority::P2, "2026-01-01T00:00:00Z");
+        done.status = Some(TicketStatus::Complete);
+        let map: HashMap<String, TicketMetadata> = [("j-done".to_string(), done)].into();
+
+        let urgency = plan_urgency(&[plan], &map);
+        assert!(urgency["j-now"].current_phase);
+        assert!(!urgency["j-later"].current_phase);
+        assert!(!urgency.contains_key("j-done"));
+    }
+
+    #[test]
+    fn test_score_and_rank() {
+        let now: Timestamp = "2026-03-01T00:00:00Z".parse().unwrap();
+        let urgent = ticket("j-urgent", TicketPriority::P0, "2026-02-28T00:00:00Z");
+        let old = ticket("j-old", TicketPriority::P3, "2025-12-01T00:00:00Z");
+        let planned = ticket("j-plan", TicketPriority::P2, "2026-02-20T00:00:00Z");
+        let plan = PlanUrgency {
+            plan_id: "plan-a1b2".to_string(),
+            current_phase: true,
+        };
+        let similar = SimilarWork {
+            ticket_id: "j-past".to_string(),
+            similarity: 0.9,
+        };
+
+        let suggestions = vec![
+            score(&urgent, now, None, None),
+            score(&old, now, None, None),
+            score(&planned, now, Some(&plan), Some(&similar)),
+        ];
+        let ranked = rank(suggestions, 2);
+
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].ticket_id, "j-plan");
+        assert_eq!(
+            ranked[0].reasons,
+            vec![
+                "current phase of plan-a1b2",
+                "like j-past you completed",
+                "P2"
+            ]
+        );
+        assert_eq!(ranked[1].ticket_id, "j-urgent");
+        assert_eq!(ranked[1].reasons, vec!["P0"]);
+    }
+
+    #[test]
+    fn test_most_similar() {
+        let history = vec![
+            ("j-a".to_string(), vec![1.0, 0.0]),
+            ("j-b".to_string(), vec![0.0, 1.0]),
+        ];
+        let best = most_similar(&[0.1, 0.9], &history).unwrap();
+        assert_eq!(best.ticket_id, "j-b");
+        assert!(most_similar(&[1.0, 0.0], &[]).is_none());
+    }
+}
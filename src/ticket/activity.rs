@@ -0,0 +1,278 @@
+//! Chronological activity feed for a ticket.
+//!
+//! Merges what is known about a ticket's history from several sources: its
+//! creation time, timestamped notes, status changes and syncs from the event
+//! log, and git commits whose message mentions the ticket ID. Janus's own
+//! auto-commits are left out, since they repeat the other entries.
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::notes::Note;
+use crate::error::Result;
+use crate::events::{EntityType, EventType, read_events};
+use crate::fs::COMMIT_PREFIX;
+use crate::types::{TicketMetadata, janus_root};
+use crate::utils::{git, truncate_string};
+
+/// Most recent commits to look at per ticket
+const MAX_COMMITS: usize = 50;
+
+/// Separates commits in `git log` output
+const RECORD_SEP: char = '\u{1e}';
+/// Separates fields within a commit
+const FIELD_SEP: char = '\u{1f}';
+
+/// Where an activity entry came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    Created,
+    Note,
+    Status,
+    Sync,
+    Commit,
+}
+
+impl ActivityKind {
+    fn label(self) -> &'static str {
+        match self {
+            ActivityKind::Created => "created",
+            ActivityKind::Note => "note",
+            ActivityKind::Status => "status",
+            ActivityKind::Sync => "sync",
+            ActivityKind::Commit => "commit",
+        }
+    }
+}
+
+/// One entry in a ticket's activity feed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ActivityItem {
+    /// When it happened, as recorded by its source. Notes written by hand
+    /// may have no timestamp.
+    pub timestamp: Option<String>,
+    pub kind: ActivityKind,
+    /// One-line description
+    pub summary: String,
+    /// Who made the change: the event actor or the commit author
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+}
+
+impl ActivityItem {
+    fn new(timestamp: Option<String>, kind: ActivityKind, summary: String) -> Self {
+        ActivityItem {
+            timestamp,
+            kind,
+            summary,
+            actor: None,
+        }
+    }
+
+    /// The entry's timestamp as `YYYY-MM-DD HH:MM`, or blank when it has none
+    pub fn display_time(&self) -> String {
+        let time = self.timestamp.as_deref().unwrap_or_default();
+        time.get(..16).unwrap_or(time).replacen('T', " ", 1)
+    }
+
+    /// The entry as a single line, without the timestamp
+    pub fn describe(&self) -> String {
+        match &self.actor {
+            Some(actor) => format!("{:<7} {} ({actor})", self.kind.label(), self.summary),
+            None => format!("{:<7} {}", self.kind.label(), self.summary),
+        }
+    }
+}
+
+/// Build the activity feed for a ticket, oldest first.
+///
+/// Sources that are unavailable (no event log, not a git repository) are
+/// skipped; a failing `git log` inside a repository is returned as an error.
+pub fn ticket_activity(
+    ticket_id: &str,
+    metadata: &TicketMetadata,
+    notes: &[Note],
+) -> Result<Vec<ActivityItem>> {
+    let mut items = Vec::new();
+
+    if let Some(created) = &metadata.created {
+        items.push(ActivityItem::new(
+            Some(created.to_string()),
+            ActivityKind::Created,
+            metadata.title.clone().unwrap_or_default(),
+        ));
+    }
+
+    for note in notes {
+        let first_line = note.text.lines().next().unwrap_or_default();
+        items.push(ActivityItem::new(
+            note.timestamp.clone(),
+            ActivityKind::Note,
+            truncate_string(first_line, 80),
+        ));
+    }
+
+    items.extend(event_activity(ticket_id));
+    items.extend(linked_commits(ticket_id)?);
+
+    sort_activity(&mut items);
+    Ok(items)
+}
+
+/// Sort entries chronologically, undated ones first.
+///
+/// Timestamps from different sources use different offsets and precision,
+/// so they are compared as instants when they parse.
+fn sort_activity(items: &mut [ActivityItem]) {
+    items.sort_by_cached_key(|item| {
+        item.timestamp
+            .as_deref()
+            .and_then(|t| t.parse::<jiff::Timestamp>().ok())
+    });
+}
+
+/// Status changes and syncs from the event log
+fn event_activity(ticket_id: &str) -> Vec<ActivityItem> {
+    let events = read_events().unwrap_or_default();
+    events
+        .into_iter()
+        .filter(|e| e.entity_type == EntityType::Ticket && e.entity_id == ticket_id)
+        .filter_map(|e| {
+            let (kind, summary) = match e.event_type {
+                EventType::StatusChanged => {
+                    let mut summary = format!(
+                        "{} → {}",
+                        e.data["from"].as_str().unwrap_or("?"),
+                        e.data["to"].as_str().unwrap_or("?")
+                    );
+                    if let Some(note) = e.data["summary"].as_str() {
+                        summary.push_str(&format!(": {}", truncate_string(note, 60)));
+                    }
+                    (ActivityKind::Status, summary)
+                }
+                EventType::TicketSynced => {
+                    let remote = e.data["remote_ref"].as_str().unwrap_or("remote");
+                    let summary = if e.data["changes_made"].as_bool().unwrap_or(false) {
+                        format!("synced with {remote}")
+                    } else {
+                        format!("synced with {remote} (no changes)")
+                    };
+                    (ActivityKind::Sync, summary)
+                }
+                _ => return None,
+            };
+            Some(ActivityItem {
+                actor: Some(e.actor.to_string()),
+                ..ActivityItem::new(Some(e.timestamp), kind, summary)
+            })
+        })
+        .collect()
+}
+
+/// Commits in the repository containing `.janus` whose message mentions the
+/// ticket ID
+fn linked_commits(ticket_id: &str) -> Result<Vec<ActivityItem>> {
+    let root = janus_root();
+    let dir = root.parent().unwrap_or(Path::new("."));
+    // Without git, outside a repository, or before its first commit, there
+    // are no commits to show
+    if git(Some(dir), ["rev-parse", "--verify", "-q", "HEAD"]).is_err() {
+        return Ok(Vec::new());
+    }
+
+    let format =
+        format!("--format=%h{FIELD_SEP}%aI{FIELD_SEP}%an{FIELD_SEP}%s{FIELD_SEP}%b{RECORD_SEP}");
+    let max_count = format!("--max-count={MAX_COMMITS}");
+    let log = git(
+        Some(dir),
+        [
+            "log",
+            "--fixed-strings",
+            "--grep",
+            ticket_id,
+            &format,
+            &max_count,
+        ],
+    )?;
+    Ok(parse_commits(&log, ticket_id))
+}
+
+fn parse_commits(log: &str, ticket_id: &str) -> Vec<ActivityItem> {
+    log.split(RECORD_SEP)
+        .filter_map(|record| {
+            let mut fields = record.trim_start().splitn(5, FIELD_SEP);
+            let (Some(hash), Some(date), Some(author), Some(subject)) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return None;
+            };
+            let body = fields.next().unwrap_or_default();
+            if subject.starts_with(COMMIT_PREFIX)
+                || !(mentions(subject, ticket_id) || mentions(body, ticket_id))
+            {
+                return None;
+            }
+            Some(ActivityItem {
+                actor: Some(author.to_string()),
+                ..ActivityItem::new(
+                    Some(date.to_string()),
+                    ActivityKind::Commit,
+                    format!("{hash} {subject}"),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Whether `text` mentions `id` as a whole word, so that `j-a1b` does not
+/// match a commit about `j-a1b2`
+fn mentions(text: &str, id: &str) -> bool {
+    let is_id_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+    text.match_indices(id).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + id.len()..].chars().next();
+        !before.is_some_and(is_id_char) && !after.is_some_and(is_id_char)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commits() {
+        let log = format!(
+            "abc1234{f}2024-06-02T09:00:00+02:00{f}Ada{f}Fix login (j-a1b2){f}{r}\n\
+             def5678{f}2024-06-03T09:00:00Z{f}Ada{f}janus: j-a1b2 status new→complete{f}{r}\n\
+             0123456{f}2024-06-04T09:00:00Z{f}Ada{f}Refactor{f}Refs j-a1b23{r}\n",
+            f = FIELD_SEP,
+            r = RECORD_SEP
+        );
+        let commits = parse_commits(&log, "j-a1b2");
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].summary, "abc1234 Fix login (j-a1b2)");
+        assert_eq!(commits[0].actor.as_deref(), Some("Ada"));
+    }
+
+    #[test]
+    fn test_sort_activity_across_offsets() {
+        let item = |time: Option<&str>, summary: &str| {
+            ActivityItem::new(
+                time.map(str::to_string),
+                ActivityKind::Note,
+                summary.to_string(),
+            )
+        };
+        let mut items = vec![
+            item(Some("2024-06-02T09:30:00Z"), "third"),
+            item(Some("2024-06-02T10:00:00+02:00"), "second"),
+            item(None, "first"),
+        ];
+        sort_activity(&mut items);
+        let order: Vec<&str> = items.iter().map(|i| i.summary.as_str()).collect();
+        assert_eq!(order, vec!["first", "second", "third"]);
+        assert_eq!(items[2].display_time(), "2024-06-02 09:30");
+    }
+}
@@ -6,6 +6,7 @@ use crate::types::{
 };
 use crate::utils;
 use serde::Serialize;
+use std::fs::File;
 use std::path::PathBuf;
 
 /// Temporary struct for serializing ticket frontmatter to YAML
@@ -27,6 +28,10 @@ struct TicketFrontmatter {
     #[serde(skip_serializing_if = "Option::is_none")]
     remote: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    assignee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    component: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     spawned_from: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     spawn_context: Option<String>,
@@ -51,6 +56,8 @@ pub struct TicketBuilder {
     external_ref: Option<String>,
     parent: Option<String>,
     remote: Option<String>,
+    assignee: Option<String>,
+    component: Option<String>,
     uuid: Option<String>,
     created: Option<String>,
     run_hooks: bool,
@@ -76,6 +83,8 @@ impl TicketBuilder {
             external_ref: None,
             parent: None,
             remote: None,
+            assignee: None,
+            component: None,
             uuid: None,
             created: None,
             run_hooks: true,
@@ -138,6 +147,16 @@ impl TicketBuilder {
         self
     }
 
+    pub fn assignee(mut self, assignee: Option<impl Into<String>>) -> Self {
+        self.assignee = assignee.map(|a| a.into());
+        self
+    }
+
+    pub fn component(mut self, component: Option<impl Into<String>>) -> Self {
+        self.component = component.map(|c| c.into());
+        self
+    }
+
     pub fn uuid(mut self, uuid: Option<impl Into<String>>) -> Self {
         self.uuid = uuid.map(|u| u.into());
         self
@@ -186,8 +205,14 @@ impl TicketBuilder {
     pub fn build(self) -> Result<(String, PathBuf)> {
         utils::ensure_dir()?;
 
+        // Held until the ticket is written, so a concurrent create can't
+        // pick the same sequential ID
+        let id_lock = utils::lock_sequential_ids(self.prefix.as_deref())?;
         let id = utils::generate_id_with_custom_prefix(self.prefix.as_deref())?;
+        self.write_ticket(id, id_lock)
+    }
 
+    fn write_ticket(self, id: String, id_lock: Option<File>) -> Result<(String, PathBuf)> {
         // Validate that the generated ID is safe to use as a filename
         utils::validate_filename(&id)?;
 
@@ -212,6 +237,8 @@ impl TicketBuilder {
             external_ref: self.external_ref,
             parent: self.parent,
             remote: self.remote,
+            assignee: self.assignee,
+            component: self.component,
             spawned_from: self.spawned_from,
             spawn_context: self.spawn_context,
             depth: self.depth,
@@ -252,6 +279,8 @@ impl TicketBuilder {
             run_pre_hooks(HookEvent::PreWrite, &context)?;
 
             crate::fs::write_file_atomic(&file_path, &content)?;
+            // Post hooks may create tickets themselves
+            drop(id_lock);
 
             run_post_hooks(HookEvent::PostWrite, &context);
             run_post_hooks(HookEvent::TicketCreated, &context);
@@ -0,0 +1,162 @@
+//! Items in a ticket's `## Acceptance Criteria` section.
+//!
+//! Every top-level list item in the section is a criterion. A task list
+//! marker (`- [ ]`, `- [x]`) records whether it has been met; a plain bullet
+//! counts as not met yet. Checking a criterion rewrites only its own line, so
+//! any other text in the section is left as it was.
+
+use serde::Serialize;
+
+/// One acceptance criterion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Criterion {
+    pub text: String,
+    pub checked: bool,
+}
+
+impl Criterion {
+    /// Parse a criterion given as text, where a leading `[x]` or `[ ]`
+    /// marks it met or not. Bullet markers, headings, and line breaks are
+    /// removed so the text fits on a single list item.
+    pub fn from_input(input: &str) -> Self {
+        let text = input
+            .lines()
+            .map(|line| line.trim().trim_start_matches('#').trim())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let text = strip_bullet(&text).unwrap_or(&text);
+        let (checked, text) = strip_checkbox(text);
+        Criterion {
+            text: text.trim().to_string(),
+            checked: checked.unwrap_or(false),
+        }
+    }
+}
+
+/// The criteria listed in an Acceptance Criteria section, in order.
+pub fn parse_criteria(section: &str) -> Vec<Criterion> {
+    section
+        .lines()
+        .filter_map(parse_item)
+        .map(|(_, checked, text)| Criterion {
+            text: text.to_string(),
+            checked: checked.unwrap_or(false),
+        })
+        .collect()
+}
+
+/// Write criteria as a task list.
+pub fn render_criteria(criteria: &[Criterion]) -> String {
+    criteria
+        .iter()
+        .map(|c| format!("- [{}] {}", if c.checked { "x" } else { " " }, c.text))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Mark criterion `index` (1-based) as met or not, turning a plain bullet
+/// into a task list item if needed. Returns `None` if there is no such
+/// criterion.
+pub fn set_checked(section: &str, index: usize, checked: bool) -> Option<String> {
+    let mut seen = 0;
+    let mut found = false;
+    let lines: Vec<String> = section
+        .lines()
+        .map(|line| {
+            let Some((bullet, _, text)) = parse_item(line) else {
+                return line.to_string();
+            };
+            seen += 1;
+            if seen != index {
+                return line.to_string();
+            }
+            found = true;
+            format!("{bullet} [{}] {text}", if checked { "x" } else { " " })
+        })
+        .collect();
+    found.then(|| lines.join("\n"))
+}
+
+/// Split a top-level list item into its bullet (`-`, `*`, `+`, or `1.`),
+/// checkbox state, and text.
+fn parse_item(line: &str) -> Option<(&str, Option<bool>, &str)> {
+    let rest = strip_bullet(line)?;
+    let bullet = line[..line.len() - rest.len()].trim_end();
+    let (checked, text) = strip_checkbox(rest);
+    let text = text.trim();
+    (!text.is_empty()).then_some((bullet, checked, text))
+}
+
+fn strip_bullet(line: &str) -> Option<&str> {
+    line.strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))
+        .or_else(|| {
+            let (number, rest) = line.split_once(". ")?;
+            (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit())).then_some(rest)
+        })
+        .map(str::trim_start)
+}
+
+fn strip_checkbox(text: &str) -> (Option<bool>, &str) {
+    if let Some(rest) = text.strip_prefix("[ ]") {
+        (Some(false), rest)
+    } else if let Some(rest) = text
+        .strip_prefix("[x]")
+        .or_else(|| text.strip_prefix("[X]"))
+    {
+        (Some(true), rest)
+    } else {
+        (None, text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECTION: &str = "Must hold for release:\n\n- [x] Login works\n- Errors are shown\n  - nested detail\n2. [ ] Session persists";
+
+    #[test]
+    fn test_parse_criteria() {
+        let criteria = parse_criteria(SECTION);
+        assert_eq!(
+            criteria
+                .iter()
+                .map(|c| (c.text.as_str(), c.checked))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Login works", true),
+                ("Errors are shown", false),
+                ("Session persists", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_checked() {
+        let updated = set_checked(SECTION, 2, true).unwrap();
+        assert_eq!(
+            updated,
+            "Must hold for release:\n\n- [x] Login works\n- [x] Errors are shown\n  - nested detail\n2. [ ] Session persists"
+        );
+        assert!(set_checked(SECTION, 4, true).is_none());
+        assert!(set_checked(SECTION, 0, true).is_none());
+    }
+
+    #[test]
+    fn test_criterion_from_input() {
+        assert_eq!(
+            Criterion::from_input("- [x] Works\non mobile"),
+            Criterion {
+                text: "Works on mobile".to_string(),
+                checked: true,
+            }
+        );
+        assert_eq!(
+            render_criteria(&[Criterion::from_input("## Docs updated")]),
+            "- [ ] Docs updated"
+        );
+    }
+}
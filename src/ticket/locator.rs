@@ -3,13 +3,31 @@
 //! This module provides the `TicketLocator` type which encapsulates the relationship
 //! between a ticket's ID and its file path on disk. It handles both finding existing
 //! tickets by partial ID and creating locators for new tickets.
+//!
+//! Anywhere a ticket ID is accepted, `@title:<query>` looks the ticket up by
+//! a fuzzy match on its title instead (the CLI's `--title` flag expands to
+//! this prefix).
+//!
+//! A ticket's UUID (or a UUID prefix) is also accepted in place of its ID,
+//! resolved through the store's UUID index.
 
 use std::path::PathBuf;
 
+use crate::commands::interactive::select_option;
 use crate::error::{JanusError, Result};
 use crate::store::get_or_init_store;
 use crate::types::{TicketId, tickets_items_dir};
-use crate::utils::extract_id_from_path;
+use crate::utils::{extract_id_from_path, is_stdin_tty};
+
+/// Prefix marking a ticket reference as a title query (`@title:login crash`)
+pub const TITLE_PREFIX: &str = "@title:";
+
+/// Most candidates offered when prompting to resolve an ambiguous title
+const MAX_TITLE_CHOICES: usize = 10;
+
+/// Shortest UUID prefix tried when no ticket ID matches, so short inputs
+/// don't resolve to a ticket by coincidence
+const MIN_UUID_PREFIX_LEN: usize = 4;
 
 fn validate_partial_id(id: &str) -> Result<String> {
     let trimmed = id.trim();
@@ -31,6 +49,9 @@ fn validate_partial_id(id: &str) -> Result<String> {
 /// Searches for a ticket file matching the given partial ID in the tickets directory.
 /// Returns the full path to the ticket file if found, or an error if not found
 /// or if multiple tickets match (ambiguous).
+///
+/// When no ticket ID matches, the input is tried as a full UUID or UUID
+/// prefix, so references stored before a ticket's ID changed still resolve.
 async fn find_ticket_by_id_impl(partial_id: &str) -> Result<PathBuf> {
     let dir = tickets_items_dir();
 
@@ -47,7 +68,10 @@ async fn find_ticket_by_id_impl(partial_id: &str) -> Result<PathBuf> {
     }
 
     // Partial match via store (store is authoritative)
-    let matches = store.find_by_partial_id(partial_id);
+    let mut matches = store.find_by_partial_id(partial_id);
+    if matches.is_empty() && partial_id.len() >= MIN_UUID_PREFIX_LEN {
+        matches = store.find_by_uuid(partial_id);
+    }
     match matches.len() {
         0 => Err(JanusError::TicketNotFound(TicketId::new_unchecked(
             partial_id,
@@ -60,6 +84,48 @@ async fn find_ticket_by_id_impl(partial_id: &str) -> Result<PathBuf> {
     }
 }
 
+/// Find a ticket by a fuzzy match on its title.
+///
+/// A single match resolves directly. With several matches, an interactive
+/// terminal is asked to pick one; otherwise the lookup fails as ambiguous.
+async fn find_ticket_by_title(query: &str) -> Result<PathBuf> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Err(JanusError::InvalidInput(
+            "title query cannot be empty".to_string(),
+        ));
+    }
+
+    let store = get_or_init_store().await?;
+    let mut matches = store.find_by_title(query);
+
+    let id = match matches.len() {
+        0 => return Err(JanusError::TicketTitleNotFound(query.to_string())),
+        1 => matches.remove(0).0,
+        _ if is_stdin_tty() => {
+            matches.truncate(MAX_TITLE_CHOICES);
+            println!("Multiple tickets match '{query}':");
+            for (i, (id, title)) in matches.iter().enumerate() {
+                println!("  [{i}] {id}  {title}");
+            }
+            let options: Vec<&str> = matches.iter().map(|(id, _)| id.as_str()).collect();
+            let choice = select_option("Select a ticket", &options, Some(0))?;
+            matches.swap_remove(choice).0
+        }
+        _ => {
+            return Err(JanusError::AmbiguousTicketTitle(
+                query.to_string(),
+                matches
+                    .into_iter()
+                    .map(|(id, title)| format!("{id} ({title})"))
+                    .collect(),
+            ));
+        }
+    };
+
+    Ok(tickets_items_dir().join(format!("{id}.md")))
+}
+
 /// Filesystem-based find implementation for tickets (fallback when store unavailable).
 /// Simple locator for ticket files
 ///
@@ -81,8 +147,13 @@ impl TicketLocator {
 
     /// Find a ticket by its (partial) ID
     ///
-    /// Searches for a ticket matching the given partial ID.
+    /// Searches for a ticket matching the given partial ID, or by title when
+    /// the reference starts with `@title:`.
     pub async fn find(partial_id: &str) -> Result<Self> {
+        if let Some(query) = partial_id.trim_start().strip_prefix(TITLE_PREFIX) {
+            let file_path = find_ticket_by_title(query).await?;
+            return TicketLocator::new(file_path);
+        }
         let partial_id = validate_partial_id(partial_id)?;
         let file_path = find_ticket_by_id_impl(&partial_id).await?;
         TicketLocator::new(file_path)
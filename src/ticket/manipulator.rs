@@ -1,5 +1,6 @@
 use crate::error::{JanusError, Result};
 use crate::parser::TITLE_RE;
+use crate::types::TicketStatus;
 
 use serde_json;
 
@@ -41,7 +42,8 @@ impl FrontmatterEditor {
     /// YAML characters are handled correctly and prevent YAML injection.
     ///
     /// Arrays can be passed as JSON strings (e.g., `["item1", "item2"]`) and will be
-    /// converted to YAML sequences automatically.
+    /// converted to YAML sequences automatically. Arrays of objects (e.g.,
+    /// `[{"name": "a.png"}]`) become sequences of mappings.
     pub fn update_field(&mut self, field: &str, value: &str) -> Result<()> {
         use serde_yaml_ng::Value;
 
@@ -53,10 +55,14 @@ impl FrontmatterEditor {
                     // Convert Vec<String> to YAML sequence
                     Value::Sequence(array.into_iter().map(Value::String).collect())
                 }
-                Err(_) => {
+                Err(_) => match serde_json::from_str::<Vec<serde_json::Value>>(value)
+                    .ok()
+                    .and_then(|array| serde_yaml_ng::to_value(array).ok())
+                {
+                    Some(sequence) => sequence,
                     // Not valid JSON, treat as string
-                    Value::String(value.to_string())
-                }
+                    None => Value::String(value.to_string()),
+                },
             }
         } else {
             // For non-array values, use string
@@ -75,6 +81,79 @@ impl FrontmatterEditor {
         self.frontmatter.remove(Value::String(field.to_string()));
     }
 
+    /// Get a string field from the frontmatter.
+    pub fn get_str(&self, field: &str) -> Option<&str> {
+        self.frontmatter.get(field).and_then(|v| v.as_str())
+    }
+
+    /// Set the status field and maintain the status timestamps.
+    ///
+    /// When the status changes, the new status is appended to `status-history`,
+    /// `started-at` is stamped the first time the ticket enters `in_progress`,
+    /// and `closed-at` is set on entering a terminal status and removed (along
+    /// with any `resolution`) on reopening, which also bumps `reopen-count`.
+    /// `completed-at` is stamped only the first time the ticket
+    /// reaches a terminal status. A value that is not a valid status is written
+    /// as-is.
+    pub fn update_status(&mut self, value: &str, now: &str) -> Result<()> {
+        use serde_yaml_ng::{Mapping, Value};
+
+        let old_status = self
+            .get_str("status")
+            .and_then(|s| s.parse::<TicketStatus>().ok());
+        self.update_field("status", value)?;
+
+        let Ok(new_status) = value.parse::<TicketStatus>() else {
+            return Ok(());
+        };
+        if old_status == Some(new_status) {
+            return Ok(());
+        }
+
+        let mut entry = Mapping::new();
+        entry.insert("status".into(), new_status.to_string().into());
+        entry.insert("at".into(), now.into());
+        match self.frontmatter.get_mut("status-history") {
+            Some(Value::Sequence(history)) => history.push(Value::Mapping(entry)),
+            _ => {
+                self.frontmatter.insert(
+                    "status-history".into(),
+                    Value::Sequence(vec![Value::Mapping(entry)]),
+                );
+            }
+        }
+
+        if new_status == TicketStatus::InProgress && self.get_str("started-at").is_none() {
+            self.update_field("started-at", now)?;
+        }
+
+        let was_terminal = old_status.is_some_and(|s| s.is_terminal());
+        if new_status.is_terminal() {
+            if !was_terminal || self.get_str("closed-at").is_none() {
+                self.update_field("closed-at", now)?;
+            }
+            // Preserve an existing stamp so Complete -> Archived keeps the
+            // original completion time
+            if !was_terminal && self.get_str("completed-at").is_none() {
+                self.update_field("completed-at", now)?;
+            }
+        } else {
+            self.remove_field("closed-at");
+            self.remove_field("resolution");
+            if was_terminal {
+                let count = self
+                    .frontmatter
+                    .get("reopen-count")
+                    .and_then(Value::as_u64)
+                    .unwrap_or(0);
+                self.frontmatter
+                    .insert("reopen-count".into(), Value::from(count + 1));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Build the final content with the updated frontmatter.
     pub fn build(self) -> Result<String> {
         // Serialize the mapping back to YAML
@@ -106,6 +185,26 @@ pub fn update_field(raw_content: &str, field: &str, value: &str) -> Result<Strin
     editor.build()
 }
 
+/// Set the status of a ticket file, maintaining its status timestamps and
+/// history (see [`FrontmatterEditor::update_status`]).
+pub fn update_status(raw_content: &str, value: &str, now: &str) -> Result<String> {
+    let mut editor = FrontmatterEditor::new(raw_content)?;
+    editor.update_status(value, now)?;
+    editor.build()
+}
+
+/// Set or remove several fields of a ticket file in one pass (`None` removes).
+pub fn update_fields(raw_content: &str, fields: &[(&str, Option<&str>)]) -> Result<String> {
+    let mut editor = FrontmatterEditor::new(raw_content)?;
+    for (field, value) in fields {
+        match value {
+            Some(value) => editor.update_field(field, value)?,
+            None => editor.remove_field(field),
+        }
+    }
+    editor.build()
+}
+
 /// Remove a field from the YAML frontmatter of a ticket file.
 pub fn remove_field(raw_content: &str, field: &str) -> Result<String> {
     let mut editor = FrontmatterEditor::new(raw_content)?;
@@ -280,6 +379,21 @@ id: test-1234
         assert!(result.contains("[tag1, tag2]"));
     }
 
+    #[test]
+    fn test_update_field_with_object_array() {
+        let content = r#"---
+id: test-1234
+---
+# Test Ticket"#;
+
+        let result =
+            update_field(content, "attachments", r#"[{"name":"shot.png","size":12}]"#).unwrap();
+        let (frontmatter, _) = crate::parser::split_frontmatter(&result).unwrap();
+        let yaml: serde_yaml_ng::Value = serde_yaml_ng::from_str(&frontmatter).unwrap();
+        assert_eq!(yaml["attachments"][0]["name"].as_str(), Some("shot.png"));
+        assert_eq!(yaml["attachments"][0]["size"].as_u64(), Some(12));
+    }
+
     #[test]
     fn test_update_field_with_braces() {
         let content = r#"---
@@ -448,4 +562,105 @@ priority: 2
         assert!(result.contains("id: test-1234"));
         assert!(result.contains("priority: 2"));
     }
+
+    #[test]
+    fn test_update_status_maintains_timestamps() {
+        let content = "---\nid: test-1234\nstatus: new\n---\n# Test Ticket\n";
+
+        let started = update_status(content, "in_progress", "2024-01-01T00:00:00Z").unwrap();
+        assert_eq!(
+            extract_field_value(&started, "started-at")
+                .unwrap()
+                .as_deref(),
+            Some("2024-01-01T00:00:00Z")
+        );
+
+        let closed = update_status(&started, "complete", "2024-01-02T00:00:00Z").unwrap();
+        assert_eq!(
+            extract_field_value(&closed, "closed-at")
+                .unwrap()
+                .as_deref(),
+            Some("2024-01-02T00:00:00Z")
+        );
+        assert_eq!(
+            extract_field_value(&closed, "completed-at")
+                .unwrap()
+                .as_deref(),
+            Some("2024-01-02T00:00:00Z")
+        );
+
+        // Reopening clears closed-at and the resolution but keeps the first
+        // start and completion
+        let closed = update_field(&closed, "resolution", "done").unwrap();
+        let reopened = update_status(&closed, "in_progress", "2024-01-03T00:00:00Z").unwrap();
+        assert_eq!(extract_field_value(&reopened, "closed-at").unwrap(), None);
+        assert_eq!(extract_field_value(&reopened, "resolution").unwrap(), None);
+        assert!(reopened.contains("reopen-count: 1"));
+        assert_eq!(
+            extract_field_value(&reopened, "started-at")
+                .unwrap()
+                .as_deref(),
+            Some("2024-01-01T00:00:00Z")
+        );
+
+        let reclosed = update_status(&reopened, "cancelled", "2024-01-04T00:00:00Z").unwrap();
+        assert_eq!(
+            extract_field_value(&reclosed, "closed-at")
+                .unwrap()
+                .as_deref(),
+            Some("2024-01-04T00:00:00Z")
+        );
+        assert_eq!(
+            extract_field_value(&reclosed, "completed-at")
+                .unwrap()
+                .as_deref(),
+            Some("2024-01-02T00:00:00Z")
+        );
+
+        let metadata = crate::ticket::parser::parse(&format!(
+            "---\nuuid: 550e8400-e29b-41d4-a716-446655440000\n{}",
+            reclosed.trim_start_matches("---\n")
+        ))
+        .unwrap();
+        let statuses: Vec<TicketStatus> =
+            metadata.status_history.iter().map(|c| c.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                TicketStatus::InProgress,
+                TicketStatus::Complete,
+                TicketStatus::InProgress,
+                TicketStatus::Cancelled,
+            ]
+        );
+        assert_eq!(
+            metadata.status_history[3].at.as_ref(),
+            "2024-01-04T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_update_status_unchanged_or_invalid() {
+        let content = "---\nid: test-1234\nstatus: complete\n---\n# Test Ticket\n";
+
+        // Setting the same status records nothing
+        let same = update_status(content, "complete", "2024-01-01T00:00:00Z").unwrap();
+        assert!(!same.contains("status-history"));
+        assert!(!same.contains("closed-at"));
+
+        // Complete -> Archived keeps an existing closed-at
+        let with_closed = update_field(content, "closed-at", "2023-12-01T00:00:00Z").unwrap();
+        let archived = update_status(&with_closed, "archived", "2024-01-01T00:00:00Z").unwrap();
+        assert_eq!(
+            extract_field_value(&archived, "closed-at")
+                .unwrap()
+                .as_deref(),
+            Some("2023-12-01T00:00:00Z")
+        );
+
+        // Values that are not statuses are written without stamps
+        let invalid = update_status(content, "bogus", "2024-01-01T00:00:00Z").unwrap();
+        assert!(invalid.contains("status: bogus"));
+        assert!(!invalid.contains("status-history"));
+    }
 }
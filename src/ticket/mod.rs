@@ -1,15 +1,22 @@
+mod activity;
 mod builder;
+mod criteria;
 mod locator;
 mod manipulator;
+mod notes;
 mod parser;
 mod repository;
 mod validate;
 
 pub use crate::types::ArrayField;
 pub use crate::types::validate_field_name;
+pub use activity::{ActivityItem, ActivityKind, ticket_activity};
 pub use builder::TicketBuilder;
+pub use criteria::{Criterion, parse_criteria, render_criteria};
+pub use locator::TITLE_PREFIX;
 pub use manipulator::{extract_body, remove_field, update_field, update_title};
-pub use parser::parse as parse_ticket;
+pub use notes::{Note, parse_notes};
+pub use parser::{extract_ticket_section, parse as parse_ticket};
 
 pub use repository::{
     TicketLoadResult, build_ticket_map, find_tickets, get_all_children_counts, get_all_tickets,
@@ -31,11 +38,13 @@ use crate::parser::{
 use crate::ticket::locator::TicketLocator;
 use crate::ticket::manipulator::{
     remove_field as remove_field_from_content, update_field as update_field_in_content,
+    update_fields as update_fields_in_content, update_status as update_status_in_content,
 };
 use crate::ticket::parser::parse;
 use crate::types::EntityType;
 use crate::types::TicketId;
 use crate::types::TicketMetadata;
+use crate::types::TicketSection;
 use crate::utils::extract_id_from_path;
 use serde_json;
 use serde_yaml_ng;
@@ -147,9 +156,24 @@ impl Ticket {
     }
 
     /// Write raw content without hooks (blocking - for sync contexts).
+    ///
+    /// Every ticket write ends here, so this is where edits to fields mirrored
+    /// from a remote are caught (see [`crate::remote::mirror`]).
     fn write_raw(&self, content: &str) -> Result<()> {
+        self.check_mirrored_fields(content)?;
         self.ensure_parent_dir()?;
-        crate::fs::write_file_atomic(&self.file_path, content)
+        crate::fs::write_document_atomic(&self.file_path, content)
+    }
+
+    /// Apply mirror mode to a write of `content` over the current file.
+    fn check_mirrored_fields(&self, content: &str) -> Result<()> {
+        let Ok(current) = std::fs::read_to_string(&self.file_path) else {
+            return Ok(());
+        };
+        match (parse(&current), parse(content)) {
+            (Ok(old), Ok(new)) => crate::remote::mirror::check_local_edit(&self.id, &old, &new),
+            _ => Ok(()),
+        }
     }
 
     /// Ensure the parent directory exists (blocking - for sync contexts).
@@ -182,25 +206,6 @@ impl Ticket {
         // Capture old value for event logging
         let old_value = self.extract_field_value_for_logging(&raw_content, field);
 
-        // When transitioning status into a terminal state for the first time, also stamp
-        // `completed-at`. This covers direct `update_field("status", ...)` callers (set
-        // command, TUI cycle-status, etc.) so every status-writing path gets the stamp.
-        let completed_at_value = if field == "status" {
-            let new_status = value.parse::<crate::types::TicketStatus>().ok();
-            let is_new_terminal = new_status.is_some_and(|s| s.is_terminal());
-            let old_metadata = parse(&raw_content).ok();
-            let was_terminal = old_metadata
-                .as_ref()
-                .and_then(|m| m.status)
-                .is_some_and(|s| s.is_terminal());
-            let already_stamped = old_metadata
-                .as_ref()
-                .is_some_and(|m| m.completed_at.is_some());
-            (is_new_terminal && !was_terminal && !already_stamped).then(crate::utils::iso_date)
-        } else {
-            None
-        };
-
         let context = self
             .hook_context()
             .with_field_name(field)
@@ -209,10 +214,14 @@ impl Ticket {
         crate::fs::with_write_hooks(
             context,
             || {
-                let mut new_content = update_field_in_content(&raw_content, field, value)?;
-                if let Some(ts) = completed_at_value.as_deref() {
-                    new_content = update_field_in_content(&new_content, "completed-at", ts)?;
-                }
+                // Status writes go through `update_status_in_content` so direct
+                // `update_field("status", ...)` callers (set command, TUI
+                // cycle-status, etc.) also maintain the status timestamps.
+                let new_content = if field == "status" {
+                    update_status_in_content(&raw_content, value, &crate::utils::iso_date())?
+                } else {
+                    update_field_in_content(&raw_content, field, value)?
+                };
                 self.write_raw(&new_content)
             },
             Some(HookEvent::TicketUpdated),
@@ -265,6 +274,46 @@ impl Ticket {
         Ok(())
     }
 
+    /// Set or remove several frontmatter fields in a single write (`None`
+    /// removes the field).
+    ///
+    /// Either every change lands or none does. Emits a `FieldUpdated` event
+    /// per field after a successful write.
+    pub fn update_fields(&self, fields: &[(&str, Option<&str>)]) -> Result<()> {
+        for (field, _) in fields {
+            validate_field_name(field, "update")?;
+        }
+
+        let raw_content = self.read_content()?;
+
+        // Capture old values for event logging
+        let old_values: Vec<Option<String>> = fields
+            .iter()
+            .map(|(field, _)| self.extract_field_value_for_logging(&raw_content, field))
+            .collect();
+
+        crate::fs::with_write_hooks(
+            self.hook_context(),
+            || {
+                let new_content = update_fields_in_content(&raw_content, fields)?;
+                self.write_raw(&new_content)
+            },
+            Some(HookEvent::TicketUpdated),
+        )?;
+
+        for ((field, value), old_value) in fields.iter().zip(old_values) {
+            crate::events::log_field_updated(
+                &self.id,
+                field,
+                old_value.as_deref(),
+                value.unwrap_or(""),
+                None,
+            );
+        }
+
+        Ok(())
+    }
+
     /// Update the status field with optional completion summary.
     ///
     /// This is a specialized method for status changes that emits a `StatusChanged`
@@ -294,21 +343,10 @@ impl Ticket {
         let raw_content = self.read_content()?;
 
         // Capture old status for event logging
-        let old_metadata = parse(&raw_content).ok();
-        let old_status = old_metadata.as_ref().and_then(|m| m.status);
+        let old_status = parse(&raw_content).ok().and_then(|m| m.status);
 
         let new_status_str = new_status.to_string();
 
-        // Decide whether this transition should stamp `completed-at`. Stamp only on the
-        // first time a ticket reaches a terminal state, and preserve an existing stamp
-        // so that, e.g., Complete → Archived keeps the original completion time.
-        let needs_completed_at_stamp = new_status.is_terminal()
-            && old_status.is_none_or(|s| !s.is_terminal())
-            && old_metadata
-                .as_ref()
-                .is_none_or(|m| m.completed_at.is_none());
-        let completed_at_value = needs_completed_at_stamp.then(crate::utils::iso_date);
-
         // Update the status field
         let context = self
             .hook_context()
@@ -318,11 +356,12 @@ impl Ticket {
         crate::fs::with_write_hooks(
             context,
             || {
-                let mut new_content =
-                    update_field_in_content(&raw_content, "status", &new_status_str)?;
-                if let Some(ts) = completed_at_value.as_deref() {
-                    new_content = update_field_in_content(&new_content, "completed-at", ts)?;
-                }
+                // Also maintains started-at, closed-at, completed-at, and status-history
+                let new_content = update_status_in_content(
+                    &raw_content,
+                    &new_status_str,
+                    &crate::utils::iso_date(),
+                )?;
                 self.write_raw(&new_content)
             },
             Some(HookEvent::TicketUpdated),
@@ -369,10 +408,22 @@ impl Ticket {
                 "external_ref" => metadata.external_ref.clone(),
                 "size" => metadata.size.map(|s| s.to_string()),
                 "remote" => metadata.remote.clone(),
+                "assignee" => metadata.assignee.clone(),
                 "triaged" => metadata.triaged.map(|t| t.to_string()),
                 "deps" => Some(format!("{:?}", metadata.deps)),
+                "external-deps" => Some(format!("{:?}", metadata.external_deps)),
                 "links" => Some(format!("{:?}", metadata.links)),
                 "labels" => Some(format!("{:?}", metadata.labels)),
+                "pull-requests" => Some(format!("{:?}", metadata.pull_requests)),
+                "docs" => Some(format!("{:?}", metadata.docs)),
+                "attachments" => Some(
+                    metadata
+                        .attachments
+                        .iter()
+                        .map(|a| a.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
                 _ => None,
             }
         } else {
@@ -753,6 +804,117 @@ impl Ticket {
         Ok(())
     }
 
+    /// The ticket's notes, oldest first.
+    pub fn notes(&self) -> Result<Vec<notes::Note>> {
+        let (_frontmatter_raw, body) = self.read_body()?;
+        Ok(notes::parse_notes(&body))
+    }
+
+    /// Replace the text of note `index` (1-based), keeping its timestamp and
+    /// the rest of the body as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JanusError::EmptyNote` if the text is empty, or
+    /// `JanusError::NoteNotFound` if there is no such note.
+    pub fn edit_note(
+        &self,
+        index: usize,
+        note_text: &str,
+        actor: Option<crate::events::Actor>,
+    ) -> Result<()> {
+        if note_text.trim().is_empty() {
+            return Err(JanusError::EmptyNote);
+        }
+
+        let (frontmatter_raw, body) = self.read_body()?;
+        let note = self.find_note(&body, index)?;
+        let new_body = notes::replace_note(&body, &note, note_text);
+        self.write(&format!("---\n{frontmatter_raw}\n---\n{new_body}"))?;
+
+        crate::events::log_note_edited(&self.id, index, note_text, actor);
+        Ok(())
+    }
+
+    /// Remove note `index` (1-based), leaving the rest of the body as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JanusError::NoteNotFound` if there is no such note.
+    pub fn remove_note(&self, index: usize, actor: Option<crate::events::Actor>) -> Result<()> {
+        let (frontmatter_raw, body) = self.read_body()?;
+        let note = self.find_note(&body, index)?;
+        let new_body = notes::remove_note(&body, &note);
+        self.write(&format!("---\n{frontmatter_raw}\n---\n{new_body}"))?;
+
+        crate::events::log_note_removed(&self.id, index, actor);
+        Ok(())
+    }
+
+    fn read_body(&self) -> Result<(String, String)> {
+        let content = self.read_content()?;
+        parse_document_raw(&content).map_err(|e| {
+            JanusError::InvalidFormat(format!("Failed to parse ticket {}: {}", self.id, e))
+        })
+    }
+
+    fn find_note(&self, body: &str, index: usize) -> Result<notes::Note> {
+        let mut notes = notes::parse_notes(body);
+        let count = notes.len();
+        if index == 0 || index > count {
+            return Err(JanusError::NoteNotFound {
+                id: self.id.clone(),
+                index,
+                count,
+            });
+        }
+        Ok(notes.swap_remove(index - 1))
+    }
+
+    /// The ticket's acceptance criteria, in order.
+    pub fn acceptance_criteria(&self) -> Result<Vec<Criterion>> {
+        let (_frontmatter_raw, body) = self.read_body()?;
+        let section = parser::extract_ticket_section(&body, TicketSection::Acceptance)?;
+        Ok(section.as_deref().map(parse_criteria).unwrap_or_default())
+    }
+
+    /// Replace the Acceptance Criteria section with `criteria` as a task
+    /// list. An empty list removes the section.
+    pub fn set_acceptance_criteria(&self, criteria: &[Criterion]) -> Result<()> {
+        let content = (!criteria.is_empty()).then(|| render_criteria(criteria));
+        self.update_ticket_section(TicketSection::Acceptance, content.as_deref())
+    }
+
+    /// Mark acceptance criterion `index` (1-based) as met or not, leaving the
+    /// rest of the section as is. Returns the updated criterion.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JanusError::CriterionNotFound` if there is no such criterion.
+    pub fn check_criterion(&self, index: usize, checked: bool) -> Result<Criterion> {
+        let (_frontmatter_raw, body) = self.read_body()?;
+        let section =
+            parser::extract_ticket_section(&body, TicketSection::Acceptance)?.unwrap_or_default();
+        let criteria = parse_criteria(&section);
+        let not_found = || JanusError::CriterionNotFound {
+            id: self.id.clone(),
+            index,
+            count: criteria.len(),
+        };
+        let criterion = index
+            .checked_sub(1)
+            .and_then(|i| criteria.get(i))
+            .ok_or_else(not_found)?;
+        if criterion.checked != checked {
+            let updated = criteria::set_checked(&section, index, checked).ok_or_else(not_found)?;
+            self.update_ticket_section(TicketSection::Acceptance, Some(&updated))?;
+        }
+        Ok(Criterion {
+            text: criterion.text.clone(),
+            checked,
+        })
+    }
+
     /// Write a completion summary section to the ticket file.
     ///
     /// If a "## Completion Summary" section already exists, it will be updated.
@@ -873,6 +1035,37 @@ impl Ticket {
         self.write(&new_content)
     }
 
+    /// Replace one of the conventional body sections.
+    ///
+    /// The section keeps whichever recognized heading it already has (e.g.
+    /// `## Design Notes`); a missing section is added under its preferred
+    /// heading. A description without a `## Description` heading lives
+    /// between the title and the first H2 and is updated in place.
+    pub fn update_ticket_section(
+        &self,
+        section: TicketSection,
+        content: Option<&str>,
+    ) -> Result<()> {
+        let raw_content = self.read_content()?;
+        let (_frontmatter_raw, body) = parse_document_raw(&raw_content).map_err(|e| {
+            JanusError::InvalidFormat(format!("Failed to parse ticket {}: {}", self.id, e))
+        })?;
+
+        let mut existing = None;
+        for heading in section.headings() {
+            if extract_section_from_body(&body, heading)?.is_some() {
+                existing = Some(*heading);
+                break;
+            }
+        }
+
+        match existing {
+            Some(heading) => self.update_section(heading, content),
+            None if section == TicketSection::Description => self.update_description(content),
+            None => self.update_section(section.heading(), content),
+        }
+    }
+
     /// Build a hook context for this ticket.
     pub fn hook_context(&self) -> HookContext {
         HookContext::new()
@@ -902,6 +1095,7 @@ impl Ticket {
                 source: e,
             });
         }
+        crate::fs::record_path(&self.file_path);
 
         run_post_hooks_async(HookEvent::PostDelete, &context).await;
 
@@ -968,6 +1162,7 @@ impl Entity for Ticket {
         let context = self.hook_context();
 
         run_pre_hooks(HookEvent::PreDelete, &context)?;
+        crate::fs::before_change(&self.file_path)?;
 
         if let Err(e) = std::fs::remove_file(&self.file_path)
             && e.kind() != std::io::ErrorKind::NotFound
@@ -979,6 +1174,7 @@ impl Entity for Ticket {
                 source: e,
             });
         }
+        crate::fs::record_path(&self.file_path);
 
         run_post_hooks(HookEvent::PostDelete, &context);
 
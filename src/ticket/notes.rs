@@ -0,0 +1,193 @@
+//! Individual notes in a ticket's `## Notes` section.
+//!
+//! Each note added by `janus add-note` starts with a bold timestamp line
+//! (`**2024-06-01T12:00:00Z**`) and runs until the next one. Text before the
+//! first timestamp (e.g. written by hand) counts as a note without a
+//! timestamp. Notes are located by byte range so that editing or removing
+//! one leaves the rest of the body exactly as it was.
+
+use std::ops::Range;
+
+/// A note from the ticket's Notes section.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Note {
+    pub timestamp: Option<String>,
+    /// Note text, trimmed
+    pub text: String,
+    /// From the timestamp line up to the next note or the end of the section
+    range: Range<usize>,
+    /// The trimmed text within `range`
+    text_range: Range<usize>,
+    /// Whether this is the last note in the section
+    last: bool,
+}
+
+/// The byte range of the Notes section content (after its heading line).
+fn notes_section(body: &str) -> Option<Range<usize>> {
+    let mut offset = 0;
+    let mut start = None;
+    for line in body.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        match start {
+            None => {
+                if trimmed
+                    .strip_prefix("## ")
+                    .is_some_and(|rest| rest.trim().eq_ignore_ascii_case("notes"))
+                {
+                    start = Some(offset + line.len());
+                }
+            }
+            Some(start) if trimmed.starts_with("## ") => return Some(start..offset),
+            Some(_) => {}
+        }
+        offset += line.len();
+    }
+    start.map(|start| start..body.len())
+}
+
+fn timestamp_line(line: &str) -> Option<&str> {
+    line.trim()
+        .strip_prefix("**")
+        .and_then(|s| s.strip_suffix("**"))
+        .filter(|s| !s.is_empty() && !s.contains("**"))
+}
+
+/// Parse the notes in a ticket body, oldest first. Empty notes are skipped.
+pub fn parse_notes(body: &str) -> Vec<Note> {
+    let Some(section) = notes_section(body) else {
+        return Vec::new();
+    };
+
+    // (timestamp, start of note, start of text)
+    let mut starts: Vec<(Option<String>, usize, usize)> =
+        vec![(None, section.start, section.start)];
+    let mut offset = section.start;
+    for line in body[section.clone()].split_inclusive('\n') {
+        if let Some(timestamp) = timestamp_line(line) {
+            starts.push((Some(timestamp.to_string()), offset, offset + line.len()));
+        }
+        offset += line.len();
+    }
+
+    let ends: Vec<usize> = starts
+        .iter()
+        .skip(1)
+        .map(|(_, start, _)| *start)
+        .chain([section.end])
+        .collect();
+
+    starts
+        .into_iter()
+        .zip(ends)
+        .filter_map(|((timestamp, start, text_start), end)| {
+            let raw = &body[text_start..end];
+            let text = raw.trim();
+            if text.is_empty() {
+                return None;
+            }
+            let text_start = text_start + (raw.len() - raw.trim_start().len());
+            Some(Note {
+                timestamp,
+                text: text.to_string(),
+                range: start..end,
+                text_range: text_start..text_start + text.len(),
+                last: end == section.end,
+            })
+        })
+        .collect()
+}
+
+/// Replace the text of `note`, keeping its timestamp.
+pub fn replace_note(body: &str, note: &Note, text: &str) -> String {
+    format!(
+        "{}{}{}",
+        &body[..note.text_range.start],
+        text.trim(),
+        &body[note.text_range.end..]
+    )
+}
+
+/// Remove `note` from the body. The Notes heading is removed along with the
+/// last remaining note.
+pub fn remove_note(body: &str, note: &Note) -> String {
+    let removed = if note.last {
+        // Take the blank lines before the note with it, keeping whatever
+        // followed it (the next section, or the file's trailing newline)
+        let start = body[..note.range.start].trim_end().len();
+        format!("{}{}", &body[..start], &body[note.text_range.end..])
+    } else {
+        format!("{}{}", &body[..note.range.start], &body[note.range.end..])
+    };
+
+    if parse_notes(&removed).is_empty() {
+        crate::parser::remove_section_from_body(&removed, "Notes")
+    } else {
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &str = "# Title\n\nDescription.\n\n## Notes\n\n**2024-06-01T10:00:00Z**\n\nFirst note\nspans lines\n\n**2024-06-02T10:00:00Z**\n\nSecond **bold** note\n";
+
+    #[test]
+    fn test_parse_notes() {
+        let section = "# Title\n\n## Notes\n\nContext before any note\n\n**2024-06-01T10:00:00Z**\n\nFirst note\n\n## Other\n\n**2024-06-03T10:00:00Z**\n";
+        let notes = parse_notes(section);
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0].timestamp, None);
+        assert_eq!(notes[0].text, "Context before any note");
+        assert_eq!(notes[1].timestamp.as_deref(), Some("2024-06-01T10:00:00Z"));
+        assert_eq!(notes[1].text, "First note");
+
+        let notes = parse_notes(BODY);
+        assert_eq!(notes[0].text, "First note\nspans lines");
+        assert_eq!(notes[1].text, "Second **bold** note");
+        assert!(parse_notes("# Title\n").is_empty());
+    }
+
+    #[test]
+    fn test_replace_note() {
+        let notes = parse_notes(BODY);
+        let updated = replace_note(BODY, &notes[0], "Rewritten");
+        assert_eq!(
+            updated,
+            BODY.replace("First note\nspans lines", "Rewritten")
+        );
+    }
+
+    #[test]
+    fn test_remove_note() {
+        let notes = parse_notes(BODY);
+
+        let without_first = remove_note(BODY, &notes[0]);
+        assert_eq!(
+            without_first,
+            "# Title\n\nDescription.\n\n## Notes\n\n**2024-06-02T10:00:00Z**\n\nSecond **bold** note\n"
+        );
+
+        let without_last = remove_note(BODY, &notes[1]);
+        assert_eq!(
+            without_last,
+            "# Title\n\nDescription.\n\n## Notes\n\n**2024-06-01T10:00:00Z**\n\nFirst note\nspans lines\n"
+        );
+
+        let only = parse_notes(&without_first);
+        let empty = remove_note(&without_first, &only[0]);
+        assert!(!empty.contains("## Notes"));
+        assert!(empty.contains("Description."));
+    }
+
+    #[test]
+    fn test_remove_note_before_next_section() {
+        let body =
+            "# T\n\n## Notes\n\n**a**\n\none\n\n**b**\n\ntwo\n\n## Completion Summary\n\nDone\n";
+        let notes = parse_notes(body);
+        assert_eq!(
+            remove_note(body, &notes[1]),
+            "# T\n\n## Notes\n\n**a**\n\none\n\n## Completion Summary\n\nDone\n"
+        );
+    }
+}
@@ -1,9 +1,11 @@
 use serde::Deserialize;
 
+use crate::attachment::Attachment;
 use crate::error::Result;
 use crate::parser::parse_document_raw;
 use crate::types::{
-    CreatedAt, TicketId, TicketMetadata, TicketPriority, TicketSize, TicketStatus, TicketType,
+    CreatedAt, StatusChange, TicketDate, TicketId, TicketMetadata, TicketPriority,
+    TicketResolution, TicketSection, TicketSections, TicketSize, TicketStatus, TicketType,
 };
 
 /// Strict frontmatter struct for YAML deserialization with required fields.
@@ -16,12 +18,44 @@ struct TicketFrontmatter {
     status: Option<TicketStatus>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     deps: Vec<TicketId>,
+    #[serde(
+        rename = "external-deps",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    external_deps: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     links: Vec<TicketId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     created: Option<CreatedAt>,
     #[serde(rename = "completed-at", skip_serializing_if = "Option::is_none")]
     completed_at: Option<CreatedAt>,
+    #[serde(rename = "started-at", skip_serializing_if = "Option::is_none")]
+    started_at: Option<CreatedAt>,
+    #[serde(rename = "closed-at", skip_serializing_if = "Option::is_none")]
+    closed_at: Option<CreatedAt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolution: Option<TicketResolution>,
+    #[serde(rename = "reopen-count", skip_serializing_if = "Option::is_none")]
+    reopen_count: Option<u32>,
+    #[serde(
+        rename = "status-history",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    status_history: Vec<StatusChange>,
+    #[serde(rename = "snoozed-until", skip_serializing_if = "Option::is_none")]
+    snoozed_until: Option<CreatedAt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires: Option<TicketDate>,
+    #[serde(rename = "escalated-at", skip_serializing_if = "Option::is_none")]
+    escalated_at: Option<CreatedAt>,
+    #[serde(rename = "locked-by", skip_serializing_if = "Option::is_none")]
+    locked_by: Option<String>,
+    #[serde(rename = "locked-until", skip_serializing_if = "Option::is_none")]
+    locked_until: Option<CreatedAt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree: Option<String>,
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     ticket_type: Option<TicketType>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -33,6 +67,10 @@ struct TicketFrontmatter {
     #[serde(skip_serializing_if = "Option::is_none")]
     remote: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    assignee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    component: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     parent: Option<TicketId>,
     #[serde(rename = "spawned-from", skip_serializing_if = "Option::is_none")]
     spawned_from: Option<TicketId>,
@@ -44,6 +82,16 @@ struct TicketFrontmatter {
     triaged: Option<bool>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     labels: Vec<String>,
+    #[serde(
+        rename = "pull-requests",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pull_requests: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attachments: Vec<Attachment>,
 }
 
 /// Parse a ticket file's content into TicketMetadata.
@@ -71,24 +119,42 @@ fn ticket_metadata_from_document(frontmatter_raw: &str, body: &str) -> Result<Ti
         uuid: Some(frontmatter.uuid),
         status: frontmatter.status,
         deps: frontmatter.deps,
+        external_deps: frontmatter.external_deps,
         links: frontmatter.links,
         created: frontmatter.created,
         completed_at: frontmatter.completed_at,
+        started_at: frontmatter.started_at,
+        closed_at: frontmatter.closed_at,
+        resolution: frontmatter.resolution,
+        reopen_count: frontmatter.reopen_count,
+        status_history: frontmatter.status_history,
+        snoozed_until: frontmatter.snoozed_until,
+        expires: frontmatter.expires,
+        escalated_at: frontmatter.escalated_at,
+        locked_by: frontmatter.locked_by,
+        locked_until: frontmatter.locked_until,
+        worktree: frontmatter.worktree,
         ticket_type: frontmatter.ticket_type,
         priority: frontmatter.priority,
         size: frontmatter.size,
         external_ref: frontmatter.external_ref,
         remote: frontmatter.remote,
+        assignee: frontmatter.assignee,
+        component: frontmatter.component,
         parent: frontmatter.parent,
         spawned_from: frontmatter.spawned_from,
         spawn_context: frontmatter.spawn_context,
         depth: frontmatter.depth,
         triaged: frontmatter.triaged,
         labels: frontmatter.labels,
+        pull_requests: frontmatter.pull_requests,
+        docs: frontmatter.docs,
+        attachments: frontmatter.attachments,
         title: extract_title(body),
         completion_summary: extract_section(body, "completion summary")?,
         file_path: None,
         body: Some(body.to_string()),
+        sections: extract_sections(body)?,
     };
 
     Ok(metadata)
@@ -105,6 +171,43 @@ fn extract_title(body: &str) -> Option<String> {
 // Re-export the shared section extraction function from parser module
 pub use crate::parser::extract_section_from_body as extract_section;
 
+/// Extract one conventional section from the body.
+///
+/// The description is the `## Description` section when there is one,
+/// otherwise the text between the title and the first H2. Empty sections
+/// count as missing.
+pub fn extract_ticket_section(body: &str, section: TicketSection) -> Result<Option<String>> {
+    for heading in section.headings() {
+        if let Some(content) = extract_section(body, heading)? {
+            return Ok(Some(content).filter(|c| !c.is_empty()));
+        }
+    }
+    if section != TicketSection::Description {
+        return Ok(None);
+    }
+
+    let after_title = match crate::parser::TITLE_RE.find(body) {
+        Some(title) => &body[title.end()..],
+        None => body,
+    };
+    let preamble = match after_title.find("\n## ") {
+        Some(pos) => &after_title[..pos],
+        None => after_title,
+    }
+    .trim();
+    Ok((!preamble.is_empty()).then(|| preamble.to_string()))
+}
+
+/// Extract all conventional sections from the body.
+pub fn extract_sections(body: &str) -> Result<TicketSections> {
+    Ok(TicketSections {
+        description: extract_ticket_section(body, TicketSection::Description)?,
+        design: extract_ticket_section(body, TicketSection::Design)?,
+        acceptance: extract_ticket_section(body, TicketSection::Acceptance)?,
+        notes: extract_ticket_section(body, TicketSection::Notes)?,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,6 +239,41 @@ This is the description.
         assert!(metadata.deps.is_empty());
     }
 
+    #[test]
+    fn test_parse_sections() {
+        let content = r#"---
+id: test-1234
+uuid: 550e8400-e29b-41d4-a716-446655440000
+status: new
+---
+# Test Ticket
+
+What and why.
+
+## Design Notes
+
+Use a queue.
+
+## Acceptance Criteria
+
+- [ ] It works
+
+## Notes
+"#;
+
+        let sections = parse(content).unwrap().sections;
+        assert_eq!(sections.description.as_deref(), Some("What and why."));
+        assert_eq!(sections.design.as_deref(), Some("Use a queue."));
+        assert_eq!(sections.acceptance.as_deref(), Some("- [ ] It works"));
+        assert_eq!(sections.notes, None);
+
+        let explicit = "# Title\n\n## Description\n\nExplicit.\n\n## Design\n\nPlan.\n";
+        assert_eq!(
+            extract_ticket_section(explicit, TicketSection::Description).unwrap(),
+            Some("Explicit.".to_string())
+        );
+    }
+
     #[test]
     fn test_parse_with_deps() {
         let content = r#"---
@@ -159,6 +297,21 @@ links: ["link-1"]
         assert_eq!(metadata.links, vec![TicketId::new_unchecked("link-1")]);
     }
 
+    #[test]
+    fn test_parse_with_assignee() {
+        let content = r#"---
+id: test-9012
+uuid: 550e8400-e29b-41d4-a716-446655440002
+status: new
+assignee: alice
+---
+# Assigned Ticket
+"#;
+
+        let metadata = parse(content).unwrap();
+        assert_eq!(metadata.assignee.as_deref(), Some("alice"));
+    }
+
     #[test]
     fn test_parse_missing_frontmatter() {
         let content = "# No frontmatter\n\nJust content.";
@@ -0,0 +1,211 @@
+//! Unified TUI (`janus tui`)
+//!
+//! Hosts the issue browser, kanban board, plan HUD, and remote view as tabs
+//! of one application. The screens share the ticket store and its filesystem
+//! watcher, so an edit made on one tab is already there when switching to
+//! another.
+//!
+//! Plain `Tab` and the number keys already mean something inside the screens
+//! (pane focus, column toggles, the Local/Remote switch), so switching tabs
+//! uses `Alt`: `Alt+1`..`Alt+4` jump to a tab and `Alt+Left`/`Alt+Right`
+//! cycle through them. Screens ignore those keys while hosted here.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use iocraft::prelude::*;
+
+use crate::tui::board::KanbanBoard;
+use crate::tui::components::ClickableText;
+use crate::tui::plan_hud::PlanHud;
+use crate::tui::remote::RemoteTui;
+use crate::tui::theme::theme;
+use crate::tui::view::IssueBrowser;
+
+/// Rows taken by the tab bar, which hosted screens leave free
+pub const TAB_BAR_HEIGHT: u16 = 1;
+
+/// Whether the screens are running inside the tabbed application
+static TABBED: AtomicBool = AtomicBool::new(false);
+
+/// Mark the screens as hosted by the tabbed application.
+pub fn set_tabbed(tabbed: bool) {
+    TABBED.store(tabbed, Ordering::Relaxed);
+}
+
+/// Whether the screens are hosted by the tabbed application.
+pub fn is_tabbed() -> bool {
+    TABBED.load(Ordering::Relaxed)
+}
+
+/// A tab of the unified TUI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TuiTab {
+    #[default]
+    Issues,
+    Board,
+    Plan,
+    Remote,
+}
+
+impl TuiTab {
+    /// Tabs in display order
+    pub const ALL: [TuiTab; 4] = [TuiTab::Issues, TuiTab::Board, TuiTab::Plan, TuiTab::Remote];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            TuiTab::Issues => "Issues",
+            TuiTab::Board => "Board",
+            TuiTab::Plan => "Plan",
+            TuiTab::Remote => "Remote",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&tab| tab == self).unwrap_or(0)
+    }
+
+    pub fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// The tab a key switches to from `current`, if it is a tab-switching key
+pub fn tab_for_key(current: TuiTab, code: KeyCode, modifiers: KeyModifiers) -> Option<TuiTab> {
+    if !modifiers.contains(KeyModifiers::ALT) {
+        return None;
+    }
+    match code {
+        KeyCode::Char(c @ '1'..='9') => {
+            let index = c.to_digit(10)? as usize - 1;
+            TuiTab::ALL.get(index).copied()
+        }
+        KeyCode::Left => Some(current.prev()),
+        KeyCode::Right => Some(current.next()),
+        _ => None,
+    }
+}
+
+/// Whether a screen should leave this key to the tabbed application
+pub fn is_tab_switch_key(code: KeyCode, modifiers: KeyModifiers) -> bool {
+    is_tabbed() && tab_for_key(TuiTab::default(), code, modifiers).is_some()
+}
+
+/// Props for the TuiApp component
+#[derive(Default, Props)]
+pub struct TuiAppProps {
+    /// Plan shown on the Plan tab
+    pub plan_id: Option<String>,
+    /// Provider for the Remote tab (defaults to the configured one)
+    pub provider: Option<String>,
+}
+
+/// The unified TUI: a tab bar above the active screen
+#[component]
+pub fn TuiApp<'a>(props: &TuiAppProps, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
+    let theme = theme();
+    let (width, height) = hooks.use_terminal_size();
+    let mut active = hooks.use_state(TuiTab::default);
+
+    hooks.use_terminal_events(move |event| {
+        if let TerminalEvent::Key(KeyEvent {
+            code,
+            kind,
+            modifiers,
+            ..
+        }) = event
+            && kind != KeyEventKind::Release
+            && let Some(tab) = tab_for_key(active.get(), code, modifiers)
+        {
+            active.set(tab);
+        }
+    });
+
+    let current = active.get();
+    let screen = match current {
+        TuiTab::Issues => element!(IssueBrowser).into_any(),
+        TuiTab::Board => element!(KanbanBoard).into_any(),
+        TuiTab::Plan => match props.plan_id.clone() {
+            Some(plan_id) => element!(PlanHud(plan_id: plan_id, bell: false)).into_any(),
+            None => element! {
+                View(
+                    width: 100pct,
+                    height: 100pct,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                ) {
+                    Text(
+                        content: "No active plan. Run 'janus tui --plan <id>' to pick one.",
+                        color: theme.text_dimmed,
+                    )
+                }
+            }
+            .into_any(),
+        },
+        TuiTab::Remote => element!(RemoteTui(provider: props.provider.clone())).into_any(),
+    };
+
+    element! {
+        View(
+            width: width,
+            height: height,
+            flex_direction: FlexDirection::Column,
+            background_color: theme.background,
+        ) {
+            View(height: TAB_BAR_HEIGHT, flex_shrink: 0.0, padding_left: 1) {
+                #(TuiTab::ALL.iter().enumerate().map(|(i, &tab)| {
+                    let is_active = tab == current;
+                    element! {
+                        ClickableText(
+                            content: format!("[{}] {} ", i + 1, tab.title()),
+                            on_click: Some(Handler::from(move |_| active.set(tab))),
+                            color: if is_active { Some(Color::Cyan) } else { Some(theme.text_dimmed) },
+                            hover_color: Some(Color::Cyan),
+                            weight: if is_active { Some(Weight::Bold) } else { Some(Weight::Normal) },
+                            hover_weight: Some(Weight::Bold),
+                        )
+                    }
+                }))
+                View(flex_grow: 1.0)
+                Text(content: "Alt+1-4 switch tabs ", color: theme.text_dimmed)
+            }
+            View(flex_grow: 1.0, width: 100pct) {
+                #(Some(screen))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tab_for_key() {
+        let alt = KeyModifiers::ALT;
+        assert_eq!(
+            tab_for_key(TuiTab::Issues, KeyCode::Char('3'), alt),
+            Some(TuiTab::Plan)
+        );
+        assert_eq!(tab_for_key(TuiTab::Issues, KeyCode::Char('5'), alt), None);
+        assert_eq!(
+            tab_for_key(TuiTab::Issues, KeyCode::Left, alt),
+            Some(TuiTab::Remote)
+        );
+        assert_eq!(
+            tab_for_key(TuiTab::Remote, KeyCode::Right, alt),
+            Some(TuiTab::Issues)
+        );
+        assert_eq!(
+            tab_for_key(TuiTab::Issues, KeyCode::Char('2'), KeyModifiers::NONE),
+            None
+        );
+        assert_eq!(
+            tab_for_key(TuiTab::Issues, KeyCode::Tab, KeyModifiers::NONE),
+            None
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! Read-only card detail modal for the kanban board
+//!
+//! Pressing Enter on a card opens this modal over the board, showing the
+//! ticket's fields, dependencies, and body (including its notes). Pressing
+//! `e` in the modal switches to the full edit overlay.
+
+use std::collections::HashMap;
+
+use iocraft::prelude::*;
+
+use crate::tui::components::{
+    Clickable, ModalBorderColor, ModalContainer, ModalHeight, ModalOverlay, ModalWidth, TextViewer,
+};
+use crate::types::TicketMetadata;
+
+/// The ticket shown in the detail modal, with its rendered content
+#[derive(Debug, Clone, Default)]
+pub struct CardDetail {
+    pub ticket: TicketMetadata,
+    /// Markdown shown in the modal, built by [`detail_text`]
+    pub text: String,
+}
+
+impl CardDetail {
+    /// Build the detail view of `ticket`, looking up dependency statuses in
+    /// `tickets`. `body` is the ticket body without frontmatter and title.
+    pub fn new(ticket: TicketMetadata, body: &str, tickets: &[TicketMetadata]) -> Self {
+        let ticket_map: HashMap<&str, &TicketMetadata> = tickets
+            .iter()
+            .filter_map(|t| t.id.as_deref().map(|id| (id, t)))
+            .collect();
+        let text = detail_text(&ticket, body, &ticket_map);
+        CardDetail { ticket, text }
+    }
+
+    fn title(&self) -> String {
+        format!(
+            "{} {}",
+            self.ticket.id.as_deref().unwrap_or("?"),
+            self.ticket.title.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+/// Markdown for the detail modal: a field summary, dependencies, then the body
+fn detail_text(
+    ticket: &TicketMetadata,
+    body: &str,
+    ticket_map: &HashMap<&str, &TicketMetadata>,
+) -> String {
+    let mut fields = vec![
+        format!("**Status:** {}", ticket.status.unwrap_or_default()),
+        format!(
+            "**Priority:** P{}",
+            ticket.priority.map(|p| p.as_num()).unwrap_or(2)
+        ),
+    ];
+    if let Some(ticket_type) = ticket.ticket_type {
+        fields.push(format!("**Type:** {ticket_type}"));
+    }
+    if let Some(size) = ticket.size {
+        fields.push(format!("**Size:** {size}"));
+    }
+    if let Some(assignee) = &ticket.assignee {
+        fields.push(format!("**Assignee:** {assignee}"));
+    }
+    let mut text = fields.join("  ");
+    if !ticket.labels.is_empty() {
+        text.push_str(&format!("\n**Labels:** {}", ticket.labels.join(", ")));
+    }
+
+    if !ticket.deps.is_empty() || !ticket.external_deps.is_empty() {
+        text.push_str("\n\n## Dependencies\n");
+        for dep in &ticket.deps {
+            let line = match ticket_map.get(dep.as_ref()) {
+                Some(t) => format!(
+                    "- {dep} [{}] {}",
+                    t.status.unwrap_or_default(),
+                    t.title.as_deref().unwrap_or_default()
+                ),
+                None => format!("- {dep} [missing]"),
+            };
+            text.push_str(&line);
+            text.push('\n');
+        }
+        for url in &ticket.external_deps {
+            text.push_str(&format!("- {url} [external]\n"));
+        }
+    }
+
+    let body = body.trim();
+    if !body.is_empty() {
+        text.push_str("\n\n");
+        text.push_str(body);
+    }
+    text
+}
+
+/// Props for the CardDetailModal component
+#[derive(Default, Props)]
+pub struct CardDetailModalProps {
+    pub detail: CardDetail,
+    /// Current scroll offset (controlled by parent)
+    pub scroll_offset: usize,
+    /// Handler invoked when modal is closed via X button
+    pub on_close: Option<Handler<()>>,
+    /// Handler invoked when scroll up is requested (mouse wheel)
+    pub on_scroll_up: Option<Handler<()>>,
+    /// Handler invoked when scroll down is requested (mouse wheel)
+    pub on_scroll_down: Option<Handler<()>>,
+}
+
+/// Read-only modal showing a card's details over the board
+#[component]
+pub fn CardDetailModal<'a>(props: &CardDetailModalProps) -> impl Into<AnyElement<'a>> {
+    let detail = &props.detail;
+
+    element! {
+        ModalOverlay() {
+            ModalContainer(
+                width: Some(ModalWidth::Percent(70)),
+                height: Some(ModalHeight::Percent(80)),
+                border_color: Some(ModalBorderColor::Info),
+                title: Some(detail.title()),
+                footer_text: Some("j/k scroll · e edit · Esc close".to_string()),
+                on_close: props.on_close.clone(),
+            ) {
+                View(
+                    flex_grow: 1.0,
+                    width: 100pct,
+                    overflow: Overflow::Hidden,
+                ) {
+                    Clickable(
+                        on_scroll_up: props.on_scroll_up.clone(),
+                        on_scroll_down: props.on_scroll_down.clone(),
+                    ) {
+                        TextViewer(
+                            text: detail.text.clone(),
+                            scroll_offset: props.scroll_offset,
+                            has_focus: true,
+                            markdown: true,
+                        )
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TicketId, TicketPriority, TicketStatus};
+
+    #[test]
+    fn test_detail_text() {
+        let dep = TicketMetadata {
+            id: Some(TicketId::new_unchecked("j-dep1")),
+            status: Some(TicketStatus::InProgress),
+            title: Some("Set up OAuth app".to_string()),
+            ..Default::default()
+        };
+        let ticket = TicketMetadata {
+            id: Some(TicketId::new_unchecked("j-a1b2")),
+            title: Some("Add login".to_string()),
+            status: Some(TicketStatus::New),
+            priority: Some(TicketPriority::P1),
+            assignee: Some("sam".to_string()),
+            deps: vec![
+                TicketId::new_unchecked("j-dep1"),
+                TicketId::new_unchecked("j-gone"),
+            ],
+            ..Default::default()
+        };
+
+        let detail = CardDetail::new(
+            ticket,
+            "Support SSO.\n\n## Notes\n\n**2024-06-01T12:00:00Z**\n\nStarted.",
+            &[dep],
+        );
+        assert_eq!(detail.title(), "j-a1b2 Add login");
+        assert!(detail.text.starts_with("**Status:** new  **Priority:** P1"));
+        assert!(detail.text.contains("**Assignee:** sam"));
+        assert!(
+            detail
+                .text
+                .contains("- j-dep1 [in_progress] Set up OAuth app")
+        );
+        assert!(detail.text.contains("- j-gone [missing]"));
+        assert!(detail.text.ends_with("Started."));
+    }
+}
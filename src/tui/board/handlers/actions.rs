@@ -5,7 +5,9 @@ use std::fs;
 use clipboard_rs::Clipboard;
 use iocraft::prelude::{KeyCode, KeyModifiers};
 
+use crate::tui::board::detail::CardDetail;
 use crate::tui::edit::extract_body_for_edit;
+use crate::types::TicketMetadata;
 
 use super::HandleResult;
 use super::context::BoardHandlerContext;
@@ -29,10 +31,14 @@ pub fn handle(
             ctx.search_focused.set(true);
             HandleResult::Handled
         }
-        KeyCode::Char('e') | KeyCode::Enter => {
+        KeyCode::Char('e') => {
             handle_edit_ticket(ctx);
             HandleResult::Handled
         }
+        KeyCode::Enter => {
+            handle_show_detail(ctx);
+            HandleResult::Handled
+        }
         KeyCode::Char('n') => {
             handle_create_new(ctx);
             HandleResult::Handled
@@ -49,23 +55,47 @@ pub fn handle(
     }
 }
 
-/// Edit the selected ticket - reads body content synchronously
+/// Edit the selected ticket
 fn handle_edit_ticket(ctx: &mut BoardHandlerContext<'_>) {
     let col = ctx.current_column.get();
     let row = ctx.current_row.get();
 
-    if let Some(ticket) = ctx.get_ticket_at(col, row)
-        && let Some(ref file_path) = ticket.file_path
-    {
-        // Read body content synchronously from file
-        let body = fs::read_to_string(file_path)
-            .ok()
-            .map(|content| extract_body_for_edit(&content))
-            .unwrap_or_default();
-
-        // Set edit state directly (synchronous)
-        ctx.edit_state().start_edit(ticket, body);
+    if let Some(ticket) = ctx.get_ticket_at(col, row) {
+        start_edit(ctx, ticket);
+    }
+}
+
+/// Open the edit overlay for a ticket - reads body content synchronously
+pub(super) fn start_edit(ctx: &mut BoardHandlerContext<'_>, ticket: TicketMetadata) {
+    if ticket.file_path.is_none() {
+        return;
     }
+    let body = read_body(&ticket);
+    // Set edit state directly (synchronous)
+    ctx.edit_state().start_edit(ticket, body);
+}
+
+/// Open the read-only detail modal for the selected ticket
+fn handle_show_detail(ctx: &mut BoardHandlerContext<'_>) {
+    let col = ctx.current_column.get();
+    let row = ctx.current_row.get();
+
+    if let Some(ticket) = ctx.get_ticket_at(col, row) {
+        let body = read_body(&ticket);
+        let detail = CardDetail::new(ticket, &body, &ctx.all_tickets.read());
+        ctx.detail_scroll.set(0);
+        ctx.detail.set(Some(detail));
+    }
+}
+
+/// Read a ticket's body (without frontmatter and title) from its file
+fn read_body(ticket: &TicketMetadata) -> String {
+    ticket
+        .file_path
+        .as_ref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|content| extract_body_for_edit(&content))
+        .unwrap_or_default()
 }
 
 /// Create a new ticket
@@ -1,8 +1,9 @@
-//! Column toggle and status movement handlers
+//! Column toggle, status movement, and column-wide operation handlers
 
 use iocraft::prelude::{KeyCode, State};
 
-use crate::tui::board::model::{COLUMN_COUNT, COLUMNS};
+use crate::tui::board::model::{COLUMN_COUNT, COLUMNS, ColumnOp};
+use crate::types::TicketStatus;
 
 use super::HandleResult;
 use super::context::BoardHandlerContext;
@@ -44,6 +45,55 @@ pub fn handle_status_move(ctx: &mut BoardHandlerContext<'_>, code: KeyCode) -> H
     }
 }
 
+/// Handle column-wide operation keys (A/P/M)
+///
+/// Archiving asks for confirmation first; see [`handle_confirm_archive`].
+pub fn handle_column_op(ctx: &mut BoardHandlerContext<'_>, code: KeyCode) -> HandleResult {
+    let col = ctx.current_column.get();
+    match code {
+        KeyCode::Char('A') => {
+            let has_unarchived = ctx
+                .get_column_tickets(col)
+                .iter()
+                .any(|t| t.status != Some(TicketStatus::Archived));
+            if has_unarchived {
+                ctx.confirm_archive.set(Some(col));
+            }
+            HandleResult::Handled
+        }
+        KeyCode::Char('P') => {
+            run_column_op(ctx, ColumnOp::RaisePriority, col);
+            HandleResult::Handled
+        }
+        KeyCode::Char('M') => {
+            run_column_op(ctx, ColumnOp::Export, col);
+            HandleResult::Handled
+        }
+        _ => HandleResult::NotHandled,
+    }
+}
+
+/// Handle keys while the archive confirmation is open: `y`/`Y` archives the
+/// column, any other key cancels
+pub fn handle_confirm_archive(ctx: &mut BoardHandlerContext<'_>, code: KeyCode) -> HandleResult {
+    let Some(col) = ctx.confirm_archive.get() else {
+        return HandleResult::NotHandled;
+    };
+    ctx.confirm_archive.set(None);
+    if matches!(code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+        run_column_op(ctx, ColumnOp::Archive, col);
+    }
+    HandleResult::Handled
+}
+
+/// Run a bulk operation on every ticket in a column - calls async handler directly
+fn run_column_op(ctx: &mut BoardHandlerContext<'_>, op: ColumnOp, col: usize) {
+    let tickets = ctx.get_column_tickets(col);
+    if !tickets.is_empty() {
+        ctx.handlers.column_op.clone()((op, col, tickets));
+    }
+}
+
 /// Move ticket to next status (right) - calls async handler directly
 fn handle_move_right(ctx: &mut BoardHandlerContext<'_>) {
     let col = ctx.current_column.get();
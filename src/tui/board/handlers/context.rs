@@ -7,7 +7,8 @@ use std::path::PathBuf;
 
 use iocraft::prelude::{Handler, State};
 
-use crate::tui::board::model::{COLUMN_COUNT, COLUMNS};
+use crate::tui::board::detail::CardDetail;
+use crate::tui::board::model::{COLUMN_COUNT, COLUMNS, ColumnOp, PlanScope, retain_in_scope};
 use crate::tui::edit::EditResult;
 use crate::tui::edit_state::{EditFormState, EditMode};
 use crate::tui::search::{FilteredTicket, filter_tickets};
@@ -28,6 +29,8 @@ pub struct FilteredCache {
 /// Async handlers for board operations
 pub struct BoardAsyncHandlers<'a> {
     pub update_status: &'a Handler<(String, TicketStatus)>,
+    /// Apply a bulk operation to the given tickets of a column
+    pub column_op: &'a Handler<(ColumnOp, usize, Vec<TicketMetadata>)>,
 }
 
 /// Context struct holding all mutable state for event handlers
@@ -44,10 +47,17 @@ pub struct BoardHandlerContext<'a> {
     pub column_height: usize,
     pub edit_mode: &'a mut State<EditMode>,
     pub edit_result: &'a mut State<EditResult>,
+    /// Ticket shown in the read-only detail modal, if open
+    pub detail: &'a mut State<Option<CardDetail>>,
+    pub detail_scroll: &'a mut State<usize>,
+    /// Column waiting for confirmation before all its cards are archived
+    pub confirm_archive: &'a mut State<Option<usize>>,
     pub all_tickets: &'a State<Vec<TicketMetadata>>,
     /// Generation counter that increments whenever all_tickets is updated.
     /// Used to invalidate the handler cache when tickets change.
     pub ticket_generation: &'a State<u64>,
+    /// Plan the board is restricted to, if any
+    pub plan_scope: Option<&'a PlanScope>,
     pub handlers: BoardAsyncHandlers<'a>,
     /// Cached filtered tickets to avoid repeated filtering on every keypress
     pub cache: &'a mut State<Option<FilteredCache>>,
@@ -81,6 +91,17 @@ impl<'a> BoardHandlerContext<'a> {
         column_tickets.get(row).map(|ft| ft.ticket.as_ref().clone())
     }
 
+    /// Get all tickets shown in a column, using cache
+    pub fn get_column_tickets(&mut self, column: usize) -> Vec<TicketMetadata> {
+        if column >= COLUMNS.len() {
+            return Vec::new();
+        }
+        self.get_cached_column_tickets(column)
+            .into_iter()
+            .map(|ft| ft.ticket.as_ref().clone())
+            .collect()
+    }
+
     /// Get cached filtered tickets for a column, computing if necessary
     fn get_cached_column_tickets(&mut self, column: usize) -> Vec<FilteredTicket> {
         let current_query = self.search_query.to_string();
@@ -97,23 +118,28 @@ impl<'a> BoardHandlerContext<'a> {
         if !cache_valid {
             // Use orchestrator results if available (includes merged fuzzy + semantic results)
             // Otherwise fall back to filtering directly
-            let filtered = if let Some(results) = self.search_orchestrator.get_results() {
+            let mut filtered = if let Some(results) = self.search_orchestrator.get_results() {
                 results
             } else if current_query.is_empty() {
+                // Matches `compute_filtered_tickets`: snoozed tickets stay hidden
+                let now = jiff::Timestamp::now();
                 self.all_tickets
                     .read()
                     .iter()
+                    .filter(|t| !t.is_snoozed_at(now))
                     .map(|t| FilteredTicket {
                         ticket: std::sync::Arc::new(t.clone()),
                         score: 0,
                         title_indices: vec![],
                         is_semantic: false,
+                        similarity: None,
                     })
                     .collect()
             } else {
                 let tickets_read = self.all_tickets.read();
                 filter_tickets(&tickets_read, &current_query)
             };
+            retain_in_scope(&mut filtered, self.plan_scope);
 
             let column_tickets: Vec<Vec<FilteredTicket>> = COLUMNS
                 .iter()
@@ -0,0 +1,60 @@
+//! Card detail modal handler (Enter on a card)
+
+use iocraft::prelude::{KeyCode, KeyModifiers};
+
+use super::HandleResult;
+use super::actions::start_edit;
+use super::context::BoardHandlerContext;
+
+/// Lines scrolled by Page Up/Down in the detail modal
+const PAGE_SCROLL: usize = 10;
+
+/// Handle events while the detail modal is open. The modal captures all
+/// input, so every key is reported as handled.
+pub fn handle(
+    ctx: &mut BoardHandlerContext<'_>,
+    code: KeyCode,
+    modifiers: KeyModifiers,
+) -> HandleResult {
+    match code {
+        KeyCode::Char('q') if modifiers.contains(KeyModifiers::CONTROL) => {
+            ctx.should_exit.set(true);
+        }
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => close(ctx),
+        KeyCode::Char('j') | KeyCode::Down => scroll_by(ctx, 1),
+        KeyCode::Char('k') | KeyCode::Up => ctx
+            .detail_scroll
+            .set(ctx.detail_scroll.get().saturating_sub(1)),
+        KeyCode::PageDown => scroll_by(ctx, PAGE_SCROLL),
+        KeyCode::PageUp => ctx
+            .detail_scroll
+            .set(ctx.detail_scroll.get().saturating_sub(PAGE_SCROLL)),
+        KeyCode::Char('g') => ctx.detail_scroll.set(0),
+        KeyCode::Char('e') => {
+            let ticket = ctx.detail.read().as_ref().map(|d| d.ticket.clone());
+            close(ctx);
+            if let Some(ticket) = ticket {
+                start_edit(ctx, ticket);
+            }
+        }
+        _ => {}
+    }
+    HandleResult::Handled
+}
+
+fn close(ctx: &mut BoardHandlerContext<'_>) {
+    ctx.detail.set(None);
+    ctx.detail_scroll.set(0);
+}
+
+/// Scroll down, stopping at the last line of the detail text
+fn scroll_by(ctx: &mut BoardHandlerContext<'_>, lines: usize) {
+    let max = ctx
+        .detail
+        .read()
+        .as_ref()
+        .map(|d| d.text.lines().count().saturating_sub(1))
+        .unwrap_or(0);
+    ctx.detail_scroll
+        .set((ctx.detail_scroll.get() + lines).min(max));
+}
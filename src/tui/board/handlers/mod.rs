@@ -6,6 +6,7 @@
 mod actions;
 mod column;
 mod context;
+mod detail;
 mod navigation;
 mod search;
 mod types;
@@ -19,7 +20,14 @@ use iocraft::prelude::{KeyCode, KeyModifiers};
 
 /// Main event dispatcher that routes events to the appropriate handler
 pub fn handle_key_event(ctx: &mut BoardHandlerContext<'_>, code: KeyCode, modifiers: KeyModifiers) {
-    // 1. Search mode has highest priority - captures all input
+    // 1. The detail modal, archive confirmation, and search mode capture all input
+    if ctx.detail.read().is_some() {
+        detail::handle(ctx, code, modifiers);
+        return;
+    }
+    if column::handle_confirm_archive(ctx, code).is_handled() {
+        return;
+    }
     if ctx.search_focused.get() && search::handle(ctx, code, modifiers).is_handled() {
         return;
     }
@@ -39,7 +47,12 @@ pub fn handle_key_event(ctx: &mut BoardHandlerContext<'_>, code: KeyCode, modifi
         return;
     }
 
-    // 5. Actions (e, n, q, /)
+    // 5. Column-wide operations (A/P/M)
+    if column::handle_column_op(ctx, code).is_handled() {
+        return;
+    }
+
+    // 6. Actions (e, Enter, n, q, /)
     actions::handle(ctx, code, modifiers);
 }
 
@@ -80,11 +93,17 @@ pub fn key_to_action(
         KeyCode::Char('s') => Some(BoardAction::MoveTicketStatusRight),
         KeyCode::Char('S') => Some(BoardAction::MoveTicketStatusLeft),
 
+        // Column operations
+        KeyCode::Char('A') => Some(BoardAction::ArchiveColumn),
+        KeyCode::Char('P') => Some(BoardAction::RaiseColumnPriority),
+        KeyCode::Char('M') => Some(BoardAction::ExportColumn),
+
         // Actions
         KeyCode::Char('q') if modifiers.contains(KeyModifiers::CONTROL) => Some(BoardAction::Quit),
         KeyCode::Esc => Some(BoardAction::Quit),
         KeyCode::Char('/') => Some(BoardAction::FocusSearch),
-        KeyCode::Char('e') | KeyCode::Enter => Some(BoardAction::EditSelected),
+        KeyCode::Char('e') => Some(BoardAction::EditSelected),
+        KeyCode::Enter => Some(BoardAction::ShowDetail),
         KeyCode::Char('E') => Some(BoardAction::OpenExternalEditor),
         KeyCode::Char('n') => Some(BoardAction::CreateNew),
         KeyCode::Char('y') => Some(BoardAction::CopyTicketId),
@@ -170,6 +189,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_key_to_action_column_operations() {
+        assert_eq!(
+            key_to_action(KeyCode::Char('A'), KeyModifiers::NONE, false),
+            Some(BoardAction::ArchiveColumn)
+        );
+        assert_eq!(
+            key_to_action(KeyCode::Char('P'), KeyModifiers::NONE, false),
+            Some(BoardAction::RaiseColumnPriority)
+        );
+        assert_eq!(
+            key_to_action(KeyCode::Char('M'), KeyModifiers::NONE, false),
+            Some(BoardAction::ExportColumn)
+        );
+    }
+
     #[test]
     fn test_key_to_action_app_actions() {
         // Ctrl+Q and Esc both quit
@@ -196,7 +231,7 @@ mod tests {
         );
         assert_eq!(
             key_to_action(KeyCode::Enter, KeyModifiers::NONE, false),
-            Some(BoardAction::EditSelected)
+            Some(BoardAction::ShowDetail)
         );
         assert_eq!(
             key_to_action(KeyCode::Char('n'), KeyModifiers::NONE, false),
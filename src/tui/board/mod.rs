@@ -3,35 +3,47 @@
 //! Provides an interactive TUI for viewing and managing tickets organized
 //! by status in a kanban-style board layout with columns for each status.
 
+pub mod detail;
 pub mod handlers;
 pub mod model;
 
 use std::path::PathBuf;
 
+use clipboard_rs::Clipboard;
 use iocraft::prelude::*;
 
-use crate::ticket::Ticket;
+use crate::config::{Config, ReadyRequirements, SlaConfig};
+use crate::sla::{evaluate_ticket, worst_state};
 use crate::tui::components::{
-    Clickable, ClickableText, EmptyState, EmptyStateKind, InlineSearchBox, TicketCard, Toast,
-    board_shortcuts, compute_empty_state, edit_shortcuts, empty_shortcuts,
+    Clickable, ClickableText, EmptyState, EmptyStateKind, InlineSearchBox, TicketCard,
+    TicketCounts, Toast, board_shortcuts, card_detail_shortcuts, compute_empty_state,
+    confirm_dialog_shortcuts, edit_shortcuts, empty_shortcuts,
 };
 use crate::tui::edit::{EditFormOverlay, EditResult};
 use crate::tui::edit_state::{EditFormState, EditMode};
 use crate::tui::hooks::use_ticket_loader;
+use crate::tui::remote::ConfirmDialog;
 use crate::tui::repository::InitResult;
-use crate::tui::screen_base::{ScreenLayout, should_process_key_event};
+use crate::tui::screen_base::{ScreenLayout, should_process_key_event, use_screen_size};
 use crate::tui::search::FilteredTicket;
 use crate::tui::search_orchestrator::{SearchState, compute_filtered_tickets};
-use crate::tui::services::ExternalEditor;
+use crate::tui::services::{ExternalEditor, TicketService};
 use crate::tui::theme::theme;
 use crate::types::{TicketMetadata, TicketStatus};
 
+use detail::{CardDetail, CardDetailModal};
 use handlers::{BoardAsyncHandlers, BoardHandlerContext, FilteredCache};
-use model::{COLUMN_COUNT, COLUMN_KEYS, COLUMN_NAMES, COLUMNS, DEFAULT_VISIBLE_COLUMNS};
+use model::{
+    COLUMN_COUNT, COLUMN_KEYS, COLUMN_NAMES, COLUMNS, ColumnOp, DEFAULT_VISIBLE_COLUMNS, PlanScope,
+    card_staleness, column_markdown, retain_in_scope,
+};
 
 /// Props for the KanbanBoard component
 #[derive(Default, Props)]
-pub struct KanbanBoardProps {}
+pub struct KanbanBoardProps {
+    /// Restrict the board to this plan's tickets (`janus board --plan`)
+    pub plan_id: Option<String>,
+}
 
 /// Get tickets for a specific column from the filtered list
 fn get_column_tickets(filtered: &[FilteredTicket], status: TicketStatus) -> Vec<FilteredTicket> {
@@ -42,6 +54,57 @@ fn get_column_tickets(filtered: &[FilteredTicket], status: TicketStatus) -> Vec<
         .collect()
 }
 
+/// Apply a column-wide operation and describe the outcome as a toast
+async fn run_column_op(op: ColumnOp, column: usize, tickets: Vec<TicketMetadata>) -> Toast {
+    let name = COLUMN_NAMES[column];
+    let verb = match op {
+        ColumnOp::Export => {
+            let markdown = column_markdown(column, &tickets);
+            return match clipboard_rs::ClipboardContext::new()
+                .and_then(|ctx| ctx.set_text(markdown))
+            {
+                Ok(()) => Toast::success(format!(
+                    "Copied {} {name} tickets as markdown",
+                    tickets.len()
+                )),
+                Err(_) => Toast::error("Clipboard unavailable".to_string()),
+            };
+        }
+        ColumnOp::Archive => "Archived",
+        ColumnOp::RaisePriority => "Raised priority of",
+    };
+
+    let mut changed = 0;
+    let mut errors = Vec::new();
+    for ticket in &tickets {
+        let Some(id) = ticket.id.as_deref() else {
+            continue;
+        };
+        let result = match op {
+            ColumnOp::Archive if ticket.status == Some(TicketStatus::Archived) => Ok(false),
+            ColumnOp::Archive => TicketService::set_status(id, TicketStatus::Archived)
+                .await
+                .map(|()| true),
+            _ => TicketService::raise_priority(id)
+                .await
+                .map(|raised| raised.is_some()),
+        };
+        match result {
+            Ok(true) => changed += 1,
+            Ok(false) => {}
+            Err(e) => errors.push(format!("{id}: {e}")),
+        }
+    }
+
+    match errors.first() {
+        None => Toast::success(format!("{verb} {changed} {name} tickets")),
+        Some(first) => Toast::error(format!(
+            "{verb} {changed} {name} tickets, {} failed ({first})",
+            errors.len()
+        )),
+    }
+}
+
 /// Main kanban board component
 ///
 /// Layout:
@@ -62,8 +125,8 @@ fn get_column_tickets(filtered: &[FilteredTicket], status: TicketStatus) -> Vec<
 /// +------------------------------------------+
 /// ```
 #[component]
-pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
-    let (width, height) = hooks.use_terminal_size();
+pub fn KanbanBoard<'a>(props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
+    let (width, height) = use_screen_size(&mut hooks);
     let mut system = hooks.use_context_mut::<SystemContext>();
 
     // State management - initialize with empty state, load asynchronously
@@ -86,6 +149,13 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
     let mut edit_mode: State<EditMode> = hooks.use_state(EditMode::default);
     let mut edit_result: State<EditResult> = hooks.use_state(EditResult::default);
 
+    // Read-only card detail modal (Enter on a card)
+    let mut detail: State<Option<CardDetail>> = hooks.use_state(|| None);
+    let mut detail_scroll = hooks.use_state(|| 0usize);
+
+    // Column waiting for confirmation before its cards are archived (`A`)
+    let mut confirm_archive: State<Option<usize>> = hooks.use_state(|| None);
+
     // Async load handler with minimum 100ms display time to prevent UI flicker
     // NOTE: This must be created before update_status_handler so it can be cloned into it
     let load_handler: Handler<()> = hooks.use_async_handler(use_ticket_loader(
@@ -105,38 +175,51 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
             let mut all_tickets_setter = all_tickets_setter;
             let mut generation = generation;
             async move {
-                match Ticket::find(&ticket_id).await {
-                    Ok(ticket) => match ticket.update_field("status", &status.to_string()) {
-                        Ok(_) => {
-                            toast_setter.set(Some(Toast::success(format!(
-                                "Updated {ticket_id} to {status}"
-                            ))));
-                            // Refresh the mutated ticket in the store, then update in-place
-                            crate::tui::repository::TicketRepository::refresh_ticket_in_store(
-                                &ticket_id,
+                match TicketService::set_status(&ticket_id, status).await {
+                    Ok(()) => {
+                        toast_setter.set(Some(Toast::success(format!(
+                            "Updated {ticket_id} to {status}"
+                        ))));
+                        // Refresh the mutated ticket in the store, then update in-place
+                        crate::tui::repository::TicketRepository::refresh_ticket_in_store(
+                            &ticket_id,
+                        )
+                        .await;
+                        let current = all_tickets_setter.read().clone();
+                        let tickets =
+                            crate::tui::repository::TicketRepository::refresh_single_ticket(
+                                current, &ticket_id,
                             )
                             .await;
-                            let current = all_tickets_setter.read().clone();
-                            let tickets =
-                                crate::tui::repository::TicketRepository::refresh_single_ticket(
-                                    current, &ticket_id,
-                                )
-                                .await;
-                            all_tickets_setter.set(tickets);
-                            generation.set(generation.get().wrapping_add(1));
-                        }
-                        Err(e) => {
-                            toast_setter.set(Some(Toast::error(format!("Failed to update: {e}"))));
-                        }
-                    },
+                        all_tickets_setter.set(tickets);
+                        generation.set(generation.get().wrapping_add(1));
+                    }
                     Err(e) => {
-                        toast_setter.set(Some(Toast::error(format!("Ticket not found: {e}"))));
+                        toast_setter.set(Some(Toast::error(format!("Failed to update: {e}"))));
                     }
                 }
             }
         }
     });
 
+    // Async handler for column-wide operations (A/P/M). Archive and priority
+    // changes touch many tickets, so they trigger a full reload afterwards.
+    let column_op_handler: Handler<(ColumnOp, usize, Vec<TicketMetadata>)> = hooks
+        .use_async_handler({
+            let toast_setter = toast;
+            let reload_setter = needs_reload;
+            move |(op, column, tickets): (ColumnOp, usize, Vec<TicketMetadata>)| {
+                let mut toast_setter = toast_setter;
+                let mut reload_setter = reload_setter;
+                async move {
+                    toast_setter.set(Some(run_column_op(op, column, tickets).await));
+                    if op != ColumnOp::Export {
+                        reload_setter.set(true);
+                    }
+                }
+            }
+        });
+
     // Trigger initial load on mount
     let mut load_started = hooks.use_state(|| false);
     if !load_started.get() {
@@ -155,6 +238,12 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
     // Cache for filtered tickets to avoid recomputing on every keystroke
     let mut cache: State<Option<FilteredCache>> = hooks.use_state(|| None);
 
+    // SLA rules for card badges, read once when the board opens
+    let sla_config: State<SlaConfig> =
+        hooks.use_state(|| Config::load().map(|c| c.sla).unwrap_or_default());
+    let ready_require: State<ReadyRequirements> =
+        hooks.use_state(|| Config::load().map(|c| c.ready.require).unwrap_or_default());
+
     // Navigation state
     let mut current_column = hooks.use_state(|| 0usize);
     let mut current_row = hooks.use_state(|| 0usize);
@@ -244,13 +333,25 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
     search_state.check_pending(query_str.clone());
     search_state.clear_if_empty(&query_str);
 
-    let filtered = compute_filtered_tickets(&all_tickets.read(), &search_state, &query_str);
+    // Plan scope is read from the store on every render, so tickets added to or
+    // moved within the plan show up as soon as the watcher reloads
+    let plan_scope: Option<PlanScope> = props.plan_id.as_deref().map(|plan_id| {
+        crate::store::get_store()
+            .and_then(|store| store.get_plan(plan_id))
+            .map(|plan| PlanScope::from_plan(&plan))
+            .unwrap_or_default()
+    });
+
+    let mut filtered = compute_filtered_tickets(&all_tickets.read(), &search_state, &query_str);
+    retain_in_scope(&mut filtered, plan_scope.as_ref());
 
     // Group filtered tickets by status for rendering
     let tickets_by_status: Vec<Vec<FilteredTicket>> = COLUMNS
         .iter()
         .map(|status| get_column_tickets(&filtered, *status))
         .collect();
+    let sla_config = sla_config.read().clone();
+    let sla_now = jiff::Timestamp::now();
 
     // Get visible column indices
     let visible_indices: Vec<usize> = visible_columns
@@ -284,6 +385,8 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
 
     // Clone handler for use in event handler closure
     let update_status_handler_for_events = update_status_handler.clone();
+    let column_op_handler_for_events = column_op_handler.clone();
+    let plan_scope_for_events = plan_scope.clone();
 
     // Keyboard event handling
     hooks.use_terminal_events({
@@ -298,7 +401,7 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
                     kind,
                     modifiers,
                     ..
-                }) if should_process_key_event(kind) => {
+                }) if should_process_key_event(kind, code, modifiers) => {
                     let mut ctx = BoardHandlerContext {
                         search_query: &mut search_query,
                         search_focused: &mut search_focused,
@@ -312,10 +415,15 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
                         column_height: cards_per_column,
                         edit_mode: &mut edit_mode,
                         edit_result: &mut edit_result,
+                        detail: &mut detail,
+                        detail_scroll: &mut detail_scroll,
+                        confirm_archive: &mut confirm_archive,
                         all_tickets: &all_tickets,
                         ticket_generation: &ticket_generation,
+                        plan_scope: plan_scope_for_events.as_ref(),
                         handlers: BoardAsyncHandlers {
                             update_status: &update_status_handler_for_events,
+                            column_op: &column_op_handler_for_events,
                         },
                         cache: &mut cache,
                         pending_external_edit: &mut pending_external_edit,
@@ -334,9 +442,15 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
     }
 
     let total_tickets = filtered.len();
-    let tickets_ref_for_count = all_tickets.read();
-    let all_ticket_count = tickets_ref_for_count.len();
-    drop(tickets_ref_for_count);
+    // Header counts are recomputed from the ticket list on every render, so
+    // they stay current after each mutation and watcher reload
+    let counts = TicketCounts::compute(
+        &all_tickets.read(),
+        total_tickets,
+        &ready_require.get(),
+        |t| plan_scope.as_ref().is_none_or(|scope| scope.contains(t)),
+    );
+    let all_ticket_count = counts.total;
 
     let theme = theme();
 
@@ -364,8 +478,18 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
 
     // Build column toggle indicators using ClickableText components
     let visible_cols = visible_columns.get();
-    let column_toggles_elements: Vec<AnyElement<'static>> = (0..COLUMN_COUNT)
-        .map(|i| {
+    let plan_label = props
+        .plan_id
+        .as_ref()
+        .map(|plan_id| -> AnyElement<'static> {
+            element! {
+                Text(content: format!("{plan_id} "), color: theme.id_color, weight: Weight::Bold)
+            }
+            .into()
+        });
+    let column_toggles_elements: Vec<AnyElement<'static>> = plan_label
+        .into_iter()
+        .chain((0..COLUMN_COUNT).map(|i| {
             let is_visible = visible_cols[i];
             let key = COLUMN_KEYS[i];
             let on_click = column_toggle_handlers[i].clone();
@@ -385,7 +509,7 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
                 )
             }
             .into()
-        })
+        }))
         .collect();
 
     // Get editing state for rendering using shared EditFormState
@@ -425,6 +549,10 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
     // Determine shortcuts to show
     let shortcuts = if is_editing {
         edit_shortcuts()
+    } else if detail.read().is_some() {
+        card_detail_shortcuts()
+    } else if confirm_archive.get().is_some() {
+        confirm_dialog_shortcuts()
     } else if show_full_empty_state {
         empty_shortcuts()
     } else {
@@ -525,12 +653,57 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
         })
         .collect();
 
+    // Detail modal close and mouse wheel handlers
+    let detail_close_handler: Handler<()> = hooks.use_async_handler({
+        move |()| {
+            let mut detail = detail;
+            let mut detail_scroll = detail_scroll;
+            async move {
+                detail.set(None);
+                detail_scroll.set(0);
+            }
+        }
+    });
+    let detail_scroll_up_handler: Handler<()> = hooks.use_async_handler({
+        move |()| {
+            let mut detail_scroll = detail_scroll;
+            async move {
+                detail_scroll.set(detail_scroll.get().saturating_sub(3));
+            }
+        }
+    });
+    let detail_scroll_down_handler: Handler<()> = hooks.use_async_handler({
+        move |()| {
+            let mut detail_scroll = detail_scroll;
+            async move {
+                let max = detail
+                    .read()
+                    .as_ref()
+                    .map(|d| d.text.lines().count().saturating_sub(1))
+                    .unwrap_or(0);
+                detail_scroll.set((detail_scroll.get() + 3).min(max));
+            }
+        }
+    });
+    let archive_message = confirm_archive.get().map(|col| {
+        let count = tickets_by_status[col]
+            .iter()
+            .filter(|ft| ft.ticket.status != Some(TicketStatus::Archived))
+            .count();
+        format!("Archive all {count} tickets in {}?", COLUMN_NAMES[col])
+    });
+    let open_detail = if is_editing {
+        None
+    } else {
+        detail.read().clone()
+    };
+
     element! {
         ScreenLayout(
             width: width,
             height: height,
             header_title: Some("Janus - Board"),
-            header_ticket_count: Some(total_tickets),
+            header_counts: Some(counts),
             header_extra: Some(column_toggles_elements),
             shortcuts: shortcuts,
             toast: toast.read().clone(),
@@ -705,6 +878,9 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
                                                                 width: Some(card_width),
                                                                 on_click: Some(card_click_handlers[col_idx].clone()),
                                                                 row_idx: row_idx,
+                                                                sla: worst_state(&evaluate_ticket(&ft.ticket, &sla_config, sla_now)),
+                                                                phase: plan_scope.as_ref().and_then(|scope| scope.phase_of(&ft.ticket)),
+                                                                staleness: card_staleness(&ft.ticket, sla_now),
                                                             )
                                                         }
                                                     }))
@@ -741,6 +917,22 @@ pub fn KanbanBoard<'a>(_props: &KanbanBoardProps, mut hooks: Hooks) -> impl Into
                 })
             })
 
+            // Read-only card detail modal
+            #(open_detail.map(|card| element! {
+                CardDetailModal(
+                    detail: card,
+                    scroll_offset: detail_scroll.get(),
+                    on_close: Some(detail_close_handler.clone()),
+                    on_scroll_up: Some(detail_scroll_up_handler.clone()),
+                    on_scroll_down: Some(detail_scroll_down_handler.clone()),
+                )
+            }))
+
+            // Confirmation before archiving a whole column
+            #(archive_message.map(|message| element! {
+                ConfirmDialog(message: message, on_close: None)
+            }))
+
             // Edit form overlay — keyed by ticket ID so that switching
             // to a different ticket always creates a fresh component.
             #(if is_editing {
@@ -796,6 +988,7 @@ mod tests {
                 score: 0,
                 title_indices: vec![],
                 is_semantic: false,
+                similarity: None,
             },
             FilteredTicket {
                 ticket: Arc::new(TicketMetadata {
@@ -808,6 +1001,7 @@ mod tests {
                 score: 0,
                 title_indices: vec![],
                 is_semantic: false,
+                similarity: None,
             },
         ];
 
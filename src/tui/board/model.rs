@@ -9,6 +9,9 @@ use crate::tui::components::toast::Toast;
 use crate::tui::components::{
     board_shortcuts, compute_empty_state, edit_shortcuts, empty_shortcuts,
 };
+use std::collections::HashMap;
+
+use crate::plan::types::PlanMetadata;
 use crate::tui::repository::InitResult;
 use crate::tui::search::{FilteredTicket, filter_tickets};
 use crate::types::{TicketMetadata, TicketStatus};
@@ -46,6 +49,72 @@ pub const COLUMN_KEYS: [char; COLUMN_COUNT] = ['N', 'X', 'I', 'C', '_', 'A'];
 /// after upgrading.
 pub const DEFAULT_VISIBLE_COLUMNS: [bool; COLUMN_COUNT] = [true, true, true, true, true, false];
 
+/// Time in a column after which a card's border is fully stale
+pub const STALE_AFTER: jiff::SignedDuration = jiff::SignedDuration::from_hours(14 * 24);
+
+/// How stale a card is: its time in the current column as a fraction of
+/// [`STALE_AFTER`], capped at 1.0. Closed tickets are not shaded, since time
+/// in a done column is not a bottleneck.
+pub fn card_staleness(ticket: &TicketMetadata, now: jiff::Timestamp) -> Option<f64> {
+    if ticket.status.unwrap_or_default().is_terminal() {
+        return None;
+    }
+    let since = ticket.status_since()?;
+    let age = now.duration_since(since).as_secs_f64().max(0.0);
+    Some((age / STALE_AFTER.as_secs_f64()).min(1.0))
+}
+
+/// The plan a board is restricted to (`janus board --plan`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlanScope {
+    /// Phase label for each ticket in the plan; `None` in a simple plan
+    phases: HashMap<String, Option<String>>,
+}
+
+impl PlanScope {
+    pub fn from_plan(plan: &PlanMetadata) -> Self {
+        let phases = if plan.is_phased() {
+            plan.phases()
+                .into_iter()
+                .flat_map(|phase| {
+                    let label = format!("Phase {}", phase.number);
+                    phase
+                        .ticket_list
+                        .tickets
+                        .iter()
+                        .map(move |id| (id.clone(), Some(label.clone())))
+                })
+                .collect()
+        } else {
+            plan.all_tickets()
+                .into_iter()
+                .map(|id| (id.to_string(), None))
+                .collect()
+        };
+        Self { phases }
+    }
+
+    /// Whether a ticket belongs to the plan
+    pub fn contains(&self, ticket: &TicketMetadata) -> bool {
+        ticket
+            .id
+            .as_deref()
+            .is_some_and(|id| self.phases.contains_key(id))
+    }
+
+    /// The phase a ticket is in, for its card
+    pub fn phase_of(&self, ticket: &TicketMetadata) -> Option<String> {
+        self.phases.get(ticket.id.as_deref()?).cloned().flatten()
+    }
+}
+
+/// Drop tickets outside the plan when the board is scoped to one
+pub fn retain_in_scope(filtered: &mut Vec<FilteredTicket>, scope: Option<&PlanScope>) {
+    if let Some(scope) = scope {
+        filtered.retain(|ft| scope.contains(&ft.ticket));
+    }
+}
+
 /// Raw state that changes during user interaction
 #[derive(Debug, Clone, Default)]
 pub struct BoardState {
@@ -133,6 +202,8 @@ pub enum BoardAction {
     ClearSearchAndExit,
 
     // Edit
+    /// Open the read-only detail modal for the selected ticket
+    ShowDetail,
     /// Edit the currently selected ticket
     EditSelected,
     /// Create a new ticket
@@ -146,6 +217,14 @@ pub enum BoardAction {
     /// Open selected ticket in external $EDITOR
     OpenExternalEditor,
 
+    // Column operations
+    /// Archive every card in the current column (after confirmation)
+    ArchiveColumn,
+    /// Raise the priority of every card in the current column
+    RaiseColumnPriority,
+    /// Copy the current column to the clipboard as markdown
+    ExportColumn,
+
     // App
     /// Quit the application
     Quit,
@@ -153,6 +232,29 @@ pub enum BoardAction {
     Reload,
 }
 
+/// A bulk operation applied to every card in a board column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnOp {
+    Archive,
+    RaisePriority,
+    Export,
+}
+
+/// Render a column's tickets as a markdown list, e.g. for a sprint summary
+pub fn column_markdown(column: usize, tickets: &[TicketMetadata]) -> String {
+    let mut markdown = format!("## {} ({})\n", COLUMN_NAMES[column], tickets.len());
+    for ticket in tickets {
+        markdown.push_str(&format!(
+            "\n- **{}** {} (P{})",
+            ticket.id.as_deref().unwrap_or("?"),
+            ticket.title.as_deref().unwrap_or_default(),
+            ticket.priority.unwrap_or_default().as_num()
+        ));
+    }
+    markdown.push('\n');
+    markdown
+}
+
 /// Computed view model for rendering
 #[derive(Debug, Clone)]
 pub struct BoardViewModel {
@@ -503,11 +605,15 @@ pub fn reduce_board_state(
 
         // These actions are handled by the component's async logic,
         // but we still need to match them to avoid warnings
-        BoardAction::EditSelected
+        BoardAction::ShowDetail
+        | BoardAction::EditSelected
         | BoardAction::MoveTicketStatusRight
         | BoardAction::MoveTicketStatusLeft
         | BoardAction::CopyTicketId
         | BoardAction::OpenExternalEditor
+        | BoardAction::ArchiveColumn
+        | BoardAction::RaiseColumnPriority
+        | BoardAction::ExportColumn
         | BoardAction::Quit
         | BoardAction::Reload => {
             // These require async I/O or system context, handled externally
@@ -1106,18 +1212,21 @@ mod tests {
                 score: 0,
                 title_indices: vec![],
                 is_semantic: false,
+                similarity: None,
             },
             FilteredTicket {
                 ticket: Arc::new(make_ticket("j-2", "WIP task", TicketStatus::InProgress)),
                 score: 0,
                 title_indices: vec![],
                 is_semantic: false,
+                similarity: None,
             },
             FilteredTicket {
                 ticket: Arc::new(make_ticket("j-3", "Another new", TicketStatus::New)),
                 score: 0,
                 title_indices: vec![],
                 is_semantic: false,
+                similarity: None,
             },
         ];
 
@@ -1348,4 +1457,80 @@ mod tests {
             "Should show 5 visible cards"
         );
     }
+
+    #[test]
+    fn test_plan_scope_phases() {
+        use crate::plan::types::{Phase, PlanSection, TicketList};
+
+        let plan = PlanMetadata {
+            sections: vec![PlanSection::Phase(Phase {
+                number: "2".to_string(),
+                name: "Backend".to_string(),
+                ticket_list: TicketList {
+                    tickets: vec!["j-in".to_string()],
+                    tickets_raw: None,
+                },
+                ..Default::default()
+            })],
+            ..Default::default()
+        };
+        let scope = PlanScope::from_plan(&plan);
+        let inside = make_ticket("j-in", "In plan", TicketStatus::New);
+        let outside = make_ticket("j-out", "Elsewhere", TicketStatus::New);
+
+        assert!(scope.contains(&inside));
+        assert!(!scope.contains(&outside));
+        assert_eq!(scope.phase_of(&inside), Some("Phase 2".to_string()));
+
+        let mut filtered: Vec<FilteredTicket> = [inside, outside]
+            .into_iter()
+            .map(|t| FilteredTicket {
+                ticket: Arc::new(t),
+                score: 0,
+                title_indices: vec![],
+                is_semantic: false,
+                similarity: None,
+            })
+            .collect();
+        retain_in_scope(&mut filtered, Some(&scope));
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].ticket.id.as_deref(), Some("j-in"));
+    }
+
+    #[test]
+    fn test_column_markdown() {
+        let mut urgent = make_ticket("j-a1b2", "Fix login", TicketStatus::Complete);
+        urgent.priority = Some(TicketPriority::P0);
+        let tickets = vec![
+            urgent,
+            make_ticket("j-c3d4", "Update docs", TicketStatus::Complete),
+        ];
+
+        assert_eq!(
+            column_markdown(3, &tickets),
+            "## COMPLETE (2)\n\n- **j-a1b2** Fix login (P0)\n- **j-c3d4** Update docs (P2)\n"
+        );
+    }
+
+    #[test]
+    fn test_card_staleness() {
+        use crate::types::{CreatedAt, StatusChange};
+
+        let now: jiff::Timestamp = "2024-01-15T00:00:00Z".parse().unwrap();
+        let mut ticket = make_ticket("j-a1b2", "Fix login", TicketStatus::InProgress);
+        ticket.created = Some(CreatedAt::new_unchecked("2023-12-01T00:00:00Z"));
+        assert_eq!(card_staleness(&ticket, now), Some(1.0));
+
+        ticket.started_at = Some(CreatedAt::new_unchecked("2024-01-08T00:00:00Z"));
+        assert_eq!(card_staleness(&ticket, now), Some(0.5));
+
+        ticket.status_history = vec![StatusChange {
+            status: TicketStatus::InProgress,
+            at: CreatedAt::new_unchecked("2024-01-15T00:00:00Z"),
+        }];
+        assert_eq!(card_staleness(&ticket, now), Some(0.0));
+
+        ticket.status = Some(TicketStatus::Complete);
+        assert_eq!(card_staleness(&ticket, now), None);
+    }
 }
@@ -106,11 +106,24 @@ pub fn browser_shortcuts() -> Vec<Shortcut> {
         .add("s", "Cycle Status")
         .add("Tab", "Switch Pane")
         .add("C-t", "Triage")
+        .add("C-s", "Semantic")
         .add("E", "$EDITOR")
         .add("y", "Copy ID")
         .build()
 }
 
+/// Shortcuts for the doc browser
+pub fn doc_browser_shortcuts() -> Vec<Shortcut> {
+    ShortcutsBuilder::new()
+        .with_navigation()
+        .with_search()
+        .with_quit()
+        .add("Tab", "Switch Pane")
+        .add("Enter", "Open")
+        .add("E", "$EDITOR")
+        .build()
+}
+
 /// Shortcuts for the kanban board
 pub fn board_shortcuts() -> Vec<Shortcut> {
     ShortcutsBuilder::new()
@@ -118,11 +131,22 @@ pub fn board_shortcuts() -> Vec<Shortcut> {
         .with_search()
         .with_edit()
         .with_quit()
+        .add("Enter", "Details")
         .add("h/l", "Column")
         .add("s/S", "Move Right/Left")
         .add("1-5", "Toggle Column")
         .add("E", "$EDITOR")
         .add("y", "Copy ID")
+        .add("A/P/M", "Column: Archive/Raise/Copy")
+        .build()
+}
+
+/// Shortcuts for the board's card detail modal
+pub fn card_detail_shortcuts() -> Vec<Shortcut> {
+    ShortcutsBuilder::new()
+        .add("j/k", "Scroll")
+        .add("e", "Edit")
+        .add("Esc", "Close")
         .build()
 }
 
@@ -146,6 +170,19 @@ pub fn search_shortcuts() -> Vec<Shortcut> {
         .build()
 }
 
+/// Shortcuts shown in the issue browser's search box, which also offers
+/// search history and completions
+pub fn browser_search_shortcuts() -> Vec<Shortcut> {
+    ShortcutsBuilder::new()
+        .add("Enter", "Apply Search")
+        .add("↑", "History")
+        .add("Tab", "Complete/Exit")
+        .add("C-s", "Semantic")
+        .add("Esc", "Clear & Exit")
+        .add("C-q", "Quit")
+        .build()
+}
+
 /// Shortcuts shown when empty state is displayed
 pub fn empty_shortcuts() -> Vec<Shortcut> {
     ShortcutsBuilder::new()
@@ -215,6 +252,16 @@ pub fn confirm_dialog_shortcuts() -> Vec<Shortcut> {
         .build()
 }
 
+/// Shortcuts for the create remote issue form
+pub fn create_issue_shortcuts() -> Vec<Shortcut> {
+    ShortcutsBuilder::new()
+        .add("Tab", "Next Field")
+        .add("Space", "Toggle Adopt")
+        .add("C-s", "Create")
+        .add("Esc", "Cancel")
+        .build()
+}
+
 /// Shortcuts for the link mode (when selecting a target)
 pub fn link_mode_shortcuts() -> Vec<Shortcut> {
     ShortcutsBuilder::new()
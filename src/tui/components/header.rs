@@ -1,10 +1,75 @@
 //! App header bar component
 //!
-//! Displays the application title and optional ticket count.
+//! Displays the application title and optional ticket count or summary
+//! counts.
+
+use std::collections::HashMap;
 
 use iocraft::prelude::*;
+use jiff::Timestamp;
 
+use crate::config::ReadyRequirements;
+use crate::status::{has_unsatisfied_dep, is_ready};
 use crate::tui::theme::theme;
+use crate::types::{TicketMetadata, TicketPriority, TicketStatus};
+
+/// Summary counts shown in the header of the browser and board
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TicketCounts {
+    /// Tickets on the screen, ignoring the search query
+    pub total: usize,
+    /// Tickets matching the search query
+    pub filtered: usize,
+    /// Tickets ready under `ready.require` (by default unsnoozed new/next
+    /// tickets whose deps are all satisfied)
+    pub ready: usize,
+    /// Unsnoozed new/next tickets with an unsatisfied dep
+    pub blocked: usize,
+    /// Open P0 tickets
+    pub p0: usize,
+}
+
+impl TicketCounts {
+    /// Count the tickets in `all` accepted by `in_scope`. Dependencies are
+    /// resolved against all of `all`, in scope or not.
+    pub fn compute(
+        all: &[TicketMetadata],
+        filtered: usize,
+        require: &ReadyRequirements,
+        in_scope: impl Fn(&TicketMetadata) -> bool,
+    ) -> Self {
+        let ticket_map: HashMap<String, TicketMetadata> = all
+            .iter()
+            .filter_map(|t| t.id.as_ref().map(|id| (id.to_string(), t.clone())))
+            .collect();
+        let now = Timestamp::now();
+
+        let mut counts = TicketCounts {
+            filtered,
+            ..Default::default()
+        };
+        for ticket in all.iter().filter(|t| in_scope(t)) {
+            counts.total += 1;
+            let status = ticket.status.unwrap_or_default();
+            if status.is_terminal() {
+                continue;
+            }
+            if ticket.priority == Some(TicketPriority::P0) {
+                counts.p0 += 1;
+            }
+            if matches!(status, TicketStatus::New | TicketStatus::Next)
+                && !ticket.is_snoozed_at(now)
+                && has_unsatisfied_dep(ticket, &ticket_map)
+            {
+                counts.blocked += 1;
+            }
+            if is_ready(ticket, &ticket_map, require, now) {
+                counts.ready += 1;
+            }
+        }
+        counts
+    }
+}
 
 /// Props for the Header component
 #[derive(Default, Props)]
@@ -18,6 +83,9 @@ pub struct HeaderProps<'a> {
     /// Ticket count
     pub ticket_count: Option<usize>,
 
+    /// Summary counts, shown instead of `ticket_count` when set
+    pub counts: Option<TicketCounts>,
+
     /// Extra elements to render on the right (before ticket count)
     pub extra: Option<Vec<AnyElement<'a>>>,
 
@@ -100,13 +168,92 @@ pub fn Header<'a>(props: &mut HeaderProps<'a>) -> impl Into<AnyElement<'a>> {
                 #(std::mem::take(&mut props.extra).unwrap_or_default())
 
                 // Ticket count
-                #(props.ticket_count.map(|count| element! {
+                #(props.ticket_count.filter(|_| props.counts.is_none()).map(|count| element! {
                         Text(
                             content: format!("{} tickets", count),
                             color: theme.text_dimmed,
                         )
                     }))
+
+                // Summary counts
+                #(props.counts.map(|counts| element! {
+                    View(flex_direction: FlexDirection::Row, gap: 1) {
+                        Text(
+                            content: if counts.filtered == counts.total {
+                                format!("{} tickets", counts.total)
+                            } else {
+                                format!("{}/{} tickets", counts.filtered, counts.total)
+                            },
+                            color: theme.text_dimmed,
+                        )
+                        Text(content: format!("{} ready", counts.ready), color: theme.status_complete)
+                        Text(content: format!("{} blocked", counts.blocked), color: theme.sla_at_risk)
+                        Text(
+                            content: format!("{} P0", counts.p0),
+                            color: if counts.p0 > 0 { theme.priority_p0 } else { theme.text_dimmed },
+                            weight: if counts.p0 > 0 { Weight::Bold } else { Weight::Normal },
+                        )
+                    }
+                }))
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TicketId;
+
+    fn ticket(id: &str, status: TicketStatus, deps: &[&str]) -> TicketMetadata {
+        TicketMetadata {
+            id: Some(TicketId::new_unchecked(id)),
+            status: Some(status),
+            deps: deps.iter().map(|d| TicketId::new_unchecked(*d)).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_ticket_counts() {
+        let mut urgent = ticket("j-urgent", TicketStatus::InProgress, &[]);
+        urgent.priority = Some(TicketPriority::P0);
+        let mut done_p0 = ticket("j-done", TicketStatus::Complete, &[]);
+        done_p0.priority = Some(TicketPriority::P0);
+        let all = vec![
+            urgent,
+            done_p0,
+            ticket("j-ready", TicketStatus::New, &["j-done"]),
+            ticket("j-blocked", TicketStatus::Next, &["j-urgent"]),
+            ticket("j-other", TicketStatus::New, &[]),
+        ];
+
+        let require = ReadyRequirements::default();
+        let counts = TicketCounts::compute(&all, 3, &require, |_| true);
+        assert_eq!(
+            counts,
+            TicketCounts {
+                total: 5,
+                filtered: 3,
+                ready: 2,
+                blocked: 1,
+                p0: 1,
+            }
+        );
+
+        // Deps outside the scope still resolve
+        let scoped =
+            TicketCounts::compute(&all, 1, &require, |t| t.id.as_deref() == Some("j-ready"));
+        assert_eq!(scoped.total, 1);
+        assert_eq!(scoped.ready, 1);
+        assert_eq!(scoped.p0, 0);
+
+        // Ready follows the configured requirements
+        let require = ReadyRequirements {
+            triaged: true,
+            ..Default::default()
+        };
+        let counts = TicketCounts::compute(&all, 3, &require, |_| true);
+        assert_eq!((counts.ready, counts.blocked), (0, 1));
+    }
+}
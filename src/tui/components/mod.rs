@@ -24,18 +24,22 @@ pub use clickable::{Clickable, ClickableProps};
 pub use clickable_text::{ClickableText, ClickableTextProps};
 pub use empty_state::{EmptyState, EmptyStateKind, EmptyStateProps, compute_empty_state};
 pub use footer::{
-    Footer, FooterProps, Shortcut, board_shortcuts, browser_shortcuts,
-    cancel_confirm_modal_shortcuts, confirm_dialog_shortcuts, edit_shortcuts, empty_shortcuts,
+    Footer, FooterProps, Shortcut, board_shortcuts, browser_search_shortcuts, browser_shortcuts,
+    cancel_confirm_modal_shortcuts, card_detail_shortcuts, confirm_dialog_shortcuts,
+    create_issue_shortcuts, doc_browser_shortcuts, edit_shortcuts, empty_shortcuts,
     error_modal_shortcuts, filter_modal_shortcuts, help_modal_shortcuts, link_mode_shortcuts,
     note_input_modal_shortcuts, search_shortcuts, sync_preview_shortcuts, triage_shortcuts,
 };
-pub use header::{Header, HeaderProps};
+pub use header::{Header, HeaderProps, TicketCounts};
 pub use modal_container::{
     ModalBorderColor, ModalContainer, ModalContainerProps, ModalHeight, ModalWidth,
 };
 pub use modal_overlay::{MODAL_BACKDROP, ModalOverlay, ModalOverlayProps};
 pub use modal_state::{ModalState, NoteModalData, StoreErrorModalData, TicketModalData};
-pub use search_box::{InlineSearchBox, InlineSearchBoxProps, SearchBox, SearchBoxProps};
+pub use search_box::{
+    InlineSearchBox, InlineSearchBoxProps, SearchBox, SearchBoxProps, SearchDropdown,
+    SearchDropdownProps,
+};
 pub use select::{Select, SelectProps, Selectable, options_for};
 pub use shortcuts::ShortcutsBuilder;
 pub use text_editor::{TextEditor, TextEditorProps};
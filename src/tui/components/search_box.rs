@@ -1,9 +1,13 @@
 //! Search input component with search icon
 //!
-//! A text input field with a search icon prefix for fuzzy searching tickets.
+//! A text input field with a search icon prefix for fuzzy searching tickets,
+//! a lexical/semantic mode badge, and a dropdown for recent searches and
+//! completions.
 
 use iocraft::prelude::*;
 
+use crate::tui::components::MODAL_BACKDROP;
+use crate::tui::search_orchestrator::SearchMode;
 use crate::tui::theme::theme;
 
 /// Props for the SearchBox component
@@ -15,6 +19,11 @@ pub struct SearchBoxProps {
     pub has_focus: bool,
     /// Whether semantic search mode is active (query starts with ~)
     pub is_semantic: bool,
+    /// Current search mode, shown as a badge when set
+    pub mode: Option<SearchMode>,
+    /// Embedding index progress while it is still building, see
+    /// [`embedding_index_progress`](crate::tui::search_orchestrator::embedding_index_progress)
+    pub index_progress: Option<(usize, usize)>,
 }
 
 /// Search input with magnifying glass icon
@@ -29,6 +38,8 @@ pub fn SearchBox(props: &SearchBoxProps) -> impl Into<AnyElement<'static>> {
         theme.border
     };
 
+    let index_label = index_label(props.is_semantic, props.index_progress);
+
     let Some(mut value) = props.value else {
         return element! {
             View(
@@ -74,10 +85,38 @@ pub fn SearchBox(props: &SearchBoxProps) -> impl Into<AnyElement<'static>> {
                     color: theme.text,
                 )
             }
+            // Embedding index still building - semantic results may be incomplete
+            #(index_label.map(|label| element! {
+                View(margin_left: 1, flex_shrink: 0.0) {
+                    Text(content: label, color: theme.text_dimmed)
+                }
+            }))
+            // Lexical/semantic mode badge
+            #(props.mode.map(|mode| element! {
+                View(margin_left: 1, flex_shrink: 0.0) {
+                    Text(
+                        content: format!("[{mode}]"),
+                        color: if mode == SearchMode::Semantic {
+                            theme.semantic_search_border
+                        } else {
+                            theme.text_dimmed
+                        },
+                    )
+                }
+            }))
         }
     }
 }
 
+/// Label for the index indicator, shown only while a semantic search is active
+fn index_label(is_semantic: bool, progress: Option<(usize, usize)>) -> Option<String> {
+    match progress? {
+        _ if !is_semantic => None,
+        (_, 0) => Some("indexing…".to_string()),
+        (done, total) => Some(format!("indexing {done}/{total}")),
+    }
+}
+
 /// A simpler inline search box without borders (for kanban board header)
 #[derive(Default, Props)]
 pub struct InlineSearchBoxProps {
@@ -138,3 +177,50 @@ pub fn InlineSearchBox(props: &InlineSearchBoxProps) -> impl Into<AnyElement<'st
         }
     }
 }
+
+/// Props for the SearchDropdown component
+#[derive(Default, Props)]
+pub struct SearchDropdownProps {
+    /// Heading shown above the entries (e.g. "Recent searches")
+    pub title: String,
+    /// Entries to list, top to bottom
+    pub items: Vec<String>,
+    /// Index of the highlighted entry, if any
+    pub selected: Option<usize>,
+}
+
+/// Dropdown listing recent searches or completions below a search box.
+///
+/// The parent positions it; it draws an opaque background so it can sit on
+/// top of the ticket list.
+#[component]
+pub fn SearchDropdown(props: &SearchDropdownProps) -> impl Into<AnyElement<'static>> {
+    let theme = theme();
+
+    element! {
+        View(
+            flex_direction: FlexDirection::Column,
+            border_style: BorderStyle::Round,
+            border_color: theme.border_focused,
+            background_color: MODAL_BACKDROP,
+            padding_left: 1,
+            padding_right: 1,
+            width: 100pct,
+        ) {
+            Text(content: props.title.clone(), color: theme.text_dimmed)
+            #(props.items.iter().enumerate().map(|(i, item)| {
+                let is_selected = props.selected == Some(i);
+                element! {
+                    View(
+                        background_color: if is_selected { Some(theme.highlight) } else { None },
+                    ) {
+                        Text(
+                            content: item.clone(),
+                            color: if is_selected { theme.highlight_text } else { theme.text },
+                        )
+                    }
+                }
+            }))
+        }
+    }
+}
@@ -1,10 +1,13 @@
 //! Ticket card component for kanban board
 //!
 //! A compact card view showing ticket id, title (truncated), priority badge,
-//! and type indicator.
+//! type indicator, the ticket's plan phase on a plan-scoped board, and an SLA
+//! badge for tickets at risk or past their SLA. The border shades from fresh
+//! to stale with the time the ticket has spent in its column.
 
 use iocraft::prelude::*;
 
+use crate::sla::SlaState;
 use crate::tui::components::Clickable;
 use crate::tui::theme::theme;
 use crate::types::{TicketMetadata, TicketPriority, TicketType};
@@ -24,6 +27,13 @@ pub struct TicketCardProps {
     pub on_click: Option<Handler<usize>>,
     /// The row index of this card within its column
     pub row_idx: usize,
+    /// Worst SLA state of the ticket, if it is at risk or breached
+    pub sla: Option<SlaState>,
+    /// Plan phase of the ticket, on a board scoped to a phased plan
+    pub phase: Option<String>,
+    /// How long the ticket has sat in its column, from 0.0 (fresh) to 1.0
+    /// (stale); `None` leaves the border uncolored
+    pub staleness: Option<f64>,
 }
 
 /// Compact ticket card for kanban board columns
@@ -35,7 +45,7 @@ pub struct TicketCardProps {
 /// | Fix the login bug |
 /// | that prevents     |
 /// | users from...     |
-/// | P1  bug           |
+/// | P1  bug  Phase 2  |
 /// +-------------------+
 /// ```
 #[component]
@@ -53,7 +63,10 @@ pub fn TicketCard(props: &TicketCardProps) -> impl Into<AnyElement<'static>> {
     let border_color = if props.is_selected {
         theme.border_focused
     } else {
-        theme.border
+        props
+            .staleness
+            .map(|staleness| theme.age_color(staleness))
+            .unwrap_or(theme.border)
     };
     let bg_color = if props.is_selected {
         Some(theme.highlight)
@@ -82,6 +95,25 @@ pub fn TicketCard(props: &TicketCardProps) -> impl Into<AnyElement<'static>> {
         theme.type_color(ticket_type)
     };
 
+    // SLA badge: "SLA" when at risk, "SLA!" when breached
+    let (sla_str, sla_color) = match props.sla {
+        Some(SlaState::Breached) => ("SLA!", theme.sla_breached),
+        Some(SlaState::AtRisk) => ("SLA", theme.sla_at_risk),
+        None => ("", theme.text),
+    };
+    let sla_color = if props.is_selected {
+        theme.highlight_text
+    } else {
+        sla_color
+    };
+
+    let phase_str = props.phase.clone().unwrap_or_default();
+    let phase_color = if props.is_selected {
+        theme.highlight_text
+    } else {
+        theme.text_dimmed
+    };
+
     // Calculate available width for title text
     // Card has padding_left: 1, padding_right: 1, and border chars (2 total for round border)
     // So available text width = card_width - 4
@@ -153,6 +185,15 @@ pub fn TicketCard(props: &TicketCardProps) -> impl Into<AnyElement<'static>> {
                                 content: type_str,
                                 color: type_color,
                             )
+                            Text(
+                                content: phase_str.clone(),
+                                color: phase_color,
+                            )
+                            Text(
+                                content: sla_str,
+                                color: sla_color,
+                                weight: Weight::Bold,
+                            )
                         }
                     }
                 }
@@ -203,6 +244,15 @@ pub fn TicketCard(props: &TicketCardProps) -> impl Into<AnyElement<'static>> {
                         content: type_str,
                         color: type_color,
                     )
+                    Text(
+                        content: phase_str,
+                        color: phase_color,
+                    )
+                    Text(
+                        content: sla_str,
+                        color: sla_color,
+                        weight: Weight::Bold,
+                    )
                 }
             }
         }
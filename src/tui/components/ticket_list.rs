@@ -257,6 +257,16 @@ pub fn TicketRow(props: &TicketRowProps) -> impl Into<AnyElement<'static>> {
                     color: text_color,
                 )
             }
+
+            // Semantic relevance score - only for tickets semantic search scored
+            #(props.ticket.similarity.map(|similarity| element! {
+                View(width: 5, flex_shrink: 0.0, justify_content: JustifyContent::End) {
+                    Text(
+                        content: format!("{similarity:.2}"),
+                        color: if props.is_selected { theme.highlight_text } else { theme.text_dimmed },
+                    )
+                }
+            }))
         }
     }
 }
@@ -281,6 +291,7 @@ mod tests {
             score: 0,
             title_indices: vec![],
             is_semantic: false,
+            similarity: None,
         }
     }
 }
@@ -0,0 +1,210 @@
+//! Sub-components for the doc browser
+//!
+//! The document list and the heading outline. The preview pane reuses
+//! `TextViewer` directly.
+
+use std::sync::Arc;
+
+use iocraft::prelude::*;
+
+use crate::tui::components::Clickable;
+use crate::tui::search::FilteredItem;
+use crate::tui::theme::theme;
+
+use super::model::{DocEntry, OutlineEntry};
+
+// ============================================================================
+// Document List
+// ============================================================================
+
+/// Props for the DocList component
+#[derive(Default, Props)]
+pub struct DocListProps {
+    /// Filtered documents to display
+    pub docs: Vec<FilteredItem<Arc<DocEntry>>>,
+    /// Index of the currently selected document
+    pub selected_index: usize,
+    /// Current scroll offset (first visible document index)
+    pub scroll_offset: usize,
+    /// Whether the list has focus
+    pub has_focus: bool,
+    /// Number of visible rows, including the border
+    pub visible_height: usize,
+    /// Handler invoked when a row is clicked (passes the actual index)
+    pub on_row_click: Option<Handler<usize>>,
+}
+
+/// Scrollable document list with selection
+#[component]
+pub fn DocList(props: &DocListProps) -> impl Into<AnyElement<'static>> {
+    let theme = theme();
+    let border_color = if props.has_focus {
+        theme.border_focused
+    } else {
+        theme.border
+    };
+
+    let start = props.scroll_offset.min(props.docs.len());
+    let rows = props.visible_height.saturating_sub(2).max(1);
+    let end = (start + rows).min(props.docs.len());
+
+    element! {
+        View(
+            width: 100pct,
+            height: 100pct,
+            flex_direction: FlexDirection::Column,
+            border_style: BorderStyle::Round,
+            border_color: border_color,
+        ) {
+            #(props.docs[start..end].iter().enumerate().map(|(i, item)| {
+                let actual_index = start + i;
+                let is_selected = actual_index == props.selected_index;
+                let on_click = props.on_row_click.clone();
+                let doc = &item.item;
+                let bg_color = if is_selected { Some(theme.highlight) } else { None };
+                let text_color = if is_selected { theme.highlight_text } else { theme.text };
+                element! {
+                    Clickable(
+                        on_click: on_click.map(|handler| {
+                            Handler::from(move |_: ()| {
+                                handler(actual_index);
+                            })
+                        }),
+                    ) {
+                        View(
+                            height: 1,
+                            width: 100pct,
+                            flex_direction: FlexDirection::Row,
+                            padding_left: 1,
+                            padding_right: 1,
+                            background_color: bg_color,
+                        ) {
+                            View(width: 2, flex_shrink: 0.0) {
+                                Text(
+                                    content: if is_selected { ">" } else { " " },
+                                    color: text_color,
+                                )
+                            }
+                            View(flex_shrink: 0.0) {
+                                Text(
+                                    content: doc.label.clone(),
+                                    color: if is_selected { theme.highlight_text } else { theme.id_color },
+                                )
+                            }
+                            View(flex_grow: 1.0, overflow: Overflow::Hidden) {
+                                Text(
+                                    content: doc
+                                        .title
+                                        .as_ref()
+                                        .map(|title| format!(" {title}"))
+                                        .unwrap_or_default(),
+                                    color: text_color,
+                                )
+                            }
+                        }
+                    }
+                }
+            }))
+
+            #(if end < props.docs.len() {
+                Some(element! {
+                    View(height: 1, padding_left: 1) {
+                        Text(
+                            content: format!("  {} more below", props.docs.len() - end),
+                            color: theme.text_dimmed,
+                        )
+                    }
+                })
+            } else {
+                None
+            })
+        }
+    }
+}
+
+// ============================================================================
+// Outline
+// ============================================================================
+
+/// Props for the DocOutline component
+#[derive(Default, Props)]
+pub struct DocOutlineProps {
+    /// Headings of the selected document
+    pub outline: Vec<OutlineEntry>,
+    /// Index of the heading containing the preview position
+    pub current: Option<usize>,
+    /// Whether the outline has focus
+    pub has_focus: bool,
+    /// Number of visible rows, including the border
+    pub visible_height: usize,
+}
+
+/// Heading outline of the selected document
+///
+/// Keeps the current heading in view by scrolling just enough to show it.
+#[component]
+pub fn DocOutline(props: &DocOutlineProps) -> impl Into<AnyElement<'static>> {
+    let theme = theme();
+    let border_color = if props.has_focus {
+        theme.border_focused
+    } else {
+        theme.border
+    };
+
+    let rows = props.visible_height.saturating_sub(2).max(1);
+    let current = props.current.unwrap_or(0);
+    let start = (current + 1).saturating_sub(rows);
+    let end = (start + rows).min(props.outline.len());
+
+    element! {
+        View(
+            width: 100pct,
+            height: 100pct,
+            flex_direction: FlexDirection::Column,
+            border_style: BorderStyle::Round,
+            border_color: border_color,
+        ) {
+            #(if props.outline.is_empty() {
+                Some(element! {
+                    View(height: 1, padding_left: 1) {
+                        Text(content: "No headings", color: theme.text_dimmed)
+                    }
+                })
+            } else {
+                None
+            })
+
+            #(props.outline[start.min(end)..end].iter().enumerate().map(|(i, entry)| {
+                let is_current = props.current == Some(start + i);
+                let bg_color = if is_current && props.has_focus {
+                    Some(theme.highlight)
+                } else {
+                    None
+                };
+                let text_color = if is_current && props.has_focus {
+                    theme.highlight_text
+                } else if is_current {
+                    theme.text
+                } else {
+                    theme.text_dimmed
+                };
+                element! {
+                    View(
+                        height: 1,
+                        width: 100pct,
+                        padding_left: 1,
+                        padding_right: 1,
+                        overflow: Overflow::Hidden,
+                        background_color: bg_color,
+                    ) {
+                        Text(
+                            content: format!("{}{}", "  ".repeat(entry.depth), entry.heading),
+                            color: text_color,
+                            weight: if is_current { Weight::Bold } else { Weight::Normal },
+                        )
+                    }
+                }
+            }))
+        }
+    }
+}
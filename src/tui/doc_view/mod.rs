@@ -0,0 +1,521 @@
+//! Doc browser view (`janus doc view`)
+//!
+//! Provides an interactive TUI for browsing documents with fuzzy search, a
+//! heading outline for jumping around the selected document, and a full-text
+//! markdown preview. Mirrors the issue browser layout.
+
+pub mod components;
+pub mod model;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use iocraft::prelude::*;
+
+use crate::tui::components::{
+    Clickable, SearchBox, TextViewer, Toast, doc_browser_shortcuts, search_shortcuts,
+};
+use crate::tui::handlers::{SearchAction, handle_search_input};
+use crate::tui::navigation::{
+    apply_page_down, apply_page_up, apply_scroll_down, apply_scroll_to_bottom, apply_scroll_to_top,
+    apply_scroll_up,
+};
+use crate::tui::screen_base::{ScreenLayout, calculate_list_height, should_process_key_event};
+use crate::tui::services::ExternalEditor;
+use crate::tui::theme::theme;
+
+use components::{DocList, DocOutline};
+use model::{DocEntry, filter_docs, load_doc_entries, outline_index_for_line};
+
+/// Which pane of the doc browser has focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum DocPane {
+    Search,
+    #[default]
+    List,
+    Outline,
+    Preview,
+}
+
+impl DocPane {
+    /// Next pane in Tab order (search is only reachable with `/`)
+    fn next(self) -> Self {
+        match self {
+            DocPane::Search | DocPane::Preview => DocPane::List,
+            DocPane::List => DocPane::Outline,
+            DocPane::Outline => DocPane::Preview,
+        }
+    }
+
+    /// Previous pane in Tab order
+    fn prev(self) -> Self {
+        match self {
+            DocPane::Search | DocPane::Outline => DocPane::List,
+            DocPane::List => DocPane::Preview,
+            DocPane::Preview => DocPane::Outline,
+        }
+    }
+}
+
+/// Props for the DocBrowser component
+#[derive(Default, Props)]
+pub struct DocBrowserProps {}
+
+/// Document browser component
+///
+/// Layout:
+/// ```text
+/// +------------------------------------------+
+/// | Header                                    |
+/// +------------------------------------------+
+/// | SearchBox                                 |
+/// +------------+----------+------------------+
+/// | DocList    | Outline  | Preview          |
+/// |            |          |                  |
+/// +------------+----------+------------------+
+/// | Footer                                    |
+/// +------------------------------------------+
+/// ```
+///
+/// The outline and preview share one position: moving through the outline
+/// scrolls the preview to that heading, and scrolling the preview moves the
+/// outline highlight to the section being read.
+#[component]
+pub fn DocBrowser<'a>(_props: &DocBrowserProps, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
+    let (width, height) = hooks.use_terminal_size();
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let theme = theme();
+
+    let all_docs: State<Vec<Arc<DocEntry>>> = hooks.use_state(Vec::new);
+    let mut is_loading = hooks.use_state(|| true);
+    let mut toast: State<Option<Toast>> = hooks.use_state(|| None);
+    let search_query = hooks.use_state(String::new);
+    let mut last_query = hooks.use_state(String::new);
+    let mut selected_index = hooks.use_state(|| 0usize);
+    let mut scroll_offset = hooks.use_state(|| 0usize);
+    let mut preview_scroll = hooks.use_state(|| 0usize);
+    let mut active_pane = hooks.use_state(DocPane::default);
+    let should_exit = hooks.use_state(|| false);
+    let mut needs_reload = hooks.use_state(|| false);
+    let mut pending_external_edit: State<Option<PathBuf>> = hooks.use_state(|| None);
+
+    let load_handler: Handler<()> = hooks.use_async_handler({
+        move |_| {
+            let mut all_docs = all_docs;
+            let mut is_loading = is_loading;
+            let mut toast = toast;
+            async move {
+                match load_doc_entries().await {
+                    Ok(docs) => all_docs.set(docs.into_iter().map(Arc::new).collect()),
+                    Err(e) => toast.set(Some(Toast::error(format!("Failed to load docs: {e}")))),
+                }
+                is_loading.set(false);
+            }
+        }
+    });
+
+    // Trigger initial load on mount
+    let mut load_started = hooks.use_state(|| false);
+    if !load_started.get() {
+        load_started.set(true);
+        load_handler.clone()(());
+    }
+
+    // Subscribe to store watcher events for live external updates.
+    hooks.use_future(crate::tui::hooks::use_store_watcher(needs_reload));
+
+    if needs_reload.get() && !is_loading.get() {
+        needs_reload.set(false);
+        is_loading.set(true);
+        load_handler.clone()(());
+    }
+
+    // Handle deferred external editor launch
+    let pending_edit_path = pending_external_edit.read().clone();
+    if let Some(path) = pending_edit_path {
+        pending_external_edit.set(None);
+        match ExternalEditor::open_ticket_file(&path) {
+            Ok(()) => needs_reload.set(true),
+            Err(e) => toast.set(Some(ExternalEditor::error_to_toast(&e))),
+        }
+    }
+
+    // Filter as the user types; fuzzy matching is cheap enough for docs
+    let query_str = search_query.to_string();
+    if *last_query.read() != query_str {
+        last_query.set(query_str.clone());
+        selected_index.set(0);
+        scroll_offset.set(0);
+        preview_scroll.set(0);
+    }
+    let filtered = filter_docs(&all_docs.read(), &query_str);
+    let total_doc_count = all_docs.read().len();
+
+    if selected_index.get() >= filtered.len() && !filtered.is_empty() {
+        selected_index.set(filtered.len() - 1);
+    }
+    let selected = filtered
+        .get(selected_index.get())
+        .map(|item| Arc::clone(&item.item));
+    let outline = selected
+        .as_ref()
+        .map(|doc| doc.outline.clone())
+        .unwrap_or_default();
+    let max_preview_scroll = selected
+        .as_ref()
+        .map(|doc| doc.body.lines().count().saturating_sub(1))
+        .unwrap_or(0);
+    let current_heading = outline_index_for_line(&outline, preview_scroll.get());
+
+    // Search box (3) + borders (2) = 5
+    let list_height = calculate_list_height(height, 5);
+    let list_rows = list_height.saturating_sub(2).max(1);
+
+    // Mouse handlers
+    let focus_search_handler: Handler<()> = hooks.use_async_handler({
+        move |_| async move {
+            active_pane.set(DocPane::Search);
+        }
+    });
+    let focus_list_handler: Handler<()> = hooks.use_async_handler({
+        move |_| async move {
+            active_pane.set(DocPane::List);
+        }
+    });
+    let focus_outline_handler: Handler<()> = hooks.use_async_handler({
+        move |_| async move {
+            active_pane.set(DocPane::Outline);
+        }
+    });
+    let focus_preview_handler: Handler<()> = hooks.use_async_handler({
+        move |_| async move {
+            active_pane.set(DocPane::Preview);
+        }
+    });
+    let row_click_handler: Handler<usize> = hooks.use_async_handler({
+        move |idx: usize| async move {
+            if idx != selected_index.get() {
+                preview_scroll.set(0);
+            }
+            selected_index.set(idx);
+            active_pane.set(DocPane::List);
+        }
+    });
+    let preview_scroll_up_handler: Handler<()> = hooks.use_async_handler({
+        move |_| async move {
+            preview_scroll.set(preview_scroll.get().saturating_sub(3));
+        }
+    });
+    let preview_scroll_down_handler: Handler<()> = hooks.use_async_handler({
+        move |_| async move {
+            preview_scroll.set((preview_scroll.get() + 3).min(max_preview_scroll));
+        }
+    });
+
+    hooks.use_terminal_events({
+        let filtered_len = filtered.len();
+        let outline = outline.clone();
+        let file_path = selected.as_ref().and_then(|doc| doc.file_path.clone());
+        let mut search_query = search_query;
+        let mut should_exit = should_exit;
+        move |event| match event {
+            TerminalEvent::Key(KeyEvent {
+                code,
+                kind,
+                modifiers,
+                ..
+            }) if should_process_key_event(kind, code, modifiers) => {
+                if active_pane.get() == DocPane::Search {
+                    match handle_search_input(code, modifiers) {
+                        SearchAction::ClearAndExit => {
+                            search_query.set(String::new());
+                            active_pane.set(DocPane::List);
+                        }
+                        SearchAction::Exit => active_pane.set(DocPane::List),
+                        SearchAction::Quit => should_exit.set(true),
+                        SearchAction::Continue => {}
+                    }
+                    return;
+                }
+
+                // Keys shared by every pane
+                match code {
+                    KeyCode::Char('q') if modifiers.contains(KeyModifiers::CONTROL) => {
+                        should_exit.set(true);
+                        return;
+                    }
+                    KeyCode::Char('/') => {
+                        active_pane.set(DocPane::Search);
+                        return;
+                    }
+                    KeyCode::Tab => {
+                        active_pane.set(active_pane.get().next());
+                        return;
+                    }
+                    KeyCode::BackTab => {
+                        active_pane.set(active_pane.get().prev());
+                        return;
+                    }
+                    KeyCode::Char('E') => {
+                        if let Some(path) = &file_path {
+                            pending_external_edit.set(Some(path.clone()));
+                        }
+                        return;
+                    }
+                    _ => {}
+                }
+
+                match active_pane.get() {
+                    DocPane::List => {
+                        let before = selected_index.get();
+                        match code {
+                            KeyCode::Esc => should_exit.set(true),
+                            KeyCode::Enter | KeyCode::Char('l') => {
+                                active_pane.set(DocPane::Outline)
+                            }
+                            KeyCode::Char('j') | KeyCode::Down => apply_scroll_down(
+                                &mut selected_index,
+                                &mut scroll_offset,
+                                filtered_len,
+                                list_rows,
+                            ),
+                            KeyCode::Char('k') | KeyCode::Up => {
+                                apply_scroll_up(&mut selected_index, &mut scroll_offset, list_rows)
+                            }
+                            KeyCode::Char('g') => {
+                                apply_scroll_to_top(&mut selected_index, &mut scroll_offset)
+                            }
+                            KeyCode::Char('G') => apply_scroll_to_bottom(
+                                &mut selected_index,
+                                &mut scroll_offset,
+                                filtered_len,
+                                list_rows,
+                            ),
+                            KeyCode::PageDown => apply_page_down(
+                                &mut selected_index,
+                                &mut scroll_offset,
+                                filtered_len,
+                                list_rows,
+                            ),
+                            KeyCode::PageUp => {
+                                apply_page_up(&mut selected_index, &mut scroll_offset, list_rows)
+                            }
+                            _ => {}
+                        }
+                        if selected_index.get() != before {
+                            preview_scroll.set(0);
+                        }
+                    }
+                    DocPane::Outline => {
+                        let current = outline_index_for_line(&outline, preview_scroll.get());
+                        let target = match code {
+                            KeyCode::Esc | KeyCode::Char('h') => {
+                                active_pane.set(DocPane::List);
+                                None
+                            }
+                            KeyCode::Enter | KeyCode::Char('l') => {
+                                active_pane.set(DocPane::Preview);
+                                None
+                            }
+                            KeyCode::Char('j') | KeyCode::Down => {
+                                Some(current.map_or(0, |i| i + 1))
+                            }
+                            KeyCode::Char('k') | KeyCode::Up => {
+                                Some(current.unwrap_or(0).saturating_sub(1))
+                            }
+                            KeyCode::Char('g') => Some(0),
+                            KeyCode::Char('G') => Some(outline.len().saturating_sub(1)),
+                            _ => None,
+                        };
+                        if let Some(entry) = target.and_then(|i| outline.get(i)) {
+                            preview_scroll.set(entry.line.min(max_preview_scroll));
+                        }
+                    }
+                    DocPane::Preview => {
+                        let page = list_rows / 2;
+                        let scroll = preview_scroll.get();
+                        match code {
+                            KeyCode::Esc | KeyCode::Char('h') => active_pane.set(DocPane::Outline),
+                            KeyCode::Char('j') | KeyCode::Down => {
+                                preview_scroll.set((scroll + 1).min(max_preview_scroll))
+                            }
+                            KeyCode::Char('k') | KeyCode::Up => {
+                                preview_scroll.set(scroll.saturating_sub(1))
+                            }
+                            KeyCode::Char('g') => preview_scroll.set(0),
+                            KeyCode::Char('G') => preview_scroll.set(max_preview_scroll),
+                            KeyCode::PageDown => {
+                                preview_scroll.set((scroll + page).min(max_preview_scroll))
+                            }
+                            KeyCode::PageUp => preview_scroll.set(scroll.saturating_sub(page)),
+                            _ => {}
+                        }
+                    }
+                    DocPane::Search => {}
+                }
+            }
+            _ => {}
+        }
+    });
+
+    if should_exit.get() {
+        system.exit();
+    }
+
+    let shortcuts = match active_pane.get() {
+        DocPane::Search => search_shortcuts(),
+        _ => doc_browser_shortcuts(),
+    };
+
+    let doc_count = filtered.len();
+    let header_extra: Vec<AnyElement<'static>> = vec![
+        element! {
+            Text(
+                content: format!("{doc_count} docs"),
+                color: theme.text_dimmed,
+            )
+        }
+        .into(),
+    ];
+
+    let placeholder = if is_loading.get() && total_doc_count == 0 {
+        "Loading..."
+    } else if total_doc_count == 0 {
+        "No documents. Create one with: janus doc create <label>"
+    } else if filtered.is_empty() {
+        "No documents match the search"
+    } else {
+        "No document selected"
+    };
+
+    let preview_border = if active_pane.get() == DocPane::Preview {
+        theme.border_focused
+    } else {
+        theme.border
+    };
+
+    element! {
+        ScreenLayout(
+            width: width,
+            height: height,
+            header_subtitle: Some("Docs"),
+            header_extra: Some(header_extra),
+            shortcuts: shortcuts,
+            toast: toast.read().clone(),
+        ) {
+            View(
+                flex_grow: 1.0,
+                flex_direction: FlexDirection::Column,
+                width: 100pct,
+                overflow: Overflow::Hidden,
+            ) {
+                Clickable(
+                    on_click: Some(focus_search_handler.clone()),
+                ) {
+                    View(
+                        width: 100pct,
+                        padding_left: 1,
+                        padding_right: 1,
+                    ) {
+                        SearchBox(
+                            value: Some(search_query),
+                            has_focus: active_pane.get() == DocPane::Search,
+                        )
+                    }
+                }
+                View(
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Row,
+                    width: 100pct,
+                    overflow: Overflow::Hidden,
+                ) {
+                    // Left pane: document list
+                    Clickable(
+                        on_click: Some(focus_list_handler.clone()),
+                    ) {
+                        View(
+                            width: 30pct,
+                            height: 100pct,
+                            flex_shrink: 0.0,
+                        ) {
+                            DocList(
+                                docs: filtered.clone(),
+                                selected_index: selected_index.get(),
+                                scroll_offset: scroll_offset.get(),
+                                has_focus: active_pane.get() == DocPane::List,
+                                visible_height: list_height,
+                                on_row_click: Some(row_click_handler.clone()),
+                            )
+                        }
+                    }
+                    // Middle pane: heading outline
+                    Clickable(
+                        on_click: Some(focus_outline_handler.clone()),
+                    ) {
+                        View(
+                            width: 25pct,
+                            height: 100pct,
+                            flex_shrink: 0.0,
+                        ) {
+                            DocOutline(
+                                outline: outline.clone(),
+                                current: current_heading,
+                                has_focus: active_pane.get() == DocPane::Outline,
+                                visible_height: list_height,
+                            )
+                        }
+                    }
+                    // Right pane: markdown preview
+                    Clickable(
+                        on_click: Some(focus_preview_handler.clone()),
+                        on_scroll_up: Some(preview_scroll_up_handler.clone()),
+                        on_scroll_down: Some(preview_scroll_down_handler.clone()),
+                    ) {
+                        View(
+                            flex_grow: 1.0,
+                            height: 100pct,
+                            flex_direction: FlexDirection::Column,
+                            border_style: BorderStyle::Round,
+                            border_color: preview_border,
+                            padding_left: 1,
+                            padding_right: 1,
+                        ) {
+                            #(selected.as_ref().map(|doc| element! {
+                                View(flex_direction: FlexDirection::Column, flex_shrink: 0.0) {
+                                    Text(
+                                        content: doc.display_title().to_string(),
+                                        color: theme.text,
+                                        weight: Weight::Bold,
+                                    )
+                                    Text(
+                                        content: if doc.tags.is_empty() {
+                                            doc.label.clone()
+                                        } else {
+                                            format!("{}  [{}]", doc.label, doc.tags.join(", "))
+                                        },
+                                        color: theme.id_color,
+                                    )
+                                    #(doc.description.as_ref().map(|description| element! {
+                                        Text(
+                                            content: description.clone(),
+                                            color: theme.text_dimmed,
+                                        )
+                                    }))
+                                }
+                            }))
+                            View(flex_grow: 1.0, overflow: Overflow::Hidden, margin_top: 1) {
+                                TextViewer(
+                                    text: selected.as_ref().map(|doc| doc.body.clone()).unwrap_or_default(),
+                                    scroll_offset: preview_scroll.get(),
+                                    has_focus: active_pane.get() == DocPane::Preview,
+                                    placeholder: Some(placeholder.to_string()),
+                                    markdown: true,
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Data model for the doc browser
+//!
+//! Loads documents with their bodies and heading outlines, and filters them
+//! with the same fuzzy matcher as the issue browser. Pure functions only; the
+//! component owns all state.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::config::DocChunkingConfig;
+use crate::doc::{DocMetadata, chunk_document};
+use crate::parser::split_frontmatter;
+use crate::tui::search::{FilteredItem, filter_items};
+
+/// A heading in a document's outline, one per chunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineEntry {
+    /// Nesting depth (0 for top-level headings and the intro)
+    pub depth: usize,
+    /// Heading text, or "(intro)" for content before the first heading
+    pub heading: String,
+    /// Line in the body where the chunk starts (0-indexed)
+    pub line: usize,
+}
+
+/// A document loaded for browsing
+#[derive(Debug, Clone, Default)]
+pub struct DocEntry {
+    pub label: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub file_path: Option<PathBuf>,
+    /// Markdown body without frontmatter
+    pub body: String,
+    pub outline: Vec<OutlineEntry>,
+}
+
+impl DocEntry {
+    /// Build an entry from parsed metadata and the raw file content
+    pub fn new(metadata: &DocMetadata, content: &str) -> Self {
+        let label = metadata.label().unwrap_or_default().to_string();
+        let body = match split_frontmatter(content) {
+            Ok((_, body)) => body,
+            Err(_) => content.to_string(),
+        };
+        let body = body.trim_start_matches('\n').to_string();
+
+        // One chunk per heading, regardless of the configured chunk size
+        let headings_only = DocChunkingConfig {
+            chunk_size: 0,
+            ..Default::default()
+        };
+
+        // Locate each chunk's heading in the body so the outline can scroll
+        // the preview, independent of how the chunker numbers lines
+        let lines: Vec<&str> = body.lines().collect();
+        let mut next_line = 0;
+        let outline = chunk_document(&label, &body, &headings_only)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|chunk| {
+                let heading = chunk.heading_path.last().cloned();
+                let line = heading
+                    .as_deref()
+                    .and_then(|heading| {
+                        (next_line..lines.len()).find(|&i| heading_text(lines[i]) == Some(heading))
+                    })
+                    .unwrap_or(next_line);
+                next_line = (line + 1).min(lines.len());
+                OutlineEntry {
+                    depth: chunk.heading_path.len().saturating_sub(1),
+                    heading: heading.unwrap_or_else(|| "(intro)".to_string()),
+                    line,
+                }
+            })
+            .collect();
+
+        DocEntry {
+            label,
+            title: metadata.title().map(str::to_string),
+            description: metadata.description.clone(),
+            tags: metadata.tags.clone(),
+            file_path: metadata.file_path().cloned(),
+            body,
+            outline,
+        }
+    }
+
+    /// Title for display, falling back to the label
+    pub fn display_title(&self) -> &str {
+        self.title.as_deref().unwrap_or(&self.label)
+    }
+}
+
+/// Text of an ATX heading line (`## Retry Policy` -> `Retry Policy`)
+fn heading_text(line: &str) -> Option<&str> {
+    let rest = line.trim_start().strip_prefix('#')?;
+    let rest = rest.trim_start_matches('#');
+    (rest.is_empty() || rest.starts_with(' ')).then(|| rest.trim().trim_end_matches('#').trim())
+}
+
+/// Load all documents in the store, sorted by label
+pub async fn load_doc_entries() -> crate::error::Result<Vec<DocEntry>> {
+    let store = crate::store::get_or_init_store().await?;
+    let mut docs: Vec<DocMetadata> = store
+        .docs()
+        .iter()
+        .map(|entry| entry.value().clone())
+        .collect();
+    docs.sort_by(|a, b| a.label().cmp(&b.label()));
+
+    Ok(docs
+        .iter()
+        .filter_map(|doc| {
+            let content = std::fs::read_to_string(doc.file_path()?).ok()?;
+            Some(DocEntry::new(doc, &content))
+        })
+        .collect())
+}
+
+/// Fuzzy-filter documents by label, title, tags, and description
+pub fn filter_docs(docs: &[Arc<DocEntry>], query: &str) -> Vec<FilteredItem<Arc<DocEntry>>> {
+    filter_items(
+        docs,
+        query,
+        |doc| {
+            format!(
+                "{} {} {} {}",
+                doc.label,
+                doc.display_title(),
+                doc.tags.join(" "),
+                doc.description.as_deref().unwrap_or_default()
+            )
+        },
+        |doc| (doc.label.len() + 1, doc.display_title().len()),
+    )
+}
+
+/// Index of the outline entry containing `line`, for tracking the preview
+/// position in the outline pane
+pub fn outline_index_for_line(outline: &[OutlineEntry], line: usize) -> Option<usize> {
+    outline.iter().rposition(|entry| entry.line <= line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::DocLabel;
+
+    const CONTENT: &str = "---\nlabel: ops\n---\n# Operations\n\nDeploys run nightly.\n\n## Retry Policy\n\nFailed jobs retry.\n";
+
+    fn entry() -> DocEntry {
+        let metadata = DocMetadata {
+            label: Some(DocLabel::new_unchecked("ops")),
+            title: Some("Operations".to_string()),
+            tags: vec!["runbook".to_string()],
+            ..Default::default()
+        };
+        DocEntry::new(&metadata, CONTENT)
+    }
+
+    #[test]
+    fn test_doc_entry_outline_lines_match_body() {
+        let entry = entry();
+        assert!(entry.body.starts_with("# Operations"));
+
+        let headings: Vec<_> = entry
+            .outline
+            .iter()
+            .map(|e| (e.depth, e.heading.as_str()))
+            .collect();
+        assert_eq!(headings, vec![(0, "Operations"), (1, "Retry Policy")]);
+
+        let lines: Vec<&str> = entry.body.lines().collect();
+        for outline in &entry.outline {
+            assert!(lines[outline.line].contains(&outline.heading));
+        }
+    }
+
+    #[test]
+    fn test_filter_docs() {
+        let docs = vec![Arc::new(entry())];
+        assert_eq!(filter_docs(&docs, "").len(), 1);
+        assert_eq!(filter_docs(&docs, "runbook").len(), 1);
+        assert!(filter_docs(&docs, "zzzz").is_empty());
+    }
+
+    #[test]
+    fn test_outline_index_for_line() {
+        let outline = entry().outline;
+        assert_eq!(outline_index_for_line(&outline, 0), Some(0));
+        assert_eq!(
+            outline_index_for_line(&outline, outline[1].line + 1),
+            Some(1)
+        );
+        assert_eq!(outline_index_for_line(&[], 3), None);
+    }
+}
@@ -1,13 +1,19 @@
 //! TUI module for interactive terminal interfaces
 //!
-//! This module provides three main views:
+//! This module provides four main views:
 //! - `view` - Issue browser with fuzzy search and inline editing
 //! - `board` - Kanban board with column-based ticket organization
 //! - `remote` - Remote TUI for managing local tickets and remote issues
+//! - `doc_view` - Doc browser with fuzzy search, outline, and preview
+//!
+//! `app` hosts the browser, board, plan HUD, and remote view as tabs of one
+//! application (`janus tui`).
 
 pub mod analytics;
+pub mod app;
 pub mod board;
 pub mod components;
+pub mod doc_view;
 pub mod edit;
 pub mod edit_state;
 pub mod handlers;
@@ -19,6 +25,7 @@ pub mod remote;
 pub mod repository;
 pub mod screen_base;
 pub mod search;
+pub mod search_history;
 pub mod search_orchestrator;
 pub mod services;
 pub mod state;
@@ -27,7 +34,9 @@ pub mod theme;
 pub mod view;
 
 pub use analytics::{StatusCounts, TicketAnalytics};
+pub use app::{TuiApp, TuiAppProps};
 pub use board::{KanbanBoard, KanbanBoardProps};
+pub use doc_view::{DocBrowser, DocBrowserProps};
 pub use edit::{
     EditField, EditForm, EditFormOverlay, EditFormProps, EditResult, extract_body_for_edit,
 };
@@ -37,7 +46,7 @@ pub use remote::RemoteTui;
 pub use repository::{InitResult, TicketRepository};
 pub use screen_base::{
     ScreenLayout, ScreenLayoutProps, ScreenState, calculate_list_height, handle_screen_exit,
-    should_process_key_event, use_screen_state,
+    should_process_key_event, use_screen_size, use_screen_state,
 };
 pub use search::{FilteredItem, FilteredTicket, filter_items, filter_tickets};
 pub use search_orchestrator::{SearchState, compute_filtered_tickets};
@@ -16,7 +16,7 @@ use crate::tui::components::{
     Clickable, ModalContainer, ModalOverlay, ShortcutsBuilder, TicketDetail, Toast,
 };
 use crate::tui::hooks::use_store_watcher;
-use crate::tui::screen_base::{ScreenLayout, should_process_key_event};
+use crate::tui::screen_base::{ScreenLayout, should_process_key_event, use_screen_size};
 use crate::tui::services::ExternalEditor;
 use crate::tui::theme::theme;
 use crate::types::TicketStatus;
@@ -43,7 +43,7 @@ pub struct PlanHudProps {
 /// Main Plan HUD component
 #[component]
 pub fn PlanHud<'a>(props: &PlanHudProps, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
-    let (width, height) = hooks.use_terminal_size();
+    let (width, height) = use_screen_size(&mut hooks);
     let mut system = hooks.use_context_mut::<SystemContext>();
 
     // Core state
@@ -338,7 +338,7 @@ pub fn PlanHud<'a>(props: &PlanHudProps, mut hooks: Hooks) -> impl Into<AnyEleme
                     kind,
                     modifiers,
                     ..
-                }) if should_process_key_event(kind) => {
+                }) if should_process_key_event(kind, code, modifiers) => {
                     // Detail modal: Esc closes it
                     if is_showing_detail {
                         match code {
@@ -719,6 +719,7 @@ mod tests {
                 status: TicketStatus::New,
                 completed_count: 0,
                 total_count: ticket_count,
+                ..Default::default()
             },
             phase_statuses,
             tickets,
@@ -753,6 +754,8 @@ mod tests {
                 status: TicketStatus::New,
                 completed_count: 0,
                 total_count: 2,
+                completed_points: 0,
+                total_points: 6,
             },
             PhaseStatus {
                 phase_number: "2".to_string(),
@@ -760,6 +763,8 @@ mod tests {
                 status: TicketStatus::New,
                 completed_count: 0,
                 total_count: 1,
+                completed_points: 0,
+                total_points: 3,
             },
         ];
         let state = make_test_state(false, 3, vec![vec![0, 1], vec![2]], phase_statuses);
@@ -782,6 +787,8 @@ mod tests {
                 status: TicketStatus::Complete,
                 completed_count: 2,
                 total_count: 2,
+                completed_points: 6,
+                total_points: 6,
             },
             PhaseStatus {
                 phase_number: "2".to_string(),
@@ -789,6 +796,8 @@ mod tests {
                 status: TicketStatus::New,
                 completed_count: 0,
                 total_count: 1,
+                completed_points: 0,
+                total_points: 3,
             },
         ];
         let state = make_test_state(false, 3, vec![vec![0, 1], vec![2]], phase_statuses);
@@ -98,6 +98,8 @@ fn render_remote_detail(
         let issue_priority = issue.priority;
         let issue_assignee = issue.assignee.clone();
         let issue_updated = issue.updated_at.clone();
+        let issue_project = issue.project.clone();
+        let issue_cycle = issue.cycle.clone();
         let issue_body = issue.body.clone();
 
         // Find linked local ticket
@@ -145,6 +147,12 @@ fn render_remote_detail(
                         Text(content: format!("Priority: {:?}", issue_priority), color: theme.text)
                         Text(content: format!("Assignee: {:?}", issue_assignee), color: theme.text)
                         Text(content: format!("Updated: {}", &issue_updated[..10.min(issue_updated.len())]), color: theme.text)
+                        #(issue_project.as_ref().map(|project| element! {
+                            Text(content: format!("Project: {}", project), color: theme.text)
+                        }))
+                        #(issue_cycle.as_ref().map(|cycle| element! {
+                            Text(content: format!("Cycle: {}", cycle), color: theme.text)
+                        }))
                         #(linked_ticket_id.as_ref().map(|linked_id| element! {
                             Text(content: format!("Linked: {}", linked_id), color: Color::Cyan)
                         }))
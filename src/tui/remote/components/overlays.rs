@@ -1,10 +1,12 @@
 //! Remote TUI modal overlay components
 //!
-//! Contains the modal overlay rendering for filter, help, error, confirm, and sync preview modals.
+//! Contains the modal overlay rendering for filter, help, error, confirm, create issue,
+//! and sync preview modals.
 
 use iocraft::prelude::*;
 
 use crate::tui::remote::confirm_modal::{ConfirmDialog, ConfirmDialogState};
+use crate::tui::remote::create_modal::{CreateIssueModal, CreateIssueState};
 use crate::tui::remote::error_modal::ErrorDetailModal;
 use crate::tui::remote::filter_modal::{FilterModal, FilterState};
 use crate::tui::remote::help_modal::HelpModal;
@@ -33,6 +35,10 @@ pub struct ModalOverlaysProps {
     pub sync_preview_state: Option<SyncPreviewState>,
     /// Confirm dialog state (Some if modal should be shown)
     pub confirm_dialog_state: Option<ConfirmDialogState>,
+    /// Create issue form state (shown while it holds a form)
+    pub create_form: Option<State<Option<CreateIssueState>>>,
+    /// Provider name shown in the create issue form title
+    pub provider: String,
 }
 
 /// Modal overlays container for filter, help, and error modals
@@ -112,6 +118,13 @@ pub fn ModalOverlays(props: &ModalOverlaysProps) -> impl Into<AnyElement<'static
                 let message = state.message.clone();
                 element! { ConfirmDialog(message: message, on_close: None) }
             }))
+
+            // Create issue modal - rendered directly since CreateIssueModal handles its own positioning via ModalOverlay
+            #(props.create_form.filter(|form| form.read().is_some()).map(|form| {
+                element! {
+                    CreateIssueModal(form: Some(form), provider: props.provider.clone(), on_close: None)
+                }
+            }))
         }
     }
 }
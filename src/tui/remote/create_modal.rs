@@ -0,0 +1,213 @@
+//! Form for creating a new remote issue from the remote TUI
+//!
+//! Opened with `c`. The issue is created directly on the active provider,
+//! with optional labels, and can be adopted as a local ticket in the same
+//! step.
+
+use iocraft::prelude::*;
+
+use crate::tui::components::{
+    ModalBorderColor, ModalContainer, ModalHeight, ModalOverlay, ModalWidth,
+};
+use crate::tui::theme::theme;
+
+/// Fields of the create form, in tab order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CreateField {
+    #[default]
+    Title,
+    Body,
+    Labels,
+    Adopt,
+}
+
+impl CreateField {
+    pub fn next(self) -> Self {
+        match self {
+            CreateField::Title => CreateField::Body,
+            CreateField::Body => CreateField::Labels,
+            CreateField::Labels => CreateField::Adopt,
+            CreateField::Adopt => CreateField::Title,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            CreateField::Title => CreateField::Adopt,
+            CreateField::Body => CreateField::Title,
+            CreateField::Labels => CreateField::Body,
+            CreateField::Adopt => CreateField::Labels,
+        }
+    }
+}
+
+/// Create form state
+#[derive(Debug, Clone, Default)]
+pub struct CreateIssueState {
+    pub title: String,
+    pub body: String,
+    /// Comma-separated label names
+    pub labels: String,
+    /// Whether to adopt the new issue as a local ticket
+    pub adopt: bool,
+    pub focused: CreateField,
+}
+
+impl CreateIssueState {
+    pub fn new() -> Self {
+        Self {
+            adopt: true,
+            ..Default::default()
+        }
+    }
+
+    /// Labels entered in the form, trimmed and without duplicates
+    pub fn label_list(&self) -> Vec<String> {
+        let mut labels: Vec<String> = Vec::new();
+        for label in self.labels.split(',').map(str::trim) {
+            if !label.is_empty() && !labels.iter().any(|l| l.eq_ignore_ascii_case(label)) {
+                labels.push(label.to_string());
+            }
+        }
+        labels
+    }
+
+    /// Whether the form can be submitted
+    pub fn is_valid(&self) -> bool {
+        !self.title.trim().is_empty()
+    }
+}
+
+/// Apply `update` to the form held in `state`, if the form is open
+fn update_form(
+    mut state: State<Option<CreateIssueState>>,
+    update: impl FnOnce(&mut CreateIssueState),
+) {
+    let mut form = state.read().clone();
+    if let Some(form_ref) = form.as_mut() {
+        update(form_ref);
+        state.set(form);
+    }
+}
+
+/// Props for the create issue modal
+#[derive(Default, Props)]
+pub struct CreateIssueModalProps {
+    /// Form state, updated as the user types
+    pub form: Option<State<Option<CreateIssueState>>>,
+    /// Name of the provider the issue will be created on
+    pub provider: String,
+    /// Handler invoked when modal is closed via X button
+    pub on_close: Option<Handler<()>>,
+}
+
+/// Create issue modal component
+#[component]
+pub fn CreateIssueModal<'a>(
+    props: &CreateIssueModalProps,
+    _hooks: Hooks,
+) -> impl Into<AnyElement<'a>> {
+    let theme = theme();
+    let Some(form_state) = props.form else {
+        return element!(View).into_any();
+    };
+    let Some(form) = form_state.read().clone() else {
+        return element!(View).into_any();
+    };
+
+    let label_color = |field: CreateField| {
+        if form.focused == field {
+            theme.highlight
+        } else {
+            theme.text_dimmed
+        }
+    };
+    let border_color = |field: CreateField| {
+        if form.focused == field {
+            theme.border_focused
+        } else {
+            theme.border
+        }
+    };
+
+    element! {
+        ModalOverlay(show_backdrop: true) {
+            ModalContainer(
+                width: Some(ModalWidth::Fixed(70)),
+                height: Some(ModalHeight::Fixed(22)),
+                border_color: Some(ModalBorderColor::Info),
+                title: Some(format!("New {} Issue", props.provider)),
+                footer_text: Some("[Tab] Next  [C-s] Create  [Esc] Cancel".to_string()),
+                on_close: props.on_close.clone(),
+            ) {
+                Text(content: "Title", color: label_color(CreateField::Title))
+                View(
+                    width: 100pct,
+                    height: 3,
+                    border_style: BorderStyle::Round,
+                    border_color: border_color(CreateField::Title),
+                    padding_left: 1,
+                    padding_right: 1,
+                ) {
+                    TextInput(
+                        has_focus: form.focused == CreateField::Title,
+                        value: form.title.clone(),
+                        on_change: move |value| update_form(form_state, |f| f.title = value),
+                        color: Some(theme.text),
+                    )
+                }
+
+                Text(content: "Body", color: label_color(CreateField::Body))
+                View(
+                    width: 100pct,
+                    height: 8,
+                    border_style: BorderStyle::Round,
+                    border_color: border_color(CreateField::Body),
+                    padding_left: 1,
+                    padding_right: 1,
+                    overflow: Overflow::Hidden,
+                ) {
+                    TextInput(
+                        has_focus: form.focused == CreateField::Body,
+                        value: form.body.clone(),
+                        on_change: move |value| update_form(form_state, |f| f.body = value),
+                        multiline: true,
+                        color: Some(theme.text),
+                    )
+                }
+
+                Text(content: "Labels (comma-separated)", color: label_color(CreateField::Labels))
+                View(
+                    width: 100pct,
+                    height: 3,
+                    border_style: BorderStyle::Round,
+                    border_color: border_color(CreateField::Labels),
+                    padding_left: 1,
+                    padding_right: 1,
+                ) {
+                    TextInput(
+                        has_focus: form.focused == CreateField::Labels,
+                        value: form.labels.clone(),
+                        on_change: move |value| update_form(form_state, |f| f.labels = value),
+                        color: Some(theme.text),
+                    )
+                }
+
+                View(
+                    width: 100pct,
+                    margin_top: 1,
+                    background_color: if form.focused == CreateField::Adopt { Some(theme.highlight) } else { None },
+                ) {
+                    Text(
+                        content: format!(
+                            "[{}] Adopt as a local ticket",
+                            if form.adopt { "x" } else { " " }
+                        ),
+                        color: if form.focused == CreateField::Adopt { theme.highlight_text } else { theme.text },
+                    )
+                }
+            }
+        }
+    }
+    .into_any()
+}
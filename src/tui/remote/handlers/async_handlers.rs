@@ -9,23 +9,27 @@
 use iocraft::hooks::UseAsyncHandler;
 use iocraft::prelude::{Handler, Hooks, State};
 
-use crate::remote::{Platform, RemoteIssue, RemoteProvider, RemoteQuery};
+use crate::remote::{Platform, RemoteIssue, RemoteProvider, RemoteQuery, cache};
+use crate::sla::format_duration;
 use crate::ticket::get_all_tickets_from_disk;
 use crate::types::TicketMetadata;
 
+use super::super::create_modal::CreateIssueState;
 use super::super::error_toast::Toast;
 use super::super::link_mode::LinkSource;
 use super::super::operations::{
-    apply_sync_change_to_local, apply_sync_change_to_remote, link_ticket_to_issue,
-    push_tickets_to_remote, unlink_ticket,
+    apply_ticket_sync_changes, create_remote_issue, link_ticket_to_issue, push_tickets_to_remote,
+    unlink_ticket,
 };
 use super::super::state::{NavigationData, ViewDisplayData};
-use super::super::sync_preview::{SyncDirection, SyncPreviewState};
+use super::super::sync_preview::{SyncChange, SyncPreviewState};
 
 /// Result from async fetch operation with pagination metadata
 #[derive(Clone)]
 pub enum FetchResult {
     Success(crate::remote::PaginatedResult<RemoteIssue>),
+    /// The remote could not be reached; a cached copy fetched at the given time
+    Offline(crate::remote::PaginatedResult<RemoteIssue>, jiff::Timestamp),
     Error(String, String), // (error_type, error_message)
 }
 
@@ -76,6 +80,19 @@ pub fn create_fetch_handler(
                     // Store result for status bar display
                     last_fetch_result.set(Some((FetchResult::Success(paginated), is_search)));
                 }
+                FetchResult::Offline(paginated, fetched_at) => {
+                    remote_issues.set(paginated.items.clone());
+                    let age = jiff::Timestamp::now().duration_since(fetched_at);
+                    toast.set(Some(Toast::warning(format!(
+                        "Remote unreachable; showing {} cached issues from {} ago",
+                        paginated.items.len(),
+                        format_duration(age)
+                    ))));
+                    last_fetch_result.set(Some((
+                        FetchResult::Offline(paginated, fetched_at),
+                        is_search,
+                    )));
+                }
                 FetchResult::Error(err_type, err_msg) => {
                     last_error.set(Some((err_type.clone(), err_msg.clone())));
                     toast.set(Some(Toast::error(format!(
@@ -244,6 +261,60 @@ pub fn create_link_handler(
     })
 }
 
+/// Factory for creating the create issue handler
+pub fn create_create_issue_handler(
+    hooks: &mut Hooks,
+    local_tickets: &State<Vec<TicketMetadata>>,
+    fetch_handler: &Handler<(Platform, RemoteQuery)>,
+    toast: &State<Option<Toast>>,
+    last_error: &State<Option<(String, String)>>,
+) -> Handler<(Platform, CreateIssueState, RemoteQuery)> {
+    let local_tickets = *local_tickets;
+    let fetch_handler = fetch_handler.clone();
+    let toast = *toast;
+    let last_error = *last_error;
+
+    hooks.use_async_handler(
+        move |(platform, form, query): (Platform, CreateIssueState, RemoteQuery)| {
+            let mut local_tickets = local_tickets;
+            let fetch_handler = fetch_handler.clone();
+            let mut toast = toast;
+            let mut last_error = last_error;
+
+            async move {
+                let result = create_remote_issue(
+                    platform,
+                    &form.title,
+                    &form.body,
+                    &form.label_list(),
+                    form.adopt,
+                )
+                .await;
+
+                match result {
+                    Ok(created) => {
+                        let msg = match &created.ticket_id {
+                            Some(id) => {
+                                format!("Created {} and adopted as {id}", created.remote_ref)
+                            }
+                            None => format!("Created {}", created.remote_ref),
+                        };
+                        toast.set(Some(Toast::info(msg)));
+                        if created.ticket_id.is_some() {
+                            local_tickets.set(get_all_tickets_from_disk().items);
+                        }
+                        fetch_handler((platform, query));
+                    }
+                    Err(e) => {
+                        last_error.set(Some(("Create Error".to_string(), e.to_string())));
+                        toast.set(Some(Toast::error(format!("Create failed: {e}"))));
+                    }
+                }
+            }
+        },
+    )
+}
+
 /// Factory for creating the unlink handler
 pub fn create_unlink_handler(
     hooks: &mut Hooks,
@@ -326,58 +397,55 @@ async fn fetch_remote_issues_with_query(platform: Platform, query: RemoteQuery)
     };
 
     let timeout = config.remote_timeout();
-    let is_search_mode = query.search_text.is_some();
 
     let fetch_operation = async {
-        match platform {
-            Platform::GitHub => match crate::remote::github::GitHubProvider::from_config(&config) {
-                Ok(provider) => {
-                    if is_search_mode {
-                        let text = query.search_text.as_ref().unwrap();
-                        provider.search_remote(text, &query).await
-                    } else {
-                        provider.browse_issues(&query).await
-                    }
-                }
-                Err(e) => Err(e),
-            },
-            Platform::Linear => match crate::remote::linear::LinearProvider::from_config(&config) {
-                Ok(provider) => {
-                    if is_search_mode {
-                        let text = query.search_text.as_ref().unwrap();
-                        provider.search_remote(text, &query).await
-                    } else {
-                        provider.browse_issues(&query).await
-                    }
-                }
-                Err(e) => Err(e),
-            },
+        let provider = crate::remote::create_provider(&platform, &config)?;
+        match query.search_text.as_deref() {
+            Some(text) => provider.search_remote(text, &query).await,
+            None => provider.browse_issues(&query).await,
         }
     };
 
-    let result = match tokio::time::timeout(timeout, fetch_operation).await {
-        Ok(result) => result,
-        Err(_) => {
-            return FetchResult::Error(
-                "TimeoutError".to_string(),
-                format!(
-                    "Remote operation timed out after {} seconds",
-                    timeout.as_secs()
-                ),
-            );
-        }
+    let (result, timed_out) = match tokio::time::timeout(timeout, fetch_operation).await {
+        Ok(result) => (result, false),
+        Err(_) => (
+            Err(crate::error::JanusError::RemoteTimeout {
+                seconds: timeout.as_secs(),
+            }),
+            true,
+        ),
     };
 
+    // Only first pages are cached; later pages continue a live listing
+    let search = query.search_text.as_deref();
+    let first_page = query.cursor.is_none();
     match result {
-        Ok(paginated) => FetchResult::Success(paginated),
+        Ok(paginated) => {
+            if first_page {
+                cache::store_list(platform, search, &paginated);
+            }
+            FetchResult::Success(paginated)
+        }
         Err(e) => {
-            // Check if it's a timeout error from the inner retry mechanism
+            if first_page
+                && cache::is_unreachable(&e)
+                && let Some(cached) = cache::load_list(platform, search)
+            {
+                return FetchResult::Offline(cached.value.into_paginated(), cached.fetched_at);
+            }
+            // Check if it's a timeout error, from the overall timeout or the
+            // inner retry mechanism
             let error_msg = if let crate::error::JanusError::RemoteTimeout { seconds } = &e {
                 format!("Remote operation timed out after {seconds} seconds")
             } else {
                 e.to_string()
             };
-            FetchResult::Error("FetchError".to_string(), error_msg)
+            let error_type = if timed_out {
+                "TimeoutError"
+            } else {
+                "FetchError"
+            };
+            FetchResult::Error(error_type.to_string(), error_msg)
         }
     }
 }
@@ -403,30 +471,29 @@ pub fn create_sync_apply_handler(
             let mut last_error = last_error;
 
             async move {
-                let accepted = state.accepted_changes();
+                // Group accepted changes by ticket so the sync hooks run once per ticket
+                let mut by_ticket: Vec<(&str, &str, Vec<&SyncChange>)> = Vec::new();
+                for change_ctx in state.accepted_changes() {
+                    match by_ticket
+                        .iter_mut()
+                        .find(|(id, _, _)| *id == change_ctx.ticket_id)
+                    {
+                        Some((_, _, changes)) => changes.push(&change_ctx.change),
+                        None => by_ticket.push((
+                            &change_ctx.ticket_id,
+                            &change_ctx.remote_ref,
+                            vec![&change_ctx.change],
+                        )),
+                    }
+                }
+
                 let mut applied = 0;
                 let mut errors = Vec::new();
-
-                for change_ctx in accepted {
-                    let result = match change_ctx.change.direction {
-                        SyncDirection::RemoteToLocal => {
-                            apply_sync_change_to_local(&change_ctx.ticket_id, &change_ctx.change)
-                                .await
-                        }
-                        SyncDirection::LocalToRemote => {
-                            apply_sync_change_to_remote(
-                                &change_ctx.remote_ref,
-                                &change_ctx.change,
-                                platform,
-                            )
-                            .await
-                        }
-                    };
-
-                    match result {
-                        Ok(()) => applied += 1,
-                        Err(e) => errors.push(e.to_string()),
-                    }
+                for (ticket_id, remote_ref, changes) in by_ticket {
+                    let (count, ticket_errors) =
+                        apply_ticket_sync_changes(ticket_id, remote_ref, &changes, platform).await;
+                    applied += count;
+                    errors.extend(ticket_errors);
                 }
 
                 if !errors.is_empty() {
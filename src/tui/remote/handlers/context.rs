@@ -18,6 +18,7 @@ use crate::tui::search_orchestrator::SearchState as SearchOrchestrator;
 use crate::types::TicketMetadata;
 
 use super::super::confirm_modal::ConfirmDialogState;
+use super::super::create_modal::CreateIssueState;
 use super::super::error_toast::Toast;
 use super::super::filter_modal::FilterState;
 use super::super::link_mode::LinkModeState;
@@ -168,6 +169,7 @@ pub struct ModalState<'a> {
     pub link_mode: &'a mut State<Option<LinkModeState>>,
     pub sync_preview: &'a mut State<Option<SyncPreviewState>>,
     pub confirm_dialog: &'a mut State<Option<ConfirmDialogState>>,
+    pub create_form: &'a mut State<Option<CreateIssueState>>,
     /// Modal visibility state (grouped)
     pub visibility: &'a mut State<ModalVisibilityData>,
 }
@@ -253,6 +255,7 @@ pub struct AsyncHandlers<'a> {
     pub sync_apply_handler: &'a Handler<(SyncPreviewState, Platform, RemoteQuery)>,
     pub link_handler: &'a Handler<LinkSource>,
     pub unlink_handler: &'a Handler<Vec<String>>,
+    pub create_issue_handler: &'a Handler<(Platform, CreateIssueState, RemoteQuery)>,
 }
 
 /// Main context struct holding grouped state for event handlers
@@ -289,6 +292,7 @@ impl<'a> HandlerContext<'a> {
             link_mode_active: self.modals.link_mode.read().is_some(),
             filter_modal_active: self.filters.filter_modal.read().is_some(),
             confirm_dialog_active: self.modals.confirm_dialog.read().is_some(),
+            create_form_field: self.modals.create_form.read().as_ref().map(|f| f.focused),
             search_focused: self.search.is_focused(),
             detail_pane_focused: self.view_state.detail_pane_focused(),
         }
@@ -7,6 +7,8 @@
 
 use iocraft::prelude::{KeyCode, KeyModifiers};
 
+use crate::tui::remote::create_modal::CreateField;
+
 // ============================================================================
 // Action enum
 // ============================================================================
@@ -75,6 +77,14 @@ pub enum RemoteAction {
     CancelLinkMode,
     LinkConfirm,
 
+    // Create issue form
+    ShowCreateIssue,
+    CreateNextField,
+    CreatePrevField,
+    CreateToggleAdopt,
+    SubmitCreateIssue,
+    CancelCreateIssue,
+
     // Filter modal
     FilterTab,
     FilterBackTab,
@@ -114,6 +124,8 @@ pub struct ModalStateSnapshot {
     pub link_mode_active: bool,
     pub filter_modal_active: bool,
     pub confirm_dialog_active: bool,
+    /// Focused field while the create issue form is open
+    pub create_form_field: Option<CreateField>,
     pub search_focused: bool,
     pub detail_pane_focused: bool,
 }
@@ -148,33 +160,39 @@ pub fn key_to_action(
         return confirm_dialog_key(code);
     }
 
-    // 4. Sync preview – captures all keys
+    // 4. Create issue form – form keys are intercepted; typing falls through
+    //    to the focused text input (returns None).
+    if let Some(field) = state.create_form_field {
+        return create_form_key(code, modifiers, field);
+    }
+
+    // 5. Sync preview – captures all keys
     if state.sync_preview_active {
         return sync_preview_key(code);
     }
 
-    // 5. Filter modal – captures all keys
+    // 6. Filter modal – captures all keys
     if state.filter_modal_active {
         return filter_modal_key(code);
     }
 
-    // 6. Link mode – captures all keys
+    // 7. Link mode – captures all keys
     if state.link_mode_active {
         return link_mode_key(code);
     }
 
-    // 7. Search mode – Esc/Enter/Ctrl-Q are intercepted; everything else
+    // 8. Search mode – Esc/Enter/Ctrl-Q are intercepted; everything else
     //    falls through to the search-box component (returns None).
     if state.search_focused {
         return search_key_to_action(code, modifiers);
     }
 
-    // 8. Detail pane focused
+    // 9. Detail pane focused
     if state.detail_pane_focused {
         return detail_pane_key(code);
     }
 
-    // 9. Normal mode
+    // 10. Normal mode
     normal_key_to_action(code, modifiers)
 }
 
@@ -212,6 +230,31 @@ fn confirm_dialog_key(code: KeyCode) -> Option<RemoteAction> {
     }
 }
 
+/// Keys recognised while the create issue form is open.
+///
+/// Enter submits from the single-line fields but inserts a newline in the
+/// body, where Ctrl+S submits instead.
+fn create_form_key(
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    field: CreateField,
+) -> Option<RemoteAction> {
+    match (code, modifiers) {
+        (KeyCode::Esc, _) => Some(RemoteAction::CancelCreateIssue),
+        (KeyCode::Tab, _) => Some(RemoteAction::CreateNextField),
+        (KeyCode::BackTab, _) => Some(RemoteAction::CreatePrevField),
+        (KeyCode::Char('s'), m) if m.contains(KeyModifiers::CONTROL) => {
+            Some(RemoteAction::SubmitCreateIssue)
+        }
+        (KeyCode::Enter, _) if field != CreateField::Body => Some(RemoteAction::SubmitCreateIssue),
+        (KeyCode::Char(' '), _) if field == CreateField::Adopt => {
+            Some(RemoteAction::CreateToggleAdopt)
+        }
+        _ if field == CreateField::Adopt => Some(RemoteAction::Consumed),
+        _ => None, // let the focused text input handle it
+    }
+}
+
 fn sync_preview_key(code: KeyCode) -> Option<RemoteAction> {
     match code {
         KeyCode::Char('y') => Some(RemoteAction::SyncAccept),
@@ -312,6 +355,7 @@ fn normal_key_to_action(code: KeyCode, modifiers: KeyModifiers) -> Option<Remote
         (KeyCode::Char('p'), KeyModifiers::NONE) => Some(RemoteAction::PushLocal),
         (KeyCode::Char('u'), KeyModifiers::NONE) => Some(RemoteAction::UnlinkLocal),
         (KeyCode::Char('a'), KeyModifiers::NONE) => Some(RemoteAction::Adopt),
+        (KeyCode::Char('c'), KeyModifiers::NONE) => Some(RemoteAction::ShowCreateIssue),
 
         // Modals
         (KeyCode::Char('f'), KeyModifiers::NONE) => Some(RemoteAction::ShowFilterModal),
@@ -430,6 +474,10 @@ mod tests {
             key_to_action(KeyCode::Char('u'), KeyModifiers::NONE, &s),
             Some(RemoteAction::UnlinkLocal)
         );
+        assert_eq!(
+            key_to_action(KeyCode::Char('c'), KeyModifiers::NONE, &s),
+            Some(RemoteAction::ShowCreateIssue)
+        );
     }
 
     // ====================================================================
@@ -575,6 +623,57 @@ mod tests {
     // Confirm dialog
     // ====================================================================
 
+    #[test]
+    fn test_key_to_action_create_form() {
+        let title = ModalStateSnapshot {
+            create_form_field: Some(CreateField::Title),
+            ..default_snapshot()
+        };
+        let body = ModalStateSnapshot {
+            create_form_field: Some(CreateField::Body),
+            ..default_snapshot()
+        };
+        let adopt = ModalStateSnapshot {
+            create_form_field: Some(CreateField::Adopt),
+            ..default_snapshot()
+        };
+
+        assert_eq!(
+            key_to_action(KeyCode::Tab, KeyModifiers::NONE, &title),
+            Some(RemoteAction::CreateNextField)
+        );
+        assert_eq!(
+            key_to_action(KeyCode::Enter, KeyModifiers::NONE, &title),
+            Some(RemoteAction::SubmitCreateIssue)
+        );
+        // Typing goes to the text input
+        assert_eq!(
+            key_to_action(KeyCode::Char('q'), KeyModifiers::NONE, &title),
+            None
+        );
+        // Enter is a newline in the body; Ctrl+S submits from anywhere
+        assert_eq!(
+            key_to_action(KeyCode::Enter, KeyModifiers::NONE, &body),
+            None
+        );
+        assert_eq!(
+            key_to_action(KeyCode::Char('s'), KeyModifiers::CONTROL, &body),
+            Some(RemoteAction::SubmitCreateIssue)
+        );
+        assert_eq!(
+            key_to_action(KeyCode::Char(' '), KeyModifiers::NONE, &adopt),
+            Some(RemoteAction::CreateToggleAdopt)
+        );
+        assert_eq!(
+            key_to_action(KeyCode::Char('x'), KeyModifiers::NONE, &adopt),
+            Some(RemoteAction::Consumed)
+        );
+        assert_eq!(
+            key_to_action(KeyCode::Esc, KeyModifiers::NONE, &adopt),
+            Some(RemoteAction::CancelCreateIssue)
+        );
+    }
+
     #[test]
     fn test_key_to_action_confirm_dialog() {
         let s = ModalStateSnapshot {
@@ -154,6 +154,28 @@ fn dispatch_action(ctx: &mut HandlerContext<'_>, action: keymap::RemoteAction) {
             ctx.modals.toggle_error();
         }
 
+        // ==================================================================
+        // Create issue form
+        // ==================================================================
+        RemoteAction::ShowCreateIssue => {
+            remote_ops::open_create_form(ctx);
+        }
+        RemoteAction::CreateNextField => {
+            remote_ops::update_create_form(ctx, |f| f.focused = f.focused.next());
+        }
+        RemoteAction::CreatePrevField => {
+            remote_ops::update_create_form(ctx, |f| f.focused = f.focused.prev());
+        }
+        RemoteAction::CreateToggleAdopt => {
+            remote_ops::update_create_form(ctx, |f| f.adopt = !f.adopt);
+        }
+        RemoteAction::SubmitCreateIssue => {
+            remote_ops::submit_create_form(ctx);
+        }
+        RemoteAction::CancelCreateIssue => {
+            ctx.modals.create_form.set(None);
+        }
+
         // ==================================================================
         // Filter modal
         // ==================================================================
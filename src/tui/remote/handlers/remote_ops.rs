@@ -1,4 +1,4 @@
-//! Remote view operations (adopt)
+//! Remote view operations (adopt, create)
 
 use std::collections::HashSet;
 
@@ -6,6 +6,7 @@ use iocraft::prelude::KeyCode;
 
 use crate::ticket::get_all_tickets_from_disk;
 
+use super::super::create_modal::CreateIssueState;
 use super::super::error_toast::Toast;
 use super::super::operations;
 
@@ -74,3 +75,42 @@ fn handle_adopt(ctx: &mut HandlerContext<'_>) {
         }
     }
 }
+
+/// Open the create issue form with empty fields
+pub fn open_create_form(ctx: &mut HandlerContext<'_>) {
+    ctx.modals.create_form.set(Some(CreateIssueState::new()));
+}
+
+/// Apply `update` to the open create issue form
+pub fn update_create_form(
+    ctx: &mut HandlerContext<'_>,
+    update: impl FnOnce(&mut CreateIssueState),
+) {
+    let mut form = ctx.modals.create_form.read().clone();
+    if let Some(form_ref) = form.as_mut() {
+        update(form_ref);
+        ctx.modals.create_form.set(form);
+    }
+}
+
+/// Close the form and create the issue on the active provider
+///
+/// The form stays open when the title is empty.
+pub fn submit_create_form(ctx: &mut HandlerContext<'_>) {
+    let Some(form) = ctx.modals.create_form.read().clone() else {
+        return;
+    };
+    if !form.is_valid() {
+        ctx.modals
+            .toast
+            .set(Some(Toast::error("A title is required to create an issue")));
+        return;
+    }
+
+    let platform = ctx.filters.provider();
+    ctx.modals.create_form.set(None);
+    ctx.modals.toast.set(Some(Toast::info(format!(
+        "Creating issue on {platform}..."
+    ))));
+    ctx.handlers.create_issue_handler.clone()((platform, form, ctx.filters.active_filters()));
+}
@@ -7,6 +7,7 @@ use futures::stream::{self, StreamExt};
 use iocraft::hooks::UseAsyncHandler;
 use iocraft::prelude::{Handler, Hooks, State};
 
+use crate::config::Config;
 use crate::remote::{Platform, RemoteQuery};
 
 use super::super::error_toast::Toast;
@@ -62,26 +63,32 @@ pub fn create_sync_fetch_handler(
                 .collect()
                 .await;
 
+            // A config that fails to load already failed each fetch above
+            let status_mapping = Config::load().map(|c| c.status_mapping).unwrap_or_default();
+
             for (ticket_id, fetch_result) in results {
                 match fetch_result {
-                    Ok((metadata, issue)) => match build_sync_changes(&metadata, &issue) {
-                        Ok(changes) => {
-                            let remote_ref = metadata.remote.clone().unwrap_or_default();
-
-                            for change in changes {
-                                all_changes.push(SyncChangeWithContext {
-                                    ticket_id: ticket_id.clone(),
-                                    remote_ref: remote_ref.clone(),
-                                    change,
-                                    decision: None,
-                                });
+                    Ok((metadata, issue)) => {
+                        match build_sync_changes(&metadata, &issue, platform, &status_mapping) {
+                            Ok(changes) => {
+                                let remote_ref = metadata.remote.clone().unwrap_or_default();
+
+                                for change in changes {
+                                    all_changes.push(SyncChangeWithContext {
+                                        ticket_id: ticket_id.clone(),
+                                        remote_ref: remote_ref.clone(),
+                                        change,
+                                        decision: None,
+                                    });
+                                }
+                            }
+                            Err(e) => {
+                                error_messages.push(format!(
+                                    "Failed to build sync changes for {ticket_id}: {e}"
+                                ));
                             }
                         }
-                        Err(e) => {
-                            error_messages
-                                .push(format!("Failed to build sync changes for {ticket_id}: {e}"));
-                        }
-                    },
+                    }
                     Err(e) => {
                         error_messages.push(format!("Failed to fetch remote for {ticket_id}: {e}"));
                     }
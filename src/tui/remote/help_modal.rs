@@ -95,7 +95,13 @@ fn build_help_text() -> String {
                 ("u", "Unlink selected ticket(s)"),
             ],
         ),
-        ("Remote Operations", vec![("a", "Adopt issue to local")]),
+        (
+            "Remote Operations",
+            vec![
+                ("a", "Adopt issue to local"),
+                ("c", "Create new remote issue"),
+            ],
+        ),
         (
             "Link & Sync",
             vec![
@@ -5,6 +5,7 @@
 
 mod components;
 mod confirm_modal;
+mod create_modal;
 mod error_modal;
 mod error_toast;
 mod filter;
@@ -19,6 +20,7 @@ mod sync_preview;
 pub mod view;
 
 pub use confirm_modal::{ConfirmDialog, ConfirmDialogState};
+pub use create_modal::{CreateField, CreateIssueModal, CreateIssueState};
 pub use error_modal::ErrorDetailModal;
 pub use error_toast::{Toast, ToastLevel};
 pub use filter::{
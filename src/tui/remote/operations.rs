@@ -1,10 +1,14 @@
 //! Operation handlers for remote TUI
 
+use crate::config::TransitionVia;
 use crate::error::{JanusError, Result};
+use crate::hooks::{HookEvent, run_post_hooks_async, run_pre_hooks_async};
 use crate::remote::Platform;
+use crate::remote::config::StatusMappingConfig;
 use crate::remote::{RemoteIssue, RemoteProvider, RemoteRef};
+use crate::status::enforce_transition;
 use crate::ticket::TicketBuilder;
-use crate::types::{TicketMetadata, TicketPriority, TicketType};
+use crate::types::{TicketMetadata, TicketPriority, TicketStatus, TicketType};
 use std::collections::HashSet;
 use std::str::FromStr;
 use thiserror::Error;
@@ -106,11 +110,13 @@ mod tests {
             status: RemoteStatus::Open,
             priority: None,
             assignee: None,
+            assignee_aliases: Vec::new(),
             updated_at: "".to_string(),
             labels: vec![],
             team: None,
             project: None,
             milestone: None,
+            cycle: None,
             due_date: None,
             created_at: "".to_string(),
             creator: None,
@@ -141,11 +147,13 @@ mod tests {
             status: RemoteStatus::Open,
             priority: None,
             assignee: None,
+            assignee_aliases: Vec::new(),
             updated_at: "".to_string(),
             labels: vec![],
             team: None,
             project: None,
             milestone: None,
+            cycle: None,
             due_date: None,
             created_at: "".to_string(),
             creator: None,
@@ -171,11 +179,13 @@ mod tests {
             status: RemoteStatus::Open,
             priority: None,
             assignee: None,
+            assignee_aliases: Vec::new(),
             updated_at: "".to_string(),
             labels: vec![],
             team: None,
             project: None,
             milestone: None,
+            cycle: None,
             due_date: None,
             created_at: "".to_string(),
             creator: None,
@@ -228,11 +238,13 @@ mod tests {
             status: RemoteStatus::Open,
             priority: None,
             assignee: None,
+            assignee_aliases: Vec::new(),
             updated_at: "".to_string(),
             labels: vec![],
             team: None,
             project: None,
             milestone: None,
+            cycle: None,
             due_date: None,
             created_at: "".to_string(),
             creator: None,
@@ -263,11 +275,13 @@ mod tests {
             status: RemoteStatus::Open,
             priority: None,
             assignee: None,
+            assignee_aliases: Vec::new(),
             updated_at: "".to_string(),
             labels: vec![],
             team: None,
             project: None,
             milestone: None,
+            cycle: None,
             due_date: None,
             created_at: "".to_string(),
             creator: None,
@@ -298,11 +312,13 @@ mod tests {
             status: RemoteStatus::Open,
             priority: None,
             assignee: None,
+            assignee_aliases: Vec::new(),
             updated_at: "".to_string(),
             labels: vec![],
             team: None,
             project: None,
             milestone: None,
+            cycle: None,
             due_date: None,
             created_at: "".to_string(),
             creator: None,
@@ -328,11 +344,13 @@ mod tests {
             status: RemoteStatus::Open,
             priority: None,
             assignee: None,
+            assignee_aliases: Vec::new(),
             updated_at: "".to_string(),
             labels: vec![],
             team: None,
             project: None,
             milestone: None,
+            cycle: None,
             due_date: None,
             created_at: "".to_string(),
             creator: None,
@@ -363,11 +381,13 @@ mod tests {
             status: RemoteStatus::Open,
             priority: None,
             assignee: None,
+            assignee_aliases: Vec::new(),
             updated_at: "".to_string(),
             labels: vec![],
             team: None,
             project: None,
             milestone: None,
+            cycle: None,
             due_date: None,
             created_at: "".to_string(),
             creator: None,
@@ -380,12 +400,13 @@ mod tests {
 
 /// Adopt remote issues into local tickets
 pub fn adopt_issues(issues: &[RemoteIssue], _local_ids: &HashSet<String>) -> Result<Vec<String>> {
+    let config = crate::config::Config::load()?;
     let mut adopted_ids = Vec::new();
 
     for issue in issues {
         let remote_ref = build_remote_ref_from_issue(issue)?;
 
-        let ticket_id = create_ticket_from_remote(issue, &remote_ref)?;
+        let ticket_id = create_ticket_from_remote(issue, &remote_ref, &config)?;
         adopted_ids.push(ticket_id);
     }
 
@@ -479,8 +500,13 @@ fn build_remote_ref_from_issue(issue: &RemoteIssue) -> Result<RemoteRef> {
 }
 
 /// Create a local ticket from a remote issue
-fn create_ticket_from_remote(remote_issue: &RemoteIssue, remote_ref: &RemoteRef) -> Result<String> {
-    let status = remote_issue.status.to_ticket_status();
+fn create_ticket_from_remote(
+    remote_issue: &RemoteIssue,
+    remote_ref: &RemoteRef,
+    config: &crate::config::Config,
+) -> Result<String> {
+    let platform = remote_ref.platform();
+    let status = config.status_mapping.to_local(platform, remote_issue);
     let priority = TicketPriority::from_str(&remote_issue.priority.unwrap_or(2).to_string())?;
 
     let sanitized_title = sanitize_for_yaml(&remote_issue.title);
@@ -501,6 +527,14 @@ fn create_ticket_from_remote(remote_issue: &RemoteIssue, remote_ref: &RemoteRef)
         .status(status)
         .ticket_type(TicketType::Task)
         .priority(priority)
+        .labels(
+            config.labels.to_local(
+                &config
+                    .status_mapping
+                    .strip_status_labels(platform, &remote_issue.labels),
+            ),
+        )
+        .assignee(config.users.to_local(platform, remote_issue))
         .remote(Some(remote_ref.to_string()))
         .run_hooks(false)
         .build()?;
@@ -508,6 +542,55 @@ fn create_ticket_from_remote(remote_issue: &RemoteIssue, remote_ref: &RemoteRef)
     Ok(id)
 }
 
+/// Result of creating a remote issue from the TUI form
+#[derive(Debug, Clone)]
+pub struct CreatedIssue {
+    pub remote_ref: RemoteRef,
+    /// ID of the local ticket when the issue was adopted
+    pub ticket_id: Option<String>,
+}
+
+/// Create a new issue on the remote platform, optionally adopting it locally
+///
+/// Labels are local names, translated through the label mapping before
+/// being applied to the new issue.
+pub async fn create_remote_issue(
+    platform: Platform,
+    title: &str,
+    body: &str,
+    labels: &[String],
+    adopt: bool,
+) -> Result<CreatedIssue> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(JanusError::EmptyTitle);
+    }
+
+    let config = crate::config::Config::load()?;
+    let provider = crate::remote::create_provider(&platform, &config)?;
+    let remote_ref = provider.create_issue(title, body).await?;
+
+    if !labels.is_empty() {
+        let updates = crate::remote::IssueUpdates {
+            labels: Some(config.labels.to_remote(labels)),
+            ..Default::default()
+        };
+        provider.update_issue(&remote_ref, updates).await?;
+    }
+
+    let ticket_id = if adopt {
+        let issue = provider.fetch_issue(&remote_ref).await?;
+        Some(create_ticket_from_remote(&issue, &remote_ref, &config)?)
+    } else {
+        None
+    };
+
+    Ok(CreatedIssue {
+        remote_ref,
+        ticket_id,
+    })
+}
+
 /// Link a local ticket to a remote issue
 pub async fn link_ticket_to_issue(local_ticket_id: &str, remote_issue: &RemoteIssue) -> Result<()> {
     use crate::ticket::Ticket;
@@ -745,35 +828,16 @@ pub async fn push_ticket_to_remote(
     let body = TITLE_RE.replace(&body_with_title, "").to_string();
 
     // Create the remote issue
-    let remote_ref =
-        match platform {
-            Platform::GitHub => {
-                let provider = crate::remote::github::GitHubProvider::from_config(&config)
-                    .map_err(|e| PushError::Config {
-                        ticket_id: ticket_id.to_string(),
-                        message: format!("Failed to create GitHub provider: {e}"),
-                        source: Some(e),
-                    })?;
-
-                provider
-                    .create_issue(&title, &body)
-                    .await
-                    .map_err(|e| PushError::from((e, ticket_id.to_string())))?
-            }
-            Platform::Linear => {
-                let provider = crate::remote::linear::LinearProvider::from_config(&config)
-                    .map_err(|e| PushError::Config {
-                        ticket_id: ticket_id.to_string(),
-                        message: format!("Failed to create Linear provider: {e}"),
-                        source: Some(e),
-                    })?;
-
-                provider
-                    .create_issue(&title, &body)
-                    .await
-                    .map_err(|e| PushError::from((e, ticket_id.to_string())))?
-            }
-        };
+    let provider =
+        crate::remote::create_provider(&platform, &config).map_err(|e| PushError::Config {
+            ticket_id: ticket_id.to_string(),
+            message: format!("Failed to create {platform} provider: {e}"),
+            source: Some(e),
+        })?;
+    let remote_ref = provider
+        .create_issue(&title, &body)
+        .await
+        .map_err(|e| PushError::from((e, ticket_id.to_string())))?;
 
     // Update the local ticket with the remote reference
     ticket
@@ -838,16 +902,8 @@ pub async fn fetch_remote_issue_for_ticket(
 
     let remote_ref = RemoteRef::parse(remote_str, Some(&config))?;
 
-    let issue = match platform {
-        Platform::GitHub => {
-            let provider = crate::remote::github::GitHubProvider::from_config(&config)?;
-            provider.fetch_issue(&remote_ref).await?
-        }
-        Platform::Linear => {
-            let provider = crate::remote::linear::LinearProvider::from_config(&config)?;
-            provider.fetch_issue(&remote_ref).await?
-        }
-    };
+    let provider = crate::remote::create_provider(&platform, &config)?;
+    let issue = provider.fetch_issue(&remote_ref).await?;
 
     Ok((metadata, issue))
 }
@@ -856,6 +912,8 @@ pub async fn fetch_remote_issue_for_ticket(
 pub fn build_sync_changes(
     ticket: &TicketMetadata,
     issue: &crate::remote::RemoteIssue,
+    platform: Platform,
+    status_mapping: &StatusMappingConfig,
 ) -> Result<Vec<SyncChange>> {
     let mut changes = Vec::new();
 
@@ -870,14 +928,15 @@ pub fn build_sync_changes(
         });
     }
 
-    // Compare status using resolve_with_local to avoid lossy round-trip corruption.
-    // This preserves more-specific local statuses (e.g., InProgress, Cancelled)
-    // when the remote only has coarse-grained states (Open/Closed).
+    // Compare status through the configured mapping, falling back to
+    // resolve_with_local to avoid lossy round-trip corruption. This preserves
+    // more-specific local statuses (e.g., InProgress, Cancelled) when the
+    // remote only has coarse-grained states (Open/Closed).
     let local_status = ticket.status.ok_or_else(|| JanusError::CorruptedTicket {
         id: ticket.id.as_deref().unwrap_or("unknown").to_string(),
         field: "status".to_string(),
     })?;
-    let remote_status = issue.status.resolve_with_local(local_status);
+    let remote_status = status_mapping.resolve_with_local(platform, issue, local_status);
     if local_status != remote_status {
         changes.push(SyncChange {
             field_name: "Status".to_string(),
@@ -903,11 +962,65 @@ pub fn build_sync_changes(
     Ok(changes)
 }
 
+/// Apply the accepted sync changes for one ticket, wrapped in the `pre_sync`
+/// and `post_sync` hooks.
+///
+/// Returns the number of changes applied and the errors for those that failed.
+/// If the `pre_sync` hook aborts, nothing is applied.
+pub async fn apply_ticket_sync_changes(
+    ticket_id: &str,
+    remote_ref: &str,
+    changes: &[&SyncChange],
+    platform: Platform,
+) -> (usize, Vec<String>) {
+    let field_names = |direction: SyncDirection| -> Vec<String> {
+        changes
+            .iter()
+            .filter(|c| c.direction == direction)
+            .map(|c| c.field_name.to_lowercase())
+            .collect()
+    };
+    let context = match crate::ticket::Ticket::find(ticket_id).await {
+        Ok(ticket) => ticket.hook_context(),
+        Err(e) => return (0, vec![e.to_string()]),
+    }
+    .with_provider(platform.to_string())
+    .with_remote_ref(remote_ref)
+    .with_changed_fields(field_names(SyncDirection::RemoteToLocal))
+    .with_pushed_fields(field_names(SyncDirection::LocalToRemote));
+
+    if let Err(e) = run_pre_hooks_async(HookEvent::PreSync, &context).await {
+        return (0, vec![e.to_string()]);
+    }
+
+    let mut applied = 0;
+    let mut errors = Vec::new();
+    for change in changes {
+        let result = match change.direction {
+            SyncDirection::RemoteToLocal => apply_sync_change_to_local(ticket_id, change).await,
+            SyncDirection::LocalToRemote => {
+                apply_sync_change_to_remote(remote_ref, change, platform).await
+            }
+        };
+        match result {
+            Ok(()) => applied += 1,
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+
+    if applied > 0 {
+        run_post_hooks_async(HookEvent::PostSync, &context).await;
+    }
+    (applied, errors)
+}
+
 /// Apply a single sync change to a local ticket
 pub async fn apply_sync_change_to_local(ticket_id: &str, change: &SyncChange) -> Result<()> {
     use crate::ticket::Ticket;
 
     let ticket = Ticket::find(ticket_id).await?;
+    // Values come from the remote, so mirror mode does not apply
+    let _pull = crate::remote::mirror::PullGuard::new();
 
     match change.field_name.as_str() {
         "Title" => {
@@ -918,6 +1031,16 @@ pub async fn apply_sync_change_to_local(ticket_id: &str, change: &SyncChange) ->
             ticket.write(&new_content)?;
         }
         "Status" => {
+            let new_status: TicketStatus = change.remote_value.parse()?;
+            let old_status = ticket.read()?.status.unwrap_or_default();
+            enforce_transition(
+                &ticket.id,
+                old_status,
+                new_status,
+                TransitionVia::Sync,
+                false,
+            )
+            .await?;
             ticket.update_field("status", &change.remote_value)?;
         }
         "Priority" => {
@@ -959,31 +1082,16 @@ pub async fn apply_sync_change_to_remote(
             ..Default::default()
         },
         "Status" => {
-            let status = if change.local_value == "complete" || change.local_value == "cancelled" {
-                crate::remote::RemoteStatus::Closed
-            } else {
-                crate::remote::RemoteStatus::Open
-            };
-            crate::remote::IssueUpdates {
-                status: Some(status),
-                ..Default::default()
-            }
+            let status: TicketStatus = change.local_value.parse()?;
+            config.status_mapping.to_remote(platform, status)
         }
         _ => {
             return Err(JanusError::UnsupportedSyncField(change.field_name.clone()));
         }
     };
 
-    match platform {
-        Platform::GitHub => {
-            let provider = crate::remote::github::GitHubProvider::from_config(&config)?;
-            provider.update_issue(&remote_ref, updates).await?;
-        }
-        Platform::Linear => {
-            let provider = crate::remote::linear::LinearProvider::from_config(&config)?;
-            provider.update_issue(&remote_ref, updates).await?;
-        }
-    }
+    let provider = crate::remote::create_provider(&platform, &config)?;
+    provider.update_issue(&remote_ref, updates).await?;
 
     Ok(())
 }
@@ -5,8 +5,9 @@
 
 use crate::tui::components::footer::Shortcut;
 use crate::tui::components::{
-    ShortcutsBuilder, confirm_dialog_shortcuts, error_modal_shortcuts, filter_modal_shortcuts,
-    help_modal_shortcuts, link_mode_shortcuts, search_shortcuts, sync_preview_shortcuts,
+    ShortcutsBuilder, confirm_dialog_shortcuts, create_issue_shortcuts, error_modal_shortcuts,
+    filter_modal_shortcuts, help_modal_shortcuts, link_mode_shortcuts, search_shortcuts,
+    sync_preview_shortcuts,
 };
 
 use super::state::ViewMode;
@@ -18,6 +19,7 @@ pub struct ModalVisibility {
     pub show_error_modal: bool,
     pub show_sync_preview: bool,
     pub show_confirm_dialog: bool,
+    pub show_create_form: bool,
     pub show_link_mode: bool,
     pub show_filter: bool,
     pub search_focused: bool,
@@ -54,6 +56,10 @@ pub fn compute_shortcuts(modals: &ModalVisibility, current_view: ViewMode) -> Ve
         return confirm_dialog_shortcuts();
     }
 
+    if modals.show_create_form {
+        return create_issue_shortcuts();
+    }
+
     if modals.show_link_mode {
         return link_mode_shortcuts();
     }
@@ -75,7 +81,8 @@ pub fn compute_shortcuts(modals: &ModalVisibility, current_view: ViewMode) -> Ve
         .add("/", "Search")
         .add("P", "Provider")
         .add("r", "Refresh")
-        .add("f", "Filter");
+        .add("f", "Filter")
+        .add("c", "Create");
 
     let view_specific = if current_view == ViewMode::Remote {
         base.add("a", "Adopt")
@@ -6,6 +6,7 @@ use iocraft::prelude::{KeyCode, KeyModifiers};
 
 use crate::remote::{RemoteIssue, RemoteStatus};
 use crate::tui::navigation;
+use crate::tui::remote::create_modal::{CreateField, CreateIssueState};
 use crate::tui::remote::filter::{filter_local_tickets, filter_remote_issues};
 use crate::tui::remote::handlers::keymap::{ModalStateSnapshot, RemoteAction, key_to_action};
 use crate::tui::remote::shortcuts::{ModalVisibility, compute_shortcuts};
@@ -36,12 +37,14 @@ fn mock_issue(id: &str, title: &str) -> RemoteIssue {
         status: RemoteStatus::Open,
         priority: None,
         assignee: None,
+        assignee_aliases: Vec::new(),
         updated_at: "2024-01-01T00:00:00Z".to_string(),
         url: format!("https://example.com/{id}"),
         labels: vec![],
         team: None,
         project: None,
         milestone: None,
+        cycle: None,
         due_date: None,
         created_at: "2024-01-01T00:00:00Z".to_string(),
         creator: None,
@@ -596,6 +599,33 @@ fn test_esc_cancels_link_mode_and_restores_view() {
     assert_eq!(display.active_view, original_view);
 }
 
+// === Create Issue Form ===
+
+/// Press 'c' to open the create form, Tab through its fields, and check the
+/// labels typed into it are split, trimmed, and deduplicated.
+#[test]
+fn test_create_form_fields_and_labels() {
+    let snapshot = default_snapshot();
+    assert_eq!(
+        key_to_action(KeyCode::Char('c'), KeyModifiers::NONE, &snapshot),
+        Some(RemoteAction::ShowCreateIssue)
+    );
+
+    let mut form = CreateIssueState::new();
+    assert!(form.adopt, "New issues are adopted by default");
+    assert!(!form.is_valid(), "A title is required");
+
+    form.focused = form.focused.next().next();
+    assert_eq!(form.focused, CreateField::Labels);
+    assert_eq!(form.focused.next().next(), CreateField::Title);
+    assert_eq!(CreateField::Title.prev(), CreateField::Adopt);
+
+    form.title = "  Crash on start  ".to_string();
+    form.labels = "bug, ui,,Bug , backend".to_string();
+    assert!(form.is_valid());
+    assert_eq!(form.label_list(), vec!["bug", "ui", "backend"]);
+}
+
 // === App Lifecycle ===
 
 /// Press 'q' to quit. Verify the component exits (render loop ends).
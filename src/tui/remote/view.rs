@@ -10,7 +10,9 @@ use iocraft::prelude::*;
 use crate::remote::RemoteIssue;
 use crate::ticket::get_all_tickets_from_disk;
 use crate::tui::components::{Clickable, InlineSearchBox};
-use crate::tui::screen_base::{ScreenLayout, calculate_list_height, should_process_key_event};
+use crate::tui::screen_base::{
+    ScreenLayout, calculate_list_height, should_process_key_event, use_screen_size,
+};
 use crate::tui::search_orchestrator::{SearchState, compute_filtered_tickets};
 use crate::tui::theme::theme;
 use crate::types::TicketMetadata;
@@ -18,14 +20,16 @@ use crate::types::TicketMetadata;
 use super::components::overlays::render_link_mode_banner;
 use super::components::{DetailPane, ListPane, ModalOverlays, SelectionBar, TabBar};
 use super::confirm_modal::ConfirmDialogState;
+use super::create_modal::CreateIssueState;
 use super::error_toast::Toast;
 use super::filter::{FilteredLocalTicket, FilteredRemoteIssue, filter_remote_issues};
 use super::filter_modal::FilterState;
 use super::handlers::{
     HandlerContext,
     async_handlers::{
-        FetchResult, create_fetch_handler, create_link_handler, create_push_handler,
-        create_search_fetch_handler, create_sync_apply_handler, create_unlink_handler,
+        FetchResult, create_create_issue_handler, create_fetch_handler, create_link_handler,
+        create_push_handler, create_search_fetch_handler, create_sync_apply_handler,
+        create_unlink_handler,
     },
     sync_handlers,
     sync_handlers::create_sync_fetch_handler,
@@ -48,7 +52,7 @@ pub struct RemoteTuiProps {
 /// Main remote TUI component
 #[component]
 pub fn RemoteTui<'a>(_props: &RemoteTuiProps, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
-    let (width, height) = hooks.use_terminal_size();
+    let (width, height) = use_screen_size(&mut hooks);
     let mut system = hooks.use_context_mut::<SystemContext>();
 
     let theme = theme();
@@ -74,6 +78,7 @@ pub fn RemoteTui<'a>(_props: &RemoteTuiProps, mut hooks: Hooks) -> impl Into<Any
     let mut toast: State<Option<Toast>> = hooks.use_state(|| None);
     let mut link_mode: State<Option<LinkModeState>> = hooks.use_state(|| None);
     let mut confirm_dialog: State<Option<ConfirmDialogState>> = hooks.use_state(|| None);
+    let mut create_form: State<Option<CreateIssueState>> = hooks.use_state(|| None);
     let mut sync_preview: State<Option<SyncPreviewState>> = hooks.use_state(|| None);
     let mut modal_visibility: State<ModalVisibilityData> = hooks.use_state(Default::default);
 
@@ -155,6 +160,13 @@ pub fn RemoteTui<'a>(_props: &RemoteTuiProps, mut hooks: Hooks) -> impl Into<Any
 
     let link_handler = create_link_handler(&mut hooks, &local_tickets, &toast);
     let unlink_handler = create_unlink_handler(&mut hooks, &local_tickets, &local_nav, &toast);
+    let create_issue_handler = create_create_issue_handler(
+        &mut hooks,
+        &local_tickets,
+        &fetch_handler,
+        &toast,
+        &last_error,
+    );
 
     // ====================================================================
     // Click handlers - using factory functions
@@ -354,6 +366,7 @@ pub fn RemoteTui<'a>(_props: &RemoteTuiProps, mut hooks: Hooks) -> impl Into<Any
     let sync_apply_handler_for_events = sync_apply_handler.clone();
     let link_handler_for_events = link_handler.clone();
     let unlink_handler_for_events = unlink_handler.clone();
+    let create_issue_handler_for_events = create_issue_handler.clone();
 
     // ====================================================================
     // Rendering calculations
@@ -462,7 +475,7 @@ pub fn RemoteTui<'a>(_props: &RemoteTuiProps, mut hooks: Hooks) -> impl Into<Any
                 kind,
                 modifiers,
                 ..
-            }) if should_process_key_event(kind) => {
+            }) if should_process_key_event(kind, code, modifiers) => {
                 // Build the handler context with grouped state references
                 use crate::tui::remote::handlers::context::{
                     AsyncHandlers, FilteringState, ModalState, NavigationState, SearchState,
@@ -496,6 +509,7 @@ pub fn RemoteTui<'a>(_props: &RemoteTuiProps, mut hooks: Hooks) -> impl Into<Any
                         link_mode: &mut link_mode,
                         sync_preview: &mut sync_preview,
                         confirm_dialog: &mut confirm_dialog,
+                        create_form: &mut create_form,
                         visibility: &mut modal_visibility,
                     },
                     filters: FilteringState {
@@ -509,6 +523,7 @@ pub fn RemoteTui<'a>(_props: &RemoteTuiProps, mut hooks: Hooks) -> impl Into<Any
                         sync_apply_handler: &sync_apply_handler_for_events,
                         link_handler: &link_handler_for_events,
                         unlink_handler: &unlink_handler_for_events,
+                        create_issue_handler: &create_issue_handler_for_events,
                     },
                 };
 
@@ -548,6 +563,7 @@ pub fn RemoteTui<'a>(_props: &RemoteTuiProps, mut hooks: Hooks) -> impl Into<Any
             show_error_modal: modal_visibility_ref.show_error,
             show_sync_preview: sync_preview.read().is_some(),
             show_confirm_dialog: confirm_dialog.read().is_some(),
+            show_create_form: create_form.read().is_some(),
             show_link_mode: link_mode.read().is_some(),
             show_filter: filter_state.read().is_some(),
             search_focused: search_ui_ref.focused,
@@ -600,6 +616,14 @@ pub fn RemoteTui<'a>(_props: &RemoteTuiProps, mut hooks: Hooks) -> impl Into<Any
                             }
                         }
                     }
+                    FetchResult::Offline(paginated, fetched_at) => {
+                        let age = jiff::Timestamp::now().duration_since(*fetched_at);
+                        Some(format!(
+                            "Offline: showing {} cached issues from {} ago",
+                            paginated.items.len(),
+                            crate::sla::format_duration(age)
+                        ))
+                    }
                     FetchResult::Error(_, _) => None, // Don't show status on error
                 }
             })
@@ -721,6 +745,8 @@ pub fn RemoteTui<'a>(_props: &RemoteTuiProps, mut hooks: Hooks) -> impl Into<Any
                 last_error: last_error_clone,
                 sync_preview_state: sync_preview_state_clone,
                 confirm_dialog_state: confirm_dialog_state_clone,
+                create_form: Some(create_form),
+                provider: filter_config_ref.provider.to_string(),
             )
         }
     }
@@ -6,7 +6,7 @@ use tracing::{debug, warn};
 
 use crate::parser::extract_ticket_body;
 use crate::store::get_or_init_store;
-use crate::ticket::Ticket;
+use crate::ticket::{Ticket, parse_notes, ticket_activity};
 use crate::types::{TicketMetadata, janus_root};
 
 /// Result of initializing the ticket repository
@@ -143,3 +143,26 @@ pub fn load_ticket_body(file_path: Option<&PathBuf>) -> String {
     };
     extract_ticket_body(&content).unwrap_or_default()
 }
+
+/// The ticket's activity feed as a markdown `## Activity` section, for
+/// appending to its body in the detail pane.
+///
+/// Like [`load_ticket_body`], this reads from disk (the event log and git
+/// history) and is called once per selection change.
+pub fn load_ticket_activity(ticket: &TicketMetadata, body: &str) -> String {
+    let Some(id) = ticket.id.as_deref() else {
+        return String::new();
+    };
+    let activity = match ticket_activity(id, ticket, &parse_notes(body)) {
+        Ok(activity) => activity,
+        Err(e) => return format!("\n\n## Activity\n\nCould not load activity: {e}\n"),
+    };
+    if activity.is_empty() {
+        return String::new();
+    }
+    let lines: Vec<String> = activity
+        .iter()
+        .map(|item| format!("- `{}` {}", item.display_time(), item.describe()))
+        .collect();
+    format!("\n\n## Activity\n\n{}\n", lines.join("\n"))
+}
@@ -34,7 +34,8 @@
 
 use iocraft::prelude::*;
 
-use crate::tui::components::{Footer, Header, Shortcut, ToastNotification};
+use crate::tui::app::{TAB_BAR_HEIGHT, is_tab_switch_key, is_tabbed};
+use crate::tui::components::{Footer, Header, Shortcut, TicketCounts, ToastNotification};
 use crate::tui::theme::theme;
 
 /// Common state that all TUI screens need
@@ -93,7 +94,7 @@ impl ScreenState {
 /// let my_custom_state = hooks.use_state(|| MyCustomState::default());
 /// ```
 pub fn use_screen_state(hooks: &mut Hooks) -> ScreenState {
-    let (width, height) = hooks.use_terminal_size();
+    let (width, height) = use_screen_size(hooks);
     let should_exit = hooks.use_state(|| false);
     let needs_reload = hooks.use_state(|| false);
     let is_loading = hooks.use_state(|| true);
@@ -107,6 +108,17 @@ pub fn use_screen_state(hooks: &mut Hooks) -> ScreenState {
     }
 }
 
+/// Size available to a screen: the whole terminal, less the tab bar when the
+/// screen is hosted by `janus tui`
+pub fn use_screen_size(hooks: &mut Hooks) -> (u16, u16) {
+    let (width, height) = hooks.use_terminal_size();
+    if is_tabbed() {
+        (width, height.saturating_sub(TAB_BAR_HEIGHT))
+    } else {
+        (width, height)
+    }
+}
+
 /// Handle the exit check for a screen
 ///
 /// This should be called after event processing. If `should_exit` is true,
@@ -139,6 +151,8 @@ pub struct ScreenLayoutProps<'a> {
     pub header_subtitle: Option<&'a str>,
     /// Ticket count for header
     pub header_ticket_count: Option<usize>,
+    /// Summary counts for header, shown instead of the ticket count
+    pub header_counts: Option<TicketCounts>,
     /// Extra header elements
     pub header_extra: Option<Vec<AnyElement<'a>>>,
     /// Provider info for header (remote screen)
@@ -194,6 +208,7 @@ pub fn ScreenLayout<'a>(props: &mut ScreenLayoutProps<'a>) -> impl Into<AnyEleme
                 title: props.header_title,
                 subtitle: props.header_subtitle,
                 ticket_count: props.header_ticket_count,
+                counts: props.header_counts,
                 extra: header_extra,
                 provider: props.header_provider.clone(),
                 triage_mode: props.triage_mode,
@@ -229,10 +244,15 @@ pub fn ScreenLayout<'a>(props: &mut ScreenLayoutProps<'a>) -> impl Into<AnyEleme
 
 /// Helper to check if a key event should be processed
 ///
-/// Returns false if the event is a key release (which should be ignored).
+/// Returns false if the event is a key release (which should be ignored), or
+/// a tab-switching key the tabbed application handles instead.
 /// Screens can use this as the first check in their event handlers.
-pub fn should_process_key_event(kind: KeyEventKind) -> bool {
-    kind != KeyEventKind::Release
+pub fn should_process_key_event(
+    kind: KeyEventKind,
+    code: KeyCode,
+    modifiers: KeyModifiers,
+) -> bool {
+    kind != KeyEventKind::Release && !is_tab_switch_key(code, modifiers)
 }
 
 /// Calculate the available list height for a screen
@@ -270,8 +290,15 @@ mod tests {
 
     #[test]
     fn test_should_process_key_event() {
-        assert!(should_process_key_event(KeyEventKind::Press));
-        assert!(should_process_key_event(KeyEventKind::Repeat));
-        assert!(!should_process_key_event(KeyEventKind::Release));
+        let (code, none) = (KeyCode::Char('1'), KeyModifiers::NONE);
+        assert!(should_process_key_event(KeyEventKind::Press, code, none));
+        assert!(should_process_key_event(KeyEventKind::Repeat, code, none));
+        assert!(!should_process_key_event(KeyEventKind::Release, code, none));
+        // Alt+digit belongs to the screen unless it is hosted by `janus tui`
+        assert!(should_process_key_event(
+            KeyEventKind::Press,
+            code,
+            KeyModifiers::ALT
+        ));
     }
 }
@@ -9,7 +9,7 @@
 //! - `~authentication` - Semantic search for tickets related to authentication
 //!
 //! Features:
-//! - Fuzzy matching across id, title, and type
+//! - Fuzzy matching across id, title, status, type, and labels
 //! - Priority shorthand: `p0`, `p1`, `p2`, `p3`, `p4`
 //! - Smart case: case-insensitive unless query contains uppercase
 //! - Semantic search with `~` prefix
@@ -35,6 +35,8 @@ pub struct FilteredTicket {
     pub title_indices: Vec<usize>,
     /// true if from semantic search
     pub is_semantic: bool,
+    /// Cosine similarity to the query, when semantic search scored this ticket
+    pub similarity: Option<f32>,
 }
 
 /// Generic result of fuzzy filtering with item, score, and title indices
@@ -115,7 +117,7 @@ where
 /// Filter tickets by a fuzzy search query
 ///
 /// Supports:
-/// - Fuzzy matching across id, title, and type
+/// - Fuzzy matching across id, title, status, type, and labels
 /// - Priority shorthand: `p0`, `p1`, `p2`, `p3`, `p4` filters by priority
 /// - Smart case: case-insensitive unless query contains uppercase
 pub fn filter_tickets(tickets: &[TicketMetadata], query: &str) -> Vec<FilteredTicket> {
@@ -127,6 +129,7 @@ pub fn filter_tickets(tickets: &[TicketMetadata], query: &str) -> Vec<FilteredTi
                 score: 0,
                 title_indices: vec![],
                 is_semantic: false,
+                similarity: None,
             })
             .collect();
     }
@@ -152,6 +155,7 @@ pub fn filter_tickets(tickets: &[TicketMetadata], query: &str) -> Vec<FilteredTi
                 score: 0,
                 title_indices: vec![],
                 is_semantic: false,
+                similarity: None,
             })
             .collect();
     }
@@ -162,7 +166,7 @@ pub fn filter_tickets(tickets: &[TicketMetadata], query: &str) -> Vec<FilteredTi
         &fuzzy_query,
         |ticket| {
             format!(
-                "{} {} {} {}",
+                "{} {} {} {} {}",
                 ticket.id.as_deref().unwrap_or(""),
                 ticket.title.as_deref().unwrap_or(""),
                 ticket.status.map(|s| s.to_string()).unwrap_or_default(),
@@ -170,6 +174,7 @@ pub fn filter_tickets(tickets: &[TicketMetadata], query: &str) -> Vec<FilteredTi
                     .ticket_type
                     .map(|t| t.to_string())
                     .unwrap_or_default(),
+                ticket.labels.join(" "),
             )
         },
         |ticket| {
@@ -187,6 +192,7 @@ pub fn filter_tickets(tickets: &[TicketMetadata], query: &str) -> Vec<FilteredTi
             score: filtered.score,
             title_indices: filtered.title_indices,
             is_semantic: false,
+            similarity: None,
         })
         .collect()
 }
@@ -264,12 +270,13 @@ pub async fn perform_semantic_search(
 }
 
 /// Merge fuzzy and semantic results, removing duplicates
-/// Fuzzy results take precedence (appear first)
+/// Fuzzy results take precedence (appear first), but keep the similarity
+/// score when semantic search also found them
 pub fn merge_search_results(
-    fuzzy: Vec<FilteredTicket>,
+    mut fuzzy: Vec<FilteredTicket>,
     semantic: Vec<crate::store::search::SearchResult>,
 ) -> Vec<FilteredTicket> {
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
 
     // Collect IDs from fuzzy results to avoid duplicates
     let fuzzy_ids: HashSet<String> = fuzzy
@@ -277,6 +284,18 @@ pub fn merge_search_results(
         .filter_map(|t| t.ticket.id.as_ref().map(|id| id.to_string()))
         .collect();
 
+    let similarities: HashMap<&str, f32> = semantic
+        .iter()
+        .filter_map(|r| Some((r.ticket.id.as_deref()?, r.similarity)))
+        .collect();
+    for ticket in &mut fuzzy {
+        ticket.similarity = ticket
+            .ticket
+            .id
+            .as_deref()
+            .and_then(|id| similarities.get(id).copied());
+    }
+
     // Convert semantic results to FilteredTickets, excluding duplicates
     let semantic_tickets: Vec<FilteredTicket> = semantic
         .into_iter()
@@ -303,6 +322,7 @@ impl From<crate::store::search::SearchResult> for FilteredTicket {
             score: 0,              // Fuzzy score not applicable
             title_indices: vec![], // No fuzzy highlighting
             is_semantic: true,
+            similarity: Some(result.similarity),
         }
     }
 }
@@ -324,6 +344,7 @@ pub fn compute_title_highlights(tickets: &[TicketMetadata], query: &str) -> Vec<
                 score: 0,
                 title_indices: vec![],
                 is_semantic: false,
+                similarity: None,
             })
             .collect();
     }
@@ -344,6 +365,7 @@ pub fn compute_title_highlights(tickets: &[TicketMetadata], query: &str) -> Vec<
                 score: 0, // Score not relevant for store-based search
                 title_indices,
                 is_semantic: false,
+                similarity: None,
             }
         })
         .collect()
@@ -545,6 +567,7 @@ mod tests {
             score: 100,
             title_indices: vec![],
             is_semantic: false,
+            similarity: None,
         }];
 
         // Create semantic results (including duplicate)
@@ -575,10 +598,12 @@ mod tests {
         // First should be fuzzy (ticket-1)
         assert_eq!(merged[0].ticket.id.as_ref().unwrap(), "ticket-1");
         assert!(!merged[0].is_semantic);
+        assert_eq!(merged[0].similarity, Some(0.95));
 
         // Second should be semantic (ticket-2)
         assert_eq!(merged[1].ticket.id.as_ref().unwrap(), "ticket-2");
         assert!(merged[1].is_semantic);
+        assert_eq!(merged[1].similarity, Some(0.85));
     }
 
     #[test]
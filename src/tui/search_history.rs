@@ -0,0 +1,163 @@
+//! Search history and query suggestions for the issue browser
+//!
+//! Recent queries are kept per repository in the user's cache directory
+//! (e.g. `~/.cache/janus/search_history/`), one per line with the newest
+//! first, and offered in a dropdown when Up is pressed in the search box.
+//! They live outside `.janus` so private queries are never committed,
+//! synced, or backed up with the tickets. While typing, the word under the cursor is
+//! completed against status names, priority shorthands, and ticket labels.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+use directories::BaseDirs;
+
+use crate::types::{TicketMetadata, TicketPriority, TicketStatus, janus_root};
+
+/// Directory of the history files in the user's `janus` cache directory
+const HISTORY_DIR: &str = "search_history";
+
+/// Most queries remembered per repository
+pub const MAX_HISTORY: usize = 50;
+
+/// Most completions offered at once
+const MAX_SUGGESTIONS: usize = 5;
+
+/// History file for the current repository, named after a hash of its Janus
+/// root. `None` when the user has no cache directory.
+fn history_path() -> Option<PathBuf> {
+    let root = janus_root();
+    let root = root.canonicalize().unwrap_or(root);
+    let hash = blake3::hash(root.to_string_lossy().as_bytes()).to_hex();
+    let dir = BaseDirs::new()?.cache_dir().join("janus").join(HISTORY_DIR);
+    Some(dir.join(&hash[..32]))
+}
+
+/// Load recent search queries, newest first. A missing or unreadable file
+/// is treated as an empty history.
+pub fn load_search_history() -> Vec<String> {
+    history_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|content| {
+            content
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .take(MAX_HISTORY)
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Move `query` to the front of `history`, dropping duplicates and the
+/// oldest entries beyond [`MAX_HISTORY`]. Returns false if the query is blank.
+pub fn push_history_entry(history: &mut Vec<String>, query: &str) -> bool {
+    let query = query.trim();
+    if query.is_empty() {
+        return false;
+    }
+    history.retain(|entry| entry != query);
+    history.insert(0, query.to_string());
+    history.truncate(MAX_HISTORY);
+    true
+}
+
+/// Add `query` to the history and save it.
+///
+/// Saving is best-effort: search keeps working when the file cannot be
+/// written, the query just is not remembered next time. The file is written
+/// directly rather than through [`crate::fs`], which would queue it for
+/// `git.auto_commit`.
+pub fn record_search(history: &mut Vec<String>, query: &str) {
+    if push_history_entry(history, query)
+        && let Some(path) = history_path()
+    {
+        let mut content = history.join("\n");
+        content.push('\n');
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let _ = fs::write(&path, content);
+    }
+}
+
+/// Labels used by any of `tickets`, sorted and deduplicated.
+pub fn collect_labels(tickets: &[TicketMetadata]) -> Vec<String> {
+    tickets
+        .iter()
+        .flat_map(|t| t.labels.iter().cloned())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Completions for the last word of `query`, as full replacement queries.
+///
+/// The word is matched by prefix (ignoring case) against status names,
+/// priority shorthands (`p0`-`p4`), and `labels`. Nothing is suggested for
+/// semantic (`~`) queries or when the query ends in whitespace.
+pub fn suggest_completions(query: &str, labels: &[String]) -> Vec<String> {
+    if query.starts_with('~') || query.ends_with(char::is_whitespace) {
+        return Vec::new();
+    }
+    let Some(word) = query.split_whitespace().last() else {
+        return Vec::new();
+    };
+    let head = &query[..query.len() - word.len()];
+    let word_lower = word.to_lowercase();
+
+    let priorities = TicketPriority::ALL_STRINGS.iter().map(|p| format!("p{p}"));
+    let statuses = TicketStatus::ALL_STRINGS.iter().map(|s| s.to_string());
+
+    let mut seen = BTreeSet::new();
+    statuses
+        .chain(priorities)
+        .chain(labels.iter().cloned())
+        .filter(|candidate| {
+            let candidate_lower = candidate.to_lowercase();
+            candidate_lower.starts_with(&word_lower) && candidate_lower != word_lower
+        })
+        .filter(|candidate| seen.insert(candidate.clone()))
+        .take(MAX_SUGGESTIONS)
+        .map(|candidate| format!("{head}{candidate}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_history_entry_dedupes_and_caps() {
+        let mut history = vec!["b".to_string(), "a".to_string()];
+        assert!(push_history_entry(&mut history, " a "));
+        assert_eq!(history, vec!["a", "b"]);
+        assert!(!push_history_entry(&mut history, "   "));
+
+        for i in 0..MAX_HISTORY {
+            push_history_entry(&mut history, &format!("q{i}"));
+        }
+        assert_eq!(history.len(), MAX_HISTORY);
+        assert_eq!(history[0], format!("q{}", MAX_HISTORY - 1));
+    }
+
+    #[test]
+    fn test_suggest_completions() {
+        let labels = vec!["backend".to_string(), "bug".to_string()];
+
+        assert_eq!(
+            suggest_completions("login in_", &labels),
+            vec!["login in_progress"]
+        );
+        assert_eq!(
+            suggest_completions("P", &labels),
+            vec!["p0", "p1", "p2", "p3", "p4"]
+        );
+        assert_eq!(suggest_completions("b", &labels), vec!["backend", "bug"]);
+        // A complete word has nothing left to suggest
+        assert!(suggest_completions("bug", &labels).is_empty());
+        assert!(suggest_completions("bug ", &labels).is_empty());
+        assert!(suggest_completions("~in", &labels).is_empty());
+    }
+}
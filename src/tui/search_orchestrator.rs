@@ -6,6 +6,7 @@
 //! - Text search via store
 //! - Search in-flight indicator
 //! - Result caching
+//! - Lexical/semantic mode toggle (semantic mode behaves like a `~` prefix)
 
 use iocraft::prelude::*;
 use std::sync::Arc;
@@ -13,6 +14,56 @@ use std::sync::Arc;
 use crate::tui::search::{FilteredTicket, compute_title_highlights};
 use crate::types::TicketMetadata;
 
+/// Which search backend runs when a query is submitted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Text search only, unless the query starts with `~`
+    #[default]
+    Lexical,
+    /// Text search plus semantic search for every query
+    Semantic,
+}
+
+enum_display!(SearchMode, {
+    Lexical => "lexical",
+    Semantic => "semantic",
+});
+
+impl SearchMode {
+    /// The other mode
+    pub fn toggle(self) -> Self {
+        match self {
+            SearchMode::Lexical => SearchMode::Semantic,
+            SearchMode::Semantic => SearchMode::Lexical,
+        }
+    }
+
+    /// The query to hand to the search handlers: in semantic mode a plain
+    /// query gets the `~` prefix so both handlers treat it as semantic.
+    pub fn effective_query(self, query: &str) -> String {
+        if self == SearchMode::Semantic && !query.is_empty() && !query.starts_with('~') {
+            format!("~{query}")
+        } else {
+            query.to_string()
+        }
+    }
+}
+
+/// Embedding index progress as `(embedded, total)` tickets while it is still
+/// being built, or `None` once every ticket has an embedding.
+///
+/// Before the store has finished initializing (which includes generating
+/// missing embeddings) the counts are unknown and `(0, 0)` is returned.
+pub fn embedding_index_progress() -> Option<(usize, usize)> {
+    match crate::store::get_store() {
+        Some(store) => {
+            let (with_embedding, total) = store.embedding_coverage();
+            (with_embedding < total).then_some((with_embedding, total))
+        }
+        None => Some((0, 0)),
+    }
+}
+
 /// State for search functionality in TUI views
 pub struct SearchState {
     /// Filtered tickets from search (with title highlights)
@@ -29,6 +80,8 @@ pub struct SearchState {
     pub semantic_error: State<Option<String>>,
     /// Handler for semantic search
     pub semantic_handler: Handler<String>,
+    /// Lexical or semantic mode, toggled from the search box
+    pub mode: State<SearchMode>,
     /// The most recently launched query — used to detect stale semantic results
     latest_query: State<String>,
 }
@@ -42,6 +95,7 @@ impl SearchState {
         let semantic_pending = hooks.use_state(|| false);
         let semantic_error: State<Option<String>> = hooks.use_state(|| None);
         let latest_query: State<String> = hooks.use_state(String::new);
+        let mode: State<SearchMode> = hooks.use_state(SearchMode::default);
 
         // Fuzzy search handler
         let search_handler: Handler<String> = hooks.use_async_handler({
@@ -169,6 +223,7 @@ impl SearchState {
             semantic_pending,
             semantic_error,
             semantic_handler,
+            mode,
             latest_query,
         }
     }
@@ -180,6 +235,7 @@ impl SearchState {
 
     /// Trigger search with the given query string
     pub fn trigger(&mut self, query: String) {
+        let query = self.mode.get().effective_query(&query);
         self.pending.set(false);
         self.in_flight.set(true);
 
@@ -196,6 +252,18 @@ impl SearchState {
         }
     }
 
+    /// Switch between lexical and semantic mode and re-run the search
+    pub fn toggle_mode(&mut self) {
+        self.mode.set(self.mode.get().toggle());
+        self.trigger_pending();
+    }
+
+    /// Check if `query` will run a semantic search, either because of the
+    /// `~` prefix or because semantic mode is on
+    pub fn is_semantic(&self, query: &str) -> bool {
+        self.mode.get() == SearchMode::Semantic || query.starts_with('~')
+    }
+
     /// Check if search is pending and trigger it
     pub fn check_pending(&mut self, query: String) {
         if self.pending.get() {
@@ -237,6 +305,9 @@ impl SearchState {
 
 /// Compute which tickets to display based on search state
 ///
+/// Snoozed tickets are hidden unless they match an active search, so a
+/// snoozed ticket can still be found by name.
+///
 /// # Arguments
 /// * `all_tickets` - All tickets in the system
 /// * `search_state` - Search state with results
@@ -249,27 +320,38 @@ pub fn compute_filtered_tickets(
     search_state: &SearchState,
     query: &str,
 ) -> Vec<FilteredTicket> {
-    if query.is_empty() {
-        all_tickets
-            .iter()
-            .map(|t: &TicketMetadata| FilteredTicket {
-                ticket: Arc::new(t.clone()),
-                score: 0,
-                title_indices: vec![],
-                is_semantic: false,
-            })
-            .collect()
-    } else if let Some(results) = search_state.get_results() {
-        results
-    } else {
-        all_tickets
-            .iter()
-            .map(|t: &TicketMetadata| FilteredTicket {
-                ticket: Arc::new(t.clone()),
-                score: 0,
-                title_indices: vec![],
-                is_semantic: false,
-            })
-            .collect()
+    if !query.is_empty()
+        && let Some(results) = search_state.get_results()
+    {
+        return results;
+    }
+
+    let now = jiff::Timestamp::now();
+    all_tickets
+        .iter()
+        .filter(|t| !t.is_snoozed_at(now))
+        .map(|t: &TicketMetadata| FilteredTicket {
+            ticket: Arc::new(t.clone()),
+            score: 0,
+            title_indices: vec![],
+            is_semantic: false,
+            similarity: None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_mode_effective_query() {
+        assert_eq!(SearchMode::Lexical.effective_query("login"), "login");
+        assert_eq!(SearchMode::Lexical.effective_query("~login"), "~login");
+        assert_eq!(SearchMode::Semantic.effective_query("login"), "~login");
+        assert_eq!(SearchMode::Semantic.effective_query("~login"), "~login");
+        assert_eq!(SearchMode::Semantic.effective_query(""), "");
+        assert_eq!(SearchMode::Lexical.toggle(), SearchMode::Semantic);
+        assert_eq!(SearchMode::Semantic.to_string(), "semantic");
     }
 }
@@ -12,7 +12,9 @@ pub use edit::TicketEditService;
 pub use external_editor::ExternalEditor;
 pub use validator::{TicketFormValidator, ValidationResult};
 
+use crate::config::TransitionVia;
 use crate::error::Result;
+use crate::status::enforce_transition;
 use crate::ticket::{Ticket, TicketBuilder};
 use crate::tui::edit::extract_body_for_edit;
 use crate::types::{TicketMetadata, TicketPriority, TicketStatus, TicketType};
@@ -29,11 +31,22 @@ impl TicketService {
     ///
     /// Status cycle: New -> Next -> InProgress -> Complete -> New
     /// Cancelled tickets reset to New
+    ///
+    /// Protected transitions are enforced without prompting: a transition that
+    /// requires confirmation is rejected and must be made from the CLI instead.
     pub async fn cycle_status(ticket_id: &str) -> Result<TicketStatus> {
         let ticket = Ticket::find(ticket_id).await?;
         let metadata = ticket.read()?;
         let current_status = metadata.status.unwrap_or_default();
         let next_status = Self::next_status(current_status);
+        enforce_transition(
+            &ticket.id,
+            current_status,
+            next_status,
+            TransitionVia::Tui,
+            false,
+        )
+        .await?;
         ticket.update_field("status", &next_status.to_string())?;
         Ok(next_status)
     }
@@ -41,10 +54,33 @@ impl TicketService {
     /// Update a ticket's status to a specific value
     pub async fn set_status(ticket_id: &str, status: TicketStatus) -> Result<()> {
         let ticket = Ticket::find(ticket_id).await?;
+        let current_status = ticket.read()?.status.unwrap_or_default();
+        enforce_transition(
+            &ticket.id,
+            current_status,
+            status,
+            TransitionVia::Tui,
+            false,
+        )
+        .await?;
         ticket.update_field("status", &status.to_string())?;
         Ok(())
     }
 
+    /// Raise a ticket's priority by one level
+    ///
+    /// Returns the new priority, or `None` if the ticket is already P0.
+    pub async fn raise_priority(ticket_id: &str) -> Result<Option<TicketPriority>> {
+        let ticket = Ticket::find(ticket_id).await?;
+        let current = ticket.read()?.priority.unwrap_or_default();
+        if current == TicketPriority::P0 {
+            return Ok(None);
+        }
+        let raised = current.prev();
+        ticket.update_field("priority", &raised.to_string())?;
+        Ok(Some(raised))
+    }
+
     /// Load ticket data for editing
     ///
     /// Returns the ticket metadata and body content suitable for the edit form.
@@ -68,6 +104,15 @@ impl TicketService {
         body: &str,
     ) -> Result<()> {
         let ticket = Ticket::find(id).await?;
+        let current_status = ticket.read()?.status.unwrap_or_default();
+        enforce_transition(
+            &ticket.id,
+            current_status,
+            status,
+            TransitionVia::Tui,
+            false,
+        )
+        .await?;
 
         // Update individual fields
         ticket.update_field("status", &status.to_string())?;
@@ -178,7 +223,9 @@ impl TicketService {
         let timestamp = iso_date();
         content.push_str(&format!("\n\n**{timestamp}**\n\n{note}"));
 
+        crate::read_only::ensure_writable(|| format!("write {}", ticket.file_path.display()))?;
         fs::write(&ticket.file_path, content)?;
+        crate::fs::record_path(&ticket.file_path);
 
         Ok(())
     }
@@ -2,6 +2,7 @@
 
 use iocraft::prelude::Color;
 
+use crate::config::{Config, DisplayConfig, ThemeColor};
 use crate::types::{TicketPriority, TicketStatus, TicketType};
 
 /// Theme configuration for TUI components
@@ -39,6 +40,14 @@ pub struct Theme {
     pub id_color: Color,
     pub error: Color,
 
+    // SLA badge colors
+    pub sla_at_risk: Color,
+    pub sla_breached: Color,
+
+    // Board card aging colors, blended by time in column
+    pub age_fresh: Color,
+    pub age_stale: Color,
+
     // Semantic search colors
     /// Color for semantic search indicator (~)
     pub semantic_indicator: Color,
@@ -116,6 +125,22 @@ impl Default for Theme {
             id_color: Color::Cyan,
             error: Color::Red,
 
+            // SLA badge defaults
+            sla_at_risk: Color::Yellow,
+            sla_breached: Color::Red,
+
+            // Card aging defaults
+            age_fresh: Color::Rgb {
+                r: 70,
+                g: 150,
+                b: 90,
+            },
+            age_stale: Color::Rgb {
+                r: 215,
+                g: 60,
+                b: 50,
+            },
+
             // Semantic search defaults
             semantic_indicator: Color::Magenta,
             semantic_search_border: Color::Rgb {
@@ -139,6 +164,30 @@ impl Default for Theme {
 }
 
 impl Theme {
+    /// Build the theme with the `display` config's accent and status colors
+    /// applied over the defaults
+    pub fn from_config(display: &DisplayConfig) -> Self {
+        let mut theme = Self::default();
+        if let Some(accent) = display.accent {
+            theme.id_color = tui_color(accent);
+        }
+        let status = &display.status;
+        let overrides = [
+            (status.new, &mut theme.status_new),
+            (status.next, &mut theme.status_next),
+            (status.in_progress, &mut theme.status_in_progress),
+            (status.complete, &mut theme.status_complete),
+            (status.cancelled, &mut theme.status_cancelled),
+            (status.archived, &mut theme.status_archived),
+        ];
+        for (color, slot) in overrides {
+            if let Some(color) = color {
+                *slot = tui_color(color);
+            }
+        }
+        theme
+    }
+
     /// Get the color for a ticket status
     pub fn status_color(&self, status: TicketStatus) -> Color {
         match status {
@@ -171,6 +220,43 @@ impl Theme {
         }
     }
 
+    /// Blend `age_fresh` into `age_stale`, with `staleness` from 0.0 (fresh)
+    /// to 1.0 (stale). Named terminal colors can't be blended, so they switch
+    /// halfway instead.
+    pub fn age_color(&self, staleness: f64) -> Color {
+        let t = staleness.clamp(0.0, 1.0);
+        match (self.age_fresh, self.age_stale) {
+            (
+                Color::Rgb {
+                    r: r1,
+                    g: g1,
+                    b: b1,
+                },
+                Color::Rgb {
+                    r: r2,
+                    g: g2,
+                    b: b2,
+                },
+            ) => {
+                let mix = |from: u8, to: u8| {
+                    (f64::from(from) + (f64::from(to) - f64::from(from)) * t).round() as u8
+                };
+                Color::Rgb {
+                    r: mix(r1, r2),
+                    g: mix(g1, g2),
+                    b: mix(b1, b2),
+                }
+            }
+            (fresh, stale) => {
+                if t < 0.5 {
+                    fresh
+                } else {
+                    stale
+                }
+            }
+        }
+    }
+
     /// Get the color for a markdown heading level.
     pub fn md_heading_color(&self, level: u8) -> Color {
         match level {
@@ -181,8 +267,31 @@ impl Theme {
     }
 }
 
-/// Global theme instance
-pub static THEME: std::sync::LazyLock<Theme> = std::sync::LazyLock::new(Theme::default);
+/// Map a configured color to its terminal color
+fn tui_color(color: ThemeColor) -> Color {
+    match color {
+        ThemeColor::Black => Color::Black,
+        ThemeColor::Red => Color::Red,
+        ThemeColor::Green => Color::Green,
+        ThemeColor::Yellow => Color::Yellow,
+        ThemeColor::Blue => Color::Blue,
+        ThemeColor::Magenta => Color::Magenta,
+        ThemeColor::Cyan => Color::Cyan,
+        ThemeColor::White => Color::White,
+        ThemeColor::Gray => Color::Rgb {
+            r: 120,
+            g: 120,
+            b: 120,
+        },
+    }
+}
+
+/// Global theme instance, with the repository's `display` config applied
+pub static THEME: std::sync::LazyLock<Theme> = std::sync::LazyLock::new(|| {
+    Config::load()
+        .map(|config| Theme::from_config(&config.display))
+        .unwrap_or_default()
+});
 
 /// Get a reference to the global theme
 pub fn theme() -> &'static Theme {
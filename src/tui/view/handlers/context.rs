@@ -18,6 +18,12 @@ use crate::tui::state::Pane;
 pub struct SearchState<'a> {
     pub query: &'a mut State<String>,
     pub orchestrator: &'a mut SearchOrchestrator,
+    /// Recent queries, newest first
+    pub history: &'a mut State<Vec<String>>,
+    /// Highlighted entry while the history dropdown is open
+    pub history_cursor: &'a mut State<Option<usize>>,
+    /// Completions for the word being typed
+    pub suggestions: &'a [String],
 }
 
 /// List navigation state (selection and scroll in list)
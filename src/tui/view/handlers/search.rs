@@ -3,6 +3,7 @@
 use iocraft::prelude::{KeyCode, KeyModifiers};
 
 use crate::tui::handlers::{SearchAction, handle_search_input};
+use crate::tui::search_history::record_search;
 use crate::tui::state::Pane;
 
 use super::HandleResult;
@@ -14,6 +15,22 @@ pub fn handle(
     code: KeyCode,
     modifiers: KeyModifiers,
 ) -> HandleResult {
+    if let Some(cursor) = ctx.search.history_cursor.get() {
+        return handle_history_dropdown(ctx, cursor, code, modifiers);
+    }
+
+    match code {
+        KeyCode::Up if !ctx.search.history.read().is_empty() => {
+            ctx.search.history_cursor.set(Some(0));
+            return HandleResult::Handled;
+        }
+        KeyCode::Tab if !ctx.search.suggestions.is_empty() => {
+            ctx.search.query.set(ctx.search.suggestions[0].clone());
+            return HandleResult::Handled;
+        }
+        _ => {}
+    }
+
     match handle_search_input(code, modifiers) {
         SearchAction::ClearAndExit => {
             ctx.search.query.set(String::new());
@@ -21,9 +38,7 @@ pub fn handle(
             HandleResult::Handled
         }
         SearchAction::Exit => {
-            // User pressed Enter - trigger search execution
-            ctx.search.orchestrator.trigger_pending();
-            ctx.app.active_pane.set(Pane::List);
+            apply_search(ctx);
             HandleResult::Handled
         }
         SearchAction::Quit => {
@@ -33,3 +48,58 @@ pub fn handle(
         SearchAction::Continue => HandleResult::Handled,
     }
 }
+
+/// Handle keys while the history dropdown is open
+///
+/// The newest search is at the top. Down moves to older searches and Up to
+/// newer ones, closing the dropdown from the top entry. Enter/Tab run the
+/// highlighted search and Esc closes the dropdown without changing the
+/// query. Any other key closes the dropdown and is handled as usual.
+fn handle_history_dropdown(
+    ctx: &mut ViewHandlerContext<'_>,
+    cursor: usize,
+    code: KeyCode,
+    modifiers: KeyModifiers,
+) -> HandleResult {
+    let len = ctx.search.history.read().len();
+    match code {
+        KeyCode::Down => {
+            ctx.search
+                .history_cursor
+                .set(Some((cursor + 1).min(len.saturating_sub(1))));
+            HandleResult::Handled
+        }
+        KeyCode::Up => {
+            ctx.search.history_cursor.set(cursor.checked_sub(1));
+            HandleResult::Handled
+        }
+        KeyCode::Enter | KeyCode::Tab => {
+            let entry = ctx.search.history.read().get(cursor).cloned();
+            ctx.search.history_cursor.set(None);
+            if let Some(entry) = entry {
+                ctx.search.query.set(entry);
+            }
+            apply_search(ctx);
+            HandleResult::Handled
+        }
+        KeyCode::Esc => {
+            ctx.search.history_cursor.set(None);
+            HandleResult::Handled
+        }
+        _ => {
+            ctx.search.history_cursor.set(None);
+            handle(ctx, code, modifiers)
+        }
+    }
+}
+
+/// Run the current query, remember it, and move focus to the list
+fn apply_search(ctx: &mut ViewHandlerContext<'_>) {
+    let query = ctx.search.query.to_string();
+    let mut history = ctx.search.history.read().clone();
+    record_search(&mut history, &query);
+    ctx.search.history.set(history);
+
+    ctx.search.orchestrator.trigger_pending();
+    ctx.app.active_pane.set(Pane::List);
+}
@@ -11,18 +11,25 @@ use std::path::PathBuf;
 
 use iocraft::prelude::*;
 
+use crate::config::{Config, ReadyRequirements};
 use crate::tui::components::{
-    Clickable, EmptyState, EmptyStateKind, ModalState, NoteModalData, SearchBox,
-    StoreErrorModalData, TicketDetail, TicketList, TicketModalData, Toast, browser_shortcuts,
-    cancel_confirm_modal_shortcuts, compute_empty_state, edit_shortcuts, empty_shortcuts,
-    error_modal_shortcuts, note_input_modal_shortcuts, search_shortcuts, triage_shortcuts,
+    Clickable, EmptyState, EmptyStateKind, ModalState, NoteModalData, SearchBox, SearchDropdown,
+    StoreErrorModalData, TicketCounts, TicketDetail, TicketList, TicketModalData, Toast,
+    browser_search_shortcuts, browser_shortcuts, cancel_confirm_modal_shortcuts,
+    compute_empty_state, edit_shortcuts, empty_shortcuts, error_modal_shortcuts,
+    note_input_modal_shortcuts, triage_shortcuts,
 };
 use crate::tui::edit::{EditFormOverlay, EditResult};
 use crate::tui::edit_state::{EditFormState, EditMode};
 use crate::tui::hooks::use_ticket_loader;
-use crate::tui::repository::{InitResult, load_ticket_body};
-use crate::tui::screen_base::{ScreenLayout, calculate_list_height, should_process_key_event};
-use crate::tui::search_orchestrator::{SearchState, compute_filtered_tickets};
+use crate::tui::repository::{InitResult, load_ticket_activity, load_ticket_body};
+use crate::tui::screen_base::{
+    ScreenLayout, calculate_list_height, should_process_key_event, use_screen_size,
+};
+use crate::tui::search_history::{collect_labels, load_search_history, suggest_completions};
+use crate::tui::search_orchestrator::{
+    SearchState, compute_filtered_tickets, embedding_index_progress,
+};
 use crate::tui::services::{ExternalEditor, TicketService};
 use crate::tui::state::Pane;
 use crate::types::TicketMetadata;
@@ -51,7 +58,7 @@ pub struct IssueBrowserProps {}
 /// ```
 #[component]
 pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
-    let (width, height) = hooks.use_terminal_size();
+    let (width, height) = use_screen_size(&mut hooks);
     let mut system = hooks.use_context_mut::<SystemContext>();
 
     // State management - initialize with empty state, load asynchronously
@@ -82,6 +89,13 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
     // Search state - search is executed on Enter, not while typing
     let mut search_state = SearchState::use_state(&mut hooks);
 
+    // Recent searches (newest first) and the highlighted entry while the
+    // history dropdown is open
+    let mut search_history: State<Vec<String>> = hooks.use_state(load_search_history);
+    let mut history_cursor: State<Option<usize>> = hooks.use_state(|| None);
+    let ready_require: State<ReadyRequirements> =
+        hooks.use_state(|| Config::load().map(|c| c.ready.require).unwrap_or_default());
+
     // Modal state for triage mode using generic ModalState
     let mut note_modal = ModalState::<NoteModalData>::use_state(&mut hooks);
     let mut cancel_confirm_modal = ModalState::<TicketModalData>::use_state(&mut hooks);
@@ -341,16 +355,29 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
     search_state.clear_if_empty(&query_str);
 
     // Check for semantic search errors and display toast if present
-    // Only show error if user explicitly requested semantic search with ~ prefix
-    if query_str.starts_with('~')
-        && let Some(error) = search_state.take_semantic_error()
-    {
+    // Only show error if user explicitly requested semantic search (~ prefix or mode)
+    let is_semantic = search_state.is_semantic(&query_str);
+    if is_semantic && let Some(error) = search_state.take_semantic_error() {
         // Provide user-friendly error message
         let user_message = format!("Semantic search failed: {error}");
         toast.set(Some(Toast::error(user_message)));
     }
 
     let filtered = compute_filtered_tickets(&all_tickets.read(), &search_state, &query_str);
+    let search_mode = search_state.mode.get();
+    let index_progress = if is_semantic {
+        embedding_index_progress()
+    } else {
+        None
+    };
+
+    // Completions for the word being typed; hidden while browsing history
+    let suggestions =
+        if active_pane.get() == Pane::Search && history_cursor.get().is_none() && !is_semantic {
+            suggest_completions(&query_str, &collect_labels(&all_tickets.read()))
+        } else {
+            Vec::new()
+        };
 
     // Clone filtered for event handler closure (each clone is cheap since FilteredTicket contains Arc)
     let filtered_for_handlers = filtered.clone();
@@ -369,7 +396,11 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
     if cached_body_path.read().as_ref() != current_file_path.as_ref() {
         // Selection changed — reload body from disk once and cache it.
         cached_body_path.set(current_file_path.clone());
-        let body = load_ticket_body(current_file_path.as_ref());
+        let mut body = load_ticket_body(current_file_path.as_ref());
+        if let Some(ticket) = &selected_ticket {
+            let activity = load_ticket_activity(ticket, &body);
+            body.push_str(&activity);
+        }
         let line_count = body.lines().count().saturating_sub(1);
         cached_body.set(body);
         max_detail_scroll.set(line_count);
@@ -406,6 +437,7 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
     hooks.use_terminal_events({
         let filtered_len = filtered_for_handlers.len();
         let filtered_for_events = filtered_for_handlers.clone();
+        let suggestions_for_events = suggestions.clone();
         let is_triage_mode_for_events = is_triage_mode;
         let edit_mode_for_events = edit_mode;
         let note_modal_open = note_modal.is_open();
@@ -425,7 +457,7 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
                     kind,
                     modifiers,
                     ..
-                }) if should_process_key_event(kind) => {
+                }) if should_process_key_event(kind, code, modifiers) => {
                     // Handle store error modal events - any key closes and exits
                     if store_error_open {
                         store_error_modal.close();
@@ -470,6 +502,12 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
                         return;
                     }
 
+                    // Handle Ctrl+S to toggle lexical/semantic search
+                    if code == KeyCode::Char('s') && modifiers == KeyModifiers::CONTROL {
+                        search_state.toggle_mode();
+                        return;
+                    }
+
                     // Handle Ctrl+T to toggle triage mode
                     if code == KeyCode::Char('t') && modifiers == KeyModifiers::CONTROL {
                         // Toggle triage mode
@@ -494,6 +532,9 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
                         search: handlers::SearchState {
                             query: &mut search_query,
                             orchestrator: &mut search_state,
+                            history: &mut search_history,
+                            history_cursor: &mut history_cursor,
+                            suggestions: &suggestions_for_events,
                         },
                         app: handlers::AppState {
                             should_exit: &mut should_exit,
@@ -547,10 +588,16 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
     // with Up/Down/j/k keys. We intentionally do NOT constrain scroll_offset
     // here to allow users to scroll independently of the selection.
 
+    // Header counts are recomputed from the ticket list on every render, so
+    // they stay current after each mutation and watcher reload
     let ticket_count = filtered.len();
-    let tickets_ref_for_count = all_tickets.read();
-    let total_ticket_count = tickets_ref_for_count.len();
-    drop(tickets_ref_for_count);
+    let counts = TicketCounts::compute(
+        &all_tickets.read(),
+        ticket_count,
+        &ready_require.get(),
+        |_| true,
+    );
+    let total_ticket_count = counts.total;
 
     // Get editing state for rendering using shared EditFormState
     let (edit_ticket, edit_body, edit_ticket_key) = {
@@ -604,11 +651,27 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
         triage_shortcuts()
     } else {
         match active_pane.get() {
-            Pane::Search => search_shortcuts(),
+            Pane::Search => browser_search_shortcuts(),
             _ => browser_shortcuts(),
         }
     };
 
+    // Dropdown under the search box: recent searches while browsing history,
+    // otherwise completions for the word being typed
+    let search_dropdown = if active_pane.get() != Pane::Search || is_editing {
+        None
+    } else if let Some(cursor) = history_cursor.get() {
+        Some((
+            "Recent searches",
+            search_history.read().clone(),
+            Some(cursor),
+        ))
+    } else if !suggestions.is_empty() {
+        Some(("Tab to complete", suggestions.clone(), Some(0)))
+    } else {
+        None
+    };
+
     // Build triage action buttons when in triage mode and no modal is open
     let triage_action_buttons: Vec<AnyElement<'_>> =
         if is_triage_mode.get()
@@ -768,7 +831,7 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
             width: width,
             height: height,
             header_subtitle: Some("Browser"),
-            header_ticket_count: Some(ticket_count),
+            header_counts: Some(counts),
             shortcuts: shortcuts,
             action_buttons: triage_action_buttons,
             toast: toast.read().clone(),
@@ -804,7 +867,9 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
                                 SearchBox(
                                     value: Some(search_query),
                                     has_focus: active_pane.get() == Pane::Search && !is_editing,
-                                    is_semantic: query_str.starts_with('~'),
+                                    is_semantic: is_semantic,
+                                    mode: Some(search_mode),
+                                    index_progress: index_progress,
                                 )
                             }
                         }
@@ -874,6 +939,23 @@ pub fn IssueBrowser<'a>(_props: &IssueBrowserProps, mut hooks: Hooks) -> impl In
                                 }
                             })
                         })
+
+                        // Search history / completion dropdown, drawn over
+                        // the top of the list just below the search box
+                        #(search_dropdown.map(|(title, items, selected)| element! {
+                            View(
+                                position: Position::Absolute,
+                                top: 3,
+                                left: 1,
+                                width: 60pct,
+                            ) {
+                                SearchDropdown(
+                                    title: title.to_string(),
+                                    items: items,
+                                    selected: selected,
+                                )
+                            }
+                        }))
                     }
                 })
             })
@@ -7,7 +7,8 @@ use crate::tui::components::empty_state::EmptyStateKind;
 use crate::tui::components::footer::Shortcut;
 use crate::tui::components::toast::Toast;
 use crate::tui::components::{
-    browser_shortcuts, compute_empty_state, edit_shortcuts, empty_shortcuts, search_shortcuts,
+    browser_search_shortcuts, browser_shortcuts, compute_empty_state, edit_shortcuts,
+    empty_shortcuts,
 };
 use crate::tui::repository::InitResult;
 use crate::tui::search::{FilteredTicket, filter_tickets};
@@ -209,7 +210,7 @@ pub fn compute_view_model(state: &ViewState, list_height: usize) -> ViewViewMode
         empty_shortcuts()
     } else {
         match state.active_pane {
-            Pane::Search => search_shortcuts(),
+            Pane::Search => browser_search_shortcuts(),
             _ => browser_shortcuts(),
         }
     };
@@ -8,6 +8,7 @@ use std::str::FromStr;
 use serde::de::{self, Deserializer};
 use serde::{Deserialize, Serialize};
 
+use crate::attachment::Attachment;
 use crate::error::JanusError;
 
 // Re-export path functions from the paths module
@@ -460,7 +461,59 @@ impl From<CreatedAt> for String {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, Hash)]
+/// A calendar date in ticket frontmatter, such as `expires: 2025-08-01`.
+///
+/// Serializes as a plain `YYYY-MM-DD` string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TicketDate(jiff::civil::Date);
+
+impl TicketDate {
+    pub fn new(date: jiff::civil::Date) -> Self {
+        TicketDate(date)
+    }
+
+    pub fn date(self) -> jiff::civil::Date {
+        self.0
+    }
+}
+
+impl fmt::Display for TicketDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for TicketDate {
+    type Err = JanusError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse::<jiff::civil::Date>()
+            .map(TicketDate)
+            .map_err(|_| {
+                JanusError::InvalidInput(format!("invalid date '{s}': expected YYYY-MM-DD"))
+            })
+    }
+}
+
+impl Serialize for TicketDate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TicketDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default, Hash,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum TicketStatus {
     #[default]
@@ -777,21 +830,157 @@ impl FromStr for TicketSize {
 
 pub const VALID_SIZES: &[&str] = TicketSize::ALL_STRINGS;
 
+/// Why a ticket was closed, recorded in the `resolution` field by
+/// `janus close --reason`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TicketResolution {
+    Done,
+    Wontfix,
+    Duplicate,
+}
+
+impl TicketResolution {
+    /// All valid string representations of this enum.
+    pub const ALL_STRINGS: &[&str] = &["done", "wontfix", "duplicate"];
+
+    /// The status a ticket closed with this resolution ends up in
+    pub fn closed_status(self) -> TicketStatus {
+        match self {
+            Self::Done => TicketStatus::Complete,
+            Self::Wontfix | Self::Duplicate => TicketStatus::Cancelled,
+        }
+    }
+}
+
+impl std::fmt::Display for TicketResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Done => write!(f, "done"),
+            Self::Wontfix => write!(f, "wontfix"),
+            Self::Duplicate => write!(f, "duplicate"),
+        }
+    }
+}
+
+impl FromStr for TicketResolution {
+    type Err = JanusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("done") {
+            Ok(TicketResolution::Done)
+        } else if s.eq_ignore_ascii_case("wontfix") {
+            Ok(TicketResolution::Wontfix)
+        } else if s.eq_ignore_ascii_case("duplicate") {
+            Ok(TicketResolution::Duplicate)
+        } else {
+            Err(JanusError::InvalidFieldValue {
+                field: "resolution".to_string(),
+                value: s.to_string(),
+                valid_values: Self::ALL_STRINGS.iter().map(|v| v.to_string()).collect(),
+            })
+        }
+    }
+}
+
 /// Default priority value (P2 - normal priority)
 pub const DEFAULT_PRIORITY: u8 = 2;
 
 /// Default priority as a string for CLI arguments
 pub const DEFAULT_PRIORITY_STR: &str = "2";
 
+/// A conventional section of a ticket body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TicketSection {
+    /// Text under `## Description`, or between the title and the first H2
+    Description,
+    Design,
+    Acceptance,
+    Notes,
+}
+
+impl TicketSection {
+    /// All valid string representations of this enum.
+    pub const ALL_STRINGS: &[&str] = &["description", "design", "acceptance", "notes"];
+
+    /// Heading the section is written under
+    pub fn heading(self) -> &'static str {
+        match self {
+            TicketSection::Description => "Description",
+            TicketSection::Design => "Design",
+            TicketSection::Acceptance => "Acceptance Criteria",
+            TicketSection::Notes => "Notes",
+        }
+    }
+
+    /// Headings recognized when reading, preferred heading first
+    pub fn headings(self) -> &'static [&'static str] {
+        match self {
+            TicketSection::Description => &["Description"],
+            TicketSection::Design => &["Design", "Design Notes"],
+            TicketSection::Acceptance => &["Acceptance Criteria", "Acceptance"],
+            TicketSection::Notes => &["Notes"],
+        }
+    }
+}
+
+enum_display_fromstr!(
+    TicketSection,
+    JanusError::invalid_ticket_section,
+    ["description", "design", "acceptance", "notes"],
+    {
+        Description => "description",
+        Design => "design",
+        Acceptance => "acceptance",
+        Notes => "notes",
+    }
+);
+
+/// The conventional sections of a ticket body, parsed at load time.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TicketSections {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub design: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acceptance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+impl TicketSections {
+    pub fn get(&self, section: TicketSection) -> Option<&str> {
+        match section {
+            TicketSection::Description => self.description.as_deref(),
+            TicketSection::Design => self.design.as_deref(),
+            TicketSection::Acceptance => self.acceptance.as_deref(),
+            TicketSection::Notes => self.notes.as_deref(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TicketField {
     Id,
     Uuid,
     Status,
     Deps,
+    ExternalDeps,
     Links,
     Created,
     CompletedAt,
+    StartedAt,
+    ClosedAt,
+    Resolution,
+    ReopenCount,
+    StatusHistory,
+    SnoozedUntil,
+    Expires,
+    EscalatedAt,
+    LockedBy,
+    LockedUntil,
+    Worktree,
     Type,
     Priority,
     Size,
@@ -803,6 +992,11 @@ pub enum TicketField {
     Depth,
     Triaged,
     Labels,
+    Assignee,
+    Component,
+    PullRequests,
+    Docs,
+    Attachments,
 }
 
 /// Enum for array field names to provide compile-time type safety.
@@ -850,9 +1044,21 @@ impl TicketField {
             TicketField::Uuid => "uuid",
             TicketField::Status => "status",
             TicketField::Deps => "deps",
+            TicketField::ExternalDeps => "external-deps",
             TicketField::Links => "links",
             TicketField::Created => "created",
             TicketField::CompletedAt => "completed-at",
+            TicketField::StartedAt => "started-at",
+            TicketField::ClosedAt => "closed-at",
+            TicketField::Resolution => "resolution",
+            TicketField::ReopenCount => "reopen-count",
+            TicketField::StatusHistory => "status-history",
+            TicketField::SnoozedUntil => "snoozed-until",
+            TicketField::Expires => "expires",
+            TicketField::EscalatedAt => "escalated-at",
+            TicketField::LockedBy => "locked-by",
+            TicketField::LockedUntil => "locked-until",
+            TicketField::Worktree => "worktree",
             TicketField::Type => "type",
             TicketField::Priority => "priority",
             TicketField::Size => "size",
@@ -864,6 +1070,11 @@ impl TicketField {
             TicketField::Depth => "depth",
             TicketField::Triaged => "triaged",
             TicketField::Labels => "labels",
+            TicketField::Assignee => "assignee",
+            TicketField::Component => "component",
+            TicketField::PullRequests => "pull-requests",
+            TicketField::Docs => "docs",
+            TicketField::Attachments => "attachments",
         }
     }
 
@@ -874,9 +1085,21 @@ impl TicketField {
             Uuid,
             Status,
             Deps,
+            ExternalDeps,
             Links,
             Created,
             CompletedAt,
+            StartedAt,
+            ClosedAt,
+            Resolution,
+            ReopenCount,
+            StatusHistory,
+            SnoozedUntil,
+            Expires,
+            EscalatedAt,
+            LockedBy,
+            LockedUntil,
+            Worktree,
             Type,
             Priority,
             Size,
@@ -888,6 +1111,11 @@ impl TicketField {
             Depth,
             Triaged,
             Labels,
+            Assignee,
+            Component,
+            PullRequests,
+            Docs,
+            Attachments,
         ]
     }
 }
@@ -926,6 +1154,16 @@ pub struct TicketMetadata {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub deps: Vec<TicketId>,
 
+    /// Dependencies outside Janus, as URLs (a vendor status page, an issue in
+    /// another tracker). They are never resolved automatically; each one
+    /// blocks the ticket until `janus dep resolve` clears it.
+    #[serde(
+        rename = "external-deps",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub external_deps: Vec<String>,
+
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub links: Vec<TicketId>,
 
@@ -939,6 +1177,56 @@ pub struct TicketMetadata {
     #[serde(rename = "completed-at", skip_serializing_if = "Option::is_none")]
     pub completed_at: Option<CreatedAt>,
 
+    /// Timestamp when the ticket first moved to `in_progress`.
+    #[serde(rename = "started-at", skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<CreatedAt>,
+
+    /// Timestamp when the ticket was last closed. Unlike `completed_at`, this is
+    /// cleared when the ticket is reopened and set again when it closes.
+    #[serde(rename = "closed-at", skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<CreatedAt>,
+
+    /// Why the ticket was closed. Cleared when the ticket is reopened.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<TicketResolution>,
+
+    /// Number of times the ticket has gone from a terminal status back to an
+    /// open one
+    #[serde(rename = "reopen-count", skip_serializing_if = "Option::is_none")]
+    pub reopen_count: Option<u32>,
+
+    /// Every status change, oldest first
+    #[serde(
+        rename = "status-history",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub status_history: Vec<StatusChange>,
+
+    /// Timestamp until which the ticket is hidden from default listings
+    #[serde(rename = "snoozed-until", skip_serializing_if = "Option::is_none")]
+    pub snoozed_until: Option<CreatedAt>,
+
+    /// Date after which an open ticket counts as expired (see `janus expire`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<TicketDate>,
+
+    /// When `janus escalate` last raised the ticket's priority
+    #[serde(rename = "escalated-at", skip_serializing_if = "Option::is_none")]
+    pub escalated_at: Option<CreatedAt>,
+
+    /// Holder of the advisory lock taken by `janus lock`
+    #[serde(rename = "locked-by", skip_serializing_if = "Option::is_none")]
+    pub locked_by: Option<String>,
+
+    /// When the advisory lock expires
+    #[serde(rename = "locked-until", skip_serializing_if = "Option::is_none")]
+    pub locked_until: Option<CreatedAt>,
+
+    /// Git worktree created for the ticket by `janus worktree start`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worktree: Option<String>,
+
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub ticket_type: Option<TicketType>,
 
@@ -955,6 +1243,16 @@ pub struct TicketMetadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remote: Option<String>,
 
+    /// Local name of the person the ticket is assigned to (mapped to remote
+    /// users through the `users` config table)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+
+    /// Area of the codebase the ticket belongs to (e.g. "api"), see the
+    /// `components` config table
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent: Option<TicketId>,
 
@@ -978,6 +1276,22 @@ pub struct TicketMetadata {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub labels: Vec<String>,
 
+    /// Linked GitHub pull requests (e.g., "owner/repo#42")
+    #[serde(
+        rename = "pull-requests",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub pull_requests: Vec<String>,
+
+    /// Labels of related docs in `.janus/docs/`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+
+    /// Attached files, whose content lives in `.janus/objects/`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+
     // --- Runtime-only fields ---
     #[serde(skip)]
     pub title: Option<String>,
@@ -993,6 +1307,10 @@ pub struct TicketMetadata {
     /// Ticket body content (only populated during store initialization, not persisted to YAML)
     #[serde(skip)]
     pub body: Option<String>,
+
+    /// Description, design, acceptance criteria, and notes parsed from the body
+    #[serde(skip)]
+    pub sections: TicketSections,
 }
 
 impl TicketData for TicketMetadata {
@@ -1080,6 +1398,56 @@ impl TicketMetadata {
         self.created.as_ref().and_then(|c| c.to_timestamp())
     }
 
+    /// Whether the ticket is snoozed at `now`, i.e. its `snoozed-until` is
+    /// still in the future. Closed tickets are never considered snoozed.
+    pub fn is_snoozed_at(&self, now: jiff::Timestamp) -> bool {
+        !self.status.unwrap_or_default().is_terminal()
+            && self
+                .snoozed_until
+                .as_ref()
+                .and_then(|c| c.to_timestamp())
+                .is_some_and(|until| until > now)
+    }
+
+    /// Whether the ticket is still open after its `expires` date, as of the
+    /// calendar day `today`
+    pub fn is_expired_on(&self, today: jiff::civil::Date) -> bool {
+        !self.status.unwrap_or_default().is_terminal()
+            && self.expires.is_some_and(|expires| expires.date() < today)
+    }
+
+    /// The holder of the ticket's advisory lock, if the lock has not expired
+    /// by `now`
+    pub fn lock_holder_at(&self, now: jiff::Timestamp) -> Option<&str> {
+        let until = self.locked_until.as_ref()?.to_timestamp()?;
+        if until <= now {
+            return None;
+        }
+        Some(self.locked_by.as_deref().unwrap_or("unknown"))
+    }
+
+    /// When the ticket entered its current status.
+    ///
+    /// Uses the last `status-history` entry when it matches the current
+    /// status. Tickets written before history was kept fall back to
+    /// `started-at` or `closed-at`, then to `created`.
+    pub fn status_since(&self) -> Option<jiff::Timestamp> {
+        let status = self.status.unwrap_or_default();
+        let from_history = self
+            .status_history
+            .last()
+            .filter(|change| change.status == status)
+            .and_then(|change| change.at.to_timestamp());
+        let from_field = match status {
+            TicketStatus::InProgress => self.started_at.as_ref(),
+            s if s.is_terminal() => self.closed_at.as_ref(),
+            _ => None,
+        };
+        from_history
+            .or_else(|| from_field.and_then(|c| c.to_timestamp()))
+            .or_else(|| self.created_timestamp())
+    }
+
     /// Get the item UUID
     pub fn uuid(&self) -> Option<&str> {
         self.uuid.as_deref()
@@ -1133,6 +1501,13 @@ pub struct TicketSummary {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub deps: Vec<TicketId>,
 
+    #[serde(
+        rename = "external-deps",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub external_deps: Vec<String>,
+
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub links: Vec<TicketId>,
 
@@ -1144,6 +1519,43 @@ pub struct TicketSummary {
     #[serde(rename = "completed-at", skip_serializing_if = "Option::is_none")]
     pub completed_at: Option<CreatedAt>,
 
+    #[serde(rename = "started-at", skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<CreatedAt>,
+
+    #[serde(rename = "closed-at", skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<CreatedAt>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<TicketResolution>,
+
+    #[serde(rename = "reopen-count", skip_serializing_if = "Option::is_none")]
+    pub reopen_count: Option<u32>,
+
+    #[serde(
+        rename = "status-history",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub status_history: Vec<StatusChange>,
+
+    #[serde(rename = "snoozed-until", skip_serializing_if = "Option::is_none")]
+    pub snoozed_until: Option<CreatedAt>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<TicketDate>,
+
+    #[serde(rename = "escalated-at", skip_serializing_if = "Option::is_none")]
+    pub escalated_at: Option<CreatedAt>,
+
+    #[serde(rename = "locked-by", skip_serializing_if = "Option::is_none")]
+    pub locked_by: Option<String>,
+
+    #[serde(rename = "locked-until", skip_serializing_if = "Option::is_none")]
+    pub locked_until: Option<CreatedAt>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worktree: Option<String>,
+
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub ticket_type: Option<TicketType>,
 
@@ -1159,6 +1571,12 @@ pub struct TicketSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remote: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent: Option<TicketId>,
 
@@ -1177,6 +1595,19 @@ pub struct TicketSummary {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub labels: Vec<String>,
 
+    #[serde(
+        rename = "pull-requests",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub pull_requests: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+
     #[serde(skip)]
     pub title: Option<String>,
 
@@ -1212,26 +1643,48 @@ impl From<&TicketMetadata> for TicketSummary {
             uuid: meta.uuid.clone(),
             status: meta.status,
             deps: meta.deps.clone(),
+            external_deps: meta.external_deps.clone(),
             links: meta.links.clone(),
             created: meta.created.clone(),
             completed_at: meta.completed_at.clone(),
+            started_at: meta.started_at.clone(),
+            closed_at: meta.closed_at.clone(),
+            resolution: meta.resolution,
+            reopen_count: meta.reopen_count,
+            status_history: meta.status_history.clone(),
+            snoozed_until: meta.snoozed_until.clone(),
+            expires: meta.expires,
+            escalated_at: meta.escalated_at.clone(),
+            locked_by: meta.locked_by.clone(),
+            locked_until: meta.locked_until.clone(),
+            worktree: meta.worktree.clone(),
             ticket_type: meta.ticket_type,
             priority: meta.priority,
             size: meta.size,
             external_ref: meta.external_ref.clone(),
             remote: meta.remote.clone(),
+            assignee: meta.assignee.clone(),
+            component: meta.component.clone(),
             parent: meta.parent.clone(),
             spawned_from: meta.spawned_from.clone(),
             spawn_context: meta.spawn_context.clone(),
             depth: meta.depth,
             triaged: meta.triaged,
             labels: meta.labels.clone(),
+            pull_requests: meta.pull_requests.clone(),
+            docs: meta.docs.clone(),
+            attachments: meta.attachments.clone(),
             title: meta.title.clone(),
             completion_summary: meta.completion_summary.clone(),
         }
     }
 }
 
+/// Whether a dependency is an external URL rather than a ticket ID.
+pub fn is_external_dep(dep: &str) -> bool {
+    dep.starts_with("https://") || dep.starts_with("http://")
+}
+
 /// Validate that a label contains only lowercase letters, digits, and underscores.
 pub fn validate_label(label: &str) -> crate::error::Result<()> {
     if label.is_empty() {
@@ -1248,6 +1701,13 @@ pub fn validate_label(label: &str) -> crate::error::Result<()> {
     Ok(())
 }
 
+/// One entry in a ticket's `status-history`: the status it moved to, and when.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub status: TicketStatus,
+    pub at: CreatedAt,
+}
+
 /// Helper struct for tickets with computed blockers
 #[derive(Debug, Clone)]
 pub struct TicketWithBlockers {
@@ -1808,4 +2268,20 @@ size: large
         assert!(TicketField::all().contains(&TicketField::Size));
         assert!(!TicketField::Size.is_immutable());
     }
+
+    #[test]
+    fn test_ticket_expiry() {
+        let expires: TicketDate = "2025-08-01".parse().unwrap();
+        assert_eq!(expires.to_string(), "2025-08-01");
+        assert!("2025-08-01T00:00:00Z".parse::<TicketDate>().is_err());
+
+        let mut ticket = TicketMetadata {
+            expires: Some(expires),
+            ..Default::default()
+        };
+        assert!(!ticket.is_expired_on(jiff::civil::date(2025, 8, 1)));
+        assert!(ticket.is_expired_on(jiff::civil::date(2025, 8, 2)));
+        ticket.status = Some(TicketStatus::Cancelled);
+        assert!(!ticket.is_expired_on(jiff::civil::date(2025, 8, 2)));
+    }
 }
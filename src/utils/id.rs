@@ -1,6 +1,10 @@
+use std::fs::{self, File};
+use std::path::Path;
+
 use rand::Rng;
 use uuid::Uuid;
 
+use crate::config::{Config, IdMode};
 use crate::error::{JanusError, Result};
 use crate::types::tickets_items_dir;
 
@@ -26,13 +30,42 @@ pub fn generate_uuid() -> String {
 }
 
 /// Generate a unique ticket ID with a custom prefix
+///
+/// The suffix is random or sequential depending on the `ids` config. A
+/// sequential ID is only unique while [`lock_sequential_ids`] is held until
+/// its ticket file is written.
 pub fn generate_id_with_custom_prefix(custom_prefix: Option<&str>) -> Result<String> {
+    let prefix = ticket_prefix(custom_prefix)?;
+    let ids = Config::load().map(|config| config.ids).unwrap_or_default();
+    match ids.mode {
+        IdMode::Random => generate_unique_id_with_prefix(prefix),
+        IdMode::Sequential => generate_sequential_id(prefix, ids.width),
+    }
+}
+
+/// Take the lock that keeps concurrent creates from picking the same
+/// sequential ID for `custom_prefix`, or `None` when IDs are random
+///
+/// Hold it from generating the ID until the ticket file is written. Anything
+/// run while it is held, such as a pre-write hook, must not create a ticket
+/// with the same prefix, or it waits for the lock forever.
+pub fn lock_sequential_ids(custom_prefix: Option<&str>) -> Result<Option<File>> {
+    let prefix = ticket_prefix(custom_prefix)?;
+    let ids = Config::load().map(|config| config.ids).unwrap_or_default();
+    match ids.mode {
+        IdMode::Random => Ok(None),
+        IdMode::Sequential => crate::fs::exclusive_lock(&format!("ids-{prefix}")).map(Some),
+    }
+}
+
+/// The prefix of a new ticket's ID: `custom_prefix` if given, `task` otherwise
+fn ticket_prefix(custom_prefix: Option<&str>) -> Result<&str> {
     match custom_prefix {
         Some(prefix) if !prefix.is_empty() => {
             validate_prefix(prefix)?;
-            generate_unique_id_with_prefix(prefix)
+            Ok(prefix)
         }
-        _ => generate_unique_id_with_prefix("task"),
+        _ => Ok("task"),
     }
 }
 
@@ -95,6 +128,73 @@ pub fn generate_unique_id_with_prefix(prefix: &str) -> Result<String> {
     )))
 }
 
+/// Generate the next sequential ID for `prefix`, zero-padded to `width` digits
+///
+/// Numbering continues after the highest number used by an existing ticket
+/// with this prefix, read from the store when it is loaded and from the
+/// tickets directory otherwise. Candidates whose ticket file already exists
+/// are skipped, so a stale store can't hand out an ID that is in use.
+pub fn generate_sequential_id(prefix: &str, width: usize) -> Result<String> {
+    let tickets_dir = tickets_items_dir();
+
+    let existing: Vec<String> = match crate::store::get_store() {
+        Some(store) => store
+            .get_all_tickets()
+            .into_iter()
+            .filter_map(|t| t.id.map(|id| id.into_inner()))
+            .collect(),
+        None => fs::read_dir(&tickets_dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| {
+                        let path = entry.ok()?.path();
+                        path.file_stem()?.to_str().map(String::from)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+    };
+    next_sequential_id(prefix, width, &existing, &tickets_dir)
+}
+
+/// The first sequential ID after the highest one in `existing` that has no
+/// file in `tickets_dir`
+fn next_sequential_id(
+    prefix: &str,
+    width: usize,
+    existing: &[String],
+    tickets_dir: &Path,
+) -> Result<String> {
+    const MAX_SKIPS: u64 = 1000;
+    let start = existing
+        .iter()
+        .filter_map(|id| sequence_number(id, prefix))
+        .max()
+        .unwrap_or(0)
+        + 1;
+
+    (start..start + MAX_SKIPS)
+        .map(|n| format!("{prefix}-{n:0width$}"))
+        .find(|candidate| {
+            validate_filename(candidate).is_ok()
+                && !tickets_dir.join(format!("{candidate}.md")).exists()
+        })
+        .ok_or_else(|| {
+            JanusError::IdGenerationFailed(format!(
+                "No free sequential ID for prefix '{prefix}' after {MAX_SKIPS} attempts"
+            ))
+        })
+}
+
+/// The number in a sequential ID (`task-0042` -> 42), if `id` is one for `prefix`
+fn sequence_number(id: &str, prefix: &str) -> Option<u64> {
+    let suffix = id.strip_prefix(prefix)?.strip_prefix('-')?;
+    if suffix.is_empty() || !suffix.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    suffix.parse().ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,6 +310,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sequence_number() {
+        assert_eq!(sequence_number("task-0042", "task"), Some(42));
+        assert_eq!(sequence_number("task-a1b2", "task"), None);
+        assert_eq!(sequence_number("bug-0042", "task"), None);
+        assert_eq!(sequence_number("task-sub-0001", "task"), None);
+        assert_eq!(sequence_number("task-", "task"), None);
+    }
+
+    #[test]
+    fn test_next_sequential_id() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path();
+
+        assert_eq!(
+            next_sequential_id("task", 4, &[], dir).unwrap(),
+            "task-0001"
+        );
+
+        let existing: Vec<String> = ["task-0007", "task-a1b2", "bug-0042"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(
+            next_sequential_id("task", 4, &existing, dir).unwrap(),
+            "task-0008"
+        );
+        assert_eq!(
+            next_sequential_id("bug", 2, &existing, dir).unwrap(),
+            "bug-43"
+        );
+
+        // A ticket created since the IDs were read is skipped over
+        std::fs::write(dir.join("task-0008.md"), "").unwrap();
+        std::fs::write(dir.join("task-0009.md"), "").unwrap();
+        assert_eq!(
+            next_sequential_id("task", 4, &existing, dir).unwrap(),
+            "task-0010"
+        );
+        // Nothing is written for the ID that was handed out
+        assert!(!dir.join("task-0010.md").exists());
+    }
+
+    #[test]
+    fn test_lock_sequential_ids() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let janus_dir = temp.path().join(".janus");
+        std::fs::create_dir_all(&janus_dir).unwrap();
+        let _guard = JanusRootGuard::new(&janus_dir);
+
+        assert!(lock_sequential_ids(Some("perf")).unwrap().is_none());
+
+        std::fs::write(janus_dir.join("config.yaml"), "ids:\n  mode: sequential\n").unwrap();
+        let lock = lock_sequential_ids(Some("perf")).unwrap();
+        assert!(lock.is_some());
+        assert!(janus_dir.join(".locks/ids-perf.lock").exists());
+        assert!(lock_sequential_ids(Some("plan")).is_err());
+    }
+
     #[test]
     fn test_generated_id_is_file_safe() {
         let temp = tempfile::TempDir::new().unwrap();
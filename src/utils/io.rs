@@ -1,4 +1,4 @@
-use std::io::{self, BufRead};
+use std::io::{self, BufRead, Write};
 use std::path::Path;
 use std::process::Command;
 
@@ -24,6 +24,12 @@ use crate::error::{JanusError, Result};
 /// The file path argument is safely passed using shell positional parameters
 /// (`$1`) to prevent path-based injection.
 pub fn open_in_editor(path: &Path) -> Result<()> {
+    open_in_editor_with_env(path, &[])
+}
+
+/// Open a file in the user's editor, like [`open_in_editor`], with extra
+/// environment variables set for the editor process
+pub fn open_in_editor_with_env(path: &Path, env: &[(&str, &Path)]) -> Result<()> {
     let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
 
     let status = Command::new("sh")
@@ -31,6 +37,7 @@ pub fn open_in_editor(path: &Path) -> Result<()> {
         .arg(format!("{editor} \"$1\""))
         .arg("--")
         .arg(path)
+        .envs(env.iter().copied())
         .status()?;
 
     if !status.success() {
@@ -40,6 +47,46 @@ pub fn open_in_editor(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Edit `content` in the user's editor via a temporary file and return the
+/// result, trimmed.
+pub fn edit_in_editor(content: &str) -> Result<String> {
+    let mut file = tempfile::Builder::new()
+        .prefix("janus-")
+        .suffix(".md")
+        .tempfile()?;
+    file.write_all(content.as_bytes())?;
+    file.flush()?;
+
+    open_in_editor(file.path())?;
+
+    let edited = std::fs::read_to_string(file.path())?;
+    Ok(edited.trim().to_string())
+}
+
+/// Open a URL in the system's default browser
+///
+/// Uses `open` on macOS, the `url.dll` protocol handler on Windows, and
+/// `xdg-open` elsewhere. The URL is passed as a single argument, never
+/// through a shell: `cmd /C start` would interpret `&`, `|`, and `^` in URLs
+/// taken from remote issue data.
+pub fn open_in_browser(url: &str) -> Result<()> {
+    let status = if cfg!(target_os = "macos") {
+        Command::new("open").arg(url).status()?
+    } else if cfg!(target_os = "windows") {
+        Command::new("rundll32")
+            .args(["url.dll,FileProtocolHandler", url])
+            .status()?
+    } else {
+        Command::new("xdg-open").arg(url).status()?
+    };
+
+    if !status.success() {
+        return Err(JanusError::BrowserFailed(status.code().unwrap_or(-1)));
+    }
+
+    Ok(())
+}
+
 /// Read all input from stdin (for piped input)
 pub fn read_stdin() -> io::Result<String> {
     let stdin = io::stdin();
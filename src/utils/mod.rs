@@ -5,19 +5,24 @@ pub mod text;
 pub mod validation;
 
 // Re-export text utilities for backward compatibility
-pub use text::{truncate_string, wrap_text_lines};
+pub use text::{expand_template, truncate_string, wrap_text_lines};
 
 // Re-export ID utilities for backward compatibility
 pub use id::{
     generate_hash, generate_id_with_custom_prefix, generate_unique_id_with_prefix, generate_uuid,
-    validate_prefix,
+    lock_sequential_ids, validate_prefix,
 };
 
 // Re-export IO utilities for backward compatibility
-pub use io::{is_stdin_tty, open_in_editor, read_stdin};
+pub use io::{
+    edit_in_editor, is_stdin_tty, open_in_browser, open_in_editor, open_in_editor_with_env,
+    read_stdin,
+};
 
 use jiff::Timestamp;
 use regex::Regex;
+use std::ffi::OsStr;
+use std::io::ErrorKind;
 use std::path::Path;
 use std::process::Command;
 use std::sync::LazyLock;
@@ -66,14 +71,16 @@ pub fn ensure_dir() -> std::io::Result<()> {
 
 /// Default contents for the `.janus/.gitignore` file.
 ///
-/// Protects sensitive configuration (API tokens) and large binary files
-/// (embeddings) from accidental inclusion in version control.
-const GITIGNORE_CONTENTS: &str = "config.yaml\nembeddings/\n";
+/// Protects sensitive configuration (API tokens), large binary files
+/// (embeddings), cached remote issues, per-user TUI search history, and
+/// transaction journals from accidental inclusion in version control.
+const GITIGNORE_CONTENTS: &str = "config.yaml\nembeddings/\nremote-cache/\nsearch_history\n.txn/\n";
 
 /// Ensure a `.gitignore` exists in the `.janus/` root directory.
 ///
-/// Creates the file with default entries (config.yaml, embeddings/) only if
-/// it does not already exist. This avoids overwriting user customizations.
+/// Creates the file with default entries (config.yaml, embeddings/,
+/// remote-cache/, search_history, .txn/) only if it does not already exist. This avoids
+/// overwriting user customizations.
 pub fn ensure_gitignore() {
     let gitignore_path = janus_root().join(".gitignore");
     if !gitignore_path.exists() {
@@ -117,20 +124,38 @@ pub fn extract_id_from_path(file_path: &Path, entity_type: &str) -> Result<Strin
 
 /// Get the git user.name config value
 pub fn get_git_user_name() -> Option<String> {
-    Command::new("git")
-        .args(["config", "user.name"])
-        .output()
+    git(None, ["config", "user.name"])
         .ok()
-        .and_then(|output| {
-            if output.status.success() {
-                String::from_utf8(output.stdout)
-                    .ok()
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-            } else {
-                None
-            }
-        })
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Run git in `dir` (the current directory when `None`) and return its stdout.
+///
+/// A non-zero exit is returned as [`JanusError::Git`] with git's stderr.
+pub fn git<I, S>(dir: Option<&Path>, args: I) -> Result<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let mut command = Command::new("git");
+    if let Some(dir) = dir {
+        command.arg("-C").arg(dir);
+    }
+    let output = command.args(args).output().map_err(|e| {
+        if e.kind() == ErrorKind::NotFound {
+            JanusError::Git("git is not installed".to_string())
+        } else {
+            JanusError::Io(e)
+        }
+    })?;
+
+    if !output.status.success() {
+        return Err(JanusError::Git(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
 }
 
 /// Get current ISO date string (without milliseconds)
@@ -140,6 +165,70 @@ pub fn iso_date() -> String {
     now.strftime("%Y-%m-%dT%H:%M:%SZ").to_string()
 }
 
+/// Parse a point in time given as an RFC 3339 timestamp, a date
+/// (`2024-06-01`, midnight UTC), or a duration back from now (`30m`, `12h`,
+/// `7d`, `2w`).
+pub fn parse_since(s: &str) -> Result<Timestamp> {
+    parse_point_in_time(s, false)
+}
+
+/// Parse a point in time given as an RFC 3339 timestamp, a date
+/// (`2025-07-01`, midnight UTC), or a duration ahead from now (`3d`, `2w`).
+pub fn parse_until(s: &str) -> Result<Timestamp> {
+    parse_point_in_time(s, true)
+}
+
+fn parse_point_in_time(s: &str, ahead: bool) -> Result<Timestamp> {
+    let s = s.trim();
+    if let Ok(ts) = s.parse::<Timestamp>() {
+        return Ok(ts);
+    }
+    if let Ok(date) = s.parse::<jiff::civil::Date>() {
+        return date
+            .to_zoned(jiff::tz::TimeZone::UTC)
+            .map(|zoned| zoned.timestamp())
+            .map_err(|e| JanusError::InvalidInput(format!("invalid date '{s}': {e}")));
+    }
+
+    let duration = parse_duration(s).map_err(|_| {
+        JanusError::InvalidInput(format!(
+            "invalid time '{s}': expected a date (2024-06-01), a timestamp, or a duration like 7d"
+        ))
+    })?;
+    let now = Timestamp::now();
+    let result = if ahead {
+        now.checked_add(duration)
+    } else {
+        now.checked_sub(duration)
+    };
+    result.map_err(|_| {
+        JanusError::InvalidInput(format!("invalid time '{s}': duration is out of range"))
+    })
+}
+
+/// Parse a duration given as a whole number of minutes, hours, days, or
+/// weeks (`30m`, `12h`, `7d`, `2w`).
+pub fn parse_duration(s: &str) -> Result<jiff::SignedDuration> {
+    let s = s.trim();
+    let invalid = || {
+        JanusError::InvalidInput(format!(
+            "invalid duration '{s}': expected a number followed by m, h, d, or w (e.g. 7d)"
+        ))
+    };
+    let split = s.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+    let (amount, unit) = s.split_at(split);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    let unit_secs = match unit {
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(invalid()),
+    };
+    let secs = amount.checked_mul(unit_secs).ok_or_else(invalid)?;
+    Ok(jiff::SignedDuration::from_secs(secs))
+}
+
 /// Validate that a string is safe to use as a filename on the current OS
 ///
 /// This function validates filename safety by checking:
@@ -278,6 +367,47 @@ mod tests {
         assert!(date.ends_with('Z'));
     }
 
+    #[test]
+    fn test_parse_since() {
+        assert_eq!(
+            parse_since("2024-06-01").unwrap(),
+            "2024-06-01T00:00:00Z".parse::<Timestamp>().unwrap()
+        );
+        assert_eq!(
+            parse_since("2024-06-01T12:30:00Z").unwrap(),
+            "2024-06-01T12:30:00Z".parse::<Timestamp>().unwrap()
+        );
+
+        let week_ago = parse_since("7d").unwrap();
+        let elapsed = Timestamp::now().duration_since(week_ago);
+        assert!((elapsed.as_secs() - 7 * 24 * 60 * 60).abs() < 60);
+
+        assert!(parse_since("7x").is_err());
+        assert!(parse_since("d").is_err());
+        assert!(parse_since("yesterday").is_err());
+    }
+
+    #[test]
+    fn test_parse_until() {
+        assert_eq!(
+            parse_until("2025-07-01").unwrap(),
+            "2025-07-01T00:00:00Z".parse::<Timestamp>().unwrap()
+        );
+        let in_three_days = parse_until("3d").unwrap();
+        let ahead = in_three_days.duration_since(Timestamp::now());
+        assert!((ahead.as_secs() - 3 * 24 * 60 * 60).abs() < 60);
+        assert!(parse_until("soon").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90m").unwrap().as_secs(), 90 * 60);
+        assert_eq!(parse_duration("24h").unwrap().as_secs(), 24 * 60 * 60);
+        assert_eq!(parse_duration(" 2w ").unwrap().as_secs(), 14 * 24 * 60 * 60);
+        assert!(parse_duration("1.5d").is_err());
+        assert!(parse_duration("12").is_err());
+    }
+
     #[test]
     fn test_extract_id_from_path_valid_ticket() {
         let path = PathBuf::from("/path/to/j-a1b2.md");
@@ -495,6 +625,7 @@ mod tests {
         let contents = std::fs::read_to_string(&gitignore_path).unwrap();
         assert!(contents.contains("config.yaml"));
         assert!(contents.contains("embeddings/"));
+        assert!(contents.contains(".txn/"));
     }
 
     #[test]
@@ -536,4 +667,15 @@ mod tests {
         assert!(contents.contains("config.yaml"));
         assert!(contents.contains("embeddings/"));
     }
+
+    #[test]
+    fn test_git_reports_failures() {
+        let temp = tempfile::TempDir::new().unwrap();
+        git(Some(temp.path()), ["init", "--quiet"]).unwrap();
+        let toplevel = git(Some(temp.path()), ["rev-parse", "--show-toplevel"]).unwrap();
+        assert!(!toplevel.trim().is_empty());
+
+        let err = git(Some(temp.path()), ["rev-parse", "HEAD"]).unwrap_err();
+        assert!(matches!(err, JanusError::Git(msg) if !msg.is_empty()));
+    }
 }
@@ -1,7 +1,46 @@
 //! Text wrapping and truncation utilities
 //!
 //! This module provides utilities for text formatting, wrapping, and truncation
-//! with support for multi-byte characters and proper ellipsis handling.
+//! with support for multi-byte characters and proper ellipsis handling, and for
+//! expanding the `{variable}` templates users can define in the config.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::error::{JanusError, Result};
+
+static TEMPLATE_VARIABLE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{(\w+)\}").expect("template variable regex should be valid"));
+
+/// Replace `{name}` variables in the template called `name`. Braces around
+/// anything but a word are left alone; an unknown variable is an error.
+pub fn expand_template(name: &str, template: &str, variables: &[(&str, &str)]) -> Result<String> {
+    let mut expanded = String::with_capacity(template.len());
+    let mut last = 0;
+    for captures in TEMPLATE_VARIABLE_RE.captures_iter(template) {
+        let whole = captures.get(0).expect("capture 0 is the whole match");
+        let variable = &captures[1];
+        let value = variables
+            .iter()
+            .find(|(key, _)| *key == variable)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| JanusError::UnknownTemplateVariable {
+                template: name.to_string(),
+                variable: variable.to_string(),
+                expected: variables
+                    .iter()
+                    .map(|(key, _)| *key)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            })?;
+        expanded.push_str(&template[last..whole.start()]);
+        expanded.push_str(value);
+        last = whole.end();
+    }
+    expanded.push_str(&template[last..]);
+    Ok(expanded)
+}
 
 /// Truncate a string to a maximum length, handling multi-byte characters properly.
 /// Appends "..." if truncated.
@@ -153,6 +192,24 @@ fn add_ellipsis_if_truncated(mut lines: Vec<String>, truncated: bool, width: usi
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_expand_template() {
+        let variables = [("user", "Sam"), ("date", "2026-03-01")];
+        assert_eq!(
+            expand_template("review", "Reviewed by {user} on {date}: ", &variables).unwrap(),
+            "Reviewed by Sam on 2026-03-01: "
+        );
+        assert_eq!(
+            expand_template("plain", "No variables {} here", &variables).unwrap(),
+            "No variables {} here"
+        );
+        assert!(matches!(
+            expand_template("bad", "By {author}", &variables),
+            Err(JanusError::UnknownTemplateVariable { variable, expected, .. })
+                if variable == "author" && expected == "user, date"
+        ));
+    }
+
     #[test]
     fn test_truncate_string_short() {
         assert_eq!(truncate_string("Hello", 10), "Hello");
@@ -315,3 +315,61 @@ completed-at: 2020-01-01T00:00:00Z
         "Fresh ticket should remain complete"
     );
 }
+
+#[test]
+fn test_ls_and_query_include_archived() {
+    let janus = JanusTest::new();
+
+    janus.write_ticket(
+        "t-arch",
+        "\
+---
+id: t-arch
+uuid: 00000000-0000-0000-0000-000000000003
+status: archived
+type: task
+priority: 2
+created: 2020-01-01T00:00:00Z
+completed-at: 2020-06-01T00:00:00Z
+---
+# Archived ticket
+",
+    );
+    let live = janus.run_success(&["create", "Live"]).trim().to_string();
+
+    let output = janus.run_success(&["ls"]);
+    assert!(output.contains(&live));
+    assert!(
+        !output.contains("t-arch"),
+        "archived ticket listed: {output}"
+    );
+    assert!(
+        janus
+            .run_success(&["ls", "--include-archived"])
+            .contains("t-arch")
+    );
+    assert!(
+        janus
+            .run_success(&["ls", "--status", "archived"])
+            .contains("t-arch")
+    );
+
+    let output = janus.run_success(&["ls", "--closed-since", "2020-01-01", "--include-archived"]);
+    assert!(output.contains("t-arch"));
+    assert!(!output.contains(&live));
+    assert!(
+        !janus
+            .run_success(&["ls", "--closed-since", "2021-01-01", "--include-archived"])
+            .contains("t-arch")
+    );
+
+    assert!(!janus.run_success(&["query"]).contains("t-arch"));
+    let output = janus.run_success(&[
+        "query",
+        "--include-archived",
+        "--closed-since",
+        "2020-01-01",
+    ]);
+    assert!(output.contains("t-arch"));
+    assert!(!output.contains(&live));
+}
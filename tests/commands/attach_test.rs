@@ -0,0 +1,90 @@
+#[path = "../common/mod.rs"]
+mod common;
+use common::JanusTest;
+
+use std::fs;
+
+// ============================================================================
+// Attach command tests
+// ============================================================================
+
+fn object_count(janus: &JanusTest) -> usize {
+    let objects = janus.temp_dir.path().join(".janus/objects");
+    let Ok(prefixes) = fs::read_dir(objects) else {
+        return 0;
+    };
+    prefixes
+        .flatten()
+        .map(|prefix| fs::read_dir(prefix.path()).unwrap().count())
+        .sum()
+}
+
+#[test]
+fn test_attach_same_file_to_two_tickets_stores_it_once() {
+    let janus = JanusTest::new();
+    let id1 = janus
+        .run_success(&["create", "Ticket 1"])
+        .trim()
+        .to_string();
+    let id2 = janus
+        .run_success(&["create", "Ticket 2"])
+        .trim()
+        .to_string();
+    fs::write(janus.temp_dir.path().join("screenshot.png"), b"pixels").unwrap();
+
+    let output = janus.run_success(&["attach", "add", &id1, "screenshot.png"]);
+    assert!(output.contains("Attached screenshot.png"));
+    janus.run_success(&[
+        "attach",
+        "add",
+        &id2,
+        "screenshot.png",
+        "--name",
+        "copy.png",
+    ]);
+    assert_eq!(object_count(&janus), 1);
+
+    let content = janus.read_ticket(&id2);
+    assert!(content.contains("attachments:"));
+    assert!(content.contains("name: copy.png"));
+
+    let output = janus.run_success(&["attach", "ls", &id1]);
+    assert!(output.contains("screenshot.png"));
+    assert!(output.contains("6 B"));
+}
+
+#[test]
+fn test_attach_get_rename_rm_and_gc() {
+    let janus = JanusTest::new();
+    let id = janus
+        .run_success(&["create", "Ticket 1"])
+        .trim()
+        .to_string();
+    fs::write(janus.temp_dir.path().join("log.txt"), b"stack trace").unwrap();
+    janus.run_success(&["attach", "add", &id, "log.txt"]);
+
+    janus.run_success(&["attach", "rename", &id, "log.txt", "crash.txt"]);
+    janus.run_success(&["attach", "get", &id, "crash.txt"]);
+    assert_eq!(
+        fs::read(janus.temp_dir.path().join("crash.txt")).unwrap(),
+        b"stack trace"
+    );
+
+    // Existing files are not overwritten
+    let stderr = janus.run_failure(&["attach", "get", &id, "crash.txt"]);
+    assert!(stderr.contains("already exists"));
+
+    // Removing keeps the object until gc
+    janus.run_success(&["attach", "rm", &id, "crash.txt"]);
+    assert!(!janus.read_ticket(&id).contains("attachments"));
+    assert_eq!(object_count(&janus), 1);
+
+    janus.run_success(&["attach", "gc", "--dry-run"]);
+    assert_eq!(object_count(&janus), 1);
+    let output = janus.run_success(&["attach", "gc"]);
+    assert!(output.contains("Removed 1 unreferenced object"));
+    assert_eq!(object_count(&janus), 0);
+
+    let stderr = janus.run_failure(&["attach", "rm", &id, "crash.txt"]);
+    assert!(stderr.contains("no attachment named 'crash.txt'"));
+}
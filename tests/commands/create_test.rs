@@ -54,6 +54,25 @@ fn test_create_multiple_tickets_same_prefix() {
     assert!(janus.ticket_exists(id2), "Ticket2 should exist");
 }
 
+#[test]
+fn test_create_sequential_ids() {
+    let janus = JanusTest::new();
+    let janus_dir = janus.temp_dir.path().join(".janus");
+    std::fs::create_dir_all(&janus_dir).unwrap();
+    std::fs::write(janus_dir.join("config.yaml"), "ids:\n  mode: sequential\n").unwrap();
+
+    let id1 = janus.run_success(&["create", "First"]).trim().to_string();
+    let id2 = janus.run_success(&["create", "Second"]).trim().to_string();
+    let bug = janus
+        .run_success(&["create", "A bug", "--prefix", "bug"])
+        .trim()
+        .to_string();
+
+    assert_eq!(id1, "task-0001");
+    assert_eq!(id2, "task-0002");
+    assert_eq!(bug, "bug-0001");
+}
+
 #[test]
 fn test_create_tickets_different_prefixes() {
     let janus = JanusTest::new();
@@ -112,3 +131,148 @@ fn test_create_with_invalid_prefix_characters_fails() {
         );
     }
 }
+
+#[test]
+fn test_create_interactive_requires_terminal() {
+    let janus = JanusTest::new();
+
+    let stderr = janus.run_failure(&["create", "--interactive"]);
+    assert!(stderr.contains("needs a terminal"));
+    let stderr = janus.run_failure(&["new"]);
+    assert!(stderr.contains("needs a terminal"));
+
+    // A title is still required without the wizard, and excluded with it
+    janus.run_failure(&["create"]);
+    janus.run_failure(&["create", "Title", "--interactive"]);
+    janus.run_failure(&["create", "--interactive", "--json"]);
+}
+
+#[test]
+fn test_create_from_stdin() {
+    let janus = JanusTest::new();
+
+    let markdown = "# Crash on empty config\n\nStartup panics when config.yaml is empty.\n\n## Design\n\nTreat an empty file as the defaults\n\n## Acceptance Criteria\n\n- [ ] No panic\n";
+    let output = janus.run_with_stdin(&["create", "--from-stdin", "-t", "bug"], markdown);
+    assert!(output.status.success());
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let content = janus.read_ticket(&id);
+    assert!(content.contains("type: bug"));
+    assert!(content.contains("# Crash on empty config"));
+    assert!(content.contains("Startup panics when config.yaml is empty."));
+    assert!(content.contains("## Design\n\nTreat an empty file as the defaults"));
+    assert!(content.contains("## Acceptance Criteria\n\n- [ ] No panic"));
+
+    // Explicit arguments win over the piped markdown
+    let output = janus.run_with_stdin(
+        &["create", "Other title", "--from-stdin", "--design", "Ours"],
+        markdown,
+    );
+    assert!(output.status.success());
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let content = janus.read_ticket(&id);
+    assert!(content.contains("# Other title"));
+    assert!(content.contains("## Design\n\nOurs"));
+    assert!(!content.contains("Treat an empty file"));
+
+    // Without an H1 or a title argument there is nothing to name the ticket
+    let output = janus.run_with_stdin(&["create", "--from-stdin"], "No heading here\n");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_create_batch() {
+    let janus = JanusTest::new();
+    let existing = janus
+        .run_success(&["create", "Existing work"])
+        .trim()
+        .to_string();
+
+    let manifest = format!(
+        r#"
+tickets:
+  - name: api
+    title: Build the reports API
+    parent: epic
+    deps: [schema, {existing}]
+  - name: schema
+    title: Design the reports schema
+    type: chore
+    priority: 1
+  - name: epic
+    title: Reporting
+    type: epic
+"#
+    );
+    std::fs::write(janus.temp_dir.path().join("tickets.yaml"), manifest).unwrap();
+
+    let output = janus.run_success(&["create", "--batch", "tickets.yaml", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let api = json["ids"]["api"].as_str().unwrap();
+    let schema = json["ids"]["schema"].as_str().unwrap();
+    let epic = json["ids"]["epic"].as_str().unwrap();
+    assert_eq!(json["created"][0]["id"], api);
+    assert_eq!(json["created"].as_array().unwrap().len(), 3);
+
+    let content = janus.read_ticket(api);
+    assert!(content.contains(&format!("parent: {epic}")));
+    assert!(content.contains(schema));
+    assert!(content.contains(&existing));
+    let content = janus.read_ticket(schema);
+    assert!(content.contains("type: chore"));
+    assert!(content.contains("priority: 1"));
+}
+
+#[test]
+fn test_create_batch_is_all_or_nothing() {
+    let janus = JanusTest::new();
+    let items = janus.temp_dir.path().join(".janus/items");
+    let ticket_count = || std::fs::read_dir(&items).map_or(0, |dir| dir.count());
+
+    // References are checked before anything is written
+    let output = janus.run_with_stdin(
+        &["create", "--batch", "-"],
+        "tickets:\n  - title: First\n  - title: Second\n    deps: [missing]\n",
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("missing"));
+
+    let output = janus.run_with_stdin(
+        &["create", "--batch", "-"],
+        "tickets:\n  - {name: a, title: A, deps: [b]}\n  - {name: b, title: B, deps: [a]}\n",
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cycle"));
+
+    // A failure partway through removes the tickets already created
+    let output = janus.run_with_stdin(
+        &["create", "--batch", "-"],
+        "tickets:\n  - title: First\n  - title: Second\n    prefix: bad/prefix\n",
+    );
+    assert!(!output.status.success());
+    assert_eq!(ticket_count(), 0);
+
+    janus.run_failure(&["create", "Title", "--batch", "tickets.yaml"]);
+}
+
+#[test]
+fn test_create_suggests_component_without_commits() {
+    let janus = JanusTest::new();
+    janus.write_config("components:\n  suggest: true\n  paths:\n    api: [src/api]\n");
+
+    let create = || {
+        let output = janus.run(&["create", "Ticket"]);
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    };
+
+    // Neither outside a repository nor before its first commit is an error
+    assert!(!create().contains("could not suggest"));
+    let status = std::process::Command::new("git")
+        .args(["init", "--quiet"])
+        .current_dir(janus.temp_dir.path())
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success());
+    assert!(!create().contains("could not suggest"));
+}
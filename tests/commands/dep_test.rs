@@ -137,6 +137,29 @@ fn test_dep_tree_full() {
     assert!(output.contains(&id3));
 }
 
+#[test]
+fn test_dep_tree_color() {
+    let janus = JanusTest::new();
+
+    let id1 = janus.run_success(&["create", "Root"]).trim().to_string();
+    let id2 = janus.run_success(&["create", "Child"]).trim().to_string();
+    janus.run_success(&["dep", "add", &id1, &id2]);
+
+    // Piped output is plain by default
+    let output = janus.run_success(&["dep", "tree", &id1]);
+    assert!(!output.contains('\x1b'));
+    assert!(output.contains(&format!("{id1} [new] Root")));
+
+    // Configured colors apply when forced on: blue IDs, red `new` badges
+    janus.write_config("display:\n  accent: blue\n  status:\n    new: red\n");
+    let output = janus.run_success(&["--color", "always", "dep", "tree", &id1]);
+    assert!(output.contains(&format!("\x1b[34m{id1}\x1b[39m")));
+    assert!(output.contains("\x1b[31m[new]\x1b[39m"));
+
+    let error = janus.run_failure(&["--color", "sometimes", "dep", "tree", &id1]);
+    assert!(error.contains("always"));
+}
+
 // ============================================================================
 // Circular dependency detection tests
 // ============================================================================
@@ -375,3 +398,71 @@ fn test_dep_add_circular_in_middle_of_chain() {
     assert!(output.contains(&id4));
     assert!(!output.contains(&id2)); // C should not depend on B
 }
+
+#[test]
+fn test_blocked_explains_chain() {
+    let janus = JanusTest::new();
+
+    let top = janus.run_success(&["create", "Top"]).trim().to_string();
+    let middle = janus.run_success(&["create", "Middle"]).trim().to_string();
+    let root = janus.run_success(&["create", "Root"]).trim().to_string();
+    let free = janus.run_success(&["create", "Free"]).trim().to_string();
+    janus.run_success(&["dep", "add", &top, &middle]);
+    janus.run_success(&["dep", "add", &middle, &root]);
+    janus.run_success(&["status", &root, "in_progress"]);
+
+    let output = janus.run_success(&["blocked"]);
+    assert!(output.contains(&top));
+    assert!(output.contains(&middle));
+    assert!(!output.contains(&format!("{free} ")));
+    assert!(output.contains("blocked by:"));
+    assert!(output.contains("root blockers:"));
+
+    let output = janus.run_success(&["blocked", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let top_entry = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["id"] == top.as_str())
+        .unwrap();
+    assert_eq!(top_entry["blocked_by"][0]["id"], middle.as_str());
+    assert_eq!(top_entry["blocked_by"][0]["status"], "new");
+    assert_eq!(top_entry["root_blockers"][0]["id"], root.as_str());
+    assert_eq!(top_entry["root_blockers"][0]["status"], "in_progress");
+}
+
+#[test]
+fn test_dep_external_url() {
+    let janus = JanusTest::new();
+
+    let id = janus
+        .run_success(&["create", "Waiting on vendor"])
+        .trim()
+        .to_string();
+    let url = "https://status.example.com/incidents/42";
+
+    let output = janus.run_success(&["dep", "add", &id, url]);
+    assert!(output.contains("Added external dependency"));
+    let content = janus.read_ticket(&id);
+    assert!(content.contains("external-deps:"));
+    assert!(content.contains(url));
+
+    let output = janus.run_success(&["ls", "--ready"]);
+    assert!(!output.contains(&id));
+    let output = janus.run_success(&["blocked"]);
+    assert!(output.contains(&id));
+    assert!(output.contains(url));
+
+    let output = janus.run_success(&["dep", "add", &id, url]);
+    assert!(output.contains("already exists"));
+
+    janus.run_success(&["dep", "resolve", &id, url]);
+    let content = janus.read_ticket(&id);
+    assert!(!content.contains("external-deps"));
+    let output = janus.run_success(&["ls", "--ready"]);
+    assert!(output.contains(&id));
+
+    let stderr = janus.run_failure(&["dep", "resolve", &id, url]);
+    assert!(stderr.contains("not found"));
+}
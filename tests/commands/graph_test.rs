@@ -329,3 +329,43 @@ fn test_graph_plan_not_found() {
         "Should fail for nonexistent graph plan"
     );
 }
+
+#[test]
+fn test_lineage_shows_ancestors_and_descendants() {
+    let janus = JanusTest::new();
+
+    let root = janus.run_success(&["create", "Origin"]).trim().to_string();
+    let mid = janus
+        .run_success(&["create", "Decomposed", "--spawned-from", &root])
+        .trim()
+        .to_string();
+    let leaf = janus
+        .run_success(&["create", "Subtask", "--spawned-from", &mid])
+        .trim()
+        .to_string();
+    let unrelated = janus
+        .run_success(&["create", "Sibling", "--spawned-from", &root])
+        .trim()
+        .to_string();
+
+    let output = janus.run_success(&["lineage", &mid]);
+    assert!(output.contains("Origin"));
+    assert!(output.contains("Subtask"));
+    assert!(!output.contains("Sibling"));
+
+    let output = janus.run_success(&["lineage", &leaf, "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let ancestors: Vec<&str> = json["ancestors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ancestors, vec![root.as_str(), mid.as_str()]);
+    assert_eq!(json["tree"]["id"], leaf);
+
+    let output = janus.run_success(&["lineage", &root, "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["tree"]["spawned"].as_array().unwrap().len(), 2);
+    assert!(output.contains(&unrelated));
+}
@@ -33,6 +33,129 @@ fn test_ls_basic() {
     assert!(output.contains("Ticket 2"));
 }
 
+#[test]
+fn test_ls_no_pager() {
+    let janus = JanusTest::new();
+
+    let id = janus
+        .run_success(&["create", "Ticket 1"])
+        .trim()
+        .to_string();
+
+    // Piped output is never paged, with or without --no-pager
+    let paged = janus.run_success(&["ls"]);
+    let direct = janus.run_success(&["--no-pager", "ls"]);
+    assert_eq!(paged, direct);
+    assert!(direct.contains(&id));
+}
+
+#[test]
+fn test_ls_filters_by_component() {
+    let janus = JanusTest::new();
+
+    let api = janus
+        .run_success(&["create", "Api ticket", "--component", "api"])
+        .trim()
+        .to_string();
+    let web = janus
+        .run_success(&["create", "Web ticket", "--component", "web"])
+        .trim()
+        .to_string();
+    let none = janus
+        .run_success(&["create", "No component"])
+        .trim()
+        .to_string();
+    assert!(janus.read_ticket(&api).contains("component: api"));
+
+    let output = janus.run_success(&["ls", "--component", "API"]);
+    assert!(output.contains(&api));
+    assert!(!output.contains(&web));
+    assert!(!output.contains(&none));
+
+    janus.run_success(&["set", &none, "component", "web"]);
+    let output = janus.run_success(&["ls", "--component", "web", "--json"]);
+    let tickets: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let ids: Vec<&str> = tickets
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&web.as_str()) && ids.contains(&none.as_str()));
+}
+
+#[test]
+fn test_ls_filters_by_prefix() {
+    let janus = JanusTest::new();
+    let janus_dir = janus.temp_dir.path().join(".janus");
+    std::fs::create_dir_all(&janus_dir).unwrap();
+    std::fs::write(
+        janus_dir.join("config.yaml"),
+        "components:\n  prefixes:\n    api: api\n    web: ui\n",
+    )
+    .unwrap();
+
+    let api = janus
+        .run_success(&["create", "Api ticket", "--component", "api"])
+        .trim()
+        .to_string();
+    let web = janus
+        .run_success(&["create", "Web ticket", "--component", "web"])
+        .trim()
+        .to_string();
+    let perf = janus
+        .run_success(&[
+            "create",
+            "Slow query",
+            "--component",
+            "api",
+            "--prefix",
+            "perf",
+        ])
+        .trim()
+        .to_string();
+    assert!(api.starts_with("api-"));
+    assert!(web.starts_with("ui-"));
+    assert!(perf.starts_with("perf-"));
+
+    let output = janus.run_success(&["ls", "--prefix", "api,perf"]);
+    assert!(output.contains(&api));
+    assert!(output.contains(&perf));
+    assert!(!output.contains(&web));
+}
+
+#[test]
+fn test_ls_hides_snoozed_tickets() {
+    let janus = JanusTest::new();
+
+    let snoozed = janus.run_success(&["create", "Later"]).trim().to_string();
+    let awake = janus.run_success(&["create", "Now"]).trim().to_string();
+
+    janus.run_success(&["snooze", &snoozed, "--until", "2w"]);
+    assert!(janus.read_ticket(&snoozed).contains("snoozed-until:"));
+
+    let output = janus.run_success(&["ls"]);
+    assert!(!output.contains(&snoozed));
+    assert!(output.contains(&awake));
+
+    let output = janus.run_success(&["ls", "--ready"]);
+    assert!(!output.contains(&snoozed));
+
+    let output = janus.run_success(&["next"]);
+    assert!(!output.contains(&snoozed));
+
+    let output = janus.run_success(&["ls", "--snoozed"]);
+    let line = output.lines().find(|l| l.contains(&snoozed)).unwrap();
+    assert!(line.contains("[snoozed until"));
+
+    // A snooze in the past is rejected; clearing makes the ticket visible again
+    janus.run_failure(&["snooze", &snoozed, "--until", "2020-01-01"]);
+    janus.run_success(&["snooze", &snoozed, "--clear"]);
+    assert!(!janus.read_ticket(&snoozed).contains("snoozed-until:"));
+    assert!(janus.run_success(&["ls"]).contains(&snoozed));
+}
+
 #[test]
 fn test_ls_status_filter() {
     let janus = JanusTest::new();
@@ -177,6 +300,72 @@ fn test_ls_closed_with_limit() {
     assert_eq!(lines.len(), 5, "All 5 closed tickets should be shown");
 }
 
+#[test]
+fn test_ready_orders_by_score() {
+    let janus = JanusTest::new();
+
+    let low_id = janus
+        .run_success(&["create", "Low priority", "-p", "4"])
+        .trim()
+        .to_string();
+    let high_id = janus
+        .run_success(&["create", "High priority", "-p", "0"])
+        .trim()
+        .to_string();
+    let blocked_id = janus.run_success(&["create", "Blocked"]).trim().to_string();
+    janus.run_success(&["dep", "add", &blocked_id, &low_id]);
+
+    let output = janus.run_success(&["ready"]);
+    let high_pos = output.find(&high_id).unwrap();
+    let low_pos = output.find(&low_id).unwrap();
+    assert!(high_pos < low_pos);
+    assert!(!output.contains(&blocked_id));
+
+    let output = janus.run_success(&["ready", "--explain", "--limit", "1"]);
+    assert!(output.contains(&high_id));
+    assert!(!output.contains(&low_id));
+    assert!(output.contains("priority 10.0"));
+
+    let output = janus.run_success(&["ready", "--json", "--explain"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json[0]["id"], high_id.as_str());
+    assert_eq!(json[0]["score_breakdown"]["priority"], 10.0);
+}
+
+#[test]
+fn test_ready_definition_from_config() {
+    let janus = JanusTest::new();
+
+    let triaged_id = janus
+        .run_success(&["create", "Triaged", "--acceptance", "- [ ] Works"])
+        .trim()
+        .to_string();
+    let content = janus.read_ticket(&triaged_id);
+    janus.write_ticket(
+        &triaged_id,
+        &content.replace("triaged: false", "triaged: true"),
+    );
+    let untriaged_id = janus
+        .run_success(&["create", "Untriaged"])
+        .trim()
+        .to_string();
+
+    janus.write_config("ready:\n  require:\n    triaged: true\n    acceptance_criteria: true\n");
+
+    for args in [&["ready"][..], &["next"][..], &["ls", "--ready"][..]] {
+        let output = janus.run_success(args);
+        assert!(output.contains(&triaged_id), "{args:?}: {output}");
+        assert!(!output.contains(&untriaged_id), "{args:?}: {output}");
+    }
+
+    // With dependencies not required, a blocked ticket is ready too
+    let blocked_id = janus.run_success(&["create", "Blocked"]).trim().to_string();
+    janus.run_success(&["dep", "add", &blocked_id, &untriaged_id]);
+    janus.write_config("ready:\n  require:\n    deps_complete: false\n");
+    let output = janus.run_success(&["ready"]);
+    assert!(output.contains(&blocked_id));
+}
+
 #[test]
 fn test_ls_ready_and_blocked_flags() {
     let janus = JanusTest::new();
@@ -33,6 +33,70 @@ fn test_add_note_multiple() {
     assert!(content.contains("Note 2"));
 }
 
+#[test]
+fn test_add_note_from_template() {
+    let janus = JanusTest::new();
+    janus.write_config(
+        "notes:\n  templates:\n    handoff: \"Handing {id} ({title}) over: \"\n    bad: \"By {author}\"\n",
+    );
+
+    let id = janus
+        .run_success(&["create", "Login fix"])
+        .trim()
+        .to_string();
+    janus.run_success(&["add-note", &id, "--template", "handoff", "ask", "Kai"]);
+    let content = janus.read_ticket(&id);
+    assert!(content.contains(&format!("Handing {id} (Login fix) over: ask Kai")));
+
+    let output = janus.run_with_stdin(&["add-note", &id, "--template", "handoff"], "see PR");
+    assert!(output.status.success());
+    assert!(janus.read_ticket(&id).contains("over: see PR"));
+
+    let stderr = janus.run_failure(&["add-note", &id, "--template", "missing", "text"]);
+    assert!(stderr.contains("'missing' is not defined"));
+    let stderr = janus.run_failure(&["add-note", &id, "--template", "bad", "text"]);
+    assert!(stderr.contains("{author}"));
+}
+
+#[test]
+fn test_note_ls_edit_rm() {
+    let janus = JanusTest::new();
+
+    let id = janus
+        .run_success(&["create", "Test", "-d", "Description"])
+        .trim()
+        .to_string();
+    janus.run_success(&["add-note", &id, "Note 1"]);
+    janus.run_success(&["add-note", &id, "Note 2"]);
+    janus.run_success(&["add-note", &id, "Note 3"]);
+
+    let output = janus.run_success(&["note", "ls", &id, "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let notes = json["notes"].as_array().unwrap();
+    assert_eq!(notes.len(), 3);
+    assert_eq!(notes[1]["index"], 2);
+    assert_eq!(notes[1]["text"], "Note 2");
+
+    janus.run_success(&["note", "edit", &id, "2", "Second", "note"]);
+    janus.run_success(&["note", "rm", &id, "1"]);
+
+    let content = janus.read_ticket(&id);
+    assert!(content.contains("Description"));
+    assert!(!content.contains("Note 1"));
+    assert!(content.contains("Second note"));
+    assert!(content.contains("Note 3"));
+    assert_eq!(content.matches("**20").count(), 2);
+
+    let stderr = janus.run_failure(&["note", "rm", &id, "3"]);
+    assert!(stderr.contains("no note 3"));
+
+    janus.run_success(&["note", "rm", &id, "2"]);
+    janus.run_success(&["note", "rm", &id, "1"]);
+    let content = janus.read_ticket(&id);
+    assert!(!content.contains("## Notes"));
+    assert!(content.contains("Description"));
+}
+
 // ============================================================================
 // Edit command tests
 // ============================================================================
@@ -48,6 +112,16 @@ fn test_edit_non_tty() {
     assert!(output.contains(&id));
 }
 
+#[test]
+fn test_edit_with_helpers_requires_tty() {
+    let janus = JanusTest::new();
+
+    let id = janus.run_success(&["create", "Test"]).trim().to_string();
+    let stderr = janus.run_failure(&["edit", &id, "--with-helpers"]);
+    assert!(stderr.contains("non-interactive"));
+    janus.run_failure(&["edit", &id, "--with-helpers", "--section", "design"]);
+}
+
 // ============================================================================
 // Query command tests
 // ============================================================================
@@ -67,6 +141,214 @@ fn test_query_basic() {
     assert!(output.contains("\"status\":\"new\""));
 }
 
+#[test]
+fn test_query_group_by() {
+    let janus = JanusTest::new();
+
+    janus.run_success(&["create", "First", "--type", "bug"]);
+    janus.run_success(&["create", "Second", "--type", "bug"]);
+    janus.run_success(&["create", "Third", "--type", "task"]);
+
+    let output = janus.run_success(&["query", "--group-by", "type"]);
+    let groups: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Output should be valid JSON"))
+        .collect();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0]["type"], "bug");
+    assert_eq!(groups[0]["count"], 2);
+    assert_eq!(groups[0]["tickets"].as_array().unwrap().len(), 2);
+    assert_eq!(groups[1]["type"], "task");
+
+    let output = janus.run_success(&["query", "--group-by", "type", "--count"]);
+    let first: serde_json::Value = serde_json::from_str(output.lines().next().unwrap()).unwrap();
+    assert_eq!(first, serde_json::json!({"type": "bug", "count": 2}));
+
+    let output = janus.run_success(&["query", "--count"]);
+    assert_eq!(output.trim(), r#"{"count":3}"#);
+}
+
+#[test]
+fn test_count_by() {
+    let janus = JanusTest::new();
+
+    janus.run_success(&["create", "First", "--type", "bug"]);
+    janus.run_success(&["create", "Second", "--type", "task"]);
+
+    assert_eq!(janus.run_success(&["count"]).trim(), "2");
+
+    let output = janus.run_success(&["count", "--by", "status", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["total"], 2);
+    assert_eq!(
+        json["groups"][0],
+        serde_json::json!({"status": "new", "count": 2})
+    );
+
+    let output = janus.run_success(&["count", "--by", "type"]);
+    assert!(output.contains("bug"));
+    assert!(output.contains("total"));
+
+    janus.run_failure(&["count", "--by", "size"]);
+}
+
+#[test]
+fn test_sla_flags_breached_tickets() {
+    let janus = JanusTest::new();
+
+    janus.write_config(
+        "\
+sla:
+  rules:
+    - name: p0-response
+      priority: 0
+      start_within: 24h
+",
+    );
+    janus.write_ticket(
+        "j-old1",
+        "\
+---
+id: j-old1
+uuid: 00000000-0000-0000-0000-000000000001
+status: new
+type: bug
+priority: 0
+created: 2020-01-01T00:00:00Z
+---
+# Old P0 bug
+",
+    );
+    janus.run_success(&["create", "Fresh P0", "--priority", "0"]);
+
+    let output = janus.run_success(&["sla", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let findings = json.as_array().unwrap();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0]["ticket_id"], "j-old1");
+    assert_eq!(findings[0]["rule"], "p0-response");
+    assert_eq!(findings[0]["kind"], "start");
+    assert_eq!(findings[0]["state"], "breached");
+    assert_eq!(findings[0]["title"], "Old P0 bug");
+
+    let output = janus.run_success(&["ls"]);
+    let old_line = output.lines().find(|l| l.contains("j-old1")).unwrap();
+    assert!(old_line.contains("[SLA breached]"));
+    assert_eq!(output.matches("[SLA").count(), 1);
+
+    // Starting the ticket satisfies the start window
+    janus.run_success(&["start", "j-old1"]);
+    let output = janus.run_success(&["sla"]);
+    assert!(output.contains("within their SLAs"));
+}
+
+#[test]
+fn test_stats_workload_flags_overloaded_assignees() {
+    let janus = JanusTest::new();
+    janus.write_config("capacity:\n  wip_limit: 1\n");
+    let tickets = [
+        ("j-a1", 1, "in_progress", Some("sam"), "large"),
+        ("j-a2", 2, "in_progress", Some("Sam"), "small"),
+        ("j-a3", 3, "new", Some("kai"), "small"),
+        ("j-a4", 4, "complete", Some("kai"), "xlarge"),
+        ("j-a5", 5, "next", None, "medium"),
+    ];
+    for (id, n, status, assignee, size) in tickets {
+        let assignee = assignee
+            .map(|a| format!("assignee: {a}\n"))
+            .unwrap_or_default();
+        janus.write_ticket(
+            id,
+            &format!(
+                "---\nid: {id}\nuuid: 00000000-0000-0000-0000-00000000000{n}\nstatus: {status}\nsize: {size}\n{assignee}created: 2026-01-01T00:00:00Z\n---\n# Ticket {id}\n"
+            ),
+        );
+    }
+
+    let output = janus.run_success(&["stats", "workload", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let rows = json.as_array().unwrap();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0]["assignee"], "sam");
+    assert_eq!(rows[0]["in_progress"], 2);
+    assert_eq!(rows[0]["points"], 7);
+    assert_eq!(rows[0]["overloaded"], true);
+    assert_eq!(rows[1]["assignee"], "kai");
+    assert_eq!(rows[1]["open"], 1);
+    assert_eq!(rows[1]["overloaded"], false);
+    assert!(rows[2]["assignee"].is_null());
+    assert_eq!(rows[2]["open"], 1);
+
+    let output = janus.run_success(&["stats", "workload"]);
+    let sam = output.lines().find(|l| l.starts_with("sam")).unwrap();
+    assert!(sam.contains("overloaded: 2 in progress, limit is 1"));
+    assert!(output.contains("(unassigned)"));
+}
+
+#[test]
+fn test_board_export() {
+    let janus = JanusTest::new();
+    let first = janus
+        .run_success(&["create", "Write <docs>", "--priority", "1"])
+        .trim()
+        .to_string();
+    let second = janus.run_success(&["create", "Ship it"]).trim().to_string();
+    janus.run_success(&["set", &second, "assignee", "sam"]);
+    janus.run_success(&["start", &second]);
+
+    let markdown = janus.run_success(&["board", "--export", "md"]);
+    assert!(markdown.starts_with("# Board\n"));
+    let new_column = markdown.split("## NEXT").next().unwrap();
+    assert!(new_column.contains("## NEW (1)"));
+    assert!(new_column.contains(&format!("**[{first}](")));
+    assert!(new_column.contains(&format!("{first}.md)** Write <docs> (P1)")));
+    assert!(markdown.contains("## IN PROGRESS (1)"));
+    assert!(markdown.contains("Ship it (P2, task, @sam)"));
+    assert!(!markdown.contains("ARCHIVED"));
+
+    let html = janus.run_success(&["board", "--export", "html"]);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("Write &lt;docs&gt;"));
+
+    let stderr = janus.run_failure(&["board", "--export", "pdf"]);
+    assert!(stderr.contains("md, html"));
+}
+
+#[test]
+fn test_suggest_ranks_ready_tickets_for_user() {
+    let janus = JanusTest::new();
+    let ticket = |id: &str, n: u32, priority: u8, assignee: Option<&str>| {
+        let assignee = assignee
+            .map(|a| format!("assignee: {a}\n"))
+            .unwrap_or_default();
+        janus.write_ticket(
+            id,
+            &format!(
+                "---\nid: {id}\nuuid: 00000000-0000-0000-0000-00000000000{n}\nstatus: new\npriority: {priority}\n{assignee}created: 2026-01-01T00:00:00Z\n---\n# Ticket {id}\n"
+            ),
+        );
+    };
+    ticket("j-mine", 1, 0, Some("sam"));
+    ticket("j-free", 2, 3, None);
+    ticket("j-theirs", 3, 0, Some("alex"));
+
+    let output = janus.run_success(&["suggest", "--for", "sam", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["user"], "sam");
+    let ids: Vec<_> = json["suggestions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["j-mine", "j-free"]);
+    assert_eq!(json["suggestions"][0]["reasons"][0], "P0");
+
+    let output = janus.run_success(&["suggest", "--for", "sam", "--limit", "1"]);
+    assert!(output.contains("1. j-mine"));
+    assert!(!output.contains("j-free"));
+}
+
 #[test]
 fn test_query_json_format() {
     let janus = JanusTest::new();
@@ -141,6 +423,33 @@ fn test_query_children_count_zero_for_leaf_tickets() {
     }
 }
 
+#[test]
+fn test_fmt_check_and_fix() {
+    let janus = JanusTest::new();
+
+    let id = janus.run_success(&["create", "Tidy"]).trim().to_string();
+
+    janus.write_ticket(
+        &id,
+        &format!(
+            "---\nlabels: [b, a]\nstatus: new\nid: {id}\ncreated: 2024-06-01T12:00:00+02:00\n---\n# Tidy\n\n# Notes\n"
+        ),
+    );
+    let stderr = janus.run_failure(&["fmt", "--check"]);
+    assert!(stderr.contains("not formatted"));
+    assert!(janus.read_ticket(&id).contains("labels: [b, a]"));
+
+    let output = janus.run_success(&["fmt"]);
+    assert!(output.contains("Formatted"));
+    let content = janus.read_ticket(&id);
+    assert!(content.starts_with(&format!("---\nid: {id}\nstatus: new\n")));
+    assert!(content.contains("created: 2024-06-01T10:00:00Z"));
+    assert!(content.contains("labels:\n- b\n- a"));
+    assert!(content.contains("## Notes"));
+
+    janus.run_success(&["fmt", "--check"]);
+}
+
 // ============================================================================
 // Error handling tests
 // ============================================================================
@@ -156,6 +465,16 @@ fn test_ticket_not_found() {
     );
 }
 
+#[test]
+fn test_tui_unknown_plan() {
+    let janus = JanusTest::new();
+    janus.run_success(&["plan", "create", "Real plan"]);
+
+    // The plan is resolved before the TUI takes over the terminal
+    let stderr = janus.run_failure(&["tui", "--plan", "plan-nope"]);
+    assert!(stderr.contains("plan-nope"));
+}
+
 #[test]
 fn test_ambiguous_id() {
     let janus = JanusTest::new();
@@ -194,3 +513,529 @@ fn test_dep_add_nonexistent() {
         "Error should indicate resource was not found"
     );
 }
+
+#[test]
+fn test_events_lists_recent_events() {
+    let janus = JanusTest::new();
+
+    let id = janus.run_success(&["create", "Evented"]).trim().to_string();
+    janus.run_success(&["start", &id]);
+
+    let output = janus.run_success(&["events", "--json"]);
+    let events: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Output should be valid JSON"))
+        .collect();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0]["event_type"], "ticket_created");
+    assert_eq!(events[1]["event_type"], "status_changed");
+
+    let output = janus.run_success(&["events", "-n", "1"]);
+    assert_eq!(output.lines().count(), 1);
+    assert!(output.contains(&format!("{id} status new→in_progress")));
+}
+
+#[test]
+fn test_status_changes_record_timestamps() {
+    let janus = JanusTest::new();
+
+    let id = janus.run_success(&["create", "Timed"]).trim().to_string();
+    janus.run_success(&["start", &id]);
+    janus.run_success(&["close", &id, "--no-summary"]);
+
+    let output = janus.run_success(&["query"]);
+    let ticket: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+    assert!(ticket["started_at"].is_string());
+    assert!(ticket["closed_at"].is_string());
+    let history: Vec<&str> = ticket["status_history"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["status"].as_str().unwrap())
+        .collect();
+    assert_eq!(history, vec!["in_progress", "complete"]);
+
+    let recent = janus.run_success(&["ls", "--closed", "--since", "1d", "--json"]);
+    assert!(recent.contains(&id));
+    let future = janus.run_success(&["ls", "--closed", "--since", "2999-01-01", "--json"]);
+    assert!(!future.contains(&id));
+
+    janus.run_success(&["reopen", &id]);
+    let output = janus.run_success(&["query"]);
+    let ticket: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+    assert!(ticket["closed_at"].is_null());
+}
+
+#[test]
+fn test_triage_lists_untriaged_open_tickets() {
+    let janus = JanusTest::new();
+
+    let pending = janus
+        .run_success(&["create", "Needs triage"])
+        .trim()
+        .to_string();
+    janus.write_ticket(
+        "j-tri1",
+        "\
+---
+id: j-tri1
+uuid: 00000000-0000-0000-0000-000000000002
+status: new
+triaged: true
+created: 2020-01-01T00:00:00Z
+---
+# Already triaged
+",
+    );
+    let closed = janus.run_success(&["create", "Closed"]).trim().to_string();
+    janus.run_success(&["close", &closed, "--no-summary"]);
+
+    let output = janus.run_success(&["triage", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let ids: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec![pending.as_str()]);
+}
+
+#[test]
+fn test_prune_removes_references_to_deleted_tickets() {
+    let janus = JanusTest::new();
+
+    let gone = janus.run_success(&["create", "Deleted"]).trim().to_string();
+    let kept = janus.run_success(&["create", "Kept"]).trim().to_string();
+    let child = janus
+        .run_success(&["create", "Child", "--parent", &gone])
+        .trim()
+        .to_string();
+    janus.run_success(&["dep", "add", &child, &gone]);
+    janus.run_success(&["dep", "add", &child, &kept]);
+    janus.run_success(&["link", "add", &kept, &gone]);
+    let plan_id = janus
+        .run_success(&["plan", "create", "Plan"])
+        .trim()
+        .to_string();
+    janus.run_success(&["plan", "add-ticket", &plan_id, &gone]);
+    janus.run_success(&["plan", "add-ticket", &plan_id, &kept]);
+    janus.delete_ticket(&gone);
+
+    // The dry run reports everything but changes nothing
+    let output = janus.run_success(&["prune", "--dry-run", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["tickets"].as_array().unwrap().len(), 3);
+    assert_eq!(json["plans"][0]["target"], gone.as_str());
+    assert_eq!(json["pruned"], false);
+    assert!(janus.read_ticket(&child).contains(&gone));
+
+    // Non-interactive runs need --yes
+    janus.run_failure(&["prune"]);
+
+    janus.run_success(&["prune", "-y"]);
+    let child_content = janus.read_ticket(&child);
+    assert!(!child_content.contains(&gone));
+    assert!(child_content.contains(&kept));
+    assert!(!janus.read_ticket(&kept).contains(&gone));
+    let plan = janus.read_plan(&plan_id);
+    assert!(!plan.contains(&gone));
+    assert!(plan.contains(&kept));
+
+    let output = janus.run_success(&["prune", "--dry-run"]);
+    assert!(output.contains("No orphaned references found"));
+}
+
+#[test]
+fn test_diff_between_git_revisions() {
+    let janus = JanusTest::new();
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(janus.temp_dir.path())
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {args:?} failed");
+    };
+    git(&["init", "--quiet"]);
+    git(&["config", "user.name", "Test"]);
+    git(&["config", "user.email", "test@example.com"]);
+
+    let closed = janus.run_success(&["create", "Closed"]).trim().to_string();
+    let edited = janus.run_success(&["create", "Edited"]).trim().to_string();
+    let deleted = janus.run_success(&["create", "Deleted"]).trim().to_string();
+    git(&["add", "-A"]);
+    git(&["commit", "--quiet", "-m", "first"]);
+    git(&["tag", "v1"]);
+
+    janus.run_success(&["close", &closed, "--no-summary"]);
+    janus.run_success(&["set", &edited, "priority", "0"]);
+    janus.delete_ticket(&deleted);
+    let created = janus.run_success(&["create", "Created"]).trim().to_string();
+    git(&["add", "-A"]);
+    git(&["commit", "--quiet", "-m", "second"]);
+
+    let output = janus.run_success(&["diff", "v1", "HEAD", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["created"][0]["id"], created.as_str());
+    assert_eq!(json["closed"][0]["id"], closed.as_str());
+    assert_eq!(json["deleted"][0]["id"], deleted.as_str());
+    assert_eq!(json["changed"][0]["id"], edited.as_str());
+    let fields = json["changed"][0]["fields"].as_array().unwrap();
+    assert!(fields.iter().any(|f| f == "priority"));
+
+    let output = janus.run_success(&["diff", "HEAD", "HEAD"]);
+    assert!(output.contains("No ticket changes"));
+
+    janus.run_failure(&["diff", "no-such-ref", "HEAD"]);
+}
+
+#[test]
+fn test_report_github_summary() {
+    let janus = JanusTest::new();
+
+    let blocker = janus
+        .run_success(&["create", "Blocker", "-p", "0"])
+        .trim()
+        .to_string();
+    let blocked = janus.run_success(&["create", "Blocked"]).trim().to_string();
+    janus.run_success(&["dep", "add", &blocked, &blocker]);
+    let done = janus.run_success(&["create", "Done"]).trim().to_string();
+    janus.run_success(&["close", &done, "--no-summary"]);
+
+    let output = janus.run_success(&["report", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["open"], 2);
+    assert_eq!(json["ready"], 1);
+    assert_eq!(json["blocked"], 1);
+    // Freshly created tickets are not stale
+    assert_eq!(json["stale_p0"].as_array().unwrap().len(), 0);
+
+    let output = janus.run_success(&["report", "--stale-days", "0", "--github-summary"]);
+    assert!(output.contains("## Janus tracker health"));
+    assert!(output.contains("| 2 | 0 | 1 | 1 | 1 | 0 |"));
+    assert!(output.contains(&blocker));
+}
+
+#[test]
+fn test_backup_and_restore() {
+    let janus = JanusTest::new();
+
+    let id = janus.run_success(&["create", "Keep me"]).trim().to_string();
+    let output = janus.run_success(&["backup", "-o", "snapshot.tar.zst", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["file"], "snapshot.tar.zst");
+
+    // Refuses to overwrite an existing backup
+    janus.run_failure(&["backup", "-o", "snapshot.tar.zst"]);
+
+    janus.delete_ticket(&id);
+    let later = janus.run_success(&["create", "Later"]).trim().to_string();
+
+    // Non-interactive restores over an existing directory need --yes
+    janus.run_failure(&["restore", "snapshot.tar.zst"]);
+    assert!(!janus.ticket_exists(&id));
+
+    janus.run_success(&["restore", "snapshot.tar.zst", "-y"]);
+    assert!(janus.read_ticket(&id).contains("Keep me"));
+    assert!(!janus.ticket_exists(&later));
+}
+
+#[test]
+fn test_read_only_mode_refuses_mutations() {
+    let janus = JanusTest::new();
+    let id = janus
+        .run_success(&["create", "Existing"])
+        .trim()
+        .to_string();
+
+    let err = janus.run_failure(&["--read-only", "set", &id, "priority", "0"]);
+    assert!(err.contains("read-only mode is enabled"));
+    janus.run_failure(&["create", "New", "--read-only"]);
+    assert!(janus.read_ticket(&id).contains("priority: 2"));
+
+    // Queries still work
+    let output = janus.run_success(&["--read-only", "ls"]);
+    assert!(output.contains(&id));
+    janus.run_success(&["--read-only", "archive", "--dry-run"]);
+
+    let output = std::process::Command::new(common::janus_binary())
+        .args(["add-note", &id, "hello"])
+        .current_dir(janus.temp_dir.path())
+        .env("JANUS_SKIP_EMBEDDINGS", "1")
+        .env("JANUS_READONLY", "1")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(!janus.read_ticket(&id).contains("hello"));
+}
+
+#[test]
+fn test_lock_hides_ticket_from_ready_and_next() {
+    let janus = JanusTest::new();
+
+    let locked = janus.run_success(&["create", "Locked"]).trim().to_string();
+    let free = janus.run_success(&["create", "Free"]).trim().to_string();
+
+    janus.run_success(&["lock", &locked, "--owner", "agent-a", "--ttl", "30m"]);
+    let content = janus.read_ticket(&locked);
+    assert!(content.contains("locked-by: agent-a"));
+    assert!(content.contains("locked-until:"));
+
+    for cmd in ["ready", "next"] {
+        let output = janus.run_success(&[cmd]);
+        assert!(!output.contains(&locked), "{cmd} listed a locked ticket");
+        assert!(output.contains(&free));
+    }
+    assert!(janus.run_success(&["ls"]).contains("[locked by agent-a]"));
+
+    // Another agent can't take or release the lock without --force
+    let stderr = janus.run_failure(&["lock", &locked, "--owner", "agent-b"]);
+    assert!(stderr.contains("locked by agent-a"));
+    janus.run_failure(&["unlock", &locked, "--owner", "agent-b"]);
+
+    janus.run_success(&["unlock", &locked, "--owner", "agent-a"]);
+    assert!(!janus.read_ticket(&locked).contains("locked-by:"));
+    assert!(janus.run_success(&["ready"]).contains(&locked));
+}
+
+#[test]
+fn test_concurrent_lock_has_one_winner() {
+    let janus = JanusTest::new();
+    let id = janus
+        .run_success(&["create", "Contested"])
+        .trim()
+        .to_string();
+
+    let winners = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..6)
+            .map(|i| {
+                let (janus, id) = (&janus, &id);
+                scope.spawn(move || {
+                    let owner = format!("agent-{i}");
+                    janus.run(&["lock", id, "--owner", &owner]).status.success()
+                })
+            })
+            .collect();
+        handles.into_iter().filter(|h| h.join().unwrap()).count()
+    });
+    assert_eq!(winners, 1);
+
+    let content = janus.read_ticket(&id);
+    assert_eq!(content.matches("locked-by:").count(), 1);
+    assert!(content.contains("locked-until:"));
+}
+
+#[test]
+fn test_expired_ticket_is_flagged_and_cancelled() {
+    let janus = JanusTest::new();
+
+    janus.write_ticket(
+        "t-spike",
+        "\
+---
+id: t-spike
+uuid: 00000000-0000-0000-0000-000000000001
+status: new
+type: task
+priority: 2
+created: 2020-01-01T00:00:00Z
+expires: 2020-02-01
+---
+# Try a new parser
+",
+    );
+    let fresh = janus
+        .run_success(&["create", "Later spike"])
+        .trim()
+        .to_string();
+    janus.run_success(&["set", &fresh, "expires", "2w"]);
+    assert!(janus.read_ticket(&fresh).contains("expires: "));
+
+    let output = janus.run_success(&["ls"]);
+    assert!(output.contains("[expired 2020-02-01]"));
+    assert_eq!(output.matches("[expired").count(), 1);
+
+    let output = janus.run_success(&["expire", "--dry-run", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["candidates"], serde_json::json!(["t-spike"]));
+    assert!(janus.read_ticket("t-spike").contains("status: new"));
+
+    let output = janus.run_success(&["expire"]);
+    assert!(output.contains("Cancelled 1 expired ticket(s): t-spike"));
+    let content = janus.read_ticket("t-spike");
+    assert!(content.contains("status: cancelled"));
+    assert!(content.contains("resolution: wontfix"));
+    assert!(content.contains("Expired: passed its expiry date of 2020-02-01"));
+    assert!(janus.read_ticket(&fresh).contains("status: new"));
+}
+
+#[test]
+fn test_escalate_raises_priority_of_old_bugs() {
+    let janus = JanusTest::new();
+    janus.write_config(
+        "escalation:\n  rules:\n    - type: bug\n      older_than: 30d\n      max_priority: 1\n",
+    );
+    janus.write_ticket(
+        "t-bug",
+        "\
+---
+id: t-bug
+uuid: 00000000-0000-0000-0000-000000000001
+status: new
+type: bug
+priority: 2
+created: 2020-01-01T00:00:00Z
+---
+# Old bug
+",
+    );
+    let fresh = janus
+        .run_success(&["create", "New bug", "--type", "bug"])
+        .trim()
+        .to_string();
+
+    let output = janus.run_success(&["escalate"]);
+    assert!(output.contains("Would escalate 1 ticket(s)"));
+    assert!(output.contains("t-bug  P2 -> P1"));
+    assert!(janus.read_ticket("t-bug").contains("priority: 2"));
+
+    let output = janus.run_success(&["escalate", "--apply", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["escalations"][0]["id"], "t-bug");
+    assert_eq!(json["escalations"][0]["to"], 1);
+    let content = janus.read_ticket("t-bug");
+    assert!(content.contains("priority: 1"));
+    assert!(content.contains("escalated-at:"));
+    assert!(content.contains("Escalated from P2 to P1 (bug older than 30d)"));
+    assert!(janus.read_ticket(&fresh).contains("priority: 2"));
+
+    // The window restarts after an escalation, and P1 is the ceiling anyway
+    let output = janus.run_success(&["escalate", "--apply"]);
+    assert!(output.contains("No tickets are due for escalation."));
+}
+
+#[test]
+fn test_worktree_start_ls_cleanup() {
+    let janus = JanusTest::new();
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(janus.temp_dir.path())
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {args:?} failed");
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+    git(&["init", "--quiet"]);
+    git(&["config", "user.name", "Test"]);
+    git(&["config", "user.email", "test@example.com"]);
+
+    let id = janus
+        .run_success(&["create", "Fix login flow"])
+        .trim()
+        .to_string();
+    git(&["add", "-A"]);
+    git(&["commit", "--quiet", "-m", "first"]);
+
+    let path = janus.temp_dir.path().join("wt");
+    let output = janus.run_success(&["worktree", "start", &id, "--path", path.to_str().unwrap()]);
+    let branch = format!("{id}-fix-login-flow");
+    assert!(output.contains(&branch));
+    assert!(path.join(".git").exists());
+    assert!(janus.read_ticket(&id).contains("worktree: "));
+    assert!(git(&["branch", "--list", &branch]).contains(&branch));
+
+    let stderr = janus.run_failure(&["worktree", "start", &id]);
+    assert!(stderr.contains("already has a worktree"));
+
+    let output = janus.run_success(&["worktree", "ls", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json[0]["id"], id.as_str());
+    assert_eq!(json[0]["branch"], branch.as_str());
+    assert_eq!(json[0]["exists"], true);
+
+    // Open tickets keep their worktrees
+    assert!(
+        janus
+            .run_success(&["worktree", "cleanup"])
+            .contains("No worktrees to clean up.")
+    );
+
+    janus.run_success(&["close", &id, "--no-summary"]);
+    let output = janus.run_success(&["worktree", "cleanup", "--dry-run"]);
+    assert!(output.contains("Would remove 1 worktree(s)"));
+    assert!(path.exists());
+
+    janus.run_success(&["worktree", "cleanup"]);
+    assert!(!path.exists());
+    assert!(!janus.read_ticket(&id).contains("worktree: "));
+    assert!(git(&["branch", "--list", &branch]).contains(&branch));
+}
+
+#[test]
+fn test_commit_msg_and_pr_body() {
+    let janus = JanusTest::new();
+    let id = janus
+        .run_success(&[
+            "create",
+            "Handle expired tokens",
+            "--type",
+            "bug",
+            "-d",
+            "Users are logged out mid-session.",
+            "--acceptance",
+            "- Refresh tokens before they expire\n- Show a clear error otherwise",
+        ])
+        .trim()
+        .to_string();
+    janus.run_success(&["set", &id, "component", "auth"]);
+
+    let message = janus.run_success(&["commit-msg", &id]);
+    assert_eq!(
+        message.trim_end(),
+        format!(
+            "fix(auth): Handle expired tokens\n\nUsers are logged out mid-session.\n\nRefs: {id}"
+        )
+    );
+
+    let body = janus.run_success(&["pr-body", &id]);
+    assert!(body.starts_with("## Summary\n\nUsers are logged out mid-session."));
+    assert!(body.contains("- [ ] Refresh tokens before they expire"));
+    assert!(!body.contains("## Design"));
+
+    janus.write_config("messages:\n  commit: \"{kind}: {title} [{id}]\"\n");
+    let message = janus.run_success(&["commit-msg", &id, "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&message).unwrap();
+    assert_eq!(
+        json["message"],
+        format!("fix: Handle expired tokens [{id}]")
+    );
+
+    janus.write_config("messages:\n  commit: \"{author}\"\n");
+    let stderr = janus.run_failure(&["commit-msg", &id]);
+    assert!(stderr.contains("unknown variable '{author}'"));
+}
+
+#[test]
+fn test_schema_dump() {
+    let janus = JanusTest::new();
+
+    let output = janus.run_success(&["schema", "dump"]);
+    let bundle: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(
+        bundle["commands"]["ls"]["items"]["allOf"][0]["$ref"],
+        "#/$defs/Ticket"
+    );
+    assert!(bundle["$defs"]["Ticket"]["properties"]["priority"].is_object());
+    assert!(bundle["mcp_tools"]["create_ticket"]["input"]["properties"]["title"].is_object());
+
+    let output = janus.run_success(&["schema", "dump", "--dir", "schemas"]);
+    assert!(output.contains("schema(s) to schemas"));
+    let dir = janus.temp_dir.path().join("schemas");
+    let content = std::fs::read_to_string(dir.join("commands/plan-status.json")).unwrap();
+    let schema: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(schema["title"], "janus plan status --json");
+    assert!(schema["$defs"]["PhaseStatus"].is_object());
+    assert!(dir.join("mcp/create_ticket.json").exists());
+}
@@ -5,12 +5,14 @@ mod common;
 
 mod aliases_test;
 mod archive_test;
+mod attach_test;
 mod create_test;
 mod dep_test;
 mod graph_test;
 mod link_test;
 mod ls_test;
 mod misc_test;
+mod schema_test;
 mod set_test;
 mod show_test;
 mod status_test;
@@ -0,0 +1,547 @@
+#[path = "../common/mod.rs"]
+mod common;
+use common::JanusTest;
+
+use std::collections::HashSet;
+use std::fs;
+
+use serde_json::Value;
+
+// ============================================================================
+// Schema dump: every command's --json output matches its published schema
+// ============================================================================
+
+/// Where the test writes `janus schema dump --dir` output, under the test directory
+const SCHEMA_DIR: &str = "schemas";
+
+/// Commands whose output the test does not validate, and why
+const NOT_RUN: &[(&str, &str)] = &[
+    ("summarize", "needs an LLM"),
+    ("ac suggest", "needs an LLM"),
+    ("search", "needs ticket embeddings"),
+    ("cache rebuild", "downloads the embedding model"),
+    ("cache sync", "downloads the embedding model"),
+    ("doc add", "fetches a web page"),
+    ("hook install", "fetches a recipe from GitHub"),
+    ("config encrypt", "needs age or gpg"),
+    ("pr link", "fetches pull requests from GitHub"),
+];
+
+const GITHUB_CONFIG: &str = "default_remote:\n  platform: github\n  org: acme\n  repo: app\n";
+
+/// Runs commands and validates their JSON output against the schema of the
+/// command, keeping track of which schemas were checked
+struct SchemaCheck<'a> {
+    janus: &'a JanusTest,
+    validated: HashSet<String>,
+}
+
+impl<'a> SchemaCheck<'a> {
+    fn new(janus: &'a JanusTest) -> Self {
+        janus.run_success(&["schema", "dump", "--dir", SCHEMA_DIR]);
+        SchemaCheck {
+            janus,
+            validated: HashSet::new(),
+        }
+    }
+
+    /// Run a command that prints one JSON document and validate it against
+    /// the schema of `command`
+    fn run(&mut self, command: &str, args: &[&str]) -> Value {
+        let output = self.janus.run_success(args);
+        self.validate_output(command, &output)
+    }
+
+    /// Like [`SchemaCheck::run`], with `input` piped to the command's stdin
+    fn run_with_stdin(&mut self, command: &str, args: &[&str], input: &str) -> Value {
+        let output = self.janus.run_with_stdin(args, input);
+        assert!(
+            output.status.success(),
+            "Command {args:?} failed\nstderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        self.validate_output(command, &String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Run a command that prints one JSON document per line and validate
+    /// each line
+    fn run_lines(&mut self, command: &str, args: &[&str]) {
+        let output = self.janus.run_success(args);
+        let lines: Vec<&str> = output.lines().filter(|l| !l.trim().is_empty()).collect();
+        assert!(!lines.is_empty(), "Command {args:?} printed nothing");
+        for line in lines {
+            self.validate_output(command, line);
+        }
+    }
+
+    fn validate_output(&mut self, command: &str, output: &str) -> Value {
+        let value: Value = serde_json::from_str(output)
+            .unwrap_or_else(|e| panic!("`janus {command}` printed invalid JSON ({e}): {output}"));
+        self.validate(command, &value);
+        value
+    }
+
+    fn validate(&mut self, command: &str, value: &Value) {
+        let name = command.replace(' ', "-");
+        let path = self
+            .janus
+            .temp_dir
+            .path()
+            .join(SCHEMA_DIR)
+            .join("commands")
+            .join(format!("{name}.json"));
+        let schema: Value = serde_json::from_str(
+            &fs::read_to_string(&path).unwrap_or_else(|e| panic!("No schema for `{command}`: {e}")),
+        )
+        .unwrap();
+        let validator = jsonschema::validator_for(&schema)
+            .unwrap_or_else(|e| panic!("Invalid schema for `{command}`: {e}"));
+
+        let errors: Vec<String> = validator
+            .iter_errors(value)
+            .map(|e| format!("  at '{}': {e}", e.instance_path))
+            .collect();
+        assert!(
+            errors.is_empty(),
+            "`janus {command} --json` does not match its schema:\n{}\noutput: {value:#}",
+            errors.join("\n")
+        );
+        self.validated.insert(name);
+    }
+}
+
+fn git(janus: &JanusTest, args: &[&str]) {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .current_dir(janus.temp_dir.path())
+        .output()
+        .expect("Failed to run git");
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn commit_all(janus: &JanusTest, message: &str) {
+    git(janus, &["add", "-A"]);
+    git(janus, &["commit", "--quiet", "-m", message]);
+}
+
+fn id_of(value: &Value) -> String {
+    value["id"]
+        .as_str()
+        .expect("output should have an id")
+        .to_string()
+}
+
+#[test]
+fn test_json_output_matches_schema() {
+    let janus = JanusTest::new().with_remote_fixtures("basic");
+    janus.write_config(GITHUB_CONFIG);
+    git(&janus, &["init", "--quiet"]);
+    git(&janus, &["config", "user.name", "Test"]);
+    git(&janus, &["config", "user.email", "test@example.com"]);
+    let mut check = SchemaCheck::new(&janus);
+
+    // Tickets
+    let id = id_of(&check.run(
+        "create",
+        &[
+            "create",
+            "Fix login flow",
+            "-d",
+            "Users are logged out mid-session.",
+            "--json",
+        ],
+    ));
+    let dep = janus
+        .run_success(&["create", "Add session store"])
+        .trim()
+        .to_string();
+    let child = janus
+        .run_success(&["create", "Retry on timeout", "--spawned-from", &id])
+        .trim()
+        .to_string();
+    commit_all(&janus, "first");
+
+    check.run("show", &["show", &id, "--json"]);
+    check.run("show", &["show", &id, "--section", "description", "--json"]);
+    check.run("show", &["show", &id, "--activity", "--json"]);
+    check.run("context", &["context", &id, "--json"]);
+    check.run("edit", &["edit", &id, "--json"]);
+    check.run("lineage", &["lineage", &child, "--json"]);
+    check.run("path", &["path", &id, "--json"]);
+    check.run("open", &["open", &id, "--json"]);
+    check.run("commit-msg", &["commit-msg", &id, "--json"]);
+    check.run("pr-body", &["pr-body", &id, "--json"]);
+
+    check.run("add-note", &["add-note", "--json", &id, "First note"]);
+    check.run("note ls", &["note", "ls", &id, "--json"]);
+    check.run(
+        "note edit",
+        &["note", "edit", "--json", &id, "1", "Edited note"],
+    );
+    check.run("note rm", &["note", "rm", &id, "1", "--json"]);
+
+    check.run("start", &["start", &id, "--json"]);
+    check.run("status", &["status", &dep, "next", "--json"]);
+    check.run("set", &["set", &id, "priority", "1", "--json"]);
+    check.run("size", &["size", &id, "small", "--json"]);
+    check.run("snooze", &["snooze", &child, "--until", "3d", "--json"]);
+    check.run("snooze", &["snooze", &child, "--clear", "--json"]);
+    check.run("lock", &["lock", &id, "--owner", "alice", "--json"]);
+    check.run("unlock", &["unlock", &id, "--owner", "alice", "--json"]);
+
+    let url = "https://example.com/issues/1";
+    check.run("dep add", &["dep", "add", &id, &dep, "--json"]);
+    check.run("dep add", &["dep", "add", &id, url, "--json"]);
+    check.run("dep resolve", &["dep", "resolve", &id, url, "--json"]);
+    check.run("dep tree", &["dep", "tree", &id, "--json"]);
+    check.run("blocked", &["blocked", "--json"]);
+    check.run("dep remove", &["dep", "remove", &id, &dep, "--json"]);
+    check.run("link add", &["link", "add", &id, &dep, "--json"]);
+    check.run("link remove", &["link", "remove", &id, &dep, "--json"]);
+
+    fs::write(janus.temp_dir.path().join("trace.txt"), "timeout after 30s").unwrap();
+    check.run("attach add", &["attach", "add", &id, "trace.txt", "--json"]);
+    check.run("attach ls", &["attach", "ls", &id, "--json"]);
+    check.run(
+        "attach rename",
+        &["attach", "rename", &id, "trace.txt", "log.txt", "--json"],
+    );
+    check.run(
+        "attach get",
+        &["attach", "get", &id, "log.txt", "-o", "copy.txt", "--json"],
+    );
+    check.run("attach rm", &["attach", "rm", &id, "log.txt", "--json"]);
+    check.run("attach gc", &["attach", "gc", "--dry-run", "--json"]);
+
+    // Listings
+    check.run("ls", &["ls", "--json"]);
+    check.run_lines("query", &["query"]);
+    check.run_lines("query", &["query", "--group-by", "status"]);
+    check.run("count", &["count", "--json"]);
+    check.run("count", &["count", "--by", "status", "--json"]);
+    check.run("sla", &["sla", "--json"]);
+    check.run("stats workload", &["stats", "workload", "--json"]);
+    check.run("triage", &["triage", "--json"]);
+    check.run("archive", &["archive", "--dry-run", "--json"]);
+    check.run("escalate", &["escalate", "--json"]);
+    check.run("expire", &["expire", "--dry-run", "--json"]);
+    check.run("graph", &["graph", "--json"]);
+    check.run("next", &["next", "--json"]);
+    check.run("ready", &["ready", "--explain", "--json"]);
+    check.run("suggest", &["suggest", "--for", "alice", "--json"]);
+    check.run("report", &["report", "--json"]);
+
+    // Plans
+    let plan = id_of(&check.run(
+        "plan create",
+        &[
+            "plan", "create", "Roadmap", "--phase", "Design", "--phase", "Build", "--json",
+        ],
+    ));
+    check.run(
+        "plan add-ticket",
+        &[
+            "plan",
+            "add-ticket",
+            &plan,
+            &id,
+            "--phase",
+            "Design",
+            "--json",
+        ],
+    );
+    janus.run_success(&["plan", "add-ticket", &plan, &dep, "--phase", "Design"]);
+    commit_all(&janus, "second");
+
+    check.run("diff", &["diff", "HEAD~1", "HEAD", "--json"]);
+    check.run("blame", &["blame", &id, "--json"]);
+    check.run(
+        "plan move-ticket",
+        &[
+            "plan",
+            "move-ticket",
+            &plan,
+            &dep,
+            "--to-phase",
+            "Build",
+            "--json",
+        ],
+    );
+    check.run("plan diff", &["plan", "diff", &plan, "--json"]);
+    check.run("plan show", &["plan", "show", &plan, "--json"]);
+    check.run("plan edit", &["plan", "edit", &plan, "--json"]);
+    check.run("plan ls", &["plan", "ls", "--json"]);
+    check.run("plan next", &["plan", "next", &plan, "--json"]);
+    check.run("plan status", &["plan", "status", &plan, "--json"]);
+    check.run("plan forecast", &["plan", "forecast", &plan, "--json"]);
+    check.run(
+        "plan add-phase",
+        &["plan", "add-phase", &plan, "Ship", "--json"],
+    );
+    check.run(
+        "plan rename-phase",
+        &["plan", "rename-phase", &plan, "Ship", "Release", "--json"],
+    );
+    check.run(
+        "plan annotate-phase",
+        &[
+            "plan",
+            "annotate-phase",
+            &plan,
+            "Release",
+            "--description",
+            "Tag and publish",
+            "--json",
+        ],
+    );
+    check.run(
+        "plan remove-phase",
+        &["plan", "remove-phase", &plan, "Release", "--json"],
+    );
+    check.run_with_stdin(
+        "plan reorder",
+        &[
+            "plan",
+            "reorder",
+            &plan,
+            "--reorder-phases",
+            "--from-file",
+            "-",
+            "--json",
+        ],
+        "2\n1\n",
+    );
+    check.run(
+        "plan rename",
+        &["plan", "rename", &plan, "Q3 roadmap", "--json"],
+    );
+    check.run(
+        "plan remove-ticket",
+        &["plan", "remove-ticket", &plan, &dep, "--json"],
+    );
+
+    fs::write(
+        janus.temp_dir.path().join("import.md"),
+        "# Imported Plan\n\n## Design\n\nDesign notes.\n\n## Implementation\n\n### Phase 1: Setup\n\n#### Task One\n\nFirst task.\n",
+    )
+    .unwrap();
+    check.run(
+        "plan import",
+        &["plan", "import", "import.md", "--dry-run", "--json"],
+    );
+    let imported = id_of(&check.run("plan import", &["plan", "import", "import.md", "--json"]));
+    check.run("plan verify", &["plan", "verify", "--json"]);
+    check.run(
+        "plan delete",
+        &["plan", "delete", &imported, "--force", "--json"],
+    );
+
+    // Docs and objectives
+    check.run(
+        "doc create",
+        &[
+            "doc",
+            "create",
+            "architecture",
+            "-t",
+            "Architecture",
+            "-d",
+            "How the pieces fit together",
+            "--json",
+        ],
+    );
+    check.run("doc ls", &["doc", "ls", "--json"]);
+    check.run("doc show", &["doc", "show", "architecture", "--json"]);
+    check.run("doc edit", &["doc", "edit", "architecture", "--json"]);
+    check.run("doc link", &["doc", "link", "architecture", &id, "--json"]);
+    check.run(
+        "doc unlink",
+        &["doc", "unlink", "architecture", &id, "--json"],
+    );
+    check.run("doc search", &["doc", "search", "architecture", "--json"]);
+
+    let objective = id_of(&check.run(
+        "objective create",
+        &[
+            "objective",
+            "create",
+            "Reliable login",
+            "-c",
+            "No failed logins",
+            "--json",
+        ],
+    ));
+    check.run(
+        "objective show",
+        &["objective", "show", &objective, "--json"],
+    );
+    check.run(
+        "objective show",
+        &["objective", "show", &objective, "--raw", "--json"],
+    );
+    check.run("objective ls", &["objective", "ls", "--json"]);
+    check.run(
+        "objective edit",
+        &["objective", "edit", &objective, "--json"],
+    );
+    check.run(
+        "objective ref add",
+        &["objective", "ref", "add", &objective, &id, "--json"],
+    );
+    check.run(
+        "objective ref del",
+        &["objective", "ref", "del", &objective, &id, "--json"],
+    );
+    check.run(
+        "objective ref reset",
+        &["objective", "ref", "reset", &objective, "--force", "--json"],
+    );
+    check.run(
+        "objective add-note",
+        &[
+            "objective",
+            "add-note",
+            &objective,
+            "-t",
+            "On track",
+            "--json",
+        ],
+    );
+    check.run(
+        "objective add-criterion",
+        &[
+            "objective",
+            "add-criterion",
+            &objective,
+            "Sessions survive restarts",
+            "--json",
+        ],
+    );
+    check.run(
+        "objective delete",
+        &["objective", "delete", &objective, "-y", "--json"],
+    );
+
+    // Remotes, served from fixtures
+    let adopted = id_of(&check.run(
+        "remote adopt",
+        &["remote", "adopt", "github:acme/app/1", "--json"],
+    ));
+    check.run("open", &["open", &adopted, "--remote", "--json"]);
+    check.run("remote sync", &["remote", "sync", &adopted, "--json"]);
+    check.run("remote sync", &["remote", "sync", "--all", "--json"]);
+    let pushed = janus
+        .run_success(&["create", "Export to CSV"])
+        .trim()
+        .to_string();
+    check.run("remote push", &["remote", "push", &pushed, "--json"]);
+    let linked = janus
+        .run_success(&["create", "Add dark mode"])
+        .trim()
+        .to_string();
+    check.run(
+        "remote link",
+        &["remote", "link", &linked, "github:acme/app/2", "--json"],
+    );
+    let release = janus
+        .run_success(&["plan", "create", "Release"])
+        .trim()
+        .to_string();
+    let changelog = janus
+        .run_success(&["create", "Write the changelog"])
+        .trim()
+        .to_string();
+    janus.run_success(&["plan", "add-ticket", &release, &changelog]);
+    check.run("plan push", &["plan", "push", &release, "--json"]);
+
+    // Worktrees
+    let worktree = janus.temp_dir.path().join("wt");
+    check.run(
+        "worktree start",
+        &[
+            "worktree",
+            "start",
+            &id,
+            "--path",
+            worktree.to_str().unwrap(),
+            "--json",
+        ],
+    );
+    check.run("worktree ls", &["worktree", "ls", "--json"]);
+    check.run(
+        "worktree cleanup",
+        &["worktree", "cleanup", "--dry-run", "--json"],
+    );
+
+    // Closing
+    check.run("close", &["close", &id, "--no-summary", "--json"]);
+    check.run("reopen", &["reopen", &id, "--json"]);
+    check.run(
+        "close",
+        &[
+            "close",
+            "--ids",
+            &format!("{id},{dep}"),
+            "--no-summary",
+            "--json",
+        ],
+    );
+
+    // Maintenance
+    check.run("config show", &["config", "show", "--json"]);
+    check.run(
+        "config set",
+        &[
+            "config",
+            "set",
+            "default.remote",
+            "github:acme/app",
+            "--json",
+        ],
+    );
+    check.run("config get", &["config", "get", "default.remote", "--json"]);
+    check.run("cache status", &["cache", "status", "--json"]);
+    check.run("cache prune", &["cache", "prune", "--json"]);
+    check.run("hook list", &["hook", "list", "--json"]);
+    check.run("hook disable", &["hook", "disable", "--json"]);
+    check.run("hook enable", &["hook", "enable", "--json"]);
+    check.run("hook log", &["hook", "log", "--json"]);
+    check.run("doctor", &["doctor", "--json"]);
+    check.run("fmt", &["fmt", "--json"]);
+    check.run("prune", &["prune", "--dry-run", "--json"]);
+    check.run("backup", &["backup", "-o", "snapshot.tar.zst", "--json"]);
+    check.run("restore", &["restore", "snapshot.tar.zst", "-y", "--json"]);
+    check.run_lines("events", &["events", "-n", "1000", "--json"]);
+    check.run("events prune", &["events", "prune", "--json"]);
+
+    // Every schema is either validated above or skipped for a reason
+    let commands_dir = janus.temp_dir.path().join(SCHEMA_DIR).join("commands");
+    let mut unchecked: HashSet<String> = fs::read_dir(&commands_dir)
+        .unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            path.file_stem().unwrap().to_string_lossy().into_owned()
+        })
+        .collect();
+    for (command, reason) in NOT_RUN {
+        let name = command.replace(' ', "-");
+        assert!(
+            !check.validated.contains(&name),
+            "`{command}` is validated, so it should not be skipped ({reason})"
+        );
+        assert!(unchecked.remove(&name), "No schema for skipped `{command}`");
+    }
+    for name in &check.validated {
+        unchecked.remove(name);
+    }
+    let mut unchecked: Vec<String> = unchecked.into_iter().collect();
+    unchecked.sort();
+    assert!(
+        unchecked.is_empty(),
+        "Schemas never checked against command output: {unchecked:?}"
+    );
+}
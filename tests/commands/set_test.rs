@@ -323,3 +323,95 @@ fn test_set_description_clear() {
         "Description should be cleared"
     );
 }
+
+#[test]
+fn test_set_preserves_toml_frontmatter() {
+    let janus = JanusTest::new();
+    janus.write_ticket(
+        "j-toml",
+        "+++\nid = \"j-toml\"\nstatus = \"new\"\ndeps = []\nlinks = []\ncreated = \"2026-01-01T00:00:00Z\"\ntype = \"task\"\npriority = 2\n+++\n# TOML ticket\n\nBody.\n",
+    );
+
+    janus.run_success(&["set", "j-toml", "priority", "0"]);
+
+    let content = janus.read_ticket("j-toml");
+    assert!(content.starts_with("+++\n"), "got: {content}");
+    assert!(content.contains("priority = 0"));
+    assert!(content.contains("# TOML ticket"));
+
+    let output = janus.run_success(&["show", "j-toml", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["priority"], 0);
+    assert_eq!(json["title"], "TOML ticket");
+}
+
+#[test]
+fn test_set_rejects_mirrored_fields() {
+    let janus = JanusTest::new();
+    janus.write_ticket(
+        "j-mirror",
+        "---\nid: j-mirror\nstatus: new\ndeps: []\nlinks: []\ncreated: 2026-01-01T00:00:00Z\ntype: task\npriority: 2\nremote: linear:acme/ENG-12\n---\n# Mirrored ticket\n",
+    );
+    std::fs::write(
+        janus.temp_dir.path().join(".janus/config.yaml"),
+        "remotes:\n  linear:\n    mode: mirror\n",
+    )
+    .unwrap();
+
+    let stderr = janus.run_failure(&["set", "j-mirror", "status", "complete"]);
+    assert!(
+        stderr.contains("mirrored from linear:acme/ENG-12"),
+        "got: {stderr}"
+    );
+    assert!(janus.read_ticket("j-mirror").contains("status: new"));
+
+    // Fields that are not synced stay editable
+    janus.run_success(&["set", "j-mirror", "priority", "0"]);
+    assert!(janus.read_ticket("j-mirror").contains("priority: 0"));
+}
+
+#[test]
+fn test_size_command() {
+    let janus = JanusTest::new();
+    let id = janus
+        .run_success(&["create", "Test ticket"])
+        .trim()
+        .to_string();
+
+    let output = janus.run_success(&["size", &id, "xl"]);
+    assert!(output.contains("(none) -> xlarge (8 points)"));
+    assert!(janus.read_ticket(&id).contains("size: xlarge"));
+
+    let output = janus.run_success(&["ls", "--ready", "--size", "xlarge"]);
+    assert!(output.contains(&id));
+    let output = janus.run_success(&["ls", "--ready", "--size", "xs,s"]);
+    assert!(!output.contains(&id));
+
+    let stderr = janus.run_failure(&["size", &id, "huge"]);
+    assert!(stderr.contains("huge"));
+}
+
+#[test]
+fn test_set_status_honors_transitions() {
+    let janus = JanusTest::new();
+    let janus_dir = janus.temp_dir.path().join(".janus");
+    std::fs::create_dir_all(&janus_dir).unwrap();
+    std::fs::write(
+        janus_dir.join("config.yaml"),
+        "transitions:\n  rules:\n    - from: complete\n      to: new\n      policy: forbid\n      allow_via: [reopen]\n    - to: cancelled\n      policy: confirm\n",
+    )
+    .unwrap();
+
+    let id = janus.run_success(&["create", "Test"]).trim().to_string();
+    janus.run_success(&["close", &id, "--no-summary"]);
+
+    let stderr = janus.run_failure(&["set", &id, "status", "new"]);
+    assert!(stderr.contains("complete"));
+    assert!(janus.read_ticket(&id).contains("status: complete"));
+
+    // Confirmation can't be prompted for without a terminal
+    janus.run_failure(&["set", &id, "status", "cancelled"]);
+    assert!(janus.read_ticket(&id).contains("status: complete"));
+    janus.run_success(&["set", &id, "status", "cancelled", "--yes"]);
+    assert!(janus.read_ticket(&id).contains("status: cancelled"));
+}
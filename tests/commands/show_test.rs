@@ -179,3 +179,174 @@ fn test_show_children_count_in_json() {
         "Expected children_count to be 2 in JSON output:\n{output}"
     );
 }
+
+#[test]
+fn test_context_bundles_related_tickets_and_notes() {
+    let janus = JanusTest::new();
+
+    let parent_id = janus
+        .run_success(&["create", "Epic work"])
+        .trim()
+        .to_string();
+    let child_id = janus
+        .run_success(&["create", "Child task", "--parent", &parent_id])
+        .trim()
+        .to_string();
+    let dep_id = janus
+        .run_success(&["create", "Prerequisite"])
+        .trim()
+        .to_string();
+    janus.run_success(&["dep", "add", &child_id, &dep_id]);
+    janus.run_success(&["add-note", &child_id, "First note"]);
+    janus.run_success(&["add-note", &child_id, "Second note"]);
+
+    let output = janus.run_success(&["context", &child_id, "--notes", "1", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["ticket"]["metadata"]["id"], child_id.as_str());
+    assert_eq!(json["parents"][0]["id"], parent_id.as_str());
+    assert_eq!(json["deps"][0]["id"], dep_id.as_str());
+    let notes = json["notes"].as_array().unwrap();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0]["text"], "Second note");
+    assert!(
+        !json["ticket"]["body"]
+            .as_str()
+            .unwrap()
+            .contains("First note")
+    );
+
+    let output = janus.run_success(&["context", &child_id]);
+    assert!(output.contains("# Child task"));
+    assert!(output.contains(&format!("## Parent: {parent_id} Epic work")));
+    assert!(output.contains("## Dependencies"));
+    assert!(output.contains("## Recent Notes"));
+}
+
+#[test]
+fn test_show_and_edit_section() {
+    let janus = JanusTest::new();
+    let id = janus
+        .run_success(&[
+            "create",
+            "Sectioned",
+            "-d",
+            "What and why",
+            "--acceptance",
+            "- [ ] Works",
+        ])
+        .trim()
+        .to_string();
+
+    let output = janus.run_success(&["show", &id, "--section", "acceptance"]);
+    assert_eq!(output.trim(), "- [ ] Works");
+
+    let output = janus.run_success(&["show", &id, "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["sections"]["description"], "What and why");
+    assert!(json["sections"]["design"].is_null());
+
+    let output = janus.run_with_stdin(&["edit", &id, "--section", "design"], "Use a queue");
+    assert!(output.status.success());
+
+    let content = janus.read_ticket(&id);
+    assert!(content.contains("## Design\n\nUse a queue"));
+    assert!(content.contains("What and why"));
+    assert!(content.contains("- [ ] Works"));
+
+    let output = janus.run_success(&["show", &id, "--section", "design", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["content"], "Use a queue");
+
+    janus.run_failure(&["show", &id, "--section", "summary"]);
+}
+
+#[test]
+fn test_show_render() {
+    let janus = JanusTest::new();
+    let id = janus
+        .run_success(&[
+            "create",
+            "Rendered",
+            "-d",
+            "Some **bold** text",
+            "--acceptance",
+            "- [ ] Works",
+            "-p",
+            "1",
+        ])
+        .trim()
+        .to_string();
+
+    let output = janus.run_success(&["show", &id, "--render"]);
+    assert!(output.starts_with(&format!("{id} [new] task P1")));
+    assert!(output.contains("Rendered\n════════"));
+    assert!(output.contains("Some bold text"));
+    assert!(output.contains("• [ ] Works"));
+    assert!(!output.contains("status: new"));
+    assert!(!output.contains('\x1b'));
+
+    let output = janus.run_success(&["show", &id, "--section", "acceptance", "--render"]);
+    assert_eq!(output.trim(), "• [ ] Works");
+}
+
+#[test]
+fn test_show_not_found_json_errors() {
+    let janus = JanusTest::new();
+    janus.run_success(&["create", "Ticket 1"]);
+
+    let stderr = janus.run_failure(&["--json-errors", "show", "j-zzzz"]);
+    let json: serde_json::Value = serde_json::from_str(stderr.trim()).unwrap();
+    assert_eq!(json["error"]["code"], "TICKET_NOT_FOUND");
+    assert!(
+        json["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("j-zzzz")
+    );
+
+    let stderr = janus.run_failure(&["show", "--json-errors", "--bogus-flag", "j-zzzz"]);
+    let json: serde_json::Value = serde_json::from_str(stderr.trim()).unwrap();
+    assert_eq!(json["error"]["code"], "USAGE");
+}
+
+#[test]
+fn test_show_activity() {
+    let janus = JanusTest::new();
+    let id = janus
+        .run_success(&["create", "Ticket 1"])
+        .trim()
+        .to_string();
+    janus.run_success(&["start", &id]);
+    janus.run_success(&["add-note", &id, "Halfway there\nmore detail"]);
+
+    let output = janus.run_success(&["show", &id, "--activity"]);
+    assert!(output.contains("## Activity"));
+    assert!(output.contains("new → in_progress"));
+    assert!(output.contains("Halfway there"));
+
+    let output = janus.run_success(&["show", &id, "--activity", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let kinds: Vec<&str> = json["activity"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["kind"].as_str().unwrap())
+        .collect();
+    // Entries within the same second may come in either order
+    assert_eq!(kinds.len(), 3);
+    assert_eq!(kinds[0], "created");
+    assert!(kinds.contains(&"status") && kinds.contains(&"note"));
+
+    let output = janus.run_success(&["show", &id, "--json"]);
+    assert!(!output.contains("\"activity\""));
+
+    // A repository without commits has no commits to link
+    let status = std::process::Command::new("git")
+        .args(["init", "--quiet"])
+        .current_dir(janus.temp_dir.path())
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success());
+    let output = janus.run_success(&["show", &id, "--activity"]);
+    assert!(output.contains("Halfway there"));
+}
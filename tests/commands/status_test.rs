@@ -91,6 +91,52 @@ fn test_status_close_summary_and_no_summary_conflict() {
     );
 }
 
+#[test]
+fn test_status_close_bulk_with_reason() {
+    let janus = JanusTest::new();
+
+    let id1 = janus.run_success(&["create", "First"]).trim().to_string();
+    let id2 = janus.run_success(&["create", "Second"]).trim().to_string();
+    let ids = format!("{id1},{id2}");
+    let output = janus.run_success(&[
+        "close",
+        "--ids",
+        &ids,
+        "--reason",
+        "duplicate",
+        "--note",
+        "Same as the login ticket",
+    ]);
+    assert!(output.contains("(duplicate)"));
+
+    for id in [&id1, &id2] {
+        let content = janus.read_ticket(id);
+        assert!(content.contains("status: cancelled"));
+        assert!(content.contains("resolution: duplicate"));
+        assert!(content.contains("Same as the login ticket"));
+    }
+
+    let output = janus.run_success(&["ls", "--closed"]);
+    assert!(output.contains("[duplicate]"));
+
+    janus.run_success(&["reopen", &id1]);
+    assert!(!janus.read_ticket(&id1).contains("resolution:"));
+
+    // A bad ID fails before any ticket is closed
+    let stderr = janus.run_failure(&["close", "--ids", &format!("{id1},nope"), "--no-summary"]);
+    assert!(!stderr.is_empty());
+    assert!(janus.read_ticket(&id1).contains("status: new"));
+
+    // So does a forbidden transition on a later ticket
+    janus.write_config(
+        "transitions:\n  rules:\n    - from: in_progress\n      to: complete\n      policy: forbid\n",
+    );
+    janus.run_success(&["start", &id2]);
+    janus.run_failure(&["close", "--ids", &ids, "--no-summary"]);
+    assert!(janus.read_ticket(&id1).contains("status: new"));
+    assert!(janus.read_ticket(&id2).contains("status: in_progress"));
+}
+
 #[test]
 fn test_status_reopen() {
     let janus = JanusTest::new();
@@ -103,6 +149,52 @@ fn test_status_reopen() {
     assert!(output.contains("status: new"));
 }
 
+#[test]
+fn test_status_reopen_adds_note_and_count() {
+    let janus = JanusTest::new();
+
+    let id = janus.run_success(&["create", "Test"]).trim().to_string();
+    let other = janus.run_success(&["create", "Other"]).trim().to_string();
+    janus.run_success(&["close", &id, "--no-summary"]);
+    janus.run_success(&["reopen", &id, "--reason", "Still broken on Safari"]);
+
+    let content = janus.read_ticket(&id);
+    assert!(content.contains("reopen-count: 1"));
+    assert!(content.contains("Reopened (was complete): Still broken on Safari"));
+
+    // Reopening through `janus status` is counted as well
+    janus.run_success(&["status", &id, "cancelled"]);
+    janus.run_success(&["status", &id, "next"]);
+    assert!(janus.read_ticket(&id).contains("reopen-count: 2"));
+
+    let output = janus.run_success(&["ls", "--reopened"]);
+    assert!(output.contains(&id));
+    assert!(output.contains("[reopened 2x]"));
+    assert!(!output.contains(&other));
+}
+
+#[test]
+fn test_status_reopen_requires_reason_when_configured() {
+    let janus = JanusTest::new();
+    let janus_dir = janus.temp_dir.path().join(".janus");
+    std::fs::create_dir_all(&janus_dir).unwrap();
+    std::fs::write(
+        janus_dir.join("config.yaml"),
+        "transitions:\n  require_reopen_reason: true\n",
+    )
+    .unwrap();
+
+    let id = janus.run_success(&["create", "Test"]).trim().to_string();
+    janus.run_success(&["close", &id, "--no-summary"]);
+
+    let stderr = janus.run_failure(&["reopen", &id]);
+    assert!(stderr.contains("--reason"));
+    assert!(janus.read_ticket(&id).contains("status: complete"));
+
+    janus.run_success(&["reopen", &id, "--reason", "Regressed"]);
+    assert!(janus.read_ticket(&id).contains("status: new"));
+}
+
 #[test]
 fn test_status_cancelled() {
     let janus = JanusTest::new();
@@ -111,12 +111,14 @@ impl RemoteIssueBuilder {
                 status: RemoteStatus::Open,
                 priority: None,
                 assignee: None,
+                assignee_aliases: Vec::new(),
                 updated_at: "2024-01-01T00:00:00Z".to_string(),
                 url: format!("https://example.com/issues/{id}"),
                 labels: vec![],
                 team: None,
                 project: None,
                 milestone: None,
+                cycle: None,
                 due_date: None,
                 created_at: "2024-01-01T00:00:00Z".to_string(),
                 creator: None,
@@ -21,13 +21,43 @@ pub fn janus_binary() -> &'static str {
 /// Helper struct to run janus commands in an isolated temp directory
 pub struct JanusTest {
     pub temp_dir: TempDir,
+    /// Extra environment variables for every command
+    env: Vec<(String, String)>,
 }
 
 impl JanusTest {
     pub fn new() -> Self {
         let temp_dir = TempDir::new().expect("Failed to create temp directory");
 
-        JanusTest { temp_dir }
+        JanusTest {
+            temp_dir,
+            env: Vec::new(),
+        }
+    }
+
+    /// Serve remotes from fixture files instead of the network.
+    ///
+    /// The files in `fixture_dir` (relative to `tests/fixtures/remote/`) are
+    /// copied into the test directory, so writes made by the commands stay
+    /// local to the test, in `remote-fixtures/` under the test directory.
+    pub fn with_remote_fixtures(mut self, fixture_dir: &str) -> Self {
+        let source = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/remote")
+            .join(fixture_dir);
+        let target = self.temp_dir.path().join("remote-fixtures");
+        fs::create_dir_all(&target).expect("Failed to create fixture dir");
+        if source.exists() {
+            for entry in fs::read_dir(&source).expect("Failed to read fixture dir") {
+                let entry = entry.expect("Failed to read fixture entry");
+                fs::copy(entry.path(), target.join(entry.file_name()))
+                    .expect("Failed to copy fixture");
+            }
+        }
+        self.env.push((
+            "JANUS_REMOTE_FIXTURES".to_string(),
+            target.to_string_lossy().into_owned(),
+        ));
+        self
     }
 
     pub fn run(&self, args: &[&str]) -> Output {
@@ -35,10 +65,37 @@ impl JanusTest {
             .args(args)
             .current_dir(self.temp_dir.path())
             .env("JANUS_SKIP_EMBEDDINGS", "1")
+            .envs(self.env.iter().map(|(k, v)| (k, v)))
             .output()
             .expect("Failed to execute janus command")
     }
 
+    /// Run a command with `input` piped to its stdin
+    pub fn run_with_stdin(&self, args: &[&str], input: &str) -> Output {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let mut child = Command::new(janus_binary())
+            .args(args)
+            .current_dir(self.temp_dir.path())
+            .env("JANUS_SKIP_EMBEDDINGS", "1")
+            .envs(self.env.iter().map(|(k, v)| (k, v)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("Failed to execute janus command");
+        child
+            .stdin
+            .take()
+            .expect("stdin should be piped")
+            .write_all(input.as_bytes())
+            .expect("Failed to write stdin");
+        child
+            .wait_with_output()
+            .expect("Failed to wait for janus command")
+    }
+
     pub fn run_success(&self, args: &[&str]) -> String {
         let output = self.run(args);
         if !output.status.success() {
@@ -96,3 +96,37 @@ fn test_config_rejects_unknown_keys() {
     let stderr = janus.run_failure(&["config", "set", "github_token", "some_token"]);
     assert!(stderr.contains("unknown config key"));
 }
+
+#[test]
+fn test_git_auto_commit() {
+    let janus = JanusTest::new();
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(janus.temp_dir.path())
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {args:?} failed");
+        String::from_utf8_lossy(&output.stdout).to_string()
+    };
+    git(&["init", "--quiet"]);
+    git(&["config", "user.name", "Test"]);
+    git(&["config", "user.email", "test@example.com"]);
+
+    janus.run_success(&["config", "set", "git.auto_commit", "true"]);
+    let id = janus
+        .run_success(&["create", "Auto commit"])
+        .trim()
+        .to_string();
+    janus.run_success(&["start", &id]);
+
+    let log = git(&["log", "--format=%s"]);
+    let subjects: Vec<&str> = log.lines().collect();
+    assert_eq!(subjects.len(), 2);
+    assert!(subjects[0].starts_with(&format!("janus: {id} status new→in_progress")));
+    assert!(subjects[1].starts_with(&format!("janus: {id} created")));
+
+    // Only the ticket file is committed; the config stays untracked
+    let files = git(&["show", "--name-only", "--format=", "HEAD"]);
+    assert_eq!(files.trim(), format!(".janus/items/{id}.md"));
+}
@@ -143,8 +143,8 @@ fn test_mcp_tools_list() {
     assert!(response["result"]["tools"].is_array());
     let tools = response["result"]["tools"].as_array().unwrap();
 
-    // Should have 29 tools (20 ticket/plan/doc tools + 9 objective tools)
-    assert_eq!(tools.len(), 29);
+    // Should have 33 tools (24 ticket/plan/doc tools + 9 objective tools)
+    assert_eq!(tools.len(), 33);
 
     // Verify all tool names are present
     let tool_names: Vec<&str> = tools.iter().map(|t| t["name"].as_str().unwrap()).collect();
@@ -152,6 +152,9 @@ fn test_mcp_tools_list() {
     assert!(tool_names.contains(&"spawn_subtask"));
     assert!(tool_names.contains(&"update_status"));
     assert!(tool_names.contains(&"add_note"));
+    assert!(tool_names.contains(&"get_acceptance_criteria"));
+    assert!(tool_names.contains(&"set_acceptance_criteria"));
+    assert!(tool_names.contains(&"check_criterion"));
     assert!(tool_names.contains(&"list_tickets"));
     assert!(tool_names.contains(&"show_ticket"));
     assert!(tool_names.contains(&"add_dependency"));
@@ -164,6 +167,7 @@ fn test_mcp_tools_list() {
     assert!(tool_names.contains(&"get_children"));
     assert!(tool_names.contains(&"semantic_search"));
     assert!(tool_names.contains(&"get_next_available_ticket"));
+    assert!(tool_names.contains(&"board_summary"));
     assert!(tool_names.contains(&"doc_list"));
     assert!(tool_names.contains(&"doc_show"));
     assert!(tool_names.contains(&"doc_set"));
@@ -634,6 +638,104 @@ fn test_mcp_call_add_note() {
     assert!(ticket_content.contains("This is a test note from MCP"));
 }
 
+#[test]
+fn test_mcp_call_acceptance_criteria() {
+    let janus = common::JanusTest::new();
+    let id = janus
+        .run_success(&["create", "Test ticket"])
+        .trim()
+        .to_string();
+
+    let mut client = McpTestClient::new(janus.temp_dir.path());
+    client.initialize();
+    client.send_initialized();
+    let mut call = |name: &str, arguments: serde_json::Value| -> String {
+        let response = client.send_request(
+            "tools/call",
+            serde_json::json!({ "name": name, "arguments": arguments }),
+        );
+        response["result"]["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    };
+
+    let text = call(
+        "set_acceptance_criteria",
+        serde_json::json!({ "id": id, "criteria": ["Login works", "- Errors are shown"] }),
+    );
+    assert!(text.contains("Set 2 acceptance criteria"));
+    assert!(
+        janus
+            .read_ticket(&id)
+            .contains("## Acceptance Criteria\n\n- [ ] Login works\n- [ ] Errors are shown")
+    );
+
+    let text = call(
+        "check_criterion",
+        serde_json::json!({ "id": id, "index": 2 }),
+    );
+    assert!(text.contains("Checked criterion 2"));
+
+    let text = call("get_acceptance_criteria", serde_json::json!({ "id": id }));
+    assert!(text.contains("1. [ ] Login works"));
+    assert!(text.contains("2. [x] Errors are shown"));
+    assert!(text.contains("1 of 2 met"));
+
+    let text = call(
+        "check_criterion",
+        serde_json::json!({ "id": id, "index": 3 }),
+    );
+    assert!(text.contains("has no acceptance criterion 3"));
+}
+
+#[test]
+fn test_mcp_prompts() {
+    let janus = common::JanusTest::new();
+    let id = janus
+        .run_success(&["create", "Flaky login", "--labels", "auth"])
+        .trim()
+        .to_string();
+
+    let mut client = McpTestClient::new(janus.temp_dir.path());
+    client.initialize();
+    client.send_initialized();
+
+    let response = client.send_request("prompts/list", serde_json::json!({}));
+    let names: Vec<&str> = response["result"]["prompts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["triage_ticket", "decompose_into_plan", "write_standup"]
+    );
+
+    let response = client.send_request(
+        "prompts/get",
+        serde_json::json!({ "name": "triage_ticket", "arguments": { "id": id } }),
+    );
+    let text = response["result"]["messages"][0]["content"]["text"]
+        .as_str()
+        .unwrap();
+    assert!(text.contains(&format!("Triage Janus ticket {id}")));
+    assert!(text.contains("Labels in use: auth (1)"));
+    assert!(text.contains("Flaky login"));
+
+    let response = client.send_request(
+        "prompts/get",
+        serde_json::json!({ "name": "triage_ticket", "arguments": {} }),
+    );
+    assert!(
+        response["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("requires the 'id' argument")
+    );
+}
+
 #[test]
 fn test_mcp_error_response_format() {
     let janus = common::JanusTest::new();
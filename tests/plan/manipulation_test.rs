@@ -522,6 +522,40 @@ fn test_plan_remove_phase_with_migrate() {
     assert!(output.contains(&ticket_id));
 }
 
+#[test]
+fn test_plan_rename_and_annotate_phase() {
+    let janus = JanusTest::new();
+
+    let plan_id = janus
+        .run_success(&["plan", "create", "Phased Plan", "--phase", "Phase One"])
+        .trim()
+        .to_string();
+    let ticket_id = janus.run_success(&["create", "Task"]).trim().to_string();
+    janus.run_success(&["plan", "add-ticket", &plan_id, &ticket_id, "--phase", "1"]);
+
+    let output = janus.run_success(&["plan", "rename-phase", &plan_id, "1", "Foundations"]);
+    assert!(output.contains("Renamed phase 1"));
+    janus.run_success(&[
+        "plan",
+        "annotate-phase",
+        &plan_id,
+        "Foundations",
+        "--description",
+        "Lay the groundwork.",
+    ]);
+
+    let content = janus.read_plan(&plan_id);
+    assert!(content.contains("## Phase 1: Foundations"));
+    assert!(content.contains("Lay the groundwork."));
+    assert!(content.contains(&ticket_id));
+
+    janus.run_success(&["plan", "annotate-phase", &plan_id, "1", "--description", ""]);
+    assert!(!janus.read_plan(&plan_id).contains("Lay the groundwork."));
+
+    let error = janus.run_failure(&["plan", "rename-phase", &plan_id, "9", "Nope"]);
+    assert!(error.contains("not found"));
+}
+
 #[test]
 fn test_plan_add_ticket_with_after() {
     let janus = JanusTest::new();
@@ -309,6 +309,35 @@ fn test_plan_reorder_requires_interactive_terminal() {
     );
 }
 
+#[test]
+fn test_plan_reorder_from_stdin() {
+    let janus = JanusTest::new();
+
+    let plan_id = janus
+        .run_success(&["plan", "create", "Test Plan"])
+        .trim()
+        .to_string();
+    let first = janus.run_success(&["create", "First"]).trim().to_string();
+    let second = janus.run_success(&["create", "Second"]).trim().to_string();
+    janus.run_success(&["plan", "add-ticket", &plan_id, &first]);
+    janus.run_success(&["plan", "add-ticket", &plan_id, &second]);
+
+    let output = janus.run_with_stdin(
+        &["plan", "reorder", &plan_id, "--from-file", "-"],
+        &format!("{second}\n{first}\n"),
+    );
+    assert!(output.status.success());
+    let content = janus.read_plan(&plan_id);
+    assert!(content.find(&second).unwrap() < content.find(&first).unwrap());
+
+    // Orders that drop a ticket are rejected
+    let output = janus.run_with_stdin(
+        &["plan", "reorder", &plan_id, "--from-file", "-"],
+        &format!("{first}\n"),
+    );
+    assert!(!output.status.success());
+}
+
 #[test]
 fn test_plan_reorder_phased_requires_phase_arg() {
     let janus = JanusTest::new();
@@ -548,6 +577,51 @@ fn test_help_has_command_groups() {
     assert!(stdout.contains("Relationships"));
 }
 
+#[test]
+fn test_plan_diff_against_git_revision() {
+    let janus = JanusTest::new();
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(janus.temp_dir.path())
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {args:?} failed");
+    };
+    git(&["init", "--quiet"]);
+    git(&["config", "user.name", "Test"]);
+    git(&["config", "user.email", "test@example.com"]);
+
+    let plan_id = janus
+        .run_success(&[
+            "plan", "create", "Release", "--phase", "Design", "--phase", "Build",
+        ])
+        .trim()
+        .to_string();
+    let moved = janus.run_success(&["create", "Moved"]).trim().to_string();
+    let removed = janus.run_success(&["create", "Removed"]).trim().to_string();
+    janus.run_success(&["plan", "add-ticket", &plan_id, &moved, "--phase", "1"]);
+    janus.run_success(&["plan", "add-ticket", &plan_id, &removed, "--phase", "1"]);
+    git(&["add", "-A"]);
+    git(&["commit", "--quiet", "-m", "first"]);
+
+    let output = janus.run_success(&["plan", "diff", &plan_id]);
+    assert!(output.contains("No structural changes"));
+
+    let added = janus.run_success(&["create", "Added"]).trim().to_string();
+    janus.run_success(&["plan", "add-ticket", &plan_id, &added, "--phase", "2"]);
+    janus.run_success(&["plan", "move-ticket", &plan_id, &moved, "--to-phase", "2"]);
+    janus.run_success(&["plan", "remove-ticket", &plan_id, &removed]);
+    janus.run_success(&["plan", "rename-phase", &plan_id, "1", "Discovery"]);
+
+    let output = janus.run_success(&["plan", "diff", &plan_id, "--ref", "HEAD", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["tickets_added"][0]["id"], added.as_str());
+    assert_eq!(json["tickets_removed"][0]["id"], removed.as_str());
+    assert_eq!(json["tickets_moved"][0]["id"], moved.as_str());
+    assert_eq!(json["phases_renamed"][0]["to"], "Discovery");
+}
+
 // ============================================================================
 // Plan Import Tests
 // ============================================================================
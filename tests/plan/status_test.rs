@@ -422,3 +422,49 @@ created: 2024-01-01T00:00:00Z
 // ============================================================================
 // Plan Show/Ls Format Option Tests
 // ============================================================================
+
+// ============================================================================
+// Plan Forecast Tests
+// ============================================================================
+
+#[test]
+fn test_plan_forecast() {
+    let janus = JanusTest::new();
+
+    let plan_id = janus
+        .run_success(&["plan", "create", "Forecast Plan"])
+        .trim()
+        .to_string();
+    let small = janus
+        .run_success(&["create", "Small", "--size", "small"])
+        .trim()
+        .to_string();
+    let unsized_ticket = janus.run_success(&["create", "Unsized"]).trim().to_string();
+    janus.run_success(&["plan", "add-ticket", &plan_id, &small]);
+    janus.run_success(&["plan", "add-ticket", &plan_id, &unsized_ticket]);
+
+    // Without any completed tickets there is no throughput to go on
+    let output = janus.run_success(&["plan", "forecast", &plan_id, "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["remaining_tickets"], 2);
+    assert_eq!(json["remaining_points"], 5);
+    assert!(json["forecast"].is_null());
+
+    let done = janus
+        .run_success(&["create", "Done", "--size", "large"])
+        .trim()
+        .to_string();
+    janus.run_success(&["close", &done, "--no-summary"]);
+
+    let output = janus.run_success(&["plan", "forecast", &plan_id, "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["weekly_throughput"][0], 5);
+    let bands = json["forecast"].as_array().unwrap();
+    assert_eq!(bands.len(), 3);
+    assert_eq!(bands[0]["confidence"], 50);
+    assert!(bands[0]["weeks"].as_u64().unwrap() >= 1);
+
+    let output = janus.run_success(&["plan", "forecast", &plan_id]);
+    assert!(output.contains("Forecast:"));
+    assert!(output.contains("95%"));
+}
@@ -88,3 +88,82 @@ fn test_help_shows_new_commands() {
     assert!(output.contains("remote"), "Should show remote command");
     assert!(output.contains("config"), "Should show config command");
 }
+
+// ============================================================================
+// Fixture-backed remote tests (see src/remote/mock.rs)
+// ============================================================================
+
+const GITHUB_CONFIG: &str = "default_remote:\n  platform: github\n  org: acme\n  repo: app\n";
+
+fn read_github_fixture(janus: &JanusTest) -> serde_json::Value {
+    let path = janus.temp_dir.path().join("remote-fixtures/github.json");
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn test_adopt_from_fixture() {
+    let janus = JanusTest::new().with_remote_fixtures("basic");
+
+    let output = janus.run_success(&["remote", "adopt", "github:acme/app/1", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let id = json["id"].as_str().unwrap();
+
+    let content = janus.read_ticket(id);
+    assert!(content.contains("# Login fails on Safari"));
+    assert!(content.contains("remote: github:acme/app/1"));
+    assert!(content.contains("Safari 17"));
+
+    let stderr = janus.run_failure(&["remote", "adopt", "github:acme/app/99"]);
+    assert!(stderr.contains("not found"), "got: {stderr}");
+}
+
+#[test]
+fn test_push_to_fixture() {
+    let janus = JanusTest::new().with_remote_fixtures("basic");
+    janus.write_config(GITHUB_CONFIG);
+
+    let id = janus
+        .run_success(&["create", "Export to CSV", "--labels", "feature"])
+        .trim()
+        .to_string();
+    let output = janus.run_success(&["remote", "push", &id, "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["remote_ref"], "github:acme/app/3");
+
+    let fixture = read_github_fixture(&janus);
+    let issue = &fixture["issues"]["github:acme/app/3"];
+    assert_eq!(issue["title"], "Export to CSV");
+    assert_eq!(issue["labels"], serde_json::json!(["feature"]));
+    assert!(janus.read_ticket(&id).contains("remote: github:acme/app/3"));
+}
+
+#[test]
+fn test_sync_all_pulls_fixture_changes() {
+    let janus = JanusTest::new().with_remote_fixtures("basic");
+    janus.write_config(&format!(
+        "{GITHUB_CONFIG}remotes:\n  github:\n    mode: mirror\n  cache_ttl: 0m\n"
+    ));
+
+    let output = janus.run_success(&["remote", "adopt", "github:acme/app/2", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let id = json["id"].as_str().unwrap().to_string();
+
+    let output = janus.run_success(&["remote", "sync", "--all"]);
+    assert!(output.contains("1 already in sync"), "got: {output}");
+
+    let mut fixture = read_github_fixture(&janus);
+    fixture["issues"]["github:acme/app/2"]["title"] = "Add dark and light themes".into();
+    std::fs::write(
+        janus.temp_dir.path().join("remote-fixtures/github.json"),
+        fixture.to_string(),
+    )
+    .unwrap();
+
+    let output = janus.run_success(&["remote", "sync", "--all"]);
+    assert!(output.contains("Pulled"), "got: {output}");
+    assert!(
+        janus
+            .read_ticket(&id)
+            .contains("# Add dark and light themes")
+    );
+}